@@ -0,0 +1,230 @@
+//! The termite command line generator, reads a data model from a YAML or JSON
+//! file and writes the generated code together with all the files it depends
+//! on into an output directory
+
+use clap::{Parser, Subcommand, ValueEnum};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+use termite_dmg::{build, Error};
+
+/// Termite Data Model Generator
+#[derive(Parser, Debug)]
+#[command(name = "termite", version, about)]
+struct Cli {
+    /// The action to perform
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// All the actions of the command line generator
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Generates the code for a data model and all the files it depends on
+    Generate {
+        /// The language to generate
+        language: Language,
+        /// The data model, files ending with .json are read as JSON and all
+        /// other files as YAML
+        model: PathBuf,
+        /// The directory to write the files to, it is created if it does not
+        /// exist
+        #[arg(short, long, default_value = ".")]
+        output: PathBuf,
+        /// The base name of the generated files, defaults to the file name of
+        /// the data model without extension
+        #[arg(short, long)]
+        name: Option<String>,
+        /// The number of spaces to use for indentation, defaults to 4 for Rust
+        /// and Python and 2 for everything else
+        #[arg(long)]
+        indent: Option<usize>,
+        /// The data type to export the JSON schema for, required for schema
+        #[arg(long = "type", required_if_eq("language", "schema"))]
+        type_name: Option<String>,
+        /// The $id of the JSON schema
+        #[arg(long, default_value = "")]
+        schema_id: String,
+    },
+    /// Validates a data model and prints all the problems found
+    Validate {
+        /// The data model to validate
+        model: PathBuf,
+        /// Validate for a specific language, for c++ any unknown type is
+        /// assumed to be defined in the headers
+        #[arg(short, long)]
+        language: Option<Language>,
+    },
+    /// Rewrites a data model in the canonical layout
+    Fmt {
+        /// The data model to format
+        model: PathBuf,
+        /// Do not write the file, fail if it is not already formatted
+        #[arg(long)]
+        check: bool,
+    },
+}
+
+/// The languages which can be generated
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+enum Language {
+    /// c++ with the termite.hpp, yaml-cpp and nlohmann::json interfaces
+    Cpp,
+    /// Rust using serde
+    Rust,
+    /// Python dataclasses
+    Python,
+    /// TypeScript types with parse functions
+    Typescript,
+    /// JSON schema
+    Schema,
+    /// Markdown reference documentation
+    Markdown,
+    /// Reference documentation as a single HTML page
+    Html,
+}
+
+impl Language {
+    /// Converts the language into the language of the build generator
+    ///
+    /// # Parameters
+    ///
+    /// type_name: The data type to export the JSON schema for
+    ///
+    /// schema_id: The $id of the JSON schema
+    fn to_build(self, type_name: String, schema_id: String) -> build::Language {
+        return match self {
+            Language::Cpp => build::Language::Cpp,
+            Language::Rust => build::Language::Rust,
+            Language::Python => build::Language::Python,
+            Language::Typescript => build::Language::TypeScript,
+            Language::Schema => build::Language::Schema {
+                type_name,
+                schema_id,
+            },
+            Language::Markdown => build::Language::Markdown,
+            Language::Html => build::Language::Html,
+        };
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Generate {
+            language,
+            model,
+            output,
+            name,
+            indent,
+            type_name,
+            schema_id,
+        } => {
+            let mut generator = build::Generator::new(model)
+                .output_dir(output)
+                .rerun_if_changed(false);
+            if let Some(name) = name {
+                generator = generator.name(name);
+            }
+            if let Some(indent) = indent {
+                generator = generator.indent(indent);
+            }
+            let language = language.to_build(type_name.unwrap_or_default(), schema_id);
+            generator
+                .generate(&language)
+                .map(|_| ())
+                .map_err(|error| error.get_messages())
+        }
+        Command::Validate { model, language } => validate(&model, language),
+        Command::Fmt { model, check } => format(&model, check).map_err(|error| vec![error]),
+    };
+
+    return match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(messages) => {
+            // Every validation error is written separately
+            for message in messages {
+                eprintln!("error: {message}");
+            }
+            ExitCode::FAILURE
+        }
+    };
+}
+
+/// Validates a data model, either with the generic rules or the rules of a
+/// specific language
+///
+/// # Parameters
+///
+/// model: The path of the data model
+///
+/// language: The language to validate for
+fn validate(model: &Path, language: Option<Language>) -> Result<(), Vec<String>> {
+    let generator = build::Generator::new(model).rerun_if_changed(false);
+
+    let result = match language {
+        // The schema is only exported for a single type so validate all of
+        // them with the generic rules
+        None | Some(Language::Schema) => match generator.load() {
+            Ok(data_model) => data_model.validate().map_err(|errors| {
+                let source = data_model.source.clone().unwrap_or_default();
+                build::Error::Model(Box::new(source), errors.into_iter().map(Error::from).collect())
+            }),
+            Err(error) => Err(error),
+        },
+        Some(language) => generator
+            .get_files(&language.to_build(String::new(), String::new()))
+            .map(|_| ()),
+    };
+
+    return result.map_err(|error| error.get_messages());
+}
+
+/// Rewrites a data model in the layout it is exported with
+///
+/// # Parameters
+///
+/// model: The path of the data model
+///
+/// check: If true the file is not written, instead it fails if it is not
+/// formatted
+fn format(model: &Path, check: bool) -> Result<(), String> {
+    let data_model = build::Generator::new(model)
+        .rerun_if_changed(false)
+        .load()
+        .map_err(|error| error.to_string())?;
+    let content = match fs::read_to_string(model) {
+        Ok(content) => content,
+        Err(error) => return Err(format!("{}: {error}", model.display())),
+    };
+
+    let is_json = model
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+    let formatted = if is_json {
+        data_model
+            .export_json_pretty()
+            .map(|formatted| format!("{formatted}\n"))
+            .map_err(|error| error.to_string())
+    } else {
+        data_model.export_yaml().map_err(|error| error.to_string())
+    };
+    let formatted = match formatted {
+        Ok(formatted) => formatted,
+        Err(error) => return Err(format!("{}: {error}", model.display())),
+    };
+
+    if formatted == content {
+        return Ok(());
+    }
+    if check {
+        return Err(format!("{}: The data model is not formatted", model.display()));
+    }
+    return match fs::write(model, formatted) {
+        Ok(()) => Ok(()),
+        Err(error) => Err(format!("{}: {error}", model.display())),
+    };
+}
@@ -0,0 +1,582 @@
+//! Helpers for generating code from a build script. The generator reads a
+//! data model file, writes the generated files into an output directory
+//! (OUT_DIR by default) and tells Cargo to rerun the build script when the
+//! data model changes. Files are only rewritten when their content changes, so
+//! build systems like CMake which look at modification times do not rebuild
+//! the generated code needlessly.
+//!
+//! ```no_run
+//! // build.rs
+//! termite_dmg::build::Generator::new("model.yaml")
+//!     .generate(&termite_dmg::build::Language::Rust)
+//!     .unwrap();
+//! ```
+
+use crate::{cpp, docs, python, rust, typescript, DataModel, LocatedError, Source};
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// The languages a generator can write
+#[derive(Clone, Debug, PartialEq)]
+pub enum Language {
+    /// The c++ header and source of the data model and of every imported data
+    /// model together with termite.hpp and the yaml-cpp and nlohmann::json
+    /// interfaces
+    Cpp,
+    /// A single Rust file
+    Rust,
+    /// A Python module together with termite.py
+    Python,
+    /// A TypeScript module together with termite.ts
+    TypeScript,
+    /// The JSON schema of a single data type
+    Schema {
+        /// The name of the data type to export the schema for
+        type_name: String,
+        /// The $id of the schema
+        schema_id: String,
+    },
+    /// Markdown reference documentation of every data type
+    Markdown,
+    /// Reference documentation of every data type as a single self-contained
+    /// HTML page
+    Html,
+}
+
+impl Language {
+    /// Gets the default number of spaces to use for indentation
+    fn default_indent(&self) -> usize {
+        return match self {
+            Language::Rust | Language::Python => 4,
+            Language::Cpp
+            | Language::TypeScript
+            | Language::Schema { .. }
+            | Language::Markdown
+            | Language::Html => 2,
+        };
+    }
+}
+
+/// Generates code from a data model file, see the module documentation
+#[derive(Clone, Debug, PartialEq)]
+pub struct Generator {
+    /// The path of the data model
+    model: PathBuf,
+    /// The directory to write the files to, OUT_DIR if not set
+    output_dir: Option<PathBuf>,
+    /// The base name of the generated files, the file name of the data model
+    /// if not set
+    name: Option<String>,
+    /// The number of spaces to use for indentation, a language specific
+    /// default if not set
+    indent: Option<usize>,
+    /// True if the cargo:rerun-if-changed lines should be printed
+    rerun_if_changed: bool,
+}
+
+impl Generator {
+    /// Creates a generator for a data model, files ending with .json are read
+    /// as JSON and all other files as YAML
+    ///
+    /// # Parameters
+    ///
+    /// model: The path of the data model
+    pub fn new(model: impl Into<PathBuf>) -> Self {
+        return Self {
+            model: model.into(),
+            output_dir: None,
+            name: None,
+            indent: None,
+            rerun_if_changed: true,
+        };
+    }
+
+    /// Sets the directory to write the generated files to, it is created if it
+    /// does not exist
+    ///
+    /// # Parameters
+    ///
+    /// output_dir: The directory to write to
+    pub fn output_dir(mut self, output_dir: impl Into<PathBuf>) -> Self {
+        self.output_dir = Some(output_dir.into());
+        return self;
+    }
+
+    /// Sets the base name of the generated files, for c++ it is also used for
+    /// the header guard
+    ///
+    /// # Parameters
+    ///
+    /// name: The base name of the files
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        return self;
+    }
+
+    /// Sets the number of spaces to use for indentation
+    ///
+    /// # Parameters
+    ///
+    /// indent: The number of spaces
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = Some(indent);
+        return self;
+    }
+
+    /// Sets if the cargo:rerun-if-changed lines should be printed, this is on
+    /// by default and should be turned off outside of build scripts
+    ///
+    /// # Parameters
+    ///
+    /// rerun_if_changed: True if the lines should be printed
+    pub fn rerun_if_changed(mut self, rerun_if_changed: bool) -> Self {
+        self.rerun_if_changed = rerun_if_changed;
+        return self;
+    }
+
+    /// Gets the base name of the generated files
+    pub fn get_name(&self) -> String {
+        if let Some(name) = &self.name {
+            return name.clone();
+        }
+
+        return match self.model.file_stem() {
+            Some(stem) => stem.to_string_lossy().to_string(),
+            None => "model".to_string(),
+        };
+    }
+
+    /// Reads the data model and all the data models it imports, the
+    /// cargo:rerun-if-changed lines for them are printed if enabled
+    pub fn load(&self) -> Result<DataModel, Error> {
+        if self.rerun_if_changed {
+            println!("cargo:rerun-if-changed={}", self.model.display());
+        }
+
+        let content = match fs::read_to_string(&self.model) {
+            Ok(content) => content,
+            Err(error) => return Err(Error::Read(self.model.clone(), error)),
+        };
+        let source = Source::new(self.model.display().to_string(), content);
+        let mut model = match DataModel::import_source(source.clone()) {
+            Ok(model) => model,
+            Err(error) => return Err(Error::Model(Box::new(source), vec![error.into()])),
+        };
+
+        if let Err(error) = model.resolve_imports(&self.model) {
+            return Err(Error::Model(Box::new(get_source(&model)), vec![error.into()]));
+        }
+        if self.rerun_if_changed {
+            for resolved in model.get_imported_models() {
+                println!("cargo:rerun-if-changed={}", resolved.file.display());
+            }
+        }
+
+        return Ok(model);
+    }
+
+    /// Generates the files for a language without writing them, returns a
+    /// list of file names and contents
+    ///
+    /// # Parameters
+    ///
+    /// language: The language to generate
+    pub fn get_files(&self, language: &Language) -> Result<Vec<(String, String)>, Error> {
+        let model = self.load()?;
+
+        // Validate first so every problem is reported with its location, c++
+        // allows types defined in the headers
+        let external_types = *language == Language::Cpp;
+        let source = Box::new(get_source(&model));
+        if let Err(errors) = model.validate_types(external_types) {
+            return Err(Error::Model(
+                source,
+                errors.into_iter().map(crate::Error::from).collect(),
+            ));
+        }
+
+        let name = self.get_name();
+        let indent = self.indent.unwrap_or(language.default_indent());
+        return get_language_files(model, language, &name, indent)
+            .map_err(|errors| Error::Model(source, errors));
+    }
+
+    /// Generates the files for a language and writes them to the output
+    /// directory, files which already have the correct content are not
+    /// touched. Returns the paths of all the generated files.
+    ///
+    /// # Parameters
+    ///
+    /// language: The language to generate
+    pub fn generate(&self, language: &Language) -> Result<Vec<PathBuf>, Error> {
+        let files = self.get_files(language)?;
+
+        let output_dir = match &self.output_dir {
+            Some(output_dir) => output_dir.clone(),
+            None => match std::env::var_os("OUT_DIR") {
+                Some(output_dir) => PathBuf::from(output_dir),
+                None => return Err(Error::MissingOutputDir),
+            },
+        };
+        if let Err(error) = fs::create_dir_all(&output_dir) {
+            return Err(Error::Write(output_dir, error));
+        }
+
+        let mut paths = vec![];
+        for (file_name, content) in files {
+            let path = output_dir.join(file_name);
+            if let Err(error) = write_if_changed(&path, &content) {
+                return Err(Error::Write(path, error));
+            }
+            paths.push(path);
+        }
+
+        return Ok(paths);
+    }
+}
+
+/// Generates the files for a language from a validated data model, returns a
+/// list of file names and contents
+///
+/// # Parameters
+///
+/// model: The data model
+///
+/// language: The language to generate
+///
+/// name: The base name of the generated files
+///
+/// indent: The number of spaces to use for indentation
+fn get_language_files(
+    model: DataModel,
+    language: &Language,
+    name: &str,
+    indent: usize,
+) -> Result<Vec<(String, String)>, Vec<crate::Error>> {
+    let files = match language {
+        Language::Cpp => {
+            // The imported data models are generated as well, their headers
+            // are included with the file name of the data model so it must
+            // not be used by the generated data model itself, which is never
+            // imported
+            cpp::check_import_names(
+                &model,
+                &mut HashMap::from([(name.to_string(), PathBuf::new())]),
+            )?;
+            let mut models = vec![(name.to_string(), model.clone())];
+            for resolved in model.get_imported_models() {
+                let name = cpp::get_file_name(&resolved.file);
+                models.push((name, resolved.data_model.clone()));
+            }
+
+            let mut files = vec![];
+            for (name, model) in models {
+                let model = cpp::DataModel::new(model)?;
+                let guard = name
+                    .chars()
+                    .map(|c| {
+                        if c.is_ascii_alphanumeric() {
+                            c.to_ascii_uppercase()
+                        } else {
+                            '_'
+                        }
+                    })
+                    .collect::<String>();
+                files.push((format!("{name}.h"), model.get_header(&guard, indent)?));
+                files.push((format!("{name}.cpp"), model.get_source(&name, indent)?));
+            }
+
+            let (yaml_header, yaml_source) = cpp::get_yaml_interface();
+            let (json_header, json_source) = cpp::get_json_interface();
+            files.extend([
+                (
+                    "termite.hpp".to_string(),
+                    cpp::get_termite_dependency().to_string(),
+                ),
+                ("termite-yaml.h".to_string(), yaml_header.to_string()),
+                ("termite-yaml.cpp".to_string(), yaml_source.to_string()),
+                ("termite-json.h".to_string(), json_header.to_string()),
+                ("termite-json.cpp".to_string(), json_source.to_string()),
+            ]);
+            files
+        }
+        Language::Rust => {
+            let model = rust::DataModel::new(model)?;
+            let source = model.get_source(indent)?;
+            vec![(format!("{name}.rs"), source)]
+        }
+        Language::Python => {
+            let model = python::DataModel::new(model)?;
+            let source = model.get_source(indent)?;
+            vec![
+                (format!("{name}.py"), source),
+                (
+                    "termite.py".to_string(),
+                    python::get_termite_dependency().to_string(),
+                ),
+            ]
+        }
+        Language::TypeScript => {
+            let model = typescript::DataModel::new(model)?;
+            let source = model.get_source(indent)?;
+            vec![
+                (format!("{name}.ts"), source),
+                (
+                    "termite.ts".to_string(),
+                    typescript::get_termite_dependency().to_string(),
+                ),
+            ]
+        }
+        Language::Schema {
+            type_name,
+            schema_id,
+        } => {
+            let schema = model.export_schema(type_name, schema_id)?;
+            vec![(
+                format!("{name}.schema.json"),
+                format!("{}\n", schema.pretty(indent as u16)),
+            )]
+        }
+        Language::Markdown => {
+            let model = docs::DataModel::new(model)?;
+            vec![(
+                format!("{name}.md"),
+                model.get_markdown(&format!("{name} Reference")),
+            )]
+        }
+        Language::Html => {
+            let model = docs::DataModel::new(model)?;
+            vec![(
+                format!("{name}.html"),
+                model.get_html(&format!("{name} Reference")),
+            )]
+        }
+    };
+
+    return Ok(files);
+}
+
+/// Writes a file only if its content is different, returns true if the file
+/// was written
+///
+/// # Parameters
+///
+/// path: The path of the file
+///
+/// content: The content the file must have
+pub fn write_if_changed(path: &Path, content: &str) -> Result<bool, io::Error> {
+    if let Ok(current) = fs::read(path) {
+        if current == content.as_bytes() {
+            return Ok(false);
+        }
+    }
+
+    fs::write(path, content)?;
+    return Ok(true);
+}
+
+/// Errors when generating code from a data model file, errors in the data model
+/// are written with the file, line and column they occur at
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    /// The data model file could not be read
+    #[error("{}: {}", .0.display(), .1)]
+    Read(PathBuf, io::Error),
+    /// The data model could not be imported, is not valid or code could not be
+    /// generated from it, every problem is written separately
+    #[error("{}", get_model_message(.0, .1))]
+    Model(Box<Source>, Vec<crate::Error>),
+    /// No output directory was set and OUT_DIR is not defined
+    #[error("No output directory was set and OUT_DIR is not defined")]
+    MissingOutputDir,
+    /// A generated file could not be written
+    #[error("{}: {}", .0.display(), .1)]
+    Write(PathBuf, io::Error),
+}
+
+impl Error {
+    /// Gets a message for every problem, errors in the data model give one
+    /// message for each invalid value and all other errors a single message
+    pub fn get_messages(&self) -> Vec<String> {
+        return match self {
+            Error::Model(source, errors) => {
+                errors.iter().map(|error| error.render(source)).collect()
+            }
+            _ => vec![self.to_string()],
+        };
+    }
+}
+
+/// Gets the source of a loaded data model
+///
+/// # Parameters
+///
+/// model: The data model
+fn get_source(model: &DataModel) -> Source {
+    return model.source.clone().unwrap_or_default();
+}
+
+/// Writes every error in the data model with the position it occurs at,
+/// separated by new lines
+///
+/// # Parameters
+///
+/// source: The source of the data model
+///
+/// errors: The errors in the data model
+fn get_model_message(source: &Source, errors: &[crate::Error]) -> String {
+    return errors
+        .iter()
+        .map(|error| error.render(source))
+        .collect::<Vec<String>>()
+        .join("\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{process, time};
+
+    #[test]
+    fn generate() {
+        let output_dir = std::env::temp_dir().join("termite_build_generate");
+        let _ = fs::remove_dir_all(&output_dir);
+        let generator = Generator::new("tests/cpp/full_example/full_example_datamodel.yaml")
+            .output_dir(&output_dir)
+            .name("full_example")
+            .rerun_if_changed(false);
+
+        let paths = generator.generate(&Language::Cpp).unwrap();
+        assert_eq!(paths.len(), 7);
+        assert_eq!(
+            fs::read_to_string(output_dir.join("full_example.h")).unwrap(),
+            include_str!("../tests/cpp/full_example/full_example.h")
+        );
+
+        // Unchanged files are not written again
+        let modified = fs::metadata(&paths[0]).unwrap().modified().unwrap();
+        std::thread::sleep(time::Duration::from_millis(20));
+        generator.generate(&Language::Cpp).unwrap();
+        assert_eq!(fs::metadata(&paths[0]).unwrap().modified().unwrap(), modified);
+    }
+
+    #[test]
+    fn errors() {
+        let generator = Generator::new("tests/cpp/full_example/full_example_datamodel.yaml")
+            .rerun_if_changed(false);
+        let error = generator.get_files(&Language::Rust).unwrap_err();
+        assert!(matches!(&error, Error::Model(_, errors) if errors.len() == 4));
+
+        // Every problem is written with the position of the invalid value
+        let messages = error.get_messages();
+        assert_eq!(messages.len(), 4);
+        assert!(messages[1].starts_with(
+            "tests/cpp/full_example/full_example_datamodel.yaml:17:5: .data_types[1].data.data_type"
+        ));
+        assert!(messages[1].ends_with("17 |     data_type: int\n   |     ^"));
+        assert!(matches!(
+            Generator::new("missing.yaml").rerun_if_changed(false).load(),
+            Err(Error::Read(_, _))
+        ));
+    }
+
+    #[test]
+    fn imports() {
+        let generator = Generator::new("tests/cpp/imports/imports.yaml").rerun_if_changed(false);
+
+        // c++ includes the imported header and generates it as well
+        let files = generator.get_files(&Language::Cpp).unwrap();
+        assert_eq!(files[0].1, include_str!("../tests/cpp/imports/imports.h"));
+        assert_eq!(files[2].0, "shared.h");
+        assert_eq!(files[2].1, include_str!("../tests/cpp/imports/shared.h"));
+
+        // The generated files compile together even with a custom name
+        let output_dir = std::env::temp_dir().join("termite_build_imports");
+        let _ = fs::remove_dir_all(&output_dir);
+        let paths = generator
+            .clone()
+            .output_dir(&output_dir)
+            .name("geometry")
+            .generate(&Language::Cpp)
+            .unwrap();
+        assert_eq!(paths.len(), 9);
+        fs::write(
+            output_dir.join("imports_test.cpp"),
+            include_str!("../tests/cpp/imports/imports_test.cpp")
+                .replace("\"imports.h\"", "\"geometry.h\""),
+        )
+        .unwrap();
+        let compile_output = process::Command::new("g++")
+            .current_dir(&output_dir)
+            .args([
+                "geometry.cpp",
+                "shared.cpp",
+                "imports_test.cpp",
+                "-I.",
+                "-Wall",
+                "-std=c++17",
+                "-o",
+                "imports_test",
+            ])
+            .output()
+            .expect("failed to compile");
+        assert_eq!(compile_output.status.code(), Some(0));
+        let test_output = process::Command::new(output_dir.join("imports_test"))
+            .output()
+            .expect("failed to test");
+        assert_eq!(test_output.status.code(), Some(0));
+
+        // Single file languages generate the imported types as well
+        let files = generator.get_files(&Language::Rust).unwrap();
+        assert!(files[0].1.contains("pub struct Point {"));
+    }
+
+    #[test]
+    fn duplicate_import_names() {
+        let directory = std::env::temp_dir().join("termite_build_duplicate_import_names");
+        let _ = fs::remove_dir_all(&directory);
+        for name in ["a", "b"] {
+            fs::create_dir_all(directory.join(name)).unwrap();
+            fs::write(
+                directory.join(name).join("common.yaml"),
+                format!(
+                    "data_types:\n- name: {}\n  data: !Array\n    data_type: number\n",
+                    name.to_uppercase()
+                ),
+            )
+            .unwrap();
+        }
+        fs::write(
+            directory.join("b").join("shapes.yaml"),
+            "imports:\n- common.yaml\n",
+        )
+        .unwrap();
+        let model = directory.join("model.yaml");
+        fs::write(&model, "imports:\n- a/common.yaml\n- b/shapes.yaml\n").unwrap();
+
+        // The second common.yaml is imported through b/shapes.yaml
+        let generator = Generator::new(&model).rerun_if_changed(false);
+        let messages = generator
+            .get_files(&Language::Cpp)
+            .unwrap_err()
+            .get_messages();
+        assert_eq!(messages.len(), 1);
+        assert!(messages[0].contains(
+            ".imports[1].imports[0]: The c++ files of the import \"common.yaml\" would be named common.h and common.cpp"
+        ));
+
+        // The name of the generated files cannot be used by an import either
+        let generator = generator.name("common");
+        let messages = generator
+            .get_files(&Language::Cpp)
+            .unwrap_err()
+            .get_messages();
+        assert!(messages[0].contains(".imports[0]: The c++ files of the import \"a/common.yaml\""));
+
+        // Other languages generate a single file
+        assert!(generator.get_files(&Language::Rust).is_ok());
+    }
+}
@@ -0,0 +1,255 @@
+use super::*;
+use std::borrow::Cow;
+
+/// Evaluates a constraint for a value, it must evaluate to a boolean
+///
+/// # Parameters
+///
+/// expression: The expression of the constraint
+///
+/// value: The value denoted x
+pub(super) fn evaluate(expression: &Expression, value: &SerializationModel) -> Result<bool, Error> {
+    return Evaluator { value }.evaluate(expression)?.to_boolean();
+}
+
+/// The result of evaluating an expression
+#[derive(Clone, Debug, PartialEq)]
+enum Value<'a> {
+    /// Part of the value, scalars are converted when needed
+    Model(&'a SerializationModel),
+    /// A number
+    Number(f64),
+    /// A string
+    String(Cow<'a, str>),
+    /// A boolean
+    Boolean(bool),
+}
+
+impl<'a> Value<'a> {
+    /// Converts the value to a number
+    fn to_number(&self) -> Result<f64, Error> {
+        return match self {
+            Value::Number(value) => Ok(*value),
+            Value::Model(SerializationModel::Value(value)) => match value.trim().parse::<f64>() {
+                Ok(value) => Ok(value),
+                Err(_) => Err(self.invalid("number")),
+            },
+            _ => Err(self.invalid("number")),
+        };
+    }
+
+    /// Converts the value to a boolean
+    fn to_boolean(&self) -> Result<bool, Error> {
+        return match self {
+            Value::Boolean(value) => Ok(*value),
+            Value::Model(SerializationModel::Value(value)) => match value.trim() {
+                "true" | "True" | "1" => Ok(true),
+                "false" | "False" | "0" => Ok(false),
+                _ => Err(self.invalid("boolean")),
+            },
+            _ => Err(self.invalid("boolean")),
+        };
+    }
+
+    /// Converts the value to a string
+    fn to_text(&self) -> Result<Cow<'a, str>, Error> {
+        return match self {
+            Value::String(value) => Ok(value.clone()),
+            Value::Model(SerializationModel::Value(value)) => Ok(Cow::Borrowed(value.as_str())),
+            _ => Err(self.invalid("string")),
+        };
+    }
+
+    /// Checks if two values are equal, the type of the literal decides how the
+    /// other value is converted
+    ///
+    /// # Parameters
+    ///
+    /// other: The value to compare with
+    fn equals(&self, other: &Value<'a>) -> Result<bool, Error> {
+        return match (self, other) {
+            (Value::Number(_), _) | (_, Value::Number(_)) => {
+                Ok(self.to_number()? == other.to_number()?)
+            }
+            (Value::Boolean(_), _) | (_, Value::Boolean(_)) => {
+                Ok(self.to_boolean()? == other.to_boolean()?)
+            }
+            _ => Ok(self.to_text()? == other.to_text()?),
+        };
+    }
+
+    /// Creates the error for a value which has the wrong type
+    ///
+    /// # Parameters
+    ///
+    /// expected: The name of the expected type
+    fn invalid(&self, expected: &str) -> Error {
+        let value = match self {
+            Value::Model(value) => (*value).clone(),
+            Value::Number(value) => SerializationModel::Value(value.to_string()),
+            Value::String(value) => SerializationModel::Value(value.to_string()),
+            Value::Boolean(value) => SerializationModel::Value(value.to_string()),
+        };
+
+        return Error::InvalidValue(expected.to_string(), value);
+    }
+}
+
+/// Evaluates expressions for a single value
+struct Evaluator<'a> {
+    /// The value denoted x
+    value: &'a SerializationModel,
+}
+
+impl<'a> Evaluator<'a> {
+    /// Evaluates an expression
+    ///
+    /// # Parameters
+    ///
+    /// expression: The expression to evaluate
+    fn evaluate(&self, expression: &'a Expression) -> Result<Value<'a>, Error> {
+        return match expression {
+            Expression::Value => Ok(Value::Model(self.value)),
+            Expression::Field(value, name) => match self.evaluate(value)? {
+                Value::Model(SerializationModel::Map(fields)) => match fields.get(name) {
+                    Some(field) => Ok(Value::Model(field)),
+                    None => Err(Error::MissingField(name.clone())),
+                },
+                value => Err(value.invalid("map")),
+            },
+            Expression::Number(value) => match value.parse::<f64>() {
+                Ok(value) => Ok(Value::Number(value)),
+                Err(_) => Err(Value::String(Cow::Borrowed(value)).invalid("number")),
+            },
+            Expression::String(value) => Ok(Value::String(Cow::Borrowed(value))),
+            Expression::Boolean(value) => Ok(Value::Boolean(*value)),
+            Expression::Length(value) => {
+                let length = match self.evaluate(value)? {
+                    Value::Model(SerializationModel::Array(elements)) => elements.len(),
+                    Value::Model(SerializationModel::Map(entries)) => entries.len(),
+                    value => value.to_text()?.chars().count(),
+                };
+                Ok(Value::Number(length as f64))
+            }
+            Expression::Matches(value, pattern) => {
+                let value = self.evaluate(value)?.to_text()?;
+                match regex::Regex::new(pattern) {
+                    Ok(pattern) => Ok(Value::Boolean(pattern.is_match(&value))),
+                    Err(error) => Err(Error::InvalidPattern(pattern.clone(), error.to_string())),
+                }
+            }
+            Expression::In(value, set) => {
+                let value = self.evaluate(value)?;
+                for element in set.iter() {
+                    if value.equals(&self.evaluate(element)?)? {
+                        return Ok(Value::Boolean(true));
+                    }
+                }
+                Ok(Value::Boolean(false))
+            }
+            Expression::Not(value) => Ok(Value::Boolean(!self.evaluate(value)?.to_boolean()?)),
+            Expression::Negate(value) => Ok(Value::Number(-self.evaluate(value)?.to_number()?)),
+            Expression::Binary(lhs, operator, rhs) => self.evaluate_binary(lhs, *operator, rhs),
+        };
+    }
+
+    /// Evaluates a binary operator, logical operators are short circuited
+    ///
+    /// # Parameters
+    ///
+    /// lhs: The left operand
+    ///
+    /// operator: The operator
+    ///
+    /// rhs: The right operand
+    fn evaluate_binary(
+        &self,
+        lhs: &'a Expression,
+        operator: Operator,
+        rhs: &'a Expression,
+    ) -> Result<Value<'a>, Error> {
+        let lhs = self.evaluate(lhs)?;
+        match operator {
+            Operator::Or if lhs.to_boolean()? => return Ok(Value::Boolean(true)),
+            Operator::And if !lhs.to_boolean()? => return Ok(Value::Boolean(false)),
+            Operator::Or | Operator::And => {
+                return Ok(Value::Boolean(self.evaluate(rhs)?.to_boolean()?))
+            }
+            _ => (),
+        }
+
+        let rhs = self.evaluate(rhs)?;
+        return Ok(match operator {
+            Operator::Equal => Value::Boolean(lhs.equals(&rhs)?),
+            Operator::NotEqual => Value::Boolean(!lhs.equals(&rhs)?),
+            Operator::Less => Value::Boolean(lhs.to_number()? < rhs.to_number()?),
+            Operator::LessEqual => Value::Boolean(lhs.to_number()? <= rhs.to_number()?),
+            Operator::Greater => Value::Boolean(lhs.to_number()? > rhs.to_number()?),
+            Operator::GreaterEqual => Value::Boolean(lhs.to_number()? >= rhs.to_number()?),
+            Operator::Add => Value::Number(lhs.to_number()? + rhs.to_number()?),
+            Operator::Subtract => Value::Number(lhs.to_number()? - rhs.to_number()?),
+            Operator::Multiply => Value::Number(lhs.to_number()? * rhs.to_number()?),
+            Operator::Divide => Value::Number(lhs.to_number()? / rhs.to_number()?),
+            Operator::Remainder => Value::Number(lhs.to_number()? % rhs.to_number()?),
+            Operator::Or | Operator::And => unreachable!(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indexmap::IndexMap;
+
+    /// Shorthand for a scalar value
+    fn v(value: &str) -> SerializationModel {
+        return SerializationModel::Value(value.to_string());
+    }
+
+    /// Evaluates a constraint
+    fn eval(constraint: &str, value: &SerializationModel) -> Result<bool, Error> {
+        return Constraint::parse(constraint).unwrap().evaluate(value);
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(eval("x > 0 && x % 2 == 0", &v("4")), Ok(true));
+        assert_eq!(eval("x > 0 && x % 2 == 0", &v("3")), Ok(false));
+        assert_eq!(eval("-x % 2 == -1", &v("3")), Ok(true));
+        assert_eq!(eval("x / 2 == 1.5 || false", &v("3")), Ok(true));
+        assert_eq!(eval("x * 2 - 1 >= 1e1", &v("5.5")), Ok(true));
+        assert_eq!(eval("x in [1, -2]", &v("-2")), Ok(true));
+        assert_eq!(
+            eval("x > 0", &v("abc")),
+            Err(Error::InvalidValue("number".to_string(), v("abc")))
+        );
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(eval("len(x) == 3", &v("åäö")), Ok(true));
+        assert_eq!(eval("matches(x, \"^\\\\d+$\")", &v("123")), Ok(true));
+        assert_eq!(eval("matches(x, \"^\\\\d+$\")", &v("12a")), Ok(false));
+        assert_eq!(eval("x in [\"a\", \"b\"]", &v("b")), Ok(true));
+        assert_eq!(eval("x != \"a\"", &v("a")), Ok(false));
+    }
+
+    #[test]
+    fn structs() {
+        let value = SerializationModel::Map(IndexMap::from([
+            ("flag".to_string(), v("true")),
+            (
+                "names".to_string(),
+                SerializationModel::Array(vec![v("a"), v("b")]),
+            ),
+        ]));
+        assert_eq!(eval("x.flag && len(x.names) == 2", &value), Ok(true));
+        assert_eq!(eval("!x.flag || len(x) == 1", &value), Ok(false));
+        assert_eq!(
+            eval("x.size > 0", &value),
+            Err(Error::MissingField("size".to_string()))
+        );
+        // Short circuiting skips the missing field
+        assert_eq!(eval("x.flag || x.size > 0", &value), Ok(true));
+    }
+}
@@ -0,0 +1,399 @@
+//! The language neutral expression language used for the constraints of
+//! constrained types.
+//!
+//! A constraint is an expression which must evaluate to true for the
+//! constrained value, the value is denoted x. It supports:
+//!
+//! - Literals: numbers (1, 2.5, 1e-9), strings ("text" with the escapes \\",
+//!   \\\\, \\n, \\r and \\t) and booleans (true and false)
+//! - Field access for struct values: x.field or x.field.nested, only required
+//!   fields and fields with a default value can be used
+//! - Comparisons: ==, !=, <, <=, > and >=, ordering is only defined for numbers
+//! - Boolean operators: &&, || and !
+//! - Arithmetic on numbers: +, -, *, / and %, division is always done in
+//!   floating point and % is only allowed for integers and takes the sign of the
+//!   left side
+//! - len(x): The number of characters of a string, elements of an array or
+//!   entries of a map
+//! - matches(x, "pattern"): True if the regular expression matches any part of
+//!   the string, anchor the pattern with ^ and $ to match the entire string.
+//!   The pattern may only use the syntax shared by the regular expression
+//!   engines of all backends
+//! - Membership: x in [1, 2, 3], the set may only contain literals
+//!
+//! Constraints are parsed when the data model is loaded and type checked when
+//! it is validated, each backend then translates them into its own language.
+
+use crate::data_model::SerializationModel;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+
+mod evaluate;
+mod parser;
+mod pattern;
+mod types;
+
+pub(crate) use types::{Context, Kind};
+
+/// A single parsed constraint of a constrained type
+#[derive(Clone, Debug, PartialEq)]
+pub struct Constraint {
+    /// The text the constraint was parsed from
+    source: String,
+    /// The parsed expression
+    expression: Expression,
+}
+
+impl Constraint {
+    /// Parses a constraint
+    ///
+    /// # Parameters
+    ///
+    /// source: The text of the constraint
+    ///
+    /// # Errors
+    ///
+    /// If the text is not a valid expression
+    pub fn parse(source: &str) -> Result<Self, Error> {
+        return Ok(Self {
+            source: source.to_string(),
+            expression: parser::parse(source)?,
+        });
+    }
+
+    /// Gets the text the constraint was parsed from
+    pub fn source(&self) -> &str {
+        return &self.source;
+    }
+
+    /// Gets the parsed expression
+    pub fn expression(&self) -> &Expression {
+        return &self.expression;
+    }
+
+    /// Checks if a value fulfills the constraint, scalars are interpreted the
+    /// same way as when parsing the value into the constrained type such that
+    /// "5" is a number when compared to a number
+    ///
+    /// # Parameters
+    ///
+    /// value: The value to check
+    ///
+    /// # Errors
+    ///
+    /// If the value does not have the shape expected by the constraint
+    pub fn evaluate(&self, value: &SerializationModel) -> Result<bool, Error> {
+        return evaluate::evaluate(&self.expression, value);
+    }
+}
+
+impl FromStr for Constraint {
+    type Err = Error;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        return Self::parse(source);
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}", self.source);
+    }
+}
+
+impl Serialize for Constraint {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        return serializer.serialize_str(&self.source);
+    }
+}
+
+impl<'de> Deserialize<'de> for Constraint {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let source = String::deserialize(deserializer)?;
+        return Self::parse(&source).map_err(|error| {
+            serde::de::Error::custom(format!("Invalid constraint {source:?}: {error}"))
+        });
+    }
+}
+
+/// An expression of the constraint language
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expression {
+    /// The constrained value, x
+    Value,
+    /// A field of a struct value
+    Field(Box<Expression>, String),
+    /// A number literal without sign, kept as it was written
+    Number(String),
+    /// A string literal
+    String(String),
+    /// A boolean literal
+    Boolean(bool),
+    /// The length of a string, array or map, len(x)
+    Length(Box<Expression>),
+    /// Checks if a regular expression matches a string, matches(x, "pattern")
+    Matches(Box<Expression>, String),
+    /// Checks if the value is any of the literals, x in [1, 2]
+    In(Box<Expression>, Vec<Expression>),
+    /// Logical not, !x
+    Not(Box<Expression>),
+    /// Negation of a number, -x
+    Negate(Box<Expression>),
+    /// Any operator with two operands
+    Binary(Box<Expression>, Operator, Box<Expression>),
+}
+
+impl Expression {
+    /// Gets the precedence of the expression, operands with a lower
+    /// precedence than their operator must be put in parentheses
+    pub(crate) fn precedence(&self) -> u8 {
+        return match self {
+            Expression::Binary(_, operator, _) => operator.precedence(),
+            Expression::In(..) => Operator::Equal.precedence(),
+            Expression::Not(_) | Expression::Negate(_) => 6,
+            _ => 7,
+        };
+    }
+}
+
+impl fmt::Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Expression::Value => write!(f, "x"),
+            Expression::Field(value, name) => write!(f, "{value}.{name}"),
+            Expression::Number(value) => write!(f, "{value}"),
+            Expression::String(value) => write!(f, "{}", get_string_literal(value)),
+            Expression::Boolean(value) => write!(f, "{value}"),
+            Expression::Length(value) => write!(f, "len({value})"),
+            Expression::Matches(value, pattern) => {
+                write!(f, "matches({value}, {})", get_string_literal(pattern))
+            }
+            Expression::In(value, set) => write!(
+                f,
+                "{} in [{}]",
+                parenthesize(value.to_string(), value.precedence(), self.precedence(), true),
+                set.iter()
+                    .map(|element| element.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Expression::Not(value) => write!(
+                f,
+                "!{}",
+                parenthesize(value.to_string(), value.precedence(), self.precedence(), false)
+            ),
+            Expression::Negate(value) => write!(
+                f,
+                "-{}",
+                parenthesize(value.to_string(), value.precedence(), self.precedence(), false)
+            ),
+            Expression::Binary(lhs, operator, rhs) => write!(
+                f,
+                "{} {} {}",
+                parenthesize(
+                    lhs.to_string(),
+                    lhs.precedence(),
+                    operator.precedence(),
+                    operator.is_comparison()
+                ),
+                operator.symbol(),
+                parenthesize(rhs.to_string(), rhs.precedence(), operator.precedence(), true)
+            ),
+        };
+    }
+}
+
+/// All operators with two operands
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Operator {
+    /// Logical or, ||
+    Or,
+    /// Logical and, &&
+    And,
+    /// ==
+    Equal,
+    /// !=
+    NotEqual,
+    /// <
+    Less,
+    /// <=
+    LessEqual,
+    /// >
+    Greater,
+    /// >=
+    GreaterEqual,
+    /// +
+    Add,
+    /// -
+    Subtract,
+    /// *
+    Multiply,
+    /// /
+    Divide,
+    /// %
+    Remainder,
+}
+
+impl Operator {
+    /// Gets the symbol of the operator in the constraint language
+    pub fn symbol(&self) -> &'static str {
+        return match self {
+            Operator::Or => "||",
+            Operator::And => "&&",
+            Operator::Equal => "==",
+            Operator::NotEqual => "!=",
+            Operator::Less => "<",
+            Operator::LessEqual => "<=",
+            Operator::Greater => ">",
+            Operator::GreaterEqual => ">=",
+            Operator::Add => "+",
+            Operator::Subtract => "-",
+            Operator::Multiply => "*",
+            Operator::Divide => "/",
+            Operator::Remainder => "%",
+        };
+    }
+
+    /// Gets the precedence of the operator, all operators are left
+    /// associative except comparisons which cannot be chained
+    pub(crate) fn precedence(&self) -> u8 {
+        return match self {
+            Operator::Or => 1,
+            Operator::And => 2,
+            Operator::Equal
+            | Operator::NotEqual
+            | Operator::Less
+            | Operator::LessEqual
+            | Operator::Greater
+            | Operator::GreaterEqual => 3,
+            Operator::Add | Operator::Subtract => 4,
+            Operator::Multiply | Operator::Divide | Operator::Remainder => 5,
+        };
+    }
+
+    /// Checks if the operator is a comparison
+    pub(crate) fn is_comparison(&self) -> bool {
+        return self.precedence() == 3;
+    }
+}
+
+/// Puts the code of an operand in parentheses if it binds weaker than its
+/// operator
+///
+/// # Parameters
+///
+/// code: The code of the operand
+///
+/// precedence: The precedence of the operand
+///
+/// operator: The precedence of the operator
+///
+/// strict: If true then operands with the same precedence are also put in
+/// parentheses, used for right operands and comparisons
+pub(crate) fn parenthesize(code: String, precedence: u8, operator: u8, strict: bool) -> String {
+    return if precedence < operator || (strict && precedence == operator) {
+        format!("({code})")
+    } else {
+        code
+    };
+}
+
+/// Converts a string to a string literal of the constraint language
+///
+/// # Parameters
+///
+/// value: The string to convert
+fn get_string_literal(value: &str) -> String {
+    let escaped = value
+        .chars()
+        .map(|c| match c {
+            '"' => "\\\"".to_string(),
+            '\\' => "\\\\".to_string(),
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            '\t' => "\\t".to_string(),
+            _ => c.to_string(),
+        })
+        .collect::<String>();
+
+    return format!("\"{escaped}\"");
+}
+
+/// Errors when parsing, type checking or evaluating constraints
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum Error {
+    /// A character which is not part of the language
+    #[error("Unexpected character {:?} at position {:}", .0, .1)]
+    UnexpectedCharacter(char, usize),
+    /// A string literal is missing the closing quote
+    #[error("Unterminated string starting at position {:}", .0)]
+    UnterminatedString(usize),
+    /// A token which is not allowed where it is
+    #[error("Unexpected {:?} at position {:}", .0, .1)]
+    UnexpectedToken(String, usize),
+    /// The constraint ended before the expression was complete
+    #[error("Unexpected end of constraint")]
+    UnexpectedEnd,
+    /// A name which is not x, true or false
+    #[error("Unknown name {:?} at position {:}, the value must be denoted x", .0, .1)]
+    UnknownName(String, usize),
+    /// A function which does not exist
+    #[error("Unknown function {:?} at position {:}, only len and matches are supported", .0, .1)]
+    UnknownFunction(String, usize),
+    /// The regular expression of matches is not valid
+    #[error("Invalid regular expression {:?}: {:}", .0, .1)]
+    InvalidPattern(String, String),
+    /// The field does not exist in the struct
+    #[error("The struct {:} has no field {:?}", .0, .1)]
+    UnknownField(String, String),
+    /// The field is optional and may not have a value
+    #[error("The field {:?} is optional and cannot be used in constraints", .0)]
+    OptionalField(String),
+    /// An operation is used with a value of the wrong type
+    #[error("{:} cannot be used with {:} values", .0, .1)]
+    InvalidOperand(String, String),
+    /// Two values of different types are compared
+    #[error("The operator {:} cannot compare {:} values with {:} values", .0, .1, .2)]
+    Incompatible(String, String, String),
+    /// The entire constraint is not a boolean
+    #[error("The constraint must be a boolean but is a {:}", .0)]
+    NotBoolean(String),
+    /// A field does not exist in the value when evaluating
+    #[error("The value has no field {:?}", .0)]
+    MissingField(String),
+    /// A value does not have the type required by the operation when
+    /// evaluating
+    #[error("Expected a {:} but received {:?}", .0, .1)]
+    InvalidValue(String, SerializationModel),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        for source in [
+            "x > 0 && x < 10",
+            "!(x.name == \"a\\\"b\") || len(x) % 2 == 0",
+            "(x + 1) * 2 <= -x.size",
+            "x - (1 - 2) != 3",
+            "matches(x, \"^[a-z]+$\")",
+            "x in [1, 2.5, -3]",
+            "(x > 0) == true",
+        ] {
+            assert_eq!(Constraint::parse(source).unwrap().expression().to_string(), source);
+        }
+    }
+
+    #[test]
+    fn serde() {
+        let constraint = Constraint::parse("len(x) > 0").unwrap();
+        assert_eq!(serde_json::to_string(&constraint).unwrap(), "\"len(x) > 0\"");
+        assert_eq!(
+            serde_json::from_str::<Constraint>("\"len(x) > 0\"").unwrap(),
+            constraint
+        );
+        assert!(serde_json::from_str::<Constraint>("\"len(x) >\"").is_err());
+    }
+}
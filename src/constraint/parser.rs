@@ -0,0 +1,499 @@
+use super::*;
+
+/// Parses the text of a constraint into an expression
+///
+/// # Parameters
+///
+/// source: The text to parse
+pub(super) fn parse(source: &str) -> Result<Expression, Error> {
+    let mut parser = Parser {
+        tokens: tokenize(source)?,
+        position: 0,
+    };
+    let expression = parser.parse_or()?;
+
+    // The entire constraint must be used
+    return match parser.peek() {
+        Some(token) => Err(Error::UnexpectedToken(token.text.clone(), token.start)),
+        None => Ok(expression),
+    };
+}
+
+/// A single token of a constraint
+#[derive(Clone, Debug, PartialEq)]
+struct Token {
+    /// The kind of token
+    kind: TokenKind,
+    /// The text of the token, the unescaped content for strings
+    text: String,
+    /// The index of the first character of the token in the constraint
+    start: usize,
+}
+
+/// The kinds of tokens in a constraint
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TokenKind {
+    /// A name like x or len
+    Identifier,
+    /// A number literal without sign
+    Number,
+    /// A string literal
+    String,
+    /// An operator or punctuation
+    Symbol,
+}
+
+/// All symbols of the language, longer symbols must come first
+const SYMBOLS: [&str; 21] = [
+    "&&", "||", "==", "!=", "<=", ">=", "<", ">", "!", "+", "-", "*", "/", "%", "(", ")", "[",
+    "]", ",", ".", "=",
+];
+
+/// Splits a constraint into tokens
+///
+/// # Parameters
+///
+/// source: The constraint to split
+fn tokenize(source: &str) -> Result<Vec<Token>, Error> {
+    let chars = source.chars().collect::<Vec<char>>();
+    let mut tokens = vec![];
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let (kind, text) = if c.is_alphabetic() || c == '_' {
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            (TokenKind::Identifier, chars[start..i].iter().collect())
+        } else if c.is_ascii_digit() {
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+            if chars.get(i) == Some(&'.') && chars.get(i + 1).is_some_and(|c| c.is_ascii_digit()) {
+                i += 1;
+                while i < chars.len() && chars[i].is_ascii_digit() {
+                    i += 1;
+                }
+            }
+            if chars.get(i).is_some_and(|c| *c == 'e' || *c == 'E') {
+                let mut end = i + 1;
+                if chars.get(end).is_some_and(|c| *c == '+' || *c == '-') {
+                    end += 1;
+                }
+                if chars.get(end).is_some_and(|c| c.is_ascii_digit()) {
+                    i = end;
+                    while i < chars.len() && chars[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                }
+            }
+            (TokenKind::Number, chars[start..i].iter().collect())
+        } else if c == '"' {
+            i += 1;
+            let mut text = String::new();
+            loop {
+                match chars.get(i) {
+                    None => return Err(Error::UnterminatedString(start)),
+                    Some('"') => break,
+                    Some('\\') => {
+                        text.push(match chars.get(i + 1) {
+                            Some('"') => '"',
+                            Some('\\') => '\\',
+                            Some('n') => '\n',
+                            Some('r') => '\r',
+                            Some('t') => '\t',
+                            Some(c) => return Err(Error::UnexpectedCharacter(*c, i + 1)),
+                            None => return Err(Error::UnterminatedString(start)),
+                        });
+                        i += 2;
+                    }
+                    Some(c) => {
+                        text.push(*c);
+                        i += 1;
+                    }
+                }
+            }
+            i += 1;
+            (TokenKind::String, text)
+        } else {
+            match SYMBOLS.iter().find(|symbol| {
+                return symbol
+                    .chars()
+                    .enumerate()
+                    .all(|(j, symbol_char)| chars.get(i + j) == Some(&symbol_char));
+            }) {
+                // A single = is most likely a mistake for ==
+                Some(&"=") | None => return Err(Error::UnexpectedCharacter(c, start)),
+                Some(symbol) => {
+                    i += symbol.len();
+                    (TokenKind::Symbol, symbol.to_string())
+                }
+            }
+        };
+
+        tokens.push(Token { kind, text, start });
+    }
+
+    return Ok(tokens);
+}
+
+/// Parses a list of tokens using recursive descent, each level of precedence
+/// has its own function
+struct Parser {
+    /// All the tokens of the constraint
+    tokens: Vec<Token>,
+    /// The index of the next token
+    position: usize,
+}
+
+impl Parser {
+    /// Gets the next token without consuming it
+    fn peek(&self) -> Option<&Token> {
+        return self.tokens.get(self.position);
+    }
+
+    /// Consumes the next token
+    fn next(&mut self) -> Result<Token, Error> {
+        let token = self.peek().cloned().ok_or(Error::UnexpectedEnd)?;
+        self.position += 1;
+        return Ok(token);
+    }
+
+    /// Consumes the next token if it is the given symbol
+    ///
+    /// # Parameters
+    ///
+    /// symbol: The symbol to look for
+    fn accept(&mut self, symbol: &str) -> bool {
+        return match self.peek() {
+            Some(token) if token.kind == TokenKind::Symbol && token.text == symbol => {
+                self.position += 1;
+                true
+            }
+            _ => false,
+        };
+    }
+
+    /// Consumes the next token and makes sure it is the given symbol
+    ///
+    /// # Parameters
+    ///
+    /// symbol: The symbol which must be next
+    fn expect(&mut self, symbol: &str) -> Result<(), Error> {
+        let token = self.next()?;
+        return if token.kind == TokenKind::Symbol && token.text == symbol {
+            Ok(())
+        } else {
+            Err(Error::UnexpectedToken(token.text, token.start))
+        };
+    }
+
+    /// Parses a left associative chain of binary operators
+    ///
+    /// # Parameters
+    ///
+    /// operators: The operators of this level of precedence
+    ///
+    /// operand: The parser for the operands
+    fn parse_binary(
+        &mut self,
+        operators: &[Operator],
+        operand: fn(&mut Self) -> Result<Expression, Error>,
+    ) -> Result<Expression, Error> {
+        let mut expression = operand(self)?;
+        'outer: loop {
+            for operator in operators {
+                if self.accept(operator.symbol()) {
+                    expression = Expression::Binary(
+                        Box::new(expression),
+                        *operator,
+                        Box::new(operand(self)?),
+                    );
+                    continue 'outer;
+                }
+            }
+
+            return Ok(expression);
+        }
+    }
+
+    /// Parses a logical or
+    fn parse_or(&mut self) -> Result<Expression, Error> {
+        return self.parse_binary(&[Operator::Or], Self::parse_and);
+    }
+
+    /// Parses a logical and
+    fn parse_and(&mut self) -> Result<Expression, Error> {
+        return self.parse_binary(&[Operator::And], Self::parse_comparison);
+    }
+
+    /// Parses a single comparison or membership test, they cannot be chained
+    fn parse_comparison(&mut self) -> Result<Expression, Error> {
+        let lhs = self.parse_sum()?;
+
+        if let Some(token) = self.peek() {
+            if token.kind == TokenKind::Identifier && token.text == "in" {
+                self.position += 1;
+                return Ok(Expression::In(Box::new(lhs), self.parse_set()?));
+            }
+        }
+
+        for operator in [
+            Operator::Equal,
+            Operator::NotEqual,
+            Operator::LessEqual,
+            Operator::GreaterEqual,
+            Operator::Less,
+            Operator::Greater,
+        ] {
+            if self.accept(operator.symbol()) {
+                return Ok(Expression::Binary(
+                    Box::new(lhs),
+                    operator,
+                    Box::new(self.parse_sum()?),
+                ));
+            }
+        }
+
+        return Ok(lhs);
+    }
+
+    /// Parses an addition or subtraction
+    fn parse_sum(&mut self) -> Result<Expression, Error> {
+        return self.parse_binary(&[Operator::Add, Operator::Subtract], Self::parse_product);
+    }
+
+    /// Parses a multiplication, division or remainder
+    fn parse_product(&mut self) -> Result<Expression, Error> {
+        return self.parse_binary(
+            &[Operator::Multiply, Operator::Divide, Operator::Remainder],
+            Self::parse_unary,
+        );
+    }
+
+    /// Parses a logical not or a negation
+    fn parse_unary(&mut self) -> Result<Expression, Error> {
+        if self.accept("!") {
+            return Ok(Expression::Not(Box::new(self.parse_unary()?)));
+        }
+        if self.accept("-") {
+            return Ok(Expression::Negate(Box::new(self.parse_unary()?)));
+        }
+
+        return self.parse_field();
+    }
+
+    /// Parses any number of field accesses
+    fn parse_field(&mut self) -> Result<Expression, Error> {
+        let mut expression = self.parse_primary()?;
+        while self.accept(".") {
+            let token = self.next()?;
+            if token.kind != TokenKind::Identifier {
+                return Err(Error::UnexpectedToken(token.text, token.start));
+            }
+            expression = Expression::Field(Box::new(expression), token.text);
+        }
+
+        return Ok(expression);
+    }
+
+    /// Parses a literal, the value, a function call or an expression in
+    /// parentheses
+    fn parse_primary(&mut self) -> Result<Expression, Error> {
+        let token = self.next()?;
+        return match token.kind {
+            TokenKind::Number => Ok(Expression::Number(token.text)),
+            TokenKind::String => Ok(Expression::String(token.text)),
+            TokenKind::Identifier => match token.text.as_str() {
+                "x" => Ok(Expression::Value),
+                "true" => Ok(Expression::Boolean(true)),
+                "false" => Ok(Expression::Boolean(false)),
+                _ if self.accept("(") => self.parse_call(token),
+                _ => Err(Error::UnknownName(token.text, token.start)),
+            },
+            TokenKind::Symbol if token.text == "(" => {
+                let expression = self.parse_or()?;
+                self.expect(")")?;
+                Ok(expression)
+            }
+            TokenKind::Symbol => Err(Error::UnexpectedToken(token.text, token.start)),
+        };
+    }
+
+    /// Parses the arguments of a function call, the opening parenthesis is
+    /// already consumed
+    ///
+    /// # Parameters
+    ///
+    /// name: The token with the name of the function
+    fn parse_call(&mut self, name: Token) -> Result<Expression, Error> {
+        let expression = match name.text.as_str() {
+            "len" => Expression::Length(Box::new(self.parse_or()?)),
+            "matches" => {
+                let value = self.parse_or()?;
+                self.expect(",")?;
+
+                // The pattern must be known when loading the model
+                let pattern = self.next()?;
+                if pattern.kind != TokenKind::String {
+                    return Err(Error::UnexpectedToken(pattern.text, pattern.start));
+                }
+                pattern::check_pattern(&pattern.text)?;
+                if let Err(error) = regex::Regex::new(&pattern.text) {
+                    return Err(Error::InvalidPattern(pattern.text, error.to_string()));
+                }
+
+                Expression::Matches(Box::new(value), pattern.text)
+            }
+            _ => return Err(Error::UnknownFunction(name.text, name.start)),
+        };
+        self.expect(")")?;
+
+        return Ok(expression);
+    }
+
+    /// Parses the set of literals of a membership test
+    fn parse_set(&mut self) -> Result<Vec<Expression>, Error> {
+        self.expect("[")?;
+        let mut set = vec![];
+        loop {
+            let negate = self.accept("-");
+            let token = self.next()?;
+            let literal = match (token.kind, token.text.as_str()) {
+                (TokenKind::Number, _) => Expression::Number(token.text),
+                (TokenKind::String, _) if !negate => Expression::String(token.text),
+                (TokenKind::Identifier, "true") if !negate => Expression::Boolean(true),
+                (TokenKind::Identifier, "false") if !negate => Expression::Boolean(false),
+                _ => return Err(Error::UnexpectedToken(token.text, token.start)),
+            };
+            set.push(if negate {
+                Expression::Negate(Box::new(literal))
+            } else {
+                literal
+            });
+
+            if !self.accept(",") {
+                break;
+            }
+        }
+        self.expect("]")?;
+
+        return Ok(set);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Shorthand for boxing an expression
+    fn b(expression: Expression) -> Box<Expression> {
+        return Box::new(expression);
+    }
+
+    #[test]
+    fn precedence() {
+        assert_eq!(
+            parse("x > 1 || x < -1 && !x.flag").unwrap(),
+            Expression::Binary(
+                b(Expression::Binary(
+                    b(Expression::Value),
+                    Operator::Greater,
+                    b(Expression::Number("1".to_string()))
+                )),
+                Operator::Or,
+                b(Expression::Binary(
+                    b(Expression::Binary(
+                        b(Expression::Value),
+                        Operator::Less,
+                        b(Expression::Negate(b(Expression::Number("1".to_string()))))
+                    )),
+                    Operator::And,
+                    b(Expression::Not(b(Expression::Field(
+                        b(Expression::Value),
+                        "flag".to_string()
+                    ))))
+                ))
+            )
+        );
+        assert_eq!(
+            parse("1 - 2 - 3 * 4").unwrap(),
+            Expression::Binary(
+                b(Expression::Binary(
+                    b(Expression::Number("1".to_string())),
+                    Operator::Subtract,
+                    b(Expression::Number("2".to_string()))
+                )),
+                Operator::Subtract,
+                b(Expression::Binary(
+                    b(Expression::Number("3".to_string())),
+                    Operator::Multiply,
+                    b(Expression::Number("4".to_string()))
+                ))
+            )
+        );
+    }
+
+    #[test]
+    fn functions() {
+        assert_eq!(
+            parse("len(x.names) >= 2.5e3").unwrap(),
+            Expression::Binary(
+                b(Expression::Length(b(Expression::Field(
+                    b(Expression::Value),
+                    "names".to_string()
+                )))),
+                Operator::GreaterEqual,
+                b(Expression::Number("2.5e3".to_string()))
+            )
+        );
+        assert_eq!(
+            parse("matches(x, \"^\\\\d+\\\"$\")").unwrap(),
+            Expression::Matches(b(Expression::Value), "^\\d+\"$".to_string())
+        );
+        assert_eq!(
+            parse("x in [\"a\", -1, true]").unwrap(),
+            Expression::In(
+                b(Expression::Value),
+                vec![
+                    Expression::String("a".to_string()),
+                    Expression::Negate(b(Expression::Number("1".to_string()))),
+                    Expression::Boolean(true),
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(parse("x > "), Err(Error::UnexpectedEnd));
+        assert_eq!(parse("x = 1"), Err(Error::UnexpectedCharacter('=', 2)));
+        assert_eq!(parse("x > 1 x"), Err(Error::UnexpectedToken("x".to_string(), 6)));
+        assert_eq!(parse("x < 1 < 2"), Err(Error::UnexpectedToken("<".to_string(), 6)));
+        assert_eq!(parse("y > 0"), Err(Error::UnknownName("y".to_string(), 0)));
+        assert_eq!(
+            parse("std::abs(x) < 1"),
+            Err(Error::UnexpectedCharacter(':', 3))
+        );
+        assert_eq!(
+            parse("abs(x) < 1"),
+            Err(Error::UnknownFunction("abs".to_string(), 0))
+        );
+        assert_eq!(parse("x == \"abc"), Err(Error::UnterminatedString(5)));
+        assert!(matches!(
+            parse("matches(x, \"(\")"),
+            Err(Error::InvalidPattern(..))
+        ));
+        assert_eq!(
+            parse("x in [x]"),
+            Err(Error::UnexpectedToken("x".to_string(), 6))
+        );
+    }
+}
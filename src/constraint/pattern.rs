@@ -0,0 +1,322 @@
+use super::*;
+
+/// The characters which can be escaped to match them literally
+const ESCAPABLE: &str = "\\.^$|?*+()[]{}-/";
+
+/// The escapes of control characters
+const CONTROL_ESCAPES: &str = "nrtfv";
+
+/// The escapes of character classes
+const CLASS_ESCAPES: &str = "dDwWsS";
+
+/// Checks that a regular expression only uses the syntax shared by the
+/// regular expression engines of every backend (regex in Rust, std::regex in
+/// c++, re in Python and RegExp in TypeScript) such that it is read the same
+/// way everywhere
+///
+/// # Parameters
+///
+/// pattern: The regular expression to check
+pub(super) fn check_pattern(pattern: &str) -> Result<(), Error> {
+    let mut checker = PatternChecker {
+        chars: pattern.chars().collect(),
+        position: 0,
+    };
+
+    return checker
+        .check_alternatives(false)
+        .map_err(|message| Error::InvalidPattern(pattern.to_string(), message));
+}
+
+/// The kinds of escapes
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Escape {
+    /// A single character like \. or \n
+    Character,
+    /// A class of characters like \d
+    Class,
+    /// A word boundary, \b or \B
+    Boundary,
+}
+
+/// Walks through a regular expression to check it
+struct PatternChecker {
+    /// All the characters of the pattern
+    chars: Vec<char>,
+    /// The index of the next character
+    position: usize,
+}
+
+impl PatternChecker {
+    /// Gets the next character and moves past it
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.get(self.position).copied();
+        if c.is_some() {
+            self.position += 1;
+        }
+        return c;
+    }
+
+    /// Moves past the next character if it is the given character
+    ///
+    /// # Parameters
+    ///
+    /// c: The character to look for
+    fn accept(&mut self, c: char) -> bool {
+        if self.chars.get(self.position) == Some(&c) {
+            self.position += 1;
+            return true;
+        }
+        return false;
+    }
+
+    /// Moves past all digits and returns how many there were
+    fn skip_digits(&mut self) -> usize {
+        let start = self.position;
+        while self
+            .chars
+            .get(self.position)
+            .is_some_and(|c| c.is_ascii_digit())
+        {
+            self.position += 1;
+        }
+        return self.position - start;
+    }
+
+    /// Checks alternatives separated by | until the end of the pattern or the
+    /// end of the current group
+    ///
+    /// # Parameters
+    ///
+    /// in_group: True if a group is open and must be closed
+    fn check_alternatives(&mut self, in_group: bool) -> Result<(), String> {
+        // Quantifiers must follow something which can be repeated
+        let mut repeatable = false;
+        loop {
+            let start = self.position;
+            let c = match self.next() {
+                Some(c) => c,
+                None if in_group => return Err("A group is not closed".to_string()),
+                None => return Ok(()),
+            };
+
+            repeatable = match c {
+                ')' if in_group => return Ok(()),
+                '(' => {
+                    if self.accept('?') && !self.accept(':') {
+                        return Err(format!(
+                            "The group at position {start} is not supported, only (...) and (?:...) can be used"
+                        ));
+                    }
+                    self.check_alternatives(true)?;
+                    true
+                }
+                '[' => {
+                    self.check_brackets(start)?;
+                    true
+                }
+                '\\' => self.check_escape(start, false)? != Escape::Boundary,
+                '|' | '^' | '$' => false,
+                '*' | '+' | '?' | '{' => {
+                    if !repeatable {
+                        return Err(format!(
+                            "The quantifier at position {start} must follow a character, group or brackets"
+                        ));
+                    }
+                    if c == '{' {
+                        self.check_counts(start)?;
+                    }
+
+                    // Lazy quantifiers are allowed
+                    self.accept('?');
+                    false
+                }
+                ')' | ']' | '}' => {
+                    return Err(format!(
+                        "The {c:?} at position {start} must be escaped as \\{c}"
+                    ))
+                }
+                // A character which is not ASCII is more than one character
+                // for std::regex such that it cannot be repeated
+                c => c.is_ascii(),
+            };
+        }
+    }
+
+    /// Checks the content of brackets, the opening bracket is already
+    /// consumed
+    ///
+    /// # Parameters
+    ///
+    /// start: The position of the opening bracket
+    fn check_brackets(&mut self, start: usize) -> Result<(), String> {
+        self.accept('^');
+
+        // The first character cannot be ] since some engines read [] as an
+        // empty set
+        let mut first = true;
+        // True if the previous item is a single character which can start a
+        // range
+        let mut single = false;
+        // True if the previous item is a class like \d which cannot be part of
+        // a range
+        let mut class = false;
+        loop {
+            let position = self.position;
+            let c = match self.next() {
+                Some(c) => c,
+                None => return Err(format!("The brackets at position {start} are not closed")),
+            };
+
+            let previous_class = class;
+            class = false;
+            single = match c {
+                ']' if !first => return Ok(()),
+                '[' | ']' => {
+                    return Err(format!(
+                        "The {c:?} at position {position} must be escaped as \\{c}"
+                    ))
+                }
+                '&' | '~' | '-' if self.chars.get(self.position) == Some(&c) => {
+                    return Err(format!(
+                        "The {c:?} at position {position} must be escaped as \\{c} when it is followed by another {c:?}"
+                    ))
+                }
+                '-' if previous_class && self.chars.get(self.position) != Some(&']') => {
+                    return Err(format!(
+                        "The '-' at position {position} must be escaped as \\- after a class like \\d"
+                    ))
+                }
+                '-' if single && self.chars.get(self.position) != Some(&']') => {
+                    let end_position = self.position;
+                    let single_end = match self.next() {
+                        Some('\\') => self.check_escape(end_position, true)? == Escape::Character,
+                        Some('[') | None => false,
+                        Some(end) => end.is_ascii(),
+                    };
+                    if !single_end {
+                        return Err(format!(
+                            "The range at position {position} must end with a single ASCII character"
+                        ));
+                    }
+                    false
+                }
+                '\\' => {
+                    class = self.check_escape(position, true)? == Escape::Class;
+                    !class
+                }
+                c if !c.is_ascii() => {
+                    return Err(format!(
+                        "The character {c:?} at position {position} cannot be in brackets, only ASCII characters are supported"
+                    ))
+                }
+                _ => true,
+            };
+            first = false;
+        }
+    }
+
+    /// Checks an escape and returns its kind, the backslash is already
+    /// consumed
+    ///
+    /// # Parameters
+    ///
+    /// start: The position of the backslash
+    ///
+    /// in_brackets: True if the escape is inside brackets
+    fn check_escape(&mut self, start: usize, in_brackets: bool) -> Result<Escape, String> {
+        return match self.next() {
+            Some(c) if ESCAPABLE.contains(c) || CONTROL_ESCAPES.contains(c) => {
+                Ok(Escape::Character)
+            }
+            Some(c) if CLASS_ESCAPES.contains(c) => Ok(Escape::Class),
+            Some('b' | 'B') if !in_brackets => Ok(Escape::Boundary),
+            Some(c) => Err(format!(
+                "The escape \\{c} at position {start} is not supported by every backend"
+            )),
+            None => Err("The pattern ends with a \\".to_string()),
+        };
+    }
+
+    /// Checks the counts of a repetition like {2}, {2,} or {2,5}, the opening
+    /// brace is already consumed
+    ///
+    /// # Parameters
+    ///
+    /// start: The position of the opening brace
+    fn check_counts(&mut self, start: usize) -> Result<(), String> {
+        let valid = self.skip_digits() > 0;
+        if valid && self.accept(',') {
+            self.skip_digits();
+        }
+        if !valid || !self.accept('}') {
+            return Err(format!(
+                "The repetition at position {start} must be written as {{n}}, {{n,}} or {{n,m}}"
+            ));
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn portable() {
+        for pattern in [
+            "^[0-9]+(\\.[0-9]+){0,2}$",
+            "^[A-Z]{2}$",
+            "^\\d+\"$",
+            "a|b|",
+            "(?:ab)*?c+?d??",
+            "[^a-z_\\-\\]]",
+            "[-a]|[a-]",
+            "\\bword\\B",
+            "\\\\\\/\\{\\}\\(\\)\\[\\]\\|\\^\\$\\*\\+\\?\\.",
+            "\\w\\W\\s\\S\\D\\n\\r\\t\\f\\v",
+            "^héé$",
+            "x{3,}",
+        ] {
+            assert_eq!(check_pattern(pattern), Ok(()), "{pattern}");
+            assert!(regex::Regex::new(pattern).is_ok(), "{pattern}");
+        }
+    }
+
+    #[test]
+    fn not_portable() {
+        for (pattern, message) in [
+            ("(?i)abc", "The group at position 0 is not supported, only (...) and (?:...) can be used"),
+            ("(?P<name>a)", "The group at position 0 is not supported, only (...) and (?:...) can be used"),
+            ("a(?=b)", "The group at position 1 is not supported, only (...) and (?:...) can be used"),
+            ("\\p{L}", "The escape \\p at position 0 is not supported by every backend"),
+            ("(a)\\1", "The escape \\1 at position 3 is not supported by every backend"),
+            ("\\Aa\\z", "The escape \\A at position 0 is not supported by every backend"),
+            ("\\x41", "The escape \\x at position 0 is not supported by every backend"),
+            ("[[:alpha:]]", "The '[' at position 1 must be escaped as \\["),
+            ("[]a]", "The ']' at position 1 must be escaped as \\]"),
+            ("[a&&b]", "The '&' at position 2 must be escaped as \\& when it is followed by another '&'"),
+            ("[\\d-z]", "The '-' at position 3 must be escaped as \\- after a class like \\d"),
+            ("[a--b]", "The '-' at position 2 must be escaped as \\- when it is followed by another '-'"),
+            ("[a-\\d]", "The range at position 2 must end with a single ASCII character"),
+            ("[\\b]", "The escape \\b at position 1 is not supported by every backend"),
+            ("[é]", "The character 'é' at position 1 cannot be in brackets, only ASCII characters are supported"),
+            ("é+", "The quantifier at position 1 must follow a character, group or brackets"),
+            ("a**", "The quantifier at position 2 must follow a character, group or brackets"),
+            ("^*", "The quantifier at position 1 must follow a character, group or brackets"),
+            ("a{,2}", "The repetition at position 1 must be written as {n}, {n,} or {n,m}"),
+            ("a{2", "The repetition at position 1 must be written as {n}, {n,} or {n,m}"),
+            ("a}", "The '}' at position 1 must be escaped as \\}"),
+            ("(a", "A group is not closed"),
+            ("a)", "The ')' at position 1 must be escaped as \\)"),
+            ("[a", "The brackets at position 0 are not closed"),
+            ("a\\", "The pattern ends with a \\"),
+        ] {
+            assert_eq!(
+                check_pattern(pattern),
+                Err(Error::InvalidPattern(pattern.to_string(), message.to_string())),
+                "{pattern}"
+            );
+        }
+    }
+}
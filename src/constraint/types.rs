@@ -0,0 +1,462 @@
+use super::*;
+use crate::data_model::{DataType, DataTypeData, DefaultType, StructField, BUILTIN_TYPES};
+use std::collections::{HashMap, HashSet};
+
+/// The kind of value an expression evaluates to
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Kind {
+    /// A whole number
+    Integer,
+    /// A floating point number
+    Number,
+    /// A string
+    String,
+    /// A boolean
+    Boolean,
+    /// An array
+    Array,
+    /// A map
+    Map,
+    /// A struct with the given name
+    Struct(String),
+    /// A variant or an enum, these cannot be used in constraints
+    Other,
+    /// A type defined outside of the data model, it is not type checked
+    External,
+}
+
+impl Kind {
+    /// Checks if the kind is a number or an integer
+    pub(crate) fn is_numeric(&self) -> bool {
+        return matches!(self, Kind::Integer | Kind::Number);
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            Kind::Integer => write!(f, "integer"),
+            Kind::Number => write!(f, "number"),
+            Kind::String => write!(f, "string"),
+            Kind::Boolean => write!(f, "boolean"),
+            Kind::Array => write!(f, "array"),
+            Kind::Map => write!(f, "map"),
+            Kind::Struct(name) => write!(f, "struct {name}"),
+            Kind::Other => write!(f, "variant or enum"),
+            Kind::External => write!(f, "external type"),
+        };
+    }
+}
+
+/// The types needed to type check the constraints of a single constrained
+/// type, also used by the backends to find the types of sub expressions
+pub(crate) struct Context<'a> {
+    /// The type which is constrained, the type of x
+    data_type: &'a str,
+    /// All the custom types of the data model
+    custom_types: HashMap<&'a str, &'a DataType>,
+}
+
+impl<'a> Context<'a> {
+    /// Creates a new context
+    ///
+    /// # Parameters
+    ///
+    /// data_type: The type which is constrained
+    ///
+    /// data_types: All the custom types of the data model
+    pub(crate) fn new(
+        data_type: &'a str,
+        data_types: impl IntoIterator<Item = &'a DataType>,
+    ) -> Self {
+        return Self {
+            data_type,
+            custom_types: data_types
+                .into_iter()
+                .map(|data_type| (data_type.name.as_str(), data_type))
+                .collect(),
+        };
+    }
+
+    /// Type checks an entire constraint, it must be a boolean
+    ///
+    /// # Parameters
+    ///
+    /// constraint: The constraint to check
+    pub(crate) fn check(&self, constraint: &Constraint) -> Result<(), Error> {
+        return match self.check_expression(constraint.expression())? {
+            Kind::Boolean | Kind::External => Ok(()),
+            kind => Err(Error::NotBoolean(kind.to_string())),
+        };
+    }
+
+    /// Gets the kind of an expression which is known to be valid, expressions
+    /// which are not valid are assumed to be external
+    ///
+    /// # Parameters
+    ///
+    /// expression: The expression to get the kind of
+    pub(crate) fn get_kind(&self, expression: &Expression) -> Kind {
+        return self
+            .check_expression(expression)
+            .unwrap_or(Kind::External);
+    }
+
+    /// Gets the name of the type of the value or a field, returns None for any
+    /// other expression
+    ///
+    /// # Parameters
+    ///
+    /// expression: The value or field to get the type of
+    pub(crate) fn get_type_name(&self, expression: &Expression) -> Option<&'a str> {
+        return match expression {
+            Expression::Value => Some(self.data_type),
+            Expression::Field(value, name) => {
+                let data_type = self.get_unwrapped_type(self.get_type_name(value)?);
+                self.get_field(data_type, name)
+                    .ok()
+                    .map(|field| field.data_type.as_str())
+            }
+            _ => None,
+        };
+    }
+
+    /// Gets the number of constrained types which must be unwrapped to get to
+    /// the underlying type
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the type
+    pub(crate) fn get_constrained_depth(&self, name: &str) -> usize {
+        let mut depth = 0;
+        let mut data_type = name;
+        let mut visited = HashSet::new();
+        while let Some(DataTypeData::ConstrainedType(data)) =
+            self.get_data_type(data_type).map(|data_type| &data_type.data)
+        {
+            if !visited.insert(data_type) {
+                break;
+            }
+            depth += 1;
+            data_type = &data.data_type;
+        }
+
+        return depth;
+    }
+
+    /// Finds a custom type
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the type
+    fn get_data_type(&self, name: &str) -> Option<&'a DataType> {
+        return self.custom_types.get(name).copied();
+    }
+
+    /// Follows constrained types to the type they wrap
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the type
+    fn get_unwrapped_type<'b>(&self, name: &'b str) -> &'b str
+    where
+        'a: 'b,
+    {
+        let mut data_type = name;
+        for _ in 0..self.get_constrained_depth(name) {
+            if let Some(DataTypeData::ConstrainedType(data)) =
+                self.get_data_type(data_type).map(|data_type| &data_type.data)
+            {
+                data_type = &data.data_type;
+            }
+        }
+
+        return data_type;
+    }
+
+    /// Gets the kind of values of a type
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the type
+    fn get_type_kind(&self, name: &str) -> Kind {
+        let name = self.get_unwrapped_type(name);
+        return match name {
+            "integer" => Kind::Integer,
+            "number" => Kind::Number,
+            "string" => Kind::String,
+            "boolean" => Kind::Boolean,
+            _ => match self.get_data_type(name).map(|data_type| &data_type.data) {
+                Some(DataTypeData::Struct(_)) => Kind::Struct(name.to_string()),
+                Some(DataTypeData::Array(_)) => Kind::Array,
+                Some(DataTypeData::Map(_)) => Kind::Map,
+                Some(_) => Kind::Other,
+                None if BUILTIN_TYPES.contains(&name) => Kind::Other,
+                None => Kind::External,
+            },
+        };
+    }
+
+    /// Finds a field of a struct or any of the structs it inherits from
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the struct
+    ///
+    /// field: The name of the field
+    fn get_field(&self, name: &str, field: &str) -> Result<&'a StructField, Error> {
+        let mut data_type = name;
+        let mut visited = HashSet::new();
+        while let Some(DataTypeData::Struct(data)) =
+            self.get_data_type(data_type).map(|data_type| &data_type.data)
+        {
+            if let Some(result) = data.fields.iter().find(|result| result.name == field) {
+                return Ok(result);
+            }
+            match &data.inherit {
+                Some(inherit) if visited.insert(data_type) => data_type = inherit,
+                _ => break,
+            }
+        }
+
+        return Err(Error::UnknownField(name.to_string(), field.to_string()));
+    }
+
+    /// Type checks an expression and gets the kind of value it evaluates to
+    ///
+    /// # Parameters
+    ///
+    /// expression: The expression to check
+    fn check_expression(&self, expression: &Expression) -> Result<Kind, Error> {
+        return match expression {
+            Expression::Value => Ok(self.get_type_kind(self.data_type)),
+            Expression::Field(value, name) => match self.check_expression(value)? {
+                Kind::Struct(data_type) => {
+                    let field = self.get_field(&data_type, name)?;
+                    if let DefaultType::Optional = field.default {
+                        return Err(Error::OptionalField(name.clone()));
+                    }
+                    Ok(self.get_type_kind(&field.data_type))
+                }
+                Kind::External => Ok(Kind::External),
+                kind => Err(Error::InvalidOperand(
+                    "Field access".to_string(),
+                    kind.to_string(),
+                )),
+            },
+            Expression::Number(value) => Ok(if value.chars().all(|c| c.is_ascii_digit()) {
+                Kind::Integer
+            } else {
+                Kind::Number
+            }),
+            Expression::String(_) => Ok(Kind::String),
+            Expression::Boolean(_) => Ok(Kind::Boolean),
+            Expression::Length(value) => match self.check_expression(value)? {
+                Kind::String | Kind::Array | Kind::Map => Ok(Kind::Integer),
+                Kind::External => Ok(Kind::External),
+                kind => Err(Error::InvalidOperand("len".to_string(), kind.to_string())),
+            },
+            Expression::Matches(value, _) => match self.check_expression(value)? {
+                Kind::String | Kind::External => Ok(Kind::Boolean),
+                kind => Err(Error::InvalidOperand(
+                    "matches".to_string(),
+                    kind.to_string(),
+                )),
+            },
+            Expression::In(value, set) => {
+                let kind = self.check_expression(value)?;
+                for element in set.iter() {
+                    let element = self.check_expression(element)?;
+                    if !is_comparable(&kind, &element) {
+                        return Err(Error::Incompatible(
+                            "in".to_string(),
+                            kind.to_string(),
+                            element.to_string(),
+                        ));
+                    }
+                }
+                Ok(Kind::Boolean)
+            }
+            Expression::Not(value) => match self.check_expression(value)? {
+                Kind::Boolean | Kind::External => Ok(Kind::Boolean),
+                kind => Err(Error::InvalidOperand(
+                    "The operator !".to_string(),
+                    kind.to_string(),
+                )),
+            },
+            Expression::Negate(value) => match self.check_expression(value)? {
+                kind if kind.is_numeric() || kind == Kind::External => Ok(kind),
+                kind => Err(Error::InvalidOperand(
+                    "The operator -".to_string(),
+                    kind.to_string(),
+                )),
+            },
+            Expression::Binary(lhs, operator, rhs) => {
+                let lhs = self.check_expression(lhs)?;
+                let rhs = self.check_expression(rhs)?;
+                check_binary(*operator, lhs, rhs)
+            }
+        };
+    }
+}
+
+/// Checks if two kinds of values can be compared for equality
+///
+/// # Parameters
+///
+/// lhs: The kind of the left operand
+///
+/// rhs: The kind of the right operand
+fn is_comparable(lhs: &Kind, rhs: &Kind) -> bool {
+    return *lhs == Kind::External
+        || *rhs == Kind::External
+        || (lhs.is_numeric() && rhs.is_numeric())
+        || (*lhs == Kind::String && *rhs == Kind::String)
+        || (*lhs == Kind::Boolean && *rhs == Kind::Boolean);
+}
+
+/// Type checks a binary operator and gets the kind of the result
+///
+/// # Parameters
+///
+/// operator: The operator
+///
+/// lhs: The kind of the left operand
+///
+/// rhs: The kind of the right operand
+fn check_binary(operator: Operator, lhs: Kind, rhs: Kind) -> Result<Kind, Error> {
+    // Find the operand which is not allowed
+    let allowed = |kind: &Kind| -> bool {
+        return *kind == Kind::External
+            || match operator {
+                Operator::Or | Operator::And => *kind == Kind::Boolean,
+                Operator::Equal | Operator::NotEqual => true,
+                Operator::Remainder => *kind == Kind::Integer,
+                _ => kind.is_numeric(),
+            };
+    };
+    for kind in [&lhs, &rhs] {
+        if !allowed(kind) {
+            return Err(Error::InvalidOperand(
+                format!("The operator {}", operator.symbol()),
+                kind.to_string(),
+            ));
+        }
+    }
+    if !is_comparable(&lhs, &rhs) {
+        return Err(Error::Incompatible(
+            operator.symbol().to_string(),
+            lhs.to_string(),
+            rhs.to_string(),
+        ));
+    }
+
+    return Ok(match operator {
+        _ if operator.precedence() <= Operator::Equal.precedence() => Kind::Boolean,
+        _ if lhs == Kind::External || rhs == Kind::External => Kind::External,
+        Operator::Divide => Kind::Number,
+        _ if lhs == Kind::Integer && rhs == Kind::Integer => Kind::Integer,
+        _ => Kind::Number,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Type checks a constraint of a type in a small data model
+    fn check(data_type: &str, constraint: &str) -> Result<(), Error> {
+        let model = crate::DataModel::import_yaml(indoc::indoc! {"
+            data_types:
+            - name: Positive
+              data: !ConstrainedType
+                data_type: integer
+                constraints: []
+            - name: Base
+              data: !Struct
+                fields:
+                - name: name
+                  data_type: string
+                  default: Required
+            - name: Point
+              data: !Struct
+                inherit: Base
+                fields:
+                - name: x
+                  data_type: Positive
+                  default: Required
+                - name: label
+                  data_type: string
+                  default: Optional
+            - name: Names
+              data: !Array
+                data_type: string
+            - name: Choice
+              data: !Variant
+                data_types: [integer, string]
+        "})
+        .unwrap();
+
+        return Context::new(data_type, &model.data_types).check(&constraint.parse().unwrap());
+    }
+
+    #[test]
+    fn valid() {
+        assert_eq!(check("Positive", "x % 2 == 0 && x / 2 < 1.5"), Ok(()));
+        assert_eq!(check("Point", "x.x > 0 && len(x.name) in [1, 2]"), Ok(()));
+        assert_eq!(check("Names", "len(x) > 0"), Ok(()));
+        assert_eq!(check("string", "matches(x, \"^a\") || x == \"b\""), Ok(()));
+        assert_eq!(check("boolean", "!x"), Ok(()));
+        assert_eq!(check("int", "x.value > 0"), Ok(()));
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            check("Point", "x.label == \"a\""),
+            Err(Error::OptionalField("label".to_string()))
+        );
+        assert_eq!(
+            check("Point", "x.y > 0"),
+            Err(Error::UnknownField("Point".to_string(), "y".to_string()))
+        );
+        assert_eq!(
+            check("number", "x % 2 == 0"),
+            Err(Error::InvalidOperand(
+                "The operator %".to_string(),
+                "number".to_string()
+            ))
+        );
+        assert_eq!(
+            check("string", "x > \"a\""),
+            Err(Error::InvalidOperand(
+                "The operator >".to_string(),
+                "string".to_string()
+            ))
+        );
+        assert_eq!(
+            check("integer", "x == \"a\""),
+            Err(Error::Incompatible(
+                "==".to_string(),
+                "integer".to_string(),
+                "string".to_string()
+            ))
+        );
+        assert_eq!(
+            check("Positive", "len(x) > 0"),
+            Err(Error::InvalidOperand("len".to_string(), "integer".to_string()))
+        );
+        assert_eq!(
+            check("Choice", "x == 1"),
+            Err(Error::Incompatible(
+                "==".to_string(),
+                "variant or enum".to_string(),
+                "integer".to_string()
+            ))
+        );
+        assert_eq!(
+            check("integer", "x + 1"),
+            Err(Error::NotBoolean("integer".to_string()))
+        );
+    }
+}
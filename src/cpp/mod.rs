@@ -8,26 +8,30 @@
 //! it can be included as "#include <termite.hpp>"
 //!
 
+use indexmap::IndexMap;
 use indoc::formatdoc;
 use std::{
     char::ToLowercase,
     collections::{HashMap, HashSet},
     fmt,
+    path::{Path, PathBuf},
 };
 
 mod type_array;
 mod type_constrained;
 mod type_enum;
+mod type_map;
 mod type_struct;
 mod type_variant;
 
 use type_array::Array;
 use type_constrained::ConstrainedType;
 use type_enum::Enum;
+use type_map::Map;
 use type_struct::Struct;
 use type_variant::Variant;
 
-use crate::data_model;
+use crate::{data_model, LocatedError, Location};
 
 /// Iterator to convert an iterator of chars to snake case converting all
 /// uppercase characters to an underscore and the lowercase character
@@ -113,6 +117,8 @@ pub fn get_json_interface() -> (&'static str, &'static str) {
 pub struct DataModel {
     /// List of the the data types to implement
     data_types: Vec<DataType>,
+    /// The names of the headers of all the directly imported data models
+    imports: Vec<String>,
     /// List of all header data used to include external packages
     headers: Headers,
     /// List of all footer data
@@ -120,7 +126,7 @@ pub struct DataModel {
     /// The nested namespace to put the data model into
     namespace: Vec<String>,
     /// A map of all macros to expand default values
-    macros: HashMap<String, data_model::SerializationModel>,
+    macros: IndexMap<String, data_model::SerializationModel>,
 }
 
 impl DataModel {
@@ -130,17 +136,80 @@ impl DataModel {
     ///
     /// data: The generic data type to convert
     pub fn new(data: crate::DataModel) -> Result<Self, Error> {
-        let data_types = data
-            .data_types
-            .into_iter()
+        // Make sure the data model is valid, types which are not defined in the
+        // data model may be c++ types included through the headers
+        if let Err(errors) = data.validate_types(true) {
+            return Err(Error {
+                location: Location::default(),
+                error: ErrorCore::Validation(errors),
+            });
+        }
+
+        // Imported data types are defined in the headers of the imported data
+        // models, they are referenced with their namespace if it is different
+        let imported_models = data.get_imported_models();
+        let local_namespace = &data.namespace;
+        let qualified_names = imported_models
+            .iter()
+            .flat_map(|resolved| {
+                let namespace = &resolved.data_model.namespace;
+                return resolved.data_model.data_types.iter().map(move |data_type| {
+                    let qualified_name = if namespace == local_namespace {
+                        data_type.name.clone()
+                    } else {
+                        format!(
+                            "::{}{}",
+                            namespace
+                                .iter()
+                                .map(|part| format!("{part}::"))
+                                .collect::<String>(),
+                            data_type.name
+                        )
+                    };
+                    return (data_type.name.clone(), qualified_name);
+                });
+            })
+            .collect::<HashMap<String, String>>();
+        let all_data_types = data
+            .get_all_data_types()
+            .iter()
+            .map(|data_type| qualify_references(data_type, &qualified_names))
+            .collect::<Vec<_>>();
+
+        // Only the data types of this data model are generated
+        let mut data_types = all_data_types[all_data_types.len() - data.data_types.len()..]
+            .iter()
             .enumerate()
             .map(|(i, data_type)| {
-                return match DataType::new(data_type) {
+                return match DataType::new(data_type.clone(), &all_data_types) {
                     Ok(result) => Ok(result),
-                    Err(error) => Err(error.add_element("data_types", i)),
+                    Err(error) => Err(error.add_element(i).add_field("data_types")),
                 };
             })
             .collect::<Result<Vec<DataType>, Error>>()?;
+
+        // Store all references to types which depend on the referencing type
+        // in boxes to allow recursive types
+        let reachable_types = get_reachable_types(&data_types);
+        for data_type in data_types.iter_mut() {
+            let recursive_types = reachable_types
+                .iter()
+                .filter(|(_, reachable)| reachable.contains(data_type.name.as_str()))
+                .map(|(name, _)| name.as_str())
+                .collect::<HashSet<&str>>();
+            data_type.data.box_references(&recursive_types);
+        }
+
+        // The headers of the imported data models are included, they must be
+        // generated with the default name
+        check_import_names(&data, &mut HashMap::new())?;
+        let imports = data
+            .imports
+            .iter()
+            .map(|import| get_file_name(Path::new(&import.path)))
+            .collect();
+        let macros = data.get_macros();
+
         let headers = match Headers::new(data.headers) {
             Ok(result) => result,
             Err(error) => return Err(error.add_field("headers")),
@@ -152,10 +221,11 @@ impl DataModel {
 
         return Ok(Self {
             data_types,
+            imports,
             headers,
             footers,
             namespace: data.namespace,
-            macros: data.macros,
+            macros,
         });
     }
 
@@ -180,22 +250,54 @@ impl DataModel {
             format!("}} // namespace {namespace}")
         };
 
+        // Sort the data types such that all dependencies are defined first
+        let sorted_data_types = self.get_sorted_data_types()?;
+
+        // Get the forward declarations of all types used before they are defined
+        let mut defined_types = HashSet::new();
+        let mut declared_types = HashSet::new();
+        let mut forward_declarations = vec![];
+        for data_type in sorted_data_types.iter() {
+            defined_types.insert(data_type.name.as_str());
+            for dependency in data_type.data.get_references() {
+                if !defined_types.contains(dependency) && declared_types.insert(dependency) {
+                    if let Some(dependency) = self
+                        .data_types
+                        .iter()
+                        .find(|data_type| data_type.name == dependency)
+                    {
+                        forward_declarations.push(dependency.get_forward_declaration());
+                    }
+                }
+            }
+        }
+        let forward_declarations = if forward_declarations.is_empty() {
+            "".to_string()
+        } else {
+            format!("{}\n\n", forward_declarations.join("\n"))
+        };
+
         // Get all structs
-        let data_types = self
-            .data_types
+        let data_types = sorted_data_types
             .iter()
             .map(|data_type| data_type.get_definition_header(indent))
             .collect::<Vec<String>>()
             .join("\n\n");
 
         // Get all parsers
-        let parsers = self
-            .data_types
+        let parsers = sorted_data_types
             .iter()
             .map(|data_type| data_type.get_parser_header(&self.namespace))
             .collect::<Vec<String>>()
             .join("\n\n");
 
+        // Include the imported data models
+        let includes = self
+            .imports
+            .iter()
+            .map(|import| format!("\n#include \"{import}.h\""))
+            .collect::<String>();
+
         // Expand macros in the header and footer
         let header = match data_model::expand_macros(
             &data_model::SerializationModel::Value(self.headers.header.clone()),
@@ -205,7 +307,7 @@ impl DataModel {
             data_model::SerializationModel::Value(value) => value,
             _ => {
                 return Err(Error {
-                    location: "".to_string(),
+                    location: Location::default(),
                     error: ErrorCore::HeaderMacro(self.headers.header.clone()),
                 })
             }
@@ -218,7 +320,7 @@ impl DataModel {
             data_model::SerializationModel::Value(value) => value,
             _ => {
                 return Err(Error {
-                    location: "".to_string(),
+                    location: Location::default(),
                     error: ErrorCore::FooterMacro(self.footers.header.clone()),
                 })
             }
@@ -235,13 +337,13 @@ impl DataModel {
             #include <optional>
             #include <variant>
             #include <algorithm>
-            #include <termite.hpp>
+            #include <termite.hpp>{includes}
 
             {header}
 
             {namespace_begin}
 
-            {data_types}
+            {forward_declarations}{data_types}
 
             {namespace_end}
 
@@ -279,22 +381,45 @@ impl DataModel {
             format!("}} // namespace {namespace}")
         };
 
-        // Get all structs
-        let data_types = self
-            .data_types
+        // Sort the data types such that all dependencies are defined first
+        let sorted_data_types = self.get_sorted_data_types()?;
+
+        // Get all structs, errors are located at the data type in the data
+        // model
+        let data_types = sorted_data_types
             .iter()
-            .map(|data_type| data_type.get_definition_source(&self.macros, indent))
+            .map(|data_type| {
+                return data_type
+                    .get_definition_source(&self.macros, indent)
+                    .map_err(|error| {
+                        let index = self
+                            .data_types
+                            .iter()
+                            .position(|other| other.name == data_type.name)
+                            .unwrap_or_default();
+                        return error.add_field("data").add_element(index).add_field("data_types");
+                    });
+            })
             .collect::<Result<Vec<_>, _>>()?
             .join("\n\n");
 
         // Get all parsers
-        let parsers = self
-            .data_types
+        let parsers = sorted_data_types
             .iter()
             .map(|data_type| data_type.get_parser_source(indent, &self.namespace, &self.data_types))
             .collect::<Vec<String>>()
             .join("\n\n");
 
+        // Constraints using regular expressions need the standard library
+        let includes = if self.data_types.iter().any(|data_type| match &data_type.data {
+            DataTypeData::ConstrainedType(data) => data.uses_regex(),
+            _ => false,
+        }) {
+            "\n#include <regex>"
+        } else {
+            ""
+        };
+
         // Expand macros in the header and footer
         let header = match data_model::expand_macros(
             &data_model::SerializationModel::Value(self.headers.source.clone()),
@@ -304,7 +429,7 @@ impl DataModel {
             data_model::SerializationModel::Value(value) => value,
             _ => {
                 return Err(Error {
-                    location: "".to_string(),
+                    location: Location::default(),
                     error: ErrorCore::HeaderMacro(self.headers.source.clone()),
                 })
             }
@@ -317,7 +442,7 @@ impl DataModel {
             data_model::SerializationModel::Value(value) => value,
             _ => {
                 return Err(Error {
-                    location: "".to_string(),
+                    location: Location::default(),
                     error: ErrorCore::FooterMacro(self.footers.source.clone()),
                 })
             }
@@ -325,7 +450,7 @@ impl DataModel {
 
         return Ok(formatdoc!("
             // Generated with the Termite Data Model Generator
-            #include \"{name}.h\"
+            #include \"{name}.h\"{includes}
 
             {header}
 
@@ -379,6 +504,213 @@ impl DataModel {
             "",
         ));
     }
+
+    /// Sorts the data types such that every data type comes after all the
+    /// data types it depends on, the original order is kept as much as
+    /// possible
+    ///
+    /// # Errors
+    ///
+    /// If some data types depend on each other in a cycle
+    fn get_sorted_data_types(&self) -> Result<Vec<&DataType>, Error> {
+        let mut sorted = vec![];
+        let mut visited = HashSet::new();
+        for data_type in self.data_types.iter() {
+            self.sort_data_type(data_type, &mut sorted, &mut visited, &mut vec![])?;
+        }
+
+        return Ok(sorted);
+    }
+
+    /// Adds a data type to the sorted list of data types after adding all its
+    /// dependencies
+    ///
+    /// # Parameters
+    ///
+    /// data_type: The data type to add
+    ///
+    /// sorted: The list of sorted data types
+    ///
+    /// visited: The names of all data types already added to sorted
+    ///
+    /// stack: The names of all data types currently being added, used to
+    /// detect cycles
+    fn sort_data_type<'a>(
+        &'a self,
+        data_type: &'a DataType,
+        sorted: &mut Vec<&'a DataType>,
+        visited: &mut HashSet<&'a str>,
+        stack: &mut Vec<&'a str>,
+    ) -> Result<(), Error> {
+        // Skip if it has already been added
+        if visited.contains(data_type.name.as_str()) {
+            return Ok(());
+        }
+
+        // Make sure there is no cycle
+        if let Some(index) = stack.iter().position(|name| *name == data_type.name) {
+            let mut cycle = stack[index..]
+                .iter()
+                .map(|name| name.to_string())
+                .collect::<Vec<_>>();
+            cycle.push(data_type.name.clone());
+            return Err(Error {
+                location: Location::default(),
+                error: ErrorCore::CyclicDependency(cycle),
+            });
+        }
+
+        // Add all dependencies
+        stack.push(&data_type.name);
+        for dependency in data_type.data.get_dependencies() {
+            if let Some(dependency) = self
+                .data_types
+                .iter()
+                .find(|data_type| data_type.name == dependency)
+            {
+                self.sort_data_type(dependency, sorted, visited, stack)?;
+            }
+        }
+        stack.pop();
+
+        // Add the data type
+        visited.insert(&data_type.name);
+        sorted.push(data_type);
+
+        return Ok(());
+    }
+}
+
+/// Gets the default name of the c++ files of a data model, the headers of
+/// imported data models are included with this name
+///
+/// # Parameters
+///
+/// path: The path of the data model file
+pub(crate) fn get_file_name(path: &Path) -> String {
+    return match path.file_stem() {
+        Some(stem) => stem.to_string_lossy().to_string(),
+        None => "model".to_string(),
+    };
+}
+
+/// Makes sure the data models imported directly or indirectly get c++ files
+/// with different names, data models like a/common.yaml and b/common.yaml
+/// would otherwise overwrite the files of each other
+///
+/// # Parameters
+///
+/// data: The data model to check the imports of
+///
+/// names: The file of the data model using each name, names which are
+/// already in it are reserved like the name of the importing data model
+pub(crate) fn check_import_names(
+    data: &crate::DataModel,
+    names: &mut HashMap<String, PathBuf>,
+) -> Result<(), Error> {
+    for (i, import) in data.imports.iter().enumerate() {
+        let Some(resolved) = &import.resolved else {
+            continue;
+        };
+        let name = get_file_name(&resolved.file);
+        match names.get(&name) {
+            // The same data model may be imported multiple times
+            Some(file) if *file == resolved.file => continue,
+            Some(_) => {
+                return Err(Error {
+                    location: Location::default(),
+                    error: ErrorCore::DuplicateFileName(import.path.clone(), name),
+                }
+                .add_element(i)
+                .add_field("imports"));
+            }
+            None => {
+                names.insert(name, resolved.file.clone());
+            }
+        }
+
+        if let Err(error) = check_import_names(&resolved.data_model, names) {
+            return Err(error.add_element(i).add_field("imports"));
+        }
+    }
+
+    return Ok(());
+}
+
+/// Replaces the name of a data type and all its references to other data
+/// types with the namespace qualified names of imported data types
+///
+/// # Parameters
+///
+/// data_type: The data type to qualify
+///
+/// qualified_names: The qualified name of every imported data type
+fn qualify_references(
+    data_type: &crate::DataType,
+    qualified_names: &HashMap<String, String>,
+) -> crate::DataType {
+    let qualify = |name: &String| match qualified_names.get(name) {
+        Some(qualified_name) => qualified_name.clone(),
+        None => name.clone(),
+    };
+
+    let mut data_type = data_type.clone();
+    data_type.name = qualify(&data_type.name);
+    match &mut data_type.data {
+        crate::DataTypeData::Struct(data) => {
+            data.inherit = data.inherit.as_ref().map(qualify);
+            for field in data.fields.iter_mut() {
+                field.data_type = qualify(&field.data_type);
+            }
+        }
+        crate::DataTypeData::Array(data) => data.data_type = qualify(&data.data_type),
+        crate::DataTypeData::Map(data) => {
+            data.key_type = data.key_type.as_ref().map(qualify);
+            data.data_type = qualify(&data.data_type);
+        }
+        crate::DataTypeData::Variant(data) => {
+            for variant_type in data.data_types.iter_mut() {
+                *variant_type = qualify(variant_type);
+            }
+        }
+        crate::DataTypeData::Enum(data) => {
+            for enum_type in data.types.iter_mut() {
+                enum_type.data_type = enum_type.data_type.as_ref().map(qualify);
+            }
+        }
+        crate::DataTypeData::ConstrainedType(data) => data.data_type = qualify(&data.data_type),
+    }
+
+    return data_type;
+}
+
+/// Finds all the data types which each data type depends on directly or
+/// indirectly, a data type only reaches itself if it is recursive
+///
+/// # Parameters
+///
+/// data_types: All the data types of the data model
+fn get_reachable_types(data_types: &[DataType]) -> HashMap<String, HashSet<String>> {
+    return data_types
+        .iter()
+        .map(|data_type| {
+            let mut reachable = HashSet::new();
+            let mut queue = data_type.data.get_dependencies();
+            while let Some(dependency) = queue.pop() {
+                if !reachable.insert(dependency.to_string()) {
+                    continue;
+                }
+                if let Some(dependency) = data_types
+                    .iter()
+                    .find(|data_type| data_type.name == dependency)
+                {
+                    queue.extend(dependency.data.get_dependencies());
+                }
+            }
+
+            return (data_type.name.clone(), reachable);
+        })
+        .collect();
 }
 
 /// All of the headers for the different files
@@ -396,12 +728,12 @@ impl Headers {
     /// # Parameters
     ///
     /// data: The generic data type to convert
-    fn new(mut data: HashMap<String, String>) -> Result<Self, Error> {
-        let source = match data.remove("cpp-source") {
+    fn new(mut data: IndexMap<String, String>) -> Result<Self, Error> {
+        let source = match data.shift_remove("cpp-source") {
             Some(value) => value,
             None => String::new(),
         };
-        let header = match data.remove("cpp-header") {
+        let header = match data.shift_remove("cpp-header") {
             Some(value) => value,
             None => String::new(),
         };
@@ -425,12 +757,12 @@ impl Footers {
     /// # Parameters
     ///
     /// data: The generic data type to convert
-    fn new(mut data: HashMap<String, String>) -> Result<Self, Error> {
-        let source = match data.remove("cpp-source") {
+    fn new(mut data: IndexMap<String, String>) -> Result<Self, Error> {
+        let source = match data.shift_remove("cpp-source") {
             Some(value) => value,
             None => String::new(),
         };
-        let header = match data.remove("cpp-header") {
+        let header = match data.shift_remove("cpp-header") {
             Some(value) => value,
             None => String::new(),
         };
@@ -456,11 +788,14 @@ impl DataType {
     /// # Parameters
     ///
     /// data: The generic data type to convert
-    fn new(data: crate::DataType) -> Result<Self, Error> {
+    ///
+    /// data_types: All the generic data types of the data model, used to
+    /// resolve references to other types
+    fn new(data: crate::DataType, data_types: &[crate::DataType]) -> Result<Self, Error> {
         // Convert the data
-        let processed_data = match DataTypeData::new(data.data) {
+        let processed_data = match DataTypeData::new(data.data, data_types) {
             Ok(data) => data,
-            Err(error) => return Err(error.add_field(&data.name)),
+            Err(error) => return Err(error.add_field("data")),
         };
 
         return Ok(Self {
@@ -470,6 +805,15 @@ impl DataType {
         });
     }
 
+    /// Gets the forward declaration of this data type
+    fn get_forward_declaration(&self) -> String {
+        return format!(
+            "{kind} {name};",
+            kind = self.data.get_kind(),
+            name = self.name
+        );
+    }
+
     /// Generates the description if it is supplied
     fn get_description(&self) -> String {
         return match &self.description {
@@ -505,7 +849,7 @@ impl DataType {
     /// indent: The number of spaces to use for indentation
     fn get_definition_source(
         &self,
-        macros: &HashMap<String, data_model::SerializationModel>,
+        macros: &IndexMap<String, data_model::SerializationModel>,
         indent: usize,
     ) -> Result<String, Error> {
         return Ok(formatdoc!(
@@ -554,6 +898,8 @@ enum DataTypeData {
     Struct(Struct),
     /// Describes an array
     Array(Array),
+    /// Describes a map
+    Map(Map),
     /// Describes a variant
     Variant(Variant),
     /// Describes an enum
@@ -568,20 +914,75 @@ impl DataTypeData {
     /// # Parameters
     ///
     /// data: The generic data type data to convert
-    fn new(data: crate::DataTypeData) -> Result<Self, Error> {
+    ///
+    /// data_types: All the generic data types of the data model, used to
+    /// resolve references to other types
+    fn new(data: crate::DataTypeData, data_types: &[crate::DataType]) -> Result<Self, Error> {
         let result = match data {
-            crate::DataTypeData::Struct(data) => DataTypeData::Struct(Struct::new(data)?),
+            crate::DataTypeData::Struct(data) => {
+                DataTypeData::Struct(Struct::new(data, data_types)?)
+            }
             crate::DataTypeData::Array(data) => DataTypeData::Array(Array::new(data)?),
+            crate::DataTypeData::Map(data) => DataTypeData::Map(Map::new(data)?),
             crate::DataTypeData::Variant(data) => DataTypeData::Variant(Variant::new(data)?),
             crate::DataTypeData::Enum(data) => DataTypeData::Enum(Enum::new(data)?),
             crate::DataTypeData::ConstrainedType(data) => {
-                DataTypeData::ConstrainedType(ConstrainedType::new(data)?)
+                DataTypeData::ConstrainedType(ConstrainedType::new(data, data_types)?)
             }
         };
 
         return Ok(result);
     }
 
+    /// Gets the c++ keyword used to define the data type (struct or class)
+    fn get_kind(&self) -> &'static str {
+        return match self {
+            DataTypeData::ConstrainedType(_) => "class",
+            _ => "struct",
+        };
+    }
+
+    /// Gets the names of all the types which must be defined before this data
+    /// type
+    fn get_dependencies(&self) -> Vec<&str> {
+        return match self {
+            DataTypeData::Struct(data) => data.get_dependencies(),
+            DataTypeData::Array(data) => data.get_dependencies(),
+            DataTypeData::Map(data) => data.get_dependencies(),
+            DataTypeData::Variant(data) => data.get_dependencies(),
+            DataTypeData::Enum(data) => data.get_dependencies(),
+            DataTypeData::ConstrainedType(data) => data.get_dependencies(),
+        };
+    }
+
+    /// Gets the names of all the types this data type references
+    fn get_references(&self) -> Vec<&str> {
+        return match self {
+            DataTypeData::Struct(data) => data.get_references(),
+            DataTypeData::Array(data) => data.get_references(),
+            DataTypeData::Map(data) => data.get_references(),
+            DataTypeData::Variant(data) => data.get_references(),
+            DataTypeData::Enum(data) => data.get_references(),
+            DataTypeData::ConstrainedType(data) => data.get_references(),
+        };
+    }
+
+    /// Stores all references to the given types in a termite::Box, arrays
+    /// do not need boxes and constrained types are never boxed
+    ///
+    /// # Parameters
+    ///
+    /// recursive_types: The types which depend on this data type
+    fn box_references(&mut self, recursive_types: &HashSet<&str>) {
+        match self {
+            DataTypeData::Struct(data) => data.box_references(recursive_types),
+            DataTypeData::Variant(data) => data.box_references(recursive_types),
+            DataTypeData::Map(data) => data.box_references(recursive_types),
+            DataTypeData::Enum(data) => data.box_references(recursive_types),
+            DataTypeData::Array(_) | DataTypeData::ConstrainedType(_) => (),
+        }
+    }
+
     /// Converts the data type data to a string for use in the header file
     ///
     /// # Parameters
@@ -593,6 +994,7 @@ impl DataTypeData {
         return match self {
             DataTypeData::Struct(data) => data.get_definition_header(name, indent),
             DataTypeData::Array(data) => data.get_definition_header(name, indent),
+            DataTypeData::Map(data) => data.get_definition_header(name, indent),
             DataTypeData::Variant(data) => data.get_definition_header(name, indent),
             DataTypeData::Enum(data) => data.get_definition_header(name, indent),
             DataTypeData::ConstrainedType(data) => data.get_definition_header(name, indent),
@@ -611,12 +1013,13 @@ impl DataTypeData {
     fn get_definition_source(
         &self,
         name: &str,
-        macros: &HashMap<String, data_model::SerializationModel>,
+        macros: &IndexMap<String, data_model::SerializationModel>,
         indent: usize,
     ) -> Result<String, Error> {
         return match self {
             DataTypeData::Struct(data) => data.get_definition_source(name, macros, indent),
             DataTypeData::Array(data) => Ok(data.get_definition_source(name, indent)),
+            DataTypeData::Map(data) => Ok(data.get_definition_source(name, indent)),
             DataTypeData::Variant(data) => Ok(data.get_definition_source(name, indent)),
             DataTypeData::Enum(data) => Ok(data.get_definition_source(name, indent)),
             DataTypeData::ConstrainedType(data) => Ok(data.get_definition_source(name, indent)),
@@ -634,6 +1037,7 @@ impl DataTypeData {
         return match self {
             DataTypeData::Struct(data) => data.get_parser_header(name, namespace),
             DataTypeData::Array(data) => data.get_parser_header(name, namespace),
+            DataTypeData::Map(data) => data.get_parser_header(name, namespace),
             DataTypeData::Variant(data) => data.get_parser_header(name, namespace),
             DataTypeData::Enum(data) => data.get_parser_header(name, namespace),
             DataTypeData::ConstrainedType(data) => data.get_parser_header(name, namespace),
@@ -665,6 +1069,7 @@ impl DataTypeData {
             DataTypeData::Array(data) => {
                 data.get_parser_source(name, indent, namespace, data_types)
             }
+            DataTypeData::Map(data) => data.get_parser_source(name, indent, namespace, data_types),
             DataTypeData::Variant(data) => {
                 data.get_parser_source(name, indent, namespace, data_types)
             }
@@ -681,7 +1086,7 @@ impl DataTypeData {
 #[derive(Debug, Clone)]
 pub struct Error {
     /// The location where the error occured
-    pub location: String,
+    pub location: Location,
     /// The actual error that occured
     pub error: ErrorCore,
 }
@@ -693,34 +1098,32 @@ impl Error {
     ///
     /// base: The base to set in the location
     fn add_field(self, base: &str) -> Error {
-        let location = if !self.location.is_empty() {
-            format!("{}.{}", base, self.location)
-        } else {
-            base.to_string()
-        };
-
         return Error {
-            location,
+            location: self.location.add_field(base),
             error: self.error,
         };
     }
 
-    /// Sets the current location to be the element of a field of the given base
+    /// Sets the current location to be the element of a sequence
     ///
     /// # Parameters
     ///
-    /// base: The base to set in the location
-    ///
-    /// index: The index of the field
-    fn add_element(self, base: &str, index: usize) -> Error {
-        let location = if !self.location.is_empty() {
-            format!("{}[{}].{}", base, index, self.location)
-        } else {
-            format!("{}[{}]", base, index)
+    /// index: The index of the element
+    fn add_element(self, index: usize) -> Error {
+        return Error {
+            location: self.location.add_element(index),
+            error: self.error,
         };
+    }
 
+    /// Sets the current location to be inside of the location of a data type
+    ///
+    /// # Parameters
+    ///
+    /// base: The location of the data type
+    fn add_location(self, base: &Location) -> Error {
         return Error {
-            location,
+            location: self.location.add_location(base),
             error: self.error,
         };
     }
@@ -728,10 +1131,20 @@ impl Error {
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Errors for the entire data model have no location
+        if self.location.is_empty() {
+            return write!(f, "{}", self.error);
+        }
         return write!(f, "{}: {}", self.location, self.error);
     }
 }
 
+impl LocatedError for Error {
+    fn get_location(&self) -> Location {
+        return self.location.clone();
+    }
+}
+
 impl From<data_model::Error> for Error {
     fn from(value: data_model::Error) -> Self {
         return Error {
@@ -753,6 +1166,16 @@ pub enum ErrorCore {
     /// The macro expansion in the footer failed
     #[error("The footer \"{:?}\" must only expand to a string when using macros", .0)]
     FooterMacro(String),
+    /// The data model is not valid
+    #[error("The data model is not valid:{}", data_model::display_errors(.0))]
+    Validation(Vec<data_model::Error>),
+    /// The data types depend on each other in a cycle
+    #[error("The types {:?} depend on each other in a cycle which cannot be represented by value", .0)]
+    CyclicDependency(Vec<String>),
+    /// An imported data model would get c++ files with the same name as a
+    /// different data model
+    #[error("The c++ files of the import {0:?} would be named {1}.h and {1}.cpp like the files of a different data model")]
+    DuplicateFileName(String, String),
 }
 
 #[cfg(test)]
@@ -816,10 +1239,31 @@ pub(crate) mod test_utils {
     }
 
     pub(crate) fn compile_and_test(name: &str) {
+        compile_and_test_with(name, &[]);
+    }
+
+    /// Compiles and runs a c++ test together with the sources of other
+    /// generated data models in the same directory
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the test
+    ///
+    /// extra_sources: The names of the extra source files without extension
+    pub(crate) fn compile_and_test_with(name: &str, extra_sources: &[&str]) {
         // Get the paths
         let source_path = get_source_path(name);
         let test_path = get_test_path(name);
         let exe_path = get_exe_path(name);
+        let extra_sources = extra_sources
+            .iter()
+            .map(|source| {
+                let source_path = path::Path::new("tests/cpp")
+                    .join(name)
+                    .join(format!("{source}.cpp"));
+                return format!(" {}", source_path.to_str().unwrap());
+            })
+            .collect::<String>();
 
         // Create the output directory
         fs::create_dir_all(exe_path.parent().unwrap()).unwrap();
@@ -829,8 +1273,9 @@ pub(crate) mod test_utils {
             process::Command::new("cmd")
                 .arg("/C")
                 .arg(format!(
-                    "g++ {} {} -Isrc/cpp -Wall -std=c++17 -o {}.exe",
+                    "g++ {}{} {} -Isrc/cpp -Wall -std=c++17 -o {}.exe",
                     source_path.to_str().unwrap(),
+                    extra_sources,
                     test_path.to_str().unwrap(),
                     exe_path.to_str().unwrap()
                 ))
@@ -840,8 +1285,9 @@ pub(crate) mod test_utils {
             process::Command::new("sh")
                 .arg("-c")
                 .arg(format!(
-                    "g++ {} {} -Isrc/cpp -Wall -std=c++17 -o {}",
+                    "g++ {}{} {} -Isrc/cpp -Wall -std=c++17 -o {}",
                     source_path.to_str().unwrap(),
+                    extra_sources,
                     test_path.to_str().unwrap(),
                     exe_path.to_str().unwrap()
                 ))
@@ -880,7 +1326,8 @@ pub(crate) mod test_utils {
 mod tests {
     use super::*;
     use crate::cpp::test_utils::*;
-    use std::process;
+    use type_struct::StructField;
+    use std::{fs, path, process};
 
     #[test]
     fn termite_basis() {
@@ -956,6 +1403,27 @@ mod tests {
 
         assert_eq!(test_output.status.code().expect("Unable to run"), 0);
 
+        let test_output_round_trip = if cfg!(target_os = "windows") {
+            process::Command::new("cmd")
+                .current_dir("tests/cpp/termite/build")
+                .arg("/C")
+                .arg(".\\Debug\\termite-round-trip.exe")
+                .output()
+                .expect("failed to test")
+        } else {
+            process::Command::new("sh")
+                .current_dir("tests/cpp/termite/build")
+                .arg("-c")
+                .arg("./termite-round-trip")
+                .output()
+                .expect("failed to test")
+        };
+
+        assert_eq!(
+            test_output_round_trip.status.code().expect("Unable to run"),
+            0
+        );
+
         let test_output_yaml = if cfg!(target_os = "windows") {
             process::Command::new("cmd")
                 .current_dir("tests/cpp/termite/build")
@@ -1000,6 +1468,7 @@ mod tests {
 
         // Make sure it generates the correct code
         let data_model = DataModel {
+            imports: vec![],
             headers: Headers {
                 header: "// header header".to_string(),
                 source: "// header source".to_string(),
@@ -1010,7 +1479,7 @@ mod tests {
             },
             data_types: vec![],
             namespace: vec![],
-            macros: HashMap::new(),
+            macros: IndexMap::new(),
         };
 
         // Create the header file
@@ -1031,6 +1500,7 @@ mod tests {
 
         // Make sure it generates the correct code
         let data_model = DataModel {
+            imports: vec![],
             headers: Headers {
                 header: "".to_string(),
                 source: "".to_string(),
@@ -1041,7 +1511,7 @@ mod tests {
             },
             data_types: vec![],
             namespace: vec![],
-            macros: HashMap::new(),
+            macros: IndexMap::new(),
         };
 
         // Create the header file
@@ -1062,6 +1532,7 @@ mod tests {
 
         // Make sure it generates the correct code
         let data_model = DataModel {
+            imports: vec![],
             headers: Headers {
                 header: "".to_string(),
                 source: "".to_string(),
@@ -1072,7 +1543,7 @@ mod tests {
             },
             data_types: vec![],
             namespace: vec!["test1".to_string(), "test2".to_string()],
-            macros: HashMap::new(),
+            macros: IndexMap::new(),
         };
 
         // Create the header file
@@ -1093,6 +1564,7 @@ mod tests {
 
         // Make sure it generates the correct code
         let data_model = DataModel {
+            imports: vec![],
             headers: Headers {
                 header: "// Header header".to_string(),
                 source: "// Header source".to_string(),
@@ -1105,16 +1577,22 @@ mod tests {
                 DataType {
                     name: "DataType1".to_string(),
                     description: Some("description1".to_string()),
-                    data: DataTypeData::Struct(Struct { fields: vec![] }),
+                    data: DataTypeData::Struct(Struct {
+                        fields: vec![],
+                        unknown_fields: crate::UnknownFields::Collect,
+                    }),
                 },
                 DataType {
                     name: "DataType2".to_string(),
                     description: Some("description2".to_string()),
-                    data: DataTypeData::Struct(Struct { fields: vec![] }),
+                    data: DataTypeData::Struct(Struct {
+                        fields: vec![],
+                        unknown_fields: crate::UnknownFields::Collect,
+                    }),
                 },
             ],
             namespace: vec!["test".to_string()],
-            macros: HashMap::new(),
+            macros: IndexMap::new(),
         };
 
         // Create the header file
@@ -1128,6 +1606,235 @@ mod tests {
         assert_eq!(str_diff(&source_file, &expected_source), None);
     }
 
+    #[test]
+    fn dependency_order() {
+        // Check c++ code
+        compile_and_test("dependency_order");
+
+        // Make sure it generates the correct code
+        let data_model = DataModel {
+            imports: vec![],
+            headers: Headers {
+                header: "".to_string(),
+                source: "".to_string(),
+            },
+            footers: Footers {
+                header: "".to_string(),
+                source: "".to_string(),
+            },
+            data_types: vec![
+                DataType {
+                    name: "DataType1".to_string(),
+                    description: None,
+                    data: DataTypeData::Struct(Struct {
+                        fields: vec![
+                            StructField {
+                                name: "field1".to_string(),
+                                description: None,
+                                data_type: "DataType3".to_string(),
+                                default: crate::DefaultType::Required,
+                                boxed: false,
+                                location: Location::default(),
+                            },
+                            StructField {
+                                name: "field2".to_string(),
+                                description: None,
+                                data_type: "DataType2".to_string(),
+                                default: crate::DefaultType::Required,
+                                boxed: false,
+                                location: Location::default(),
+                            },
+                        ],
+                        unknown_fields: crate::UnknownFields::Collect,
+                    }),
+                },
+                DataType {
+                    name: "DataType2".to_string(),
+                    description: None,
+                    data: DataTypeData::Array(Array {
+                        data_type: "DataType3".to_string(),
+                    }),
+                },
+                DataType {
+                    name: "DataType3".to_string(),
+                    description: None,
+                    data: DataTypeData::Struct(Struct {
+                        fields: vec![StructField {
+                            name: "field1".to_string(),
+                            description: None,
+                            data_type: "integer".to_string(),
+                            default: crate::DefaultType::Required,
+                            boxed: false,
+                            location: Location::default(),
+                        }],
+                        unknown_fields: crate::UnknownFields::Collect,
+                    }),
+                },
+            ],
+            namespace: vec!["test".to_string()],
+            macros: IndexMap::new(),
+        };
+
+        // Create the header file
+        let header_file = data_model.get_header("HEADER", 2).unwrap();
+        let source_file = data_model.get_source("dependency_order", 2).unwrap();
+        let expected_header = include_str!("../../tests/cpp/dependency_order/dependency_order.h");
+        let expected_source =
+            include_str!("../../tests/cpp/dependency_order/dependency_order.cpp");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&header_file, &expected_header), None);
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn dependency_cycle() {
+        // Constrained types are never boxed so they cannot wrap each other
+        let data_model = DataModel::new(crate::DataModel {
+            imports: vec![],
+            source: None,
+            headers: IndexMap::new(),
+            footers: IndexMap::new(),
+            data_types: vec![
+                crate::DataType {
+                    name: "DataType1".to_string(),
+                    description: None,
+                    data: crate::DataTypeData::Array(crate::Array {
+                        data_type: "DataType2".to_string(),
+                    }),
+                },
+                crate::DataType {
+                    name: "DataType2".to_string(),
+                    description: None,
+                    data: crate::DataTypeData::ConstrainedType(crate::ConstrainedType {
+                        data_type: "DataType3".to_string(),
+                        constraints: vec![],
+                    }),
+                },
+                crate::DataType {
+                    name: "DataType3".to_string(),
+                    description: None,
+                    data: crate::DataTypeData::ConstrainedType(crate::ConstrainedType {
+                        data_type: "DataType2".to_string(),
+                        constraints: vec![],
+                    }),
+                },
+            ],
+            namespace: vec![],
+            macros: IndexMap::new(),
+            unknown_fields: crate::UnknownFields::Collect,
+        })
+        .unwrap();
+
+        let expected = vec![
+            "DataType2".to_string(),
+            "DataType3".to_string(),
+            "DataType2".to_string(),
+        ];
+        match data_model.get_header("HEADER", 2).unwrap_err().error {
+            ErrorCore::CyclicDependency(cycle) => assert_eq!(cycle, expected),
+            error => panic!("Unexpected error: {error:?}"),
+        }
+        match data_model.get_source("cycle", 2).unwrap_err().error {
+            ErrorCore::CyclicDependency(cycle) => assert_eq!(cycle, expected),
+            error => panic!("Unexpected error: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn recursive() {
+        // Check c++ code
+        compile_and_test("recursive");
+
+        // Make sure it generates the correct code
+        let model =
+            crate::DataModel::import_yaml(include_str!("../../tests/cpp/recursive/recursive.yaml"))
+                .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the header file
+        let header_file = data_model.get_header("HEADER", 2).unwrap();
+        let source_file = data_model.get_source("recursive", 2).unwrap();
+        let expected_header = include_str!("../../tests/cpp/recursive/recursive.h");
+        let expected_source = include_str!("../../tests/cpp/recursive/recursive.cpp");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&header_file, &expected_header), None);
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn errors() {
+        // Check c++ code
+        compile_and_test("errors");
+
+        // Make sure it generates the correct code
+        let model =
+            crate::DataModel::import_yaml(include_str!("../../tests/cpp/errors/errors.yaml"))
+                .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the header file
+        let header_file = data_model.get_header("HEADER", 2).unwrap();
+        let source_file = data_model.get_source("errors", 2).unwrap();
+        let expected_header = include_str!("../../tests/cpp/errors/errors.h");
+        let expected_source = include_str!("../../tests/cpp/errors/errors.cpp");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&header_file, &expected_header), None);
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn imports() {
+        // Check c++ code
+        compile_and_test_with("imports", &["shared"]);
+
+        // Make sure it generates the correct code for both data models
+        for name in ["shared", "imports"] {
+            let path = path::Path::new("tests/cpp/imports").join(format!("{name}.yaml"));
+            let mut model =
+                crate::DataModel::import_yaml(&fs::read_to_string(&path).unwrap()).unwrap();
+            model.resolve_imports(&path).unwrap();
+            let data_model = DataModel::new(model).unwrap();
+
+            let header_file = data_model.get_header(&name.to_uppercase(), 2).unwrap();
+            let source_file = data_model.get_source(name, 2).unwrap();
+            let expected_header =
+                fs::read_to_string(format!("tests/cpp/imports/{name}.h")).unwrap();
+            let expected_source =
+                fs::read_to_string(format!("tests/cpp/imports/{name}.cpp")).unwrap();
+
+            // Check that they are the same
+            assert_eq!(str_diff(&header_file, &expected_header), None);
+            assert_eq!(str_diff(&source_file, &expected_source), None);
+        }
+    }
+
+    #[test]
+    fn duplicate_import_names() {
+        let directory = std::env::temp_dir().join("termite_cpp_duplicate_import_names");
+        let _ = fs::remove_dir_all(&directory);
+        for name in ["a", "b"] {
+            fs::create_dir_all(directory.join(name)).unwrap();
+            fs::write(directory.join(name).join("common.yaml"), "data_types: []\n").unwrap();
+        }
+        let path = directory.join("model.yaml");
+        let mut model = crate::DataModel::import_yaml(
+            "imports:\n- a/common.yaml\n- a/common.yaml\n- b/common.yaml\n",
+        )
+        .unwrap();
+        model.resolve_imports(&path).unwrap();
+
+        // Importing the same data model twice is fine
+        let error = DataModel::new(model).unwrap_err();
+        assert_eq!(error.location.to_string(), ".imports[2]");
+        assert!(matches!(
+            error.error,
+            ErrorCore::DuplicateFileName(path, name) if path == "b/common.yaml" && name == "common"
+        ));
+    }
+
     #[test]
     fn full_example() {
         // Check c++ code
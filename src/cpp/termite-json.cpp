@@ -9,11 +9,91 @@
 
 #include "termite-json.h"
 
+#include <algorithm>
 #include <fstream>
 
 namespace termite {
 
-Result<Node> from_JSON(const nlohmann::json &node) {
+namespace {
+
+/**
+ * @brief The JSON text a nlohmann::json was parsed from, used to convert the
+ * character positions of the values into lines and columns
+ *
+ */
+class Source {
+public:
+  /**
+   * @brief Constructs a new source
+   *
+   * @param text The JSON text
+   * @param file The path of the file the text was read from, empty if unknown
+   */
+  explicit Source(const std::string &text, std::string file)
+      : file_(std::move(file)) {
+    line_starts_.push_back(0);
+    for (size_t position = 0; position < text.size(); ++position) {
+      if (text[position] == '\n') {
+        line_starts_.push_back(position + 1);
+      }
+    }
+  }
+
+  /**
+   * @brief Converts a character position into a mark
+   *
+   * @param position The position of the character in the text
+   * @return The mark of the character
+   */
+  [[nodiscard]] Mark get_mark(size_t position) const {
+    size_t line = std::upper_bound(line_starts_.cbegin(), line_starts_.cend(),
+                                   position) -
+                  line_starts_.cbegin();
+    return Mark(line, position - line_starts_[line - 1] + 1, file_);
+  }
+
+private:
+  /**
+   * @brief The path of the file
+   *
+   */
+  std::string file_;
+  /**
+   * @brief The position of the first character of every line
+   *
+   */
+  std::vector<size_t> line_starts_;
+};
+
+/**
+ * @brief Gets the position of a nlohmann::json in the source, this is only
+ * available if nlohmann::json is compiled with JSON_DIAGNOSTIC_POSITIONS
+ *
+ * @param node The node to get the position of
+ * @param source The text the node was parsed from, nullptr if unknown
+ * @return The mark or nothing if the position is unknown
+ */
+std::optional<Mark> get_mark([[maybe_unused]] const nlohmann::json &node,
+                             [[maybe_unused]] const Source *source) {
+#if JSON_DIAGNOSTIC_POSITIONS
+  if (source != nullptr && node.start_pos() != std::string::npos) {
+    return source->get_mark(node.start_pos());
+  }
+#endif
+  return std::nullopt;
+}
+
+/**
+ * @brief Converts a nlohmann::json to a termite::Node with the positions of
+ * the values as marks
+ *
+ * @param node The node to convert
+ * @param source The text the node was parsed from, nullptr if unknown
+ * @return The termite node or an error if the node is not compatible
+ */
+Result<Node> convert_JSON(const nlohmann::json &node, const Source *source) {
+  std::optional<Mark> mark = get_mark(node, source);
+
   // Convert a list
   if (node.is_array()) {
     std::vector<Node> list;
@@ -21,7 +101,7 @@ Result<Node> from_JSON(const nlohmann::json &node) {
     for (auto value_it = node.begin(); value_it != node.end();
          ++value_it, ++index) {
       // Get the value
-      Result<Node> value = from_JSON(*value_it);
+      Result<Node> value = convert_JSON(*value_it, source);
       if (!value.is_ok()) {
         return Result<Node>::err(value.get_err().add_list(index));
       }
@@ -31,7 +111,8 @@ Result<Node> from_JSON(const nlohmann::json &node) {
     }
 
     // Return the node
-    return Result<Node>::ok(Node(Node::List(std::move(list))));
+    return Result<Node>::ok(
+        Node(Node::List(std::move(list)), std::move(mark)));
   }
 
   // Convert a map
@@ -42,7 +123,7 @@ Result<Node> from_JSON(const nlohmann::json &node) {
       std::string key = key_value.key();
 
       // Get the value
-      Result<Node> value = from_JSON(key_value.value());
+      Result<Node> value = convert_JSON(key_value.value(), source);
       if (!value.is_ok()) {
         return Result<Node>::err(value.get_err().add_field(key));
       }
@@ -52,7 +133,7 @@ Result<Node> from_JSON(const nlohmann::json &node) {
     }
 
     // Return the node
-    return Result<Node>::ok(Node(Node::Map(std::move(map))));
+    return Result<Node>::ok(Node(Node::Map(std::move(map)), std::move(mark)));
   }
 
   // Convert a scalar
@@ -62,17 +143,27 @@ Result<Node> from_JSON(const nlohmann::json &node) {
         node.is_string() ? static_cast<std::string>(node) : node.dump();
 
     // Return the node
-    return Result<Node>::ok(Node(Node::Value(std::move(value))));
+    return Result<Node>::ok(
+        Node(Node::Value(std::move(value)), std::move(mark)));
   }
 
   // Return an error
-  return Result<Node>::err(Error(
-      "Unknown node type, must be either Primitive, Structured or Array"));
+  return Result<Node>::err(
+      Error("Unknown node type, must be either Primitive, Structured or Array")
+          .set_mark(mark));
 }
 
-Result<Node> from_JSON_string(const std::string &string) {
+/**
+ * @brief Parses a JSON text and converts it to a termite::Node
+ *
+ * @param string The JSON text
+ * @param file The path of the file the text was read from, empty if unknown
+ * @return The termite::Node or an error if the text is invalid
+ */
+Result<Node> parse_JSON(const std::string &string, std::string file) {
   try {
-    return from_JSON(nlohmann::json::parse(string));
+    Source source(string, std::move(file));
+    return convert_JSON(nlohmann::json::parse(string), &source);
   } catch (const std::exception &e) {
     std::stringstream ss;
     ss << "Unable to parse JSON string: " << e.what();
@@ -80,6 +171,16 @@ Result<Node> from_JSON_string(const std::string &string) {
   }
 }
 
+} // namespace
+
+Result<Node> from_JSON(const nlohmann::json &node) {
+  return convert_JSON(node, nullptr);
+}
+
+Result<Node> from_JSON_string(const std::string &string) {
+  return parse_JSON(string, "");
+}
+
 Result<Node> from_JSON_file(const std::filesystem::path &path) {
   std::ifstream file(path);
   if (!file.is_open()) {
@@ -97,7 +198,7 @@ Result<Node> from_JSON_file(const std::filesystem::path &path) {
     ss << "Unable to read file: " << path.generic_string();
     return Result<Node>::err(Error(ss.str()));
   }
-  return from_JSON_string(json_string);
+  return parse_JSON(json_string, path.generic_string());
 }
 
 nlohmann::json to_JSON(const Node &node) {
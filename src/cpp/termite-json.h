@@ -17,21 +17,26 @@
 namespace termite {
 
 /**
- * @brief Converts a nlohmann::json to a termite::Node
+ * @brief Converts a nlohmann::json to a termite::Node without marks since
+ * nlohmann::json only stores character positions and not the text
  *
  * @param node The node to convert
  * @return The termite node or an error if the node is not compatible
  */
 Result<Node> from_JSON(const nlohmann::json &node);
 /**
- * @brief Converts a JSON string to a termite::Node
+ * @brief Converts a JSON string to a termite::Node, the position of every value
+ * is kept as its mark if nlohmann::json is compiled with
+ * JSON_DIAGNOSTIC_POSITIONS
  *
  * @param string The string to convert
  * @return The termite::Node or an error if the string is invalid
  */
 [[nodiscard]] Result<Node> from_JSON_string(const std::string &string);
 /**
- * @brief Reads a nlohmann::json from a file and converts it to a termite::Node
+ * @brief Reads a nlohmann::json from a file and converts it to a termite::Node,
+ * the position of every value is kept as its mark if nlohmann::json is
+ * compiled with JSON_DIAGNOSTIC_POSITIONS
  *
  * @param path The path to the file to read
  * @return The termite::Node or an error if the file is invalid
@@ -13,7 +13,28 @@
 
 namespace termite {
 
-Result<Node> from_YAML(const YAML::Node &node) {
+namespace {
+
+/**
+ * @brief Gets the position of a YAML::Node in the source
+ *
+ * @param node The node to get the position of
+ * @param file The path of the file the node was read from
+ * @return The mark or nothing if the node was not parsed from a source
+ */
+std::optional<Mark> get_mark(const YAML::Node &node, const std::string &file) {
+  YAML::Mark mark = node.Mark();
+  if (mark.is_null()) {
+    return std::nullopt;
+  }
+  return Mark(mark.line + 1, mark.column + 1, file);
+}
+
+} // namespace
+
+Result<Node> from_YAML(const YAML::Node &node, const std::string &file) {
+  std::optional<Mark> mark = get_mark(node, file);
+
   // Convert a map
   if (node.IsMap()) {
     std::map<std::string, Node> map;
@@ -25,11 +46,12 @@ Result<Node> from_YAML(const YAML::Node &node) {
       } catch (const std::exception &err) {
         std::stringstream ss;
         ss << "Unable to read key: " << err.what();
-        return Result<Node>::err(Error(ss.str()));
+        return Result<Node>::err(
+            Error(ss.str()).set_mark(get_mark(key_value.first, file)));
       }
 
       // Get the value
-      Result<Node> value = from_YAML(key_value.second);
+      Result<Node> value = from_YAML(key_value.second, file);
       if (!value.is_ok()) {
         return Result<Node>::err(value.get_err().add_field(key));
       }
@@ -39,7 +61,7 @@ Result<Node> from_YAML(const YAML::Node &node) {
     }
 
     // Return the node
-    return Result<Node>::ok(Node(Node::Map(std::move(map))));
+    return Result<Node>::ok(Node(Node::Map(std::move(map)), std::move(mark)));
   }
 
   // Convert a list
@@ -49,7 +71,7 @@ Result<Node> from_YAML(const YAML::Node &node) {
     for (YAML::const_iterator value_it = node.begin(); value_it != node.end();
          ++value_it, ++index) {
       // Get the value
-      Result<Node> value = from_YAML(*value_it);
+      Result<Node> value = from_YAML(*value_it, file);
       if (!value.is_ok()) {
         return Result<Node>::err(value.get_err().add_list(index));
       }
@@ -59,7 +81,8 @@ Result<Node> from_YAML(const YAML::Node &node) {
     }
 
     // Return the node
-    return Result<Node>::ok(Node(Node::List(std::move(list))));
+    return Result<Node>::ok(
+        Node(Node::List(std::move(list)), std::move(mark)));
   }
 
   // Convert a scalar
@@ -71,16 +94,18 @@ Result<Node> from_YAML(const YAML::Node &node) {
     } catch (const std::exception &err) {
       std::stringstream ss;
       ss << "Unable to read value: " << err.what();
-      return Result<Node>::err(Error(ss.str()));
+      return Result<Node>::err(Error(ss.str()).set_mark(mark));
     }
 
     // Return the node
-    return Result<Node>::ok(Node(Node::Value(std::move(value))));
+    return Result<Node>::ok(
+        Node(Node::Value(std::move(value)), std::move(mark)));
   }
 
   // Return an error
   return Result<Node>::err(
-      Error("Unknown node type, must be either Scalar, Map or Sequence"));
+      Error("Unknown node type, must be either Scalar, Map or Sequence")
+          .set_mark(mark));
 }
 
 Result<Node> from_YAML_string(const std::string &string) {
@@ -95,7 +120,8 @@ Result<Node> from_YAML_string(const std::string &string) {
 
 Result<Node> from_YAML_file(const std::filesystem::path &path) {
   try {
-    return from_YAML(YAML::LoadFile(path.generic_string()));
+    return from_YAML(YAML::LoadFile(path.generic_string()),
+                     path.generic_string());
   } catch (const std::exception &e) {
     std::stringstream ss;
     ss << "Unable to load YAML file: " << e.what();
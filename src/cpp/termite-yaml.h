@@ -19,12 +19,15 @@
 namespace termite {
 
 /**
- * @brief Converts a YAML::Node to a termite::Node
+ * @brief Converts a YAML::Node to a termite::Node, the position of every
+ * parsed node is kept as its mark
  *
  * @param node The node to convert
+ * @param file The path of the file the node was read from, empty if unknown
  * @return The termite::Node or an error if the node is not compatible
  */
-[[nodiscard]] Result<Node> from_YAML(const YAML::Node &node);
+[[nodiscard]] Result<Node> from_YAML(const YAML::Node &node,
+                                     const std::string &file = "");
 /**
  * @brief Converts a YAML string to a termite::Node
  *
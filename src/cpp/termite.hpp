@@ -11,11 +11,17 @@
 #define TERMITE_H_INCLUDED
 
 #include <algorithm>
+#include <array>
+#include <charconv>
+#include <cmath>
 #include <iostream>
+#include <limits>
 #include <map>
 #include <memory>
+#include <optional>
 #include <sstream>
 #include <string>
+#include <string_view>
 #include <variant>
 #include <vector>
 #include <cstdint>
@@ -95,6 +101,24 @@ public:
   }
 };
 
+/**
+ * @brief Counts the characters of a UTF-8 string such that the length of a
+ * string is the same as in the other languages, every byte which does not
+ * continue a multi-byte character starts a new character
+ *
+ * @param value The string to count the characters of
+ * @return The number of characters
+ */
+[[nodiscard]] inline integer utf8_length(const std::string &value) {
+  integer length = 0;
+  for (char c : value) {
+    if ((static_cast<unsigned char>(c) & 0xC0) != 0x80) {
+      ++length;
+    }
+  }
+  return length;
+}
+
 template <typename T> class Reference {
 public:
   /**
@@ -152,6 +176,200 @@ private:
   const T &ref_;
 };
 
+/**
+ * @brief Stores a value on the heap while keeping value semantics, it is used
+ * for recursive types which cannot store their values directly
+ *
+ * @tparam T The type of the value to store
+ */
+template <typename T> class Box {
+public:
+  /**
+   * @brief The type of the stored value
+   *
+   */
+  using value_type = T;
+
+  /**
+   * @brief Constructs a new box
+   *
+   * @param value The value to store
+   */
+  Box(T value) : value_(std::make_unique<T>(std::move(value))) {}
+  /**
+   * @brief Constructs a new box with a copy of the value of another box
+   *
+   * @param other The box to copy
+   */
+  Box(const Box &other) : value_(std::make_unique<T>(*other.value_)) {}
+  Box(Box &&other) = default;
+
+  /**
+   * @brief Sets the value to a copy of the value of another box
+   *
+   * @param other The box to copy
+   * @return This box
+   */
+  Box &operator=(const Box &other) {
+    if (this != &other) {
+      value_ = std::make_unique<T>(*other.value_);
+    }
+    return *this;
+  }
+  Box &operator=(Box &&other) = default;
+
+  /**
+   * @brief Gets the stored value
+   *
+   * @return The value
+   */
+  [[nodiscard]] const T &get() const { return *value_; }
+  /**
+   * @brief Gets the stored value
+   *
+   * @return The value
+   */
+  [[nodiscard]] T &get() { return *value_; }
+  [[nodiscard]] const T &operator*() const { return *value_; }
+  [[nodiscard]] T &operator*() { return *value_; }
+  [[nodiscard]] const T *operator->() const { return value_.get(); }
+  [[nodiscard]] T *operator->() { return value_.get(); }
+
+  /**
+   * @brief Checks if the value of this box and the other box are identical
+   *
+   * @param other The other box to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Box &other) const {
+    return *value_ == *other.value_;
+  }
+  /**
+   * @brief Checks if the value of this box and the other box are different
+   *
+   * @param other The other box to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Box &other) const {
+    return !(*this == other);
+  }
+  /**
+   * @brief Prints the value to an ostream
+   *
+   * @param os The stream to print to
+   * @param value The box to print
+   * @return The same ostream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Box &value) {
+    return os << *value.value_;
+  }
+
+private:
+  /**
+   * @brief The stored value
+   *
+   */
+  std::unique_ptr<T> value_;
+};
+
+// Helper trait to detect if T is a Box
+template <typename T> struct is_box : std::false_type {};
+template <typename T> struct is_box<Box<T>> : std::true_type {};
+template <typename T> constexpr bool is_box_v = is_box<T>::value;
+
+// Helper trait to detect if T has a generated parser which finds all errors,
+// the generated code specializes it for every data type
+template <typename T> struct has_to_value_all : std::false_type {};
+template <typename T>
+constexpr bool has_to_value_all_v = has_to_value_all<T>::value;
+
+/**
+ * @brief A position in a source file which a node was read from
+ *
+ */
+class Mark {
+public:
+  /**
+   * @brief Constructs a new mark
+   *
+   * @param line The line number starting at 1
+   * @param column The column number starting at 1
+   * @param file The path of the file, empty if it was not read from a file
+   */
+  explicit Mark(size_t line, size_t column, std::string file = "")
+      : file_(std::move(file)), line_(line), column_(column) {}
+
+  /**
+   * @brief Gets the path of the file, empty if it was not read from a file
+   *
+   * @return A reference to the path
+   */
+  [[nodiscard]] const std::string &get_file() const { return file_; }
+  /**
+   * @brief Gets the line number starting at 1
+   *
+   * @return The line number
+   */
+  [[nodiscard]] size_t get_line() const { return line_; }
+  /**
+   * @brief Gets the column number starting at 1
+   *
+   * @return The column number
+   */
+  [[nodiscard]] size_t get_column() const { return column_; }
+
+  /**
+   * @brief Checks if this mark is identical to another mark
+   *
+   * @param other The other mark to compare with
+   * @return true if they are identical, false otherwise
+   */
+  [[nodiscard]] bool operator==(const Mark &other) const {
+    return file_ == other.file_ && line_ == other.line_ &&
+           column_ == other.column_;
+  }
+  /**
+   * @brief Checks if this mark is not identical to another mark
+   *
+   * @param other The other mark to compare with
+   * @return true if they are not identical, false otherwise
+   */
+  [[nodiscard]] bool operator!=(const Mark &other) const {
+    return !(*this == other);
+  }
+
+  /**
+   * @brief Prints this mark as file:line:column to the output stream
+   *
+   * @param stream The stream to print to
+   * @param mark The mark to print
+   * @return The same stream object
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Mark &mark) {
+    if (!mark.file_.empty()) {
+      os << mark.file_ << ":";
+    }
+    return os << mark.line_ << ":" << mark.column_;
+  }
+
+private:
+  /**
+   * @brief The path of the file
+   *
+   */
+  std::string file_;
+  /**
+   * @brief The line number
+   *
+   */
+  size_t line_;
+  /**
+   * @brief The column number
+   *
+   */
+  size_t column_;
+};
+
 /**
  * @brief Describes any error within a data model
  *
@@ -179,6 +397,25 @@ public:
    * @return A reference to the location
    */
   [[nodiscard]] const std::string &get_location() const { return location_; }
+  /**
+   * @brief Gets the position in the source file this error occured at
+   *
+   * @return A reference to the mark, empty if the position is unknown
+   */
+  [[nodiscard]] const std::optional<Mark> &get_mark() const { return mark_; }
+
+  /**
+   * @brief Sets the position in the source file if it is not already known,
+   * such that the innermost position is kept
+   *
+   * @param mark The mark to set, nothing is changed if it is empty
+   */
+  Error &set_mark(const std::optional<Mark> &mark) {
+    if (!mark_.has_value()) {
+      mark_ = mark;
+    }
+    return *this;
+  }
 
   /**
    * @brief Adds a field to the location such that the old location is a field
@@ -220,7 +457,8 @@ public:
    * @return true if they are identical, false otherwise
    */
   [[nodiscard]] bool operator==(const Error &other) const {
-    return location_ == other.location_ && message_ == other.message_;
+    return location_ == other.location_ && message_ == other.message_ &&
+           mark_ == other.mark_;
   }
   /**
    * @brief Checks if this error is not identical to another error
@@ -240,6 +478,9 @@ public:
    * @return The same stream object
    */
   friend std::ostream &operator<<(std::ostream &os, const Error &error) {
+    if (error.mark_.has_value()) {
+      os << *error.mark_ << ": ";
+    }
     if (error.location_.empty()) {
       return os << error.message_;
     }
@@ -257,15 +498,151 @@ private:
    *
    */
   std::string message_;
+  /**
+   * @brief The position in the source file where the error occured
+   *
+   */
+  std::optional<Mark> mark_;
+};
+
+/**
+ * @brief A list of errors used when parsing continues after the first error to
+ * find every problem in the data
+ *
+ */
+class Errors {
+public:
+  /**
+   * @brief Constructs an empty list of errors
+   *
+   */
+  explicit Errors() = default;
+  /**
+   * @brief Constructs a list with a single error
+   *
+   * @param error The error
+   */
+  explicit Errors(Error error) : errors_({std::move(error)}) {}
+
+  /**
+   * @brief Retrieves all the errors in the order they were found
+   *
+   * @return A reference to the errors
+   */
+  [[nodiscard]] const std::vector<Error> &get() const { return errors_; }
+  /**
+   * @brief Checks if there are no errors
+   *
+   * @return true if there are no errors, false otherwise
+   */
+  [[nodiscard]] bool empty() const { return errors_.empty(); }
+
+  /**
+   * @brief Adds an error to the end of the list
+   *
+   * @param error The error to add
+   */
+  void add(Error error) { errors_.push_back(std::move(error)); }
+  /**
+   * @brief Adds all errors of another list to the end of this list
+   *
+   * @param errors The errors to add
+   */
+  void append(const Errors &errors) {
+    errors_.insert(errors_.end(), errors.errors_.cbegin(),
+                   errors.errors_.cend());
+  }
+
+  /**
+   * @brief Adds a field to the location of every error such that the old
+   * locations are fields of the new location
+   *
+   * @param name The name of the new base
+   */
+  Errors &add_field(const std::string &name) {
+    for (Error &error : errors_) {
+      error.add_field(name);
+    }
+    return *this;
+  }
+  /**
+   * @brief Adds a list element to the location of every error such that the old
+   * locations are fields of the new list element
+   *
+   * @param index The index of the list
+   */
+  Errors &add_list(size_t index) {
+    for (Error &error : errors_) {
+      error.add_list(index);
+    }
+    return *this;
+  }
+  /**
+   * @brief Sets the position in the source file of every error which does not
+   * already have one
+   *
+   * @param mark The mark to set, nothing is changed if it is empty
+   */
+  Errors &set_mark(const std::optional<Mark> &mark) {
+    for (Error &error : errors_) {
+      error.set_mark(mark);
+    }
+    return *this;
+  }
+
+  /**
+   * @brief Checks if these errors are identical to other errors
+   *
+   * @param other The other errors to compare with
+   * @return true if they are identical, false otherwise
+   */
+  [[nodiscard]] bool operator==(const Errors &other) const {
+    return errors_ == other.errors_;
+  }
+  /**
+   * @brief Checks if these errors are not identical to other errors
+   *
+   * @param other The other errors to compare with
+   * @return true if they are not identical, false otherwise
+   */
+  [[nodiscard]] bool operator!=(const Errors &other) const {
+    return !(*this == other);
+  }
+
+  /**
+   * @brief Prints every error on its own line to the output stream
+   *
+   * @param stream The stream to print to
+   * @param errors The errors to print
+   * @return The same stream object
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Errors &errors) {
+    for (auto error = errors.errors_.cbegin(); error != errors.errors_.cend();
+         ++error) {
+      if (error != errors.errors_.cbegin()) {
+        os << "\n";
+      }
+      os << *error;
+    }
+    return os;
+  }
+
+private:
+  /**
+   * @brief The errors in the order they were found
+   *
+   */
+  std::vector<Error> errors_;
 };
 
 /**
  * @brief Class containing a result, either it is ok and an object of type T can
- * be retrieved or it is err and an Error can be retrieved
+ * be retrieved or it is err and an error of type E can be retrieved
  *
  * @tparam T The type of the ok value
+ * @tparam E The type of the err value, either Error or Errors
  */
-template <typename T> class Result {
+template <typename T, typename E = Error> class Result {
 public:
   /**
    * @brief Constructs an ok result
@@ -280,7 +657,7 @@ public:
    * @param error The error value
    * @return The resulting Result object
    */
-  [[nodiscard]] static Result err(Error error) { return Result(error); }
+  [[nodiscard]] static Result err(E error) { return Result(error); }
 
   /**
    * @brief Checks if this result is Ok or Err
@@ -312,7 +689,7 @@ public:
    *
    * @return The err value
    */
-  [[nodiscard]] Error get_err() { return std::move(std::get<Error>(value_)); }
+  [[nodiscard]] E get_err() { return std::move(std::get<E>(value_)); }
 
   /**
    * @brief Checks equality with another result, only enabled if T has the
@@ -351,7 +728,7 @@ public:
     if (result.is_ok()) {
       return os << "Ok ( " << std::get<T>(result.value_) << " )";
     } else {
-      return os << "Err ( " << std::get<Error>(result.value_) << " )";
+      return os << "Err ( " << std::get<E>(result.value_) << " )";
     }
   }
 
@@ -362,13 +739,13 @@ private:
    * @param value The value of the result
    * @param is_ok true if the value is ok, false if it is err
    */
-  Result(std::variant<T, Error> value) : value_(value) {}
+  Result(std::variant<T, E> value) : value_(value) {}
 
   /**
    * @brief The value of the result, is_ok_ describes how to interpret it
    *
    */
-  std::variant<T, Error> value_;
+  std::variant<T, E> value_;
 };
 
 /**
@@ -443,7 +820,26 @@ public:
           return Result<T>::ok(false);
         }
         return Result<T>::err(Error("Unable to parse"));
+      } else if constexpr (std::is_same_v<T, std::string>) {
+        // Keep the entire string instead of stopping at a space
+        return Result<T>::ok(value_);
       } else {
+        // Infinity and NaN are written like in YAML
+        if constexpr (std::is_floating_point_v<T>) {
+          std::string_view special = value_;
+          bool negative = !special.empty() && special.front() == '-';
+          if (!special.empty() && (negative || special.front() == '+')) {
+            special.remove_prefix(1);
+          }
+          if (special == ".inf" || special == ".Inf" || special == ".INF") {
+            T infinity = std::numeric_limits<T>::infinity();
+            return Result<T>::ok(negative ? -infinity : infinity);
+          }
+          if (value_ == ".nan" || value_ == ".NaN" || value_ == ".NAN") {
+            return Result<T>::ok(std::numeric_limits<T>::quiet_NaN());
+          }
+        }
+
         // Create the value
         std::istringstream ss(value_);
         T output;
@@ -465,6 +861,21 @@ public:
       }
     }
 
+    /**
+     * @brief Casts the node value to the given type, a value only has a single
+     * error so it is the same as to_value
+     *
+     * @tparam T The type to cast to
+     * @return A result of the given type
+     */
+    template <typename T> [[nodiscard]] Result<T, Errors> to_value_all() const {
+      Result<T> value = to_value<T>();
+      if (!value.is_ok()) {
+        return Result<T, Errors>::err(Errors(value.get_err()));
+      }
+      return Result<T, Errors>::ok(value.get_ok());
+    }
+
     /**
      * @brief Checks if this node value and another node value are identical
      *
@@ -531,14 +942,81 @@ public:
     }
 
     /**
-     * @brief Casts the node map to the given type, if not specialized then it
-     * will always return an error
+     * @brief Casts the node map to the given type and stops at the first
+     * error, if not specialized then it will always return an error unless the
+     * type has a generated parser
      *
      * @tparam T The type to cast to
      * @return A result of the given type
      */
     template <typename T> [[nodiscard]] Result<T> to_value() const {
-      return Result<T>::err(Error("Parsing not implemented for given type"));
+      if constexpr (has_to_value_all_v<T>) {
+        return first_error(to_value_all<T>());
+      } else {
+        return Result<T>::err(Error("Parsing not implemented for given type"));
+      }
+    }
+
+    /**
+     * @brief Casts the node map to the given type and keeps going after an
+     * error to find all errors, if not specialized then the single error of
+     * to_value is returned such that types with a custom to_value can still be
+     * parsed
+     *
+     * @tparam T The type to cast to
+     * @return A result of the given type with all the errors
+     */
+    template <typename T> [[nodiscard]] Result<T, Errors> to_value_all() const {
+      // Generated parsers of other kinds of nodes are not called to avoid
+      // calling to_value in a loop
+      if constexpr (has_to_value_all_v<T>) {
+        return Result<T, Errors>::err(
+            Errors(Error("Parsing not implemented for given type")));
+      } else {
+        Result<T> value = to_value<T>();
+        if (!value.is_ok()) {
+          return Result<T, Errors>::err(Errors(value.get_err()));
+        }
+        return Result<T, Errors>::ok(value.get_ok());
+      }
+    }
+
+    /**
+     * @brief Creates the error for the keys which are left after parsing all
+     * the fields of a struct which rejects unknown fields, the closest field
+     * name is suggested for every key
+     *
+     * @param map The keys which are not fields of the struct
+     * @param fields The names of all the fields of the struct
+     * @return The error naming all the unknown keys
+     */
+    [[nodiscard]] static Error
+    unknown_fields_error(const std::map<std::string, Node> &map,
+                         const std::vector<std::string> &fields) {
+      std::ostringstream ss;
+      ss << (map.size() == 1 ? "Unknown field " : "Unknown fields ");
+      for (auto key_value = map.cbegin(); key_value != map.cend();
+           ++key_value) {
+        if (key_value != map.cbegin()) {
+          ss << ", ";
+        }
+        ss << "\"" << key_value->first << "\"";
+
+        // Suggest the closest field if it is only a small typo away
+        const std::string *closest = nullptr;
+        size_t closest_distance = 0;
+        for (const std::string &field : fields) {
+          size_t distance = edit_distance(key_value->first, field);
+          if (closest == nullptr || distance < closest_distance) {
+            closest = &field;
+            closest_distance = distance;
+          }
+        }
+        if (closest != nullptr && closest_distance <= closest->size() / 3) {
+          ss << " (did you mean \"" << *closest << "\"?)";
+        }
+      }
+      return Error(ss.str());
     }
 
     /**
@@ -580,6 +1058,39 @@ public:
     }
 
   private:
+    /**
+     * @brief Counts the number of characters which must be inserted, removed
+     * or replaced to turn one string into another, swapping two neighbouring
+     * characters counts as a single edit
+     *
+     * @param from The string to start from
+     * @param to The string to end at
+     * @return The number of edits
+     */
+    [[nodiscard]] static size_t edit_distance(const std::string &from,
+                                              const std::string &to) {
+      std::vector<size_t> before(to.size() + 1);
+      std::vector<size_t> previous(to.size() + 1);
+      for (size_t j = 0; j <= to.size(); ++j) {
+        previous[j] = j;
+      }
+      for (size_t i = 1; i <= from.size(); ++i) {
+        std::vector<size_t> current(to.size() + 1);
+        current[0] = i;
+        for (size_t j = 1; j <= to.size(); ++j) {
+          size_t replace = previous[j - 1] + (from[i - 1] == to[j - 1] ? 0 : 1);
+          current[j] = std::min({previous[j] + 1, current[j - 1] + 1, replace});
+          if (i > 1 && j > 1 && from[i - 1] == to[j - 2] &&
+              from[i - 2] == to[j - 1]) {
+            current[j] = std::min(current[j], before[j - 2] + 1);
+          }
+        }
+        before = std::move(previous);
+        previous = std::move(current);
+      }
+      return previous[to.size()];
+    }
+
     /**
      * @brief The map of this node
      *
@@ -613,14 +1124,43 @@ public:
     [[nodiscard]] const std::vector<Node> &get() const { return list_; }
 
     /**
-     * @brief Casts the node list to the given type, if not specialized then it
-     * will always return an error
+     * @brief Casts the node list to the given type and stops at the first
+     * error, if not specialized then it will always return an error unless the
+     * type has a generated parser
      *
      * @tparam T The type to cast to
      * @return A result of the given type
      */
     template <typename T> [[nodiscard]] Result<T> to_value() const {
-      return Result<T>::err(Error("Parsing not implemented for given type"));
+      if constexpr (has_to_value_all_v<T>) {
+        return first_error(to_value_all<T>());
+      } else {
+        return Result<T>::err(Error("Parsing not implemented for given type"));
+      }
+    }
+
+    /**
+     * @brief Casts the node list to the given type and keeps going after an
+     * error to find all errors, if not specialized then the single error of
+     * to_value is returned such that types with a custom to_value can still be
+     * parsed
+     *
+     * @tparam T The type to cast to
+     * @return A result of the given type with all the errors
+     */
+    template <typename T> [[nodiscard]] Result<T, Errors> to_value_all() const {
+      // Generated parsers of other kinds of nodes are not called to avoid
+      // calling to_value in a loop
+      if constexpr (has_to_value_all_v<T>) {
+        return Result<T, Errors>::err(
+            Errors(Error("Parsing not implemented for given type")));
+      } else {
+        Result<T> value = to_value<T>();
+        if (!value.is_ok()) {
+          return Result<T, Errors>::err(Errors(value.get_err()));
+        }
+        return Result<T, Errors>::ok(value.get_ok());
+      }
     }
 
     /**
@@ -674,9 +1214,11 @@ public:
    * @brief Constructs a new node
    *
    * @param value The value of the node
+   * @param mark The position in the source file the node was read from
    */
-  explicit Node(std::variant<Value, Map, List> value)
-      : value_(std::move(value)) {}
+  explicit Node(std::variant<Value, Map, List> value,
+                std::optional<Mark> mark = std::nullopt)
+      : value_(std::move(value)), mark_(std::move(mark)) {}
   Node(const Node &node) = default;
 
   /**
@@ -687,37 +1229,117 @@ public:
   [[nodiscard]] const std::variant<Value, Map, List> &get() const {
     return value_;
   }
+  /**
+   * @brief Retrieves the position in the source file the node was read from
+   *
+   * @return The mark, empty if the node was not read from a source file
+   */
+  [[nodiscard]] const std::optional<Mark> &get_mark() const { return mark_; }
 
   /**
-   * @brief Casts the node to the given type
+   * @brief Casts the node to the given type and stops at the first error
    *
    * @tparam T The type to cast to
    * @return A result of the given type
    */
   template <typename T> [[nodiscard]] Result<T> to_value() const {
-    return std::visit(
-        [](const auto &value) -> Result<T> {
-          return value.template to_value<T>();
-        },
-        value_);
+    if constexpr (is_box_v<T> || has_parsing_operator_v<T> ||
+                  has_to_value_all_v<T>) {
+      return first_error(to_value_all<T>());
+    } else {
+      // Types with a custom to_value for the value, map or list
+      Result<T> result = std::visit(
+          [](const auto &value) -> Result<T> {
+            return value.template to_value<T>();
+          },
+          value_);
+      if (!result.is_ok()) {
+        Error error = result.get_err();
+        return Result<T>::err(error.set_mark(mark_));
+      }
+      return result;
+    }
+  }
+
+  /**
+   * @brief Casts the node to the given type and keeps going after an error in
+   * a struct field, list element, map value or enum value to find all errors,
+   * types without a generated parser get the single error of to_value
+   *
+   * @tparam T The type to cast to
+   * @return A result of the given type with all the errors
+   */
+  template <typename T> [[nodiscard]] Result<T, Errors> to_value_all() const {
+    // Parse the value of boxes directly
+    if constexpr (is_box_v<T>) {
+      Result<typename T::value_type, Errors> value =
+          to_value_all<typename T::value_type>();
+      if (!value.is_ok()) {
+        return Result<T, Errors>::err(value.get_err());
+      }
+      return Result<T, Errors>::ok(T(value.get_ok()));
+    } else if constexpr (!has_parsing_operator_v<T> &&
+                         !has_to_value_all_v<T>) {
+      // Types with a custom to_value
+      Result<T> value = to_value<T>();
+      if (!value.is_ok()) {
+        Errors errors(value.get_err());
+        return Result<T, Errors>::err(errors.set_mark(mark_));
+      }
+      return Result<T, Errors>::ok(value.get_ok());
+    } else {
+      Result<T, Errors> result = std::visit(
+          [](const auto &value) -> Result<T, Errors> {
+            return value.template to_value_all<T>();
+          },
+          value_);
+      if (!result.is_ok()) {
+        return Result<T, Errors>::err(result.get_err().set_mark(mark_));
+      }
+      return result;
+    }
   }
 
   /**
-   * @brief Constructs a node from a given value
+   * @brief Constructs a node from a given value, booleans are written as
+   * true/false and floating point numbers with the shortest representation
+   * which is parsed to the same number
    *
    * @tparam T The type of the value
    * @param value The value to convert to a node
    * @return The node
    */
   template <typename T> [[nodiscard]] static Node from_value(const T &value) {
-    static_assert(has_insertion_operator_v<T>, "Type must have operator<<");
-    std::stringstream ss;
-    ss << value;
-    return Node(Value(ss.str()));
+    // Export the value of boxes directly
+    if constexpr (is_box_v<T>) {
+      return from_value(*value);
+    } else if constexpr (std::is_same_v<T, bool>) {
+      return Node(Value(value ? "true" : "false"));
+    } else if constexpr (std::is_floating_point_v<T>) {
+      // Infinity and NaN are written like in YAML such that they can be parsed
+      if (std::isnan(value)) {
+        return Node(Value(".nan"));
+      }
+      if (std::isinf(value)) {
+        return Node(Value(value < 0 ? "-.inf" : ".inf"));
+      }
+
+      // Write the shortest representation which is parsed to the same number
+      std::array<char, 64> buffer;
+      std::to_chars_result result =
+          std::to_chars(buffer.data(), buffer.data() + buffer.size(), value);
+      return Node(Value(std::string(buffer.data(), result.ptr)));
+    } else {
+      static_assert(has_insertion_operator_v<T>, "Type must have operator<<");
+      std::stringstream ss;
+      ss << value;
+      return Node(Value(ss.str()));
+    }
   }
 
   /**
-   * @brief Checks if this node and another node are identical
+   * @brief Checks if this node and another node are identical, the marks are
+   * ignored
    *
    * @param other The other node to compare with
    * @return true if they are identical, false if not
@@ -753,11 +1375,36 @@ public:
   }
 
 private:
+  /**
+   * @brief Keeps only the first error of a result with all errors
+   *
+   * @tparam T The type of the ok value
+   * @param result The result with all the errors
+   * @return The result with the first error
+   */
+  template <typename T>
+  [[nodiscard]] static Result<T> first_error(Result<T, Errors> result) {
+    if (!result.is_ok()) {
+      // A failed result should always have an error
+      Errors errors = result.get_err();
+      if (errors.empty()) {
+        return Result<T>::err(Error("Parsing failed without an error"));
+      }
+      return Result<T>::err(errors.get().front());
+    }
+    return Result<T>::ok(result.get_ok());
+  }
+
   /**
    * @brief The value of this node
    *
    */
   std::variant<Value, Map, List> value_;
+  /**
+   * @brief The position in the source file the node was read from
+   *
+   */
+  std::optional<Mark> mark_;
 };
 
 } // namespace termite
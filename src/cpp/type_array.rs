@@ -20,6 +20,17 @@ impl Array {
         });
     }
 
+    /// Gets the names of all the types which must be defined before this
+    /// array, std::vector allows its elements to be defined later
+    pub(super) fn get_dependencies(&self) -> Vec<&str> {
+        return vec![];
+    }
+
+    /// Gets the names of all the types this array references
+    pub(super) fn get_references(&self) -> Vec<&str> {
+        return vec![self.data_type.as_str()];
+    }
+
     /// Converts the array to a string for use in the header file
     ///
     /// # Parameters
@@ -136,7 +147,10 @@ impl Array {
         return formatdoc!(
             "
             template<>
-            [[nodiscard]] Result<{typename}> Node::List::to_value<{typename}>() const;
+            struct has_to_value_all<{typename}> : std::true_type {{}};
+
+            template<>
+            [[nodiscard]] Result<{typename}, Errors> Node::List::to_value_all<{typename}>() const;
 
             template<>
             [[nodiscard]] Node Node::from_value<{typename}>(const {typename} &value);",
@@ -182,20 +196,23 @@ impl Array {
         return formatdoc!(
             "
             template<>
-            [[nodiscard]] Result<{typename}> Node::List::to_value<{typename}>() const {{
+            [[nodiscard]] Result<{typename}, Errors> Node::List::to_value_all<{typename}>() const {{
             {0:indent$}std::vector<{data_type}> values;
             {0:indent$}values.reserve(list_.size());
+            {0:indent$}Errors errors;
             {0:indent$}for (auto node = list_.cbegin(); node < list_.cend(); ++node) {{
-            {0:indent$}{0:indent$}Result<{data_type}> value = node->to_value<{data_type}>();
-            {0:indent$}{0:indent$}if (!value.is_ok()) {{
-            {0:indent$}{0:indent$}{0:indent$}Error error = value.get_err();
-            {0:indent$}{0:indent$}{0:indent$}error.add_list(node - list_.cbegin());
-            {0:indent$}{0:indent$}{0:indent$}return Result<{typename}>::err(std::move(error));
+            {0:indent$}{0:indent$}Result<{data_type}, Errors> value = node->to_value_all<{data_type}>();
+            {0:indent$}{0:indent$}if (value.is_ok()) {{
+            {0:indent$}{0:indent$}{0:indent$}values.push_back(std::move(value.get_ok()));
+            {0:indent$}{0:indent$}}} else {{
+            {0:indent$}{0:indent$}{0:indent$}errors.append(value.get_err().add_list(node - list_.cbegin()));
             {0:indent$}{0:indent$}}}
-            {0:indent$}{0:indent$}values.push_back(std::move(value.get_ok()));
+            {0:indent$}}}
+            {0:indent$}if (!errors.empty()) {{
+            {0:indent$}{0:indent$}return Result<{typename}, Errors>::err(std::move(errors));
             {0:indent$}}}
 
-            {0:indent$}return Result<{typename}>::ok({typename}(std::move(values)));
+            {0:indent$}return Result<{typename}, Errors>::ok({typename}(std::move(values)));
             }}
             
             template<>
@@ -224,6 +241,7 @@ mod tests {
 
         // Make sure it generates the correct code
         let data_model = DataModel {
+            imports: vec![],
             headers: Headers {
                 header: "".to_string(),
                 source: "".to_string(),
@@ -249,7 +267,7 @@ mod tests {
                 },
             ],
             namespace: vec!["test".to_string()],
-            macros: HashMap::new(),
+            macros: IndexMap::new(),
         };
 
         // Create the header file
@@ -1,4 +1,5 @@
 use super::*;
+use crate::constraint::{self, parenthesize, Expression, Kind, Operator};
 use indoc::formatdoc;
 
 /// The type specific information for a constrained type
@@ -7,7 +8,20 @@ pub(super) struct ConstrainedType {
     /// The type that is constrained
     pub(super) data_type: String,
     /// All extra constraints for the type
-    pub(super) constraints: Vec<String>,
+    pub(super) constraints: Vec<Constraint>,
+}
+
+/// A single constraint translated into c++
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Constraint {
+    /// The constraint as it was written in the data model
+    pub(super) source: String,
+    /// The c++ expression which must be true, the value is denoted x
+    pub(super) code: String,
+    /// The c++ string literals of the regular expressions the expression
+    /// uses, they are compiled once into static variables named pattern_n
+    /// where n counts the patterns of all the constraints of the type
+    pub(super) patterns: Vec<String>,
 }
 
 impl ConstrainedType {
@@ -16,13 +30,61 @@ impl ConstrainedType {
     /// # Parameters
     ///
     /// data: The generic variant to convert
-    pub(super) fn new(data: crate::ConstrainedType) -> Result<Self, Error> {
+    ///
+    /// data_types: All the generic data types of the data model, used to find
+    /// the types of the values in the constraints
+    pub(super) fn new(
+        data: crate::ConstrainedType,
+        data_types: &[crate::DataType],
+    ) -> Result<Self, Error> {
+        let context = constraint::Context::new(&data.data_type, data_types);
+        let mut pattern_count = 0;
+        let constraints = data
+            .constraints
+            .iter()
+            .map(|constraint| {
+                let mut patterns = vec![];
+                let code = get_expression(
+                    constraint.expression(),
+                    &context,
+                    pattern_count,
+                    &mut patterns,
+                );
+                pattern_count += patterns.len();
+                return Constraint {
+                    source: constraint.to_string(),
+                    code,
+                    patterns,
+                };
+            })
+            .collect();
+
         return Ok(Self {
             data_type: data.data_type,
-            constraints: data.constraints,
+            constraints,
         });
     }
 
+    /// Checks if any of the constraints uses std::regex such that <regex> must
+    /// be included
+    pub(super) fn uses_regex(&self) -> bool {
+        return self
+            .constraints
+            .iter()
+            .any(|constraint| !constraint.patterns.is_empty());
+    }
+
+    /// Gets the names of all the types which must be defined before this
+    /// constrained type
+    pub(super) fn get_dependencies(&self) -> Vec<&str> {
+        return vec![self.data_type.as_str()];
+    }
+
+    /// Gets the names of all the types this constrained type references
+    pub(super) fn get_references(&self) -> Vec<&str> {
+        return vec![self.data_type.as_str()];
+    }
+
     /// Converts the variant to a string for use in the header file
     ///
     /// # Parameters
@@ -43,7 +105,7 @@ impl ConstrainedType {
             .constraints
             .iter()
             .map(|constraint| {
-                return format!("\n{0:indent$} * - {constraint}", "");
+                return format!("\n{0:indent$} * - {source}", "", source = constraint.source);
             })
             .collect::<Vec<String>>()
             .join("");
@@ -148,13 +210,34 @@ impl ConstrainedType {
                 self.data_type.clone()
             };
 
+        // Compile the regular expressions only once
+        let patterns = self
+            .constraints
+            .iter()
+            .flat_map(|constraint| constraint.patterns.iter())
+            .enumerate()
+            .map(|(index, pattern)| {
+                format!(
+                    "{0:indent$}static const std::regex pattern_{index}({pattern});\n",
+                    ""
+                )
+            })
+            .collect::<String>();
+        let patterns = if patterns.is_empty() {
+            patterns
+        } else {
+            format!("{patterns}\n")
+        };
+
         // Create the tests
         let tests = self.constraints.iter()
             .map(|constraint| formatdoc!("
-                {0:indent$}if (!({constraint})) {{
-                {0:indent$}{0:indent$}return termite::Result<termite::Empty>::err(termite::Error(\"Did not pass constraint: {constraint}\"));
+                {0:indent$}if (!({code})) {{
+                {0:indent$}{0:indent$}return termite::Result<termite::Empty>::err(termite::Error({message}));
                 {0:indent$}}}\n\n",
                 "",
+                code = constraint.code,
+                message = get_string_literal(&format!("Did not pass constraint: {}", constraint.source)),
             ))
             .collect::<Vec<String>>()
             .join("");
@@ -195,7 +278,7 @@ impl ConstrainedType {
             }}
 
             [[nodiscard]] termite::Result<termite::Empty> {name}::validate(const {data_type} &{param_name}) {{
-            {tests}{0:indent$}return termite::Result<termite::Empty>::ok(termite::Empty());
+            {patterns}{tests}{0:indent$}return termite::Result<termite::Empty>::ok(termite::Empty());
             }}",
             "",
         );
@@ -220,7 +303,10 @@ impl ConstrainedType {
         return formatdoc!(
             "
             template<>
-            [[nodiscard]] Result<{typename}> Node::to_value<{typename}>() const;
+            struct has_to_value_all<{typename}> : std::true_type {{}};
+
+            template<>
+            [[nodiscard]] Result<{typename}, Errors> Node::to_value_all<{typename}>() const;
 
             template<>
             [[nodiscard]] Node Node::from_value<{typename}>(const {typename} &value);",
@@ -266,13 +352,17 @@ impl ConstrainedType {
         return formatdoc!(
             "
             template<>
-            [[nodiscard]] Result<{typename}> Node::to_value<{typename}>() const {{
-            {0:indent$}Result<{data_type}> value = to_value<{data_type}>();
+            [[nodiscard]] Result<{typename}, Errors> Node::to_value_all<{typename}>() const {{
+            {0:indent$}Result<{data_type}, Errors> value = to_value_all<{data_type}>();
             {0:indent$}if (!value.is_ok()) {{
-            {0:indent$}{0:indent$}return Result<{typename}>::err(Error(value.get_err()));
+            {0:indent$}{0:indent$}return Result<{typename}, Errors>::err(value.get_err());
             {0:indent$}}}
 
-            {0:indent$}return {typename}::from_value(value.get_ok());
+            {0:indent$}Result<{typename}> result = {typename}::from_value(value.get_ok());
+            {0:indent$}if (!result.is_ok()) {{
+            {0:indent$}{0:indent$}return Result<{typename}, Errors>::err(Errors(result.get_err().set_mark(mark_)));
+            {0:indent$}}}
+            {0:indent$}return Result<{typename}, Errors>::ok(result.get_ok());
             }}
 
             template<>
@@ -284,6 +374,133 @@ impl ConstrainedType {
     }
 }
 
+/// Converts an expression of the constraint language into c++, the value is
+/// denoted x and constrained types are unwrapped using get()
+///
+/// # Parameters
+///
+/// expression: The expression to convert
+///
+/// context: The types of the values in the expression
+///
+/// first_pattern: The index of the first pattern of this expression
+///
+/// patterns: The string literals of the regular expressions used so far, the
+/// patterns of this expression are added
+fn get_expression(
+    expression: &Expression,
+    context: &constraint::Context,
+    first_pattern: usize,
+    patterns: &mut Vec<String>,
+) -> String {
+    // Converts an operand and puts it in parentheses if needed
+    let operand = |operand: &Expression,
+                   precedence: u8,
+                   strict: bool,
+                   patterns: &mut Vec<String>|
+     -> String {
+        return parenthesize(
+            get_expression(operand, context, first_pattern, patterns),
+            operand.precedence(),
+            precedence,
+            strict,
+        );
+    };
+
+    return match expression {
+        Expression::Value | Expression::Field(..) => {
+            let value = match expression {
+                Expression::Field(value, name) => {
+                    format!("{}.{name}", operand(value, 7, false, patterns))
+                }
+                _ => "x".to_string(),
+            };
+            let depth = context
+                .get_type_name(expression)
+                .map_or(0, |data_type| context.get_constrained_depth(data_type));
+            format!("{value}{}", ".get()".repeat(depth))
+        }
+        Expression::Number(value) => value.clone(),
+        Expression::String(value) => get_string_literal(value),
+        Expression::Boolean(value) => value.to_string(),
+        Expression::Length(value) if context.get_kind(value) == Kind::String => {
+            let value = get_expression(value, context, first_pattern, patterns);
+            format!("termite::utf8_length({value})")
+        }
+        Expression::Length(value) => {
+            // Arrays and maps keep their elements in values
+            let values = match context.get_kind(value) {
+                Kind::Array | Kind::Map => ".values",
+                _ => "",
+            };
+            format!(
+                "static_cast<termite::integer>({}{values}.size())",
+                operand(value, 7, false, patterns)
+            )
+        }
+        Expression::Matches(value, pattern) => {
+            let value = get_expression(value, context, first_pattern, patterns);
+            patterns.push(get_string_literal(pattern));
+            format!(
+                "std::regex_search({value}, pattern_{})",
+                first_pattern + patterns.len() - 1
+            )
+        }
+        Expression::In(value, set) => format!(
+            "({})",
+            set.iter()
+                .map(|element| format!(
+                    "{} == {}",
+                    operand(value, Operator::Equal.precedence(), true, patterns),
+                    operand(element, Operator::Equal.precedence(), true, patterns)
+                ))
+                .collect::<Vec<String>>()
+                .join(" || ")
+        ),
+        Expression::Not(value) => format!("!{}", operand(value, 6, false, patterns)),
+        Expression::Negate(value) => format!("-{}", operand(value, 6, false, patterns)),
+        Expression::Binary(lhs, operator, rhs) => {
+            let precedence = operator.precedence();
+            let mut lhs_code = operand(lhs, precedence, operator.is_comparison(), patterns);
+
+            // Division is always done in floating point
+            if *operator == Operator::Divide
+                && context.get_kind(lhs) == Kind::Integer
+                && context.get_kind(rhs) == Kind::Integer
+            {
+                lhs_code = format!("static_cast<termite::number>({lhs_code})");
+            }
+
+            format!(
+                "{lhs_code} {} {}",
+                operator.symbol(),
+                operand(rhs, precedence, true, patterns)
+            )
+        }
+    };
+}
+
+/// Converts a string into a c++ string literal
+///
+/// # Parameters
+///
+/// value: The string to convert
+fn get_string_literal(value: &str) -> String {
+    let escaped = value
+        .chars()
+        .map(|c| match c {
+            '"' => "\\\"".to_string(),
+            '\\' => "\\\\".to_string(),
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            '\t' => "\\t".to_string(),
+            _ => c.to_string(),
+        })
+        .collect::<String>();
+
+    return format!("\"{escaped}\"");
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -296,6 +513,7 @@ mod tests {
 
         // Make sure it generates the correct code
         let data_model = DataModel {
+            imports: vec![],
             headers: Headers {
                 header: "".to_string(),
                 source: "".to_string(),
@@ -323,7 +541,7 @@ mod tests {
                 },
             ],
             namespace: vec!["test".to_string()],
-            macros: HashMap::new(),
+            macros: IndexMap::new(),
         };
 
         // Create the header file
@@ -346,6 +564,7 @@ mod tests {
 
         // Make sure it generates the correct code
         let data_model = DataModel {
+            imports: vec![],
             headers: Headers {
                 header: "".to_string(),
                 source: "".to_string(),
@@ -360,7 +579,18 @@ mod tests {
                     description: None,
                     data: DataTypeData::ConstrainedType(ConstrainedType {
                         data_type: "int".to_string(),
-                        constraints: vec!["x > 0".to_string(), "x % 2 == 0".to_string()],
+                        constraints: vec![
+                            Constraint {
+                                source: "x > 0".to_string(),
+                                code: "x > 0".to_string(),
+                                patterns: vec![],
+                            },
+                            Constraint {
+                                source: "x % 2 == 0".to_string(),
+                                code: "x % 2 == 0".to_string(),
+                                patterns: vec![],
+                            },
+                        ],
                     }),
                 },
                 DataType {
@@ -368,12 +598,16 @@ mod tests {
                     description: None,
                     data: DataTypeData::ConstrainedType(ConstrainedType {
                         data_type: "float".to_string(),
-                        constraints: vec!["std::abs(x) < 1e-9".to_string()],
+                        constraints: vec![Constraint {
+                            source: "x < 1e-9 && x > -1e-9".to_string(),
+                            code: "x < 1e-9 && x > -1e-9".to_string(),
+                            patterns: vec![],
+                        }],
                     }),
                 },
             ],
             namespace: vec!["test".to_string()],
-            macros: HashMap::new(),
+            macros: IndexMap::new(),
         };
 
         // Create the header file
@@ -390,4 +624,70 @@ mod tests {
         assert_eq!(str_diff(&header_file, &expected_header), None);
         assert_eq!(str_diff(&source_file, &expected_source), None);
     }
+
+    #[test]
+    fn matches() {
+        // Check c++ code
+        compile_and_test("type_constrained/matches");
+
+        // Make sure it generates the correct code
+        let data_model = DataModel::new(
+            crate::DataModel::import_yaml(include_str!(
+                "../../tests/cpp/type_constrained/matches/matches.yaml"
+            ))
+            .unwrap(),
+        )
+        .unwrap();
+
+        // Create the header file
+        let header_file = data_model.get_header("HEADER", 2).unwrap();
+        let source_file = data_model.get_source("matches", 2).unwrap();
+        let expected_header = include_str!("../../tests/cpp/type_constrained/matches/matches.h");
+        let expected_source = include_str!("../../tests/cpp/type_constrained/matches/matches.cpp");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&header_file, &expected_header), None);
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn length() {
+        let model = crate::DataModel::import_yaml(indoc::indoc! {"
+            data_types:
+            - name: Name
+              data: !ConstrainedType
+                data_type: string
+                constraints:
+                - len(x) <= 3
+            - name: Names
+              data: !Array
+                data_type: Name
+            - name: ShortNames
+              data: !ConstrainedType
+                data_type: Names
+                constraints:
+                - len(x) <= 3
+        "})
+        .unwrap();
+
+        // Strings count characters and arrays count elements
+        let codes = [
+            "termite::utf8_length(x) <= 3",
+            "static_cast<termite::integer>(x.values.size()) <= 3",
+        ];
+        for (name, code) in ["Name", "ShortNames"].into_iter().zip(codes) {
+            let data = model
+                .data_types
+                .iter()
+                .find_map(|data_type| match &data_type.data {
+                    crate::DataTypeData::ConstrainedType(data) if data_type.name == name => {
+                        Some(data.clone())
+                    }
+                    _ => None,
+                })
+                .unwrap();
+            let data = ConstrainedType::new(data, &model.data_types).unwrap();
+            assert_eq!(data.constraints[0].code, code);
+        }
+    }
 }
@@ -25,6 +25,39 @@ impl Enum {
         return Ok(Self { types });
     }
 
+    /// Gets the names of all the types which must be defined before this enum
+    pub(super) fn get_dependencies(&self) -> Vec<&str> {
+        return self
+            .types
+            .iter()
+            .filter(|enum_type| !enum_type.boxed)
+            .filter_map(|enum_type| enum_type.data_type.as_deref())
+            .collect();
+    }
+
+    /// Gets the names of all the types this enum references
+    pub(super) fn get_references(&self) -> Vec<&str> {
+        return self
+            .types
+            .iter()
+            .filter_map(|enum_type| enum_type.data_type.as_deref())
+            .collect();
+    }
+
+    /// Stores all values of the given types in a termite::Box
+    ///
+    /// # Parameters
+    ///
+    /// recursive_types: The types which depend on this enum
+    pub(super) fn box_references(&mut self, recursive_types: &HashSet<&str>) {
+        for enum_type in self.types.iter_mut() {
+            enum_type.boxed = match &enum_type.data_type {
+                Some(data_type) => recursive_types.contains(data_type.as_str()),
+                None => false,
+            };
+        }
+    }
+
     /// Converts the enum to a string for use in the header file
     ///
     /// # Parameters
@@ -185,11 +218,14 @@ impl Enum {
 
         return formatdoc!(
             "
+            template<>
+            struct has_to_value_all<{typename}> : std::true_type {{}};
+
             template<>
             [[nodiscard]] Result<{typename}> Node::Value::to_value<{typename}>() const;
             
             template<>
-            [[nodiscard]] Result<{typename}> Node::Map::to_value<{typename}>() const;
+            [[nodiscard]] Result<{typename}, Errors> Node::Map::to_value_all<{typename}>() const;
 
             template<>
             [[nodiscard]] Node Node::from_value<{typename}>(const {typename} &value);",
@@ -257,18 +293,18 @@ impl Enum {
             }}
             
             template<>
-            [[nodiscard]] Result<{typename}> Node::Map::to_value<{typename}>() const {{
+            [[nodiscard]] Result<{typename}, Errors> Node::Map::to_value_all<{typename}>() const {{
             {0:indent$}if (map_.size() != 1) {{
             {0:indent$}{0:indent$}std::stringstream ss;
             {0:indent$}{0:indent$}ss << \"There must be exactly one enum type specified but received \" << map_.size();
-            {0:indent$}{0:indent$}return Result<{typename}>::err(Error(ss.str()));
+            {0:indent$}{0:indent$}return Result<{typename}, Errors>::err(Errors(Error(ss.str())));
             {0:indent$}}}
 
             {map_parsers}
 
             {0:indent$}std::stringstream ss;
             {0:indent$}ss << \"Unknown enum type \\\"\" << map_.cbegin()->first << \"\\\"\";
-            {0:indent$}return Result<{typename}>::err(Error(ss.str()));
+            {0:indent$}return Result<{typename}, Errors>::err(Errors(Error(ss.str())));
             }}
 
             template<>
@@ -293,6 +329,9 @@ pub(super) struct EnumType {
     pub(super) description: Option<String>,
     /// The type for this enum type, may be omitted for an empty type
     pub(super) data_type: Option<String>,
+    /// True if the value is stored in a termite::Box because its type
+    /// depends on the enum
+    pub(super) boxed: bool,
 }
 
 impl EnumType {
@@ -306,6 +345,7 @@ impl EnumType {
             name: data.name,
             description: data.description,
             data_type: data.data_type,
+            boxed: false,
         });
     }
 
@@ -348,6 +388,8 @@ impl EnumType {
                 let data_type =
                     if ["string", "number", "integer", "boolean"].contains(&data_type.as_str()) {
                         format!("termite::{data_type}")
+                    } else if self.boxed {
+                        format!("termite::Box<{data_type}>")
                     } else {
                         data_type.clone()
                     };
@@ -530,23 +572,25 @@ impl EnumType {
         let internal = match &self.data_type {
             Some(data_type) => {
                 // Add possible namespace to the typename
-                let data_type = if let Some(_) = data_types.iter().find(|new_data_type| &new_data_type.name == data_type) {
+                let data_type = if self.boxed {
+                    format!("Box<{namespace}{data_type}>")
+                } else if let Some(_) = data_types.iter().find(|new_data_type| &new_data_type.name == data_type) {
                     format!("{namespace}{data_type}")
                 } else {
                     format!("{data_type}")
                 };
 
                 formatdoc!("
-                    {0:indent$}{0:indent$}Result<{data_type}> value = map_.cbegin()->second.to_value<{data_type}>();
+                    {0:indent$}{0:indent$}Result<{data_type}, Errors> value = map_.cbegin()->second.to_value_all<{data_type}>();
                     {0:indent$}{0:indent$}if (value.is_ok()) {{
-                    {0:indent$}{0:indent$}{0:indent$}return Result<{typename}>::ok({typename}({typename}::Type{name}{{value.get_ok()}}));
+                    {0:indent$}{0:indent$}{0:indent$}return Result<{typename}, Errors>::ok({typename}({typename}::Type{name}{{value.get_ok()}}));
                     {0:indent$}{0:indent$}}}
-                    {0:indent$}{0:indent$}return Result<{typename}>::err(value.get_err().add_field(\"{name}\"));",
+                    {0:indent$}{0:indent$}return Result<{typename}, Errors>::err(value.get_err().add_field(\"{name}\"));",
                     "",
                     name = self.name,
                 )
             },
-            None => format!("{0:indent$}{0:indent$}return Result<{typename}>::err(Error(\"Enum type {name} must not include values\"));", "", name = self.name),
+            None => format!("{0:indent$}{0:indent$}return Result<{typename}, Errors>::err(Errors(Error(\"Enum type {name} must not include values\")));", "", name = self.name),
         };
 
         return formatdoc!(
@@ -607,6 +651,7 @@ mod tests {
 
         // Make sure it generates the correct code
         let data_model = DataModel {
+            imports: vec![],
             headers: Headers {
                 header: "".to_string(),
                 source: "".to_string(),
@@ -624,27 +669,31 @@ mod tests {
                             name: "Int1".to_string(),
                             description: Some("An integer".to_string()),
                             data_type: Some("int".to_string()),
+                            boxed: false,
                         },
                         EnumType {
                             name: "Int2".to_string(),
                             description: Some("Another integer".to_string()),
                             data_type: Some("int".to_string()),
+                            boxed: false,
                         },
                         EnumType {
                             name: "Float".to_string(),
                             description: None,
                             data_type: Some("float".to_string()),
+                            boxed: false,
                         },
                         EnumType {
                             name: "Empty".to_string(),
                             description: Some("Nothing".to_string()),
                             data_type: None,
+                            boxed: false,
                         },
                     ],
                 }),
             }],
             namespace: vec!["test".to_string()],
-            macros: HashMap::new(),
+            macros: IndexMap::new(),
         };
 
         // Create the header file
@@ -0,0 +1,380 @@
+use super::*;
+use indoc::formatdoc;
+
+/// The type specific information for a map
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Map {
+    /// The data type for all values of the map
+    pub(super) data_type: String,
+    /// The type all keys must be valid as, any key is allowed if not given
+    pub(super) key_type: Option<String>,
+    /// True if the values are stored in a termite::Box because their type
+    /// depends on the map
+    pub(super) boxed: bool,
+}
+
+impl Map {
+    /// Constructs a new c++ map from a generic map
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic map to convert
+    pub(super) fn new(data: crate::Map) -> Result<Self, Error> {
+        return Ok(Self {
+            data_type: data.data_type,
+            key_type: data.key_type,
+            boxed: false,
+        });
+    }
+
+    /// Gets the names of all the types which must be defined before this map
+    pub(super) fn get_dependencies(&self) -> Vec<&str> {
+        return if self.boxed {
+            vec![]
+        } else {
+            vec![self.data_type.as_str()]
+        };
+    }
+
+    /// Gets the names of all the types this map references
+    pub(super) fn get_references(&self) -> Vec<&str> {
+        return vec![self.data_type.as_str()];
+    }
+
+    /// Stores the values in a termite::Box if they are of one of the given
+    /// types
+    ///
+    /// # Parameters
+    ///
+    /// recursive_types: The types which depend on this map
+    pub(super) fn box_references(&mut self, recursive_types: &HashSet<&str>) {
+        self.boxed = recursive_types.contains(self.data_type.as_str());
+    }
+
+    /// Converts the map to a string for use in the header file
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the map
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_definition_header(&self, name: &str, indent: usize) -> String {
+        let data_type =
+            if ["string", "number", "integer", "boolean"].contains(&self.data_type.as_str()) {
+                format!("termite::{data_type}", data_type = self.data_type)
+            } else if self.boxed {
+                format!("termite::Box<{data_type}>", data_type = self.data_type)
+            } else {
+                self.data_type.clone()
+            };
+
+        return formatdoc!("
+            struct {name} {{
+            public:
+            {0:indent$}/**
+            {0:indent$} * @brief Constructs a new {name} object
+            {0:indent$} * 
+            {0:indent$} * @param values The values of the map
+            {0:indent$} */
+            {0:indent$}explicit {name}(std::map<std::string, {data_type}> values) : values(std::move(values)) {{}}
+
+            {0:indent$}/**
+            {0:indent$} * @brief Checks if this object and the other object are identical
+            {0:indent$} * 
+            {0:indent$} * @param x The other object to compare with
+            {0:indent$} * @return true if they are identical, false if not
+            {0:indent$} */
+            {0:indent$}[[nodiscard]] bool operator==(const {name} &x) const;
+            {0:indent$}/**
+            {0:indent$} * @brief Checks if this object and the other object are different
+            {0:indent$} * 
+            {0:indent$} * @param x The other object to compare with
+            {0:indent$} * @return true if they are different, false if not
+            {0:indent$} */
+            {0:indent$}[[nodiscard]] bool operator!=(const {name} &x) const {{
+            {0:indent$}{0:indent$}return !(*this == x);
+            {0:indent$}}}
+            {0:indent$}/**
+            {0:indent$} * @brief Prints the object onto the output stream
+            {0:indent$} * 
+            {0:indent$} * @param os The output stream to print to
+            {0:indent$} * @param x The object to print
+            {0:indent$} * @return The output stream
+            {0:indent$} */
+            {0:indent$}friend std::ostream &operator<<(std::ostream &os, const {name} &x);
+
+            {0:indent$}/**
+            {0:indent$} * @brief The values of the map
+            {0:indent$} * 
+            {0:indent$} */
+            {0:indent$}std::map<std::string, {data_type}> values;
+            }};",
+            "",
+        );
+    }
+
+    /// Converts the map to a string for use in the source file
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the map
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_definition_source(&self, name: &str, indent: usize) -> String {
+        return formatdoc!("
+            bool {name}::operator==(const {name} &x) const {{
+            {0:indent$}return values == x.values;
+            }}
+
+            std::ostream &operator<<(std::ostream &os, const {name} &x) {{
+            {0:indent$}os << \"{{ values: {{ \";
+            {0:indent$}for (auto value = x.values.cbegin(); value != x.values.cend(); ++value) {{
+            {0:indent$}{0:indent$}if (value != x.values.cbegin()) {{
+            {0:indent$}{0:indent$}{0:indent$}os << \", \";
+            {0:indent$}{0:indent$}}}
+            {0:indent$}{0:indent$}os << \"\\\"\" << value->first << \"\\\": \" << value->second;
+            {0:indent$}}}
+            {0:indent$}return os << \" }} }}\";
+            }}",
+            "",
+        );
+    }
+
+    /// Gets the header code for the parser for this map allowing it to be read from a file
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the map
+    ///
+    /// namespace: The namespace of the map
+    pub(super) fn get_parser_header(&self, name: &str, namespace: &[String]) -> String {
+        // Get the namespace name
+        let namespace = namespace
+            .iter()
+            .map(|single_name| format!("{single_name}::"))
+            .collect::<Vec<String>>()
+            .join("");
+        let typename = format!("{namespace}{name}");
+
+        return formatdoc!(
+            "
+            template<>
+            struct has_to_value_all<{typename}> : std::true_type {{}};
+
+            template<>
+            [[nodiscard]] Result<{typename}, Errors> Node::Map::to_value_all<{typename}>() const;
+
+            template<>
+            [[nodiscard]] Node Node::from_value<{typename}>(const {typename} &value);",
+        );
+    }
+
+    /// Gets the source code for the parser for this map allowing it to be read from a file
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the map
+    ///
+    /// indent: The number of spaces to use for indentation
+    ///
+    /// namespace: The namespace of the map
+    ///
+    /// data_types: List of all the data types defined in the data model
+    pub(super) fn get_parser_source(
+        &self,
+        name: &str,
+        indent: usize,
+        namespace: &[String],
+        data_types: &[DataType],
+    ) -> String {
+        // Get the namespace name
+        let namespace = namespace
+            .iter()
+            .map(|single_name| format!("{single_name}::"))
+            .collect::<Vec<String>>()
+            .join("");
+        let typename = format!("{namespace}{name}");
+
+        // Add possible namespace to the typename
+        let data_type = if let Some(_) = data_types
+            .iter()
+            .find(|data_type| data_type.name == self.data_type)
+        {
+            format!("{namespace}{data_type}", data_type = self.data_type)
+        } else {
+            format!("{data_type}", data_type = self.data_type)
+        };
+        let data_type = if self.boxed {
+            format!("Box<{data_type}>")
+        } else {
+            data_type
+        };
+
+        // Get the validation of the keys, any string is a valid string so it is skipped
+        let key_check = match &self.key_type {
+            Some(key_type) if key_type != "string" => {
+                let key_type = if let Some(_) = data_types
+                    .iter()
+                    .find(|data_type| &data_type.name == key_type)
+                {
+                    format!("{namespace}{key_type}")
+                } else {
+                    format!("{key_type}")
+                };
+
+                formatdoc!("
+                    {0:indent$}{0:indent$}Result<{key_type}, Errors> key = Node(Node::Value(node->first)).to_value_all<{key_type}>();
+                    {0:indent$}{0:indent$}if (!key.is_ok()) {{
+                    {0:indent$}{0:indent$}{0:indent$}errors.append(key.get_err().add_field(node->first));
+                    {0:indent$}{0:indent$}}}
+                    ",
+                    "",
+                )
+            }
+            _ => "".to_string(),
+        };
+
+        return formatdoc!(
+            "
+            template<>
+            [[nodiscard]] Result<{typename}, Errors> Node::Map::to_value_all<{typename}>() const {{
+            {0:indent$}std::map<std::string, {data_type}> values;
+            {0:indent$}Errors errors;
+            {0:indent$}for (auto node = map_.cbegin(); node != map_.cend(); ++node) {{
+            {key_check}{0:indent$}{0:indent$}Result<{data_type}, Errors> value = node->second.to_value_all<{data_type}>();
+            {0:indent$}{0:indent$}if (value.is_ok()) {{
+            {0:indent$}{0:indent$}{0:indent$}values.insert({{node->first, std::move(value.get_ok())}});
+            {0:indent$}{0:indent$}}} else {{
+            {0:indent$}{0:indent$}{0:indent$}errors.append(value.get_err().add_field(node->first));
+            {0:indent$}{0:indent$}}}
+            {0:indent$}}}
+            {0:indent$}if (!errors.empty()) {{
+            {0:indent$}{0:indent$}return Result<{typename}, Errors>::err(std::move(errors));
+            {0:indent$}}}
+
+            {0:indent$}return Result<{typename}, Errors>::ok({typename}(std::move(values)));
+            }}
+
+            template<>
+            [[nodiscard]] Node Node::from_value<{typename}>(const {typename} &value) {{
+            {0:indent$}std::map<std::string, Node> map;
+            {0:indent$}for (auto node = value.values.cbegin(); node != value.values.cend(); ++node) {{
+            {0:indent$}{0:indent$}map.insert({{node->first, Node::from_value(node->second)}});
+            {0:indent$}}}
+            {0:indent$}return Node(Node::Map(std::move(map)));
+            }}",
+            "",
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpp::test_utils::*;
+
+    #[test]
+    fn basic() {
+        // Check c++ code
+        compile_and_test("type_map/basic");
+
+        // Make sure it generates the correct code
+        let data_model = DataModel {
+            imports: vec![],
+            headers: Headers {
+                header: "".to_string(),
+                source: "".to_string(),
+            },
+            footers: Footers {
+                header: "".to_string(),
+                source: "".to_string(),
+            },
+            data_types: vec![
+                DataType {
+                    name: "DataType1".to_string(),
+                    description: None,
+                    data: DataTypeData::Map(Map {
+                        data_type: "int".to_string(),
+                        key_type: None,
+                        boxed: false,
+                    }),
+                },
+                DataType {
+                    name: "DataType2".to_string(),
+                    description: None,
+                    data: DataTypeData::Map(Map {
+                        data_type: "float".to_string(),
+                        key_type: Some("string".to_string()),
+                        boxed: false,
+                    }),
+                },
+            ],
+            namespace: vec!["test".to_string()],
+            macros: IndexMap::new(),
+        };
+
+        // Create the header file
+        let header_file = data_model.get_header("HEADER", 2).unwrap();
+        let source_file = data_model.get_source("basic", 2).unwrap();
+        let expected_header = include_str!("../../tests/cpp/type_map/basic/basic.h");
+        let expected_source = include_str!("../../tests/cpp/type_map/basic/basic.cpp");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&header_file, &expected_header), None);
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn key_type() {
+        // Check c++ code
+        compile_and_test("type_map/key_type");
+
+        // Make sure it generates the correct code
+        let data_model = DataModel {
+            imports: vec![],
+            headers: Headers {
+                header: "".to_string(),
+                source: "".to_string(),
+            },
+            footers: Footers {
+                header: "".to_string(),
+                source: "".to_string(),
+            },
+            data_types: vec![
+                DataType {
+                    name: "Key".to_string(),
+                    description: None,
+                    data: DataTypeData::ConstrainedType(ConstrainedType {
+                        data_type: "string".to_string(),
+                        constraints: vec![type_constrained::Constraint {
+                            source: "len(x) == 2".to_string(),
+                            code: "termite::utf8_length(x) == 2".to_string(),
+                            patterns: vec![],
+                        }],
+                    }),
+                },
+                DataType {
+                    name: "DataType".to_string(),
+                    description: None,
+                    data: DataTypeData::Map(Map {
+                        data_type: "integer".to_string(),
+                        key_type: Some("Key".to_string()),
+                        boxed: false,
+                    }),
+                },
+            ],
+            namespace: vec!["test".to_string()],
+            macros: IndexMap::new(),
+        };
+
+        // Create the header file
+        let header_file = data_model.get_header("HEADER", 2).unwrap();
+        let source_file = data_model.get_source("key_type", 2).unwrap();
+        let expected_header = include_str!("../../tests/cpp/type_map/key_type/key_type.h");
+        let expected_source = include_str!("../../tests/cpp/type_map/key_type/key_type.cpp");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&header_file, &expected_header), None);
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
@@ -9,6 +9,9 @@ use indoc::formatdoc;
 pub(super) struct Struct {
     /// A list of all the fields of the struct
     pub(super) fields: Vec<StructField>,
+    /// What to do with unknown fields when parsing, they are only stored in
+    /// extra_fields when collected
+    pub(super) unknown_fields: crate::UnknownFields,
 }
 
 impl Struct {
@@ -17,16 +20,65 @@ impl Struct {
     /// # Parameters
     ///
     /// data: The generic struct to convert
-    pub(super) fn new(data: crate::Struct) -> Result<Self, Error> {
-        // Convert the fields
-        let fields = data
-            .fields
+    ///
+    /// data_types: All the generic data types of the data model, used to
+    /// find the fields of inherited structs
+    pub(super) fn new(data: crate::Struct, data_types: &[crate::DataType]) -> Result<Self, Error> {
+        // Get all the inherited fields, the fields of the base struct are first,
+        // the data model is validated so the inheritance chain is known to be
+        // valid
+        let inherited_fields = match &data.inherit {
+            Some(inherit) => get_inherited_fields(inherit, data_types),
+            None => vec![],
+        };
+
+        // Convert the fields, inherited fields are located at the inherit
+        // field
+        let fields = inherited_fields
             .into_iter()
-            .map(|data| StructField::new(data))
+            .map(|field| (field, Location::default().with_field("inherit")))
+            .chain(data.fields.into_iter().enumerate().map(|(i, field)| {
+                return (field, Location::default().with_field("fields").with_element(i));
+            }))
+            .map(|(data, location)| StructField::new(data, location))
             .collect::<Result<Vec<StructField>, Error>>()?;
 
         // Move data
-        return Ok(Self { fields });
+        return Ok(Self {
+            fields,
+            unknown_fields: data.unknown_fields.unwrap_or_default(),
+        });
+    }
+
+    /// Gets the names of all the types which must be defined before this
+    /// struct
+    pub(super) fn get_dependencies(&self) -> Vec<&str> {
+        return self
+            .fields
+            .iter()
+            .filter(|field| !field.boxed)
+            .map(|field| field.data_type.as_str())
+            .collect();
+    }
+
+    /// Gets the names of all the types this struct references
+    pub(super) fn get_references(&self) -> Vec<&str> {
+        return self
+            .fields
+            .iter()
+            .map(|field| field.data_type.as_str())
+            .collect();
+    }
+
+    /// Stores all fields of the given types in a termite::Box
+    ///
+    /// # Parameters
+    ///
+    /// recursive_types: The types which depend on this struct
+    pub(super) fn box_references(&mut self, recursive_types: &HashSet<&str>) {
+        for field in self.fields.iter_mut() {
+            field.boxed = recursive_types.contains(field.data_type.as_str());
+        }
     }
 
     /// Converts the struct to a string for use in the header file
@@ -78,6 +130,40 @@ impl Struct {
             .collect::<Vec<String>>()
             .join("");
 
+        // The extra fields are only stored if unknown fields are collected
+        let (constructor, extra_fields_definition) = if self.unknown_fields.is_collect() {
+            (
+                formatdoc!("
+                    {0:indent$} * @param extra_fields Any extra fields to attach to this struct
+                    {0:indent$} */
+                    {0:indent$}explicit {name}({constructor_parameters}::termite::Node::Map extra_fields = ::termite::Node::Map()) : {constructor_setters}extra_fields(std::move(extra_fields)) {{}}",
+                    "",
+                ),
+                formatdoc!("
+                    \n{0:indent$}/**
+                    {0:indent$} * @brief All extra fields from when reading which could not be captured
+                    {0:indent$} * 
+                    {0:indent$} */
+                    {0:indent$}::termite::Node::Map extra_fields;",
+                    "",
+                ),
+            )
+        } else {
+            let constructor_setters = match constructor_setters.strip_suffix(", ") {
+                Some(constructor_setters) => format!(" : {constructor_setters}"),
+                None => "".to_string(),
+            };
+            (
+                formatdoc!("
+                    {0:indent$} */
+                    {0:indent$}explicit {name}({constructor_parameters}){constructor_setters} {{}}",
+                    "",
+                    constructor_parameters = constructor_parameters.trim_end_matches(", "),
+                ),
+                "".to_string(),
+            )
+        };
+
         // Generate the code
         return formatdoc!("
             struct {name} {{
@@ -85,9 +171,7 @@ impl Struct {
             {0:indent$}/**
             {0:indent$} * @brief Constructs a new {name} object
             {0:indent$} * {constructor_description}
-            {0:indent$} * @param extra_fields Any extra fields to attach to this struct
-            {0:indent$} */
-            {0:indent$}explicit {name}({constructor_parameters}::termite::Node::Map extra_fields = ::termite::Node::Map()) : {constructor_setters}extra_fields(std::move(extra_fields)) {{}}
+            {constructor}
             {default_constructors}
             {0:indent$}/**
             {0:indent$} * @brief Checks if this object and the other object are identical
@@ -113,12 +197,7 @@ impl Struct {
             {0:indent$} * @return The output stream
             {0:indent$} */
             {0:indent$}friend std::ostream &operator<<(std::ostream &os, const {name} &x);
-            {field_definitions}
-            {0:indent$}/**
-            {0:indent$} * @brief All extra fields from when reading which could not be captured
-            {0:indent$} * 
-            {0:indent$} */
-            {0:indent$}::termite::Node::Map extra_fields;
+            {field_definitions}{extra_fields_definition}
             }};", "",
         );
     }
@@ -135,7 +214,7 @@ impl Struct {
     pub(super) fn get_definition_source(
         &self,
         name: &str,
-        macros: &HashMap<String, data_model::SerializationModel>,
+        macros: &IndexMap<String, data_model::SerializationModel>,
         indent: usize,
     ) -> Result<String, Error> {
         // Get the equality test
@@ -162,14 +241,29 @@ impl Struct {
             .collect::<Result<Vec<_>, _>>()?
             .join("");
 
+        // The extra fields are only compared and printed if they are collected
+        let (equality_test, printout) = if self.unknown_fields.is_collect() {
+            (
+                format!("{equality_test}extra_fields == x.extra_fields"),
+                format!("{printout}\"extra_fields: \" << x.extra_fields << "),
+            )
+        } else {
+            let equality_test = match equality_test.strip_suffix(" && ") {
+                Some(equality_test) => equality_test.to_string(),
+                None => "true".to_string(),
+            };
+            let printout = printout.strip_suffix("\", \" << ").unwrap_or(&printout);
+            (equality_test, printout.to_string())
+        };
+
         // Generate the code
         return Ok(formatdoc!("
             [[nodiscard]] bool {name}::operator==(const {name} &x) const {{
-            {0:indent$}return {equality_test}extra_fields == x.extra_fields;
+            {0:indent$}return {equality_test};
             }}
             {default_constructors}
             std::ostream &operator<<(std::ostream &os, const {name} &x) {{
-            {0:indent$}return os << \"{{ \" << {printout}\"extra_fields: \" << x.extra_fields << \" }}\";
+            {0:indent$}return os << \"{{ \" << {printout}\" }}\";
             }}", "",
         ));
     }
@@ -193,7 +287,10 @@ impl Struct {
         return formatdoc!(
             "
             template<>
-            [[nodiscard]] Result<{typename}> Node::Map::to_value<{typename}>() const;
+            struct has_to_value_all<{typename}> : std::true_type {{}};
+
+            template<>
+            [[nodiscard]] Result<{typename}, Errors> Node::Map::to_value_all<{typename}>() const;
 
             template<>
             [[nodiscard]] Node Node::from_value<{typename}>(const {typename} &value);",
@@ -249,17 +346,65 @@ impl Struct {
             .collect::<Vec<String>>()
             .join("");
 
+        // Handle the fields which are left after parsing all known fields
+        let (unknown_check, parameter_retrievals, export_map) = match self.unknown_fields {
+            crate::UnknownFields::Collect => (
+                "".to_string(),
+                format!("{parameter_retrievals}Map(std::move(map))"),
+                "value.extra_fields.get()",
+            ),
+            crate::UnknownFields::Reject => (
+                formatdoc!("
+                    \n{0:indent$}if (!map.empty()) {{
+                    {0:indent$}{0:indent$}errors.add(Map::unknown_fields_error(map, {{{field_names}}}));
+                    {0:indent$}}}\n",
+                    "",
+                    field_names = self
+                        .fields
+                        .iter()
+                        .map(|field| format!("\"{}\"", field.name))
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
+                parameter_retrievals.trim_end_matches(", ").to_string(),
+                "",
+            ),
+            crate::UnknownFields::Ignore => (
+                "".to_string(),
+                parameter_retrievals.trim_end_matches(", ").to_string(),
+                "",
+            ),
+        };
+        let export_map = match export_map {
+            "" => "".to_string(),
+            export_map => format!(" = {export_map}"),
+        };
+
+        // Without any fields or unknown field checks there can be no errors
+        let checks = if parsing.is_empty() && unknown_check.is_empty() {
+            "".to_string()
+        } else {
+            formatdoc!("
+                {0:indent$}Errors errors;
+                {parsing}{unknown_check}
+                {0:indent$}if (!errors.empty()) {{
+                {0:indent$}{0:indent$}return Result<{typename}, Errors>::err(std::move(errors));
+                {0:indent$}}}\n",
+                "",
+            )
+        };
+
         return formatdoc!("
             template<>
-            [[nodiscard]] Result<{typename}> Node::Map::to_value<{typename}>() const {{
+            [[nodiscard]] Result<{typename}, Errors> Node::Map::to_value_all<{typename}>() const {{
             {0:indent$}std::map<std::string, Node> map = map_;
-            {parsing}
-            {0:indent$}return Result<{typename}>::ok({typename}({parameter_retrievals}Map(std::move(map))));
+            {checks}
+            {0:indent$}return Result<{typename}, Errors>::ok({typename}({parameter_retrievals}));
             }}
  
             template<>
             [[nodiscard]] Node Node::from_value<{typename}>(const {typename} &value) {{
-            {0:indent$}std::map<std::string, Node> map = value.extra_fields.get();
+            {0:indent$}std::map<std::string, Node> map{export_map};
             {parsing_export}
             {0:indent$}return Node(Node::Map(std::move(map)));
             }}",
@@ -280,6 +425,11 @@ pub(super) struct StructField {
     /// Describes if the field is required or not, if optional it gives the
     /// default value
     pub(super) default: crate::DefaultType,
+    /// True if the field is stored in a termite::Box because its type
+    /// depends on the struct
+    pub(super) boxed: bool,
+    /// The location of the field in the struct, used for errors
+    pub(super) location: Location,
 }
 
 impl StructField {
@@ -288,12 +438,16 @@ impl StructField {
     /// # Parameters
     ///
     /// data: The generic struct field to convert
-    fn new(data: crate::StructField) -> Result<Self, Error> {
+    ///
+    /// location: The location of the field in the struct
+    fn new(data: crate::StructField, location: Location) -> Result<Self, Error> {
         return Ok(Self {
             name: data.name,
             description: data.description,
             data_type: data.data_type,
             default: data.default,
+            boxed: false,
+            location,
         });
     }
 
@@ -304,6 +458,11 @@ impl StructField {
         } else {
             self.data_type.clone()
         };
+        let data_type = if self.boxed {
+            format!("termite::Box<{data_type}>")
+        } else {
+            data_type
+        };
 
         return match &self.default {
             DefaultType::Optional => {
@@ -379,7 +538,7 @@ impl StructField {
     fn get_default_constructor_source(
         &self,
         main_name: &str,
-        macros: &HashMap<String, data_model::SerializationModel>,
+        macros: &IndexMap<String, data_model::SerializationModel>,
         indent: usize,
     ) -> Result<String, Error> {
         return Ok(match &self.default {
@@ -404,7 +563,12 @@ impl StructField {
                 typename = self.get_typename(),
                 snake_case = ToSnakeCase::new(&mut self.name.chars()).collect::<String>(),
                 default_value = serialization_to_termite_node(
-                    &data_model::expand_macros(default_value, macros, &mut HashSet::new())?,
+                    &data_model::expand_macros(default_value, macros, &mut HashSet::new())
+                        .map_err(|error| {
+                            return Error::from(error)
+                                .add_field("default")
+                                .add_location(&self.location);
+                        })?,
                     indent,
                     indent
                 ),
@@ -451,8 +615,6 @@ impl StructField {
     ///
     /// # Parameters
     ///
-    /// main_name: The name of the type which holds this field including namespace
-    ///
     /// namespace: The namespace of the struct
     ///
     /// data_types: List of all the data types defined in the data model
@@ -460,7 +622,6 @@ impl StructField {
     /// indent: The indentation to use
     fn get_parsing_required(
         &self,
-        main_name: &str,
         namespace: &str,
         data_types: &[DataType],
         indent: usize,
@@ -474,20 +635,26 @@ impl StructField {
         } else {
             format!("{data_type}", data_type = self.data_type)
         };
+        let typename = if self.boxed {
+            format!("Box<{typename}>")
+        } else {
+            typename
+        };
 
         return formatdoc!("
             \n{0:indent$}auto location_{name} = map.find(\"{name}\");
+            {0:indent$}std::optional<{typename}> value_{name};
             {0:indent$}if (location_{name} == map.end()) {{
-            {0:indent$}{0:indent$}return Result<{main_name}>::err(Error(\"Missing {name}\"));
-            {0:indent$}}}
-            {0:indent$}Result<{typename}> raw_value_{name} = location_{name}->second.to_value<{typename}>();
-            {0:indent$}if (!raw_value_{name}.is_ok()) {{
-            {0:indent$}{0:indent$}Error error = raw_value_{name}.get_err();
-            {0:indent$}{0:indent$}error.add_field(\"{name}\");
-            {0:indent$}{0:indent$}return Result<{main_name}>::err(std::move(error));
-            {0:indent$}}}
-            {0:indent$}{typename} value_{name} = raw_value_{name}.get_ok();
-            {0:indent$}map.erase(location_{name});\n",
+            {0:indent$}{0:indent$}errors.add(Error(\"Missing {name}\"));
+            {0:indent$}}} else {{
+            {0:indent$}{0:indent$}Result<{typename}, Errors> raw_value_{name} = location_{name}->second.to_value_all<{typename}>();
+            {0:indent$}{0:indent$}if (raw_value_{name}.is_ok()) {{
+            {0:indent$}{0:indent$}{0:indent$}value_{name} = raw_value_{name}.get_ok();
+            {0:indent$}{0:indent$}}} else {{
+            {0:indent$}{0:indent$}{0:indent$}errors.append(raw_value_{name}.get_err().add_field(\"{name}\"));
+            {0:indent$}{0:indent$}}}
+            {0:indent$}{0:indent$}map.erase(location_{name});
+            {0:indent$}}}\n",
             "",
             name = self.name,
         );
@@ -520,6 +687,11 @@ impl StructField {
         } else {
             format!("{data_type}", data_type = self.data_type)
         };
+        let base_typename = if self.boxed {
+            format!("Box<{base_typename}>")
+        } else {
+            base_typename
+        };
 
         let typename = match &self.default {
             DefaultType::Optional => format!("std::optional<{base_typename}>"),
@@ -539,13 +711,12 @@ impl StructField {
             \n{0:indent$}auto location_{name} = map.find(\"{name}\");
             {0:indent$}{typename} value_{name}{default};
             {0:indent$}if (location_{name} != map.end()) {{
-            {0:indent$}{0:indent$}Result<{base_typename}> raw_value_{name} = location_{name}->second.to_value<{base_typename}>();
-            {0:indent$}{0:indent$}if (!raw_value_{name}.is_ok()) {{
-            {0:indent$}{0:indent$}{0:indent$}Error error = raw_value_{name}.get_err();
-            {0:indent$}{0:indent$}{0:indent$}error.add_field(\"{name}\");
-            {0:indent$}{0:indent$}{0:indent$}return Result<{main_name}>::err(std::move(error));
+            {0:indent$}{0:indent$}Result<{base_typename}, Errors> raw_value_{name} = location_{name}->second.to_value_all<{base_typename}>();
+            {0:indent$}{0:indent$}if (raw_value_{name}.is_ok()) {{
+            {0:indent$}{0:indent$}{0:indent$}value_{name} = raw_value_{name}.get_ok();
+            {0:indent$}{0:indent$}}} else {{
+            {0:indent$}{0:indent$}{0:indent$}errors.append(raw_value_{name}.get_err().add_field(\"{name}\"));
             {0:indent$}{0:indent$}}}
-            {0:indent$}{0:indent$}value_{name} = raw_value_{name}.get_ok();
             {0:indent$}{0:indent$}map.erase(location_{name});
             {0:indent$}}}\n",
             "",
@@ -572,9 +743,7 @@ impl StructField {
         indent: usize,
     ) -> String {
         return match self.default {
-            DefaultType::Required => {
-                self.get_parsing_required(main_name, namespace, data_types, indent)
-            }
+            DefaultType::Required => self.get_parsing_required(namespace, data_types, indent),
             _ => self.get_parsing_optional(main_name, namespace, data_types, indent),
         };
     }
@@ -603,12 +772,44 @@ impl StructField {
         };
     }
 
-    /// Gets the value of this field when parsing after it is read
+    /// Gets the value of this field when parsing after it is read, required
+    /// fields are read into an optional since they have no value on errors
     fn get_parameter_retrieval(&self) -> String {
-        return format!("std::move(value_{name}), ", name = self.name);
+        return match self.default {
+            DefaultType::Required => format!("std::move(*value_{name}), ", name = self.name),
+            _ => format!("std::move(value_{name}), ", name = self.name),
+        };
     }
 }
 
+/// Gets all the fields of a struct to inherit from including the fields it
+/// inherits itself, the fields of the base are first
+///
+/// # Parameters
+///
+/// name: The name of the struct to inherit from
+///
+/// data_types: All the generic data types of the validated data model
+fn get_inherited_fields(name: &str, data_types: &[crate::DataType]) -> Vec<crate::StructField> {
+    // The validation of the data model makes sure the struct exists
+    let data = match data_types.iter().find(|data_type| data_type.name == name) {
+        Some(crate::DataType {
+            data: crate::DataTypeData::Struct(data),
+            ..
+        }) => data,
+        _ => return vec![],
+    };
+
+    // Get the fields of the base first
+    let mut fields = match &data.inherit {
+        Some(inherit) => get_inherited_fields(inherit, data_types),
+        None => vec![],
+    };
+    fields.extend(data.fields.iter().cloned());
+
+    return fields;
+}
+
 fn string_sanitize(value: &str) -> String {
     return value
         .replace("\n", "\\n")
@@ -628,6 +829,10 @@ fn serialization_to_termite_node(
     let next_indent = total_indent + indent;
 
     return match value {
+        data_model::SerializationModel::Map(value) if value.is_empty() => {
+            // An empty initializer list would be ambiguous between the constructors
+            "termite::Node(termite::Node::Map(std::map<std::string, termite::Node>{}))".to_string()
+        }
         data_model::SerializationModel::Map(value) => {
             let entries = value
                 .iter()
@@ -647,6 +852,9 @@ fn serialization_to_termite_node(
                 ""
             )
         }
+        data_model::SerializationModel::Array(ref value) if value.is_empty() => {
+            "termite::Node(termite::Node::List(std::vector<termite::Node>{}))".to_string()
+        }
         data_model::SerializationModel::Array(ref value) => {
             let elements = value
                 .iter()
@@ -686,6 +894,7 @@ mod tests {
 
         // Make sure it generates the correct code
         let data_model = DataModel {
+            imports: vec![],
             headers: Headers {
                 header: "".to_string(),
                 source: "".to_string(),
@@ -698,16 +907,22 @@ mod tests {
                 DataType {
                     name: "DataType1".to_string(),
                     description: None,
-                    data: DataTypeData::Struct(Struct { fields: vec![] }),
+                    data: DataTypeData::Struct(Struct {
+                        fields: vec![],
+                        unknown_fields: crate::UnknownFields::Collect,
+                    }),
                 },
                 DataType {
                     name: "DataType2".to_string(),
                     description: None,
-                    data: DataTypeData::Struct(Struct { fields: vec![] }),
+                    data: DataTypeData::Struct(Struct {
+                        fields: vec![],
+                        unknown_fields: crate::UnknownFields::Collect,
+                    }),
                 },
             ],
             namespace: vec!["test".to_string()],
-            macros: HashMap::new(),
+            macros: IndexMap::new(),
         };
 
         // Create the header file
@@ -730,6 +945,7 @@ mod tests {
 
         // Make sure it generates the correct code
         let data_model = DataModel {
+            imports: vec![],
             headers: Headers {
                 header: "".to_string(),
                 source: "".to_string(),
@@ -742,16 +958,22 @@ mod tests {
                 DataType {
                     name: "DataType1".to_string(),
                     description: Some("description1".to_string()),
-                    data: DataTypeData::Struct(Struct { fields: vec![] }),
+                    data: DataTypeData::Struct(Struct {
+                        fields: vec![],
+                        unknown_fields: crate::UnknownFields::Collect,
+                    }),
                 },
                 DataType {
                     name: "DataType2".to_string(),
                     description: Some("description2".to_string()),
-                    data: DataTypeData::Struct(Struct { fields: vec![] }),
+                    data: DataTypeData::Struct(Struct {
+                        fields: vec![],
+                        unknown_fields: crate::UnknownFields::Collect,
+                    }),
                 },
             ],
             namespace: vec!["test".to_string()],
-            macros: HashMap::new(),
+            macros: IndexMap::new(),
         };
 
         // Create the header file
@@ -768,6 +990,186 @@ mod tests {
         assert_eq!(str_diff(&source_file, &expected_source), None);
     }
 
+    #[test]
+    fn inherit() {
+        // Check c++ code
+        compile_and_test("type_struct/inherit");
+
+        // Make sure it generates the correct code
+        let data_model = DataModel::new(crate::DataModel {
+            imports: vec![],
+            source: None,
+            headers: IndexMap::new(),
+            footers: IndexMap::new(),
+            data_types: vec![
+                crate::DataType {
+                    name: "DataType3".to_string(),
+                    description: None,
+                    data: crate::DataTypeData::Struct(crate::Struct {
+                        fields: vec![crate::StructField {
+                            name: "field3".to_string(),
+                            description: None,
+                            data_type: "int".to_string(),
+                            default: DefaultType::Default(data_model::SerializationModel::Value(
+                                "3".to_string(),
+                            )),
+                        }],
+                        inherit: Some("DataType2".to_string()),
+                        unknown_fields: None,
+                    }),
+                },
+                crate::DataType {
+                    name: "DataType1".to_string(),
+                    description: None,
+                    data: crate::DataTypeData::Struct(crate::Struct {
+                        fields: vec![crate::StructField {
+                            name: "field1".to_string(),
+                            description: None,
+                            data_type: "int".to_string(),
+                            default: DefaultType::Required,
+                        }],
+                        inherit: None,
+                        unknown_fields: None,
+                    }),
+                },
+                crate::DataType {
+                    name: "DataType2".to_string(),
+                    description: None,
+                    data: crate::DataTypeData::Struct(crate::Struct {
+                        fields: vec![crate::StructField {
+                            name: "field2".to_string(),
+                            description: None,
+                            data_type: "float".to_string(),
+                            default: DefaultType::Optional,
+                        }],
+                        inherit: Some("DataType1".to_string()),
+                        unknown_fields: None,
+                    }),
+                },
+            ],
+            namespace: vec!["test".to_string()],
+            macros: IndexMap::new(),
+            unknown_fields: crate::UnknownFields::Collect,
+        })
+        .unwrap();
+
+        // Create the header file
+        let header_file = data_model.get_header("HEADER", 2).unwrap();
+        let source_file = data_model.get_source("inherit", 2).unwrap();
+        let expected_header = include_str!("../../tests/cpp/type_struct/inherit/inherit.h");
+        let expected_source = include_str!("../../tests/cpp/type_struct/inherit/inherit.cpp");
+        //println!("header:\n{header_file}\n---\n");
+        //println!("source:\n{source_file}\n---\n");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&header_file, &expected_header), None);
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn inherit_errors() {
+        let create_model = |data_types: Vec<crate::DataType>| {
+            let error = DataModel::new(crate::DataModel {
+                imports: vec![],
+                source: None,
+                headers: IndexMap::new(),
+                footers: IndexMap::new(),
+                data_types,
+                namespace: vec![],
+                macros: IndexMap::new(),
+                unknown_fields: crate::UnknownFields::Collect,
+            })
+            .unwrap_err();
+            return match error.error {
+                ErrorCore::Validation(mut errors) => {
+                    assert_eq!(errors.len(), 1);
+                    errors.remove(0)
+                }
+                _ => panic!("Expected a validation error: {error}"),
+            };
+        };
+        let create_struct = |name: &str, field: &str, inherit: Option<&str>| {
+            return crate::DataType {
+                name: name.to_string(),
+                description: None,
+                data: crate::DataTypeData::Struct(crate::Struct {
+                    fields: vec![crate::StructField {
+                        name: field.to_string(),
+                        description: None,
+                        data_type: "int".to_string(),
+                        default: DefaultType::Required,
+                    }],
+                    inherit: inherit.map(|inherit| inherit.to_string()),
+                    unknown_fields: None,
+                }),
+            };
+        };
+
+        // Missing base
+        let error = create_model(vec![create_struct("DataType", "field", Some("Missing"))]);
+        assert_eq!(error.location.to_string(), ".data_types[0].data.inherit");
+        assert!(matches!(
+            error.error,
+            data_model::ErrorCore::UnknownInherit(name) if name == "Missing"
+        ));
+
+        // Base is not a struct
+        let error = create_model(vec![
+            create_struct("DataType", "field", Some("Array")),
+            crate::DataType {
+                name: "Array".to_string(),
+                description: None,
+                data: crate::DataTypeData::Array(crate::Array {
+                    data_type: "int".to_string(),
+                }),
+            },
+        ]);
+        assert!(matches!(
+            error.error,
+            data_model::ErrorCore::InheritNotStruct(name) if name == "Array"
+        ));
+
+        // Field defined in both base and derived struct
+        let error = create_model(vec![
+            create_struct("DataType1", "field", None),
+            create_struct("DataType2", "field", Some("DataType1")),
+        ]);
+        assert_eq!(error.location.to_string(), ".data_types[1].data.fields[0].name");
+        assert!(matches!(
+            error.error,
+            data_model::ErrorCore::InheritedFieldConflict(field, base) if field == "field" && base == "DataType1"
+        ));
+    }
+
+    #[test]
+    fn unknown_fields() {
+        // Check c++ code
+        compile_and_test("type_struct/unknown_fields");
+
+        // Make sure it generates the correct code
+        let data_model = DataModel::new(
+            crate::DataModel::import_yaml(include_str!(
+                "../../tests/cpp/type_struct/unknown_fields/unknown_fields.yaml"
+            ))
+            .unwrap(),
+        )
+        .unwrap();
+
+        // Create the header file
+        let header_file = data_model.get_header("HEADER", 2).unwrap();
+        let source_file = data_model.get_source("unknown_fields", 2).unwrap();
+        let expected_header =
+            include_str!("../../tests/cpp/type_struct/unknown_fields/unknown_fields.h");
+        let expected_source =
+            include_str!("../../tests/cpp/type_struct/unknown_fields/unknown_fields.cpp");
+        //println!("header:\n{header_file}\n---\n");
+        //println!("source:\n{source_file}\n---\n");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&header_file, &expected_header), None);
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
     mod field {
         use crate::SerializationModel;
 
@@ -780,6 +1182,7 @@ mod tests {
 
             // Make sure it generates the correct code
             let data_model = DataModel {
+                imports: vec![],
                 headers: Headers {
                     header: "".to_string(),
                     source: "".to_string(),
@@ -798,18 +1201,23 @@ mod tests {
                                 description: None,
                                 data_type: "int".to_string(),
                                 default: DefaultType::Required,
+                                boxed: false,
+                                location: Location::default(),
                             },
                             StructField {
                                 name: "field2".to_string(),
                                 description: None,
                                 data_type: "float".to_string(),
                                 default: DefaultType::Required,
+                                boxed: false,
+                                location: Location::default(),
                             },
                         ],
+                        unknown_fields: crate::UnknownFields::Collect,
                     }),
                 }],
                 namespace: vec!["test".to_string()],
-                macros: HashMap::new(),
+                macros: IndexMap::new(),
             };
 
             // Create the header file
@@ -832,6 +1240,7 @@ mod tests {
 
             // Make sure it generates the correct code
             let data_model = DataModel {
+                imports: vec![],
                 headers: Headers {
                     header: "".to_string(),
                     source: "".to_string(),
@@ -850,18 +1259,23 @@ mod tests {
                                 description: Some("description1".to_string()),
                                 data_type: "int".to_string(),
                                 default: DefaultType::Required,
+                                boxed: false,
+                                location: Location::default(),
                             },
                             StructField {
                                 name: "field2".to_string(),
                                 description: Some("description2".to_string()),
                                 data_type: "float".to_string(),
                                 default: DefaultType::Required,
+                                boxed: false,
+                                location: Location::default(),
                             },
                         ],
+                        unknown_fields: crate::UnknownFields::Collect,
                     }),
                 }],
                 namespace: vec!["test".to_string()],
-                macros: HashMap::new(),
+                macros: IndexMap::new(),
             };
 
             // Create the header file
@@ -886,6 +1300,7 @@ mod tests {
 
             // Make sure it generates the correct code
             let data_model = DataModel {
+                imports: vec![],
                 headers: Headers {
                     header: "".to_string(),
                     source: "".to_string(),
@@ -906,18 +1321,23 @@ mod tests {
                                 default: DefaultType::Default(
                                     data_model::SerializationModel::Value("1".to_string()),
                                 ),
+                                boxed: false,
+                                location: Location::default(),
                             },
                             StructField {
                                 name: "field2".to_string(),
                                 description: None,
                                 data_type: "float".to_string(),
                                 default: DefaultType::Optional,
+                                boxed: false,
+                                location: Location::default(),
                             },
                         ],
+                        unknown_fields: crate::UnknownFields::Collect,
                     }),
                 }],
                 namespace: vec!["test".to_string()],
-                macros: HashMap::new(),
+                macros: IndexMap::new(),
             };
 
             // Create the header file
@@ -942,6 +1362,7 @@ mod tests {
 
             // Make sure it generates the correct code
             let data_model = DataModel {
+                imports: vec![],
                 headers: Headers {
                     header: "".to_string(),
                     source: "".to_string(),
@@ -962,18 +1383,23 @@ mod tests {
                                 default: DefaultType::Default(
                                     data_model::SerializationModel::Value("$MACRO$".to_string()),
                                 ),
+                                boxed: false,
+                                location: Location::default(),
                             },
                             StructField {
                                 name: "field2".to_string(),
                                 description: None,
                                 data_type: "float".to_string(),
                                 default: DefaultType::Optional,
+                                boxed: false,
+                                location: Location::default(),
                             },
                         ],
+                        unknown_fields: crate::UnknownFields::Collect,
                     }),
                 }],
                 namespace: vec!["test".to_string()],
-                macros: HashMap::from([("MACRO".to_string(), SerializationModel::Value("1".to_string()))]),
+                macros: IndexMap::from([("MACRO".to_string(), SerializationModel::Value("1".to_string()))]),
             };
 
             // Create the header file
@@ -990,5 +1416,32 @@ mod tests {
             assert_eq!(str_diff(&header_file, &expected_header), None);
             assert_eq!(str_diff(&source_file, &expected_source), None);
         }
+
+        #[test]
+        fn empty_default() {
+            // Check c++ code
+            compile_and_test("type_struct/field/empty_default");
+
+            // Make sure it generates the correct code
+            let data_model = DataModel::new(
+                crate::DataModel::import_yaml(include_str!(
+                    "../../tests/cpp/type_struct/field/empty_default/empty_default.yaml"
+                ))
+                .unwrap(),
+            )
+            .unwrap();
+
+            // Create the header file
+            let header_file = data_model.get_header("HEADER", 2).unwrap();
+            let source_file = data_model.get_source("empty_default", 2).unwrap();
+            let expected_header =
+                include_str!("../../tests/cpp/type_struct/field/empty_default/empty_default.h");
+            let expected_source =
+                include_str!("../../tests/cpp/type_struct/field/empty_default/empty_default.cpp");
+
+            // Check that they are the same
+            assert_eq!(str_diff(&header_file, &expected_header), None);
+            assert_eq!(str_diff(&source_file, &expected_source), None);
+        }
     }
 }
@@ -6,6 +6,9 @@ use indoc::formatdoc;
 pub(super) struct Variant {
     /// The possible types for the variant
     pub(super) data_types: Vec<String>,
+    /// The types which are stored in a termite::Box because they depend on
+    /// the variant
+    pub(super) boxed_types: HashSet<String>,
 }
 
 impl Variant {
@@ -17,9 +20,44 @@ impl Variant {
     pub(super) fn new(data: crate::Variant) -> Result<Self, Error> {
         return Ok(Self {
             data_types: data.data_types,
+            boxed_types: HashSet::new(),
         });
     }
 
+    /// Gets the names of all the types which must be defined before this
+    /// variant
+    pub(super) fn get_dependencies(&self) -> Vec<&str> {
+        return self
+            .data_types
+            .iter()
+            .filter(|data_type| !self.boxed_types.contains(*data_type))
+            .map(|data_type| data_type.as_str())
+            .collect();
+    }
+
+    /// Gets the names of all the types this variant references
+    pub(super) fn get_references(&self) -> Vec<&str> {
+        return self
+            .data_types
+            .iter()
+            .map(|data_type| data_type.as_str())
+            .collect();
+    }
+
+    /// Stores all values of the given types in a termite::Box
+    ///
+    /// # Parameters
+    ///
+    /// recursive_types: The types which depend on this variant
+    pub(super) fn box_references(&mut self, recursive_types: &HashSet<&str>) {
+        self.boxed_types = self
+            .data_types
+            .iter()
+            .filter(|data_type| recursive_types.contains(data_type.as_str()))
+            .cloned()
+            .collect();
+    }
+
     /// Converts the variant to a string for use in the header file
     ///
     /// # Parameters
@@ -35,6 +73,8 @@ impl Variant {
             .map(|data_type| {
                 if ["string", "number", "integer", "boolean"].contains(&data_type.as_str()) {
                     format!("termite::{data_type}")
+                } else if self.boxed_types.contains(data_type) {
+                    format!("termite::Box<{data_type}>")
                 } else {
                     data_type.clone()
                 }
@@ -104,6 +144,8 @@ impl Variant {
                 let data_type =
                     if ["string", "number", "integer", "boolean"].contains(&data_type.as_str()) {
                         format!("termite::{data_type}")
+                    } else if self.boxed_types.contains(data_type) {
+                        format!("termite::Box<{data_type}>")
                     } else {
                         data_type.clone()
                     };
@@ -162,7 +204,10 @@ impl Variant {
         return formatdoc!(
             "
             template<>
-            [[nodiscard]] Result<{typename}> Node::to_value<{typename}>() const;
+            struct has_to_value_all<{typename}> : std::true_type {{}};
+
+            template<>
+            [[nodiscard]] Result<{typename}, Errors> Node::to_value_all<{typename}>() const;
 
             template<>
             [[nodiscard]] Node Node::from_value<{typename}>(const {typename} &value);",
@@ -195,11 +240,31 @@ impl Variant {
             .join("");
         let typename = format!("{namespace}{name}");
 
-        // Get snake case naming
+        // Get snake case naming from the names without namespace such that
+        // imported types give valid identifiers, types with the same name
+        // from different namespaces are told apart by their index
         let snake_case_data_types = self
             .data_types
             .iter()
-            .map(|data_type| ToSnakeCase::new(&mut data_type.chars()).collect::<String>())
+            .map(|data_type| {
+                let name = data_type.rsplit("::").next().unwrap();
+                return ToSnakeCase::new(&mut name.chars()).collect::<String>();
+            })
+            .collect::<Vec<String>>();
+        let snake_case_data_types = snake_case_data_types
+            .iter()
+            .enumerate()
+            .map(|(index, snake_case)| {
+                let count = snake_case_data_types
+                    .iter()
+                    .filter(|name| *name == snake_case)
+                    .count();
+                return if count > 1 {
+                    format!("{snake_case}_{index}")
+                } else {
+                    snake_case.clone()
+                };
+            })
             .collect::<Vec<String>>();
 
         // Get all the readers
@@ -207,7 +272,9 @@ impl Variant {
             .zip(snake_case_data_types.iter())
             .map(|(data_type, snake_case)| {
                 // Add possible namespace to the typename
-                let data_type = if let Some(_) = data_types.iter().find(|new_data_type| &new_data_type.name == data_type) {
+                let data_type = if self.boxed_types.contains(data_type) {
+                    format!("Box<{namespace}{data_type}>")
+                } else if let Some(_) = data_types.iter().find(|new_data_type| &new_data_type.name == data_type) {
                     format!("{namespace}{data_type}")
                 } else {
                     format!("{data_type}")
@@ -216,7 +283,7 @@ impl Variant {
                 return formatdoc!("
                     {0:indent$}Result<{data_type}> result_{snake_case} = to_value<{data_type}>();
                     {0:indent$}if (result_{snake_case}.is_ok()) {{
-                    {0:indent$}{0:indent$}return Result<{typename}>::ok({typename}(result_{snake_case}.get_ok()));
+                    {0:indent$}{0:indent$}return Result<{typename}, Errors>::ok({typename}(result_{snake_case}.get_ok()));
                     {0:indent$}}}
                     {0:indent$}error << \"{data_type} {{ \" << result_{snake_case}.get_err() << \" }}\";",
                     "",
@@ -234,7 +301,7 @@ impl Variant {
         return formatdoc!(
             "
             template<>
-            [[nodiscard]] Result<{typename}> Node::to_value<{typename}>() const {{
+            [[nodiscard]] Result<{typename}, Errors> Node::to_value_all<{typename}>() const {{
             {0:indent$}std::stringstream error;
             {0:indent$}error << \"Unable to parse any variant: [ \";
 
@@ -242,7 +309,7 @@ impl Variant {
             
             {0:indent$}error << \" ]\";
 
-            {0:indent$}return Result<{typename}>::err(Error(error.str()));
+            {0:indent$}return Result<{typename}, Errors>::err(Errors(Error(error.str()).set_mark(mark_)));
             }}
 
             template<>
@@ -268,6 +335,7 @@ mod tests {
 
         // Make sure it generates the correct code
         let data_model = DataModel {
+            imports: vec![],
             headers: Headers {
                 header: "".to_string(),
                 source: "".to_string(),
@@ -281,10 +349,11 @@ mod tests {
                 description: None,
                 data: DataTypeData::Variant(Variant {
                     data_types: vec!["int".to_string(), "float".to_string()],
+                    boxed_types: HashSet::new(),
                 }),
             }],
             namespace: vec!["test".to_string()],
-            macros: HashMap::new(),
+            macros: IndexMap::new(),
         };
 
         // Create the header file
@@ -1,48 +1,1110 @@
+use crate::{
+    constraint::{self, Constraint},
+    source::{LocatedError, Source},
+    Location,
+};
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use std::{
     collections::{HashMap, HashSet},
-    fmt,
+    fmt, fs,
+    path::{Path, PathBuf},
 };
 
 /// An entire data model
 #[derive(Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
 #[serde(default)]
 pub struct DataModel {
+    /// List of other data models to use the data types and macros of, relative
+    /// paths are relative to the directory of this data model
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub imports: Vec<Import>,
     /// List of the the data types to implement
     pub data_types: Vec<DataType>,
     /// List of all header data used to include external packages
-    pub headers: HashMap<String, String>,
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    pub headers: IndexMap<String, String>,
     /// List of all footer data
-    pub footers: HashMap<String, String>,
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    pub footers: IndexMap<String, String>,
     /// The nested namespace to put the data model into
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub namespace: Vec<String>,
+    /// What to do with unknown fields when parsing structs which do not set it
+    /// themselves
+    #[serde(skip_serializing_if = "UnknownFields::is_collect")]
+    pub unknown_fields: UnknownFields,
     /// A set of replacement macros to use for default values
-    pub macros: HashMap<String, SerializationModel>,
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    pub macros: IndexMap<String, SerializationModel>,
+    /// The text the data model was imported from, used to write the file, line
+    /// and column of errors
+    #[serde(skip)]
+    pub source: Option<Source>,
+}
+
+/// The values of a data model which are written as numbers or booleans when
+/// exporting, see DataModel::get_typed_values()
+struct TypedValues {
+    /// The default values of struct fields with the index of the data type
+    /// and the index of the field
+    defaults: Vec<(usize, usize, serde_json::Value)>,
+    /// The macros which are only used where a number or a boolean is expected
+    macros: Vec<(String, serde_json::Value)>,
+}
+
+/// Converts a scalar into a number or boolean if its typed value is a number or
+/// boolean and the conversion keeps the exact text
+///
+/// # Parameters
+///
+/// text: The text of the scalar
+///
+/// typed: The scalar converted to its type
+fn typed_scalar(text: &str, typed: &jzon::JsonValue) -> Option<serde_json::Value> {
+    if typed.is_boolean() {
+        return match text {
+            "true" => Some(serde_json::Value::Bool(true)),
+            "false" => Some(serde_json::Value::Bool(false)),
+            _ => None,
+        };
+    }
+    if !typed.is_number() {
+        return None;
+    }
+
+    if let Some(number) = text
+        .parse::<i64>()
+        .ok()
+        .filter(|number| number.to_string() == text)
+    {
+        return Some(serde_json::Value::from(number));
+    }
+    return text
+        .parse::<f64>()
+        .ok()
+        .filter(|number| {
+            number.is_finite() && serde_yaml::Number::from(*number).to_string() == text
+        })
+        .and_then(serde_json::Number::from_f64)
+        .map(serde_json::Value::Number);
+}
+
+/// A data model imported by another data model
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub struct Import {
+    /// The path of the data model as written in the importing data model
+    pub path: String,
+    /// The imported data model, it is only set after resolving the imports
+    pub resolved: Option<ResolvedImport>,
+}
+
+impl From<String> for Import {
+    fn from(path: String) -> Self {
+        return Self {
+            path,
+            resolved: None,
+        };
+    }
+}
+
+impl From<Import> for String {
+    fn from(import: Import) -> Self {
+        return import.path;
+    }
+}
+
+/// A data model which has been read from an import
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResolvedImport {
+    /// The canonical path of the file the data model was read from
+    pub file: PathBuf,
+    /// The data model with all of its own imports resolved
+    pub data_model: DataModel,
 }
 
 impl DataModel {
-    /// Exports the data model to a yaml string
+    /// Exports the data model to a yaml string, default values and macros
+    /// are only written as numbers or booleans if their type is a number or
+    /// a boolean, see get_typed_values()
     pub fn export_yaml(&self) -> Result<String, serde_yaml::Error> {
-        return serde_yaml::to_string(self);
+        let mut value = serde_yaml::to_value(self)?;
+        let typed_values = self.get_typed_values();
+        for (type_index, field_index, default) in typed_values.defaults {
+            if let Some(serde_yaml::Value::Tagged(tagged)) = value
+                .get_mut("data_types")
+                .and_then(|value| value.get_mut(type_index))
+                .and_then(|value| value.get_mut("data"))
+                .and_then(|value| value.get_mut("fields"))
+                .and_then(|value| value.get_mut(field_index))
+                .and_then(|value| value.get_mut("default"))
+            {
+                tagged.value = serde_yaml::to_value(default)?;
+            }
+        }
+        for (name, macro_value) in typed_values.macros {
+            if let Some(value) = value
+                .get_mut("macros")
+                .and_then(|value| value.get_mut(name.as_str()))
+            {
+                *value = serde_yaml::to_value(macro_value)?;
+            }
+        }
+
+        return serde_yaml::to_string(&value);
     }
 
-    /// Exports the data model to a json string
+    /// Exports the data model to a json string, default values and macros
+    /// are only written as numbers or booleans if their type is a number or
+    /// a boolean, see get_typed_values()
     pub fn export_json(&self) -> Result<String, serde_json::Error> {
-        return serde_json::to_string(self);
+        return serde_json::to_string(&self.export_json_value()?);
+    }
+
+    /// Exports the data model to an indented json string, see export_json()
+    pub fn export_json_pretty(&self) -> Result<String, serde_json::Error> {
+        return serde_json::to_string_pretty(&self.export_json_value()?);
+    }
+
+    /// Exports the data model to a json value, see export_json()
+    fn export_json_value(&self) -> Result<serde_json::Value, serde_json::Error> {
+        let mut value = serde_json::to_value(self)?;
+        let typed_values = self.get_typed_values();
+        for (type_index, field_index, default) in typed_values.defaults {
+            let pointer = format!(
+                "/data_types/{type_index}/data/Struct/fields/{field_index}/default/Default"
+            );
+            if let Some(value) = value.pointer_mut(&pointer) {
+                *value = default;
+            }
+        }
+        for (name, macro_value) in typed_values.macros {
+            if let Some(value) = value
+                .get_mut("macros")
+                .and_then(|value| value.get_mut(&name))
+            {
+                *value = macro_value;
+            }
+        }
+
+        return Ok(value);
+    }
+
+    /// Gets the default values of all struct fields with the values which have
+    /// a number or boolean type written as numbers or booleans, and the macros
+    /// which are only used where a number or a boolean is expected. Values
+    /// are only written as numbers or booleans if that keeps their exact
+    /// text, all other values are kept as strings.
+    fn get_typed_values(&self) -> TypedValues {
+        let custom_types = self
+            .get_all_data_types()
+            .into_iter()
+            .map(|data_type| (data_type.name.clone(), data_type))
+            .collect::<HashMap<_, _>>();
+        let all_macros = self.get_macros();
+
+        // Find the types of the default values, the macros used as a whole
+        // value get the type of the value and all other uses make the macro
+        // text
+        let mut macro_types = HashMap::new();
+        let mut defaults = vec![];
+        for (type_index, data_type) in self.data_types.iter().enumerate() {
+            let DataTypeData::Struct(data) = &data_type.data else {
+                continue;
+            };
+            for (field_index, field) in data.fields.iter().enumerate() {
+                let DefaultType::Default(value) = &field.default else {
+                    continue;
+                };
+                let typed = expand_macros(value, &all_macros, &mut HashSet::new())
+                    .ok()
+                    .and_then(|expanded| {
+                        crate::schema::to_json(&expanded, &field.data_type, &custom_types).ok()
+                    })
+                    .unwrap_or(jzon::JsonValue::Null);
+                let default = self.typed_value(value, &typed, &mut macro_types);
+                defaults.push((type_index, field_index, default));
+            }
+        }
+        for text in self.headers.values().chain(self.footers.values()) {
+            self.add_text_macro_uses(text, &mut macro_types);
+        }
+        for value in self.macros.values() {
+            self.add_macro_uses(value, &mut macro_types);
+        }
+
+        let macros = macro_types
+            .into_iter()
+            .filter_map(|(name, typed)| match (self.macros.get(&name), typed) {
+                (Some(SerializationModel::Value(text)), Some(typed)) => {
+                    typed_scalar(text, &typed).map(|value| (name, value))
+                }
+                _ => None,
+            })
+            .collect();
+
+        return TypedValues { defaults, macros };
+    }
+
+    /// Converts a default value into a json value where the values which are
+    /// numbers or booleans in the typed value are written as such
+    ///
+    /// # Parameters
+    ///
+    /// value: The default value to convert
+    ///
+    /// typed: The default value with all macros expanded converted to its
+    /// type, null if it could not be converted
+    ///
+    /// macro_types: The typed value of every macro used as a whole value, or
+    /// None if a macro is used as text
+    fn typed_value(
+        &self,
+        value: &SerializationModel,
+        typed: &jzon::JsonValue,
+        macro_types: &mut HashMap<String, Option<jzon::JsonValue>>,
+    ) -> serde_json::Value {
+        return match value {
+            SerializationModel::Map(map) => serde_json::Value::Object(
+                map.iter()
+                    .map(|(key, value)| {
+                        (
+                            key.clone(),
+                            self.typed_value(value, &typed[key.as_str()], macro_types),
+                        )
+                    })
+                    .collect(),
+            ),
+            SerializationModel::Array(array) => serde_json::Value::Array(
+                array
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| self.typed_value(value, &typed[i], macro_types))
+                    .collect(),
+            ),
+            SerializationModel::Value(text) => {
+                let name = text
+                    .strip_prefix('$')
+                    .and_then(|text| text.strip_suffix('$'))
+                    .filter(|name| !name.is_empty() && !name.contains('$'));
+                match name {
+                    Some(name) if self.macros.contains_key(name) => {
+                        let typed =
+                            (typed.is_number() || typed.is_boolean()).then(|| typed.clone());
+                        let entry = macro_types.entry(name.to_string()).or_insert(typed.clone());
+                        if typed.is_none() {
+                            *entry = None;
+                        }
+                    }
+                    _ => self.add_text_macro_uses(text, macro_types),
+                }
+
+                typed_scalar(text, typed).unwrap_or_else(|| serde_json::Value::String(text.clone()))
+            }
+        };
+    }
+
+    /// Marks all macros used in a macro value as used as text
+    ///
+    /// # Parameters
+    ///
+    /// value: The value of the macro
+    ///
+    /// macro_types: The typed value of every macro used as a whole value, or
+    /// None if a macro is used as text
+    fn add_macro_uses(
+        &self,
+        value: &SerializationModel,
+        macro_types: &mut HashMap<String, Option<jzon::JsonValue>>,
+    ) {
+        match value {
+            SerializationModel::Map(map) => {
+                for value in map.values() {
+                    self.add_macro_uses(value, macro_types);
+                }
+            }
+            SerializationModel::Array(array) => {
+                for value in array.iter() {
+                    self.add_macro_uses(value, macro_types);
+                }
+            }
+            SerializationModel::Value(text) => self.add_text_macro_uses(text, macro_types),
+        }
+    }
+
+    /// Marks all macros used in a text as used as text
+    ///
+    /// # Parameters
+    ///
+    /// text: The text which may use macros
+    ///
+    /// macro_types: The typed value of every macro used as a whole value, or
+    /// None if a macro is used as text
+    fn add_text_macro_uses(
+        &self,
+        text: &str,
+        macro_types: &mut HashMap<String, Option<jzon::JsonValue>>,
+    ) {
+        for name in self.macros.keys() {
+            if text.contains(&format!("${name}$")) {
+                macro_types.insert(name.clone(), None);
+            }
+        }
+    }
+
+    /// Imports a data model from a yaml string, errors include the location
+    /// of the offending value like .data_types[0].data.ConstrainedType.constraints[1]
+    pub fn import_yaml(mode: &str) -> Result<DataModel, Error> {
+        return match serde_yaml::from_str(mode) {
+            Ok(value) => deserialize(sanitize_yaml(value)),
+            Err(error) => Err(parse_error(error)),
+        };
+    }
+
+    /// Imports a data model from a json string, errors include the location
+    /// of the offending value like .data_types[0].data.ConstrainedType.constraints[1]
+    pub fn import_json(mode: &str) -> Result<DataModel, Error> {
+        return match serde_json::from_str(mode) {
+            Ok(value) => deserialize(sanitize_json(value)),
+            Err(error) => Err(parse_error(error)),
+        };
+    }
+
+    /// Imports a data model from the text of a YAML or JSON file, files ending
+    /// with .json are read as JSON and all other files as YAML. The source is
+    /// kept such that errors can be written with their file, line and column.
+    ///
+    /// # Parameters
+    ///
+    /// source: The text of the data model
+    ///
+    /// # Errors
+    ///
+    /// If the text is not valid YAML or JSON or does not describe a data model,
+    /// the location is the location of the invalid value
+    pub fn import_source(source: Source) -> Result<DataModel, Error> {
+        let mut data_model = if source.file.to_lowercase().ends_with(".json") {
+            DataModel::import_json(&source.text)?
+        } else {
+            DataModel::import_yaml(&source.text)?
+        };
+        data_model.source = Some(source);
+
+        return Ok(data_model);
+    }
+
+    /// Reads all the imported data models and their imports, files ending
+    /// with .json are read as JSON and all other files as YAML
+    ///
+    /// # Parameters
+    ///
+    /// path: The path of this data model, used to resolve relative imports
+    ///
+    /// # Errors
+    ///
+    /// If an import cannot be read or parsed or if the imports form a cycle
+    pub fn resolve_imports(&mut self, path: &Path) -> Result<(), Error> {
+        return self.resolve_imports_from(path, &mut vec![]);
     }
 
-    /// Imports a data model from a yaml string
-    pub fn import_yaml(mode: &str) -> Result<DataModel, serde_yaml::Error> {
-        return serde_yaml::from_value(sanitize_yaml(serde_yaml::from_str(mode)?));
+    /// Reads all the imported data models, see resolve_imports()
+    ///
+    /// # Parameters
+    ///
+    /// path: The path of this data model
+    ///
+    /// stack: The canonical paths of all the data models currently being
+    /// resolved, used to detect cycles
+    fn resolve_imports_from(&mut self, path: &Path, stack: &mut Vec<PathBuf>) -> Result<(), Error> {
+        stack.push(fs::canonicalize(path).unwrap_or(path.to_path_buf()));
+        let directory = path.parent().unwrap_or(Path::new(""));
+        for (i, import) in self.imports.iter_mut().enumerate() {
+            let import_path = directory.join(&import.path);
+            let import_error = |error| {
+                return Error {
+                    location: Location::default(),
+                    error,
+                }
+                .add_element(i)
+                .add_field("imports");
+            };
+
+            let file = match fs::canonicalize(&import_path) {
+                Ok(file) => file,
+                Err(error) => {
+                    return Err(import_error(ErrorCore::ImportRead(
+                        import.path.clone(),
+                        error.to_string(),
+                    )))
+                }
+            };
+
+            // Make sure there is no cycle
+            if let Some(index) = stack.iter().position(|parent| *parent == file) {
+                let mut cycle = stack[index..]
+                    .iter()
+                    .map(|parent| parent.display().to_string())
+                    .collect::<Vec<_>>();
+                cycle.push(file.display().to_string());
+                return Err(import_error(ErrorCore::ImportCycle(cycle)));
+            }
+
+            let content = match fs::read_to_string(&file) {
+                Ok(content) => content,
+                Err(error) => {
+                    return Err(import_error(ErrorCore::ImportRead(
+                        import.path.clone(),
+                        error.to_string(),
+                    )))
+                }
+            };
+            // The source of the import is kept even if it is not valid such
+            // that the errors are written for the imported file
+            let source = Source::new(import_path.display().to_string(), content);
+            let mut data_model = match DataModel::import_source(source.clone()) {
+                Ok(data_model) => data_model,
+                Err(error) => {
+                    if let Some(own_source) = &mut self.source {
+                        own_source.set_import(i, Some(source));
+                    }
+                    let location = error.location.clone();
+                    return Err(Error {
+                        location,
+                        error: ErrorCore::ImportParse(import.path.clone(), Box::new(error)),
+                    }
+                    .add_element(i)
+                    .add_field("imports"));
+                }
+            };
+
+            let result = data_model.resolve_imports_from(&import_path, stack);
+            if let Some(source) = &mut self.source {
+                source.set_import(i, data_model.source.clone());
+            }
+            if let Err(error) = result {
+                return Err(error.add_element(i).add_field("imports"));
+            }
+            import.resolved = Some(ResolvedImport { file, data_model });
+        }
+        stack.pop();
+
+        return Ok(());
     }
 
-    /// Imports a data model from a json string
-    pub fn import_json(mode: &str) -> Result<DataModel, serde_json::Error> {
-        return serde_json::from_value(sanitize_json(serde_json::from_str(mode)?));
+    /// Gets all the data models imported directly or indirectly, every file is
+    /// only included once and imported data models come before the data
+    /// models importing them. Unresolved imports are skipped.
+    pub(crate) fn get_imported_models(&self) -> Vec<&ResolvedImport> {
+        let mut imported = vec![];
+        self.collect_imported_models(&mut imported);
+        return imported;
+    }
+
+    /// Adds all the data models imported directly or indirectly which are not
+    /// already in the list, see get_imported_models()
+    ///
+    /// # Parameters
+    ///
+    /// imported: The list of imported data models to add to
+    fn collect_imported_models<'a>(&'a self, imported: &mut Vec<&'a ResolvedImport>) {
+        for resolved in self.imports.iter().filter_map(|import| import.resolved.as_ref()) {
+            if imported.iter().any(|other| other.file == resolved.file) {
+                continue;
+            }
+            resolved.data_model.collect_imported_models(imported);
+            imported.push(resolved);
+        }
+    }
+
+    /// Gets all the macros available to this data model, macros defined in
+    /// this data model replace imported macros with the same name
+    pub(crate) fn get_macros(&self) -> IndexMap<String, SerializationModel> {
+        let mut macros = IndexMap::new();
+        for resolved in self.get_imported_models() {
+            macros.extend(resolved.data_model.macros.clone());
+        }
+        macros.extend(self.macros.clone());
+        return macros;
+    }
+
+    /// Gets all the data types available to this data model, the imported
+    /// data types come first. Structs which do not set unknown_fields get the
+    /// setting of the data model they are defined in.
+    pub(crate) fn get_all_data_types(&self) -> Vec<DataType> {
+        return self
+            .get_imported_models()
+            .into_iter()
+            .map(|resolved| &resolved.data_model)
+            .chain([self])
+            .flat_map(|data_model| {
+                return data_model.data_types.iter().map(|data_type| {
+                    let mut data_type = data_type.clone();
+                    if let DataTypeData::Struct(data) = &mut data_type.data {
+                        data.unknown_fields.get_or_insert(data_model.unknown_fields);
+                    }
+                    return data_type;
+                });
+            })
+            .collect();
+    }
+
+    /// Gets the location of every data type of this data model and of the data
+    /// models it imports, like .imports[0].data_types[1] for the second data
+    /// type of the first import
+    pub(crate) fn get_type_locations(&self) -> HashMap<String, Location> {
+        let mut locations = HashMap::new();
+        for (i, import) in self.imports.iter().enumerate() {
+            if let Some(resolved) = &import.resolved {
+                for (name, location) in resolved.data_model.get_type_locations() {
+                    locations
+                        .entry(name)
+                        .or_insert(location.add_element(i).add_field("imports"));
+                }
+            }
+        }
+        for (i, data_type) in self.data_types.iter().enumerate() {
+            locations.insert(
+                data_type.name.clone(),
+                Location::default().with_field("data_types").with_element(i),
+            );
+        }
+
+        return locations;
+    }
+
+    /// Moves all the imported data types and macros into this data model, used
+    /// by the generators which put everything into a single file
+    pub(crate) fn flatten_imports(self) -> DataModel {
+        return DataModel {
+            imports: vec![],
+            data_types: self.get_all_data_types(),
+            macros: self.get_macros(),
+            ..self
+        };
+    }
+
+    /// Validates the entire data model before any code generation, it makes
+    /// sure that all type names are unique, all type references can be
+    /// resolved to builtin or custom types, no struct field or enum type is
+    /// defined twice, that all default values fit the type of their field and
+    /// that all constraints are type correct
+    ///
+    /// # Errors
+    ///
+    /// A list of all the problems found in the data model
+    pub fn validate(&self) -> Result<(), Vec<Error>> {
+        return self.validate_types(false);
+    }
+
+    /// Validates the entire data model, see validate()
+    ///
+    /// # Parameters
+    ///
+    /// external_types: If true then any type which is not a builtin or custom
+    /// type is assumed to be defined externally (like a c++ type included in
+    /// the headers), default values using such types are not type checked
+    pub(crate) fn validate_types(&self, external_types: bool) -> Result<(), Vec<Error>> {
+        let mut errors = vec![];
+
+        // Validate all the imported data models
+        for (i, import) in self.imports.iter().enumerate() {
+            let import_errors = match &import.resolved {
+                Some(resolved) => match resolved.data_model.validate_types(external_types) {
+                    Ok(()) => vec![],
+                    Err(errors) => errors,
+                },
+                None => vec![Error {
+                    location: Location::default(),
+                    error: ErrorCore::UnresolvedImport(import.path.clone()),
+                }],
+            };
+            errors.extend(
+                import_errors
+                    .into_iter()
+                    .map(|error| error.add_element(i).add_field("imports")),
+            );
+        }
+
+        // Imported data types are known but must not be defined twice
+        let mut custom_types = HashMap::new();
+        for data_type in self
+            .get_imported_models()
+            .into_iter()
+            .flat_map(|resolved| resolved.data_model.data_types.iter())
+        {
+            if custom_types
+                .insert(data_type.name.clone(), data_type.clone())
+                .is_some()
+            {
+                errors.push(Error {
+                    location: Location::default().add_field("imports"),
+                    error: ErrorCore::DuplicateType(data_type.name.clone()),
+                });
+            }
+        }
+        let mut names = custom_types
+            .keys()
+            .cloned()
+            .collect::<HashSet<String>>();
+        custom_types.extend(
+            self.data_types
+                .iter()
+                .map(|data_type| (data_type.name.clone(), data_type.clone())),
+        );
+
+        let macros = self.get_macros();
+        let context = ValidationContext {
+            custom_types,
+            macros: &macros,
+            external_types,
+        };
+
+        // Validate all the data types
+        for (i, data_type) in self.data_types.iter().enumerate() {
+            let mut type_errors = vec![];
+            if BUILTIN_TYPES.contains(&data_type.name.as_str()) {
+                type_errors.push(Error {
+                    location: Location::default().add_field("name"),
+                    error: ErrorCore::BuiltinTypeName(data_type.name.clone()),
+                });
+            } else if !names.insert(data_type.name.clone()) {
+                type_errors.push(Error {
+                    location: Location::default().add_field("name"),
+                    error: ErrorCore::DuplicateType(data_type.name.clone()),
+                });
+            }
+            type_errors.extend(
+                data_type
+                    .data
+                    .validate(&data_type.name, &context)
+                    .into_iter()
+                    .map(|error| error.add_field("data")),
+            );
+
+            errors.extend(
+                type_errors
+                    .into_iter()
+                    .map(|error| error.add_element(i).add_field("data_types")),
+            );
+        }
+
+        return if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        };
     }
 }
 
-fn sanitize_yaml(value: serde_yaml::Value) -> serde_yaml::Value {
+/// The names of all builtin types
+pub(crate) const BUILTIN_TYPES: [&str; 4] = ["string", "number", "integer", "boolean"];
+
+/// All the information about the data model needed when validating a single
+/// data type
+struct ValidationContext<'a> {
+    /// All the custom types of the data model
+    custom_types: HashMap<String, DataType>,
+    /// The macros used to expand default values
+    macros: &'a IndexMap<String, SerializationModel>,
+    /// If true then unknown types are assumed to be defined externally
+    external_types: bool,
+}
+
+impl<'a> ValidationContext<'a> {
+    /// Makes sure a type reference can be resolved
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the type which is referenced
+    fn check_type(&self, name: &str) -> Result<(), Error> {
+        return if self.external_types
+            || BUILTIN_TYPES.contains(&name)
+            || self.custom_types.contains_key(name)
+        {
+            Ok(())
+        } else {
+            Err(Error {
+                location: Location::default(),
+                error: ErrorCore::UnknownType(name.to_string()),
+            })
+        };
+    }
+
+    /// Checks if a type and all the types it depends on are builtin or custom
+    /// types such that values of it can be type checked
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the type to check
+    ///
+    /// visited: All the types already checked, used to prevent infinite
+    /// recursion
+    fn is_known<'b>(&'b self, name: &'b str, visited: &mut HashSet<&'b str>) -> bool {
+        if BUILTIN_TYPES.contains(&name) || !visited.insert(name) {
+            return true;
+        }
+
+        return match self.custom_types.get(name) {
+            Some(data_type) => match &data_type.data {
+                DataTypeData::Struct(data) => {
+                    data.inherit
+                        .iter()
+                        .all(|inherit| self.is_known(inherit, visited))
+                        && data
+                            .fields
+                            .iter()
+                            .all(|field| self.is_known(&field.data_type, visited))
+                }
+                DataTypeData::Array(data) => self.is_known(&data.data_type, visited),
+                DataTypeData::Map(data) => {
+                    data.key_type
+                        .iter()
+                        .all(|key_type| self.is_known(key_type, visited))
+                        && self.is_known(&data.data_type, visited)
+                }
+                DataTypeData::Variant(data) => data
+                    .data_types
+                    .iter()
+                    .all(|data_type| self.is_known(data_type, visited)),
+                DataTypeData::Enum(data) => data
+                    .types
+                    .iter()
+                    .filter_map(|enum_type| enum_type.data_type.as_ref())
+                    .all(|data_type| self.is_known(data_type, visited)),
+                DataTypeData::ConstrainedType(data) => self.is_known(&data.data_type, visited),
+            },
+            None => false,
+        };
+    }
+
+    /// Checks if values of a type are always strings, types which are not
+    /// defined in the data model are assumed to be strings
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the type to check
+    ///
+    /// visited: All the types already checked, used to prevent infinite
+    /// recursion
+    fn is_string<'b>(&'b self, name: &'b str, visited: &mut HashSet<&'b str>) -> bool {
+        if name == "string" {
+            return true;
+        }
+        if BUILTIN_TYPES.contains(&name) || !visited.insert(name) {
+            return false;
+        }
+
+        return match self.custom_types.get(name) {
+            Some(DataType {
+                data: DataTypeData::ConstrainedType(data),
+                ..
+            }) => self.is_string(&data.data_type, visited),
+            Some(_) => false,
+            None => true,
+        };
+    }
+
+    /// Makes sure a default value can be converted to the given type after
+    /// expanding all macros
+    ///
+    /// # Parameters
+    ///
+    /// value: The default value to check
+    ///
+    /// data_type: The type the default value must have
+    fn check_default(&self, value: &SerializationModel, data_type: &str) -> Result<(), Error> {
+        // Expand the macros
+        let value = expand_macros(value, self.macros, &mut HashSet::new())?;
+
+        // Skip the type check if it relies on external types
+        if !self.is_known(data_type, &mut HashSet::new()) {
+            return Ok(());
+        }
+
+        return match crate::schema::to_json(&value, data_type, &self.custom_types) {
+            Ok(_) => Ok(()),
+            Err(error) => Err(Error {
+                location: Location::default(),
+                error: ErrorCore::InvalidDefault(Box::new(error)),
+            }),
+        };
+    }
+}
+
+impl DataTypeData {
+    /// Validates the type specific data
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the data type
+    ///
+    /// context: The information about the data model
+    fn validate(&self, name: &str, context: &ValidationContext) -> Vec<Error> {
+        return match self {
+            DataTypeData::Struct(data) => data.validate(name, context),
+            DataTypeData::Array(data) => data.validate(context),
+            DataTypeData::Map(data) => data.validate(context),
+            DataTypeData::Variant(data) => data.validate(context),
+            DataTypeData::Enum(data) => data.validate(context),
+            DataTypeData::ConstrainedType(data) => data.validate(context),
+        };
+    }
+}
+
+impl Struct {
+    /// Validates the struct
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the struct
+    ///
+    /// context: The information about the data model
+    fn validate(&self, name: &str, context: &ValidationContext) -> Vec<Error> {
+        let mut errors = vec![];
+
+        // Check the inheritance chain and collect the inherited field names
+        let mut inherited_fields = HashMap::new();
+        let mut visited = HashSet::from([name]);
+        let mut current = self;
+        while let Some(inherit) = &current.inherit {
+            if !visited.insert(inherit.as_str()) {
+                errors.push(Error {
+                    location: Location::default().add_field("inherit"),
+                    error: ErrorCore::RecursiveInherit(inherit.clone()),
+                });
+                break;
+            }
+            current = match context.custom_types.get(inherit) {
+                Some(DataType {
+                    data: DataTypeData::Struct(data),
+                    ..
+                }) => data,
+                Some(_) => {
+                    errors.push(Error {
+                        location: Location::default().add_field("inherit"),
+                        error: ErrorCore::InheritNotStruct(inherit.clone()),
+                    });
+                    break;
+                }
+                None => {
+                    errors.push(Error {
+                        location: Location::default().add_field("inherit"),
+                        error: ErrorCore::UnknownInherit(inherit.clone()),
+                    });
+                    break;
+                }
+            };
+            for field in current.fields.iter() {
+                inherited_fields.insert(field.name.as_str(), inherit.as_str());
+            }
+        }
+
+        // Check all the fields
+        let mut names = HashSet::new();
+        for (i, field) in self.fields.iter().enumerate() {
+            let mut field_errors = vec![];
+            if !names.insert(field.name.as_str()) {
+                field_errors.push(Error {
+                    location: Location::default().add_field("name"),
+                    error: ErrorCore::DuplicateField(field.name.clone()),
+                });
+            } else if let Some(inherit) = inherited_fields.get(field.name.as_str()) {
+                field_errors.push(Error {
+                    location: Location::default().add_field("name"),
+                    error: ErrorCore::InheritedFieldConflict(
+                        field.name.clone(),
+                        inherit.to_string(),
+                    ),
+                });
+            }
+            if let Err(error) = context.check_type(&field.data_type) {
+                field_errors.push(error.add_field("data_type"));
+            }
+            if let DefaultType::Default(value) = &field.default {
+                if let Err(error) = context.check_default(value, &field.data_type) {
+                    field_errors.push(error.add_field("default"));
+                }
+            }
+
+            errors.extend(
+                field_errors
+                    .into_iter()
+                    .map(|error| error.add_element(i).add_field("fields")),
+            );
+        }
+
+        return errors;
+    }
+}
+
+impl Array {
+    /// Validates the array
+    ///
+    /// # Parameters
+    ///
+    /// context: The information about the data model
+    fn validate(&self, context: &ValidationContext) -> Vec<Error> {
+        return match context.check_type(&self.data_type) {
+            Ok(()) => vec![],
+            Err(error) => vec![error.add_field("data_type")],
+        };
+    }
+}
+
+impl Map {
+    /// Validates the map
+    ///
+    /// # Parameters
+    ///
+    /// context: The information about the data model
+    fn validate(&self, context: &ValidationContext) -> Vec<Error> {
+        let mut errors = vec![];
+        if let Some(key_type) = &self.key_type {
+            match context.check_type(key_type) {
+                Ok(()) => {
+                    if !context.is_string(key_type, &mut HashSet::new()) {
+                        errors.push(Error {
+                            location: Location::default().add_field("key_type"),
+                            error: ErrorCore::InvalidKeyType(key_type.clone()),
+                        });
+                    }
+                }
+                Err(error) => errors.push(error.add_field("key_type")),
+            }
+        }
+        if let Err(error) = context.check_type(&self.data_type) {
+            errors.push(error.add_field("data_type"));
+        }
+
+        return errors;
+    }
+}
+
+impl Variant {
+    /// Validates the variant
+    ///
+    /// # Parameters
+    ///
+    /// context: The information about the data model
+    fn validate(&self, context: &ValidationContext) -> Vec<Error> {
+        return self
+            .data_types
+            .iter()
+            .enumerate()
+            .filter_map(|(i, data_type)| match context.check_type(data_type) {
+                Ok(()) => None,
+                Err(error) => Some(error.add_element(i).add_field("data_types")),
+            })
+            .collect();
+    }
+}
+
+impl Enum {
+    /// Validates the enum
+    ///
+    /// # Parameters
+    ///
+    /// context: The information about the data model
+    fn validate(&self, context: &ValidationContext) -> Vec<Error> {
+        let mut errors = vec![];
+        let mut names = HashSet::new();
+        for (i, enum_type) in self.types.iter().enumerate() {
+            if !names.insert(enum_type.name.as_str()) {
+                errors.push(
+                    Error {
+                        location: Location::default().add_field("name"),
+                        error: ErrorCore::DuplicateEnumType(enum_type.name.clone()),
+                    }
+                    .add_element(i)
+                    .add_field("types"),
+                );
+            }
+            if let Some(data_type) = &enum_type.data_type {
+                if let Err(error) = context.check_type(data_type) {
+                    errors.push(
+                        error
+                            .add_field("data_type")
+                            .add_element(i)
+                            .add_field("types"),
+                    );
+                }
+            }
+        }
+
+        return errors;
+    }
+}
+
+impl ConstrainedType {
+    /// Validates the constrained type
+    ///
+    /// # Parameters
+    ///
+    /// context: The information about the data model
+    fn validate(&self, context: &ValidationContext) -> Vec<Error> {
+        if let Err(error) = context.check_type(&self.data_type) {
+            return vec![error.add_field("data_type")];
+        }
+
+        // Type check the constraints
+        let constraint_context = constraint::Context::new(&self.data_type, context.custom_types.values());
+        return self
+            .constraints
+            .iter()
+            .enumerate()
+            .filter_map(|(i, constraint)| match constraint_context.check(constraint) {
+                Ok(()) => None,
+                Err(error) => Some(
+                    Error {
+                        location: Location::default(),
+                        error: ErrorCore::InvalidConstraint(constraint.to_string(), Box::new(error)),
+                    }
+                    .add_element(i)
+                    .add_field("constraints"),
+                ),
+            })
+            .collect();
+    }
+}
+
+/// Deserializes a data model keeping track of the location of the value which
+/// could not be deserialized
+///
+/// # Parameters
+///
+/// value: The YAML or JSON value to deserialize
+fn deserialize<'de, D: serde::Deserializer<'de>>(value: D) -> Result<DataModel, Error> {
+    return serde_path_to_error::deserialize(value).map_err(|error| {
+        let location = error
+            .path()
+            .iter()
+            .fold(Location::default(), |location, segment| match segment {
+                serde_path_to_error::Segment::Seq { index } => location.with_element(*index),
+                serde_path_to_error::Segment::Map { key } => location.with_field(key),
+                serde_path_to_error::Segment::Enum { variant } => location.with_field(variant),
+                serde_path_to_error::Segment::Unknown => location.with_field("?"),
+            });
+        return Error {
+            location,
+            error: ErrorCore::Parse(error.into_inner().to_string()),
+        };
+    });
+}
+
+/// Creates the error for a data model which could not be parsed
+///
+/// # Parameters
+///
+/// error: The error from the parser
+fn parse_error(error: impl fmt::Display) -> Error {
+    return Error {
+        location: Location::default(),
+        error: ErrorCore::Parse(error.to_string()),
+    };
+}
+
+pub(crate) fn sanitize_yaml(value: serde_yaml::Value) -> serde_yaml::Value {
     match value {
         serde_yaml::Value::Bool(value) => {
             if value {
@@ -71,7 +1133,7 @@ fn sanitize_yaml(value: serde_yaml::Value) -> serde_yaml::Value {
     }
 }
 
-fn sanitize_json(value: serde_json::Value) -> serde_json::Value {
+pub(crate) fn sanitize_json(value: serde_json::Value) -> serde_json::Value {
     match value {
         serde_json::Value::Bool(value) => {
             if value {
@@ -100,6 +1162,7 @@ pub struct DataType {
     /// The name of the type
     pub name: String,
     /// The description of the type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// The type specific data
     pub data: DataTypeData,
@@ -112,6 +1175,8 @@ pub enum DataTypeData {
     Struct(Struct),
     /// Describes an array
     Array(Array),
+    /// Describes a map
+    Map(Map),
     /// Describes a variant
     Variant(Variant),
     /// Describes an enum
@@ -122,15 +1187,41 @@ pub enum DataTypeData {
 
 /// A struct which has a number of fields
 ///
-/// It will automatically add a termite::Node::Map field called extra_fields
-/// which holds all fields which were not captured when parsing
+/// Unless unknown fields are rejected or ignored it will automatically add a
+/// termite::Node::Map field called extra_fields which holds all fields which
+/// were not captured when parsing
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Struct {
     /// A list of all the fields of the struct
     pub fields: Vec<StructField>,
     /// The name of a different Struct this Struct builds onto, used in Schema
     /// generation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub inherit: Option<String>,
+    /// What to do with unknown fields when parsing, None to use the setting of
+    /// the data model
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unknown_fields: Option<UnknownFields>,
+}
+
+/// What to do with the fields of a struct which are not known when parsing
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnknownFields {
+    /// Keep the unknown fields in extra_fields
+    #[default]
+    Collect,
+    /// Parsing fails with an error naming the unknown fields
+    Reject,
+    /// The unknown fields are dropped
+    Ignore,
+}
+
+impl UnknownFields {
+    /// Checks if unknown fields are collected, the default
+    pub fn is_collect(&self) -> bool {
+        return *self == UnknownFields::Collect;
+    }
 }
 
 /// The data for a single field in a struct
@@ -139,6 +1230,7 @@ pub struct StructField {
     /// The name of the field
     pub name: String,
     /// The description of the field
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// What type the field is, without Option<>
     pub data_type: String,
@@ -153,6 +1245,17 @@ pub struct Array {
     pub data_type: String,
 }
 
+/// A map from user chosen string keys to values of the same data type
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Map {
+    /// The data type for all values
+    pub data_type: String,
+    /// The type all keys must be valid as, must be a string or a constrained
+    /// type of a string, if not given any key is allowed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_type: Option<String>,
+}
+
 /// A variant which can be any of a number of different types, when parsing it
 /// will attempt to parse all types from the start until it is successful
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -174,8 +1277,10 @@ pub struct EnumType {
     /// The name of this enum type
     pub name: String,
     /// The description describing this enum type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// The type this enum type is wrapping, may be omitted for an empty type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data_type: Option<String>,
 }
 
@@ -184,9 +1289,9 @@ pub struct EnumType {
 pub struct ConstrainedType {
     /// The type that is constrained
     pub data_type: String,
-    /// All extra constraints for the type, must be written as an expression where
-    /// the constrained value is denoted x
-    pub constraints: Vec<String>,
+    /// All extra constraints for the type, written in the constraint language
+    /// where the constrained value is denoted x, see the constraint module
+    pub constraints: Vec<Constraint>,
 }
 
 /// Describes whether a field is required or optional
@@ -207,7 +1312,7 @@ pub enum DefaultType {
 #[serde(untagged)]
 pub enum SerializationModel {
     /// A generic key-value pair map where the key must be a string
-    Map(HashMap<String, SerializationModel>),
+    Map(IndexMap<String, SerializationModel>),
     /// An array of other serialization models
     Array(Vec<SerializationModel>),
     /// A single value, must be a string
@@ -225,7 +1330,7 @@ pub enum SerializationModel {
 /// used_macros: A set of macros that are currently being used, used to prevent infinite recursion
 pub(crate) fn expand_macros<'a>(
     value: &SerializationModel,
-    macros: &'a HashMap<String, SerializationModel>,
+    macros: &'a IndexMap<String, SerializationModel>,
     used_macros: &mut HashSet<&'a str>,
 ) -> Result<SerializationModel, Error> {
     return match value {
@@ -235,7 +1340,7 @@ pub(crate) fn expand_macros<'a>(
                 Ok(value) => Ok((k.clone(), value)),
                 Err(error) => Err(error.add_field(k)),
             })
-            .collect::<Result<HashMap<_, _>, _>>()
+            .collect::<Result<IndexMap<_, _>, _>>()
             .map(SerializationModel::Map),
         SerializationModel::Array(value) => value
             .iter()
@@ -258,7 +1363,7 @@ pub(crate) fn expand_macros<'a>(
                 // Prevent infinite recursion
                 if used_macros.contains(macro_name) {
                     return Err(Error {
-                        location: "".to_string(),
+                        location: Location::default(),
                         error: ErrorCore::RecursiveMacro(macro_name.to_string()),
                     });
                 }
@@ -274,7 +1379,7 @@ pub(crate) fn expand_macros<'a>(
                     }
                 } else {
                     Err(Error {
-                        location: "".to_string(),
+                        location: Location::default(),
                         error: ErrorCore::MissingMacro(macro_name.to_string()),
                     })
                 };
@@ -304,7 +1409,7 @@ pub(crate) fn expand_macros<'a>(
                         // Prevent infinite recursion
                         if used_macros.contains(macro_name) {
                             return Err(Error {
-                                location: "".to_string(),
+                                location: Location::default(),
                                 error: ErrorCore::RecursiveMacro(macro_name.to_string()),
                             });
                         }
@@ -321,7 +1426,7 @@ pub(crate) fn expand_macros<'a>(
                                     }
                                     _ => {
                                         return Err(Error {
-                                            location: "".to_string(),
+                                            location: Location::default(),
                                             error: ErrorCore::PartialMacro(
                                                 macro_name.to_string(),
                                                 value.clone(),
@@ -335,7 +1440,7 @@ pub(crate) fn expand_macros<'a>(
                             }
                         } else {
                             return Err(Error {
-                                location: "".to_string(),
+                                location: Location::default(),
                                 error: ErrorCore::MissingMacro(macro_name.to_string()),
                             });
                         }
@@ -343,7 +1448,7 @@ pub(crate) fn expand_macros<'a>(
                         current_index = end_index + 1;
                     } else {
                         return Err(Error {
-                            location: "".to_string(),
+                            location: Location::default(),
                             error: ErrorCore::IncompleteMacro(value.clone()),
                         });
                     }
@@ -363,7 +1468,7 @@ pub(crate) fn expand_macros<'a>(
 #[derive(Debug, Clone)]
 pub struct Error {
     /// The location where the error occured
-    pub location: String,
+    pub location: Location,
     /// The actual error that occured
     pub error: ErrorCore,
 }
@@ -375,38 +1480,32 @@ impl Error {
     ///
     /// base: The base to set in the location
     fn add_field(self, base: &str) -> Error {
-        let location = format!(".{}{}", base, self.location);
-
         return Error {
-            location,
+            location: self.location.add_field(base),
             error: self.error,
         };
     }
 
-    /// Sets the current location to be the element of a field of the given base
+    /// Sets the current location to be the element of a sequence
     ///
     /// # Parameters
     ///
-    /// index: The index of the field
+    /// index: The index of the element
     fn add_element(self, index: usize) -> Error {
-        let location = format!("[{}]{}", index, self.location);
-
         return Error {
-            location,
+            location: self.location.add_element(index),
             error: self.error,
         };
     }
 
-    /// Sets the current location to be the element of a field of the given base
+    /// Sets the current location to be the expansion of a macro
     ///
     /// # Parameters
     ///
-    /// index: The index of the field
-    fn add_macro(self, index: &str) -> Error {
-        let location = format!("[{}]{}", index, self.location);
-
+    /// name: The name of the macro
+    fn add_macro(self, name: &str) -> Error {
         return Error {
-            location,
+            location: self.location.add_macro(name),
             error: self.error,
         };
     }
@@ -414,10 +1513,46 @@ impl Error {
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Errors for the entire file like syntax errors have no location
+        if self.location.is_empty() {
+            return write!(f, "{}", self.error);
+        }
         return write!(f, "{}: {}", self.location, self.error);
     }
 }
 
+impl LocatedError for Error {
+    fn get_location(&self) -> Location {
+        return self.location.clone();
+    }
+}
+
+/// Describes the kind of a value for error messages without writing out
+/// entire maps and lists
+///
+/// # Parameters
+///
+/// value: The value to describe
+pub(crate) fn display_kind(value: &SerializationModel) -> String {
+    return match value {
+        SerializationModel::Map(_) => "map".to_string(),
+        SerializationModel::Array(_) => "list".to_string(),
+        SerializationModel::Value(value) => format!("value \"{value}\""),
+    };
+}
+
+/// Writes a list of validation errors with one error per line
+///
+/// # Parameters
+///
+/// errors: The errors to write
+pub(crate) fn display_errors<E: std::fmt::Display>(errors: &[E]) -> String {
+    return errors
+        .iter()
+        .map(|error| format!("\n  {error}"))
+        .collect();
+}
+
 /// Errors for when converting generic data models into JSON schema data models
 #[derive(thiserror::Error, Debug, Clone)]
 pub enum ErrorCore {
@@ -433,4 +1568,610 @@ pub enum ErrorCore {
     /// A partial macro insertion can only have a string value
     #[error("The partial macro insertion of \"{}\" in \"{}\" must be a string", .0, .1)]
     PartialMacro(String, String),
+    /// The type is neither a builtin type or a custom type
+    #[error("The type \"{}\" does not exist in the model or as a builtin type", .0)]
+    UnknownType(String),
+    /// Two data types have the same name
+    #[error("The type \"{}\" is defined more than once", .0)]
+    DuplicateType(String),
+    /// A data type has the name of a builtin type
+    #[error("The type \"{}\" has the same name as a builtin type", .0)]
+    BuiltinTypeName(String),
+    /// Two fields of a struct have the same name
+    #[error("The field \"{}\" is defined more than once", .0)]
+    DuplicateField(String),
+    /// Two enum types of an enum have the same name
+    #[error("The enum type \"{}\" is defined more than once", .0)]
+    DuplicateEnumType(String),
+    /// The type to inherit from does not exist
+    #[error("The type \"{}\" to inherit from does not exist in the model", .0)]
+    UnknownInherit(String),
+    /// The type to inherit from is not a struct
+    #[error("The type \"{}\" to inherit from must be a struct", .0)]
+    InheritNotStruct(String),
+    /// The inheritance forms a cycle
+    #[error("The struct \"{}\" inherits from itself", .0)]
+    RecursiveInherit(String),
+    /// A field is defined both in a struct and in a struct it inherits from
+    #[error("The field \"{}\" is already defined in the inherited struct \"{}\"", .0, .1)]
+    InheritedFieldConflict(String, String),
+    /// The key type of a map is not a string
+    #[error("The key type \"{}\" must be a string or a constrained type of a string", .0)]
+    InvalidKeyType(String),
+    /// A default value does not fit the type of its field
+    #[error("The default value does not fit the type of the field: {}", .0)]
+    InvalidDefault(Box<crate::schema::Error>),
+    /// A constraint is not type correct
+    #[error("The constraint {:?} is not valid: {}", .0, .1)]
+    InvalidConstraint(String, Box<constraint::Error>),
+    /// The text is not valid YAML or JSON or does not describe a data model
+    #[error("The data model could not be parsed: {}", .0)]
+    Parse(String),
+    /// An import has not been read
+    #[error("The import \"{}\" has not been resolved", .0)]
+    UnresolvedImport(String),
+    /// An imported data model could not be read
+    #[error("The import \"{}\" could not be read: {}", .0, .1)]
+    ImportRead(String, String),
+    /// An imported data model is not valid YAML or JSON or does not describe
+    /// a data model, the error has the location inside of the imported data
+    /// model
+    #[error("The import \"{}\" could not be parsed: {}", .0, .1.error)]
+    ImportParse(String, Box<Error>),
+    /// The imports form a cycle
+    #[error("The data models {:?} import each other in a cycle", .0)]
+    ImportCycle(Vec<String>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates a struct data type with the given fields
+    fn create_struct(name: &str, fields: Vec<StructField>, inherit: Option<&str>) -> DataType {
+        return DataType {
+            name: name.to_string(),
+            description: None,
+            data: DataTypeData::Struct(Struct {
+                fields,
+                inherit: inherit.map(|inherit| inherit.to_string()),
+                unknown_fields: None,
+            }),
+        };
+    }
+
+    /// Creates a struct field
+    fn create_field(name: &str, data_type: &str, default: DefaultType) -> StructField {
+        return StructField {
+            name: name.to_string(),
+            description: None,
+            data_type: data_type.to_string(),
+            default,
+        };
+    }
+
+    #[test]
+    fn validate_valid() {
+        let model = DataModel::import_yaml(include_str!("../tests/yaml_import.yaml")).unwrap();
+
+        assert!(model.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_types() {
+        let model = DataModel {
+            data_types: vec![
+                create_struct(
+                    "DataType",
+                    vec![
+                        create_field("field1", "Missing", DefaultType::Required),
+                        create_field("field1", "integer", DefaultType::Optional),
+                    ],
+                    None,
+                ),
+                DataType {
+                    name: "DataType".to_string(),
+                    description: None,
+                    data: DataTypeData::Enum(Enum {
+                        types: vec![
+                            EnumType {
+                                name: "Type".to_string(),
+                                description: None,
+                                data_type: Some("integer".to_string()),
+                            },
+                            EnumType {
+                                name: "Type".to_string(),
+                                description: None,
+                                data_type: None,
+                            },
+                        ],
+                    }),
+                },
+                DataType {
+                    name: "string".to_string(),
+                    description: None,
+                    data: DataTypeData::Variant(Variant {
+                        data_types: vec!["number".to_string(), "Unknown".to_string()],
+                    }),
+                },
+            ],
+            ..Default::default()
+        };
+
+        let errors = model
+            .validate()
+            .unwrap_err()
+            .into_iter()
+            .map(|error| error.location.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                ".data_types[0].data.fields[0].data_type",
+                ".data_types[0].data.fields[1].name",
+                ".data_types[1].name",
+                ".data_types[1].data.types[1].name",
+                ".data_types[2].name",
+                ".data_types[2].data.data_types[1]",
+            ]
+        );
+
+        // External types are allowed when generating c++
+        let errors = model.validate_types(true).unwrap_err();
+        assert_eq!(errors.len(), 4);
+    }
+
+    #[test]
+    fn validate_map() {
+        let create_map = |name: &str, data_type: &str, key_type: Option<&str>| DataType {
+            name: name.to_string(),
+            description: None,
+            data: DataTypeData::Map(Map {
+                data_type: data_type.to_string(),
+                key_type: key_type.map(|key_type| key_type.to_string()),
+            }),
+        };
+        let model = DataModel {
+            data_types: vec![
+                DataType {
+                    name: "Key".to_string(),
+                    description: None,
+                    data: DataTypeData::ConstrainedType(ConstrainedType {
+                        data_type: "string".to_string(),
+                        constraints: vec![],
+                    }),
+                },
+                create_map("DataType1", "integer", None),
+                create_map("DataType2", "DataType1", Some("Key")),
+                create_map("DataType3", "Missing", Some("integer")),
+                create_map("DataType4", "integer", Some("DataType1")),
+            ],
+            ..Default::default()
+        };
+
+        let errors = model.validate().unwrap_err();
+        assert_eq!(errors.len(), 3);
+        assert_eq!(errors[0].location.to_string(), ".data_types[3].data.key_type");
+        assert!(matches!(errors[0].error, ErrorCore::InvalidKeyType(_)));
+        assert_eq!(errors[1].location.to_string(), ".data_types[3].data.data_type");
+        assert!(matches!(errors[1].error, ErrorCore::UnknownType(_)));
+        assert_eq!(errors[2].location.to_string(), ".data_types[4].data.key_type");
+        assert!(matches!(errors[2].error, ErrorCore::InvalidKeyType(_)));
+    }
+
+    #[test]
+    fn validate_inherit() {
+        let model = DataModel {
+            data_types: vec![
+                create_struct(
+                    "DataType1",
+                    vec![create_field("field1", "integer", DefaultType::Required)],
+                    Some("DataType2"),
+                ),
+                create_struct(
+                    "DataType2",
+                    vec![create_field("field2", "integer", DefaultType::Required)],
+                    Some("DataType1"),
+                ),
+            ],
+            ..Default::default()
+        };
+
+        let errors = model.validate().unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors
+            .iter()
+            .all(|error| matches!(error.error, ErrorCore::RecursiveInherit(_))));
+        assert_eq!(
+            display_errors(&errors),
+            format!("\n  {}\n  {}", errors[0], errors[1])
+        );
+    }
+
+    #[test]
+    fn validate_defaults() {
+        let model = DataModel {
+            data_types: vec![
+                create_struct(
+                    "DataType1",
+                    vec![create_field(
+                        "field1",
+                        "integer",
+                        DefaultType::Default(SerializationModel::Value("1".to_string())),
+                    )],
+                    None,
+                ),
+                create_struct(
+                    "DataType2",
+                    vec![
+                        create_field(
+                            "field2",
+                            "DataType1",
+                            DefaultType::Default(SerializationModel::Map(IndexMap::from([(
+                                "field1".to_string(),
+                                SerializationModel::Value("$MACRO$".to_string()),
+                            )]))),
+                        ),
+                        create_field(
+                            "field3",
+                            "integer",
+                            DefaultType::Default(SerializationModel::Value("1.5".to_string())),
+                        ),
+                        create_field(
+                            "field4",
+                            "integer",
+                            DefaultType::Default(SerializationModel::Value("$MISSING$".to_string())),
+                        ),
+                        create_field(
+                            "field5",
+                            "int",
+                            DefaultType::Default(SerializationModel::Value("text".to_string())),
+                        ),
+                    ],
+                    Some("DataType1"),
+                ),
+                create_struct(
+                    "DataType3",
+                    vec![create_field(
+                        "field6",
+                        "DataType2",
+                        DefaultType::Default(SerializationModel::Map(IndexMap::from([
+                            ("field1".to_string(), SerializationModel::Value("2".to_string())),
+                            ("field3".to_string(), SerializationModel::Value("3".to_string())),
+                        ]))),
+                    )],
+                    None,
+                ),
+            ],
+            macros: IndexMap::from([(
+                "MACRO".to_string(),
+                SerializationModel::Value("5".to_string()),
+            )]),
+            ..Default::default()
+        };
+
+        // The unknown type is only an error when not allowing external types
+        let errors = model.validate_types(true).unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].location.to_string(), ".data_types[1].data.fields[1].default");
+        assert!(matches!(errors[0].error, ErrorCore::InvalidDefault(_)));
+        assert!(matches!(errors[1].error, ErrorCore::MissingMacro(_)));
+        assert_eq!(model.validate().unwrap_err().len(), 3);
+    }
+
+    #[test]
+    fn validate_constraints() {
+        // Syntax errors are found when importing
+        let error = DataModel::import_yaml(indoc::indoc! {"
+            data_types:
+            - name: Positive
+              data: !ConstrainedType
+                data_type: integer
+                constraints:
+                - x > 0
+                - x >
+        "})
+        .unwrap_err();
+        assert_eq!(
+            error.location.to_string(),
+            ".data_types[0].data.ConstrainedType.constraints[1]"
+        );
+        assert!(matches!(error.error, ErrorCore::Parse(message) if message.starts_with("Invalid")));
+
+        // Type errors are found when validating
+        let model = DataModel::import_yaml(indoc::indoc! {"
+            data_types:
+            - name: Positive
+              data: !ConstrainedType
+                data_type: integer
+                constraints:
+                - x > 0
+                - len(x) > 0
+            - name: Point
+              data: !Struct
+                fields:
+                - name: x
+                  data_type: Positive
+                  default: !Default \"-1\"
+        "})
+        .unwrap();
+        let errors = model.validate().unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].location.to_string(), ".data_types[0].data.constraints[1]");
+        assert!(matches!(errors[0].error, ErrorCore::InvalidConstraint(_, _)));
+        assert_eq!(errors[1].location.to_string(), ".data_types[1].data.fields[0].default");
+        assert!(matches!(errors[1].error, ErrorCore::InvalidDefault(_)));
+    }
+
+    #[test]
+    fn imports() {
+        let path = Path::new("tests/cpp/imports/imports.yaml");
+        let mut model = DataModel::import_yaml(&fs::read_to_string(path).unwrap()).unwrap();
+
+        // Imports must be resolved before validating
+        let errors = model.validate().unwrap_err();
+        assert_eq!(errors[0].location.to_string(), ".imports[0]");
+        assert!(matches!(errors[0].error, ErrorCore::UnresolvedImport(_)));
+
+        // Imported types and macros can be used
+        model.resolve_imports(path).unwrap();
+        assert!(model.validate().is_ok());
+        let flattened = model.clone().flatten_imports();
+        assert_eq!(flattened.data_types.len(), 7);
+        assert!(flattened.macros.contains_key("Origin"));
+
+        // Only the path is exported
+        assert!(model.export_yaml().unwrap().starts_with("imports:\n- shared.yaml\n"));
+
+        // Imported types cannot be redefined
+        let point = &model.imports[0].resolved.as_ref().unwrap().data_model.data_types[1];
+        model.data_types.push(point.clone());
+        let errors = model.validate().unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].location.to_string(), ".data_types[5].name");
+        assert!(matches!(errors[0].error, ErrorCore::DuplicateType(_)));
+    }
+
+    #[test]
+    fn import_errors() {
+        let directory = std::env::temp_dir().join("termite_import_errors");
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join("a.yaml"), "imports:\n- b.yaml\n").unwrap();
+        fs::write(directory.join("b.yaml"), "imports:\n- a.yaml\n").unwrap();
+        fs::write(directory.join("c.yaml"), "imports:\n- missing.yaml\n").unwrap();
+        fs::write(directory.join("d.yaml"), "imports:\n- e.json\n").unwrap();
+        fs::write(directory.join("e.json"), "{\"data_types\": 5}").unwrap();
+
+        let resolve = |name: &str| {
+            let path = directory.join(name);
+            let mut model = DataModel::import_yaml(&fs::read_to_string(&path).unwrap()).unwrap();
+            return model.resolve_imports(&path).unwrap_err();
+        };
+
+        let error = resolve("a.yaml");
+        assert_eq!(error.location.to_string(), ".imports[0].imports[0]");
+        match error.error {
+            ErrorCore::ImportCycle(cycle) => {
+                assert_eq!(cycle.len(), 3);
+                assert!(cycle[0].ends_with("a.yaml") && cycle[2].ends_with("a.yaml"));
+            }
+            error => panic!("Unexpected error: {error:?}"),
+        }
+
+        let error = resolve("c.yaml");
+        assert_eq!(error.location.to_string(), ".imports[0]");
+        assert!(matches!(error.error, ErrorCore::ImportRead(path, _) if path == "missing.yaml"));
+
+        // Parse errors keep the location inside of the imported data model
+        let error = resolve("d.yaml");
+        assert_eq!(error.location.to_string(), ".imports[0].data_types");
+        match error.error {
+            ErrorCore::ImportParse(path, error) => {
+                assert_eq!(path, "e.json");
+                assert_eq!(error.location.to_string(), ".data_types");
+                assert!(matches!(error.error, ErrorCore::Parse(_)));
+            }
+            error => panic!("Unexpected error: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn preserve_order() {
+        let yaml = indoc::indoc! {"
+            data_types:
+            - name: Point
+              data: !Struct
+                fields:
+                - name: y
+                  data_type: number
+                  default: Required
+                - name: x
+                  data_type: number
+                  default: Required
+            - name: Line
+              data: !Struct
+                fields:
+                - name: start
+                  data_type: Point
+                  default: !Default
+                    y: $Zero$
+                    x: $Zero$
+            headers:
+              rust: // rust
+              cpp-source: // source
+              cpp-header: // header
+            macros:
+              Zero: 0.0
+              One: '1.0'
+        "};
+
+        // The keys are exported in the order they were imported, the unused
+        // macro has no type and is kept as a string
+        let model = DataModel::import_yaml(yaml).unwrap();
+        assert_eq!(model.export_yaml().unwrap(), yaml);
+        let json = model.export_json().unwrap();
+        assert_eq!(DataModel::import_json(&json).unwrap().export_json().unwrap(), json);
+        assert!(json.contains(r#""macros":{"Zero":0.0,"One":"1.0"}"#));
+    }
+
+    #[test]
+    fn typed_export() {
+        let yaml = indoc::indoc! {"
+            data_types:
+            - name: Settings
+              data: !Struct
+                fields:
+                - name: name
+                  data_type: string
+                  default: !Default '1'
+                - name: flag
+                  data_type: string
+                  default: !Default 'true'
+                - name: count
+                  data_type: integer
+                  default: !Default 1
+                - name: enabled
+                  data_type: boolean
+                  default: !Default true
+                - name: scale
+                  data_type: number
+                  default: !Default '1.50'
+                - name: limits
+                  data_type: Limits
+                  default: !Default
+                    low: $Low$
+                    label: '2.5'
+                - name: values
+                  data_type: Values
+                  default: !Default
+                  - 1
+                  - $Low$
+                - name: external
+                  data_type: External
+                  default: !Default '3'
+            - name: Limits
+              data: !Struct
+                fields:
+                - name: low
+                  data_type: number
+                  default: Required
+                - name: label
+                  data_type: string
+                  default: Required
+            - name: Values
+              data: !Array
+                data_type: Count
+            - name: Count
+              data: !ConstrainedType
+                data_type: integer
+                constraints:
+                - x >= 0
+            macros:
+              Low: 0
+              Text: '5'
+        "};
+
+        // Strings which look like numbers or booleans keep their quotes, the
+        // number 1.50 would lose its text and is kept as a string
+        let model = DataModel::import_yaml(yaml).unwrap();
+        assert_eq!(model.export_yaml().unwrap(), yaml);
+        let json = model.export_json().unwrap();
+        assert!(json.contains(r#""default":{"Default":"1"}"#));
+        assert!(json.contains(r#""default":{"Default":1}"#));
+        assert!(json.contains(r#""default":{"Default":[1,"$Low$"]}"#));
+        assert!(json.contains(r#""macros":{"Low":0,"Text":"5"}"#));
+        assert_eq!(DataModel::import_json(&json).unwrap(), model);
+    }
+
+    #[test]
+    fn import_source() {
+        let directory = std::env::temp_dir().join("termite_import_source");
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(
+            directory.join("shared.yaml"),
+            "data_types:\n- name: Bad\n  data: !Array\n    data_type: Missing\n",
+        )
+        .unwrap();
+        let path = directory.join("model.yaml");
+        let text = "imports:\n- shared.yaml\ndata_types: []\n";
+
+        // Errors in imports are written for the imported file
+        let mut model =
+            DataModel::import_source(Source::new(path.display().to_string(), text)).unwrap();
+        model.resolve_imports(&path).unwrap();
+        let error = model.validate().unwrap_err().remove(0);
+        assert_eq!(error.location.to_string(), ".imports[0].data_types[0].data.data_type");
+        assert_eq!(
+            error.render(model.source.as_ref().unwrap()),
+            format!(
+                "{}:4:5: {error}\n  |\n4 |     data_type: Missing\n  |     ^",
+                directory.join("shared.yaml").display()
+            )
+        );
+
+        // Imports which cannot be parsed are written for the imported file,
+        // also through other imports
+        let import_error = |shared: &str| {
+            fs::write(directory.join("shared.yaml"), "imports:\n- nested.yaml\n").unwrap();
+            fs::write(directory.join("nested.yaml"), shared).unwrap();
+            let mut model =
+                DataModel::import_source(Source::new(path.display().to_string(), text)).unwrap();
+            let error = model.resolve_imports(&path).unwrap_err();
+            return (error.render(model.source.as_ref().unwrap()), error);
+        };
+        let nested = directory.join("nested.yaml").display().to_string();
+        let (rendered, error) = import_error("data_types:\n- name: Bad\n  data: 5\n");
+        assert_eq!(error.location.to_string(), ".imports[0].imports[0].data_types[0].data");
+        assert_eq!(
+            rendered,
+            format!("{nested}:3:3: {error}\n  |\n3 |   data: 5\n  |   ^")
+        );
+        let (rendered, error) = import_error("data_types: [\n");
+        assert_eq!(error.location.to_string(), ".imports[0].imports[0]");
+        assert!(rendered.starts_with(&format!("{nested}:2:1: {error}\n")));
+
+        // Parse errors have the location of the invalid value
+        let source = Source::new("model.json", r#"{"data_types": [{"name": 5}]}"#);
+        let error = DataModel::import_source(source.clone()).unwrap_err();
+        assert_eq!(error.location.to_string(), ".data_types[0]");
+        assert!(matches!(error.error, ErrorCore::Parse(_)));
+        assert!(error.render(&source).starts_with("model.json:1:18: .data_types[0]: "));
+    }
+
+    #[test]
+    fn unknown_fields() {
+        let yaml = indoc::indoc! {"
+            data_types:
+            - name: Ignored
+              data: !Struct
+                fields: []
+                unknown_fields: ignore
+            - name: Default
+              data: !Struct
+                fields: []
+            unknown_fields: reject
+        "};
+
+        // The setting is only exported when it is not the default
+        let model = DataModel::import_yaml(yaml).unwrap();
+        assert_eq!(model.unknown_fields, UnknownFields::Reject);
+        assert_eq!(model.export_yaml().unwrap(), yaml);
+        assert!(DataModel::import_yaml("data_types: []").unwrap().unknown_fields.is_collect());
+
+        // Structs without a setting use the one of the data model
+        let unknown_fields = model
+            .get_all_data_types()
+            .into_iter()
+            .map(|data_type| match data_type.data {
+                DataTypeData::Struct(data) => data.unknown_fields,
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            unknown_fields,
+            vec![Some(UnknownFields::Ignore), Some(UnknownFields::Reject)]
+        );
+    }
 }
@@ -0,0 +1,165 @@
+use super::*;
+use indoc::formatdoc;
+
+/// The style of the HTML page
+const STYLE: &str = "\
+body { font-family: sans-serif; line-height: 1.5; max-width: 60em; margin: 2em auto; }
+h2 { border-bottom: 1px solid #ddd; margin-top: 2em; }
+table { border-collapse: collapse; }
+th, td { border: 1px solid #ddd; padding: 0.25em 0.75em; text-align: left; vertical-align: top; }
+code { background: #f4f4f4; padding: 0 0.2em; }
+.kind { color: #666; font-style: italic; }";
+
+/// Writes the documentation as a self-contained HTML page
+///
+/// # Parameters
+///
+/// title: The title of the documentation
+///
+/// sections: The documentation of every data type
+pub(super) fn get_document(title: &str, sections: &[Section]) -> String {
+    let anchors = get_anchors(title, sections);
+    let mut blocks = vec![format!("<h1>{}</h1>", escape(title))];
+
+    // The index links to every section
+    if !sections.is_empty() {
+        blocks.push("<h2>Data Types</h2>".to_string());
+        blocks.push(get_table(
+            &["Type", "Kind", "Description"],
+            &sections
+                .iter()
+                .map(|section| {
+                    return vec![
+                        vec![Inline::Link(section.name.clone())],
+                        vec![Inline::Text(section.kind.to_string())],
+                        match &section.summary {
+                            Some(summary) => vec![Inline::Text(summary.clone())],
+                            None => vec![],
+                        },
+                    ];
+                })
+                .collect::<Vec<_>>(),
+            &anchors,
+        ));
+    }
+
+    for section in sections.iter() {
+        blocks.push(format!(
+            "<h2 id=\"{}\">{}</h2>",
+            anchors[&section.name],
+            escape(&section.name)
+        ));
+        blocks.push(format!("<p class=\"kind\">{}</p>", section.kind));
+        blocks.extend(
+            section
+                .blocks
+                .iter()
+                .map(|block| get_block(block, &anchors)),
+        );
+    }
+
+    return formatdoc!(
+        "
+        <!DOCTYPE html>
+        <html lang=\"en\">
+        <head>
+        <meta charset=\"utf-8\">
+        <title>{title}</title>
+        <style>
+        {STYLE}
+        </style>
+        </head>
+        <body>
+        {blocks}
+        </body>
+        </html>
+        ",
+        title = escape(title),
+        blocks = blocks.join("\n"),
+    );
+}
+
+/// Writes a single block
+///
+/// # Parameters
+///
+/// block: The block to write
+///
+/// anchors: The anchor of the section of every data type
+fn get_block(block: &Block, anchors: &HashMap<String, String>) -> String {
+    return match block {
+        Block::Paragraph(content) => format!("<p>{}</p>", get_inlines(content, anchors)),
+        Block::List { ordered, items } => {
+            let tag = if *ordered { "ol" } else { "ul" };
+            let items = items
+                .iter()
+                .map(|item| format!("<li>{}</li>\n", get_inlines(item, anchors)))
+                .collect::<String>();
+            format!("<{tag}>\n{items}</{tag}>")
+        }
+        Block::Table { header, rows } => get_table(header, rows, anchors),
+    };
+}
+
+/// Writes a table
+///
+/// # Parameters
+///
+/// header: The titles of the columns
+///
+/// rows: The content of every cell of every row
+///
+/// anchors: The anchor of the section of every data type
+fn get_table(
+    header: &[&str],
+    rows: &[Vec<Vec<Inline>>],
+    anchors: &HashMap<String, String>,
+) -> String {
+    let header = header
+        .iter()
+        .map(|title| format!("<th>{}</th>", escape(title)))
+        .collect::<String>();
+    let rows = rows
+        .iter()
+        .map(|row| {
+            let cells = row
+                .iter()
+                .map(|cell| format!("<td>{}</td>", get_inlines(cell, anchors)))
+                .collect::<String>();
+            return format!("<tr>{cells}</tr>\n");
+        })
+        .collect::<String>();
+
+    return format!("<table>\n<tr>{header}</tr>\n{rows}</table>");
+}
+
+/// Writes a piece of text, new lines are kept
+///
+/// # Parameters
+///
+/// content: The text to write
+///
+/// anchors: The anchor of the section of every data type
+fn get_inlines(content: &[Inline], anchors: &HashMap<String, String>) -> String {
+    return content
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => escape(text).replace('\n', "<br>\n"),
+            Inline::Code(code) => format!("<code>{}</code>", escape(code)),
+            Inline::Link(name) => format!("<a href=\"#{}\">{}</a>", anchors[name], escape(name)),
+        })
+        .collect();
+}
+
+/// Escapes the characters with a special meaning in HTML
+///
+/// # Parameters
+///
+/// text: The text to escape
+fn escape(text: &str) -> String {
+    return text
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;");
+}
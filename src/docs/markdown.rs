@@ -0,0 +1,134 @@
+use super::*;
+
+/// Writes the documentation as a Markdown file
+///
+/// # Parameters
+///
+/// title: The title of the documentation
+///
+/// sections: The documentation of every data type
+pub(super) fn get_document(title: &str, sections: &[Section]) -> String {
+    let anchors = get_anchors(title, sections);
+    let mut blocks = vec![format!("# {title}")];
+
+    // The index links to every section
+    if !sections.is_empty() {
+        blocks.push("## Data Types".to_string());
+        blocks.push(get_table(
+            &["Type", "Kind", "Description"],
+            &sections
+                .iter()
+                .map(|section| {
+                    return vec![
+                        vec![Inline::Link(section.name.clone())],
+                        vec![Inline::Text(section.kind.to_string())],
+                        match &section.summary {
+                            Some(summary) => vec![Inline::Text(summary.clone())],
+                            None => vec![],
+                        },
+                    ];
+                })
+                .collect::<Vec<_>>(),
+            &anchors,
+        ));
+    }
+
+    for section in sections.iter() {
+        blocks.push(format!("## {}", section.name));
+        blocks.push(format!("*{}*", section.kind));
+        blocks.extend(
+            section
+                .blocks
+                .iter()
+                .map(|block| get_block(block, &anchors)),
+        );
+    }
+
+    return format!("{}\n", blocks.join("\n\n"));
+}
+
+/// Writes a single block
+///
+/// # Parameters
+///
+/// block: The block to write
+///
+/// anchors: The anchor of the section of every data type
+fn get_block(block: &Block, anchors: &HashMap<String, String>) -> String {
+    return match block {
+        Block::Paragraph(content) => get_inlines(content, false, anchors),
+        Block::List { ordered, items } => items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let marker = if *ordered {
+                    format!("{}.", i + 1)
+                } else {
+                    "-".to_string()
+                };
+                return format!("{marker} {}", get_inlines(item, false, anchors));
+            })
+            .collect::<Vec<String>>()
+            .join("\n"),
+        Block::Table { header, rows } => get_table(header, rows, anchors),
+    };
+}
+
+/// Writes a table
+///
+/// # Parameters
+///
+/// header: The titles of the columns
+///
+/// rows: The content of every cell of every row
+///
+/// anchors: The anchor of the section of every data type
+fn get_table(
+    header: &[&str],
+    rows: &[Vec<Vec<Inline>>],
+    anchors: &HashMap<String, String>,
+) -> String {
+    let mut lines = vec![
+        format!("| {} |", header.join(" | ")),
+        format!("|{}", " --- |".repeat(header.len())),
+    ];
+    lines.extend(rows.iter().map(|row| {
+        let cells = row
+            .iter()
+            .map(|cell| get_inlines(cell, true, anchors))
+            .collect::<Vec<String>>();
+        return format!("| {} |", cells.join(" | "));
+    }));
+
+    return lines.join("\n");
+}
+
+/// Writes a piece of text
+///
+/// # Parameters
+///
+/// content: The text to write
+///
+/// in_table: True if the text is inside a table cell which must be on a single
+/// line and escape the column separators
+///
+/// anchors: The anchor of the section of every data type
+fn get_inlines(content: &[Inline], in_table: bool, anchors: &HashMap<String, String>) -> String {
+    let escape = |text: &str| {
+        return if in_table {
+            text.replace('|', "\\|").replace('\n', "<br>")
+        } else {
+            text.to_string()
+        };
+    };
+
+    return content
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => escape(text),
+            Inline::Code(code) if code.contains('`') => format!("`` {} ``", escape(code)),
+            Inline::Code(code) => format!("`{}`", escape(code)),
+            Inline::Link(name) => format!("[{}](#{})", name, anchors[name]),
+        })
+        .collect();
+}
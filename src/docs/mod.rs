@@ -0,0 +1,696 @@
+//!
+//! This module handles generation of reference documentation for a data model,
+//! written for the users writing the configuration files rather than the
+//! developers using the generated code. It creates a single Markdown file or a
+//! self-contained HTML page with an index of all data types followed by a
+//! section for every data type with its description, the fields of structs
+//! with their types and default values, the values of enums, the types of
+//! variants in the order they are tried and the constraints of constrained
+//! types. Every reference to a data type links to its section.
+//!
+
+use indexmap::IndexMap;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+mod html;
+mod markdown;
+
+use crate::{data_model, LocatedError, Location};
+
+/// Gets the anchor of the section of a data type, it is the same as the one
+/// Markdown renderers create for the heading
+///
+/// # Parameters
+///
+/// name: The name of the data type
+fn get_anchor(name: &str) -> String {
+    return name
+        .chars()
+        .filter_map(|c| {
+            return if c.is_alphanumeric() || c == '-' || c == '_' {
+                Some(c.to_ascii_lowercase())
+            } else if c == ' ' {
+                Some('-')
+            } else {
+                None
+            };
+        })
+        .collect();
+}
+
+/// Gets the anchors of the sections of all data types, anchors which are
+/// already taken get a number like Markdown renderers do for headings with the
+/// same anchor such that Point and point link to different sections
+///
+/// # Parameters
+///
+/// title: The title of the documentation, it is the first heading
+///
+/// sections: The documentation of every data type
+fn get_anchors(title: &str, sections: &[Section]) -> HashMap<String, String> {
+    let mut taken = HashMap::new();
+    let mut get_unique = |name: &str| {
+        let base = get_anchor(name);
+        let mut anchor = base.clone();
+        while taken.contains_key(&anchor) {
+            let count = taken.entry(base.clone()).or_insert(0);
+            *count += 1;
+            anchor = format!("{base}-{count}");
+        }
+        taken.insert(anchor.clone(), 0);
+        return anchor;
+    };
+
+    get_unique(title);
+    if !sections.is_empty() {
+        get_unique("Data Types");
+    }
+    return sections
+        .iter()
+        .map(|section| (section.name.clone(), get_unique(&section.name)))
+        .collect();
+}
+
+/// Writes a default value on a single line
+///
+/// # Parameters
+///
+/// value: The default value to write
+fn get_literal(value: &data_model::SerializationModel) -> String {
+    return match value {
+        data_model::SerializationModel::Map(map) => {
+            let values = map
+                .iter()
+                .map(|(key, value)| format!("{}: {}", key, get_literal(value)))
+                .collect::<Vec<String>>()
+                .join(", ");
+            format!("{{{values}}}")
+        }
+        data_model::SerializationModel::Array(list) => {
+            let values = list
+                .iter()
+                .map(get_literal)
+                .collect::<Vec<String>>()
+                .join(", ");
+            format!("[{values}]")
+        }
+        data_model::SerializationModel::Value(value) if value.is_empty() => "\"\"".to_string(),
+        data_model::SerializationModel::Value(value) => value.clone(),
+    };
+}
+
+/// A piece of text in the documentation
+#[derive(Clone, Debug, PartialEq)]
+enum Inline {
+    /// Text written as it is in Markdown
+    Text(String),
+    /// A value or type written as code
+    Code(String),
+    /// A link to the section of a data type
+    Link(String),
+}
+
+impl Inline {
+    /// Creates the reference to a type, a link for data types of the data
+    /// model and code for the builtin types
+    ///
+    /// # Parameters
+    ///
+    /// data_type: The name of the type
+    ///
+    /// data_types: The names of all the data types in the data model
+    fn data_type(data_type: &str, data_types: &HashSet<String>) -> Self {
+        return if data_types.contains(data_type) {
+            Inline::Link(data_type.to_string())
+        } else {
+            Inline::Code(data_type.to_string())
+        };
+    }
+}
+
+/// A block of the section of a data type
+#[derive(Clone, Debug, PartialEq)]
+enum Block {
+    /// A paragraph of text
+    Paragraph(Vec<Inline>),
+    /// A list where the order is either important (ordered) or not
+    List {
+        /// True if the order of the items is important
+        ordered: bool,
+        /// The content of every item
+        items: Vec<Vec<Inline>>,
+    },
+    /// A table with a header row
+    Table {
+        /// The titles of the columns
+        header: Vec<&'static str>,
+        /// The content of every cell of every row
+        rows: Vec<Vec<Vec<Inline>>>,
+    },
+}
+
+/// The documentation of a single data type
+#[derive(Clone, Debug, PartialEq)]
+struct Section {
+    /// The name of the data type
+    name: String,
+    /// The kind of data type like Struct or Enum
+    kind: &'static str,
+    /// The first paragraph of the description, used in the index
+    summary: Option<String>,
+    /// The content of the section
+    blocks: Vec<Block>,
+}
+
+/// An entire data model
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataModel {
+    /// The documentation of every data type in the order they are defined
+    sections: Vec<Section>,
+}
+
+impl DataModel {
+    /// Constructs a new documentation data model from a generic data model
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic data type to convert
+    pub fn new(data: crate::DataModel) -> Result<Self, Error> {
+        // Make sure the data model is valid, all types must be defined in the
+        // data model to be able to link to them
+        if let Err(errors) = data.validate() {
+            return Err(Error {
+                location: Location::default(),
+                error: ErrorCore::Validation(errors),
+            });
+        }
+
+        // The imported data types are documented as well, errors are located
+        // where the type is defined
+        let locations = data.get_type_locations();
+        let data = data.flatten_imports();
+
+        let names = data
+            .data_types
+            .iter()
+            .map(|data_type| data_type.name.clone())
+            .collect::<HashSet<String>>();
+        let structs = data
+            .data_types
+            .iter()
+            .filter_map(|data_type| match &data_type.data {
+                data_model::DataTypeData::Struct(data) => Some((data_type.name.as_str(), data)),
+                _ => None,
+            })
+            .collect::<HashMap<&str, &data_model::Struct>>();
+
+        let sections = data
+            .data_types
+            .iter()
+            .map(|data_type| {
+                return Section::new(data_type, &names, &structs, &data.macros).map_err(|error| {
+                    return error
+                        .add_field("data")
+                        .add_location(&locations[&data_type.name]);
+                });
+            })
+            .collect::<Result<Vec<Section>, Error>>()?;
+
+        return Ok(Self { sections });
+    }
+
+    /// Generates the documentation as a Markdown file
+    ///
+    /// # Parameters
+    ///
+    /// title: The title of the documentation
+    pub fn get_markdown(&self, title: &str) -> String {
+        return markdown::get_document(title, &self.sections);
+    }
+
+    /// Generates the documentation as a self-contained HTML page
+    ///
+    /// # Parameters
+    ///
+    /// title: The title of the documentation
+    pub fn get_html(&self, title: &str) -> String {
+        return html::get_document(title, &self.sections);
+    }
+}
+
+impl Section {
+    /// Creates the documentation of a data type
+    ///
+    /// # Parameters
+    ///
+    /// data: The data type to document
+    ///
+    /// data_types: The names of all data types, used to create links
+    ///
+    /// structs: All the structs of the data model, used to find inherited
+    /// fields
+    ///
+    /// macros: The macros to expand in default values
+    fn new(
+        data: &crate::DataType,
+        data_types: &HashSet<String>,
+        structs: &HashMap<&str, &data_model::Struct>,
+        macros: &IndexMap<String, data_model::SerializationModel>,
+    ) -> Result<Self, Error> {
+        // Every paragraph of the description is kept
+        let description = data.description.as_deref().unwrap_or("");
+        let paragraphs = description
+            .split("\n\n")
+            .map(|paragraph| paragraph.trim())
+            .filter(|paragraph| !paragraph.is_empty())
+            .collect::<Vec<&str>>();
+        let summary = paragraphs.first().map(|paragraph| paragraph.to_string());
+        let mut blocks = paragraphs
+            .iter()
+            .map(|paragraph| Block::Paragraph(vec![Inline::Text(paragraph.to_string())]))
+            .collect::<Vec<Block>>();
+
+        let kind = match &data.data {
+            data_model::DataTypeData::Struct(data) => {
+                blocks.extend(get_struct_blocks(data, data_types, structs, macros)?);
+                "Struct"
+            }
+            data_model::DataTypeData::Array(data) => {
+                blocks.push(Block::Paragraph(vec![
+                    Inline::Text("A list where every element is a ".to_string()),
+                    Inline::data_type(&data.data_type, data_types),
+                    Inline::Text(".".to_string()),
+                ]));
+                "Array"
+            }
+            data_model::DataTypeData::Map(data) => {
+                let mut paragraph = vec![Inline::Text("A map from ".to_string())];
+                match &data.key_type {
+                    Some(key_type) => {
+                        paragraph.push(Inline::Text("keys which are a ".to_string()));
+                        paragraph.push(Inline::data_type(key_type, data_types));
+                    }
+                    None => paragraph.push(Inline::Text("any string key".to_string())),
+                }
+                paragraph.push(Inline::Text(" to values which are a ".to_string()));
+                paragraph.push(Inline::data_type(&data.data_type, data_types));
+                paragraph.push(Inline::Text(".".to_string()));
+                blocks.push(Block::Paragraph(paragraph));
+                "Map"
+            }
+            data_model::DataTypeData::Variant(data) => {
+                blocks.push(Block::Paragraph(vec![Inline::Text(
+                    "Any of the following types, they are tried in order and the first one \
+                     the value is valid as is used:"
+                        .to_string(),
+                )]));
+                blocks.push(Block::List {
+                    ordered: true,
+                    items: data
+                        .data_types
+                        .iter()
+                        .map(|data_type| vec![Inline::data_type(data_type, data_types)])
+                        .collect(),
+                });
+                "Variant"
+            }
+            data_model::DataTypeData::Enum(data) => {
+                blocks.push(Block::Paragraph(vec![Inline::Text(
+                    "One of the following values, values without a type are written as the \
+                     name of the value and values with a type as a map with the name of the \
+                     value as the only key:"
+                        .to_string(),
+                )]));
+                blocks.push(Block::Table {
+                    header: vec!["Value", "Type", "Description"],
+                    rows: data
+                        .types
+                        .iter()
+                        .map(|enum_type| {
+                            return vec![
+                                vec![Inline::Code(enum_type.name.clone())],
+                                match &enum_type.data_type {
+                                    Some(data_type) => {
+                                        vec![Inline::data_type(data_type, data_types)]
+                                    }
+                                    None => vec![],
+                                },
+                                get_description(&enum_type.description),
+                            ];
+                        })
+                        .collect(),
+                });
+                "Enum"
+            }
+            data_model::DataTypeData::ConstrainedType(data) => {
+                let mut paragraph = vec![
+                    Inline::Text("A ".to_string()),
+                    Inline::data_type(&data.data_type, data_types),
+                ];
+                if data.constraints.is_empty() {
+                    paragraph.push(Inline::Text(".".to_string()));
+                    blocks.push(Block::Paragraph(paragraph));
+                } else {
+                    paragraph.push(Inline::Text(
+                        " which must fulfill all the constraints, where x is the value:"
+                            .to_string(),
+                    ));
+                    blocks.push(Block::Paragraph(paragraph));
+                    blocks.push(Block::List {
+                        ordered: false,
+                        items: data
+                            .constraints
+                            .iter()
+                            .map(|constraint| vec![Inline::Code(constraint.to_string())])
+                            .collect(),
+                    });
+                }
+                "ConstrainedType"
+            }
+        };
+
+        return Ok(Self {
+            name: data.name.clone(),
+            kind,
+            summary,
+            blocks,
+        });
+    }
+}
+
+/// Creates the table of fields of a struct together with how inheritance and
+/// unknown fields are handled
+///
+/// # Parameters
+///
+/// data: The struct to document
+///
+/// data_types: The names of all data types, used to create links
+///
+/// structs: All the structs of the data model, used to find inherited fields
+///
+/// macros: The macros to expand in default values
+fn get_struct_blocks(
+    data: &data_model::Struct,
+    data_types: &HashSet<String>,
+    structs: &HashMap<&str, &data_model::Struct>,
+    macros: &IndexMap<String, data_model::SerializationModel>,
+) -> Result<Vec<Block>, Error> {
+    let mut blocks = vec![];
+
+    // Inherited fields are listed before the fields of the struct itself
+    let mut fields = vec![];
+    if let Some(inherit) = &data.inherit {
+        blocks.push(Block::Paragraph(vec![
+            Inline::Text("Includes all the fields of ".to_string()),
+            Inline::Link(inherit.clone()),
+            Inline::Text(".".to_string()),
+        ]));
+
+        let mut parents = vec![];
+        let mut parent = structs.get(inherit.as_str());
+        while let Some(parent_data) = parent {
+            parents.push(*parent_data);
+            parent = parent_data
+                .inherit
+                .as_ref()
+                .and_then(|inherit| structs.get(inherit.as_str()));
+        }
+        for parent_data in parents.into_iter().rev() {
+            fields.extend(parent_data.fields.iter());
+        }
+    }
+    let inherited = fields.len();
+    fields.extend(data.fields.iter());
+
+    if fields.is_empty() {
+        blocks.push(Block::Paragraph(vec![Inline::Text(
+            "The struct has no fields.".to_string(),
+        )]));
+    } else {
+        let rows = fields
+            .into_iter()
+            .enumerate()
+            .map(|(i, field)| {
+                let default = match &field.default {
+                    data_model::DefaultType::Required => Inline::Text("Required".to_string()),
+                    data_model::DefaultType::Optional => Inline::Text("Optional".to_string()),
+                    data_model::DefaultType::Default(default) => {
+                        let default =
+                            data_model::expand_macros(default, macros, &mut HashSet::new())
+                                .map_err(|error| {
+                                    // Inherited fields are located at the
+                                    // inherit field
+                                    let error = Error::from(error);
+                                    return match i.checked_sub(inherited) {
+                                        Some(i) => error
+                                            .add_field("default")
+                                            .add_element(i)
+                                            .add_field("fields"),
+                                        None => error.add_field("inherit"),
+                                    };
+                                })?;
+                        Inline::Code(get_literal(&default))
+                    }
+                };
+
+                return Ok(vec![
+                    vec![Inline::Code(field.name.clone())],
+                    vec![Inline::data_type(&field.data_type, data_types)],
+                    vec![default],
+                    get_description(&field.description),
+                ]);
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        blocks.push(Block::Table {
+            header: vec!["Field", "Type", "Default", "Description"],
+            rows,
+        });
+    }
+
+    let unknown_fields = match data.unknown_fields.unwrap_or_default() {
+        data_model::UnknownFields::Collect => "Fields which are not listed are kept.",
+        data_model::UnknownFields::Reject => "Fields which are not listed are not allowed.",
+        data_model::UnknownFields::Ignore => "Fields which are not listed are ignored.",
+    };
+    blocks.push(Block::Paragraph(vec![Inline::Text(unknown_fields.to_string())]));
+
+    return Ok(blocks);
+}
+
+/// Creates the content of a description cell
+///
+/// # Parameters
+///
+/// description: The description to write
+fn get_description(description: &Option<String>) -> Vec<Inline> {
+    return match description {
+        Some(description) => vec![Inline::Text(description.trim().to_string())],
+        None => vec![],
+    };
+}
+
+/// Errors for when converting generic data models into documentation data
+/// models including location
+#[derive(Debug, Clone)]
+pub struct Error {
+    /// The location where the error occured
+    pub location: Location,
+    /// The actual error that occured
+    pub error: ErrorCore,
+}
+
+impl Error {
+    /// Sets the current location to be the field of the given base
+    ///
+    /// # Parameters
+    ///
+    /// base: The base to set in the location
+    fn add_field(self, base: &str) -> Error {
+        return Error {
+            location: self.location.add_field(base),
+            error: self.error,
+        };
+    }
+
+    /// Sets the current location to be the element of a sequence
+    ///
+    /// # Parameters
+    ///
+    /// index: The index of the element
+    fn add_element(self, index: usize) -> Error {
+        return Error {
+            location: self.location.add_element(index),
+            error: self.error,
+        };
+    }
+
+    /// Sets the current location to be inside of the location of a data type
+    ///
+    /// # Parameters
+    ///
+    /// base: The location of the data type
+    fn add_location(self, base: &Location) -> Error {
+        return Error {
+            location: self.location.add_location(base),
+            error: self.error,
+        };
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Errors for the entire data model have no location
+        if self.location.is_empty() {
+            return write!(f, "{}", self.error);
+        }
+        return write!(f, "{}: {}", self.location, self.error);
+    }
+}
+
+impl LocatedError for Error {
+    fn get_location(&self) -> Location {
+        return self.location.clone();
+    }
+}
+
+impl From<data_model::Error> for Error {
+    fn from(value: data_model::Error) -> Self {
+        return Error {
+            location: value.location.clone(),
+            error: ErrorCore::MacroError(value),
+        };
+    }
+}
+
+/// Errors for when converting generic data models into documentation data
+/// models
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum ErrorCore {
+    /// Error expanding macros
+    #[error("An error occured when expanding macros: {}", .0.error)]
+    MacroError(data_model::Error),
+    /// The data model is not valid
+    #[error("The data model is not valid:{}", data_model::display_errors(.0))]
+    Validation(Vec<data_model::Error>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn markdown() {
+        let model =
+            crate::DataModel::import_yaml(include_str!("../../tests/yaml_import.yaml")).unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        let markdown = data_model.get_markdown("Geometry Reference");
+        let expected = include_str!("../../tests/docs/yaml_import.md");
+
+        assert_eq!(str_diff(&markdown, &expected), None);
+    }
+
+    #[test]
+    fn html() {
+        let model =
+            crate::DataModel::import_yaml(include_str!("../../tests/yaml_import.yaml")).unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        let html = data_model.get_html("Geometry Reference");
+        let expected = include_str!("../../tests/docs/yaml_import.html");
+
+        assert_eq!(str_diff(&html, &expected), None);
+    }
+
+    #[test]
+    fn inheritance() {
+        let model = crate::DataModel::import_yaml(indoc::indoc! {"
+            data_types:
+            - name: Base
+              data: !Struct
+                fields:
+                - name: id
+                  data_type: integer
+                  default: Required
+            - name: Derived
+              data: !Struct
+                inherit: Base
+                unknown_fields: reject
+                fields:
+                - name: tags
+                  description: |-
+                    The tags | separated
+                    over two lines
+                  data_type: string
+                  default: !Default $TAGS$
+            macros:
+              TAGS: a|b
+        "})
+        .unwrap();
+        let markdown = DataModel::new(model).unwrap().get_markdown("Inheritance");
+
+        assert!(markdown.contains(indoc::indoc! {"
+            ## Derived
+
+            *Struct*
+
+            Includes all the fields of [Base](#base).
+
+            | Field | Type | Default | Description |
+            | --- | --- | --- | --- |
+            | `id` | `integer` | Required |  |
+            | `tags` | `string` | `a\\|b` | The tags \\| separated<br>over two lines |
+
+            Fields which are not listed are not allowed.
+        "}));
+    }
+
+    #[test]
+    fn anchors() {
+        let model = crate::DataModel::import_yaml(indoc::indoc! {"
+            data_types:
+            - name: Point
+              data: !Struct
+                fields:
+                - name: other
+                  data_type: point
+                  default: Optional
+            - name: point
+              data: !Struct
+                fields: []
+            - name: Data-Types
+              data: !Struct
+                fields: []
+        "})
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        let markdown = data_model.get_markdown("Points");
+        assert!(markdown.contains("| [Point](#point) | Struct |  |"));
+        assert!(markdown.contains("| `other` | [point](#point-1) | Optional |  |"));
+        assert!(markdown.contains("| [Data-Types](#data-types-1) | Struct |  |"));
+
+        let html = data_model.get_html("Points");
+        assert!(html.contains("<h2 id=\"point\">Point</h2>"));
+        assert!(html.contains("<h2 id=\"point-1\">point</h2>"));
+        assert!(html.contains("<h2 id=\"data-types-1\">Data-Types</h2>"));
+    }
+
+    #[test]
+    fn macro_error() {
+        // The location of the macro error is only written once
+        let error = Error::from(data_model::Error {
+            location: Location::default().add_field("default"),
+            error: data_model::ErrorCore::MissingMacro("Zero".to_string()),
+        });
+        assert_eq!(
+            error.to_string(),
+            ".default: An error occured when expanding macros: The macro \"Zero\" is not defined"
+        );
+    }
+}
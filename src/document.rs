@@ -0,0 +1,915 @@
+//! Validation of instance documents like the configuration files of a user
+//! against a data model without generating any code. The documents are
+//! checked with the same rules as the generated c++ parsers, like in c++ the
+//! ASCII whitespace around every scalar is removed before it is parsed.
+
+use crate::{data_model, DefaultType, LocatedError, Location, SerializationModel};
+use indexmap::IndexMap;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+impl data_model::DataModel {
+    /// Validates a document against a type of the data model with the same
+    /// rules as the generated c++ parsers and returns the normalized document,
+    /// all default values are filled in, booleans are written as true/false,
+    /// ignored fields are removed and variants are written as the first type
+    /// they match
+    ///
+    /// # Parameters
+    ///
+    /// type_name: The name of the type the document must be
+    ///
+    /// document: The document to validate
+    ///
+    /// # Errors
+    ///
+    /// Every problem found in the document with the location of the value
+    pub fn validate_document(
+        &self,
+        type_name: &str,
+        document: &SerializationModel,
+    ) -> Result<SerializationModel, Vec<Error>> {
+        // Make sure the data model is valid
+        if let Err(errors) = self.validate() {
+            return Err(vec![Error {
+                location: Location::default(),
+                error: ErrorCore::Validation(errors),
+            }]);
+        }
+
+        return DocumentContext::new(self).validate(document, type_name);
+    }
+}
+
+impl SerializationModel {
+    /// Imports a document from a YAML string, booleans and numbers are read as
+    /// values
+    ///
+    /// # Parameters
+    ///
+    /// document: The YAML string
+    pub fn import_yaml(document: &str) -> Result<SerializationModel, Error> {
+        return serde_yaml::from_str(document)
+            .and_then(|value| serde_yaml::from_value(data_model::sanitize_yaml(value)))
+            .map_err(|error| Error {
+                location: Location::default(),
+                error: ErrorCore::Parse(error.to_string()),
+            });
+    }
+
+    /// Imports a document from a JSON string, booleans and numbers are read as
+    /// values
+    ///
+    /// # Parameters
+    ///
+    /// document: The JSON string
+    pub fn import_json(document: &str) -> Result<SerializationModel, Error> {
+        return serde_json::from_str(document)
+            .and_then(|value| serde_json::from_value(data_model::sanitize_json(value)))
+            .map_err(|error| Error {
+                location: Location::default(),
+                error: ErrorCore::Parse(error.to_string()),
+            });
+    }
+}
+
+/// Removes the whitespace around a scalar like termite::Node::Value in c++,
+/// which only removes the ASCII whitespace of std::isspace
+///
+/// # Parameters
+///
+/// value: The scalar to trim
+fn trim(value: &str) -> &str {
+    return value.trim_matches([' ', '\t', '\n', '\x0B', '\x0C', '\r']);
+}
+
+/// All the information about the data model needed when validating a document
+pub(crate) struct DocumentContext {
+    /// All the custom types of the data model including the imported ones
+    pub(crate) custom_types: HashMap<String, data_model::DataType>,
+    /// The macros used to expand default values
+    pub(crate) macros: IndexMap<String, SerializationModel>,
+}
+
+impl DocumentContext {
+    /// Collects the data types and macros of a data model, the data model must
+    /// be valid
+    ///
+    /// # Parameters
+    ///
+    /// data_model: The data model to validate documents for
+    pub(crate) fn new(data_model: &data_model::DataModel) -> Self {
+        return DocumentContext {
+            custom_types: HashMap::from_iter(
+                data_model
+                    .get_all_data_types()
+                    .into_iter()
+                    .map(|data_type| (data_type.name.clone(), data_type)),
+            ),
+            macros: data_model.get_macros(),
+        };
+    }
+
+    /// Validates a value of a document and returns the normalized value
+    ///
+    /// # Parameters
+    ///
+    /// value: The value to validate
+    ///
+    /// data_type: The name of the type the value must be
+    pub(crate) fn validate(
+        &self,
+        value: &SerializationModel,
+        data_type: &str,
+    ) -> Result<SerializationModel, Vec<Error>> {
+        return match data_type {
+            "string" => match value {
+                SerializationModel::Value(value) => {
+                    Ok(SerializationModel::Value(trim(value).to_string()))
+                }
+                _ => Err(vec![Error::kind(value, data_type)]),
+            },
+            "boolean" => match value {
+                SerializationModel::Value(value) => match trim(value) {
+                    "true" | "True" | "1" => Ok(SerializationModel::Value("true".to_string())),
+                    "false" | "False" | "0" => Ok(SerializationModel::Value("false".to_string())),
+                    _ => Err(vec![Error {
+                        location: Location::default(),
+                        error: ErrorCore::BoolConversion(value.clone()),
+                    }]),
+                },
+                _ => Err(vec![Error::kind(value, data_type)]),
+            },
+            "integer" => match value {
+                SerializationModel::Value(value) => match trim(value).parse::<i64>() {
+                    Ok(_) => Ok(SerializationModel::Value(trim(value).to_string())),
+                    Err(_) => Err(vec![Error {
+                        location: Location::default(),
+                        error: ErrorCore::IntegerConversion(value.clone()),
+                    }]),
+                },
+                _ => Err(vec![Error::kind(value, data_type)]),
+            },
+            "number" => match value {
+                SerializationModel::Value(value) => {
+                    // Words like inf and nan are not numbers in c++, only the
+                    // YAML spelling of infinity and NaN
+                    let trimmed = trim(value);
+                    let special = trimmed.strip_prefix(['+', '-']).unwrap_or(trimmed);
+                    let is_number = (trimmed
+                        .chars()
+                        .all(|c| c.is_ascii_digit() || "+-.eE".contains(c))
+                        && trimmed.parse::<f64>().is_ok_and(|number| number.is_finite()))
+                        || [".inf", ".Inf", ".INF"].contains(&special)
+                        || [".nan", ".NaN", ".NAN"].contains(&trimmed);
+                    if is_number {
+                        Ok(SerializationModel::Value(trimmed.to_string()))
+                    } else {
+                        Err(vec![Error {
+                            location: Location::default(),
+                            error: ErrorCore::FloatConversion(value.clone()),
+                        }])
+                    }
+                }
+                _ => Err(vec![Error::kind(value, data_type)]),
+            },
+            _ => match self.custom_types.get(data_type) {
+                Some(custom_type) => match &custom_type.data {
+                    data_model::DataTypeData::Struct(data) => self.validate_struct(value, data),
+                    data_model::DataTypeData::Array(data) => self.validate_array(value, data),
+                    data_model::DataTypeData::Map(data) => self.validate_map(value, data),
+                    data_model::DataTypeData::Variant(data) => self.validate_variant(value, data),
+                    data_model::DataTypeData::Enum(data) => self.validate_enum(value, data),
+                    data_model::DataTypeData::ConstrainedType(data) => {
+                        self.validate_constrained(value, data)
+                    }
+                },
+                None => Err(vec![Error {
+                    location: Location::default(),
+                    error: ErrorCore::UnknownType(data_type.to_string()),
+                }]),
+            },
+        };
+    }
+
+    /// Validates a struct, all fields are checked even if one of them fails
+    ///
+    /// # Parameters
+    ///
+    /// value: The value to validate
+    ///
+    /// data: The struct the value must be
+    fn validate_struct(
+        &self,
+        value: &SerializationModel,
+        data: &data_model::Struct,
+    ) -> Result<SerializationModel, Vec<Error>> {
+        let map = match value {
+            SerializationModel::Map(map) => map,
+            _ => return Err(vec![Error::kind(value, "struct")]),
+        };
+
+        // Validate all the fields
+        let fields = self.struct_fields(data);
+        let mut errors = vec![];
+        let mut result = IndexMap::new();
+        for field in fields.iter() {
+            let field_value = match (map.get(&field.name), &field.default) {
+                (Some(field_value), _) => field_value.clone(),
+                (None, DefaultType::Required) => {
+                    errors.push(Error {
+                        location: Location::default(),
+                        error: ErrorCore::MissingField(field.name.clone()),
+                    });
+                    continue;
+                }
+                (None, DefaultType::Optional) => continue,
+                (None, DefaultType::Default(default)) => {
+                    match data_model::expand_macros(default, &self.macros, &mut HashSet::new()) {
+                        Ok(default) => default,
+                        Err(error) => {
+                            errors.push(Error {
+                                location: Location::default().add_field(&field.name),
+                                error: ErrorCore::MacroError(error),
+                            });
+                            continue;
+                        }
+                    }
+                }
+            };
+
+            match self.validate(&field_value, &field.data_type) {
+                Ok(field_value) => {
+                    result.insert(field.name.clone(), field_value);
+                }
+                Err(field_errors) => errors.extend(
+                    field_errors
+                        .into_iter()
+                        .map(|error| error.add_field(&field.name)),
+                ),
+            }
+        }
+
+        // Handle the fields which are not part of the struct
+        let unknown = map
+            .iter()
+            .filter(|(key, _)| fields.iter().all(|field| &field.name != *key))
+            .collect::<Vec<_>>();
+        match data.unknown_fields.unwrap_or_default() {
+            data_model::UnknownFields::Collect => result.extend(
+                unknown
+                    .into_iter()
+                    .map(|(key, value)| (key.clone(), value.clone())),
+            ),
+            data_model::UnknownFields::Reject if !unknown.is_empty() => errors.push(Error {
+                location: Location::default(),
+                error: ErrorCore::UnknownFields(
+                    unknown.into_iter().map(|(key, _)| key.clone()).collect(),
+                ),
+            }),
+            _ => (),
+        }
+
+        return if errors.is_empty() {
+            Ok(SerializationModel::Map(result))
+        } else {
+            Err(errors)
+        };
+    }
+
+    /// Gets all the fields of a struct, the fields of the inherited structs
+    /// come first
+    ///
+    /// # Parameters
+    ///
+    /// data: The struct to get the fields of
+    pub(crate) fn struct_fields<'a>(
+        &'a self,
+        data: &'a data_model::Struct,
+    ) -> Vec<&'a data_model::StructField> {
+        let mut structs = vec![data];
+        let mut visited = HashSet::new();
+        let mut inherit = &data.inherit;
+        while let Some(name) = inherit {
+            match self.custom_types.get(name) {
+                Some(data_model::DataType {
+                    data: data_model::DataTypeData::Struct(data),
+                    ..
+                }) if visited.insert(name) => {
+                    structs.push(data);
+                    inherit = &data.inherit;
+                }
+                _ => break,
+            }
+        }
+
+        return structs
+            .into_iter()
+            .rev()
+            .flat_map(|data| data.fields.iter())
+            .collect();
+    }
+
+    /// Validates an array, all elements are checked even if one of them fails
+    ///
+    /// # Parameters
+    ///
+    /// value: The value to validate
+    ///
+    /// data: The array the value must be
+    fn validate_array(
+        &self,
+        value: &SerializationModel,
+        data: &data_model::Array,
+    ) -> Result<SerializationModel, Vec<Error>> {
+        let list = match value {
+            SerializationModel::Array(list) => list,
+            _ => return Err(vec![Error::kind(value, "array")]),
+        };
+
+        let mut errors = vec![];
+        let mut result = vec![];
+        for (i, element) in list.iter().enumerate() {
+            match self.validate(element, &data.data_type) {
+                Ok(element) => result.push(element),
+                Err(element_errors) => errors.extend(
+                    element_errors
+                        .into_iter()
+                        .map(|error| error.add_element(i)),
+                ),
+            }
+        }
+
+        return if errors.is_empty() {
+            Ok(SerializationModel::Array(result))
+        } else {
+            Err(errors)
+        };
+    }
+
+    /// Validates a map, all keys and values are checked even if one of them
+    /// fails
+    ///
+    /// # Parameters
+    ///
+    /// value: The value to validate
+    ///
+    /// data: The map the value must be
+    fn validate_map(
+        &self,
+        value: &SerializationModel,
+        data: &data_model::Map,
+    ) -> Result<SerializationModel, Vec<Error>> {
+        let map = match value {
+            SerializationModel::Map(map) => map,
+            _ => return Err(vec![Error::kind(value, "map")]),
+        };
+
+        let mut errors = vec![];
+        let mut result = IndexMap::new();
+        for (key, element) in map.iter() {
+            // Make sure the key is valid
+            if let Some(key_type) = &data.key_type {
+                if let Err(key_errors) =
+                    self.validate(&SerializationModel::Value(key.clone()), key_type)
+                {
+                    errors.extend(key_errors.into_iter().map(|error| error.add_field(key)));
+                    continue;
+                }
+            }
+
+            match self.validate(element, &data.data_type) {
+                Ok(element) => {
+                    result.insert(key.clone(), element);
+                }
+                Err(element_errors) => {
+                    errors.extend(element_errors.into_iter().map(|error| error.add_field(key)))
+                }
+            }
+        }
+
+        return if errors.is_empty() {
+            Ok(SerializationModel::Map(result))
+        } else {
+            Err(errors)
+        };
+    }
+
+    /// Validates a variant, the value is the first type it matches
+    ///
+    /// # Parameters
+    ///
+    /// value: The value to validate
+    ///
+    /// data: The variant the value must be
+    fn validate_variant(
+        &self,
+        value: &SerializationModel,
+        data: &data_model::Variant,
+    ) -> Result<SerializationModel, Vec<Error>> {
+        let mut failures = vec![];
+        for data_type in data.data_types.iter() {
+            match self.validate(value, data_type) {
+                Ok(value) => return Ok(value),
+                Err(errors) => failures.push((data_type.clone(), errors)),
+            }
+        }
+
+        return Err(vec![Error {
+            location: Location::default(),
+            error: ErrorCore::VariantConversion(failures),
+        }]);
+    }
+
+    /// Validates an enum, a value without a type is written as its name and a
+    /// typed value as a map with the name as the only key
+    ///
+    /// # Parameters
+    ///
+    /// value: The value to validate
+    ///
+    /// data: The enum the value must be
+    fn validate_enum(
+        &self,
+        value: &SerializationModel,
+        data: &data_model::Enum,
+    ) -> Result<SerializationModel, Vec<Error>> {
+        return match value {
+            SerializationModel::Value(name) => {
+                match data.types.iter().find(|enum_type| enum_type.name == trim(name)) {
+                    Some(data_model::EnumType {
+                        data_type: None, ..
+                    }) => Ok(SerializationModel::Value(trim(name).to_string())),
+                    Some(_) => Err(vec![Error {
+                        location: Location::default(),
+                        error: ErrorCore::EnumMissingValue(name.clone()),
+                    }]),
+                    None => Err(vec![Error {
+                        location: Location::default(),
+                        error: ErrorCore::UnknownEnumType(name.clone()),
+                    }]),
+                }
+            }
+            SerializationModel::Map(map) => {
+                if map.len() != 1 {
+                    return Err(vec![Error {
+                        location: Location::default(),
+                        error: ErrorCore::TypedEnumLayout(map.len()),
+                    }]);
+                }
+
+                let (name, enum_value) = map.iter().next().unwrap();
+                match data.types.iter().find(|enum_type| &enum_type.name == name) {
+                    Some(data_model::EnumType {
+                        data_type: Some(data_type),
+                        ..
+                    }) => match self.validate(enum_value, data_type) {
+                        Ok(enum_value) => Ok(SerializationModel::Map(IndexMap::from([(
+                            name.clone(),
+                            enum_value,
+                        )]))),
+                        Err(errors) => {
+                            Err(errors.into_iter().map(|error| error.add_field(name)).collect())
+                        }
+                    },
+                    Some(_) => Err(vec![Error {
+                        location: Location::default(),
+                        error: ErrorCore::EnumExcessValue(name.clone()),
+                    }]),
+                    None => Err(vec![Error {
+                        location: Location::default(),
+                        error: ErrorCore::UnknownEnumType(name.clone()),
+                    }]),
+                }
+            }
+            SerializationModel::Array(_) => Err(vec![Error::kind(value, "enum")]),
+        };
+    }
+
+    /// Validates a constrained type, the constraints are only checked if the
+    /// value is valid for the constrained type
+    ///
+    /// # Parameters
+    ///
+    /// value: The value to validate
+    ///
+    /// data: The constrained type the value must be
+    fn validate_constrained(
+        &self,
+        value: &SerializationModel,
+        data: &data_model::ConstrainedType,
+    ) -> Result<SerializationModel, Vec<Error>> {
+        let value = self.validate(value, &data.data_type)?;
+
+        return match data.check_constraints(&value) {
+            Ok(()) => Ok(value),
+            Err(error) => Err(vec![Error {
+                location: Location::default(),
+                error: ErrorCore::Constraint(Box::new(error)),
+            }]),
+        };
+    }
+}
+
+/// Errors for when validating a document including location
+#[derive(Debug, Clone)]
+pub struct Error {
+    /// The location in the document where the error occured
+    pub location: Location,
+    /// The actual error that occured
+    pub error: ErrorCore,
+}
+
+impl Error {
+    /// Creates an error for a value of the wrong kind like a list where a
+    /// struct is expected
+    ///
+    /// # Parameters
+    ///
+    /// value: The value of the wrong kind
+    ///
+    /// expected: What the value should have been
+    fn kind(value: &SerializationModel, expected: &str) -> Error {
+        return Error {
+            location: Location::default(),
+            error: ErrorCore::SerializationModel(Box::new(value.clone()), expected.to_string()),
+        };
+    }
+
+    /// Sets the current location to be the field of the given base
+    ///
+    /// # Parameters
+    ///
+    /// base: The base to set in the location
+    fn add_field(self, base: &str) -> Error {
+        return Error {
+            location: self.location.add_field(base),
+            error: self.error,
+        };
+    }
+
+    /// Sets the current location to be the element of a sequence
+    ///
+    /// # Parameters
+    ///
+    /// index: The index of the element
+    fn add_element(self, index: usize) -> Error {
+        return Error {
+            location: self.location.add_element(index),
+            error: self.error,
+        };
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Errors for the entire document have no location
+        if self.location.is_empty() {
+            return write!(f, "{}", self.error);
+        }
+        return write!(f, "{}: {}", self.location, self.error);
+    }
+}
+
+impl LocatedError for Error {
+    fn get_location(&self) -> Location {
+        return self.location.clone();
+    }
+}
+
+/// Errors for when validating a document
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum ErrorCore {
+    /// The text is not valid YAML or JSON
+    #[error("The document could not be parsed: {}", .0)]
+    Parse(String),
+    /// The data model is not valid
+    #[error("The data model is not valid:{}", data_model::display_errors(.0))]
+    Validation(Vec<data_model::Error>),
+    /// The type is neither a builtin type or a custom type
+    #[error("The type \"{}\" does not exist in the model or as a builtin type", .0)]
+    UnknownType(String),
+    /// The value is a map, list or value where another one is expected
+    #[error("The {} is incompatible with the type: {}", data_model::display_kind(.0), .1)]
+    SerializationModel(Box<SerializationModel>, String),
+    /// Unable to convert to boolean
+    #[error("Unable to convert \"{}\" to a boolean", .0)]
+    BoolConversion(String),
+    /// Unable to convert to integer
+    #[error("Unable to convert \"{}\" to an integer", .0)]
+    IntegerConversion(String),
+    /// Unable to convert to float
+    #[error("Unable to convert \"{}\" to a number", .0)]
+    FloatConversion(String),
+    /// A required field of a struct is missing
+    #[error("Missing the required field \"{}\"", .0)]
+    MissingField(String),
+    /// A struct which rejects unknown fields has fields it does not know
+    #[error("Unknown fields {}", display_names(.0))]
+    UnknownFields(Vec<String>),
+    /// A default value could not be expanded
+    #[error("An error occured when expanding macros in the default value: {}", .0)]
+    MacroError(data_model::Error),
+    /// The value does not match any of the types of a variant
+    #[error("Unable to convert to any of the variant types: {}", display_failures(.0))]
+    VariantConversion(Vec<(String, Vec<Error>)>),
+    /// The enum type does not exist
+    #[error("Unknown enum type \"{}\"", .0)]
+    UnknownEnumType(String),
+    /// An enum type with a data type is written without a value
+    #[error("The enum type \"{}\" must contain a value", .0)]
+    EnumMissingValue(String),
+    /// An enum type without a data type is written with a value
+    #[error("The enum type \"{}\" must not include a value", .0)]
+    EnumExcessValue(String),
+    /// A typed enum must be a map with a single key
+    #[error("There must be exactly one enum type specified but received {}", .0)]
+    TypedEnumLayout(usize),
+    /// The value does not fulfill a constraint
+    #[error("{}", .0)]
+    Constraint(Box<crate::schema::Error>),
+}
+
+/// Writes a list of field names as a comma separated list of quoted names
+///
+/// # Parameters
+///
+/// names: The names to write
+fn display_names(names: &[String]) -> String {
+    return names
+        .iter()
+        .map(|name| format!("\"{name}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+}
+
+/// Writes the errors of all types of a variant in the same layout as the
+/// generated c++ parsers, the locations are relative to the variant
+///
+/// # Parameters
+///
+/// failures: The type names together with the errors for that type
+fn display_failures(failures: &[(String, Vec<Error>)]) -> String {
+    let failures = failures
+        .iter()
+        .map(|(data_type, errors)| {
+            let errors = errors
+                .iter()
+                .map(|error| error.to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            return format!("{data_type} {{ {errors} }}");
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    return format!("[ {failures} ]");
+}
+
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use crate::data_model;
+
+    /// Imports the example data model used by the document and example tests
+    pub(crate) fn model() -> data_model::DataModel {
+        return data_model::DataModel::import_yaml(include_str!("../tests/yaml_import.yaml"))
+            .unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_utils::*;
+    use super::*;
+
+    /// Parses a YAML document into a serialization model
+    fn document(yaml: &str) -> SerializationModel {
+        return SerializationModel::import_yaml(yaml).unwrap();
+    }
+
+    #[test]
+    fn defaults() {
+        let model = model();
+
+        // Defaults are filled in with macros expanded
+        let result = model
+            .validate_document("NamedGeometry", &document("name: A name"))
+            .unwrap();
+        assert_eq!(
+            result,
+            document(indoc::indoc! {"
+                geometry:
+                  Point:
+                    x: '1.0'
+                    y: '0.0'
+                    id: '0'
+                name: A name
+            "})
+        );
+
+        // Optional fields are left out
+        let result = model
+            .validate_document("Point", &document("y: -3.0"))
+            .unwrap();
+        assert_eq!(result, document("{x: '0.0', y: '-3.0'}"));
+    }
+
+    #[test]
+    fn variants_and_enums() {
+        let model = model();
+
+        for (type_name, yaml) in [
+            ("Geometry", include_str!("../tests/yaml_geometry_nothing_example.yaml")),
+            ("Geometry", include_str!("../tests/yaml_geometry_point_example.yaml")),
+            ("Geometry", include_str!("../tests/yaml_geometry_sizes_example.yaml")),
+        ] {
+            let value = document(yaml);
+            assert!(model.validate_document(type_name, &value).is_ok(), "{yaml}");
+        }
+
+        // The first matching type of a variant is used
+        let result = model
+            .validate_document("SizeArray", &document("[1.5, {w: 2, h: 3}]"))
+            .unwrap();
+        assert_eq!(result, document("['1.5', {w: '2', h: '3'}]"));
+
+        let errors = model
+            .validate_document("Geometry", &document("Point"))
+            .unwrap_err();
+        assert!(matches!(errors[0].error, ErrorCore::EnumMissingValue(_)));
+        let errors = model
+            .validate_document("Geometry", &document("{Nothing: 1}"))
+            .unwrap_err();
+        assert!(matches!(errors[0].error, ErrorCore::EnumExcessValue(_)));
+        let errors = model
+            .validate_document("Geometry", &document("{Point: {}, Nothing: 1}"))
+            .unwrap_err();
+        assert!(matches!(errors[0].error, ErrorCore::TypedEnumLayout(2)));
+    }
+
+    #[test]
+    fn all_errors() {
+        let model = model();
+
+        let errors = model
+            .validate_document(
+                "NamedGeometry",
+                &document(indoc::indoc! {"
+                    geometry:
+                      Sizes:
+                      - 1.0
+                      - -2.0
+                      - {w: 1.0}
+                "}),
+            )
+            .unwrap_err();
+        let locations = errors
+            .iter()
+            .map(|error| error.location.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(locations, vec![".geometry.Sizes[1]", ".geometry.Sizes[2]", ""]);
+        assert!(matches!(errors[2].error, ErrorCore::MissingField(_)));
+        match &errors[0].error {
+            ErrorCore::VariantConversion(failures) => {
+                assert!(matches!(failures[0].1[0].error, ErrorCore::Constraint(_)));
+                assert!(matches!(failures[1].1[0].error, ErrorCore::SerializationModel(_, _)));
+            }
+            error => panic!("{error}"),
+        }
+
+        // The errors of the variant types are written out
+        assert_eq!(
+            errors[0].to_string(),
+            concat!(
+                ".geometry.Sizes[1]: Unable to convert to any of the variant types: [ ",
+                "PositiveDouble { The value \"-2.0\" does not fulfill the constraint ",
+                "\"x > 0.0\" }, ",
+                "Size { The value \"-2.0\" is incompatible with the type: struct } ]"
+            )
+        );
+
+        // The locations of the nested errors are relative to the variant
+        let errors = model
+            .validate_document("SizeArray", &document("[{w: -1.0, h: 1.0}]"))
+            .unwrap_err();
+        assert_eq!(
+            errors[0].to_string(),
+            concat!(
+                "[0]: Unable to convert to any of the variant types: [ ",
+                "PositiveDouble { The map is incompatible with the type: number }, ",
+                "Size { .w: The value \"-1.0\" does not fulfill the constraint \"x > 0.0\" } ]"
+            )
+        );
+    }
+
+    #[test]
+    fn builtin_types() {
+        let model = data_model::DataModel::import_yaml(indoc::indoc! {"
+            data_types:
+            - name: Values
+              data: !Struct
+                fields:
+                - name: flag
+                  data_type: boolean
+                  default: Required
+                - name: count
+                  data_type: integer
+                  default: Required
+                - name: scale
+                  data_type: number
+                  default: Required
+                unknown_fields: reject
+        "})
+        .unwrap();
+
+        let result = model
+            .validate_document("Values", &document("{flag: True, count: 2, scale: '1e3'}"))
+            .unwrap();
+        assert_eq!(result, document("{flag: 'true', count: '2', scale: '1e3'}"));
+        for scale in ["-.inf", ".NaN"] {
+            let values = document(&format!("{{flag: true, count: 2, scale: '{scale}'}}"));
+            assert_eq!(model.validate_document("Values", &values).unwrap(), values);
+        }
+
+        let errors = model
+            .validate_document("Values", &document("{flag: yes, count: 2.5, scale: inf, x: 1}"))
+            .unwrap_err()
+            .into_iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                ".flag: Unable to convert \"yes\" to a boolean",
+                ".count: Unable to convert \"2.5\" to an integer",
+                ".scale: Unable to convert \"inf\" to a number",
+                "Unknown fields \"x\"",
+            ]
+        );
+    }
+    #[test]
+    fn whitespace() {
+        let model = data_model::DataModel::import_yaml(indoc::indoc! {"
+            data_types:
+            - name: Values
+              data: !Struct
+                fields:
+                - name: text
+                  data_type: string
+                  default: Required
+                - name: flag
+                  data_type: boolean
+                  default: Required
+                - name: count
+                  data_type: integer
+                  default: Required
+                - name: scale
+                  data_type: number
+                  default: Required
+                - name: kind
+                  data_type: Kind
+                  default: Required
+            - name: Kind
+              data: !Enum
+                types:
+                - name: Small
+        "})
+        .unwrap();
+        let values = |text: &str, flag: &str, count: &str, scale: &str, kind: &str| {
+            return SerializationModel::Map(IndexMap::from([
+                ("text".to_string(), SerializationModel::Value(text.to_string())),
+                ("flag".to_string(), SerializationModel::Value(flag.to_string())),
+                ("count".to_string(), SerializationModel::Value(count.to_string())),
+                ("scale".to_string(), SerializationModel::Value(scale.to_string())),
+                ("kind".to_string(), SerializationModel::Value(kind.to_string())),
+            ]));
+        };
+
+        // All ASCII whitespace around a scalar is removed like in c++, the
+        // whitespace inside of strings is kept
+        let result = model
+            .validate_document(
+                "Values",
+                &values(" \ta  b\n", "True\r\n", "\x0B-2\x0C", " .inf ", " Small\t"),
+            )
+            .unwrap();
+        assert_eq!(result, values("a  b", "true", "-2", ".inf", "Small"));
+
+        // Other whitespace is part of the value
+        let errors = model
+            .validate_document(
+                "Values",
+                &values("\u{a0}a\u{a0}", "\u{a0}true", "2\u{a0}", "\u{2003}1.5", "Small\u{a0}"),
+            )
+            .unwrap_err()
+            .into_iter()
+            .map(|error| error.location.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(errors, vec![".flag", ".count", ".scale", ".kind"]);
+        let result = model
+            .validate_document("Values", &values("\u{a0}a\u{a0}", "1", "0", "0", "Small"))
+            .unwrap();
+        assert_eq!(result, values("\u{a0}a\u{a0}", "true", "0", "0", "Small"));
+    }
+}
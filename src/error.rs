@@ -0,0 +1,472 @@
+use crate::{cpp, data_model, docs, document, example, python, rust, schema, typescript, LocatedError};
+use std::fmt;
+
+/// A single step of the location of a value in the data model
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LocationSegment {
+    /// A field of a mapping like data_types
+    Field(String),
+    /// An element of a sequence
+    Index(usize),
+    /// The expansion of a macro used in a default value
+    Macro(String),
+}
+
+impl fmt::Display for LocationSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            LocationSegment::Field(name) => write!(f, ".{name}"),
+            LocationSegment::Index(index) => write!(f, "[{index}]"),
+            LocationSegment::Macro(name) => write!(f, "[${name}$]"),
+        };
+    }
+}
+
+/// The location of a value in the data model as a list of steps, it is
+/// written like
+/// .data_types[0].data.fields[1].default[$Origin$]
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct Location {
+    /// The steps from the root of the data model to the value
+    pub segments: Vec<LocationSegment>,
+}
+
+impl Location {
+    /// Sets the current location to be the field of the given base
+    ///
+    /// # Parameters
+    ///
+    /// base: The name of the field
+    pub(crate) fn add_field(mut self, base: &str) -> Self {
+        self.segments.insert(0, LocationSegment::Field(base.to_string()));
+        return self;
+    }
+
+    /// Sets the current location to be the element of a sequence
+    ///
+    /// # Parameters
+    ///
+    /// index: The index of the element
+    pub(crate) fn add_element(mut self, index: usize) -> Self {
+        self.segments.insert(0, LocationSegment::Index(index));
+        return self;
+    }
+
+    /// Sets the current location to be the expansion of a macro
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the macro without dollar signs
+    pub(crate) fn add_macro(mut self, name: &str) -> Self {
+        self.segments.insert(0, LocationSegment::Macro(name.to_string()));
+        return self;
+    }
+
+    /// Sets the current location to be inside of another location
+    ///
+    /// # Parameters
+    ///
+    /// base: The location to put in front of the current location
+    pub(crate) fn add_location(mut self, base: &Location) -> Self {
+        self.segments.splice(0..0, base.segments.iter().cloned());
+        return self;
+    }
+
+    /// Gets the location of a field of the value at this location
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the field
+    pub(crate) fn with_field(&self, name: &str) -> Self {
+        let mut location = self.clone();
+        location.segments.push(LocationSegment::Field(name.to_string()));
+        return location;
+    }
+
+    /// Gets the location of an element of the sequence at this location
+    ///
+    /// # Parameters
+    ///
+    /// index: The index of the element
+    pub(crate) fn with_element(&self, index: usize) -> Self {
+        let mut location = self.clone();
+        location.segments.push(LocationSegment::Index(index));
+        return location;
+    }
+
+    /// Checks if the location is the entire data model
+    pub fn is_empty(&self) -> bool {
+        return self.segments.is_empty();
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in &self.segments {
+            write!(f, "{segment}")?;
+        }
+        return Ok(());
+    }
+}
+
+/// Any error from importing, validating or generating code from a data model,
+/// the errors of every module can be converted into it. The errors of every
+/// module can also be converted into a list which has one error for every
+/// problem of a data model which is not valid.
+#[derive(Debug, Clone)]
+pub struct Error {
+    /// The location of the value the error is about, empty if it is about the
+    /// entire data model
+    pub location: Location,
+    /// The actual error that occured
+    pub kind: ErrorKind,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.location.is_empty() {
+            return write!(f, "{}", self.kind);
+        }
+        return write!(f, "{}: {}", self.location, self.kind);
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl LocatedError for Error {
+    fn get_location(&self) -> Location {
+        return self.location.clone();
+    }
+}
+
+/// The module an error occured in together with the error
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum ErrorKind {
+    /// The data model could not be imported or is not valid
+    #[error("{}", .0)]
+    DataModel(data_model::ErrorCore),
+    /// The c++ code could not be generated
+    #[error("{}", .0)]
+    Cpp(cpp::ErrorCore),
+    /// The Rust code could not be generated
+    #[error("{}", .0)]
+    Rust(rust::ErrorCore),
+    /// The Python code could not be generated
+    #[error("{}", .0)]
+    Python(python::ErrorCore),
+    /// The TypeScript code could not be generated
+    #[error("{}", .0)]
+    TypeScript(typescript::ErrorCore),
+    /// The JSON schema could not be generated
+    #[error("{}", .0)]
+    Schema(schema::ErrorCore),
+    /// The documentation could not be generated
+    #[error("{}", .0)]
+    Docs(docs::ErrorCore),
+    /// A document is not valid for the data model
+    #[error("{}", .0)]
+    Document(document::ErrorCore),
+    /// The example document could not be generated
+    #[error("{}", .0)]
+    Example(example::ErrorCore),
+}
+
+impl From<data_model::Error> for Error {
+    fn from(value: data_model::Error) -> Self {
+        return Error {
+            location: value.location,
+            kind: ErrorKind::DataModel(value.error),
+        };
+    }
+}
+
+impl From<cpp::Error> for Error {
+    fn from(value: cpp::Error) -> Self {
+        return Error {
+            location: value.location,
+            kind: ErrorKind::Cpp(value.error),
+        };
+    }
+}
+
+impl From<rust::Error> for Error {
+    fn from(value: rust::Error) -> Self {
+        return Error {
+            location: value.location,
+            kind: ErrorKind::Rust(value.error),
+        };
+    }
+}
+
+impl From<python::Error> for Error {
+    fn from(value: python::Error) -> Self {
+        return Error {
+            location: value.location,
+            kind: ErrorKind::Python(value.error),
+        };
+    }
+}
+
+impl From<typescript::Error> for Error {
+    fn from(value: typescript::Error) -> Self {
+        return Error {
+            location: value.location,
+            kind: ErrorKind::TypeScript(value.error),
+        };
+    }
+}
+
+impl From<schema::Error> for Error {
+    fn from(value: schema::Error) -> Self {
+        return Error {
+            location: value.location,
+            kind: ErrorKind::Schema(value.error),
+        };
+    }
+}
+
+impl From<docs::Error> for Error {
+    fn from(value: docs::Error) -> Self {
+        return Error {
+            location: value.location,
+            kind: ErrorKind::Docs(value.error),
+        };
+    }
+}
+
+impl From<document::Error> for Error {
+    fn from(value: document::Error) -> Self {
+        return Error {
+            location: value.location,
+            kind: ErrorKind::Document(value.error),
+        };
+    }
+}
+
+impl From<example::Error> for Error {
+    fn from(value: example::Error) -> Self {
+        return Error {
+            location: value.location,
+            kind: ErrorKind::Example(value.error),
+        };
+    }
+}
+
+/// Converts the errors of a data model which is not valid into one error for
+/// every problem with the location of the problem
+///
+/// # Parameters
+///
+/// location: The location of the error holding the errors
+///
+/// errors: The problems with the data model
+fn expand_validation(location: &Location, errors: Vec<data_model::Error>) -> Vec<Error> {
+    return errors
+        .into_iter()
+        .map(|error| {
+            let mut error = Error::from(error);
+            error.location = error.location.add_location(location);
+            return error;
+        })
+        .collect();
+}
+
+impl From<cpp::Error> for Vec<Error> {
+    fn from(value: cpp::Error) -> Self {
+        return match value.error {
+            cpp::ErrorCore::Validation(errors) => expand_validation(&value.location, errors),
+            error => vec![Error {
+                location: value.location,
+                kind: ErrorKind::Cpp(error),
+            }],
+        };
+    }
+}
+
+impl From<rust::Error> for Vec<Error> {
+    fn from(value: rust::Error) -> Self {
+        return match value.error {
+            rust::ErrorCore::Validation(errors) => expand_validation(&value.location, errors),
+            error => vec![Error {
+                location: value.location,
+                kind: ErrorKind::Rust(error),
+            }],
+        };
+    }
+}
+
+impl From<python::Error> for Vec<Error> {
+    fn from(value: python::Error) -> Self {
+        return match value.error {
+            python::ErrorCore::Validation(errors) => expand_validation(&value.location, errors),
+            error => vec![Error {
+                location: value.location,
+                kind: ErrorKind::Python(error),
+            }],
+        };
+    }
+}
+
+impl From<typescript::Error> for Vec<Error> {
+    fn from(value: typescript::Error) -> Self {
+        return match value.error {
+            typescript::ErrorCore::Validation(errors) => expand_validation(&value.location, errors),
+            error => vec![Error {
+                location: value.location,
+                kind: ErrorKind::TypeScript(error),
+            }],
+        };
+    }
+}
+
+impl From<schema::Error> for Vec<Error> {
+    fn from(value: schema::Error) -> Self {
+        return match value.error {
+            schema::ErrorCore::Validation(errors) => expand_validation(&value.location, errors),
+            error => vec![Error {
+                location: value.location,
+                kind: ErrorKind::Schema(error),
+            }],
+        };
+    }
+}
+
+impl From<docs::Error> for Vec<Error> {
+    fn from(value: docs::Error) -> Self {
+        return match value.error {
+            docs::ErrorCore::Validation(errors) => expand_validation(&value.location, errors),
+            error => vec![Error {
+                location: value.location,
+                kind: ErrorKind::Docs(error),
+            }],
+        };
+    }
+}
+
+impl From<document::Error> for Vec<Error> {
+    fn from(value: document::Error) -> Self {
+        return match value.error {
+            document::ErrorCore::Validation(errors) => expand_validation(&value.location, errors),
+            error => vec![Error {
+                location: value.location,
+                kind: ErrorKind::Document(error),
+            }],
+        };
+    }
+}
+
+impl From<example::Error> for Vec<Error> {
+    fn from(value: example::Error) -> Self {
+        return match value.error {
+            example::ErrorCore::Validation(errors) => expand_validation(&value.location, errors),
+            error => vec![Error {
+                location: value.location,
+                kind: ErrorKind::Example(error),
+            }],
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn segments() {
+        // Errors are created at the value and the callers add their own steps
+        let base = Location::default().with_field("data_types").with_element(0);
+        let location = Location::default()
+            .add_macro("Origin")
+            .add_field("default")
+            .add_element(1)
+            .add_field("fields")
+            .add_field("data")
+            .add_location(&base);
+        assert_eq!(
+            location.segments,
+            vec![
+                LocationSegment::Field("data_types".to_string()),
+                LocationSegment::Index(0),
+                LocationSegment::Field("data".to_string()),
+                LocationSegment::Field("fields".to_string()),
+                LocationSegment::Index(1),
+                LocationSegment::Field("default".to_string()),
+                LocationSegment::Macro("Origin".to_string()),
+            ]
+        );
+        assert_eq!(location.to_string(), ".data_types[0].data.fields[1].default[$Origin$]");
+        assert_eq!(base.with_field("data").to_string(), ".data_types[0].data");
+        assert!(Location::default().is_empty());
+        assert_eq!(Location::default().to_string(), "");
+    }
+
+    #[test]
+    fn conversions() {
+        let model = crate::DataModel::import_yaml(indoc::indoc! {"
+            data_types:
+            - name: Point
+              data: !Array
+                data_type: Missing
+        "})
+        .unwrap();
+
+        let errors = model.validate().unwrap_err();
+        let error = Error::from(errors[0].clone());
+        assert_eq!(error.location.to_string(), ".data_types[0].data.data_type");
+        assert!(matches!(error.kind, ErrorKind::DataModel(_)));
+        assert_eq!(error.to_string(), errors[0].to_string());
+
+        // Errors about the entire data model have no location
+        let error = Error::from(crate::rust::DataModel::new(model).unwrap_err());
+        assert!(matches!(error.kind, ErrorKind::Rust(_)));
+        assert!(error.location.is_empty());
+        assert_eq!(error.to_string(), error.kind.to_string());
+    }
+
+    #[test]
+    fn expand_validation() {
+        let model = crate::DataModel::import_yaml(indoc::indoc! {"
+            data_types:
+            - name: Point
+              data: !Array
+                data_type: number
+            - name: Point
+              data: !Map
+                data_type: number
+                key_type: integer
+        "})
+        .unwrap();
+        let expected = model
+            .validate()
+            .unwrap_err()
+            .iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(expected.len(), 2);
+
+        // Every problem of an invalid data model is a separate error with its
+        // own location
+        let check = |errors: Vec<Error>| {
+            assert_eq!(
+                errors.iter().map(|error| error.to_string()).collect::<Vec<_>>(),
+                expected
+            );
+            assert_eq!(errors[0].location.to_string(), ".data_types[1].name");
+            assert_eq!(errors[1].location.to_string(), ".data_types[1].data.key_type");
+            assert!(errors.iter().all(|error| matches!(error.kind, ErrorKind::DataModel(_))));
+        };
+        check(crate::cpp::DataModel::new(model.clone()).unwrap_err().into());
+        check(crate::rust::DataModel::new(model.clone()).unwrap_err().into());
+        check(crate::python::DataModel::new(model.clone()).unwrap_err().into());
+        check(crate::typescript::DataModel::new(model.clone()).unwrap_err().into());
+        check(model.export_schema("Point", "id").unwrap_err().into());
+
+        // Other errors stay a single error
+        let errors: Vec<Error> = crate::DataModel::default()
+            .export_schema("Missing", "id")
+            .unwrap_err()
+            .into();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(errors[0].kind, ErrorKind::Schema(_)));
+    }
+}
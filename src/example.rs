@@ -0,0 +1,703 @@
+//! Generation of example documents for a type of a data model, like a template
+//! for the configuration file of a user with every default value filled in
+
+use crate::{
+    data_model, document, document::DocumentContext, DefaultType, LocatedError, Location,
+    SerializationModel,
+};
+use std::{collections::HashSet, fmt};
+
+/// The format of a generated example document
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExampleFormat {
+    /// A YAML document with the descriptions as comments where the optional
+    /// fields are commented out
+    Yaml,
+    /// A JSON document without comments where the optional fields are left out
+    Json,
+}
+
+impl data_model::DataModel {
+    /// Generates an example document for a type of the data model, all default
+    /// values are filled in with the macros expanded, values which must be
+    /// supplied by the user are written as placeholders like <string> and
+    /// optional fields are commented out
+    ///
+    /// # Parameters
+    ///
+    /// type_name: The name of the type to generate an example for
+    ///
+    /// format: The format of the document
+    pub fn generate_example(
+        &self,
+        type_name: &str,
+        format: ExampleFormat,
+    ) -> Result<String, Error> {
+        // Make sure the data model is valid
+        if let Err(errors) = self.validate() {
+            return Err(Error {
+                location: Location::default(),
+                error: ErrorCore::Validation(errors),
+            });
+        }
+
+        let context = DocumentContext::new(self);
+        let description = match context.custom_types.get(type_name) {
+            Some(data_type) => data_type.description.clone(),
+            None if data_model::BUILTIN_TYPES.contains(&type_name) => None,
+            None => {
+                return Err(Error {
+                    location: Location::default(),
+                    error: ErrorCore::UnknownID(type_name.to_string()),
+                })
+            }
+        };
+
+        let node = ExampleGenerator { context: &context }.generate(
+            None,
+            type_name,
+            &mut HashSet::new(),
+        )?;
+
+        return Ok(match format {
+            ExampleFormat::Yaml => write_yaml(description.as_deref(), &node),
+            ExampleFormat::Json => write_json(&node),
+        });
+    }
+}
+
+/// A value of an example document before it is written in a specific format
+#[derive(Clone, Debug, PartialEq)]
+enum ExampleNode {
+    /// A single value or placeholder
+    Value(String),
+    /// A map of fields in the order they are written
+    Map(Vec<ExampleField>),
+    /// A list of values
+    List(Vec<ExampleNode>),
+}
+
+/// A single field of a map in an example document
+#[derive(Clone, Debug, PartialEq)]
+struct ExampleField {
+    /// The key of the field
+    name: String,
+    /// The description written as a comment above the field
+    description: Option<String>,
+    /// If true then the field is commented out or left out
+    optional: bool,
+    /// The value of the field
+    value: ExampleNode,
+}
+
+/// Generates the example nodes for the types of a data model
+struct ExampleGenerator<'a> {
+    /// The data types and macros of the data model
+    context: &'a DocumentContext,
+}
+
+impl<'a> ExampleGenerator<'a> {
+    /// Generates the example node for a value of a type
+    ///
+    /// # Parameters
+    ///
+    /// value: The normalized value to write, if None then a template for the
+    /// type is generated
+    ///
+    /// data_type: The name of the type
+    ///
+    /// generating: The types which templates are currently generated for, used
+    /// to stop recursive types
+    fn generate(
+        &self,
+        value: Option<&SerializationModel>,
+        data_type: &str,
+        generating: &mut HashSet<String>,
+    ) -> Result<ExampleNode, Error> {
+        let custom_type = match self.context.custom_types.get(data_type) {
+            Some(custom_type) => custom_type,
+            None => {
+                return Ok(match value {
+                    Some(value) => generic_node(value),
+                    None => ExampleNode::Value(format!("<{data_type}>")),
+                })
+            }
+        };
+
+        // Recursive types are only written once
+        if value.is_none() && !generating.insert(data_type.to_string()) {
+            return Ok(ExampleNode::Value(format!("<{data_type}>")));
+        }
+
+        let result = match &custom_type.data {
+            data_model::DataTypeData::Struct(data) => self.generate_struct(value, data, generating),
+            data_model::DataTypeData::Array(data) => match value {
+                Some(SerializationModel::Array(values)) => values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, value)| {
+                        return self
+                            .generate(Some(value), &data.data_type, generating)
+                            .map_err(|error| error.add_element(i));
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(ExampleNode::List),
+                Some(value) => Ok(generic_node(value)),
+                None => Ok(ExampleNode::List(vec![self.generate(
+                    None,
+                    &data.data_type,
+                    generating,
+                )?])),
+            },
+            data_model::DataTypeData::Map(data) => match value {
+                Some(SerializationModel::Map(values)) => values
+                    .iter()
+                    .map(|(key, value)| {
+                        return Ok(ExampleField {
+                            name: key.clone(),
+                            description: None,
+                            optional: false,
+                            value: self
+                                .generate(Some(value), &data.data_type, generating)
+                                .map_err(|error| error.add_field(key))?,
+                        });
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .map(ExampleNode::Map),
+                Some(value) => Ok(generic_node(value)),
+                None => Ok(ExampleNode::Map(vec![ExampleField {
+                    name: format!("<{}>", data.key_type.as_deref().unwrap_or("key")),
+                    description: None,
+                    optional: false,
+                    value: self.generate(None, &data.data_type, generating)?,
+                }])),
+            },
+            data_model::DataTypeData::Variant(data) => match value {
+                // The value is written as the first type it matches like when parsing
+                Some(value) => match data
+                    .data_types
+                    .iter()
+                    .find(|data_type| self.context.validate(value, data_type).is_ok())
+                {
+                    Some(data_type) => self.generate(Some(value), data_type, generating),
+                    None => Ok(generic_node(value)),
+                },
+                None => Ok(ExampleNode::Value(format!("<{}>", data.data_types.join(" | ")))),
+            },
+            data_model::DataTypeData::Enum(data) => match value {
+                Some(SerializationModel::Map(values)) if values.len() == 1 => {
+                    let (name, value) = values.iter().next().unwrap();
+                    match data.types.iter().find(|enum_type| &enum_type.name == name) {
+                        Some(data_model::EnumType {
+                            description,
+                            data_type: Some(data_type),
+                            ..
+                        }) => Ok(ExampleNode::Map(vec![ExampleField {
+                            name: name.clone(),
+                            description: description.clone(),
+                            optional: false,
+                            value: self
+                                .generate(Some(value), data_type, generating)
+                                .map_err(|error| error.add_field(name))?,
+                        }])),
+                        _ => Ok(generic_node(&SerializationModel::Map(values.clone()))),
+                    }
+                }
+                Some(value) => Ok(generic_node(value)),
+                None => Ok(ExampleNode::Value(format!(
+                    "<{}>",
+                    data.types
+                        .iter()
+                        .map(|enum_type| enum_type.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" | ")
+                ))),
+            },
+            data_model::DataTypeData::ConstrainedType(data) => {
+                if value.is_none() && data_model::BUILTIN_TYPES.contains(&data.data_type.as_str()) {
+                    Ok(ExampleNode::Value(format!("<{data_type}>")))
+                } else {
+                    self.generate(value, &data.data_type, generating)
+                }
+            }
+        };
+
+        if value.is_none() {
+            generating.remove(data_type);
+        }
+
+        return result;
+    }
+
+    /// Generates the example node for a struct, fields which are not in the
+    /// value are optional and written as templates
+    ///
+    /// # Parameters
+    ///
+    /// value: The normalized value to write, if None then a template for the
+    /// struct is generated
+    ///
+    /// data: The struct
+    ///
+    /// generating: The types which templates are currently generated for
+    fn generate_struct(
+        &self,
+        value: Option<&SerializationModel>,
+        data: &data_model::Struct,
+        generating: &mut HashSet<String>,
+    ) -> Result<ExampleNode, Error> {
+        let values = match value {
+            Some(SerializationModel::Map(values)) => Some(values),
+            Some(value) => return Ok(generic_node(value)),
+            None => None,
+        };
+
+        let fields = self.context.struct_fields(data);
+        let mut result = vec![];
+        for field in fields.iter() {
+            let (field_value, optional) = match values {
+                Some(values) => (
+                    values.get(&field.name).cloned(),
+                    !values.contains_key(&field.name),
+                ),
+                None => match &field.default {
+                    DefaultType::Required => (None, false),
+                    DefaultType::Optional => (None, true),
+                    DefaultType::Default(default) => (
+                        Some(
+                            self.default_value(default, &field.data_type)
+                                .map_err(|error| error.add_field(&field.name))?,
+                        ),
+                        false,
+                    ),
+                },
+            };
+
+            result.push(ExampleField {
+                name: field.name.clone(),
+                description: field.description.clone(),
+                optional,
+                value: self
+                    .generate(field_value.as_ref(), &field.data_type, generating)
+                    .map_err(|error| error.add_field(&field.name))?,
+            });
+        }
+
+        // Unknown fields of a value are kept as they are
+        if let Some(values) = values {
+            result.extend(
+                values
+                    .iter()
+                    .filter(|(key, _)| fields.iter().all(|field| &field.name != *key))
+                    .map(|(key, value)| ExampleField {
+                        name: key.clone(),
+                        description: None,
+                        optional: false,
+                        value: generic_node(value),
+                    }),
+            );
+        }
+
+        return Ok(ExampleNode::Map(result));
+    }
+
+    /// Expands the macros of a default value and fills in all the default
+    /// values inside of it
+    ///
+    /// # Parameters
+    ///
+    /// default: The default value
+    ///
+    /// data_type: The type of the default value
+    fn default_value(
+        &self,
+        default: &SerializationModel,
+        data_type: &str,
+    ) -> Result<SerializationModel, Error> {
+        let default = data_model::expand_macros(default, &self.context.macros, &mut HashSet::new())
+            .map_err(|error| Error {
+                location: Location::default(),
+                error: ErrorCore::MacroError(error),
+            })?;
+
+        return self.context.validate(&default, data_type).map_err(|errors| Error {
+            location: Location::default(),
+            error: ErrorCore::InvalidDefault(errors),
+        });
+    }
+}
+
+/// Creates the example node of a value without a known type
+///
+/// # Parameters
+///
+/// value: The value to convert
+fn generic_node(value: &SerializationModel) -> ExampleNode {
+    return match value {
+        SerializationModel::Value(value) => ExampleNode::Value(value.clone()),
+        SerializationModel::Array(values) => {
+            ExampleNode::List(values.iter().map(generic_node).collect())
+        }
+        SerializationModel::Map(values) => ExampleNode::Map(
+            values
+                .iter()
+                .map(|(key, value)| ExampleField {
+                    name: key.clone(),
+                    description: None,
+                    optional: false,
+                    value: generic_node(value),
+                })
+                .collect(),
+        ),
+    };
+}
+
+/// Writes an example document as YAML
+///
+/// # Parameters
+///
+/// description: The description of the type written as a comment at the top
+///
+/// node: The example node to write
+fn write_yaml(description: Option<&str>, node: &ExampleNode) -> String {
+    let mut lines = vec![];
+    if let Some(description) = description {
+        lines.extend(description.lines().map(|line| format!("# {line}")));
+    }
+    let (header, body) = yaml_lines(node);
+    if let Some(header) = header {
+        lines.push(header);
+    }
+    lines.extend(body);
+
+    return format!("{}\n", lines.join("\n"));
+}
+
+/// Converts an example node into YAML lines
+///
+/// # Parameters
+///
+/// node: The example node to convert
+///
+/// # Returns
+///
+/// The value to write on the same line as the key if there is one and the
+/// lines to write below the key without indentation
+fn yaml_lines(node: &ExampleNode) -> (Option<String>, Vec<String>) {
+    return match node {
+        ExampleNode::Value(value) => (Some(yaml_value(value)), vec![]),
+        ExampleNode::List(values) => {
+            if values.is_empty() {
+                return (Some("[]".to_string()), vec![]);
+            }
+
+            let mut lines = vec![];
+            for value in values.iter() {
+                let (header, body) = yaml_lines(value);
+                match header {
+                    Some(header) => lines.push(format!("- {header}")),
+                    None => lines.push("-".to_string()),
+                }
+                lines.extend(body.into_iter().map(|line| format!("  {line}")));
+            }
+
+            (None, lines)
+        }
+        ExampleNode::Map(fields) => {
+            let mut lines = vec![];
+            for field in fields.iter() {
+                if let Some(description) = &field.description {
+                    lines.extend(description.lines().map(|line| format!("# {line}")));
+                }
+
+                let (header, body) = yaml_lines(&field.value);
+                let mut field_lines = vec![match header {
+                    Some(header) => format!("{}: {}", yaml_value(&field.name), header),
+                    None => format!("{}:", yaml_value(&field.name)),
+                }];
+                field_lines.extend(body.into_iter().map(|line| format!("  {line}")));
+
+                // Optional fields are commented out
+                if field.optional {
+                    lines.extend(field_lines.into_iter().map(|line| format!("# {line}")));
+                } else {
+                    lines.extend(field_lines);
+                }
+            }
+
+            // A map where every field is commented out must still be a map
+            if fields.iter().all(|field| field.optional) {
+                (Some("{}".to_string()), lines)
+            } else {
+                (None, lines)
+            }
+        }
+    };
+}
+
+/// Writes a value or key as a plain YAML scalar if it is read back as the
+/// same string and as a quoted string otherwise
+///
+/// # Parameters
+///
+/// value: The value to write
+fn yaml_value(value: &str) -> String {
+    let is_plain = !value.is_empty()
+        && value == value.trim()
+        && !["null", "Null", "NULL", "~"].contains(&value)
+        && !value.starts_with(|c: char| "-?:,[]{}#&*!|>'\"%@`".contains(c))
+        && !value.ends_with(':')
+        && !value.contains(": ")
+        && !value.contains(" #")
+        && !value.contains(['\n', '\t']);
+
+    return if is_plain {
+        value.to_string()
+    } else {
+        serde_json::to_string(value).unwrap()
+    };
+}
+
+/// Writes an example document as JSON, values which are numbers or booleans
+/// are written as such and optional fields are left out
+///
+/// # Parameters
+///
+/// node: The example node to write
+fn write_json(node: &ExampleNode) -> String {
+    return format!("{}\n", serde_json::to_string_pretty(&json_value(node)).unwrap());
+}
+
+/// Converts an example node into a JSON value
+///
+/// # Parameters
+///
+/// node: The example node to convert
+fn json_value(node: &ExampleNode) -> serde_json::Value {
+    return match node {
+        ExampleNode::Value(value) => match value.as_str() {
+            "true" => serde_json::Value::Bool(true),
+            "false" => serde_json::Value::Bool(false),
+            _ => match serde_json::from_str::<serde_json::Number>(value) {
+                Ok(number) => serde_json::Value::Number(number),
+                Err(_) => serde_json::Value::String(value.clone()),
+            },
+        },
+        ExampleNode::List(values) => {
+            serde_json::Value::Array(values.iter().map(json_value).collect())
+        }
+        ExampleNode::Map(fields) => serde_json::Value::Object(
+            fields
+                .iter()
+                .filter(|field| !field.optional)
+                .map(|field| (field.name.clone(), json_value(&field.value)))
+                .collect(),
+        ),
+    };
+}
+
+/// Errors for when generating example documents including location
+#[derive(Debug, Clone)]
+pub struct Error {
+    /// The location in the example where the error occured
+    pub location: Location,
+    /// The actual error that occured
+    pub error: ErrorCore,
+}
+
+impl Error {
+    /// Sets the current location to be the field of the given base
+    ///
+    /// # Parameters
+    ///
+    /// base: The base to set in the location
+    fn add_field(self, base: &str) -> Error {
+        return Error {
+            location: self.location.add_field(base),
+            error: self.error,
+        };
+    }
+
+    /// Sets the current location to be the element of a sequence
+    ///
+    /// # Parameters
+    ///
+    /// index: The index of the element
+    fn add_element(self, index: usize) -> Error {
+        return Error {
+            location: self.location.add_element(index),
+            error: self.error,
+        };
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Errors for the entire example have no location
+        if self.location.is_empty() {
+            return write!(f, "{}", self.error);
+        }
+        return write!(f, "{}: {}", self.location, self.error);
+    }
+}
+
+impl LocatedError for Error {
+    fn get_location(&self) -> Location {
+        return self.location.clone();
+    }
+}
+
+/// Errors for when generating example documents
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum ErrorCore {
+    /// The data model is not valid
+    #[error("The data model is not valid:{}", data_model::display_errors(.0))]
+    Validation(Vec<data_model::Error>),
+    /// Unable to find the type id
+    #[error("The type id \"{}\" does not exist in the model", .0)]
+    UnknownID(String),
+    /// A default value could not be expanded
+    #[error("An error occured when expanding macros in the default value: {}", .0)]
+    MacroError(data_model::Error),
+    /// A default value does not fit the type of its field
+    #[error(
+        "The default value does not fit the type of the field:{}",
+        data_model::display_errors(.0)
+    )]
+    InvalidDefault(Vec<document::Error>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::test_utils::model;
+
+    #[test]
+    fn yaml() {
+        let model = model();
+
+        assert_eq!(
+            model.generate_example("NamedGeometry", ExampleFormat::Yaml).unwrap(),
+            indoc::indoc! {"
+                # The geometry data
+                geometry:
+                  # A point
+                  Point:
+                    x: 1.0
+                    y: 0.0
+                    id: 0
+                # The name of the geometry
+                name: <string>
+            "}
+        );
+        assert_eq!(
+            model.generate_example("Point", ExampleFormat::Yaml).unwrap(),
+            indoc::indoc! {"
+                # A point in 2D space
+                x: 0.0
+                y: 0.0
+                # id: <integer>
+            "}
+        );
+        assert_eq!(
+            model.generate_example("SizeArray", ExampleFormat::Yaml).unwrap(),
+            "- <PositiveDouble | Size>\n"
+        );
+        assert_eq!(
+            model.generate_example("Size", ExampleFormat::Yaml).unwrap(),
+            indoc::indoc! {"
+                # The size of a box
+                # The width
+                w: <PositiveDouble>
+                # The height
+                h: <PositiveDouble>
+            "}
+        );
+    }
+
+    #[test]
+    fn json() {
+        let model = model();
+
+        assert_eq!(
+            model.generate_example("NamedGeometry", ExampleFormat::Json).unwrap(),
+            indoc::indoc! {r#"
+                {
+                  "geometry": {
+                    "Point": {
+                      "x": 1.0,
+                      "y": 0.0,
+                      "id": 0
+                    }
+                  },
+                  "name": "<string>"
+                }
+            "#}
+        );
+    }
+
+    #[test]
+    fn valid_documents() {
+        let model = model();
+
+        // The examples are valid documents when they have no placeholders
+        for format in [ExampleFormat::Yaml, ExampleFormat::Json] {
+            let example = model.generate_example("NamedGeometry", format).unwrap();
+            let document = match format {
+                ExampleFormat::Yaml => SerializationModel::import_yaml(&example),
+                ExampleFormat::Json => SerializationModel::import_json(&example),
+            }
+            .unwrap();
+            assert!(model.validate_document("NamedGeometry", &document).is_ok(), "{example}");
+        }
+    }
+
+    #[test]
+    fn recursive() {
+        let model = data_model::DataModel::import_yaml(indoc::indoc! {"
+            data_types:
+            - name: Tree
+              data: !Struct
+                fields:
+                - name: children
+                  data_type: Trees
+                  default: Required
+                - name: label
+                  data_type: string
+                  default: !Default ': leaf'
+            - name: Trees
+              data: !Array
+                data_type: Tree
+        "})
+        .unwrap();
+
+        assert_eq!(
+            model.generate_example("Tree", ExampleFormat::Yaml).unwrap(),
+            indoc::indoc! {r#"
+                children:
+                  - <Tree>
+                label: ": leaf"
+            "#}
+        );
+        let error = model.generate_example("Missing", ExampleFormat::Yaml).unwrap_err();
+        assert!(matches!(error.error, ErrorCore::UnknownID(_)));
+        assert_eq!(error.to_string(), "The type id \"Missing\" does not exist in the model");
+    }
+
+    #[test]
+    fn invalid_default() {
+        let model = model();
+
+        // The errors of the default value are written with their locations
+        let default = SerializationModel::import_yaml("{w: -1.0}").unwrap();
+        let errors = model.validate_document("Size", &default).unwrap_err();
+        assert_eq!(
+            ErrorCore::InvalidDefault(errors).to_string(),
+            concat!(
+                "The default value does not fit the type of the field:\n",
+                "  .w: The value \"-1.0\" does not fulfill the constraint \"x > 0.0\"\n",
+                "  Missing the required field \"h\""
+            )
+        );
+    }
+}
@@ -1,26 +1,40 @@
 #![doc = include_str!("../README.md")]
 
-mod data_model;
+pub mod build;
+pub mod constraint;
 pub mod cpp;
+pub mod data_model;
+pub mod docs;
+pub mod document;
+mod error;
+pub mod example;
+pub mod python;
+pub mod rust;
 pub mod schema;
+mod source;
+pub mod typescript;
 
-pub use data_model::{DataModel, DataType, DataTypeData, Struct, StructField, DefaultType, Array, Variant, Enum, EnumType, ConstrainedType, SerializationModel};
+pub use data_model::{DataModel, Import, ResolvedImport, DataType, DataTypeData, Struct, StructField, UnknownFields, DefaultType, Array, Map, Variant, Enum, EnumType, ConstrainedType, SerializationModel};
+/// The map used for headers, footers, macros and map values, it keeps the order
+/// the keys are inserted in
+pub use indexmap::IndexMap;
+pub use error::{Error, ErrorKind, Location, LocationSegment};
+pub use source::{LocatedError, Source, Span};
 
 #[cfg(test)]
 mod tests {
   use super::*;
-  use std::{
-    fs,
-    collections::HashMap,
-  };
+  use std::fs;
 
   #[test]
   fn main_model() {
     let model = DataModel {
+      imports: vec![],
+      source: None,
       namespace: vec!["my_namespace".to_string()],
-      macros: HashMap::from([("DEFAULT_COORDINATE".to_string(), SerializationModel::Value("0.0".to_string())), ("MESSAGE".to_string(), SerializationModel::Value("This is a macro message".to_string()))]),
-      headers: HashMap::from([("cpp-header".to_string(), "// My .h Header with message: $MESSAGE$".to_string()), ("cpp-source".to_string(), "// My .cpp Header and this is a dollar sign: $$".to_string())]),
-      footers: HashMap::from([("cpp-header".to_string(), "// My .h Footer".to_string()), ("cpp-source".to_string(), "// My .cpp Footer".to_string())]),
+      macros: IndexMap::from([("DEFAULT_COORDINATE".to_string(), SerializationModel::Value("0.0".to_string())), ("MESSAGE".to_string(), SerializationModel::Value("This is a macro message".to_string()))]),
+      headers: IndexMap::from([("cpp-header".to_string(), "// My .h Header with message: $MESSAGE$".to_string()), ("cpp-source".to_string(), "// My .cpp Header and this is a dollar sign: $$".to_string())]),
+      footers: IndexMap::from([("cpp-header".to_string(), "// My .h Footer".to_string()), ("cpp-source".to_string(), "// My .cpp Footer".to_string())]),
       data_types: vec![
         DataType {
           name: "PositiveDouble".to_string(),
@@ -28,7 +42,7 @@ mod tests {
           data: DataTypeData::ConstrainedType(ConstrainedType {
             data_type: "number".to_string(),
             constraints: vec![
-              "x > 0.0".to_string(),
+              constraint::Constraint::parse("x > 0.0").unwrap(),
             ],
           }),
         },
@@ -57,6 +71,7 @@ mod tests {
                 default: DefaultType::Optional,
               },
             ],
+            unknown_fields: None,
           }),
         },
         DataType {
@@ -78,6 +93,7 @@ mod tests {
                 default: DefaultType::Required,
               },
             ],
+            unknown_fields: None,
           }),
         },
         DataType {
@@ -129,8 +145,8 @@ mod tests {
                 name: "geometry".to_string(),
                 description: Some("The geometry data".to_string()),
                 data_type: "Geometry".to_string(),
-                default: DefaultType::Default(SerializationModel::Map(HashMap::from([
-                  ("Point".to_string(), SerializationModel::Map(HashMap::from([
+                default: DefaultType::Default(SerializationModel::Map(IndexMap::from([
+                  ("Point".to_string(), SerializationModel::Map(IndexMap::from([
                     ("x".to_string(), SerializationModel::Value("1.0".to_string())),
                     ("id".to_string(), SerializationModel::Value("0".to_string())),
                   ])))
@@ -144,9 +160,11 @@ mod tests {
               }
             ],
             inherit: None,
+            unknown_fields: None,
           })
         },
       ],
+      unknown_fields: UnknownFields::Collect,
     };
 
     //let yaml_model = model.export_yaml().unwrap();
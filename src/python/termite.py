@@ -0,0 +1,424 @@
+"""
+The base termite Python dependency required for all generated data models.
+
+All values are parsed from plain Python values (dicts, lists, strings, numbers
+and booleans) as obtained from json.load or yaml.safe_load, and written back to
+the same plain values. Scalars follow the same rules as termite::Node::Value in
+c++, they are read as trimmed strings such that "5" and 5 are both valid
+integers.
+"""
+
+from __future__ import annotations
+
+import re
+import typing
+
+_NUMBER_PATTERN = re.compile(r"[+-]?(\d+\.?\d*|\.\d+)([eE][+-]?\d+)?")
+_INTEGER_PATTERN = re.compile(r"[+-]?\d+")
+
+
+class Error(Exception):
+    """
+    Describes any error within a data model
+
+    Attributes:
+        message: The message describing what is wrong
+        location: The location in the data model where the error occured
+    """
+
+    def __init__(self, message: str, location: str = ""):
+        super().__init__(message, location)
+        self.message = message
+        self.location = location
+
+    def add_field(self, name: str) -> Error:
+        """
+        Adds a field to the location such that the old location is a field of
+        the new location
+
+        Args:
+            name: The name of the new base
+
+        Returns:
+            The same error
+        """
+        separator = "." if self.location and not self.location.startswith("[") else ""
+        self.location = f"{name}{separator}{self.location}"
+        return self
+
+    def add_list(self, index: int) -> Error:
+        """
+        Adds a list element to the locations such that the old location is a
+        field of the new list element
+
+        Args:
+            index: The index of the list
+
+        Returns:
+            The same error
+        """
+        separator = "." if self.location and not self.location.startswith("[") else ""
+        self.location = f"[{index}]{separator}{self.location}"
+        return self
+
+    def __eq__(self, other: object) -> bool:
+        return (
+            isinstance(other, Error)
+            and self.message == other.message
+            and self.location == other.location
+        )
+
+    def __str__(self) -> str:
+        if not self.location:
+            return self.message
+        return f"{self.location}: {self.message}"
+
+
+def _to_string(value: typing.Any) -> str:
+    """
+    Converts a scalar into the trimmed string representation used by c++
+
+    Args:
+        value: The value to convert
+
+    Returns:
+        The string representation of the value
+    """
+    if isinstance(value, bool):
+        return "true" if value else "false"
+    if isinstance(value, (int, float, str)):
+        return str(value).strip()
+    raise Error(f"Expected a value but received {type(value).__name__}")
+
+
+def parse_string(value: typing.Any) -> str:
+    """
+    Parses a string
+
+    Args:
+        value: The value to parse
+
+    Returns:
+        The parsed string
+    """
+    return _to_string(value)
+
+
+def parse_number(value: typing.Any) -> float:
+    """
+    Parses a floating point number
+
+    Args:
+        value: The value to parse
+
+    Returns:
+        The parsed number
+    """
+    if isinstance(value, bool):
+        raise Error("Unable to parse")
+    if isinstance(value, (int, float)):
+        return float(value)
+    value = _to_string(value)
+    if _NUMBER_PATTERN.fullmatch(value) is None:
+        raise Error("Unable to parse")
+    return float(value)
+
+
+def parse_integer(value: typing.Any) -> int:
+    """
+    Parses a 64 bit integer
+
+    Args:
+        value: The value to parse
+
+    Returns:
+        The parsed integer
+    """
+    if isinstance(value, (bool, float)):
+        raise Error("Unable to parse")
+    if not isinstance(value, int):
+        value = _to_string(value)
+        if _INTEGER_PATTERN.fullmatch(value) is None:
+            raise Error("Unable to parse")
+        value = int(value)
+    if value < -(2**63) or value >= 2**63:
+        raise Error("Unable to parse")
+    return value
+
+
+def parse_boolean(value: typing.Any) -> bool:
+    """
+    Parses a boolean
+
+    Args:
+        value: The value to parse
+
+    Returns:
+        The parsed boolean
+    """
+    value = _to_string(value)
+    if value in ("true", "True", "1"):
+        return True
+    if value in ("false", "False", "0"):
+        return False
+    raise Error("Unable to parse")
+
+
+def parse_map(value: typing.Any) -> dict[str, typing.Any]:
+    """
+    Makes sure the value is a map
+
+    Args:
+        value: The value to check
+
+    Returns:
+        A shallow copy of the map
+    """
+    if not isinstance(value, dict):
+        raise Error(f"Expected a map but received {type(value).__name__}")
+    return dict(value)
+
+
+def parse_list(
+    value: typing.Any, parser: typing.Callable[[typing.Any], typing.Any]
+) -> list[typing.Any]:
+    """
+    Parses all elements of a list
+
+    Args:
+        value: The list to parse
+        parser: The parser to use for each element
+
+    Returns:
+        The list of parsed elements
+    """
+    if not isinstance(value, list):
+        raise Error(f"Expected a list but received {type(value).__name__}")
+    result = []
+    for index, element in enumerate(value):
+        try:
+            result.append(parser(element))
+        except Error as error:
+            raise error.add_list(index)
+    return result
+
+
+def parse_values(
+    value: typing.Any,
+    parser: typing.Callable[[typing.Any], typing.Any],
+    key_parser: typing.Callable[[typing.Any], typing.Any] | None = None,
+) -> dict[str, typing.Any]:
+    """
+    Parses all values of a map
+
+    Args:
+        value: The map to parse
+        parser: The parser to use for each value
+        key_parser: The parser all keys must be valid for, if not given any key
+            is allowed
+
+    Returns:
+        The map of parsed values
+    """
+    result = {}
+    for key, element in parse_map(value).items():
+        try:
+            if key_parser is not None:
+                key_parser(key)
+            result[_to_string(key)] = parser(element)
+        except Error as error:
+            raise error.add_field(str(key))
+    return result
+
+
+def parse_field(
+    fields: dict[str, typing.Any],
+    name: str,
+    parser: typing.Callable[[typing.Any], typing.Any],
+) -> typing.Any:
+    """
+    Removes a field from a map and parses it
+
+    Args:
+        fields: The fields which have not yet been parsed
+        name: The name of the field to parse
+        parser: The parser to use for the field
+
+    Returns:
+        The parsed field
+    """
+    if name not in fields:
+        raise Error(f"Missing {name}")
+    try:
+        return parser(fields.pop(name))
+    except Error as error:
+        raise error.add_field(name)
+
+
+def reject_fields(fields: dict[str, typing.Any], names: list[str]) -> None:
+    """
+    Makes sure all fields of a struct have been parsed, used by structs which
+    reject unknown fields
+
+    Args:
+        fields: The fields which have not been parsed
+        names: The names of all the fields of the struct, used to suggest the
+            closest field for typos
+    """
+    if not fields:
+        return
+    unknown = []
+    for key in sorted(str(key) for key in fields):
+        message = f'"{key}"'
+        closest = min(names, key=lambda name: _edit_distance(key, name), default=None)
+        if closest is not None and _edit_distance(key, closest) <= len(closest) // 3:
+            message += f' (did you mean "{closest}"?)'
+        unknown.append(message)
+    prefix = "Unknown field " if len(unknown) == 1 else "Unknown fields "
+    raise Error(prefix + ", ".join(unknown))
+
+
+def _edit_distance(source: str, target: str) -> int:
+    """
+    Counts the number of characters which must be inserted, removed or
+    replaced to turn one string into another, swapping two neighbouring
+    characters counts as a single edit
+
+    Args:
+        source: The string to start from
+        target: The string to end at
+
+    Returns:
+        The number of edits
+    """
+    before: list[int] = []
+    previous = list(range(len(target) + 1))
+    for i in range(1, len(source) + 1):
+        current = [i] + [0] * len(target)
+        for j in range(1, len(target) + 1):
+            replace = previous[j - 1] + (source[i - 1] != target[j - 1])
+            current[j] = min(previous[j] + 1, current[j - 1] + 1, replace)
+            if (
+                i > 1
+                and j > 1
+                and source[i - 1] == target[j - 2]
+                and source[i - 2] == target[j - 1]
+            ):
+                current[j] = min(current[j], before[j - 2] + 1)
+        before, previous = previous, current
+    return previous[len(target)]
+
+
+def parse_variant(
+    value: typing.Any,
+    parsers: list[tuple[str, typing.Callable[[typing.Any], typing.Any]]],
+) -> typing.Any:
+    """
+    Parses the first of the types which succeeds
+
+    Args:
+        value: The value to parse
+        parsers: The name and parser of all the types in order
+
+    Returns:
+        The value parsed by the first successful parser
+    """
+    errors = []
+    for name, parser in parsers:
+        try:
+            return parser(value)
+        except Error as error:
+            errors.append(f"{name} {{ {error} }}")
+    raise Error(f"Unable to parse any variant: [ {', '.join(errors)} ]")
+
+
+def parse_enum(
+    value: typing.Any,
+    empty_types: dict[str, typing.Callable[[], typing.Any]],
+    types: dict[str, typing.Callable[[typing.Any], typing.Any]],
+) -> typing.Any:
+    """
+    Parses an enum where empty types are given as strings and other types as a
+    map with a single key being the name of the type
+
+    Args:
+        value: The value to parse
+        empty_types: The constructors of the enum types without a value
+        types: The parsers of the enum types with a value
+
+    Returns:
+        The parsed enum type
+    """
+    if isinstance(value, dict):
+        if len(value) != 1:
+            raise Error(
+                f"There must be exactly one enum type specified but received {len(value)}"
+            )
+        name, data = next(iter(value.items()))
+        if name in empty_types:
+            raise Error(f"Enum type {name} must not include values")
+        if name not in types:
+            raise Error(f'Unknown enum type "{name}"')
+        try:
+            return types[name](data)
+        except Error as error:
+            raise error.add_field(name)
+    if isinstance(value, list):
+        raise Error("Expected a value or a map but received list")
+
+    name = _to_string(value)
+    if name in types:
+        raise Error(f"Enum type {name} must contain a value")
+    if name not in empty_types:
+        raise Error(f'Unknown enum type "{name}"')
+    return empty_types[name]()
+
+
+def to_value(value: typing.Any) -> typing.Any:
+    """
+    Converts any value into plain Python values
+
+    Args:
+        value: The value to convert
+
+    Returns:
+        The plain value
+    """
+    if hasattr(value, "to_value"):
+        return value.to_value()
+    if isinstance(value, list):
+        return [to_value(element) for element in value]
+    if isinstance(value, dict):
+        return {key: to_value(element) for key, element in value.items()}
+    return value
+
+
+def matches(value: str, pattern: str) -> bool:
+    """
+    Checks if a regular expression matches any part of a string, used by
+    constraints
+
+    Args:
+        value: The string to search
+        pattern: The regular expression
+
+    Returns:
+        True if the pattern matches
+    """
+    return re.search(pattern, value) is not None
+
+
+def remainder(lhs: int, rhs: int) -> int:
+    """
+    Calculates the remainder of an integer division where the result has the
+    sign of the left side like in c++, used by constraints
+
+    Args:
+        lhs: The dividend
+        rhs: The divisor
+
+    Returns:
+        The remainder
+    """
+    result = abs(lhs) % abs(rhs)
+    return -result if lhs < 0 else result
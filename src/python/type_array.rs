@@ -0,0 +1,86 @@
+use super::*;
+use indoc::formatdoc;
+
+/// The type specific information for an array
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Array {
+    /// The data type for all elements of the array
+    pub(super) data_type: String,
+}
+
+impl Array {
+    /// Constructs a new Python array from a generic array
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic array to convert
+    pub(super) fn new(data: crate::Array) -> Result<Self, Error> {
+        return Ok(Self {
+            data_type: data.data_type,
+        });
+    }
+
+    /// Converts the array to a string for use in the source file
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the array
+    ///
+    /// description: The paragraphs of the docstring of the array
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_source(&self, name: &str, mut description: Vec<String>, indent: usize) -> String {
+        description.push(format!(
+            "Attributes:\n{0:indent$}values: The values of the array",
+            ""
+        ));
+
+        return formatdoc!(
+            "
+            @dataclasses.dataclass
+            class {name}:
+            {docstring}
+            {0:indent$}values: list[{typename}]
+
+            {0:indent$}@classmethod
+            {0:indent$}def from_value(cls, value: typing.Any) -> {name}:
+            {0:indent$}{0:indent$}\"\"\"Parses {name} from a plain value\"\"\"
+            {0:indent$}{0:indent$}return cls(termite.parse_list(value, {parser}))
+
+            {0:indent$}def to_value(self) -> list[typing.Any]:
+            {0:indent$}{0:indent$}\"\"\"Converts the {name} to a plain value\"\"\"
+            {0:indent$}{0:indent$}return termite.to_value(self.values)",
+            "",
+            docstring = get_docstring(&description, indent),
+            typename = get_typename(&self.data_type),
+            parser = get_parser(&self.data_type),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::*;
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn basic() {
+        // Check Python code
+        run_python_test("type_array/basic/basic");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/python/type_array/basic/basic.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source = include_str!("../../tests/python/type_array/basic/basic.py");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
@@ -0,0 +1,227 @@
+use super::*;
+use crate::constraint::{self, parenthesize, Expression, Operator};
+use indoc::formatdoc;
+
+/// The type specific information for a constrained type
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct ConstrainedType {
+    /// The type that is constrained
+    pub(super) data_type: String,
+    /// All extra constraints for the type, the constraint as it was written in
+    /// the data model and the Python expression which must be true
+    pub(super) constraints: Vec<(String, String)>,
+}
+
+impl ConstrainedType {
+    /// Constructs a new Python constrained type from a generic constrained
+    /// type
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic constrained type to convert
+    ///
+    /// data_types: All the generic data types of the data model, used to
+    /// find the types of the values in the constraints
+    pub(super) fn new(
+        data: crate::ConstrainedType,
+        data_types: &[crate::DataType],
+    ) -> Result<Self, Error> {
+        let context = constraint::Context::new(&data.data_type, data_types);
+        let constraints = data
+            .constraints
+            .iter()
+            .map(|constraint| {
+                (
+                    constraint.to_string(),
+                    get_expression(constraint.expression(), &context),
+                )
+            })
+            .collect();
+
+        return Ok(Self {
+            data_type: data.data_type,
+            constraints,
+        });
+    }
+
+    /// Converts the constrained type to a string for use in the source file,
+    /// the constraints are checked every time it is constructed
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the constrained type
+    ///
+    /// description: The paragraphs of the docstring of the constrained type
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_source(&self, name: &str, mut description: Vec<String>, indent: usize) -> String {
+        if !self.constraints.is_empty() {
+            description.push(format!(
+                "The value must fulfill the constraints:\n{constraints}",
+                constraints = self
+                    .constraints
+                    .iter()
+                    .map(|(source, _)| format!("- {source}"))
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            ));
+        }
+        description.push(format!(
+            "Attributes:\n{0:indent$}value: The constrained value",
+            ""
+        ));
+
+        let checks = self
+            .constraints
+            .iter()
+            .map(|(source, code)| {
+                formatdoc!(
+                    "
+                    {0:indent$}{0:indent$}if not ({code}):
+                    {0:indent$}{0:indent$}{0:indent$}raise termite.Error({message})
+                    ",
+                    "",
+                    message = get_string_literal(&format!("Did not pass constraint: {source}")),
+                )
+            })
+            .collect::<String>();
+        let checks = if checks.is_empty() {
+            "".to_string()
+        } else {
+            format!(
+                "\n{0:indent$}def __post_init__(self):\n{0:indent$}{0:indent$}x = self.value\n{checks}",
+                ""
+            )
+        };
+
+        return formatdoc!(
+            "
+            @dataclasses.dataclass(frozen=True)
+            class {name}:
+            {docstring}
+            {0:indent$}value: {typename}
+            {checks}
+            {0:indent$}@classmethod
+            {0:indent$}def from_value(cls, value: typing.Any) -> {name}:
+            {0:indent$}{0:indent$}\"\"\"Parses {name} from a plain value\"\"\"
+            {0:indent$}{0:indent$}return cls({parser}(value))
+
+            {0:indent$}def to_value(self) -> typing.Any:
+            {0:indent$}{0:indent$}\"\"\"Converts the {name} to a plain value\"\"\"
+            {0:indent$}{0:indent$}return termite.to_value(self.value)",
+            "",
+            docstring = get_docstring(&description, indent),
+            typename = get_typename(&self.data_type),
+            parser = get_parser(&self.data_type),
+        );
+    }
+}
+
+/// Converts an expression of the constraint language into Python, the value
+/// is denoted x and constrained types are unwrapped using their value
+///
+/// # Parameters
+///
+/// expression: The expression to convert
+///
+/// context: The types of the values in the expression
+fn get_expression(expression: &Expression, context: &constraint::Context) -> String {
+    // Converts an operand and puts it in parentheses if needed, not binds
+    // weaker than comparisons in Python
+    let operand = |operand: &Expression, precedence: u8, strict: bool| -> String {
+        let operand_precedence = match operand {
+            Expression::Not(_) if precedence <= Operator::And.precedence() => 7,
+            Expression::Not(_) => 0,
+            _ => operand.precedence(),
+        };
+        return parenthesize(
+            get_expression(operand, context),
+            operand_precedence,
+            precedence,
+            strict,
+        );
+    };
+
+    return match expression {
+        Expression::Value | Expression::Field(..) => {
+            let value = match expression {
+                Expression::Field(value, name) => {
+                    format!("{}.{}", operand(value, 7, false), get_identifier(name))
+                }
+                _ => "x".to_string(),
+            };
+            let depth = context
+                .get_type_name(expression)
+                .map_or(0, |data_type| context.get_constrained_depth(data_type));
+            format!("{value}{}", ".value".repeat(depth))
+        }
+        Expression::Number(value) => value.clone(),
+        Expression::String(value) => get_string_literal(value),
+        Expression::Boolean(true) => "True".to_string(),
+        Expression::Boolean(false) => "False".to_string(),
+        Expression::Length(value) => format!("len({})", get_expression(value, context)),
+        Expression::Matches(value, pattern) => format!(
+            "termite.matches({}, {})",
+            get_expression(value, context),
+            get_string_literal(pattern)
+        ),
+        Expression::In(value, set) => format!(
+            "{} in [{}]",
+            operand(value, Operator::Equal.precedence(), true),
+            set.iter()
+                .map(|element| get_expression(element, context))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        Expression::Not(value) => {
+            format!("not {}", operand(value, Operator::Equal.precedence(), false))
+        }
+        Expression::Negate(value) => format!("-{}", operand(value, 6, false)),
+        Expression::Binary(lhs, Operator::Remainder, rhs) => format!(
+            "termite.remainder({}, {})",
+            get_expression(lhs, context),
+            get_expression(rhs, context)
+        ),
+        Expression::Binary(lhs, operator, rhs) => {
+            let symbol = match operator {
+                Operator::Or => "or",
+                Operator::And => "and",
+                _ => operator.symbol(),
+            };
+            let precedence = operator.precedence();
+            format!(
+                "{} {symbol} {}",
+                operand(lhs, precedence, operator.is_comparison()),
+                operand(rhs, precedence, true)
+            )
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::*;
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn basic() {
+        // Check Python code
+        run_python_test("type_constrained/basic/basic");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/python/type_constrained/basic/basic.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source =
+            include_str!("../../tests/python/type_constrained/basic/basic.py");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
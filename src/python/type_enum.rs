@@ -0,0 +1,227 @@
+use super::*;
+use indoc::formatdoc;
+
+/// The type specific information for an enum
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Enum {
+    /// A list of all the enum types
+    pub(super) types: Vec<EnumType>,
+}
+
+impl Enum {
+    /// Constructs a new Python enum from a generic enum
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic enum to convert
+    pub(super) fn new(data: crate::Enum) -> Result<Self, Error> {
+        let types = data
+            .types
+            .into_iter()
+            .map(|enum_type| EnumType::new(enum_type))
+            .collect::<Result<Vec<EnumType>, Error>>()?;
+
+        return Ok(Self { types });
+    }
+
+    /// Converts the enum to a string for use in the source file, every enum
+    /// type is a nested dataclass, empty types are written as strings and
+    /// other types as a map with a single key being the name of the type
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the enum
+    ///
+    /// description: The paragraphs of the docstring of the enum
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_source(&self, name: &str, mut description: Vec<String>, indent: usize) -> String {
+        description.push(format!(
+            "Attributes:\n{0:indent$}value: The enum type which is set",
+            ""
+        ));
+
+        let types = self
+            .types
+            .iter()
+            .map(|enum_type| enum_type.get_definition(indent))
+            .collect::<String>();
+        let typename = self
+            .types
+            .iter()
+            .map(|enum_type| format!("{name}.{type_name}", type_name = enum_type.name))
+            .collect::<Vec<String>>()
+            .join(" | ");
+
+        // Get the parsers for the empty types and the types with values
+        let empty_parsers = self
+            .types
+            .iter()
+            .filter(|enum_type| enum_type.data_type.is_none())
+            .map(|enum_type| {
+                format!(
+                    "{0:indent$}{0:indent$}{0:indent$}{0:indent$}{type_name}: cls.{name},\n",
+                    "",
+                    type_name = get_string_literal(&enum_type.name),
+                    name = enum_type.name,
+                )
+            })
+            .collect::<String>();
+        let value_parsers = self
+            .types
+            .iter()
+            .filter_map(|enum_type| {
+                return enum_type.data_type.as_ref().map(|data_type| {
+                    format!(
+                        "{0:indent$}{0:indent$}{0:indent$}{0:indent$}{type_name}: lambda data: cls.{name}({parser}(data)),\n",
+                        "",
+                        type_name = get_string_literal(&enum_type.name),
+                        name = enum_type.name,
+                        parser = get_parser(data_type),
+                    )
+                });
+            })
+            .collect::<String>();
+        let get_parsers = |parsers: String| {
+            return if parsers.is_empty() {
+                "{}".to_string()
+            } else {
+                format!("{{\n{parsers}{0:indent$}{0:indent$}{0:indent$}}}", "")
+            };
+        };
+
+        // Get the writers for all types
+        let writers = self
+            .types
+            .iter()
+            .map(|enum_type| {
+                let value = match enum_type.data_type {
+                    Some(_) => format!(
+                        "{{{type_name}: termite.to_value(self.value.value)}}",
+                        type_name = get_string_literal(&enum_type.name),
+                    ),
+                    None => get_string_literal(&enum_type.name),
+                };
+
+                return format!(
+                    "{0:indent$}{0:indent$}if isinstance(self.value, {name}.{type_name}):\n{0:indent$}{0:indent$}{0:indent$}return {value}\n",
+                    "",
+                    type_name = enum_type.name,
+                );
+            })
+            .collect::<String>();
+
+        return formatdoc!(
+            "
+            @dataclasses.dataclass
+            class {name}:
+            {docstring}{types}
+            {0:indent$}value: {typename}
+
+            {0:indent$}@classmethod
+            {0:indent$}def from_value(cls, value: typing.Any) -> {name}:
+            {0:indent$}{0:indent$}\"\"\"Parses {name} from a plain value\"\"\"
+            {0:indent$}{0:indent$}return cls(termite.parse_enum(
+            {0:indent$}{0:indent$}{0:indent$}value,
+            {0:indent$}{0:indent$}{0:indent$}{empty_parsers},
+            {0:indent$}{0:indent$}{0:indent$}{value_parsers},
+            {0:indent$}{0:indent$}))
+
+            {0:indent$}def to_value(self) -> typing.Any:
+            {0:indent$}{0:indent$}\"\"\"Converts the {name} to a plain value\"\"\"
+            {writers}{0:indent$}{0:indent$}raise termite.Error(f\"Unknown enum type {{type(self.value).__name__}}\")",
+            "",
+            docstring = get_docstring(&description, indent),
+            empty_parsers = get_parsers(empty_parsers),
+            value_parsers = get_parsers(value_parsers),
+        );
+    }
+}
+
+/// The type specific information for an enum type
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct EnumType {
+    /// The name of this enum type
+    pub(super) name: String,
+    /// The description describing this enum type
+    pub(super) description: Option<String>,
+    /// The type this enum type is wrapping, may be omitted for an empty type
+    pub(super) data_type: Option<String>,
+}
+
+impl EnumType {
+    /// Constructs a new Python enum type from a generic enum type
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic enum type to convert
+    fn new(data: crate::EnumType) -> Result<Self, Error> {
+        return Ok(Self {
+            name: data.name,
+            description: data.description,
+            data_type: data.data_type,
+        });
+    }
+
+    /// Converts the enum type to a nested dataclass for use in the enum
+    /// definition
+    ///
+    /// # Parameters
+    ///
+    /// indent: The number of spaces to use for indentation
+    fn get_definition(&self, indent: usize) -> String {
+        let description = match &self.description {
+            Some(description) => vec![description.clone()],
+            None => vec![],
+        };
+        let docstring = get_docstring(&description, 2 * indent);
+
+        let body = match &self.data_type {
+            Some(data_type) if docstring.is_empty() => format!(
+                "{0:indent$}{0:indent$}value: {typename}\n",
+                "",
+                typename = get_typename(data_type),
+            ),
+            Some(data_type) => format!(
+                "{docstring}\n{0:indent$}{0:indent$}value: {typename}\n",
+                "",
+                typename = get_typename(data_type),
+            ),
+            None if docstring.is_empty() => format!("{0:indent$}{0:indent$}pass\n", ""),
+            None => docstring,
+        };
+
+        return format!(
+            "\n{0:indent$}@dataclasses.dataclass\n{0:indent$}class {name}:\n{body}",
+            "",
+            name = self.name,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::*;
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn basic() {
+        // Check Python code
+        run_python_test("type_enum/basic/basic");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/python/type_enum/basic/basic.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source = include_str!("../../tests/python/type_enum/basic/basic.py");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
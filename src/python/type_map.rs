@@ -0,0 +1,122 @@
+use super::*;
+use indoc::formatdoc;
+
+/// The type specific information for a map
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Map {
+    /// The data type for all values of the map
+    pub(super) data_type: String,
+    /// The type all keys must be valid as, if not given any key is allowed
+    pub(super) key_type: Option<String>,
+}
+
+impl Map {
+    /// Constructs a new Python map from a generic map
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic map to convert
+    pub(super) fn new(data: crate::Map) -> Result<Self, Error> {
+        // A string key is the same as not having a key type
+        let key_type = match data.key_type {
+            Some(key_type) if key_type != "string" => Some(key_type),
+            _ => None,
+        };
+
+        return Ok(Self {
+            data_type: data.data_type,
+            key_type,
+        });
+    }
+
+    /// Converts the map to a string for use in the source file, the keys are
+    /// stored as strings and are only checked against the key type when
+    /// parsing
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the map
+    ///
+    /// description: The paragraphs of the docstring of the map
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_source(&self, name: &str, mut description: Vec<String>, indent: usize) -> String {
+        description.push(format!(
+            "Attributes:\n{0:indent$}values: The values of the map",
+            ""
+        ));
+
+        let key_parser = match &self.key_type {
+            Some(key_type) => format!(", {parser}", parser = get_parser(key_type)),
+            None => "".to_string(),
+        };
+
+        return formatdoc!(
+            "
+            @dataclasses.dataclass
+            class {name}:
+            {docstring}
+            {0:indent$}values: dict[str, {typename}]
+
+            {0:indent$}@classmethod
+            {0:indent$}def from_value(cls, value: typing.Any) -> {name}:
+            {0:indent$}{0:indent$}\"\"\"Parses {name} from a plain value\"\"\"
+            {0:indent$}{0:indent$}return cls(termite.parse_values(value, {parser}{key_parser}))
+
+            {0:indent$}def to_value(self) -> dict[str, typing.Any]:
+            {0:indent$}{0:indent$}\"\"\"Converts the {name} to a plain value\"\"\"
+            {0:indent$}{0:indent$}return termite.to_value(self.values)",
+            "",
+            docstring = get_docstring(&description, indent),
+            typename = get_typename(&self.data_type),
+            parser = get_parser(&self.data_type),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::*;
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn basic() {
+        // Check Python code
+        run_python_test("type_map/basic/basic");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/python/type_map/basic/basic.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source = include_str!("../../tests/python/type_map/basic/basic.py");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn key_type() {
+        // Check Python code
+        run_python_test("type_map/key_type/key_type");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/python/type_map/key_type/key_type.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source = include_str!("../../tests/python/type_map/key_type/key_type.py");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
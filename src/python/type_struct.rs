@@ -0,0 +1,382 @@
+use super::*;
+use indoc::formatdoc;
+
+/// The type specific information for a struct
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Struct {
+    /// A list of all the fields of the struct, the fields of inherited structs
+    /// are first
+    pub(super) fields: Vec<StructField>,
+    /// What to do with unknown fields when parsing
+    pub(super) unknown_fields: crate::UnknownFields,
+}
+
+impl Struct {
+    /// Constructs a new Python struct from a generic struct
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic struct to convert
+    ///
+    /// data_types: All the generic data types of the data model, used to
+    /// find the fields of inherited structs
+    pub(super) fn new(data: crate::Struct, data_types: &[crate::DataType]) -> Result<Self, Error> {
+        // Get all the inherited fields, the data model is validated so the
+        // inheritance chain is known to be correct
+        let mut bases = vec![];
+        let mut inherit = &data.inherit;
+        while let Some(name) = inherit {
+            match data_types.iter().find(|data_type| &data_type.name == name) {
+                Some(crate::DataType {
+                    data: crate::DataTypeData::Struct(base),
+                    ..
+                }) => {
+                    bases.push(base);
+                    inherit = &base.inherit;
+                }
+                _ => break,
+            }
+        }
+
+        // Convert the fields
+        let fields = bases
+            .iter()
+            .rev()
+            .flat_map(|base| base.fields.iter())
+            .map(|field| (field, Location::default().with_field("inherit")))
+            .chain(data.fields.iter().enumerate().map(|(i, field)| {
+                return (field, Location::default().with_field("fields").with_element(i));
+            }))
+            .map(|(field, location)| StructField::new(field.clone(), location))
+            .collect::<Result<Vec<StructField>, Error>>()?;
+
+        return Ok(Self {
+            fields,
+            unknown_fields: data.unknown_fields.unwrap_or_default(),
+        });
+    }
+
+    /// Converts the struct to a string for use in the source file
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the struct
+    ///
+    /// description: The paragraphs of the docstring of the struct
+    ///
+    /// macros: A map of all macros to expand default values
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_source(
+        &self,
+        name: &str,
+        mut description: Vec<String>,
+        macros: &IndexMap<String, data_model::SerializationModel>,
+        indent: usize,
+    ) -> Result<String, Error> {
+        // Document all the fields
+        let attributes = self
+            .fields
+            .iter()
+            .filter_map(|field| {
+                return field.description.as_ref().map(|description| {
+                    // Indent all lines after the first to continue the entry
+                    let description = description
+                        .lines()
+                        .enumerate()
+                        .map(|(i, line)| {
+                            return if i == 0 || line.is_empty() {
+                                line.to_string()
+                            } else {
+                                format!("{0:indent$}{0:indent$}{line}", "")
+                            };
+                        })
+                        .collect::<Vec<String>>()
+                        .join("\n");
+
+                    format!(
+                        "{0:indent$}{name}: {description}\n",
+                        "",
+                        name = get_identifier(&field.name),
+                    )
+                });
+            })
+            .collect::<String>();
+        let collect = self.unknown_fields == crate::UnknownFields::Collect;
+        if collect {
+            description.push(format!(
+                "Attributes:\n{attributes}{0:indent$}extra_fields: All fields which were not captured when parsing",
+                ""
+            ));
+        } else if !attributes.is_empty() {
+            description.push(format!("Attributes:\n{}", attributes.trim_end()));
+        }
+
+        // Get the fields
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| match field.get_definition(macros, indent) {
+                Ok(result) => Ok(result),
+                Err(error) => Err(error.add_field("default").add_location(&field.location)),
+            })
+            .collect::<Result<String, Error>>()?;
+
+        // Get the parsers and writers of the fields
+        let parsers = self
+            .fields
+            .iter()
+            .map(|field| field.get_parser(indent))
+            .collect::<String>();
+        let writers = self
+            .fields
+            .iter()
+            .map(|field| field.get_writer(indent))
+            .collect::<String>();
+
+        // Unknown fields are either kept in extra_fields, rejected after all
+        // known fields are parsed or dropped
+        let (extra_fields, unknown, check, construct, value) = match self.unknown_fields {
+            crate::UnknownFields::Collect => (
+                format!(
+                    "{0:indent$}extra_fields: dict[str, typing.Any] = dataclasses.field(default_factory=dict)\n",
+                    ""
+                ),
+                "kept in extra_fields",
+                "".to_string(),
+                "cls(**kwargs, extra_fields=fields)",
+                "termite.to_value(self.extra_fields)",
+            ),
+            crate::UnknownFields::Reject => (
+                "".to_string(),
+                "rejected",
+                format!(
+                    "{0:indent$}{0:indent$}termite.reject_fields(fields, [{names}])\n",
+                    "",
+                    names = self
+                        .fields
+                        .iter()
+                        .map(|field| get_string_literal(&field.name))
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                ),
+                "cls(**kwargs)",
+                "{}",
+            ),
+            crate::UnknownFields::Ignore => {
+                ("".to_string(), "ignored", "".to_string(), "cls(**kwargs)", "{}")
+            }
+        };
+
+        // Separate the docstring and the attributes from the methods
+        let mut body = get_docstring(&description, indent);
+        let attributes = format!("{fields}{extra_fields}");
+        if !body.is_empty() && !attributes.is_empty() {
+            body.push('\n');
+        }
+        body.push_str(&attributes);
+        if !body.is_empty() {
+            body.push('\n');
+        }
+
+        return Ok(formatdoc!(
+            "
+            @dataclasses.dataclass(kw_only=True)
+            class {name}:
+            {body}{0:indent$}@classmethod
+            {0:indent$}def from_value(cls, value: typing.Any) -> {name}:
+            {0:indent$}{0:indent$}\"\"\"Parses {name} from a plain value, unknown fields are {unknown}\"\"\"
+            {0:indent$}{0:indent$}fields = termite.parse_map(value)
+            {0:indent$}{0:indent$}kwargs = {{}}
+            {parsers}{check}{0:indent$}{0:indent$}return {construct}
+
+            {0:indent$}def to_value(self) -> dict[str, typing.Any]:
+            {0:indent$}{0:indent$}\"\"\"Converts the {name} to a plain value\"\"\"
+            {0:indent$}{0:indent$}value = {value}
+            {writers}{0:indent$}{0:indent$}return value",
+            "",
+        ));
+    }
+}
+
+/// The data for a single field in a struct
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct StructField {
+    /// The name of the field
+    pub(super) name: String,
+    /// The description of the field
+    pub(super) description: Option<String>,
+    /// What type the field is, without None
+    pub(super) data_type: String,
+    /// A default value if it it not required
+    pub(super) default: crate::DefaultType,
+    /// The location of the field in the struct, used for errors
+    pub(super) location: Location,
+}
+
+impl StructField {
+    /// Constructs a new Python struct field from a generic struct field
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic struct field to convert
+    ///
+    /// location: The location of the field in the struct
+    fn new(data: crate::StructField, location: Location) -> Result<Self, Error> {
+        return Ok(Self {
+            name: data.name,
+            description: data.description,
+            data_type: data.data_type,
+            default: data.default,
+            location,
+        });
+    }
+
+    /// Converts the field to a string for use in the dataclass definition,
+    /// default values are parsed from their plain value when the field is not
+    /// supplied
+    ///
+    /// # Parameters
+    ///
+    /// macros: A map of all macros to expand default values
+    ///
+    /// indent: The number of spaces to use for indentation
+    fn get_definition(
+        &self,
+        macros: &IndexMap<String, data_model::SerializationModel>,
+        indent: usize,
+    ) -> Result<String, Error> {
+        let typename = get_typename(&self.data_type);
+        let definition = match &self.default {
+            crate::DefaultType::Required => typename,
+            crate::DefaultType::Optional => format!("{typename} | None = None"),
+            crate::DefaultType::Default(value) => {
+                let value = data_model::expand_macros(value, macros, &mut HashSet::new())?;
+                format!(
+                    "{typename} = dataclasses.field(default_factory=lambda: {parser}({value}))",
+                    parser = get_parser(&self.data_type),
+                    value = get_literal(&value),
+                )
+            }
+        };
+
+        return Ok(format!(
+            "{0:indent$}{name}: {definition}\n",
+            "",
+            name = get_identifier(&self.name),
+        ));
+    }
+
+    /// Gets the code parsing the field from the map of fields
+    ///
+    /// # Parameters
+    ///
+    /// indent: The number of spaces to use for indentation
+    fn get_parser(&self, indent: usize) -> String {
+        let parser = format!(
+            "kwargs[\"{identifier}\"] = termite.parse_field(fields, {name}, {parser})\n",
+            identifier = get_identifier(&self.name),
+            name = get_string_literal(&self.name),
+            parser = get_parser(&self.data_type),
+        );
+
+        return match self.default {
+            crate::DefaultType::Required => format!("{0:indent$}{0:indent$}{parser}", ""),
+            _ => format!(
+                "{0:indent$}{0:indent$}if {name} in fields:\n{0:indent$}{0:indent$}{0:indent$}{parser}",
+                "",
+                name = get_string_literal(&self.name),
+            ),
+        };
+    }
+
+    /// Gets the code writing the field to the plain value
+    ///
+    /// # Parameters
+    ///
+    /// indent: The number of spaces to use for indentation
+    fn get_writer(&self, indent: usize) -> String {
+        let identifier = get_identifier(&self.name);
+        let writer = format!(
+            "value[{name}] = termite.to_value(self.{identifier})\n",
+            name = get_string_literal(&self.name),
+        );
+
+        return match self.default {
+            crate::DefaultType::Optional => format!(
+                "{0:indent$}{0:indent$}if self.{identifier} is not None:\n{0:indent$}{0:indent$}{0:indent$}{writer}",
+                ""
+            ),
+            _ => format!("{0:indent$}{0:indent$}{writer}", ""),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::*;
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn basic() {
+        // Check Python code
+        run_python_test("type_struct/basic/basic");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/python/type_struct/basic/basic.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source = include_str!("../../tests/python/type_struct/basic/basic.py");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn inheritance() {
+        // Check Python code
+        run_python_test("type_struct/inheritance/inheritance");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/python/type_struct/inheritance/inheritance.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source =
+            include_str!("../../tests/python/type_struct/inheritance/inheritance.py");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn unknown_fields() {
+        // Check Python code
+        run_python_test("type_struct/unknown_fields/unknown_fields");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/python/type_struct/unknown_fields/unknown_fields.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source =
+            include_str!("../../tests/python/type_struct/unknown_fields/unknown_fields.py");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
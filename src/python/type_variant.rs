@@ -0,0 +1,105 @@
+use super::*;
+use indoc::formatdoc;
+
+/// The type specific information for a variant
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Variant {
+    /// The list of data types the variant can be
+    pub(super) data_types: Vec<String>,
+}
+
+impl Variant {
+    /// Constructs a new Python variant from a generic variant
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic variant to convert
+    pub(super) fn new(data: crate::Variant) -> Result<Self, Error> {
+        return Ok(Self {
+            data_types: data.data_types,
+        });
+    }
+
+    /// Converts the variant to a string for use in the source file, when
+    /// parsing the first type which succeeds is used
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the variant
+    ///
+    /// description: The paragraphs of the docstring of the variant
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_source(&self, name: &str, mut description: Vec<String>, indent: usize) -> String {
+        description.push(format!(
+            "Attributes:\n{0:indent$}value: The value of the first type which could be parsed",
+            ""
+        ));
+
+        let typename = self
+            .data_types
+            .iter()
+            .map(|data_type| get_typename(data_type))
+            .collect::<Vec<String>>()
+            .join(" | ");
+        let parsers = self
+            .data_types
+            .iter()
+            .map(|data_type| {
+                format!(
+                    "{0:indent$}{0:indent$}{0:indent$}({name}, {parser}),\n",
+                    "",
+                    name = get_string_literal(data_type),
+                    parser = get_parser(data_type),
+                )
+            })
+            .collect::<String>();
+
+        return formatdoc!(
+            "
+            @dataclasses.dataclass
+            class {name}:
+            {docstring}
+            {0:indent$}value: {typename}
+
+            {0:indent$}@classmethod
+            {0:indent$}def from_value(cls, value: typing.Any) -> {name}:
+            {0:indent$}{0:indent$}\"\"\"Parses {name} from a plain value\"\"\"
+            {0:indent$}{0:indent$}return cls(termite.parse_variant(value, [
+            {parsers}{0:indent$}{0:indent$}]))
+
+            {0:indent$}def to_value(self) -> typing.Any:
+            {0:indent$}{0:indent$}\"\"\"Converts the {name} to a plain value\"\"\"
+            {0:indent$}{0:indent$}return termite.to_value(self.value)",
+            "",
+            docstring = get_docstring(&description, indent),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::*;
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn basic() {
+        // Check Python code
+        run_python_test("type_variant/basic/basic");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/python/type_variant/basic/basic.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source = include_str!("../../tests/python/type_variant/basic/basic.py");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
@@ -0,0 +1,634 @@
+//!
+//! This module handles generation of Rust code to support a data model, it
+//! creates a single source file with all the data types which can be
+//! (de)serialized using serde.
+//!
+//! The generated code depends on the serde crate with the derive feature and
+//! the serde_json crate which is used for default values and for storing
+//! unknown fields, constraints using matches() also depend on the regex crate
+//! and need Rust 1.80 or newer to compile the patterns once
+//!
+
+use indexmap::IndexMap;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+mod type_array;
+mod type_constrained;
+mod type_enum;
+mod type_map;
+mod type_struct;
+mod type_variant;
+
+use type_array::Array;
+use type_constrained::ConstrainedType;
+use type_enum::Enum;
+use type_map::Map;
+use type_struct::Struct;
+use type_variant::Variant;
+
+use crate::{data_model, LocatedError, Location};
+
+/// All keywords of Rust which cannot be used as identifiers without the raw
+/// identifier prefix
+const KEYWORDS: [&str; 50] = [
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "macro",
+    "override", "priv", "typeof", "unsized", "virtual", "yield",
+];
+
+/// The derives which are added to all generated types
+const DERIVES: &str = "#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]";
+
+/// Converts a type name from the data model to the Rust type
+///
+/// # Parameters
+///
+/// data_type: The name of the type to convert
+fn get_typename(data_type: &str) -> String {
+    return match data_type {
+        "string" => "String".to_string(),
+        "number" => "f64".to_string(),
+        "integer" => "i64".to_string(),
+        "boolean" => "bool".to_string(),
+        _ => data_type.to_string(),
+    };
+}
+
+/// Converts a name to an identifier, keywords are prefixed with r#
+///
+/// # Parameters
+///
+/// name: The name to convert
+fn get_identifier(name: &str) -> String {
+    return if KEYWORDS.contains(&name) {
+        format!("r#{name}")
+    } else {
+        name.to_string()
+    };
+}
+
+/// Creates the doc comment for a description, it is empty if there is no
+/// description
+///
+/// # Parameters
+///
+/// description: The description to write
+///
+/// indent: The number of spaces to indent the doc comment by
+fn get_doc_comment(description: &Option<String>, indent: usize) -> String {
+    return match description {
+        Some(description) => description
+            .lines()
+            .map(|line| {
+                return if line.is_empty() {
+                    format!("{0:indent$}///\n", "")
+                } else {
+                    format!("{0:indent$}/// {line}\n", "")
+                };
+            })
+            .collect::<String>(),
+        None => "".to_string(),
+    };
+}
+
+/// An entire data model
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataModel {
+    /// List of the the data types to implement
+    data_types: Vec<DataType>,
+    /// The header to add to the top of the file
+    header: String,
+    /// The footer to add to the bottom of the file
+    footer: String,
+    /// A map of all macros to expand the header and footer
+    macros: IndexMap<String, data_model::SerializationModel>,
+}
+
+impl DataModel {
+    /// Constructs a new Rust data model from a generic data model
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic data type to convert
+    pub fn new(data: crate::DataModel) -> Result<Self, Error> {
+        // Make sure the data model is valid, all types must be defined in the
+        // data model as default values are checked against them
+        if let Err(errors) = data.validate() {
+            return Err(Error {
+                location: Location::default(),
+                error: ErrorCore::Validation(errors),
+            });
+        }
+
+        // Everything is generated into a single file so the imported data types
+        // are generated as well, errors are located where the type is defined
+        let locations = data.get_type_locations();
+        let data = data.flatten_imports();
+
+        let custom_types = data
+            .data_types
+            .iter()
+            .map(|data_type| (data_type.name.clone(), data_type.clone()))
+            .collect::<HashMap<_, _>>();
+        let mut data_types = data
+            .data_types
+            .iter()
+            .map(|data_type| {
+                return match DataType::new(data_type.clone(), &custom_types, &data.macros) {
+                    Ok(result) => Ok(result),
+                    Err(error) => Err(error.add_location(&locations[&data_type.name])),
+                };
+            })
+            .collect::<Result<Vec<DataType>, Error>>()?;
+
+        // Store all references to types which depend on the referencing type
+        // in boxes to allow recursive types
+        let reachable_types = get_reachable_types(&data_types);
+        for data_type in data_types.iter_mut() {
+            let recursive_types = reachable_types
+                .iter()
+                .filter(|(_, reachable)| reachable.contains(data_type.name.as_str()))
+                .map(|(name, _)| name.as_str())
+                .collect::<HashSet<&str>>();
+            data_type.data.box_references(&recursive_types);
+        }
+
+        // Constrained types are never boxed so they may still form a cycle
+        for data_type in data_types.iter() {
+            let mut stack = vec![];
+            if let Some(cycle) = find_cycle(&data_type.name, &data_types, &mut stack) {
+                return Err(Error {
+                    location: Location::default(),
+                    error: ErrorCore::CyclicDependency(cycle),
+                });
+            }
+        }
+
+        let header = match data.headers.get("rust") {
+            Some(value) => value.clone(),
+            None => String::new(),
+        };
+        let footer = match data.footers.get("rust") {
+            Some(value) => value.clone(),
+            None => String::new(),
+        };
+
+        return Ok(Self {
+            data_types,
+            header,
+            footer,
+            macros: data.macros,
+        });
+    }
+
+    /// Generates the source file
+    ///
+    /// # Parameters
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub fn get_source(&self, indent: usize) -> Result<String, Error> {
+        // Get all the data types
+        let data_types = self
+            .data_types
+            .iter()
+            .map(|data_type| data_type.get_source(indent))
+            .collect::<Vec<String>>()
+            .join("\n\n");
+
+        // Expand macros in the header and footer
+        let header = match data_model::expand_macros(
+            &data_model::SerializationModel::Value(self.header.clone()),
+            &self.macros,
+            &mut HashSet::new(),
+        )? {
+            data_model::SerializationModel::Value(value) => value,
+            _ => {
+                return Err(Error {
+                    location: Location::default(),
+                    error: ErrorCore::HeaderMacro(self.header.clone()),
+                })
+            }
+        };
+        let footer = match data_model::expand_macros(
+            &data_model::SerializationModel::Value(self.footer.clone()),
+            &self.macros,
+            &mut HashSet::new(),
+        )? {
+            data_model::SerializationModel::Value(value) => value,
+            _ => {
+                return Err(Error {
+                    location: Location::default(),
+                    error: ErrorCore::FooterMacro(self.footer.clone()),
+                })
+            }
+        };
+
+        // Leave out the header and footer if they are empty
+        let sections = [
+            "// Generated with the Termite Data Model Generator".to_string(),
+            header,
+            data_types,
+            footer,
+        ];
+
+        return Ok(sections
+            .into_iter()
+            .filter(|section| !section.is_empty())
+            .map(|section| section + "\n")
+            .collect::<Vec<String>>()
+            .join("\n"));
+    }
+}
+
+/// Finds all the data types which each data type depends on directly or
+/// indirectly, a data type only reaches itself if it is recursive
+///
+/// # Parameters
+///
+/// data_types: All the data types of the data model
+fn get_reachable_types(data_types: &[DataType]) -> HashMap<String, HashSet<String>> {
+    return data_types
+        .iter()
+        .map(|data_type| {
+            let mut reachable = HashSet::new();
+            let mut queue = data_type.data.get_dependencies();
+            while let Some(dependency) = queue.pop() {
+                if !reachable.insert(dependency.to_string()) {
+                    continue;
+                }
+                if let Some(dependency) = data_types
+                    .iter()
+                    .find(|data_type| data_type.name == dependency)
+                {
+                    queue.extend(dependency.data.get_dependencies());
+                }
+            }
+
+            return (data_type.name.clone(), reachable);
+        })
+        .collect();
+}
+
+/// Searches for a cycle of types which contain each other by value, returns
+/// the types of the cycle with the first type repeated at the end
+///
+/// # Parameters
+///
+/// name: The name of the type to search from
+///
+/// data_types: All the data types of the data model
+///
+/// stack: The types currently being searched
+fn find_cycle(name: &str, data_types: &[DataType], stack: &mut Vec<String>) -> Option<Vec<String>> {
+    if let Some(index) = stack.iter().position(|stack_name| stack_name == name) {
+        let mut cycle = stack[index..].to_vec();
+        cycle.push(name.to_string());
+        return Some(cycle);
+    }
+
+    let data_type = data_types.iter().find(|data_type| data_type.name == name)?;
+    stack.push(name.to_string());
+    for dependency in data_type.data.get_dependencies() {
+        if let Some(cycle) = find_cycle(dependency, data_types, stack) {
+            return Some(cycle);
+        }
+    }
+    stack.pop();
+
+    return None;
+}
+
+/// Any data type (struct, variant, ect.)
+#[derive(Clone, Debug, PartialEq)]
+struct DataType {
+    /// The name of the type
+    name: String,
+    /// The description of the type
+    description: Option<String>,
+    /// The type specific data
+    data: DataTypeData,
+}
+
+impl DataType {
+    /// Constructs a new Rust data type from a generic data type
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic data type to convert
+    ///
+    /// custom_types: All the generic data types of the data model, used to
+    /// resolve references to other types
+    ///
+    /// macros: A map of all macros to expand default values
+    fn new(
+        data: crate::DataType,
+        custom_types: &HashMap<String, crate::DataType>,
+        macros: &IndexMap<String, data_model::SerializationModel>,
+    ) -> Result<Self, Error> {
+        // Convert the data
+        let processed_data = match DataTypeData::new(data.data, custom_types, macros) {
+            Ok(data) => data,
+            Err(error) => return Err(error.add_field("data")),
+        };
+
+        return Ok(Self {
+            name: data.name,
+            description: data.description,
+            data: processed_data,
+        });
+    }
+
+    /// Converts the data type to a string for use in the source file
+    ///
+    /// # Parameters
+    ///
+    /// indent: The number of spaces to use for indentation
+    fn get_source(&self, indent: usize) -> String {
+        return format!(
+            "{description}{definition}",
+            description = get_doc_comment(&self.description, 0),
+            definition = self.data.get_source(&self.name, indent),
+        );
+    }
+}
+
+/// Supplies the type specific information for a data type
+#[derive(Clone, Debug, PartialEq)]
+enum DataTypeData {
+    /// Describes a struct
+    Struct(Struct),
+    /// Describes an array
+    Array(Array),
+    /// Describes a map
+    Map(Map),
+    /// Describes a variant
+    Variant(Variant),
+    /// Describes an enum
+    Enum(Enum),
+    /// Describes a constrained type
+    ConstrainedType(ConstrainedType),
+}
+
+impl DataTypeData {
+    /// Constructs a new Rust data type data from a generic data type data
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic data type data to convert
+    ///
+    /// custom_types: All the generic data types of the data model, used to
+    /// resolve references to other types
+    ///
+    /// macros: A map of all macros to expand default values
+    fn new(
+        data: crate::DataTypeData,
+        custom_types: &HashMap<String, crate::DataType>,
+        macros: &IndexMap<String, data_model::SerializationModel>,
+    ) -> Result<Self, Error> {
+        let result = match data {
+            crate::DataTypeData::Struct(data) => {
+                DataTypeData::Struct(Struct::new(data, custom_types, macros)?)
+            }
+            crate::DataTypeData::Array(data) => DataTypeData::Array(Array::new(data)?),
+            crate::DataTypeData::Map(data) => DataTypeData::Map(Map::new(data)?),
+            crate::DataTypeData::Variant(data) => DataTypeData::Variant(Variant::new(data)?),
+            crate::DataTypeData::Enum(data) => DataTypeData::Enum(Enum::new(data)?),
+            crate::DataTypeData::ConstrainedType(data) => {
+                DataTypeData::ConstrainedType(ConstrainedType::new(data, custom_types)?)
+            }
+        };
+
+        return Ok(result);
+    }
+
+    /// Gets the names of all the types which are stored by value inside this
+    /// data type
+    fn get_dependencies(&self) -> Vec<&str> {
+        return match self {
+            DataTypeData::Struct(data) => data.get_dependencies(),
+            DataTypeData::Array(data) => data.get_dependencies(),
+            DataTypeData::Map(data) => data.get_dependencies(),
+            DataTypeData::Variant(data) => data.get_dependencies(),
+            DataTypeData::Enum(data) => data.get_dependencies(),
+            DataTypeData::ConstrainedType(data) => data.get_dependencies(),
+        };
+    }
+
+    /// Stores all references to the given types in a Box, arrays and maps
+    /// do not need boxes and constrained types are never boxed
+    ///
+    /// # Parameters
+    ///
+    /// recursive_types: The types which depend on this data type
+    fn box_references(&mut self, recursive_types: &HashSet<&str>) {
+        match self {
+            DataTypeData::Struct(data) => data.box_references(recursive_types),
+            DataTypeData::Variant(data) => data.box_references(recursive_types),
+            DataTypeData::Enum(data) => data.box_references(recursive_types),
+            DataTypeData::Array(_) | DataTypeData::Map(_) | DataTypeData::ConstrainedType(_) => (),
+        }
+    }
+
+    /// Converts the data type data to a string for use in the source file
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the data type
+    ///
+    /// indent: The number of spaces to use for indentation
+    fn get_source(&self, name: &str, indent: usize) -> String {
+        return match self {
+            DataTypeData::Struct(data) => data.get_source(name, indent),
+            DataTypeData::Array(data) => data.get_source(name, indent),
+            DataTypeData::Map(data) => data.get_source(name, indent),
+            DataTypeData::Variant(data) => data.get_source(name, indent),
+            DataTypeData::Enum(data) => data.get_source(name, indent),
+            DataTypeData::ConstrainedType(data) => data.get_source(name, indent),
+        };
+    }
+}
+
+/// Errors for when converting generic data models into Rust data models
+/// including location
+#[derive(Debug, Clone)]
+pub struct Error {
+    /// The location where the error occured
+    pub location: Location,
+    /// The actual error that occured
+    pub error: ErrorCore,
+}
+
+impl Error {
+    /// Sets the current location to be the field of the given base
+    ///
+    /// # Parameters
+    ///
+    /// base: The base to set in the location
+    fn add_field(self, base: &str) -> Error {
+        return Error {
+            location: self.location.add_field(base),
+            error: self.error,
+        };
+    }
+
+    /// Sets the current location to be the element of a sequence
+    ///
+    /// # Parameters
+    ///
+    /// index: The index of the element
+    fn add_element(self, index: usize) -> Error {
+        return Error {
+            location: self.location.add_element(index),
+            error: self.error,
+        };
+    }
+
+    /// Sets the current location to be inside of the location of a data type
+    ///
+    /// # Parameters
+    ///
+    /// base: The location of the data type
+    fn add_location(self, base: &Location) -> Error {
+        return Error {
+            location: self.location.add_location(base),
+            error: self.error,
+        };
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Errors for the entire data model have no location
+        if self.location.is_empty() {
+            return write!(f, "{}", self.error);
+        }
+        return write!(f, "{}: {}", self.location, self.error);
+    }
+}
+
+impl LocatedError for Error {
+    fn get_location(&self) -> Location {
+        return self.location.clone();
+    }
+}
+
+impl From<data_model::Error> for Error {
+    fn from(value: data_model::Error) -> Self {
+        return Error {
+            location: value.location.clone(),
+            error: ErrorCore::MacroError(value),
+        };
+    }
+}
+
+/// Errors for when converting generic data models into Rust data models
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum ErrorCore {
+    /// Error expanding macros
+    #[error("An error occured when expanding macros: {:?}", .0)]
+    MacroError(data_model::Error),
+    /// The macro expansion in the header failed
+    #[error("The header \"{:?}\" must only expand to a string when using macros", .0)]
+    HeaderMacro(String),
+    /// The macro expansion in the footer failed
+    #[error("The footer \"{:?}\" must only expand to a string when using macros", .0)]
+    FooterMacro(String),
+    /// The data model is not valid
+    #[error("The data model is not valid:{}", data_model::display_errors(.0))]
+    Validation(Vec<data_model::Error>),
+    /// The data types contain each other in a cycle
+    #[error("The types {:?} contain each other in a cycle which cannot be represented by value", .0)]
+    CyclicDependency(Vec<String>),
+    /// A default value could not be converted to its type
+    #[error("The default value does not fit the type of the field: {}", .0)]
+    InvalidDefault(Box<crate::schema::Error>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn outline() {
+        // Make sure it generates the correct code
+        let model =
+            crate::DataModel::import_yaml(include_str!("../../tests/rust/outline/outline.yaml"))
+                .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source = include_str!("../../tests/rust/outline/outline.rs");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn recursive() {
+        // Make sure it generates the correct code
+        let model =
+            crate::DataModel::import_yaml(include_str!("../../tests/cpp/recursive/recursive.yaml"))
+                .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source = include_str!("../../tests/rust/recursive/recursive.rs");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn dependency_cycle() {
+        let model = crate::DataModel {
+            imports: vec![],
+            source: None,
+            headers: IndexMap::new(),
+            footers: IndexMap::new(),
+            data_types: vec![
+                crate::DataType {
+                    name: "DataType1".to_string(),
+                    description: None,
+                    data: crate::DataTypeData::Array(crate::Array {
+                        data_type: "DataType2".to_string(),
+                    }),
+                },
+                crate::DataType {
+                    name: "DataType2".to_string(),
+                    description: None,
+                    data: crate::DataTypeData::ConstrainedType(crate::ConstrainedType {
+                        data_type: "DataType3".to_string(),
+                        constraints: vec![],
+                    }),
+                },
+                crate::DataType {
+                    name: "DataType3".to_string(),
+                    description: None,
+                    data: crate::DataTypeData::ConstrainedType(crate::ConstrainedType {
+                        data_type: "DataType2".to_string(),
+                        constraints: vec![],
+                    }),
+                },
+            ],
+            namespace: vec![],
+            macros: IndexMap::new(),
+            unknown_fields: crate::UnknownFields::Collect,
+        };
+
+        match DataModel::new(model) {
+            Err(Error {
+                error: ErrorCore::CyclicDependency(cycle),
+                ..
+            }) => assert_eq!(cycle, vec!["DataType2", "DataType3", "DataType2"]),
+            result => panic!("Expected a cyclic dependency error, got {result:?}"),
+        }
+    }
+}
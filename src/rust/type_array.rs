@@ -0,0 +1,72 @@
+use super::*;
+use indoc::formatdoc;
+
+/// The type specific information for an array
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Array {
+    /// The data type for all elements of the array
+    pub(super) data_type: String,
+}
+
+impl Array {
+    /// Constructs a new Rust array from a generic array
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic array to convert
+    pub(super) fn new(data: crate::Array) -> Result<Self, Error> {
+        return Ok(Self {
+            data_type: data.data_type,
+        });
+    }
+
+    /// Gets the names of all the types which are stored by value inside this
+    /// array, the elements are stored on the heap so it is always empty
+    pub(super) fn get_dependencies(&self) -> Vec<&str> {
+        return vec![];
+    }
+
+    /// Converts the array to a string for use in the source file
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the array
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_source(&self, name: &str, indent: usize) -> String {
+        return formatdoc!(
+            "
+            {DERIVES}
+            #[serde(transparent)]
+            pub struct {name} {{
+            {0:indent$}/// The values of the array
+            {0:indent$}pub values: Vec<{data_type}>,
+            }}",
+            "",
+            data_type = get_typename(&self.data_type),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn basic() {
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/rust/type_array/basic/basic.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source = include_str!("../../tests/rust/type_array/basic/basic.rs");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
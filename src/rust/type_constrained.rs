@@ -0,0 +1,356 @@
+use super::*;
+use crate::constraint::{self, parenthesize, Expression, Kind, Operator};
+use indoc::formatdoc;
+
+/// The type specific information for a constrained type
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct ConstrainedType {
+    /// The type which is constrained
+    pub(super) data_type: String,
+    /// All extra constraints for the type, the constraint as it was written in
+    /// the data model and the Rust expression which must be true
+    pub(super) constraints: Vec<(String, String)>,
+    /// The regular expressions used by matches, every pattern is compiled
+    /// once into a static named PATTERN_{index}
+    pub(super) patterns: Vec<String>,
+}
+
+impl ConstrainedType {
+    /// Constructs a new Rust constrained type from a generic constrained type
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic constrained type to convert
+    ///
+    /// custom_types: All the generic data types of the data model, used to
+    /// find the types of the values in the constraints
+    pub(super) fn new(
+        data: crate::ConstrainedType,
+        custom_types: &HashMap<String, crate::DataType>,
+    ) -> Result<Self, Error> {
+        let context = constraint::Context::new(&data.data_type, custom_types.values());
+        let mut patterns = vec![];
+        for constraint in data.constraints.iter() {
+            get_patterns(constraint.expression(), &mut patterns);
+        }
+        let constraints = data
+            .constraints
+            .iter()
+            .map(|constraint| {
+                (
+                    constraint.to_string(),
+                    get_expression(constraint.expression(), &context, &patterns, false),
+                )
+            })
+            .collect();
+
+        return Ok(Self {
+            data_type: data.data_type,
+            constraints,
+            patterns,
+        });
+    }
+
+    /// Gets the names of all the types which are stored by value inside this
+    /// constrained type
+    pub(super) fn get_dependencies(&self) -> Vec<&str> {
+        return vec![self.data_type.as_str()];
+    }
+
+    /// Converts the constrained type to a string for use in the source file,
+    /// it is a newtype which can only be constructed through new() such that
+    /// the constraints are always upheld
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the constrained type
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_source(&self, name: &str, indent: usize) -> String {
+        let data_type = get_typename(&self.data_type);
+
+        // Create the constraints description
+        let constraints = if self.constraints.is_empty() {
+            "".to_string()
+        } else {
+            self.constraints
+                .iter()
+                .map(|(source, _)| format!("\n{0:indent$}/// - {source}", ""))
+                .fold(
+                    " if the value fulfills the constraints:".to_string(),
+                    |result, constraint| result + &constraint,
+                )
+        };
+
+        // Create the checks, the value is denoted x in the constraints and is
+        // copied for the simple builtin types to allow comparisons with
+        // literals
+        let checks = if self.constraints.is_empty() {
+            "".to_string()
+        } else {
+            let binding = if ["number", "integer", "boolean"].contains(&self.data_type.as_str()) {
+                "value"
+            } else {
+                "&value"
+            };
+            let tests = self
+                .constraints
+                .iter()
+                .map(|(source, code)| {
+                    formatdoc!(
+                        "
+                        {0:indent$}{0:indent$}let valid = {code};
+                        {0:indent$}{0:indent$}if !valid {{
+                        {0:indent$}{0:indent$}{0:indent$}return Err({message:?}.to_string());
+                        {0:indent$}{0:indent$}}}
+                        ",
+                        "",
+                        message = format!("Did not pass constraint: {source}"),
+                    )
+                })
+                .collect::<String>();
+
+            // The regular expressions are only compiled the first time they
+            // are used, they are checked when the data model is loaded
+            let patterns = self
+                .patterns
+                .iter()
+                .enumerate()
+                .map(|(i, pattern)| {
+                    formatdoc!(
+                        "
+                        {0:indent$}{0:indent$}static PATTERN_{i}: std::sync::LazyLock<regex::Regex> = std::sync::LazyLock::new(|| {{
+                        {0:indent$}{0:indent$}{0:indent$}regex::Regex::new({pattern:?}).expect(\"The pattern is checked by termite\")
+                        {0:indent$}{0:indent$}}});
+                        ",
+                        "",
+                    )
+                })
+                .collect::<String>();
+
+            format!(
+                "{patterns}{0:indent$}{0:indent$}let x = {binding};\n{tests}",
+                ""
+            )
+        };
+
+        return formatdoc!(
+            "
+            {DERIVES}
+            #[serde(try_from = \"{data_type}\", into = \"{data_type}\")]
+            pub struct {name}({data_type});
+
+            impl {name} {{
+            {0:indent$}/// Constructs a new {name}{constraints}
+            {0:indent$}pub fn new(value: {data_type}) -> Result<Self, String> {{
+            {checks}{0:indent$}{0:indent$}Ok(Self(value))
+            {0:indent$}}}
+
+            {0:indent$}/// Gets a reference to the constrained value
+            {0:indent$}pub fn get(&self) -> &{data_type} {{
+            {0:indent$}{0:indent$}&self.0
+            {0:indent$}}}
+
+            {0:indent$}/// Takes out the constrained value
+            {0:indent$}pub fn into_inner(self) -> {data_type} {{
+            {0:indent$}{0:indent$}self.0
+            {0:indent$}}}
+            }}
+
+            impl TryFrom<{data_type}> for {name} {{
+            {0:indent$}type Error = String;
+
+            {0:indent$}fn try_from(value: {data_type}) -> Result<Self, Self::Error> {{
+            {0:indent$}{0:indent$}Self::new(value)
+            {0:indent$}}}
+            }}
+
+            impl From<{name}> for {data_type} {{
+            {0:indent$}fn from(value: {name}) -> Self {{
+            {0:indent$}{0:indent$}value.0
+            {0:indent$}}}
+            }}",
+            "",
+        );
+    }
+}
+
+/// Converts an expression of the constraint language into Rust, the value is
+/// denoted x and constrained types are unwrapped using get(). Integers are
+/// converted to f64 whenever they are combined with numbers
+///
+/// # Parameters
+///
+/// expression: The expression to convert
+///
+/// context: The types of the values in the expression
+///
+/// patterns: The regular expressions of the constrained type, the index is
+/// the name of the static it is compiled into
+///
+/// float: If true then numeric results must be f64
+fn get_expression(
+    expression: &Expression,
+    context: &constraint::Context,
+    patterns: &[String],
+    float: bool,
+) -> String {
+    // Converts an operand and puts it in parentheses if needed
+    let operand = |operand: &Expression, precedence: u8, strict: bool, float: bool| -> String {
+        return parenthesize(
+            get_expression(operand, context, patterns, float),
+            operand.precedence(),
+            precedence,
+            strict,
+        );
+    };
+    // Checks if numbers must be compared or combined as f64
+    let is_float = |operands: &[&Expression]| -> bool {
+        return operands
+            .iter()
+            .any(|operand| context.get_kind(operand) == Kind::Number);
+    };
+
+    let kind = context.get_kind(expression);
+    return match expression {
+        Expression::Value | Expression::Field(..) => {
+            let value = match expression {
+                Expression::Field(value, name) => format!(
+                    "{}.{}",
+                    operand(value, 7, false, false),
+                    get_identifier(name)
+                ),
+                _ => "x".to_string(),
+            };
+            let depth = context
+                .get_type_name(expression)
+                .map_or(0, |data_type| context.get_constrained_depth(data_type));
+
+            // Copy the simple types out of the constrained types
+            let value = format!("{value}{}", ".get()".repeat(depth));
+            let value = if depth > 0 && (kind.is_numeric() || kind == Kind::Boolean) {
+                format!("*{value}")
+            } else {
+                value
+            };
+            if float && kind == Kind::Integer {
+                format!("({value} as f64)")
+            } else {
+                value
+            }
+        }
+        Expression::Number(value) => {
+            if float && kind == Kind::Integer {
+                format!("{value}.0")
+            } else {
+                value.clone()
+            }
+        }
+        Expression::String(value) => format!("{value:?}"),
+        Expression::Boolean(value) => value.to_string(),
+        Expression::Length(value) => {
+            let length = if context.get_kind(value) == Kind::String {
+                "chars().count()"
+            } else {
+                "len()"
+            };
+            format!(
+                "({}.{length} as {})",
+                operand(value, 7, false, false),
+                if float { "f64" } else { "i64" }
+            )
+        }
+        Expression::Matches(value, pattern) => format!(
+            "PATTERN_{}.is_match(&{})",
+            patterns.iter().position(|other| other == pattern).unwrap(),
+            operand(value, 7, false, false)
+        ),
+        Expression::In(value, set) => {
+            let float = is_float(&[value]) || set.iter().any(|element| is_float(&[element]));
+            let precedence = Operator::Equal.precedence();
+            format!(
+                "({})",
+                set.iter()
+                    .map(|element| format!(
+                        "{} == {}",
+                        operand(value, precedence, true, float),
+                        operand(element, precedence, true, float)
+                    ))
+                    .collect::<Vec<String>>()
+                    .join(" || ")
+            )
+        }
+        Expression::Not(value) => format!("!{}", operand(value, 6, false, false)),
+        Expression::Negate(value) => format!("-{}", operand(value, 6, false, float)),
+        Expression::Binary(lhs, operator, rhs) => {
+            let float = match operator {
+                Operator::Or | Operator::And => false,
+                Operator::Divide => true,
+                _ if operator.is_comparison() => is_float(&[lhs, rhs]),
+                _ => float || is_float(&[lhs, rhs]),
+            };
+            let precedence = operator.precedence();
+            format!(
+                "{} {} {}",
+                operand(lhs, precedence, operator.is_comparison(), float),
+                operator.symbol(),
+                operand(rhs, precedence, true, float)
+            )
+        }
+    };
+}
+
+/// Collects the regular expressions used by matches in an expression, every
+/// pattern is only added once
+///
+/// # Parameters
+///
+/// expression: The expression to search
+///
+/// patterns: The patterns found so far
+fn get_patterns(expression: &Expression, patterns: &mut Vec<String>) {
+    match expression {
+        Expression::Matches(value, pattern) => {
+            get_patterns(value, patterns);
+            if !patterns.contains(pattern) {
+                patterns.push(pattern.clone());
+            }
+        }
+        Expression::Field(value, _)
+        | Expression::Length(value)
+        | Expression::In(value, _)
+        | Expression::Not(value)
+        | Expression::Negate(value) => get_patterns(value, patterns),
+        Expression::Binary(lhs, _, rhs) => {
+            get_patterns(lhs, patterns);
+            get_patterns(rhs, patterns);
+        }
+        Expression::Value
+        | Expression::Number(_)
+        | Expression::String(_)
+        | Expression::Boolean(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn basic() {
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/rust/type_constrained/basic/basic.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source = include_str!("../../tests/rust/type_constrained/basic/basic.rs");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
@@ -0,0 +1,264 @@
+use super::*;
+use indoc::formatdoc;
+
+/// The type specific information for an enum
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Enum {
+    /// A list of all the enum types
+    pub(super) types: Vec<EnumType>,
+}
+
+impl Enum {
+    /// Constructs a new Rust enum from a generic enum
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic enum to convert
+    pub(super) fn new(data: crate::Enum) -> Result<Self, Error> {
+        let types = data
+            .types
+            .into_iter()
+            .map(|enum_type| EnumType::new(enum_type))
+            .collect::<Result<Vec<EnumType>, Error>>()?;
+
+        return Ok(Self { types });
+    }
+
+    /// Gets the names of all the types which are stored by value inside this
+    /// enum
+    pub(super) fn get_dependencies(&self) -> Vec<&str> {
+        return self
+            .types
+            .iter()
+            .filter(|enum_type| !enum_type.boxed)
+            .filter_map(|enum_type| enum_type.data_type.as_deref())
+            .collect();
+    }
+
+    /// Stores all values of the given types in a Box
+    ///
+    /// # Parameters
+    ///
+    /// recursive_types: The types which depend on this enum
+    pub(super) fn box_references(&mut self, recursive_types: &HashSet<&str>) {
+        for enum_type in self.types.iter_mut() {
+            enum_type.boxed = match &enum_type.data_type {
+                Some(data_type) => recursive_types.contains(data_type.as_str()),
+                None => false,
+            };
+        }
+    }
+
+    /// Converts the enum to a string for use in the source file, empty types
+    /// are written as strings and other types as a map with a single key
+    /// being the name of the type, the serde implementations are written out
+    /// as the external tagging of serde is not the same for all formats
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the enum
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_source(&self, name: &str, indent: usize) -> String {
+        let types = self
+            .types
+            .iter()
+            .map(|enum_type| enum_type.get_definition(indent))
+            .collect::<String>();
+
+        // Get the names of all enum types
+        let names = self
+            .types
+            .iter()
+            .map(|enum_type| format!("\"{name}\"", name = enum_type.name))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        // Get the serializers
+        let serializers = self
+            .types
+            .iter()
+            .map(|enum_type| match enum_type.data_type {
+                Some(_) => format!(
+                    "{0:indent$}{0:indent$}{0:indent$}Self::{name}(value) => serde::Serialize::serialize(&std::collections::BTreeMap::from([(\"{name}\", value)]), serializer),\n",
+                    "",
+                    name = enum_type.name,
+                ),
+                None => format!(
+                    "{0:indent$}{0:indent$}{0:indent$}Self::{name} => serializer.serialize_str(\"{name}\"),\n",
+                    "",
+                    name = enum_type.name,
+                ),
+            })
+            .collect::<String>();
+
+        // Get the parser for empty types which are written as strings
+        let empty_parsers = self
+            .types
+            .iter()
+            .filter(|enum_type| enum_type.data_type.is_none())
+            .map(|enum_type| {
+                format!(
+                    "{0:indent$}{0:indent$}{0:indent$}{0:indent$}{0:indent$}\"{type_name}\" => Ok({name}::{type_name}),\n",
+                    "",
+                    type_name = enum_type.name,
+                )
+            })
+            .collect::<String>();
+        let empty_parser = if empty_parsers.is_empty() {
+            "".to_string()
+        } else {
+            "\n\n".to_string()
+                + &formatdoc!(
+                "
+                {0:indent$}{0:indent$}{0:indent$}fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {{
+                {0:indent$}{0:indent$}{0:indent$}{0:indent$}match value {{
+                {empty_parsers}{0:indent$}{0:indent$}{0:indent$}{0:indent$}{0:indent$}_ => Err(E::unknown_variant(value, NAMES)),
+                {0:indent$}{0:indent$}{0:indent$}{0:indent$}}}
+                {0:indent$}{0:indent$}{0:indent$}}}",
+                "",
+            )
+        };
+
+        // Get the parser for types with values which are written as maps
+        let value_parsers = self
+            .types
+            .iter()
+            .filter(|enum_type| enum_type.data_type.is_some())
+            .map(|enum_type| {
+                format!(
+                    "{0:indent$}{0:indent$}{0:indent$}{0:indent$}{0:indent$}\"{type_name}\" => {name}::{type_name}(map.next_value()?),\n",
+                    "",
+                    type_name = enum_type.name,
+                )
+            })
+            .collect::<String>();
+        let value_parser = if value_parsers.is_empty() {
+            "".to_string()
+        } else {
+            "\n\n".to_string()
+                + &formatdoc!(
+                "
+                {0:indent$}{0:indent$}{0:indent$}fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {{
+                {0:indent$}{0:indent$}{0:indent$}{0:indent$}let Some(type_name) = map.next_key::<String>()? else {{
+                {0:indent$}{0:indent$}{0:indent$}{0:indent$}{0:indent$}return Err(serde::de::Error::invalid_length(0, &self));
+                {0:indent$}{0:indent$}{0:indent$}{0:indent$}}};
+                {0:indent$}{0:indent$}{0:indent$}{0:indent$}let value = match type_name.as_str() {{
+                {value_parsers}{0:indent$}{0:indent$}{0:indent$}{0:indent$}{0:indent$}_ => return Err(serde::de::Error::unknown_variant(&type_name, NAMES)),
+                {0:indent$}{0:indent$}{0:indent$}{0:indent$}}};
+                {0:indent$}{0:indent$}{0:indent$}{0:indent$}if map.next_key::<serde::de::IgnoredAny>()?.is_some() {{
+                {0:indent$}{0:indent$}{0:indent$}{0:indent$}{0:indent$}return Err(serde::de::Error::invalid_length(2, &self));
+                {0:indent$}{0:indent$}{0:indent$}{0:indent$}}}
+                {0:indent$}{0:indent$}{0:indent$}{0:indent$}Ok(value)
+                {0:indent$}{0:indent$}{0:indent$}}}",
+                "",
+            )
+        };
+
+        return formatdoc!(
+            "
+            #[derive(Clone, Debug, PartialEq)]
+            pub enum {name} {{
+            {types}}}
+
+            impl serde::Serialize for {name} {{
+            {0:indent$}fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+            {0:indent$}{0:indent$}match self {{
+            {serializers}{0:indent$}{0:indent$}}}
+            {0:indent$}}}
+            }}
+
+            impl<'de> serde::Deserialize<'de> for {name} {{
+            {0:indent$}fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+            {0:indent$}{0:indent$}const NAMES: &[&str] = &[{names}];
+
+            {0:indent$}{0:indent$}struct Visitor;
+
+            {0:indent$}{0:indent$}impl<'de> serde::de::Visitor<'de> for Visitor {{
+            {0:indent$}{0:indent$}{0:indent$}type Value = {name};
+
+            {0:indent$}{0:indent$}{0:indent$}fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {{
+            {0:indent$}{0:indent$}{0:indent$}{0:indent$}formatter.write_str(\"one of the types of {name}\")
+            {0:indent$}{0:indent$}{0:indent$}}}{empty_parser}{value_parser}
+            {0:indent$}{0:indent$}}}
+
+            {0:indent$}{0:indent$}deserializer.deserialize_any(Visitor)
+            {0:indent$}}}
+            }}",
+            "",
+        );
+    }
+}
+
+/// The type specific information for an enum type
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct EnumType {
+    /// The name of this enum type
+    pub(super) name: String,
+    /// The description describing this enum type
+    pub(super) description: Option<String>,
+    /// The type this enum type is wrapping, may be omitted for an empty type
+    pub(super) data_type: Option<String>,
+    /// True if the value is stored in a Box because its type depends on the
+    /// enum
+    pub(super) boxed: bool,
+}
+
+impl EnumType {
+    /// Constructs a new Rust enum type from a generic enum type
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic enum type to convert
+    fn new(data: crate::EnumType) -> Result<Self, Error> {
+        return Ok(Self {
+            name: data.name,
+            description: data.description,
+            data_type: data.data_type,
+            boxed: false,
+        });
+    }
+
+    /// Converts the enum type to a string for use in the enum definition
+    ///
+    /// # Parameters
+    ///
+    /// indent: The number of spaces to use for indentation
+    fn get_definition(&self, indent: usize) -> String {
+        let data_type = match &self.data_type {
+            Some(data_type) if self.boxed => format!("(Box<{data_type}>)"),
+            Some(data_type) => format!("({data_type})", data_type = get_typename(data_type)),
+            None => "".to_string(),
+        };
+
+        return format!(
+            "{description}{0:indent$}{name}{data_type},\n",
+            "",
+            description = get_doc_comment(&self.description, indent),
+            name = self.name,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn basic() {
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/rust/type_enum/basic/basic.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source = include_str!("../../tests/rust/type_enum/basic/basic.rs");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
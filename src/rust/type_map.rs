@@ -0,0 +1,135 @@
+use super::*;
+use indoc::formatdoc;
+
+/// The type specific information for a map
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Map {
+    /// The data type for all values of the map
+    pub(super) data_type: String,
+    /// The type all keys must be valid as, any key is allowed if not given
+    pub(super) key_type: Option<String>,
+}
+
+impl Map {
+    /// Constructs a new Rust map from a generic map
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic map to convert
+    pub(super) fn new(data: crate::Map) -> Result<Self, Error> {
+        return Ok(Self {
+            data_type: data.data_type,
+            key_type: data.key_type,
+        });
+    }
+
+    /// Gets the names of all the types which are stored by value inside this
+    /// map, the values are stored on the heap so it is always empty
+    pub(super) fn get_dependencies(&self) -> Vec<&str> {
+        return vec![];
+    }
+
+    /// Converts the map to a string for use in the source file
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the map
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_source(&self, name: &str, indent: usize) -> String {
+        let map_type = format!(
+            "std::collections::BTreeMap<String, {data_type}>",
+            data_type = get_typename(&self.data_type)
+        );
+
+        // Any string is a valid string so the keys only have to be checked
+        // for custom key types
+        let key_type = match &self.key_type {
+            Some(key_type) if key_type != "string" => key_type,
+            _ => {
+                return formatdoc!(
+                    "
+                    {DERIVES}
+                    #[serde(transparent)]
+                    pub struct {name} {{
+                    {0:indent$}/// The values of the map
+                    {0:indent$}pub values: {map_type},
+                    }}",
+                    "",
+                );
+            }
+        };
+
+        return formatdoc!(
+            "
+            {DERIVES}
+            #[serde(try_from = \"{map_type}\", into = \"{map_type}\")]
+            pub struct {name} {{
+            {0:indent$}/// The values of the map, all keys must be valid as {key_type}
+            {0:indent$}pub values: {map_type},
+            }}
+
+            impl TryFrom<{map_type}> for {name} {{
+            {0:indent$}type Error = String;
+
+            {0:indent$}fn try_from(values: {map_type}) -> Result<Self, Self::Error> {{
+            {0:indent$}{0:indent$}for key in values.keys() {{
+            {0:indent$}{0:indent$}{0:indent$}let deserializer: serde::de::value::StrDeserializer<serde::de::value::Error> =
+            {0:indent$}{0:indent$}{0:indent$}{0:indent$}serde::de::IntoDeserializer::into_deserializer(key.as_str());
+            {0:indent$}{0:indent$}{0:indent$}if let Err(error) = <{key_type} as serde::Deserialize>::deserialize(deserializer) {{
+            {0:indent$}{0:indent$}{0:indent$}{0:indent$}return Err(format!(\"Invalid key {{key:?}}: {{error}}\"));
+            {0:indent$}{0:indent$}{0:indent$}}}
+            {0:indent$}{0:indent$}}}
+            {0:indent$}{0:indent$}Ok(Self {{ values }})
+            {0:indent$}}}
+            }}
+
+            impl From<{name}> for {map_type} {{
+            {0:indent$}fn from(value: {name}) -> Self {{
+            {0:indent$}{0:indent$}value.values
+            {0:indent$}}}
+            }}",
+            "",
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn basic() {
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/rust/type_map/basic/basic.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source = include_str!("../../tests/rust/type_map/basic/basic.rs");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn key_type() {
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/rust/type_map/key_type/key_type.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source = include_str!("../../tests/rust/type_map/key_type/key_type.rs");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
@@ -0,0 +1,356 @@
+use super::*;
+use indoc::formatdoc;
+
+/// The type specific information for a struct
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Struct {
+    /// A list of all the fields of the struct, the fields of inherited structs
+    /// are first
+    pub(super) fields: Vec<StructField>,
+    /// What to do with unknown fields when parsing
+    pub(super) unknown_fields: crate::UnknownFields,
+}
+
+impl Struct {
+    /// Constructs a new Rust struct from a generic struct
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic struct to convert
+    ///
+    /// custom_types: All the generic data types of the data model, used to
+    /// find the fields of inherited structs and convert default values
+    ///
+    /// macros: A map of all macros to expand default values
+    pub(super) fn new(
+        data: crate::Struct,
+        custom_types: &HashMap<String, crate::DataType>,
+        macros: &IndexMap<String, data_model::SerializationModel>,
+    ) -> Result<Self, Error> {
+        // Get all the inherited fields, the data model is validated so the
+        // inheritance chain is known to be correct
+        let mut bases = vec![];
+        let mut inherit = &data.inherit;
+        while let Some(name) = inherit {
+            match custom_types.get(name) {
+                Some(crate::DataType {
+                    data: crate::DataTypeData::Struct(base),
+                    ..
+                }) => {
+                    bases.push(base);
+                    inherit = &base.inherit;
+                }
+                _ => break,
+            }
+        }
+
+        // Convert the fields, errors in inherited fields are located at the
+        // inherit field
+        let mut fields = vec![];
+        for field in bases.iter().rev().flat_map(|base| base.fields.iter()) {
+            match StructField::new(field.clone(), custom_types, macros) {
+                Ok(result) => fields.push(result),
+                Err(error) => return Err(error.add_field("inherit")),
+            }
+        }
+        for (i, field) in data.fields.iter().enumerate() {
+            match StructField::new(field.clone(), custom_types, macros) {
+                Ok(result) => fields.push(result),
+                Err(error) => {
+                    return Err(error.add_field("default").add_element(i).add_field("fields"))
+                }
+            }
+        }
+
+        return Ok(Self {
+            fields,
+            unknown_fields: data.unknown_fields.unwrap_or_default(),
+        });
+    }
+
+    /// Gets the names of all the types which are stored by value inside this
+    /// struct
+    pub(super) fn get_dependencies(&self) -> Vec<&str> {
+        return self
+            .fields
+            .iter()
+            .filter(|field| !field.boxed)
+            .map(|field| field.data_type.as_str())
+            .collect();
+    }
+
+    /// Stores all fields of the given types in a Box
+    ///
+    /// # Parameters
+    ///
+    /// recursive_types: The types which depend on this struct
+    pub(super) fn box_references(&mut self, recursive_types: &HashSet<&str>) {
+        for field in self.fields.iter_mut() {
+            field.boxed = recursive_types.contains(field.data_type.as_str());
+        }
+    }
+
+    /// Converts the struct to a string for use in the source file
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the struct
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_source(&self, name: &str, indent: usize) -> String {
+        // Get the fields
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| field.get_definition(name, indent))
+            .collect::<String>();
+
+        // Get the functions for the default values
+        let default_functions = self
+            .fields
+            .iter()
+            .filter_map(|field| field.get_default_function(indent))
+            .collect::<Vec<String>>();
+        let default_functions = if default_functions.is_empty() {
+            "".to_string()
+        } else {
+            formatdoc!(
+                "
+
+
+                impl {name} {{
+                {functions}
+                }}",
+                functions = default_functions.join("\n\n"),
+            )
+        };
+
+        // Unknown fields are either kept, rejected by serde or skipped which
+        // is the default of serde
+        let (attribute, extra_fields) = match self.unknown_fields {
+            crate::UnknownFields::Collect => (
+                "",
+                formatdoc!(
+                    "
+                    {0:indent$}/// All fields which were not captured when parsing
+                    {0:indent$}#[serde(flatten)]
+                    {0:indent$}pub extra_fields: serde_json::Map<String, serde_json::Value>,
+                    ",
+                    "",
+                ),
+            ),
+            crate::UnknownFields::Reject => ("#[serde(deny_unknown_fields)]\n", "".to_string()),
+            crate::UnknownFields::Ignore => ("", "".to_string()),
+        };
+
+        return formatdoc!(
+            "
+            {DERIVES}
+            {attribute}pub struct {name} {{
+            {fields}{extra_fields}}}{default_functions}",
+        );
+    }
+}
+
+/// The data for a single field in a struct
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct StructField {
+    /// The name of the field
+    pub(super) name: String,
+    /// The description of the field
+    pub(super) description: Option<String>,
+    /// What type the field is, without Option<>
+    pub(super) data_type: String,
+    /// A default value if it it not required
+    pub(super) default: DefaultType,
+    /// True if the field is stored in a Box because its type depends on the
+    /// struct
+    pub(super) boxed: bool,
+}
+
+impl StructField {
+    /// Constructs a new Rust struct field from a generic struct field
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic struct field to convert
+    ///
+    /// custom_types: All the generic data types of the data model, used to
+    /// convert the default value
+    ///
+    /// macros: A map of all macros to expand default values
+    fn new(
+        data: crate::StructField,
+        custom_types: &HashMap<String, crate::DataType>,
+        macros: &IndexMap<String, data_model::SerializationModel>,
+    ) -> Result<Self, Error> {
+        let default = match data.default {
+            crate::DefaultType::Required => DefaultType::Required,
+            crate::DefaultType::Optional => DefaultType::Optional,
+            crate::DefaultType::Default(value) => {
+                let value = data_model::expand_macros(&value, macros, &mut HashSet::new())?;
+                match crate::schema::to_json(&value, &data.data_type, custom_types) {
+                    Ok(value) => DefaultType::Default(value.dump()),
+                    Err(error) => {
+                        return Err(Error {
+                            location: Location::default(),
+                            error: ErrorCore::InvalidDefault(Box::new(error)),
+                        })
+                    }
+                }
+            }
+        };
+
+        return Ok(Self {
+            name: data.name,
+            description: data.description,
+            data_type: data.data_type,
+            default,
+            boxed: false,
+        });
+    }
+
+    /// Gets the Rust type of the field
+    fn get_typename(&self) -> String {
+        let typename = get_typename(&self.data_type);
+        let typename = if self.boxed {
+            format!("Box<{typename}>")
+        } else {
+            typename
+        };
+
+        return match self.default {
+            DefaultType::Optional => format!("Option<{typename}>"),
+            _ => typename,
+        };
+    }
+
+    /// Gets the name of the function returning the default value
+    fn get_default_name(&self) -> String {
+        return format!("default_{name}", name = self.name);
+    }
+
+    /// Converts the field to a string for use in the struct definition
+    ///
+    /// # Parameters
+    ///
+    /// struct_name: The name of the struct the field is part of
+    ///
+    /// indent: The number of spaces to use for indentation
+    fn get_definition(&self, struct_name: &str, indent: usize) -> String {
+        let attribute = match self.default {
+            DefaultType::Required => "".to_string(),
+            DefaultType::Optional => format!(
+                "{0:indent$}#[serde(default, skip_serializing_if = \"Option::is_none\")]\n",
+                ""
+            ),
+            DefaultType::Default(_) => format!(
+                "{0:indent$}#[serde(default = \"{struct_name}::{default_name}\")]\n",
+                "",
+                default_name = self.get_default_name(),
+            ),
+        };
+
+        return format!(
+            "{description}{attribute}{0:indent$}pub {name}: {typename},\n",
+            "",
+            description = get_doc_comment(&self.description, indent),
+            name = get_identifier(&self.name),
+            typename = self.get_typename(),
+        );
+    }
+
+    /// Gets the function returning the default value of the field if it has
+    /// one
+    ///
+    /// # Parameters
+    ///
+    /// indent: The number of spaces to use for indentation
+    fn get_default_function(&self, indent: usize) -> Option<String> {
+        return match &self.default {
+            DefaultType::Default(value) => Some(formatdoc!(
+                "
+                {0:indent$}/// The default value of the {name} field
+                {0:indent$}pub fn {default_name}() -> {typename} {{
+                {0:indent$}{0:indent$}serde_json::from_value(serde_json::json!({value})).unwrap()
+                {0:indent$}}}",
+                "",
+                name = self.name,
+                default_name = self.get_default_name(),
+                typename = self.get_typename(),
+            )),
+            _ => None,
+        };
+    }
+}
+
+/// Describes whether a field is required or optional
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum DefaultType {
+    /// The field must be supplied
+    Required,
+    /// The field can be supplied, if not supplied it is None
+    Optional,
+    /// The field can be supplied, if not supplied it defaults to the value
+    /// which is stored as JSON
+    Default(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn basic() {
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/rust/type_struct/basic/basic.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source = include_str!("../../tests/rust/type_struct/basic/basic.rs");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn inheritance() {
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/rust/type_struct/inheritance/inheritance.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source =
+            include_str!("../../tests/rust/type_struct/inheritance/inheritance.rs");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn unknown_fields() {
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/rust/type_struct/unknown_fields/unknown_fields.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source =
+            include_str!("../../tests/rust/type_struct/unknown_fields/unknown_fields.rs");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
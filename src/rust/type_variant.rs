@@ -0,0 +1,113 @@
+use super::*;
+use indoc::formatdoc;
+
+/// The type specific information for a variant
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Variant {
+    /// The possible types for the variant
+    pub(super) data_types: Vec<String>,
+    /// The types which are stored in a Box because they depend on the variant
+    pub(super) boxed_types: HashSet<String>,
+}
+
+impl Variant {
+    /// Constructs a new Rust variant from a generic variant
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic variant to convert
+    pub(super) fn new(data: crate::Variant) -> Result<Self, Error> {
+        return Ok(Self {
+            data_types: data.data_types,
+            boxed_types: HashSet::new(),
+        });
+    }
+
+    /// Gets the names of all the types which are stored by value inside this
+    /// variant
+    pub(super) fn get_dependencies(&self) -> Vec<&str> {
+        return self
+            .data_types
+            .iter()
+            .filter(|data_type| !self.boxed_types.contains(*data_type))
+            .map(|data_type| data_type.as_str())
+            .collect();
+    }
+
+    /// Stores all values of the given types in a Box
+    ///
+    /// # Parameters
+    ///
+    /// recursive_types: The types which depend on this variant
+    pub(super) fn box_references(&mut self, recursive_types: &HashSet<&str>) {
+        self.boxed_types = self
+            .data_types
+            .iter()
+            .filter(|data_type| recursive_types.contains(data_type.as_str()))
+            .cloned()
+            .collect();
+    }
+
+    /// Converts the variant to a string for use in the source file, it is
+    /// untagged such that serde attempts the types in order
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the variant
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_source(&self, name: &str, indent: usize) -> String {
+        let variants = self
+            .data_types
+            .iter()
+            .map(|data_type| {
+                // Builtin types are named by their capitalized name
+                let variant_name = match data_type.as_str() {
+                    "string" => "String",
+                    "number" => "Number",
+                    "integer" => "Integer",
+                    "boolean" => "Boolean",
+                    _ => data_type.as_str(),
+                };
+                let typename = if self.boxed_types.contains(data_type) {
+                    format!("Box<{data_type}>")
+                } else {
+                    get_typename(data_type)
+                };
+
+                return format!("{0:indent$}{variant_name}({typename}),\n", "");
+            })
+            .collect::<String>();
+
+        return formatdoc!(
+            "
+            {DERIVES}
+            #[serde(untagged)]
+            pub enum {name} {{
+            {variants}}}",
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn basic() {
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/rust/type_variant/basic/basic.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(4).unwrap();
+        let expected_source = include_str!("../../tests/rust/type_variant/basic/basic.rs");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
@@ -0,0 +1,472 @@
+use crate::constraint::{Constraint, Expression, Operator};
+use crate::data_model;
+use jzon::JsonValue;
+use std::collections::{HashMap, HashSet};
+
+/// The kind of JSON value a constrained type is stored as, decides which
+/// schema keywords a constraint can be translated into
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) enum ValueKind {
+    /// A number or an integer, x can be compared directly
+    Number,
+    /// A string, the length can be constrained
+    String,
+    /// An array, the number of elements can be constrained
+    Array,
+    /// A map, the number of entries can be constrained
+    Map,
+    /// Any other value, no constraints can be translated
+    Other,
+}
+
+impl ValueKind {
+    /// Finds the kind of JSON value a type is stored as, constrained types are
+    /// followed to the type they wrap
+    ///
+    /// # Parameters
+    ///
+    /// data_type: The name of the type
+    ///
+    /// custom_types: All the custom types in the schema
+    pub(super) fn new(
+        data_type: &str,
+        custom_types: &HashMap<String, data_model::DataType>,
+    ) -> ValueKind {
+        let mut data_type = data_type;
+        let mut visited = HashSet::new();
+        loop {
+            match data_type {
+                "number" | "integer" => return ValueKind::Number,
+                "string" => return ValueKind::String,
+                _ => (),
+            }
+
+            if !visited.insert(data_type) {
+                return ValueKind::Other;
+            }
+
+            match custom_types.get(data_type).map(|custom_type| &custom_type.data) {
+                Some(data_model::DataTypeData::Array(_)) => return ValueKind::Array,
+                Some(data_model::DataTypeData::Map(_)) => return ValueKind::Map,
+                Some(data_model::DataTypeData::ConstrainedType(data)) => {
+                    data_type = &data.data_type
+                }
+                _ => return ValueKind::Other,
+            }
+        }
+    }
+}
+
+/// Translates a constraint into JSON schema keywords, the constraint is split
+/// at all top level ands and the parts of the forms "x op c", "len(x) op c",
+/// "matches(x, pattern)" and "x in [...]" are recognised where op is a
+/// comparison and c is a literal.
+///
+/// Returns a list of all the keywords of the recognised parts and a list of
+/// the parts which could not be recognised
+///
+/// # Parameters
+///
+/// constraint: The constraint to translate
+///
+/// kind: The kind of value the constraint is for
+pub(super) fn translate(
+    constraint: &Constraint,
+    kind: ValueKind,
+) -> (Vec<(String, JsonValue)>, Vec<String>) {
+    let mut parts = vec![];
+    split_conjunction(constraint.expression(), &mut parts);
+
+    let mut keywords = vec![];
+    let mut unknown = vec![];
+    for part in parts {
+        match get_keywords(part, kind) {
+            Some(part_keywords) => keywords.extend(part_keywords),
+            None => unknown.push(part.to_string()),
+        }
+    }
+
+    return (keywords, unknown);
+}
+
+/// Splits an expression at all top level ands
+///
+/// # Parameters
+///
+/// expression: The expression to split
+///
+/// parts: The list to add all the parts to
+fn split_conjunction<'a>(expression: &'a Expression, parts: &mut Vec<&'a Expression>) {
+    match expression {
+        Expression::Binary(lhs, Operator::And, rhs) => {
+            split_conjunction(lhs, parts);
+            split_conjunction(rhs, parts);
+        }
+        _ => parts.push(expression),
+    }
+}
+
+/// Translates a single part of a constraint into JSON schema keywords,
+/// returns None if it cannot be translated
+///
+/// # Parameters
+///
+/// expression: The part to translate
+///
+/// kind: The kind of value the constraint is for
+fn get_keywords(expression: &Expression, kind: ValueKind) -> Option<Vec<(String, JsonValue)>> {
+    return match expression {
+        Expression::Matches(value, pattern)
+            if **value == Expression::Value && kind == ValueKind::String =>
+        {
+            Some(vec![("pattern".to_string(), JsonValue::from(pattern.as_str()))])
+        }
+        Expression::In(value, set) if **value == Expression::Value => {
+            let values = set
+                .iter()
+                .map(|element| get_literal(element, kind))
+                .collect::<Option<Vec<JsonValue>>>()?;
+            Some(vec![("enum".to_string(), JsonValue::Array(values))])
+        }
+        Expression::Binary(..) => get_comparison(expression)?.to_keywords(kind),
+        _ => None,
+    };
+}
+
+/// Converts a literal into a JSON value, returns None if it is not a literal
+/// of the given kind
+///
+/// # Parameters
+///
+/// expression: The literal to convert
+///
+/// kind: The kind of value the literal must have
+fn get_literal(expression: &Expression, kind: ValueKind) -> Option<JsonValue> {
+    return match (expression, kind) {
+        (Expression::String(value), ValueKind::String) => Some(JsonValue::from(value.as_str())),
+        (Expression::Number(_) | Expression::Negate(_), ValueKind::Number) => {
+            let text = get_number_text(expression)?;
+            // Integers are kept as integers
+            match text.parse::<i64>() {
+                Ok(value) => Some(JsonValue::from(value)),
+                Err(_) => Some(JsonValue::from(text.parse::<f64>().ok()?)),
+            }
+        }
+        _ => None,
+    };
+}
+
+/// Gets the text of a signed number literal, returns None if it is not a
+/// finite number
+///
+/// # Parameters
+///
+/// expression: The literal to get the text of
+fn get_number_text(expression: &Expression) -> Option<String> {
+    let text = match expression {
+        Expression::Number(value) => value.clone(),
+        Expression::Negate(value) => match value.as_ref() {
+            Expression::Number(value) => format!("-{value}"),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    return match text.parse::<f64>() {
+        Ok(value) if value.is_finite() => Some(text),
+        _ => None,
+    };
+}
+
+/// The value a constraint compares
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Subject {
+    /// The value itself
+    Value,
+    /// The length of the value
+    Length,
+}
+
+/// A comparison between the value and a literal
+#[derive(Clone, Debug, PartialEq)]
+struct Comparison<'a> {
+    /// What is compared
+    subject: Subject,
+    /// The comparison operator with the subject on the left
+    operator: Operator,
+    /// The literal the subject is compared to
+    literal: &'a Expression,
+}
+
+/// Reads a comparison between the value or its length and a literal, the
+/// literal may be on either side
+///
+/// # Parameters
+///
+/// expression: The expression of the comparison
+fn get_comparison(expression: &Expression) -> Option<Comparison<'_>> {
+    let (lhs, operator, rhs) = match expression {
+        Expression::Binary(lhs, operator, rhs) if operator.is_comparison() => {
+            (lhs.as_ref(), *operator, rhs.as_ref())
+        }
+        _ => return None,
+    };
+
+    // Put the subject on the left side
+    return match (get_subject(lhs), get_subject(rhs)) {
+        (Some(subject), None) => Some(Comparison {
+            subject,
+            operator,
+            literal: rhs,
+        }),
+        (None, Some(subject)) => Some(Comparison {
+            subject,
+            operator: match operator {
+                Operator::Greater => Operator::Less,
+                Operator::GreaterEqual => Operator::LessEqual,
+                Operator::Less => Operator::Greater,
+                Operator::LessEqual => Operator::GreaterEqual,
+                _ => operator,
+            },
+            literal: lhs,
+        }),
+        _ => None,
+    };
+}
+
+/// Reads the value or the length of the value
+///
+/// # Parameters
+///
+/// expression: The expression of the subject
+fn get_subject(expression: &Expression) -> Option<Subject> {
+    return match expression {
+        Expression::Value => Some(Subject::Value),
+        Expression::Length(value) if **value == Expression::Value => Some(Subject::Length),
+        _ => None,
+    };
+}
+
+impl Comparison<'_> {
+    /// Converts the comparison into JSON schema keywords, returns None if the
+    /// comparison is not supported for the kind of value
+    ///
+    /// # Parameters
+    ///
+    /// kind: The kind of value the comparison is for
+    fn to_keywords(&self, kind: ValueKind) -> Option<Vec<(String, JsonValue)>> {
+        return match (self.subject, kind) {
+            (Subject::Value, ValueKind::Number) => {
+                let value = get_literal(self.literal, kind)?;
+                Some(match self.operator {
+                    Operator::Greater => vec![("exclusiveMinimum".to_string(), value)],
+                    Operator::GreaterEqual => vec![("minimum".to_string(), value)],
+                    Operator::Less => vec![("exclusiveMaximum".to_string(), value)],
+                    Operator::LessEqual => vec![("maximum".to_string(), value)],
+                    Operator::Equal => vec![("const".to_string(), value)],
+                    _ => vec![("not".to_string(), jzon::object! { "const": value })],
+                })
+            }
+            (Subject::Value, ValueKind::String) => {
+                let value = get_literal(self.literal, kind)?;
+                match self.operator {
+                    Operator::Equal => Some(vec![("const".to_string(), value)]),
+                    Operator::NotEqual => {
+                        Some(vec![("not".to_string(), jzon::object! { "const": value })])
+                    }
+                    _ => None,
+                }
+            }
+            (Subject::Length, ValueKind::String) => self.to_length_keywords("Length"),
+            (Subject::Length, ValueKind::Array) => self.to_length_keywords("Items"),
+            (Subject::Length, ValueKind::Map) => self.to_length_keywords("Properties"),
+            _ => None,
+        };
+    }
+
+    /// Converts a comparison of the length into the min and max keywords
+    ///
+    /// # Parameters
+    ///
+    /// suffix: The suffix of the keywords, Length, Items or Properties
+    fn to_length_keywords(&self, suffix: &str) -> Option<Vec<(String, JsonValue)>> {
+        let constant = get_number_text(self.literal)?.parse::<f64>().ok()?;
+
+        // The length is always an integer so the bounds can be rounded
+        let (min, max) = match self.operator {
+            Operator::Greater => (Some(constant.floor() + 1.0), None),
+            Operator::GreaterEqual => (Some(constant.ceil()), None),
+            Operator::Less => (None, Some(constant.ceil() - 1.0)),
+            Operator::LessEqual => (None, Some(constant.floor())),
+            Operator::Equal if constant.fract() == 0.0 => (Some(constant), Some(constant)),
+            Operator::NotEqual if constant.fract() == 0.0 && constant >= 0.0 => {
+                let length = constant as u64;
+                let mut not = jzon::object::Object::new();
+                not.insert(&format!("min{suffix}"), JsonValue::from(length));
+                not.insert(&format!("max{suffix}"), JsonValue::from(length));
+                return Some(vec![("not".to_string(), JsonValue::Object(not))]);
+            }
+            _ => return None,
+        };
+
+        // Lengths can never be negative
+        if max.is_some_and(|max| max < 0.0) || min.is_some_and(|min| min < 0.0) {
+            return None;
+        }
+
+        let mut keywords = vec![];
+        if let Some(min) = min {
+            keywords.push((format!("min{suffix}"), JsonValue::from(min as u64)));
+        }
+        if let Some(max) = max {
+            keywords.push((format!("max{suffix}"), JsonValue::from(max as u64)));
+        }
+
+        return Some(keywords);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Translates a constraint and converts the keywords to a JSON object for
+    /// easy comparison
+    fn translate_object(constraint: &str, kind: ValueKind) -> (String, Vec<String>) {
+        let (keywords, unknown) = translate(&Constraint::parse(constraint).unwrap(), kind);
+        let mut object = jzon::object::Object::new();
+        for (keyword, value) in keywords {
+            object.insert(&keyword, value);
+        }
+
+        return (JsonValue::Object(object).dump(), unknown);
+    }
+
+    #[test]
+    fn value_kind() {
+        let model = crate::DataModel::import_yaml(indoc::indoc! {"
+            data_types:
+            - name: Positive
+              data: !ConstrainedType
+                data_type: integer
+                constraints: []
+            - name: Small
+              data: !ConstrainedType
+                data_type: Positive
+                constraints: []
+            - name: Names
+              data: !Array
+                data_type: string
+            - name: FewNames
+              data: !ConstrainedType
+                data_type: Names
+                constraints: []
+            - name: Flag
+              data: !ConstrainedType
+                data_type: boolean
+                constraints: []
+        "})
+        .unwrap();
+        let custom_types = HashMap::from_iter(
+            model
+                .data_types
+                .iter()
+                .map(|data_type| (data_type.name.clone(), data_type.clone())),
+        );
+
+        assert_eq!(ValueKind::new("Small", &custom_types), ValueKind::Number);
+        assert_eq!(ValueKind::new("FewNames", &custom_types), ValueKind::Array);
+        assert_eq!(ValueKind::new("Flag", &custom_types), ValueKind::Other);
+        assert_eq!(ValueKind::new("string", &custom_types), ValueKind::String);
+    }
+
+    #[test]
+    fn number() {
+        assert_eq!(
+            translate_object("x > 0.0", ValueKind::Number),
+            ("{\"exclusiveMinimum\":0}".to_string(), vec![])
+        );
+        assert_eq!(
+            translate_object("x >= -1.5 && x <= 2", ValueKind::Number),
+            ("{\"minimum\":-1.5,\"maximum\":2}".to_string(), vec![])
+        );
+        assert_eq!(
+            translate_object("10 > x", ValueKind::Number),
+            ("{\"exclusiveMaximum\":10}".to_string(), vec![])
+        );
+        assert_eq!(
+            translate_object("(x != 3) && (x == 1e3)", ValueKind::Number),
+            ("{\"not\":{\"const\":3},\"const\":1000}".to_string(), vec![])
+        );
+    }
+
+    #[test]
+    fn length() {
+        assert_eq!(
+            translate_object("!(x == \"\") && len(x) < 10", ValueKind::String),
+            (
+                "{\"maxLength\":9}".to_string(),
+                vec!["!(x == \"\")".to_string()]
+            )
+        );
+        assert_eq!(
+            translate_object("len(x) == 2", ValueKind::String),
+            ("{\"minLength\":2,\"maxLength\":2}".to_string(), vec![])
+        );
+        assert_eq!(
+            translate_object("len(x) > 0", ValueKind::Array),
+            ("{\"minItems\":1}".to_string(), vec![])
+        );
+        assert_eq!(
+            translate_object("len(x) <= 4", ValueKind::Map),
+            ("{\"maxProperties\":4}".to_string(), vec![])
+        );
+        assert_eq!(
+            translate_object("len(x) != 0", ValueKind::Array),
+            ("{\"not\":{\"minItems\":0,\"maxItems\":0}}".to_string(), vec![])
+        );
+    }
+
+    #[test]
+    fn string() {
+        assert_eq!(
+            translate_object("matches(x, \"^[a-z]+$\") && x != \"abc\"", ValueKind::String),
+            (
+                "{\"pattern\":\"^[a-z]+$\",\"not\":{\"const\":\"abc\"}}".to_string(),
+                vec![]
+            )
+        );
+        assert_eq!(
+            translate_object("x in [\"a\", \"b\"]", ValueKind::String),
+            ("{\"enum\":[\"a\",\"b\"]}".to_string(), vec![])
+        );
+        assert_eq!(
+            translate_object("x in [1, -2.5]", ValueKind::Number),
+            ("{\"enum\":[1,-2.5]}".to_string(), vec![])
+        );
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(
+            translate_object("x > 0 || x < -1", ValueKind::Number),
+            ("{}".to_string(), vec!["x > 0 || x < -1".to_string()])
+        );
+        assert_eq!(
+            translate_object("len(x) < 10", ValueKind::Number),
+            ("{}".to_string(), vec!["len(x) < 10".to_string()])
+        );
+        assert_eq!(
+            translate_object("x > 0", ValueKind::String),
+            ("{}".to_string(), vec!["x > 0".to_string()])
+        );
+        assert_eq!(
+            translate_object("len(x) < 0", ValueKind::String),
+            ("{}".to_string(), vec!["len(x) < 0".to_string()])
+        );
+        assert_eq!(
+            translate_object("x > 2 * x", ValueKind::Number),
+            ("{}".to_string(), vec!["x > 2 * x".to_string()])
+        );
+    }
+}
@@ -0,0 +1,1079 @@
+//! Import of data models from JSON schemas, the inverse of export_schema
+
+use super::*;
+use crate::constraint::Constraint;
+
+/// Keywords which only document a schema and are skipped when importing
+const ANNOTATIONS: [&str; 9] = [
+    "$schema",
+    "$id",
+    "$comment",
+    "title",
+    "description",
+    "examples",
+    "deprecated",
+    "readOnly",
+    "writeOnly",
+];
+
+/// Keywords which are imported as constraints of a constrained type
+const CONSTRAINTS: [&str; 14] = [
+    "minimum",
+    "maximum",
+    "exclusiveMinimum",
+    "exclusiveMaximum",
+    "multipleOf",
+    "minLength",
+    "maxLength",
+    "pattern",
+    "minItems",
+    "maxItems",
+    "minProperties",
+    "maxProperties",
+    "const",
+    "enum",
+];
+
+/// The keywords holding the definitions of a schema
+const DEFINITIONS: [&str; 2] = ["$defs", "definitions"];
+
+impl data_model::DataModel {
+    /// Creates a data model from a JSON schema, every definition in $defs (or
+    /// definitions) becomes a data type with the same name and the root schema
+    /// becomes a data type named after its title unless it only references a
+    /// definition. Schemas written inline are turned into data types named
+    /// after where they are used, like PointPosition for the position property
+    /// of Point.
+    ///
+    /// Objects with properties become structs, objects with only
+    /// additionalProperties become maps, arrays become arrays, anyOf and oneOf
+    /// become variants unless every alternative is an enum value as written by
+    /// export_schema, enums of strings become enums and numeric, string, array
+    /// and object bounds become constrained types. Every keyword which can not
+    /// be imported is reported as an error with its location in the schema.
+    ///
+    /// # Parameters
+    ///
+    /// schema: The JSON schema to import
+    pub fn from_json_schema(schema: &str) -> Result<data_model::DataModel, Vec<Error>> {
+        let schema = match jzon::parse(schema) {
+            Ok(schema) => schema,
+            Err(error) => {
+                return Err(vec![Error {
+                    location: Location::default(),
+                    error: ErrorCore::Parse(error.to_string()),
+                }])
+            }
+        };
+
+        let mut importer = SchemaImporter::new(&schema);
+        importer.import(&schema);
+        if !importer.errors.is_empty() {
+            return Err(importer.errors);
+        }
+
+        // Make sure the schema was converted into a valid data model
+        let data_model = data_model::DataModel {
+            data_types: importer.data_types,
+            ..Default::default()
+        };
+        if let Err(errors) = data_model.validate() {
+            return Err(vec![Error {
+                location: Location::default(),
+                error: ErrorCore::Validation(errors),
+            }]);
+        }
+
+        return Ok(data_model);
+    }
+}
+
+/// Converts the schemas of a JSON schema into data types
+struct SchemaImporter {
+    /// The names of the data types referenced by every supported $ref
+    refs: HashMap<String, String>,
+    /// All the names of data types which are used
+    names: HashSet<String>,
+    /// All the imported data types
+    data_types: Vec<data_model::DataType>,
+    /// All the problems found in the schema
+    errors: Vec<Error>,
+}
+
+impl SchemaImporter {
+    /// Creates an importer for a schema, all the definitions are registered so
+    /// they can be referenced before they are imported
+    ///
+    /// # Parameters
+    ///
+    /// schema: The root schema
+    fn new(schema: &JsonValue) -> Self {
+        let mut importer = Self {
+            refs: HashMap::new(),
+            names: HashSet::new(),
+            data_types: vec![],
+            errors: vec![],
+        };
+
+        for (keyword, name, definition) in get_definitions(schema) {
+            importer.names.insert(name.to_string());
+            importer
+                .refs
+                .insert(format!("#/{keyword}/{name}"), name.to_string());
+            if let Some(id) = definition["$id"].as_str() {
+                importer.refs.insert(id.to_string(), name.to_string());
+                if get_anchored_struct(definition).is_some() {
+                    importer
+                        .refs
+                        .insert(format!("{id}#{FIELDS_ANCHOR}"), name.to_string());
+                }
+            }
+        }
+
+        return importer;
+    }
+
+    /// Imports the root schema and all the definitions
+    ///
+    /// # Parameters
+    ///
+    /// schema: The root schema
+    fn import(&mut self, schema: &JsonValue) {
+        if !schema.is_object() {
+            self.errors.push(Error {
+                location: Location::default(),
+                error: ErrorCore::UnsupportedSchema(
+                    "The root of the schema must be an object".to_string(),
+                ),
+            });
+            return;
+        }
+
+        // The root schema is only a data type if it is more than a reference
+        // to one of the definitions
+        let keywords = get_keywords(schema, &DEFINITIONS);
+        if keywords.iter().any(|keyword| *keyword != "$ref") {
+            let title = schema["title"].as_str().unwrap_or("");
+            let name = match get_pascal_case(title) {
+                name if name.is_empty() => self.get_unique_name("Root"),
+                name => self.get_unique_name(&name),
+            };
+            self.refs.insert("#".to_string(), name.clone());
+            self.import_definition(&name, schema, &Location::default(), &DEFINITIONS);
+        } else if let Some(reference) = schema["$ref"].as_str() {
+            self.get_reference(reference, &Location::default().with_field("$ref"));
+        }
+
+        for (keyword, name, definition) in get_definitions(schema) {
+            let location = Location::default().with_field(keyword).with_field(name);
+            self.import_definition(name, definition, &location, &[]);
+        }
+    }
+
+    /// Imports a schema as a data type, the data type is added before any of
+    /// the data types created for its inline schemas
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the data type
+    ///
+    /// schema: The schema of the data type
+    ///
+    /// location: The location of the schema
+    ///
+    /// skipped: Keywords which are handled by the user of the schema
+    fn import_definition(
+        &mut self,
+        name: &str,
+        schema: &JsonValue,
+        location: &Location,
+        skipped: &[&str],
+    ) {
+        // Structs with their fields behind an anchor are read as one schema
+        let anchored = get_anchored_struct(schema);
+        let schema = anchored.as_ref().unwrap_or(schema);
+
+        let index = self.data_types.len();
+        if let Some(data) = self.get_data(name, schema, location, skipped) {
+            self.data_types.insert(
+                index,
+                data_model::DataType {
+                    name: name.to_string(),
+                    description: schema["description"].as_str().map(|value| value.to_string()),
+                    data,
+                },
+            );
+        }
+    }
+
+    /// Gets the name of the type of a schema used by another schema, if it is
+    /// not a reference or a builtin type then a new data type is created
+    ///
+    /// # Parameters
+    ///
+    /// schema: The schema of the type
+    ///
+    /// name: The name of the data type to create if needed
+    ///
+    /// location: The location of the schema
+    ///
+    /// skipped: Keywords which are handled by the user of the schema
+    fn get_type(
+        &mut self,
+        schema: &JsonValue,
+        name: &str,
+        location: &Location,
+        skipped: &[&str],
+    ) -> String {
+        let keywords = get_keywords(schema, skipped);
+        if let [keyword] = keywords.as_slice() {
+            if *keyword == "$ref" {
+                if let Some(reference) = schema["$ref"].as_str() {
+                    return self.get_reference(reference, &location.with_field("$ref"));
+                }
+            }
+            if *keyword == "type" {
+                if let Some(data_type) = schema["type"].as_str() {
+                    if data_model::BUILTIN_TYPES.contains(&data_type) {
+                        return data_type.to_string();
+                    }
+                }
+            }
+        }
+
+        let name = self.get_unique_name(name);
+        self.import_definition(&name, schema, location, skipped);
+        return name;
+    }
+
+    /// Gets the name of the data type a $ref refers to
+    ///
+    /// # Parameters
+    ///
+    /// reference: The value of the $ref keyword
+    ///
+    /// location: The location of the $ref keyword
+    fn get_reference(&mut self, reference: &str, location: &Location) -> String {
+        return match self.refs.get(reference) {
+            Some(name) => name.clone(),
+            None => {
+                self.errors.push(Error {
+                    location: location.clone(),
+                    error: ErrorCore::UnresolvedRef(reference.to_string()),
+                });
+                "string".to_string()
+            }
+        };
+    }
+
+    /// Converts a schema into the type specific data of a data type
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the data type
+    ///
+    /// schema: The schema of the data type
+    ///
+    /// location: The location of the schema
+    ///
+    /// skipped: Keywords which are handled by the user of the schema
+    fn get_data(
+        &mut self,
+        name: &str,
+        schema: &JsonValue,
+        location: &Location,
+        skipped: &[&str],
+    ) -> Option<data_model::DataTypeData> {
+        if !schema.is_object() {
+            self.errors.push(Error {
+                location: location.clone(),
+                error: ErrorCore::UnsupportedSchema(
+                    "Boolean schemas are not supported".to_string(),
+                ),
+            });
+            return None;
+        }
+        let keywords = get_keywords(schema, skipped);
+        let has = |keyword: &str| keywords.contains(&keyword);
+        let schema_type = schema["type"].as_str();
+
+        // Enums of strings and enums written like export_schema does
+        let is_string = matches!(schema_type, None | Some("string"));
+        if has("enum") && is_string && schema["enum"].members().all(|value| value.is_string()) {
+            self.check_keywords(&keywords, &["enum", "type"], location);
+            return Some(data_model::DataTypeData::Enum(data_model::Enum {
+                types: schema["enum"]
+                    .members()
+                    .map(|value| data_model::EnumType {
+                        name: value.as_str().unwrap().to_string(),
+                        description: None,
+                        data_type: None,
+                    })
+                    .collect(),
+            }));
+        }
+        if has("oneOf") && schema["oneOf"].members().all(is_enum_type) {
+            self.check_keywords(&keywords, &["oneOf"], location);
+            let types = schema["oneOf"]
+                .members()
+                .enumerate()
+                .map(|(i, value)| {
+                    let location = location.with_field("oneOf").with_element(i);
+                    return self.get_enum_type(name, value, &location);
+                })
+                .collect();
+            return Some(data_model::DataTypeData::Enum(data_model::Enum { types }));
+        }
+
+        // Bounds are constraints on the type described by the other keywords
+        if keywords.iter().any(|keyword| CONSTRAINTS.contains(keyword)) {
+            return Some(self.get_constrained_type(name, schema, location, skipped));
+        }
+
+        if has("anyOf") || has("oneOf") {
+            let keyword = if has("anyOf") { "anyOf" } else { "oneOf" };
+            self.check_keywords(&keywords, &[keyword], location);
+            let data_types = schema[keyword]
+                .members()
+                .enumerate()
+                .map(|(i, value)| {
+                    let location = location.with_field(keyword).with_element(i);
+                    return self.get_type(value, &format!("{name}{}", i + 1), &location, &[]);
+                })
+                .collect();
+            return Some(data_model::DataTypeData::Variant(data_model::Variant { data_types }));
+        }
+
+        if schema_type == Some("array") || (schema_type.is_none() && has("items")) {
+            self.check_keywords(&keywords, &["type", "items"], location);
+            if !has("items") {
+                self.errors.push(Error {
+                    location: location.clone(),
+                    error: ErrorCore::UnsupportedSchema(
+                        "Arrays must define the type of the items".to_string(),
+                    ),
+                });
+                return None;
+            }
+            let data_type = self.get_type(
+                &schema["items"],
+                &format!("{name}Item"),
+                &location.with_field("items"),
+                &[],
+            );
+            return Some(data_model::DataTypeData::Array(data_model::Array { data_type }));
+        }
+
+        if schema_type == Some("object") || has("properties") || has("additionalProperties") {
+            // Objects with only a schema for the values are maps
+            if schema["additionalProperties"].is_object() && !has("properties") {
+                self.check_keywords(
+                    &keywords,
+                    &["type", "additionalProperties", "propertyNames"],
+                    location,
+                );
+                let data_type = self.get_type(
+                    &schema["additionalProperties"],
+                    &format!("{name}Value"),
+                    &location.with_field("additionalProperties"),
+                    &[],
+                );
+                let key_type = if has("propertyNames") {
+                    Some(self.get_type(
+                        &schema["propertyNames"],
+                        &format!("{name}Key"),
+                        &location.with_field("propertyNames"),
+                        &[],
+                    ))
+                } else {
+                    None
+                };
+                return Some(data_model::DataTypeData::Map(data_model::Map {
+                    data_type,
+                    key_type,
+                }));
+            }
+
+            return Some(self.get_struct(name, schema, location, &keywords));
+        }
+
+        // Anything else is an alias of another type
+        let data_type = if let Some(data_type) =
+            schema_type.filter(|data_type| data_model::BUILTIN_TYPES.contains(data_type))
+        {
+            self.check_keywords(&keywords, &["type"], location);
+            Some(data_type.to_string())
+        } else if let (false, Some(reference)) = (has("type"), schema["$ref"].as_str()) {
+            self.check_keywords(&keywords, &["$ref"], location);
+            Some(self.get_reference(reference, &location.with_field("$ref")))
+        } else {
+            None
+        };
+        if let Some(data_type) = data_type {
+            return Some(data_model::DataTypeData::ConstrainedType(
+                data_model::ConstrainedType {
+                    data_type,
+                    constraints: vec![],
+                },
+            ));
+        }
+
+        self.errors.push(Error {
+            location: location.clone(),
+            error: ErrorCore::UnsupportedSchema(match &schema["type"] {
+                JsonValue::Null => "Unable to find the type of the schema".to_string(),
+                value => format!("The type {} is not supported", value.dump()),
+            }),
+        });
+        return None;
+    }
+
+    /// Converts an object schema with properties into a struct
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the struct
+    ///
+    /// schema: The schema of the struct
+    ///
+    /// location: The location of the schema
+    ///
+    /// keywords: The keywords of the schema which must be imported
+    fn get_struct(
+        &mut self,
+        name: &str,
+        schema: &JsonValue,
+        location: &Location,
+        keywords: &[&str],
+    ) -> data_model::DataTypeData {
+        self.check_keywords(
+            keywords,
+            &[
+                "type",
+                "properties",
+                "required",
+                "additionalProperties",
+                "unevaluatedProperties",
+                "$ref",
+            ],
+            location,
+        );
+
+        let required = schema["required"]
+            .members()
+            .filter_map(|value| value.as_str())
+            .collect::<Vec<&str>>();
+        let mut fields = vec![];
+        for (field_name, field_schema) in schema["properties"].entries() {
+            let field_location = location.with_field("properties").with_field(field_name);
+            let data_type = self.get_type(
+                field_schema,
+                &format!("{name}{}", get_pascal_case(field_name)),
+                &field_location,
+                &["default"],
+            );
+            let default = if field_schema.has_key("default") {
+                match get_serialization_model(&field_schema["default"]) {
+                    Some(value) => DefaultType::Default(value),
+                    None => {
+                        self.errors.push(Error {
+                            location: field_location.with_field("default"),
+                            error: ErrorCore::InvalidKeyword("default".to_string()),
+                        });
+                        DefaultType::Optional
+                    }
+                }
+            } else if required.contains(&field_name) {
+                DefaultType::Required
+            } else {
+                DefaultType::Optional
+            };
+
+            fields.push(data_model::StructField {
+                name: field_name.to_string(),
+                description: field_schema["description"]
+                    .as_str()
+                    .map(|value| value.to_string()),
+                data_type,
+                default,
+            });
+        }
+
+        // A struct referencing another struct inherits its fields
+        let inherit = schema["$ref"]
+            .as_str()
+            .map(|reference| self.get_reference(reference, &location.with_field("$ref")));
+
+        let mut unknown_fields = None;
+        for keyword in ["additionalProperties", "unevaluatedProperties"] {
+            match &schema[keyword] {
+                JsonValue::Null | JsonValue::Boolean(true) => (),
+                JsonValue::Boolean(false) => {
+                    unknown_fields = Some(data_model::UnknownFields::Reject);
+                }
+                _ => self.errors.push(Error {
+                    location: location.with_field(keyword),
+                    error: ErrorCore::UnsupportedSchema(
+                        "Objects with properties must not define the type of other properties"
+                            .to_string(),
+                    ),
+                }),
+            }
+        }
+
+        return data_model::DataTypeData::Struct(data_model::Struct {
+            fields,
+            inherit,
+            unknown_fields,
+        });
+    }
+
+    /// Converts an alternative of a oneOf written like export_schema does for
+    /// enums into an enum type
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the enum
+    ///
+    /// schema: The schema of the alternative
+    ///
+    /// location: The location of the schema
+    fn get_enum_type(
+        &mut self,
+        name: &str,
+        schema: &JsonValue,
+        location: &Location,
+    ) -> data_model::EnumType {
+        let description = schema["description"].as_str().map(|value| value.to_string());
+
+        if let Some(value) = schema["const"].as_str() {
+            return data_model::EnumType {
+                name: value.to_string(),
+                description,
+                data_type: None,
+            };
+        }
+
+        let (value, value_schema) = schema["properties"].entries().next().unwrap();
+        let data_type = self.get_type(
+            value_schema,
+            &format!("{name}{}", get_pascal_case(value)),
+            &location.with_field("properties").with_field(value),
+            &[],
+        );
+        return data_model::EnumType {
+            name: value.to_string(),
+            description,
+            data_type: Some(data_type),
+        };
+    }
+
+    /// Converts a schema with bounds into a constrained type, the keywords
+    /// which are not bounds describe the type which is constrained
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the constrained type
+    ///
+    /// schema: The schema of the constrained type
+    ///
+    /// location: The location of the schema
+    ///
+    /// skipped: Keywords which are handled by the user of the schema
+    fn get_constrained_type(
+        &mut self,
+        name: &str,
+        schema: &JsonValue,
+        location: &Location,
+        skipped: &[&str],
+    ) -> data_model::DataTypeData {
+        // Repeated bounds are written in allOf by export_schema
+        let mut bounds = vec![];
+        let mut base = jzon::object::Object::new();
+        for (keyword, value) in schema.entries() {
+            if CONSTRAINTS.contains(&keyword) {
+                bounds.push((keyword.to_string(), value, location.with_field(keyword)));
+            } else if keyword == "allOf" && value.members().all(is_bounds) {
+                for (i, bound) in value.members().enumerate() {
+                    for (keyword, value) in bound.entries() {
+                        let location = location.with_field("allOf").with_element(i);
+                        bounds.push((keyword.to_string(), value, location.with_field(keyword)));
+                    }
+                }
+            } else if !ANNOTATIONS.contains(&keyword) {
+                base.insert(keyword, value.clone());
+            }
+        }
+
+        let data_type = self.get_type(
+            &JsonValue::Object(base),
+            &format!("{name}Base"),
+            location,
+            skipped,
+        );
+
+        let mut constraints = vec![];
+        for (keyword, value, location) in bounds {
+            match get_constraint(&keyword, value, &data_type) {
+                Some(source) => match Constraint::parse(&source) {
+                    Ok(constraint) => constraints.push(constraint),
+                    Err(_) => self.errors.push(Error {
+                        location,
+                        error: ErrorCore::InvalidKeyword(keyword),
+                    }),
+                },
+                None => self.errors.push(Error {
+                    location,
+                    error: ErrorCore::InvalidKeyword(keyword),
+                }),
+            }
+        }
+
+        return data_model::DataTypeData::ConstrainedType(data_model::ConstrainedType {
+            data_type,
+            constraints,
+        });
+    }
+
+    /// Reports every keyword of a schema which is not supported
+    ///
+    /// # Parameters
+    ///
+    /// keywords: The keywords of the schema
+    ///
+    /// supported: The keywords which are imported
+    ///
+    /// location: The location of the schema
+    fn check_keywords(&mut self, keywords: &[&str], supported: &[&str], location: &Location) {
+        for keyword in keywords.iter() {
+            if !supported.contains(keyword) {
+                self.errors.push(Error {
+                    location: location.with_field(keyword),
+                    error: ErrorCore::UnsupportedKeyword(keyword.to_string()),
+                });
+            }
+        }
+    }
+
+    /// Gets a name for a new data type which is not used yet
+    ///
+    /// # Parameters
+    ///
+    /// name: The preferred name
+    fn get_unique_name(&mut self, name: &str) -> String {
+        let mut unique_name = name.to_string();
+        let mut index = 2;
+        while self.names.contains(&unique_name) {
+            unique_name = format!("{name}{index}");
+            index += 1;
+        }
+        self.names.insert(unique_name.clone());
+
+        return unique_name;
+    }
+}
+
+/// Gets all the definitions of a schema with the keyword they are defined in
+///
+/// # Parameters
+///
+/// schema: The root schema
+fn get_definitions(schema: &JsonValue) -> Vec<(&'static str, &str, &JsonValue)> {
+    return DEFINITIONS
+        .iter()
+        .flat_map(|keyword| {
+            return schema[*keyword]
+                .entries()
+                .map(|(name, definition)| (*keyword, name, definition));
+        })
+        .collect();
+}
+
+/// Merges a struct written like export_schema does for inherited structs
+/// rejecting unknown fields, with the fields in an anchored definition, back
+/// into a single schema
+///
+/// # Parameters
+///
+/// schema: The schema of the struct
+fn get_anchored_struct(schema: &JsonValue) -> Option<JsonValue> {
+    let fields = &schema["$defs"][FIELDS_ANCHOR];
+    if schema["$ref"].as_str() != Some(&format!("#{FIELDS_ANCHOR}"))
+        || fields["$anchor"].as_str() != Some(FIELDS_ANCHOR)
+        || schema["$defs"].len() != 1
+    {
+        return None;
+    }
+
+    let mut merged = fields.clone();
+    merged.remove("$anchor");
+    for (keyword, value) in schema.entries() {
+        if keyword != "$ref" && keyword != "$defs" {
+            merged[keyword] = value.clone();
+        }
+    }
+    return Some(merged);
+}
+
+/// Gets the keywords of a schema which must be imported
+///
+/// # Parameters
+///
+/// schema: The schema
+///
+/// skipped: Keywords which are handled by the user of the schema
+fn get_keywords<'a>(schema: &'a JsonValue, skipped: &[&str]) -> Vec<&'a str> {
+    return schema
+        .entries()
+        .map(|(keyword, _)| keyword)
+        .filter(|keyword| !ANNOTATIONS.contains(keyword) && !skipped.contains(keyword))
+        .collect();
+}
+
+/// Checks if an alternative of a oneOf is an enum type, either a const string
+/// or an object with a single required property and no other properties
+///
+/// # Parameters
+///
+/// schema: The alternative
+fn is_enum_type(schema: &JsonValue) -> bool {
+    let keywords = get_keywords(schema, &[]);
+    if keywords == ["const"] {
+        return schema["const"].is_string();
+    }
+
+    let mut sorted = keywords.clone();
+    sorted.sort();
+    return sorted == ["additionalProperties", "properties", "required", "type"]
+        && schema["type"] == "object"
+        && schema["additionalProperties"] == false
+        && schema["properties"].len() == 1
+        && schema["required"].len() == 1
+        && schema["properties"].has_key(schema["required"][0].as_str().unwrap_or(""));
+}
+
+/// Checks if a schema only consists of bounds
+///
+/// # Parameters
+///
+/// schema: The schema
+fn is_bounds(schema: &JsonValue) -> bool {
+    return schema.is_object()
+        && get_keywords(schema, &[])
+            .iter()
+            .all(|keyword| CONSTRAINTS.contains(keyword));
+}
+
+/// Writes a bound as a constraint, returns None if the value of the bound is
+/// not supported
+///
+/// # Parameters
+///
+/// keyword: The keyword of the bound
+///
+/// value: The value of the bound
+///
+/// data_type: The type which is constrained
+fn get_constraint(keyword: &str, value: &JsonValue, data_type: &str) -> Option<String> {
+    let number = if value.is_number() { Some(value.dump()) } else { None };
+    let count = value.as_u64().map(|value| value.to_string());
+
+    return match keyword {
+        "minimum" => number.map(|value| format!("x >= {value}")),
+        "maximum" => number.map(|value| format!("x <= {value}")),
+        "exclusiveMinimum" => number.map(|value| format!("x > {value}")),
+        "exclusiveMaximum" => number.map(|value| format!("x < {value}")),
+        // The remainder is only defined for integers
+        "multipleOf" if data_type == "integer" => count.map(|value| format!("x % {value} == 0")),
+        "minLength" | "minItems" | "minProperties" => {
+            count.map(|value| format!("len(x) >= {value}"))
+        }
+        "maxLength" | "maxItems" | "maxProperties" => {
+            count.map(|value| format!("len(x) <= {value}"))
+        }
+        "pattern" => value
+            .as_str()
+            .map(|value| format!("matches(x, {})", get_string_literal(value))),
+        "const" => get_literal(value).map(|value| format!("x == {value}")),
+        "enum" => value
+            .members()
+            .map(get_literal)
+            .collect::<Option<Vec<String>>>()
+            .map(|values| format!("x in [{}]", values.join(", "))),
+        _ => None,
+    };
+}
+
+/// Writes a JSON value as a literal of the constraint language
+///
+/// # Parameters
+///
+/// value: The value to write
+fn get_literal(value: &JsonValue) -> Option<String> {
+    return match value {
+        JsonValue::Number(_) | JsonValue::Boolean(_) => Some(value.dump()),
+        JsonValue::Short(_) | JsonValue::String(_) => Some(get_string_literal(value.as_str()?)),
+        _ => None,
+    };
+}
+
+/// Writes a string literal of the constraint language
+///
+/// # Parameters
+///
+/// value: The content of the string
+fn get_string_literal(value: &str) -> String {
+    let escaped = value
+        .chars()
+        .map(|c| match c {
+            '\\' => "\\\\".to_string(),
+            '"' => "\\\"".to_string(),
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            '\t' => "\\t".to_string(),
+            c => c.to_string(),
+        })
+        .collect::<String>();
+
+    return format!("\"{escaped}\"");
+}
+
+/// Converts a JSON value into a serialization model, returns None for null
+///
+/// # Parameters
+///
+/// value: The value to convert
+fn get_serialization_model(value: &JsonValue) -> Option<data_model::SerializationModel> {
+    return match value {
+        JsonValue::Null => None,
+        JsonValue::Short(_) | JsonValue::String(_) => Some(data_model::SerializationModel::Value(
+            value.as_str()?.to_string(),
+        )),
+        JsonValue::Number(_) | JsonValue::Boolean(_) => {
+            Some(data_model::SerializationModel::Value(value.dump()))
+        }
+        JsonValue::Array(values) => values
+            .iter()
+            .map(get_serialization_model)
+            .collect::<Option<Vec<_>>>()
+            .map(data_model::SerializationModel::Array),
+        JsonValue::Object(values) => values
+            .iter()
+            .map(|(key, value)| Some((key.to_string(), get_serialization_model(value)?)))
+            .collect::<Option<IndexMap<_, _>>>()
+            .map(data_model::SerializationModel::Map),
+    };
+}
+
+/// Converts a property name or title into a name for a data type like
+/// line_width into LineWidth
+///
+/// # Parameters
+///
+/// name: The name to convert
+fn get_pascal_case(name: &str) -> String {
+    return name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .map(|word| {
+            let mut chars = word.chars();
+            return match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            };
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        // Importing an exported schema gives the same schema when exported again
+        let model =
+            data_model::DataModel::import_yaml(include_str!("../../tests/yaml_import.yaml"))
+                .unwrap();
+        let schema = model.export_schema("NamedGeometry", "geometry").unwrap();
+
+        let imported = data_model::DataModel::from_json_schema(&schema.dump()).unwrap();
+        assert_eq!(imported.export_schema("NamedGeometry", "geometry").unwrap(), schema);
+    }
+
+    #[test]
+    fn round_trip_inherit() {
+        // Inherited structs rejecting unknown fields keep their fields apart
+        let model = data_model::DataModel::import_yaml(indoc::indoc! {"
+            data_types:
+            - name: Base
+              description: The base
+              data: !Struct
+                unknown_fields: reject
+                fields:
+                - name: width
+                  data_type: number
+                  default: Required
+            - name: Strict
+              data: !Struct
+                inherit: Base
+                unknown_fields: reject
+                fields:
+                - name: height
+                  data_type: number
+                  default: Optional
+        "})
+        .unwrap();
+        let schema = model.export_schema("Strict", "model").unwrap();
+
+        let imported = data_model::DataModel::from_json_schema(&schema.dump()).unwrap();
+        assert_eq!(imported.data_types.len(), 2);
+        assert_eq!(imported.data_types[1].name, "Base");
+        assert_eq!(imported.data_types[1].description.as_deref(), Some("The base"));
+        assert_eq!(imported.export_schema("Strict", "model").unwrap(), schema);
+    }
+
+    #[test]
+    fn upstream_schema() {
+        let model = data_model::DataModel::from_json_schema(indoc::indoc! {r##"
+            {
+              "$schema": "https://json-schema.org/draft/2020-12/schema",
+              "title": "server config",
+              "type": "object",
+              "properties": {
+                "name": { "type": "string", "description": "The name of the server" },
+                "port": { "$ref": "#/$defs/Port", "default": 8080 },
+                "log_level": { "enum": ["debug", "info", "warning"], "default": "info" },
+                "hosts": {
+                  "type": "array",
+                  "items": { "type": "string", "pattern": "^[a-z.]+$" },
+                  "minItems": 1
+                },
+                "limits": {
+                  "type": "object",
+                  "additionalProperties": { "type": "integer", "minimum": 0 }
+                },
+                "backend": { "anyOf": [{ "$ref": "#/$defs/Port" }, { "type": "string" }] }
+              },
+              "required": ["name", "hosts"],
+              "additionalProperties": false,
+              "$defs": {
+                "Port": {
+                  "type": "integer",
+                  "exclusiveMinimum": 0,
+                  "maximum": 65535
+                }
+              }
+            }
+        "##})
+        .unwrap();
+
+        assert_eq!(
+            model.export_yaml().unwrap(),
+            indoc::indoc! {r#"
+                data_types:
+                - name: ServerConfig
+                  data: !Struct
+                    fields:
+                    - name: name
+                      description: The name of the server
+                      data_type: string
+                      default: Required
+                    - name: port
+                      data_type: Port
+                      default: !Default 8080
+                    - name: log_level
+                      data_type: ServerConfigLogLevel
+                      default: !Default info
+                    - name: hosts
+                      data_type: ServerConfigHosts
+                      default: Required
+                    - name: limits
+                      data_type: ServerConfigLimits
+                      default: Optional
+                    - name: backend
+                      data_type: ServerConfigBackend
+                      default: Optional
+                    unknown_fields: reject
+                - name: ServerConfigLogLevel
+                  data: !Enum
+                    types:
+                    - name: debug
+                    - name: info
+                    - name: warning
+                - name: ServerConfigHosts
+                  data: !ConstrainedType
+                    data_type: ServerConfigHostsBase
+                    constraints:
+                    - len(x) >= 1
+                - name: ServerConfigHostsBase
+                  data: !Array
+                    data_type: ServerConfigHostsBaseItem
+                - name: ServerConfigHostsBaseItem
+                  data: !ConstrainedType
+                    data_type: string
+                    constraints:
+                    - matches(x, "^[a-z.]+$")
+                - name: ServerConfigLimits
+                  data: !Map
+                    data_type: ServerConfigLimitsValue
+                - name: ServerConfigLimitsValue
+                  data: !ConstrainedType
+                    data_type: integer
+                    constraints:
+                    - x >= 0
+                - name: ServerConfigBackend
+                  data: !Variant
+                    data_types:
+                    - Port
+                    - string
+                - name: Port
+                  data: !ConstrainedType
+                    data_type: integer
+                    constraints:
+                    - x > 0
+                    - x <= 65535
+            "#}
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        let errors = data_model::DataModel::from_json_schema(indoc::indoc! {r##"
+            {
+              "$ref": "#/$defs/Config",
+              "$defs": {
+                "Config": {
+                  "type": "object",
+                  "properties": {
+                    "email": { "type": "string", "format": "email" },
+                    "parent": { "$ref": "#/$defs/Missing" },
+                    "note": { "type": ["string", "null"] },
+                    "ratio": { "type": "number", "multipleOf": 0.5 }
+                  },
+                  "if": { "required": ["email"] }
+                }
+              }
+            }
+        "##})
+        .unwrap_err();
+
+        assert_eq!(
+            errors
+                .iter()
+                .map(|error| error.to_string())
+                .collect::<Vec<String>>(),
+            [
+                ".$defs.Config.if: The keyword \"if\" is not supported when importing a JSON \
+                 schema",
+                ".$defs.Config.properties.email.format: The keyword \"format\" is not supported \
+                 when importing a JSON schema",
+                ".$defs.Config.properties.parent.$ref: Unable to resolve \"#/$defs/Missing\", only \
+                 references to the definitions are supported",
+                ".$defs.Config.properties.note: The schema is not supported: The type \
+                 [\"string\",\"null\"] is not supported",
+                ".$defs.Config.properties.ratio.multipleOf: The value of the keyword \
+                 \"multipleOf\" is not supported",
+            ]
+        );
+    }
+}
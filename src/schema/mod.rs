@@ -1,10 +1,19 @@
-use crate::{data_model, DefaultType};
+use crate::{data_model, DefaultType, LocatedError, Location};
+use indexmap::IndexMap;
 use jzon::JsonValue;
 use std::{
     collections::{HashMap, HashSet},
     fmt,
 };
 
+mod constraint;
+mod import;
+
+/// The anchor of the fields of a struct which rejects unknown fields and is
+/// inherited from, inheriting structs refer to it as they would otherwise
+/// reject the fields they add
+const FIELDS_ANCHOR: &str = "fields";
+
 impl data_model::DataModel {
     /// Creates a JSON schema from the data model
     ///
@@ -14,16 +23,25 @@ impl data_model::DataModel {
     ///
     /// schema_id: The JSON Schema id to put in the json object
     pub fn export_schema(&self, id: &str, schema_id: &str) -> Result<JsonValue, Error> {
-        // Convert the data model to a map
+        // Make sure the data model is valid
+        if let Err(errors) = self.validate() {
+            return Err(Error {
+                location: Location::default(),
+                error: ErrorCore::Validation(errors),
+            });
+        }
+
+        // Convert the data model to a map including all the imported types
         let data_types = HashMap::<String, data_model::DataType>::from_iter(
-            self.data_types
-                .iter()
-                .map(|data_type| (data_type.name.clone(), data_type.clone())),
+            self.get_all_data_types()
+                .into_iter()
+                .map(|data_type| (data_type.name.clone(), data_type)),
         );
+        let macros = self.get_macros();
 
         // Find the main type
         let main_type = data_types.get(id).ok_or(Error {
-            location: "".to_string(),
+            location: Location::default(),
             error: ErrorCore::UnknownID(id.to_string()),
         })?;
 
@@ -48,7 +66,7 @@ impl data_model::DataModel {
             let type_schema = data_types.get(&implement_type).unwrap().export_schema(
                 &data_types,
                 &mut dependencies,
-                &self.macros,
+                &macros,
             )?;
 
             // Add it to the definitions
@@ -85,13 +103,41 @@ impl data_model::DataType {
         &self,
         custom_types: &HashMap<String, data_model::DataType>,
         dependencies: &mut HashSet<String>,
-        macros: &HashMap<String, data_model::SerializationModel>,
+        macros: &IndexMap<String, data_model::SerializationModel>,
     ) -> Result<jzon::object::Object, Error> {
         // Convert the data to a schema
         let mut schema = self
             .data
             .export_schema(custom_types, dependencies, macros)?;
 
+        // The closing keyword of a struct only sees the properties evaluated
+        // inside it, so the fields of an inherited struct rejecting unknown
+        // fields are kept apart for the inheriting structs to refer to
+        if let data_model::DataTypeData::Struct(data) = &self.data {
+            let inherited = custom_types
+                .values()
+                .any(|data_type| match &data_type.data {
+                    data_model::DataTypeData::Struct(child) => {
+                        child.inherit.as_ref() == Some(&self.name)
+                    }
+                    _ => false,
+                });
+            if inherited && data.unknown_fields == Some(data_model::UnknownFields::Reject) {
+                let mut fields = schema;
+                fields.remove("unevaluatedProperties");
+                let comment = fields.remove("$comment").unwrap();
+                fields.insert("$anchor", JsonValue::String(FIELDS_ANCHOR.to_string()));
+
+                let mut defs = jzon::object::Object::new();
+                defs.insert(FIELDS_ANCHOR, JsonValue::Object(fields));
+                schema = jzon::object::Object::new();
+                schema.insert("$comment", comment);
+                schema.insert("$ref", JsonValue::String(format!("#{FIELDS_ANCHOR}")));
+                schema.insert("unevaluatedProperties", JsonValue::Boolean(false));
+                schema.insert("$defs", JsonValue::Object(defs));
+            }
+        }
+
         // Add the id
         schema.insert("$id", JsonValue::String(self.name.clone()));
 
@@ -133,13 +179,14 @@ impl data_model::DataTypeData {
         &self,
         custom_types: &HashMap<String, data_model::DataType>,
         dependencies: &mut HashSet<String>,
-        macros: &HashMap<String, data_model::SerializationModel>,
+        macros: &IndexMap<String, data_model::SerializationModel>,
     ) -> Result<jzon::object::Object, Error> {
         return match self {
             data_model::DataTypeData::Struct(data) => {
                 data.export_schema(custom_types, dependencies, macros)
             }
             data_model::DataTypeData::Array(data) => data.export_schema(custom_types, dependencies),
+            data_model::DataTypeData::Map(data) => data.export_schema(custom_types, dependencies),
             data_model::DataTypeData::Variant(data) => {
                 data.export_schema(custom_types, dependencies)
             }
@@ -165,6 +212,7 @@ impl data_model::DataTypeData {
         return match self {
             data_model::DataTypeData::Struct(data) => data.schema_value(value, custom_types),
             data_model::DataTypeData::Array(data) => data.schema_value(value, custom_types),
+            data_model::DataTypeData::Map(data) => data.schema_value(value, custom_types),
             data_model::DataTypeData::Variant(data) => data.schema_value(value, custom_types),
             data_model::DataTypeData::Enum(data) => data.schema_value(value, custom_types),
             data_model::DataTypeData::ConstrainedType(data) => {
@@ -188,7 +236,7 @@ impl data_model::Struct {
         &self,
         custom_types: &HashMap<String, data_model::DataType>,
         dependencies: &mut HashSet<String>,
-        macros: &HashMap<String, data_model::SerializationModel>,
+        macros: &IndexMap<String, data_model::SerializationModel>,
     ) -> Result<jzon::object::Object, Error> {
         // Setup the required list
         let mut required = vec![];
@@ -243,26 +291,40 @@ impl data_model::Struct {
         if let Some(inherit) = &self.inherit {
             match custom_types.get(inherit) {
                 Some(value) => match &value.data {
-                    data_model::DataTypeData::Struct(_) => {
+                    data_model::DataTypeData::Struct(data) => {
+                        // Structs rejecting unknown fields are only open
+                        // through their fields anchor
                         dependencies.insert(inherit.clone());
-                        schema.insert("$ref", JsonValue::String(inherit.clone()));
+                        let reference =
+                            if data.unknown_fields == Some(data_model::UnknownFields::Reject) {
+                                format!("{inherit}#{FIELDS_ANCHOR}")
+                            } else {
+                                inherit.clone()
+                            };
+                        schema.insert("$ref", JsonValue::String(reference));
                     }
                     _ => {
                         return Err(Error {
-                            location: "".to_string(),
+                            location: Location::default(),
                             error: ErrorCore::EnheritStruct(inherit.clone()),
                         })
                     }
                 },
                 None => {
                     return Err(Error {
-                        location: "".to_string(),
+                        location: Location::default(),
                         error: ErrorCore::UnknownID(inherit.clone()),
                     });
                 }
             }
         }
 
+        // Unknown fields are only disallowed when rejected, the fields of the
+        // inherited struct are only evaluated through the $ref
+        if self.unknown_fields == Some(data_model::UnknownFields::Reject) {
+            schema.insert("unevaluatedProperties", JsonValue::Boolean(false));
+        }
+
         return Ok(schema);
     }
 
@@ -280,9 +342,26 @@ impl data_model::Struct {
     ) -> Result<JsonValue, Error> {
         return match value {
             data_model::SerializationModel::Map(value) => {
+                // Get all fields including the inherited ones
+                let mut fields = self.fields.iter().collect::<Vec<_>>();
+                let mut visited = HashSet::new();
+                let mut inherit = &self.inherit;
+                while let Some(name) = inherit {
+                    match custom_types.get(name) {
+                        Some(data_model::DataType {
+                            data: data_model::DataTypeData::Struct(data),
+                            ..
+                        }) if visited.insert(name) => {
+                            fields.extend(data.fields.iter());
+                            inherit = &data.inherit;
+                        }
+                        _ => break,
+                    }
+                }
+
                 // Convert each field
                 let mut json_object = jzon::object::Object::new();
-                for field in self.fields.iter() {
+                for field in fields.into_iter() {
                     if let Some(field_value) = value.get(&field.name) {
                         match to_json(field_value, &field.data_type, custom_types) {
                             Ok(value) => {
@@ -292,9 +371,9 @@ impl data_model::Struct {
                         }
                     } else if let DefaultType::Required = field.default {
                         return Err(Error {
-                            location: "".to_string(),
+                            location: Location::default(),
                             error: ErrorCore::StructConversionMissingField(
-                                value.clone(),
+                                Box::new(value.clone()),
                                 field.name.clone(),
                             ),
                         });
@@ -304,7 +383,7 @@ impl data_model::Struct {
                 // Make sure all fields are used
                 if json_object.len() != value.len() {
                     Err(Error {
-                        location: "".to_string(),
+                        location: Location::default(),
                         error: ErrorCore::StructConversionExcessFields(value.clone()),
                     })
                 } else {
@@ -312,8 +391,8 @@ impl data_model::Struct {
                 }
             }
             _ => Err(Error {
-                location: "".to_string(),
-                error: ErrorCore::SerializationModel(value.clone(), "struct".to_string()),
+                location: Location::default(),
+                error: ErrorCore::SerializationModel(Box::new(value.clone()), "struct".to_string()),
             }),
         };
     }
@@ -378,8 +457,86 @@ impl data_model::Array {
                 }
             }
             _ => Err(Error {
-                location: "".to_string(),
-                error: ErrorCore::SerializationModel(value.clone(), "array".to_string()),
+                location: Location::default(),
+                error: ErrorCore::SerializationModel(Box::new(value.clone()), "array".to_string()),
+            }),
+        };
+    }
+}
+
+impl data_model::Map {
+    /// Creates a JSON schema from the map
+    ///
+    /// # Parameters
+    ///
+    /// custom_types: The map of all the custom types, used to check if a type is builtin or not
+    ///
+    /// dependencies: A set to add all dependencies of this map to
+    pub fn export_schema(
+        &self,
+        custom_types: &HashMap<String, data_model::DataType>,
+        dependencies: &mut HashSet<String>,
+    ) -> Result<jzon::object::Object, Error> {
+        // Add the type to dependencies
+        let ref_keyword = is_schema_type(&self.data_type, custom_types, dependencies)?;
+
+        // Construct the value schema
+        let mut value_schema = jzon::object::Object::new();
+        value_schema.insert(ref_keyword, JsonValue::String(self.data_type.clone()));
+
+        // Construct the schema
+        let mut schema = jzon::object::Object::new();
+        schema.insert(
+            "$comment",
+            JsonValue::String("A map of the same type values with any keys".to_string()),
+        );
+        schema.insert("type", JsonValue::String("object".to_string()));
+        schema.insert("additionalProperties", JsonValue::Object(value_schema));
+
+        // Construct the key schema
+        if let Some(key_type) = &self.key_type {
+            let ref_keyword = is_schema_type(key_type, custom_types, dependencies)?;
+            let mut key_schema = jzon::object::Object::new();
+            key_schema.insert(ref_keyword, JsonValue::String(key_type.clone()));
+            schema.insert("propertyNames", JsonValue::Object(key_schema));
+        }
+
+        return Ok(schema);
+    }
+
+    /// Converts a serialization model value into a JSON value of the for of this type
+    ///
+    /// # Parameters
+    ///
+    /// value: The serialization model to convert
+    ///
+    /// custom_types: All the custom types in the schema
+    pub fn schema_value(
+        &self,
+        value: &data_model::SerializationModel,
+        custom_types: &HashMap<String, data_model::DataType>,
+    ) -> Result<JsonValue, Error> {
+        return match value {
+            data_model::SerializationModel::Map(values) => {
+                let mut json_object = jzon::object::Object::new();
+                for (key, value) in values.iter() {
+                    if let Some(key_type) = &self.key_type {
+                        let key_value = data_model::SerializationModel::Value(key.clone());
+                        if let Err(error) = to_json(&key_value, key_type, custom_types) {
+                            return Err(error.add_field(key));
+                        }
+                    }
+                    match to_json(value, &self.data_type, custom_types) {
+                        Ok(value) => json_object.insert(key, value),
+                        Err(error) => return Err(error.add_field(key)),
+                    }
+                }
+
+                Ok(JsonValue::Object(json_object))
+            }
+            _ => Err(Error {
+                location: Location::default(),
+                error: ErrorCore::SerializationModel(Box::new(value.clone()), "map".to_string()),
             }),
         };
     }
@@ -448,8 +605,8 @@ impl data_model::Variant {
         }
 
         return Err(Error {
-            location: "".to_string(),
-            error: ErrorCore::VariantConversion(value.clone(), failures),
+            location: Location::default(),
+            error: ErrorCore::VariantConversion(Box::new(value.clone()), failures),
         });
     }
 }
@@ -497,12 +654,12 @@ impl data_model::Enum {
                     schema
                 } else {
                     let mut schema = jzon::object::Object::new();
-                    schema.insert("type", JsonValue::String("string".to_string()));
-                    schema.insert("pattern", JsonValue::String(value.name.clone()));
+                    schema.insert("const", JsonValue::String(value.name.clone()));
 
                     schema
                 };
 
+                schema.insert("title", JsonValue::String(value.name.clone()));
                 if let Some(description) = &value.description {
                     schema.insert("description", JsonValue::String(description.clone()));
                 }
@@ -511,13 +668,13 @@ impl data_model::Enum {
             })
             .collect::<Result<Vec<_>, Error>>()?;
 
-        // Create the
+        // Create the schema, every value matches exactly one of the enum types
         let mut schema = jzon::object::Object::new();
         schema.insert(
             "$comment",
             JsonValue::String("A rust-like enum which can be typed".to_string()),
         );
-        schema.insert("enum", JsonValue::Array(enum_list));
+        schema.insert("oneOf", JsonValue::Array(enum_list));
 
         return Ok(schema);
     }
@@ -545,7 +702,7 @@ impl data_model::Enum {
                     return Ok(JsonValue::String(value.clone()));
                 } else {
                     Err(Error {
-                        location: "".to_string(),
+                        location: Location::default(),
                         error: ErrorCore::EnumConversion(value.clone()),
                     })
                 }
@@ -553,7 +710,7 @@ impl data_model::Enum {
             data_model::SerializationModel::Map(value) => {
                 if value.len() != 1 {
                     return Err(Error {
-                        location: "".to_string(),
+                        location: Location::default(),
                         error: ErrorCore::TypedEnumLayout(value.clone()),
                     });
                 }
@@ -576,14 +733,14 @@ impl data_model::Enum {
                     Ok(JsonValue::Object(json_object))
                 } else {
                     return Err(Error {
-                        location: "".to_string(),
+                        location: Location::default(),
                         error: ErrorCore::TypedEnumConversion(value.clone()),
                     });
                 }
             }
             data_model::SerializationModel::Array(_) => Err(Error {
-                location: "".to_string(),
-                error: ErrorCore::SerializationModel(value.clone(), "enum".to_string()),
+                location: Location::default(),
+                error: ErrorCore::SerializationModel(Box::new(value.clone()), "enum".to_string()),
             }),
         };
     }
@@ -605,20 +762,45 @@ impl data_model::ConstrainedType {
         // Add the type to dependencies
         let ref_keyword = is_schema_type(&self.data_type, custom_types, dependencies)?;
 
-        // Get the list of constraints
-        let constraints = self.constraints.join(", ");
+        // Translate the constraints into keywords where possible
+        let kind = constraint::ValueKind::new(&self.data_type, custom_types);
+        let mut keywords = vec![];
+        let mut unknown = vec![];
+        for constraint in self.constraints.iter() {
+            let (constraint_keywords, constraint_unknown) = constraint::translate(constraint, kind);
+            keywords.extend(constraint_keywords);
+            unknown.extend(constraint_unknown);
+        }
 
-        // Create the schema
+        // Create the schema, constraints which could not be translated are
+        // kept as a comment
         let mut schema = jzon::object::Object::new();
-        schema.insert(
-            "$comment",
-            JsonValue::String(format!(
-                "A constrained type which must keep the following true: [{}]",
-                constraints
-            )),
-        );
+        if !unknown.is_empty() {
+            schema.insert(
+                "$comment",
+                JsonValue::String(format!(
+                    "A constrained type which must keep the following true: [{}]",
+                    unknown.join(", ")
+                )),
+            );
+        }
         schema.insert(ref_keyword, JsonValue::String(self.data_type.clone()));
 
+        // Keywords which are used more than once are put into allOf
+        let mut all_of = vec![];
+        for (keyword, value) in keywords {
+            if schema.get(&keyword).is_some() {
+                let mut sub_schema = jzon::object::Object::new();
+                sub_schema.insert(&keyword, value);
+                all_of.push(JsonValue::Object(sub_schema));
+            } else {
+                schema.insert(&keyword, value);
+            }
+        }
+        if !all_of.is_empty() {
+            schema.insert("allOf", JsonValue::Array(all_of));
+        }
+
         return Ok(schema);
     }
 
@@ -634,7 +816,34 @@ impl data_model::ConstrainedType {
         value: &data_model::SerializationModel,
         custom_types: &HashMap<String, data_model::DataType>,
     ) -> Result<JsonValue, Error> {
-        return to_json(value, &self.data_type, custom_types);
+        let result = to_json(value, &self.data_type, custom_types)?;
+        self.check_constraints(value)?;
+
+        return Ok(result);
+    }
+
+    /// Makes sure a serialization model value fulfills all the constraints
+    ///
+    /// # Parameters
+    ///
+    /// value: The serialization model to check
+    pub(crate) fn check_constraints(
+        &self,
+        value: &data_model::SerializationModel,
+    ) -> Result<(), Error> {
+        for constraint in self.constraints.iter() {
+            let error = match constraint.evaluate(value) {
+                Ok(true) => continue,
+                Ok(false) => ErrorCore::FailedConstraint(Box::new(value.clone()), constraint.to_string()),
+                Err(error) => ErrorCore::ConstraintError(constraint.to_string(), Box::new(error)),
+            };
+            return Err(Error {
+                location: Location::default(),
+                error,
+            });
+        }
+
+        return Ok(());
     }
 }
 
@@ -647,7 +856,7 @@ impl data_model::ConstrainedType {
 /// data_type: The data type of the serialization model
 ///
 /// custom_types: All the custom types in the schema
-fn to_json(
+pub(crate) fn to_json(
     value: &data_model::SerializationModel,
     data_type: &str,
     custom_types: &HashMap<String, data_model::DataType>,
@@ -658,46 +867,46 @@ fn to_json(
                 "true" => Ok(JsonValue::Boolean(true)),
                 "false" => Ok(JsonValue::Boolean(false)),
                 _ => Err(Error {
-                    location: "".to_string(),
+                    location: Location::default(),
                     error: ErrorCore::BoolConversion(value.clone()),
                 }),
             },
             _ => Err(Error {
-                location: "".to_string(),
-                error: ErrorCore::SerializationModel(value.clone(), "boolean".to_string()),
+                location: Location::default(),
+                error: ErrorCore::SerializationModel(Box::new(value.clone()), "boolean".to_string()),
             }),
         },
         "integer" => match value {
             data_model::SerializationModel::Value(value) => match value.parse::<i64>() {
                 Ok(value) => Ok(JsonValue::Number(jzon::number::Number::from(value))),
                 Err(_) => Err(Error {
-                    location: "".to_string(),
+                    location: Location::default(),
                     error: ErrorCore::IntegerConversion(value.clone()),
                 }),
             },
             _ => Err(Error {
-                location: "".to_string(),
-                error: ErrorCore::SerializationModel(value.clone(), "integer".to_string()),
+                location: Location::default(),
+                error: ErrorCore::SerializationModel(Box::new(value.clone()), "integer".to_string()),
             }),
         },
         "number" => match value {
             data_model::SerializationModel::Value(value) => match value.parse::<f64>() {
                 Ok(value) => Ok(JsonValue::Number(jzon::number::Number::from(value))),
                 Err(_) => Err(Error {
-                    location: "".to_string(),
+                    location: Location::default(),
                     error: ErrorCore::FloatConversion(value.clone()),
                 }),
             },
             _ => Err(Error {
-                location: "".to_string(),
-                error: ErrorCore::SerializationModel(value.clone(), "number".to_string()),
+                location: Location::default(),
+                error: ErrorCore::SerializationModel(Box::new(value.clone()), "number".to_string()),
             }),
         },
         "string" => match value {
             data_model::SerializationModel::Value(value) => Ok(JsonValue::String(value.clone())),
             _ => Err(Error {
-                location: "".to_string(),
-                error: ErrorCore::SerializationModel(value.clone(), "string".to_string()),
+                location: Location::default(),
+                error: ErrorCore::SerializationModel(Box::new(value.clone()), "string".to_string()),
             }),
         },
         _ => {
@@ -705,7 +914,7 @@ fn to_json(
                 custom_type.schema_value(value, custom_types)
             } else {
                 Err(Error {
-                    location: "".to_string(),
+                    location: Location::default(),
                     error: ErrorCore::UnknownType(data_type.to_string()),
                 })
             }
@@ -737,7 +946,7 @@ fn is_schema_type(
     } else {
         if !vec!["boolean", "integer", "number", "string"].contains(&name) {
             return Err(Error {
-                location: "".to_string(),
+                location: Location::default(),
                 error: ErrorCore::UnknownType(name.to_string()),
             });
         }
@@ -750,7 +959,7 @@ fn is_schema_type(
 #[derive(Debug, Clone)]
 pub struct Error {
     /// The location where the error occured
-    pub location: String,
+    pub location: Location,
     /// The actual error that occured
     pub error: ErrorCore,
 }
@@ -762,24 +971,20 @@ impl Error {
     ///
     /// base: The base to set in the location
     fn add_field(self, base: &str) -> Error {
-        let location = format!(".{}{}", base, self.location);
-
         return Error {
-            location,
+            location: self.location.add_field(base),
             error: self.error,
         };
     }
 
-    /// Sets the current location to be the element of a field of the given base
+    /// Sets the current location to be the element of a sequence
     ///
     /// # Parameters
     ///
-    /// index: The index of the field
+    /// index: The index of the element
     fn add_element(self, index: usize) -> Error {
-        let location = format!("[{}]{}", index, self.location);
-
         return Error {
-            location,
+            location: self.location.add_element(index),
             error: self.error,
         };
     }
@@ -787,10 +992,20 @@ impl Error {
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Errors for the entire data model have no location
+        if self.location.is_empty() {
+            return write!(f, "{}", self.error);
+        }
         return write!(f, "{}: {}", self.location, self.error);
     }
 }
 
+impl LocatedError for Error {
+    fn get_location(&self) -> Location {
+        return self.location.clone();
+    }
+}
+
 impl From<data_model::Error> for Error {
     fn from(value: data_model::Error) -> Self {
         return Error {
@@ -814,7 +1029,7 @@ pub enum ErrorCore {
     EnheritStruct(String),
     /// Serialization model has an incompatible type
     #[error("The serialization model {:?} is incompatible with the type: {:}", .0, .1)]
-    SerializationModel(data_model::SerializationModel, String),
+    SerializationModel(Box<data_model::SerializationModel>, String),
     /// Unable to convert to boolean
     #[error("Unable to convert {:?} to a boolean", .0)]
     BoolConversion(String),
@@ -829,20 +1044,180 @@ pub enum ErrorCore {
     EnumConversion(String),
     /// Unable to convert to typed enum
     #[error("Unable to convert {:?} to a typed enum", .0)]
-    TypedEnumConversion(HashMap<String, data_model::SerializationModel>),
+    TypedEnumConversion(IndexMap<String, data_model::SerializationModel>),
     /// The map had more or less than one element when converting to a typed enum
     #[error("Unable to convert {:?} to a typed enum because it did not have a single field", .0)]
-    TypedEnumLayout(HashMap<String, data_model::SerializationModel>),
+    TypedEnumLayout(IndexMap<String, data_model::SerializationModel>),
     /// Unable to convert to variant
     #[error("Unable to convert {:?} to a variant with the following errors: {:?}", .0, .1)]
-    VariantConversion(data_model::SerializationModel, Vec<(String, Error)>),
+    VariantConversion(Box<data_model::SerializationModel>, Vec<(String, Error)>),
     /// Unable to convert to struct due to missing field
     #[error("Unable to convert {:?} to an struct because it is missing field {:}", .0, .1)]
-    StructConversionMissingField(HashMap<String, data_model::SerializationModel>, String),
+    StructConversionMissingField(Box<IndexMap<String, data_model::SerializationModel>>, String),
     /// Unable to convert to struct due to excess fields
     #[error("Unable to convert {:?} to an struct because it has excess fields", .0)]
-    StructConversionExcessFields(HashMap<String, data_model::SerializationModel>),
+    StructConversionExcessFields(IndexMap<String, data_model::SerializationModel>),
     /// Error expanding macros
     #[error("An error occured when expanding macros: {:?}", .0)]
     MacroError(data_model::Error),
+    /// The data model is not valid
+    #[error("The data model is not valid:{}", data_model::display_errors(.0))]
+    Validation(Vec<data_model::Error>),
+    /// The value does not fulfill a constraint
+    #[error("The {} does not fulfill the constraint {:?}", data_model::display_kind(.0), .1)]
+    FailedConstraint(Box<data_model::SerializationModel>, String),
+    /// A constraint could not be evaluated for the value
+    #[error("Unable to evaluate the constraint {:?}: {}", .0, .1)]
+    ConstraintError(String, Box<crate::constraint::Error>),
+    /// The JSON schema to import is not valid JSON
+    #[error("Unable to parse the JSON schema: {}", .0)]
+    Parse(String),
+    /// A keyword of the JSON schema to import is not supported
+    #[error("The keyword {:?} is not supported when importing a JSON schema", .0)]
+    UnsupportedKeyword(String),
+    /// The value of a keyword of the JSON schema to import is not supported
+    #[error("The value of the keyword {:?} is not supported", .0)]
+    InvalidKeyword(String),
+    /// A schema of the JSON schema to import can not be converted to a data type
+    #[error("The schema is not supported: {}", .0)]
+    UnsupportedSchema(String),
+    /// A $ref of the JSON schema to import does not refer to a definition
+    #[error("Unable to resolve {:?}, only references to the definitions are supported", .0)]
+    UnresolvedRef(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    /// Exports the Geometry type of the example data model and compiles it into a validator
+    fn geometry_validator() -> jsonschema::Validator {
+        let model = data_model::DataModel::import_yaml(
+            &fs::read_to_string("tests/yaml_import.yaml").unwrap(),
+        )
+        .unwrap();
+        let schema = model
+            .export_schema("Geometry", "https://example.com/geometry.schema.json")
+            .unwrap();
+        let schema = serde_json::from_str::<serde_json::Value>(&schema.dump()).unwrap();
+
+        return jsonschema::validator_for(&schema).unwrap();
+    }
+
+    #[test]
+    fn inherit_unknown_fields() {
+        let model = data_model::DataModel::import_yaml(indoc::indoc! {"
+            data_types:
+            - name: Base
+              data: !Struct
+                unknown_fields: reject
+                fields:
+                - name: width
+                  data_type: number
+                  default: Required
+            - name: Strict
+              data: !Struct
+                inherit: Base
+                unknown_fields: reject
+                fields:
+                - name: height
+                  data_type: number
+                  default: Required
+            - name: Deep
+              data: !Struct
+                inherit: Strict
+                unknown_fields: reject
+                fields:
+                - name: depth
+                  data_type: number
+                  default: Optional
+            - name: Open
+              data: !Struct
+                inherit: Base
+                fields:
+                - name: depth
+                  data_type: number
+                  default: Optional
+        "})
+        .unwrap();
+
+        for (id, document, valid) in [
+            ("Base", "{width: 1}", true),
+            ("Base", "{width: 1, height: 2}", false),
+            ("Base", "{}", false),
+            ("Strict", "{width: 1, height: 2}", true),
+            ("Strict", "{width: 1, height: 2, depth: 3}", false),
+            ("Strict", "{height: 2}", false),
+            ("Strict", "{width: 1, height: a}", false),
+            ("Deep", "{width: 1, height: 2, depth: 3}", true),
+            ("Deep", "{width: 1, height: 2}", true),
+            ("Deep", "{width: 1, height: 2, other: 3}", false),
+            ("Open", "{width: 1, depth: 3, other: 4}", true),
+            ("Open", "{depth: 3}", false),
+        ] {
+            let schema = model
+                .export_schema(id, "https://example.com/model.schema.json")
+                .unwrap();
+            let schema = serde_json::from_str::<serde_json::Value>(&schema.dump()).unwrap();
+            let validator = jsonschema::validator_for(&schema).unwrap();
+            let document = serde_yaml::from_str::<serde_json::Value>(document).unwrap();
+            assert_eq!(validator.is_valid(&document), valid, "{id}: {document}");
+        }
+    }
+
+    #[test]
+    fn enum_schema() {
+        let model = data_model::DataModel::import_yaml(
+            &fs::read_to_string("tests/yaml_import.yaml").unwrap(),
+        )
+        .unwrap();
+        let schema = model.export_schema("Geometry", "geometry").unwrap();
+        let variants = &schema["$defs"]["Geometry"]["oneOf"];
+
+        assert_eq!(variants.len(), 3);
+        assert_eq!(
+            variants[0].dump(),
+            "{\"const\":\"Nothing\",\"title\":\"Nothing\",\"description\":\"No geometry\"}"
+        );
+        assert_eq!(variants[1]["title"], "Sizes");
+        assert_eq!(variants[1]["description"], "A number of sizes");
+        assert_eq!(variants[1]["required"][0], "Sizes");
+        assert_eq!(variants[1]["properties"]["Sizes"]["$ref"], "SizeArray");
+        assert_eq!(variants[2]["title"], "Point");
+        assert!(schema["$defs"]["Geometry"]["enum"].is_null());
+    }
+
+    #[test]
+    fn enum_examples() {
+        let validator = geometry_validator();
+
+        for name in ["nothing", "point", "sizes"] {
+            let path = format!("tests/yaml_geometry_{name}_example.yaml");
+            let document =
+                serde_yaml::from_str::<serde_json::Value>(&fs::read_to_string(&path).unwrap())
+                    .unwrap();
+            if let Err(error) = validator.validate(&document) {
+                panic!("{path} is not valid: {error}");
+            }
+        }
+    }
+
+    #[test]
+    fn enum_invalid() {
+        let validator = geometry_validator();
+
+        for document in [
+            "xNothingx",
+            "Nothingness",
+            "Point",
+            "{\"Nothing\": 1}",
+            "{\"Point\": {\"x\": 1.0}, \"Sizes\": [1.0]}",
+            "{\"Point\": {\"x\": \"a\"}}",
+            "{\"Sizes\": [-1.0]}",
+        ] {
+            let document = serde_yaml::from_str::<serde_json::Value>(document).unwrap();
+            assert!(!validator.is_valid(&document), "{document} is valid");
+        }
+    }
 }
@@ -0,0 +1,503 @@
+use crate::Location;
+use indoc::formatdoc;
+use std::{collections::HashMap, fmt};
+use yaml_rust2::{
+    parser::{Event, MarkedEventReceiver, Parser},
+    scanner::Marker,
+};
+
+/// A position in the text of a data model
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span {
+    /// The line number starting at 1
+    pub line: usize,
+    /// The column number starting at 1
+    pub column: usize,
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return write!(f, "{}:{}", self.line, self.column);
+    }
+}
+
+/// The text of a data model file together with the position of every value in
+/// it, the positions are found from the locations used in the errors like
+/// .data_types[0].data.fields[1].default
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct Source {
+    /// The name of the file used in error messages
+    pub file: String,
+    /// The text of the file
+    pub text: String,
+    /// The position of the key or element of every value in the file
+    spans: HashMap<String, Span>,
+    /// True if the text is not valid YAML or JSON, the position of the entire
+    /// file is then the position of the syntax error
+    syntax_error: bool,
+    /// The sources of the imported data models in the order they are imported,
+    /// None if an import has not been resolved
+    imports: Vec<Option<Source>>,
+}
+
+impl Source {
+    /// Creates a source from the text of a YAML or JSON file, if the text
+    /// cannot be parsed the position of the syntax error is used for the whole
+    /// file
+    ///
+    /// # Parameters
+    ///
+    /// file: The name of the file used in error messages
+    ///
+    /// text: The text of the file
+    pub fn new(file: impl Into<String>, text: impl Into<String>) -> Self {
+        let text = text.into();
+
+        // The keys are counted first to know which mappings have a single key
+        let mut counter = KeyCounter {
+            counts: vec![],
+            stack: vec![],
+        };
+        let (spans, syntax_error) = match Parser::new_from_str(&text).load(&mut counter, false) {
+            Ok(()) => {
+                let mut collector = SpanCollector {
+                    spans: HashMap::new(),
+                    stack: vec![],
+                    key_counts: counter.counts,
+                    mappings: 0,
+                };
+                // The text is already known to be valid
+                let _ = Parser::new_from_str(&text).load(&mut collector, false);
+                (collector.spans, false)
+            }
+            Err(error) => (HashMap::from([("".to_string(), to_span(error.marker()))]), true),
+        };
+
+        return Self {
+            file: file.into(),
+            text,
+            spans,
+            syntax_error,
+            imports: vec![],
+        };
+    }
+
+    /// Sets the source of an imported data model
+    ///
+    /// # Parameters
+    ///
+    /// index: The index of the import
+    ///
+    /// source: The source of the imported data model
+    pub(crate) fn set_import(&mut self, index: usize, source: Option<Source>) {
+        if self.imports.len() <= index {
+            self.imports.resize(index + 1, None);
+        }
+        self.imports[index] = source;
+    }
+
+    /// Finds the position of a location, if the exact location is not in the
+    /// file the position of the closest parent is used
+    ///
+    /// # Parameters
+    ///
+    /// location: The location like .data_types[0].data.fields[1]
+    pub fn get_span(&self, location: &str) -> Option<Span> {
+        let mut location = location.to_string();
+
+        loop {
+            if let Some(span) = self.spans.get(&location) {
+                return Some(*span);
+            }
+            match location.rfind(['.', '[']) {
+                Some(index) => location.truncate(index),
+                None => return None,
+            }
+        }
+    }
+
+    /// Writes a message with the file, line and column of a location followed
+    /// by the line of the file it is on, locations inside imports are written
+    /// for the imported file. The location of an import which is not valid
+    /// YAML or JSON is written at the syntax error in the imported file.
+    ///
+    /// # Parameters
+    ///
+    /// location: The location the message is about
+    ///
+    /// message: The message to write
+    pub fn render(&self, location: &str, message: &str) -> String {
+        // Use the imported file if the location is inside an import
+        if let Some(rest) = location.strip_prefix(".imports[") {
+            if let Some((index, rest)) = rest.split_once(']') {
+                if let Some(Some(source)) = index
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|index| self.imports.get(index))
+                {
+                    if rest.starts_with(['.', '[']) || (rest.is_empty() && source.syntax_error) {
+                        return source.render(rest, message);
+                    }
+                }
+            }
+        }
+
+        let span = match self.get_span(location) {
+            Some(span) => span,
+            None => return format!("{}: {message}", self.file),
+        };
+        let line = self.text.lines().nth(span.line - 1).unwrap_or("");
+        let width = span.line.to_string().len();
+
+        return formatdoc!(
+            "
+            {file}:{span}: {message}
+            {0:width$} |
+            {line_number} | {line}
+            {0:width$} | {0:column$}^",
+            "",
+            file = self.file,
+            line_number = span.line,
+            column = line
+                .chars()
+                .take(span.column - 1)
+                .map(|c| if c == '\t' { 4 } else { 1 })
+                .sum::<usize>(),
+        );
+    }
+}
+
+/// An error with a location in the data model which can be written together
+/// with the position in the file it occured in
+pub trait LocatedError: fmt::Display {
+    /// Gets the path to the value the error is about
+    fn get_location(&self) -> Location;
+
+    /// Writes the error with the file, line and column of its location and the
+    /// line of the data model it is on
+    ///
+    /// # Parameters
+    ///
+    /// source: The source of the data model
+    fn render(&self, source: &Source) -> String {
+        return source.render(&self.get_location().to_string(), &self.to_string());
+    }
+}
+
+/// Converts a position from the YAML parser which has 0 based columns
+///
+/// # Parameters
+///
+/// mark: The position to convert
+fn to_span(mark: &Marker) -> Span {
+    return Span {
+        line: mark.line(),
+        column: mark.col() + 1,
+    };
+}
+
+/// Counts the keys of every mapping in the order the mappings start
+struct KeyCounter {
+    /// The number of keys of every mapping found so far
+    counts: Vec<usize>,
+    /// The index of every mapping currently being parsed together with the
+    /// number of nodes in it, None for sequences
+    stack: Vec<Option<(usize, usize)>>,
+}
+
+impl MarkedEventReceiver for KeyCounter {
+    fn on_event(&mut self, event: Event, _mark: Marker) {
+        match event {
+            Event::Scalar(..)
+            | Event::Alias(_)
+            | Event::MappingStart(..)
+            | Event::SequenceStart(..) => (),
+            Event::MappingEnd | Event::SequenceEnd => {
+                self.stack.pop();
+                return;
+            }
+            _ => return,
+        }
+
+        // Every other node of a mapping is a key
+        if let Some(Some((index, nodes))) = self.stack.last_mut() {
+            if *nodes % 2 == 0 {
+                self.counts[*index] += 1;
+            }
+            *nodes += 1;
+        }
+
+        match event {
+            Event::MappingStart(..) => {
+                self.stack.push(Some((self.counts.len(), 0)));
+                self.counts.push(0);
+            }
+            Event::SequenceStart(..) => self.stack.push(None),
+            _ => (),
+        }
+    }
+}
+
+/// Checks if the data model expects an enum written as a mapping from the
+/// variant to its value at a location, this is the data of a data type and the
+/// default value of a field
+///
+/// # Parameters
+///
+/// path: The location as written in the file
+fn expects_variant(path: &str) -> bool {
+    if !path.starts_with(".data_types[") {
+        return false;
+    }
+    let is_data = path
+        .strip_prefix(".data_types[")
+        .and_then(|rest| rest.strip_suffix("].data"))
+        .is_some_and(|index| index.parse::<usize>().is_ok());
+    let is_default = path.ends_with("].default") && path.contains(".fields[");
+    return is_data || is_default;
+}
+
+/// A node of the document being parsed
+enum Frame {
+    /// A mapping where key is the location of the value currently being
+    /// parsed, None if the next node is a key. Variant is true if the mapping
+    /// is an enum written as a mapping from the variant to its value.
+    Mapping {
+        paths: Vec<String>,
+        key: Option<Vec<String>>,
+        pending: Option<Vec<String>>,
+        variant: bool,
+    },
+    /// A sequence where index is the index of the next element
+    Sequence { paths: Vec<String>, index: usize },
+}
+
+/// Collects the position of every key and element while parsing a document
+struct SpanCollector {
+    /// The positions found so far
+    spans: HashMap<String, Span>,
+    /// The mappings and sequences currently being parsed
+    stack: Vec<Frame>,
+    /// The number of keys of every mapping in the order the mappings start
+    key_counts: Vec<usize>,
+    /// The number of mappings started so far
+    mappings: usize,
+}
+
+impl SpanCollector {
+    /// Stores the position of a location if it is not already known
+    ///
+    /// # Parameters
+    ///
+    /// paths: All the ways the location can be written
+    ///
+    /// mark: The position
+    fn insert(&mut self, paths: &[String], mark: Marker) {
+        for path in paths {
+            self.spans.entry(path.clone()).or_insert(to_span(&mark));
+        }
+    }
+}
+
+impl MarkedEventReceiver for SpanCollector {
+    fn on_event(&mut self, event: Event, mark: Marker) {
+        let tag = match &event {
+            Event::Scalar(..) | Event::Alias(_) => None,
+            Event::MappingStart(_, tag) | Event::SequenceStart(_, tag) => tag.as_ref(),
+            Event::MappingEnd | Event::SequenceEnd => {
+                self.stack.pop();
+                return;
+            }
+            _ => return,
+        };
+
+        // Find the location of the node, keys are stored with the location of
+        // their value
+        let paths = match self.stack.last_mut() {
+            Some(Frame::Mapping {
+                paths,
+                key,
+                pending,
+                variant,
+            }) => match key.take() {
+                Some(paths) => paths,
+                None => {
+                    let name = match &event {
+                        Event::Scalar(value, ..) => value.clone(),
+                        _ => "?".to_string(),
+                    };
+                    let mut value_paths = paths
+                        .iter()
+                        .map(|path| format!("{path}.{name}"))
+                        .collect::<Vec<_>>();
+                    // Enums in JSON are written as a mapping from the
+                    // capitalized variant to the value, the variant is left
+                    // out of the location when validating
+                    if *variant && name.starts_with(|c: char| c.is_ascii_uppercase()) {
+                        value_paths.extend(paths.iter().cloned());
+                    }
+                    *key = Some(value_paths.clone());
+
+                    // The position of a mapping is the position of its first key
+                    let mut found = value_paths;
+                    if let Some(pending) = pending.take() {
+                        found.extend(pending);
+                    }
+                    self.insert(&found, mark);
+                    if !matches!(event, Event::Scalar(..) | Event::Alias(_)) {
+                        self.stack.push(Frame::Sequence {
+                            paths: vec![],
+                            index: 0,
+                        });
+                    }
+                    return;
+                }
+            },
+            Some(Frame::Sequence { paths, index }) => {
+                let element_paths = paths
+                    .iter()
+                    .map(|path| format!("{path}[{index}]"))
+                    .collect::<Vec<_>>();
+                *index += 1;
+                element_paths
+            }
+            None => vec!["".to_string()],
+        };
+
+        // Values of enums are tagged, the tag is also part of the location
+        // when deserializing
+        let mut child_paths = paths.clone();
+        if let Some(tag) = tag {
+            child_paths.extend(paths.iter().map(|path| format!("{path}.{}", tag.suffix)));
+        }
+
+        match event {
+            Event::MappingStart(..) => {
+                // Enums in YAML are written with a tag instead
+                let keys = self.key_counts.get(self.mappings).copied().unwrap_or(0);
+                self.mappings += 1;
+                let variant = tag.is_none()
+                    && keys == 1
+                    && paths.first().is_some_and(|path| expects_variant(path));
+                self.stack.push(Frame::Mapping {
+                    paths: child_paths,
+                    key: None,
+                    pending: Some(paths),
+                    variant,
+                });
+            }
+            Event::SequenceStart(..) => {
+                self.insert(&paths, mark);
+                self.stack.push(Frame::Sequence {
+                    paths: child_paths,
+                    index: 0,
+                });
+            }
+            _ => self.insert(&paths, mark),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spans() {
+        let source = Source::new(
+            "model.yaml",
+            indoc::indoc! {"
+                data_types:
+                - name: Point
+                  data: !Struct
+                    fields:
+                    - name: x
+                      data_type: number
+                      default: !Optional $Zero$
+                macros:
+                  Zero: '0.0'
+            "},
+        );
+        let span = |line, column| Some(Span { line, column });
+
+        assert_eq!(source.get_span(".data_types[0]"), span(2, 3));
+        assert_eq!(source.get_span(".data_types[0].data.fields[0]"), span(5, 7));
+        assert_eq!(source.get_span(".data_types[0].data.Struct.fields[0]"), span(5, 7));
+        assert_eq!(source.get_span(".data_types[0].data.fields[0].default"), span(7, 7));
+        assert_eq!(source.get_span(".macros.Zero"), span(9, 3));
+        assert_eq!(source.get_span(".data_types[0].data.fields[0].default[$Zero$]"), span(7, 7));
+        assert_eq!(source.get_span(".missing"), span(1, 1));
+    }
+
+    #[test]
+    fn json_spans() {
+        let source = Source::new(
+            "model.json",
+            indoc::indoc! {r#"
+                {"data_types": [
+                  {"name": "A", "data": {"Array": {"data_type": "B"}}}
+                ]}
+            "#},
+        );
+
+        assert_eq!(
+            source.get_span(".data_types[0].data.data_type"),
+            Some(Span { line: 2, column: 36 })
+        );
+    }
+
+    #[test]
+    fn uppercase_keys() {
+        let source = Source::new(
+            "model.json",
+            indoc::indoc! {r#"
+                {"data_types": [
+                  {"name": "A", "data": {"Struct": {"fields": [
+                    {"name": "b", "data_type": "C", "default": {"Default": {
+                      "Name": {"x": "1"},
+                      "x": "2"}}}
+                  ]}}}],
+                 "macros": {"Zero": {"y": "0"},
+                  "y": "1"}}
+            "#},
+        );
+        let span = |line, column| Some(Span { line, column });
+
+        // Only the variants of the data type and the default are left out,
+        // capitalized fields and macros are not variants
+        assert_eq!(source.get_span(".data_types[0].data.fields"), span(2, 37));
+        assert_eq!(source.get_span(".data_types[0].data.fields[0].default.x"), span(5, 7));
+        assert_eq!(source.get_span(".data_types[0].data.fields[0].default.Name.x"), span(4, 16));
+        assert_eq!(source.get_span(".macros.y"), span(8, 3));
+        assert_eq!(source.get_span(".macros.Zero.y"), span(7, 22));
+    }
+
+    #[test]
+    fn render() {
+        let mut source = Source::new("model.yaml", "imports:\n- shared.yaml\nmacros:\n\tA: B\n");
+        assert_eq!(
+            source.render(".macros.A", "Invalid"),
+            "model.yaml:4:2: Invalid\n  |\n4 | \tA: B\n  |     ^"
+        );
+
+        // Locations in imports are written for the imported file
+        source.set_import(0, Some(Source::new("shared.yaml", "data_types: []\n")));
+        assert_eq!(
+            source.render(".imports[0].data_types", "Invalid"),
+            "shared.yaml:1:1: Invalid\n  |\n1 | data_types: []\n  | ^"
+        );
+
+        // The import itself is written for the importing file unless the
+        // imported file has a syntax error
+        let mut source = Source::new("model.yaml", "imports:\n- shared.yaml\n");
+        source.set_import(0, Some(Source::new("shared.yaml", "data_types: []\n")));
+        assert!(source.render(".imports[0]", "Invalid").starts_with("model.yaml:2:3: "));
+        source.set_import(0, Some(Source::new("shared.yaml", "data_types: [\n")));
+        assert!(source.render(".imports[0]", "Invalid").starts_with("shared.yaml:2:1: "));
+
+        // Syntax errors use the position of the error
+        let source = Source::new("model.yaml", "data_types: [\n");
+        assert_eq!(source.get_span(".data_types[0]"), Some(Span { line: 2, column: 1 }));
+        assert_eq!(Source::default().render("", "Invalid"), ": Invalid");
+    }
+}
@@ -0,0 +1,688 @@
+//!
+//! This module handles generation of TypeScript code to support a data model,
+//! it creates a single source file with a type for every data type together
+//! with an object of the same name holding the functions to parse it from
+//! plain values like the ones obtained from JSON.parse, filling in default
+//! values and checking the constraints of constrained types.
+//!
+//! For any data model to work the termite dependency must be generated from
+//! get_termite_dependency() and be saved as "termite.ts" next to the generated
+//! file.
+//!
+
+use indexmap::IndexMap;
+use std::{
+    collections::HashSet,
+    fmt,
+};
+
+mod type_array;
+mod type_constrained;
+mod type_enum;
+mod type_map;
+mod type_struct;
+mod type_variant;
+
+use type_array::Array;
+use type_constrained::ConstrainedType;
+use type_enum::Enum;
+use type_map::Map;
+use type_struct::Struct;
+use type_variant::Variant;
+
+use crate::{data_model, LocatedError, Location};
+
+/// Obtains the base termite TypeScript dependency required for all generated
+/// data models
+pub fn get_termite_dependency() -> &'static str {
+    return include_str!("termite.ts");
+}
+
+/// Converts a type name from the data model to the TypeScript type
+///
+/// # Parameters
+///
+/// data_type: The name of the type to convert
+fn get_typename(data_type: &str) -> String {
+    return match data_type {
+        "string" => "string".to_string(),
+        "number" | "integer" => "number".to_string(),
+        "boolean" => "boolean".to_string(),
+        _ => data_type.to_string(),
+    };
+}
+
+/// Gets the function parsing a plain value into the given type
+///
+/// # Parameters
+///
+/// data_type: The name of the type to parse
+fn get_parser(data_type: &str) -> String {
+    return match data_type {
+        "string" => "termite.parseString".to_string(),
+        "number" => "termite.parseNumber".to_string(),
+        "integer" => "termite.parseInteger".to_string(),
+        "boolean" => "termite.parseBoolean".to_string(),
+        _ => format!("{data_type}.parse"),
+    };
+}
+
+/// Converts a name to a property name, names which are not valid identifiers
+/// are quoted
+///
+/// # Parameters
+///
+/// name: The name to convert
+fn get_property(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+        }
+        _ => false,
+    };
+
+    return if is_identifier {
+        name.to_string()
+    } else {
+        get_string_literal(name)
+    };
+}
+
+/// Gets the code accessing a property of an object, names which are not valid
+/// identifiers are accessed with brackets
+///
+/// # Parameters
+///
+/// name: The name of the property
+fn get_property_access(name: &str) -> String {
+    let property = get_property(name);
+
+    return if property.starts_with('"') {
+        format!("[{property}]")
+    } else {
+        format!(".{property}")
+    };
+}
+
+/// Converts a string to a TypeScript string literal
+///
+/// # Parameters
+///
+/// value: The string to convert
+fn get_string_literal(value: &str) -> String {
+    let escaped = value
+        .chars()
+        .map(|c| match c {
+            '\\' => "\\\\".to_string(),
+            '"' => "\\\"".to_string(),
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            '\t' => "\\t".to_string(),
+            c if c.is_control() => format!("\\u{:04x}", c as u32),
+            c => c.to_string(),
+        })
+        .collect::<String>();
+
+    return format!("\"{escaped}\"");
+}
+
+/// Converts a serialization model to a plain TypeScript value
+///
+/// # Parameters
+///
+/// value: The serialization model to convert
+fn get_literal(value: &data_model::SerializationModel) -> String {
+    return match value {
+        data_model::SerializationModel::Map(map) => {
+            let values = map
+                .iter()
+                .map(|(key, value)| format!("{}: {}", get_property(key), get_literal(value)))
+                .collect::<Vec<String>>()
+                .join(", ");
+            if values.is_empty() {
+                "{}".to_string()
+            } else {
+                format!("{{ {values} }}")
+            }
+        }
+        data_model::SerializationModel::Array(list) => {
+            let values = list
+                .iter()
+                .map(|value| get_literal(value))
+                .collect::<Vec<String>>()
+                .join(", ");
+            format!("[{values}]")
+        }
+        data_model::SerializationModel::Value(value) => get_string_literal(value),
+    };
+}
+
+/// Creates a doc comment from a description, it is empty if there is no
+/// description
+///
+/// # Parameters
+///
+/// description: The description to write
+///
+/// indent: The number of spaces to indent the doc comment by
+fn get_doc_comment(description: &Option<String>, indent: usize) -> String {
+    let Some(description) = description else {
+        return "".to_string();
+    };
+
+    let lines = description
+        .lines()
+        .map(|line| {
+            return if line.is_empty() {
+                format!("{0:indent$} *\n", "")
+            } else {
+                format!("{0:indent$} * {line}\n", "")
+            };
+        })
+        .collect::<String>();
+
+    return format!("{0:indent$}/**\n{lines}{0:indent$} */\n", "");
+}
+
+/// An entire data model
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataModel {
+    /// List of the the data types to implement
+    data_types: Vec<DataType>,
+    /// The header to add to the top of the file
+    header: String,
+    /// The footer to add to the bottom of the file
+    footer: String,
+    /// A map of all macros to expand default values
+    macros: IndexMap<String, data_model::SerializationModel>,
+}
+
+impl DataModel {
+    /// Constructs a new TypeScript data model from a generic data model
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic data type to convert
+    pub fn new(data: crate::DataModel) -> Result<Self, Error> {
+        // Make sure the data model is valid, all types must be defined in the
+        // data model as there is a parser for each of them
+        if let Err(errors) = data.validate() {
+            return Err(Error {
+                location: Location::default(),
+                error: ErrorCore::Validation(errors),
+            });
+        }
+
+        // Everything is generated into a single file so the imported data types
+        // are generated as well, errors are located where the type is defined
+        let locations = data.get_type_locations();
+        let data = data.flatten_imports();
+
+        let data_types = data
+            .data_types
+            .iter()
+            .map(|data_type| {
+                let location = locations[&data_type.name].clone();
+                return DataType::new(data_type.clone(), &data.data_types, location);
+            })
+            .collect::<Result<Vec<DataType>, Error>>()?;
+
+        let header = match data.headers.get("typescript") {
+            Some(value) => value.clone(),
+            None => String::new(),
+        };
+        let footer = match data.footers.get("typescript") {
+            Some(value) => value.clone(),
+            None => String::new(),
+        };
+
+        return Ok(Self {
+            data_types,
+            header,
+            footer,
+            macros: data.macros,
+        });
+    }
+
+    /// Generates the source file
+    ///
+    /// # Parameters
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub fn get_source(&self, indent: usize) -> Result<String, Error> {
+        // Get all the data types
+        let data_types = self
+            .data_types
+            .iter()
+            .map(|data_type| data_type.get_source(&self.macros, indent))
+            .collect::<Result<Vec<String>, Error>>()?
+            .join("\n\n");
+
+        // Expand macros in the header and footer
+        let header = match data_model::expand_macros(
+            &data_model::SerializationModel::Value(self.header.clone()),
+            &self.macros,
+            &mut HashSet::new(),
+        )? {
+            data_model::SerializationModel::Value(value) => value,
+            _ => {
+                return Err(Error {
+                    location: Location::default(),
+                    error: ErrorCore::HeaderMacro(self.header.clone()),
+                })
+            }
+        };
+        let footer = match data_model::expand_macros(
+            &data_model::SerializationModel::Value(self.footer.clone()),
+            &self.macros,
+            &mut HashSet::new(),
+        )? {
+            data_model::SerializationModel::Value(value) => value,
+            _ => {
+                return Err(Error {
+                    location: Location::default(),
+                    error: ErrorCore::FooterMacro(self.footer.clone()),
+                })
+            }
+        };
+
+        // Leave out the header and footer if they are empty
+        let sections = [
+            "// Generated with the Termite Data Model Generator\n\nimport * as termite from \"./termite\";"
+                .to_string(),
+            header,
+            data_types,
+            footer,
+        ];
+
+        return Ok(sections
+            .into_iter()
+            .filter(|section| !section.is_empty())
+            .map(|section| section + "\n")
+            .collect::<Vec<String>>()
+            .join("\n"));
+    }
+}
+
+/// Any data type (struct, variant, ect.)
+#[derive(Clone, Debug, PartialEq)]
+struct DataType {
+    /// The name of the type
+    name: String,
+    /// The description of the type
+    description: Option<String>,
+    /// The type specific data
+    data: DataTypeData,
+    /// The location of the data type in the data model, used for errors
+    location: Location,
+}
+
+impl DataType {
+    /// Constructs a new TypeScript data type from a generic data type
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic data type to convert
+    ///
+    /// data_types: All the generic data types of the data model, used to
+    /// find the types of the values in constraints
+    ///
+    /// location: The location of the data type in the data model
+    fn new(
+        data: crate::DataType,
+        data_types: &[crate::DataType],
+        location: Location,
+    ) -> Result<Self, Error> {
+        // Convert the data
+        let processed_data = match DataTypeData::new(data.data, data_types) {
+            Ok(data) => data,
+            Err(error) => return Err(error.add_field("data").add_location(&location)),
+        };
+
+        return Ok(Self {
+            name: data.name,
+            description: data.description,
+            data: processed_data,
+            location,
+        });
+    }
+
+    /// Converts the data type to a string for use in the source file
+    ///
+    /// # Parameters
+    ///
+    /// macros: A map of all macros to expand default values
+    ///
+    /// indent: The number of spaces to use for indentation
+    fn get_source(
+        &self,
+        macros: &IndexMap<String, data_model::SerializationModel>,
+        indent: usize,
+    ) -> Result<String, Error> {
+        return match self
+            .data
+            .get_source(&self.name, &self.description, macros, indent)
+        {
+            Ok(result) => Ok(result),
+            Err(error) => Err(error.add_field("data").add_location(&self.location)),
+        };
+    }
+}
+
+/// Supplies the type specific information for a data type
+#[derive(Clone, Debug, PartialEq)]
+enum DataTypeData {
+    /// Describes a struct
+    Struct(Struct),
+    /// Describes an array
+    Array(Array),
+    /// Describes a map
+    Map(Map),
+    /// Describes a variant
+    Variant(Variant),
+    /// Describes an enum
+    Enum(Enum),
+    /// Describes a constrained type
+    ConstrainedType(ConstrainedType),
+}
+
+impl DataTypeData {
+    /// Constructs a new TypeScript data type data from a generic data type data
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic data type data to convert
+    ///
+    /// data_types: All the generic data types of the data model, used to
+    /// find the types of the values in constraints
+    fn new(data: crate::DataTypeData, data_types: &[crate::DataType]) -> Result<Self, Error> {
+        let result = match data {
+            crate::DataTypeData::Struct(data) => {
+                DataTypeData::Struct(Struct::new(data, data_types)?)
+            }
+            crate::DataTypeData::Array(data) => DataTypeData::Array(Array::new(data)?),
+            crate::DataTypeData::Map(data) => DataTypeData::Map(Map::new(data)?),
+            crate::DataTypeData::Variant(data) => DataTypeData::Variant(Variant::new(data)?),
+            crate::DataTypeData::Enum(data) => DataTypeData::Enum(Enum::new(data)?),
+            crate::DataTypeData::ConstrainedType(data) => {
+                DataTypeData::ConstrainedType(ConstrainedType::new(data, data_types)?)
+            }
+        };
+
+        return Ok(result);
+    }
+
+    /// Converts the data type data to a string for use in the source file
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the data type
+    ///
+    /// description: The description of the data type
+    ///
+    /// macros: A map of all macros to expand default values
+    ///
+    /// indent: The number of spaces to use for indentation
+    fn get_source(
+        &self,
+        name: &str,
+        description: &Option<String>,
+        macros: &IndexMap<String, data_model::SerializationModel>,
+        indent: usize,
+    ) -> Result<String, Error> {
+        return match self {
+            DataTypeData::Struct(data) => data.get_source(name, description, macros, indent),
+            DataTypeData::Array(data) => Ok(data.get_source(name, description, indent)),
+            DataTypeData::Map(data) => Ok(data.get_source(name, description, indent)),
+            DataTypeData::Variant(data) => Ok(data.get_source(name, description, indent)),
+            DataTypeData::Enum(data) => Ok(data.get_source(name, description, indent)),
+            DataTypeData::ConstrainedType(data) => Ok(data.get_source(name, description, indent)),
+        };
+    }
+}
+
+/// Errors for when converting generic data models into TypeScript data models
+/// including location
+#[derive(Debug, Clone)]
+pub struct Error {
+    /// The location where the error occured
+    pub location: Location,
+    /// The actual error that occured
+    pub error: ErrorCore,
+}
+
+impl Error {
+    /// Sets the current location to be the field of the given base
+    ///
+    /// # Parameters
+    ///
+    /// base: The base to set in the location
+    fn add_field(self, base: &str) -> Error {
+        return Error {
+            location: self.location.add_field(base),
+            error: self.error,
+        };
+    }
+
+    /// Sets the current location to be the element of a sequence
+    ///
+    /// # Parameters
+    ///
+    /// index: The index of the element
+    fn add_element(self, index: usize) -> Error {
+        return Error {
+            location: self.location.add_element(index),
+            error: self.error,
+        };
+    }
+
+    /// Sets the current location to be inside of the location of a data type
+    ///
+    /// # Parameters
+    ///
+    /// base: The location of the data type
+    fn add_location(self, base: &Location) -> Error {
+        return Error {
+            location: self.location.add_location(base),
+            error: self.error,
+        };
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Errors for the entire data model have no location
+        if self.location.is_empty() {
+            return write!(f, "{}", self.error);
+        }
+        return write!(f, "{}: {}", self.location, self.error);
+    }
+}
+
+impl LocatedError for Error {
+    fn get_location(&self) -> Location {
+        return self.location.clone();
+    }
+}
+
+impl From<data_model::Error> for Error {
+    fn from(value: data_model::Error) -> Self {
+        return Error {
+            location: value.location.clone(),
+            error: ErrorCore::MacroError(value),
+        };
+    }
+}
+
+/// Errors for when converting generic data models into TypeScript data models
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum ErrorCore {
+    /// Error expanding macros
+    #[error("An error occured when expanding macros: {:?}", .0)]
+    MacroError(data_model::Error),
+    /// The macro expansion in the header failed
+    #[error("The header \"{:?}\" must only expand to a string when using macros", .0)]
+    HeaderMacro(String),
+    /// The macro expansion in the footer failed
+    #[error("The footer \"{:?}\" must only expand to a string when using macros", .0)]
+    FooterMacro(String),
+    /// The data model is not valid
+    #[error("The data model is not valid:{}", data_model::display_errors(.0))]
+    Validation(Vec<data_model::Error>),
+}
+
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use std::{fs, path, process};
+
+    /// Runs a command and makes sure it succeeds
+    ///
+    /// # Parameters
+    ///
+    /// command: The command to run
+    fn run(command: &mut process::Command) {
+        let output = command
+            .output()
+            .unwrap_or_else(|error| panic!("failed to run {:?}: {error}", command.get_program()));
+
+        assert_eq!(
+            output.status.code(),
+            Some(0),
+            "{}{}",
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    /// Type checks and runs the TypeScript tests of a test directory in
+    /// tests/typescript, the tests are in name_test.ts and import the
+    /// generated code, termite.ts and the assertions in
+    /// tests/typescript/assert.ts
+    ///
+    /// # Parameters
+    ///
+    /// name: The path of the test relative to tests/typescript without the file
+    /// extension
+    pub(crate) fn run_typescript_test(name: &str) {
+        let test_path = path::Path::new("tests/typescript").join(format!("{name}_test.ts"));
+        let test_dir = test_path.parent().unwrap();
+        let test_name = test_path.file_stem().unwrap().to_str().unwrap();
+
+        // Gather all the files in one directory since the generated code
+        // imports termite.ts from its own directory
+        let build_dir = path::Path::new("target").join(test_dir);
+        let _ = fs::remove_dir_all(&build_dir);
+        fs::create_dir_all(&build_dir).unwrap();
+        fs::copy("src/typescript/termite.ts", build_dir.join("termite.ts")).unwrap();
+        fs::copy("tests/typescript/assert.ts", build_dir.join("assert.ts")).unwrap();
+        for entry in fs::read_dir(test_dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_some_and(|extension| extension == "ts") {
+                fs::copy(&path, build_dir.join(path.file_name().unwrap())).unwrap();
+            }
+        }
+
+        let source_path = build_dir.join(format!("{test_name}.ts"));
+        let output_dir = build_dir.join("js");
+        let options = ["--strict", "--target", "es2020", "--module", "commonjs"];
+
+        // Make sure the generated code type checks
+        run(process::Command::new("tsc")
+            .arg("--noEmit")
+            .args(options)
+            .arg(&source_path));
+
+        // Run the tests
+        run(process::Command::new("tsc")
+            .args(options)
+            .arg("--outDir")
+            .arg(&output_dir)
+            .arg(&source_path));
+        run(process::Command::new("node").arg(output_dir.join(format!("{test_name}.js"))));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_utils::*;
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn termite_basis() {
+        run_typescript_test("termite/termite");
+    }
+
+    #[test]
+    fn outline() {
+        // Check TypeScript code
+        run_typescript_test("outline/outline");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/typescript/outline/outline.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(2).unwrap();
+        let expected_source = include_str!("../../tests/typescript/outline/outline.ts");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn recursive() {
+        // Check TypeScript code
+        run_typescript_test("recursive/recursive");
+
+        // Make sure it generates the correct code
+        let model =
+            crate::DataModel::import_yaml(include_str!("../../tests/cpp/recursive/recursive.yaml"))
+                .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(2).unwrap();
+        let expected_source = include_str!("../../tests/typescript/recursive/recursive.ts");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn default_order() {
+        // Map defaults keep the order they are written in
+        let model = crate::DataModel::import_yaml(indoc::indoc! {"
+            data_types:
+            - name: Point
+              data: !Struct
+                fields:
+                - name: x
+                  data_type: number
+                  default: Required
+                - name: y
+                  data_type: number
+                  default: Required
+            - name: Shape
+              data: !Struct
+                fields:
+                - name: origin
+                  data_type: Point
+                  default: !Default
+                    y: 2.0
+                    x: 1.0
+        "})
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+        let source_file = data_model.get_source(2).unwrap();
+
+        assert!(
+            source_file.contains("Point.parse({ y: \"2.0\", x: \"1.0\" })"),
+            "{source_file}"
+        );
+    }
+}
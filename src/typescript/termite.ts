@@ -0,0 +1,429 @@
+/**
+ * The base termite TypeScript dependency required for all generated data
+ * models.
+ *
+ * All values are parsed from plain values (objects, arrays, strings, numbers
+ * and booleans) as obtained from JSON.parse or a YAML parser. Scalars follow the
+ * same rules as termite::Node::Value in c++, they are read as trimmed strings
+ * such that "5" and 5 are both valid integers.
+ */
+
+const NUMBER_PATTERN = /^[+-]?(\d+\.?\d*|\.\d+)([eE][+-]?\d+)?$/;
+const INTEGER_PATTERN = /^[+-]?\d+$/;
+
+/**
+ * Marks a type as having been validated as the named constrained type, brands
+ * of constrained types wrapping each other are combined
+ */
+export type Brand<T, Name extends string> = T & {
+  readonly __brand: { readonly [K in Name]: true };
+};
+
+/**
+ * Describes any error within a data model
+ */
+export class Error extends globalThis.Error {
+  /** The message describing what is wrong without the location */
+  reason: string;
+  /** The location in the data model where the error occured */
+  location: string;
+
+  /**
+   * @param message The message describing what is wrong
+   * @param location The location in the data model where the error occured
+   */
+  constructor(message: string, location: string = "") {
+    super(location === "" ? message : `${location}: ${message}`);
+    this.name = "TermiteError";
+    this.reason = message;
+    this.location = location;
+  }
+
+  /**
+   * Adds a field to the location such that the old location is a field of the
+   * new location
+   *
+   * @param name The name of the new base
+   * @returns A new error with the updated location
+   */
+  addField(name: string): Error {
+    const separator =
+      this.location !== "" && !this.location.startsWith("[") ? "." : "";
+    return new Error(this.reason, `${name}${separator}${this.location}`);
+  }
+
+  /**
+   * Adds a list element to the location such that the old location is a field
+   * of the new list element
+   *
+   * @param index The index of the list
+   * @returns A new error with the updated location
+   */
+  addList(index: number): Error {
+    const separator =
+      this.location !== "" && !this.location.startsWith("[") ? "." : "";
+    return new Error(this.reason, `[${index}]${separator}${this.location}`);
+  }
+}
+
+/**
+ * Adds a location to an error if it is a termite error
+ *
+ * @param error The error which was thrown
+ * @param update Updates the location of the termite error
+ * @returns The error to rethrow
+ */
+function relocate(error: unknown, update: (error: Error) => Error): unknown {
+  return error instanceof Error ? update(error) : error;
+}
+
+/**
+ * Converts a scalar into the trimmed string representation used by c++
+ *
+ * @param value The value to convert
+ * @returns The string representation of the value
+ */
+function toText(value: unknown): string {
+  if (typeof value === "boolean") {
+    return value ? "true" : "false";
+  }
+  if (typeof value === "number" || typeof value === "string") {
+    return String(value).trim();
+  }
+  throw new Error(`Expected a value but received ${describe(value)}`);
+}
+
+/**
+ * Gets a short description of the type of a value for error messages
+ *
+ * @param value The value to describe
+ * @returns The description of the value
+ */
+function describe(value: unknown): string {
+  if (value === null) {
+    return "null";
+  }
+  if (Array.isArray(value)) {
+    return "list";
+  }
+  return typeof value === "object" ? "map" : typeof value;
+}
+
+/**
+ * Parses a string
+ *
+ * @param value The value to parse
+ * @returns The parsed string
+ */
+export function parseString(value: unknown): string {
+  return toText(value);
+}
+
+/**
+ * Parses a floating point number
+ *
+ * @param value The value to parse
+ * @returns The parsed number
+ */
+export function parseNumber(value: unknown): number {
+  if (typeof value === "number") {
+    return value;
+  }
+  if (typeof value === "boolean") {
+    throw new Error("Unable to parse");
+  }
+  const text = toText(value);
+  if (!NUMBER_PATTERN.test(text)) {
+    throw new Error("Unable to parse");
+  }
+  return Number(text);
+}
+
+/**
+ * Parses an integer, only integers which can be represented exactly by a
+ * number are allowed
+ *
+ * @param value The value to parse
+ * @returns The parsed integer
+ */
+export function parseInteger(value: unknown): number {
+  if (typeof value === "boolean") {
+    throw new Error("Unable to parse");
+  }
+  const text = toText(value);
+  if (!INTEGER_PATTERN.test(text)) {
+    throw new Error("Unable to parse");
+  }
+  const result = Number(text);
+  if (!Number.isSafeInteger(result)) {
+    throw new Error("Unable to parse");
+  }
+  return result;
+}
+
+/**
+ * Parses a boolean
+ *
+ * @param value The value to parse
+ * @returns The parsed boolean
+ */
+export function parseBoolean(value: unknown): boolean {
+  const text = toText(value);
+  if (text === "true" || text === "True" || text === "1") {
+    return true;
+  }
+  if (text === "false" || text === "False" || text === "0") {
+    return false;
+  }
+  throw new Error("Unable to parse");
+}
+
+/**
+ * Makes sure the value is a map
+ *
+ * @param value The value to check
+ * @returns A shallow copy of the map
+ */
+export function parseMap(value: unknown): Record<string, unknown> {
+  if (typeof value !== "object" || value === null || Array.isArray(value)) {
+    throw new Error(`Expected a map but received ${describe(value)}`);
+  }
+  return { ...(value as Record<string, unknown>) };
+}
+
+/**
+ * Parses all elements of a list
+ *
+ * @param value The list to parse
+ * @param parser The parser to use for each element
+ * @returns The list of parsed elements
+ */
+export function parseList<T>(value: unknown, parser: (value: unknown) => T): T[] {
+  if (!Array.isArray(value)) {
+    throw new Error(`Expected a list but received ${describe(value)}`);
+  }
+  return value.map((element, index) => {
+    try {
+      return parser(element);
+    } catch (error) {
+      throw relocate(error, (error) => error.addList(index));
+    }
+  });
+}
+
+/**
+ * Parses all values of a map
+ *
+ * @param value The map to parse
+ * @param parser The parser to use for each value
+ * @param keyParser The parser all keys must be valid for, if not given any key
+ * is allowed
+ * @returns The map of parsed values
+ */
+export function parseValues<T>(
+  value: unknown,
+  parser: (value: unknown) => T,
+  keyParser?: (value: unknown) => unknown,
+): Record<string, T> {
+  const result: Record<string, T> = {};
+  for (const [key, element] of Object.entries(parseMap(value))) {
+    try {
+      if (keyParser !== undefined) {
+        keyParser(key);
+      }
+      result[key] = parser(element);
+    } catch (error) {
+      throw relocate(error, (error) => error.addField(key));
+    }
+  }
+  return result;
+}
+
+/**
+ * Checks if a field is set in a map
+ *
+ * @param fields The fields of the map
+ * @param name The name of the field
+ * @returns True if the field is set
+ */
+export function hasField(fields: Record<string, unknown>, name: string): boolean {
+  return Object.prototype.hasOwnProperty.call(fields, name);
+}
+
+/**
+ * Parses a field of a map
+ *
+ * @param fields The fields of the map
+ * @param name The name of the field to parse
+ * @param parser The parser to use for the field
+ * @returns The parsed field
+ */
+export function parseField<T>(
+  fields: Record<string, unknown>,
+  name: string,
+  parser: (value: unknown) => T,
+): T {
+  if (!hasField(fields, name)) {
+    throw new Error(`Missing ${name}`);
+  }
+  try {
+    return parser(fields[name]);
+  } catch (error) {
+    throw relocate(error, (error) => error.addField(name));
+  }
+}
+
+/**
+ * Makes sure a map only holds the fields of a struct, used by structs which
+ * reject unknown fields
+ *
+ * @param fields The fields of the map
+ * @param names The names of all the fields of the struct, used to suggest the
+ * closest field for typos
+ */
+export function rejectFields(fields: Record<string, unknown>, names: string[]): void {
+  const unknown = Object.keys(fields)
+    .filter((key) => !names.includes(key))
+    .sort()
+    .map((key) => {
+      let closest: string | undefined = undefined;
+      let closestDistance = 0;
+      for (const name of names) {
+        const distance = editDistance(key, name);
+        if (closest === undefined || distance < closestDistance) {
+          closest = name;
+          closestDistance = distance;
+        }
+      }
+      if (closest !== undefined && closestDistance <= Math.floor(closest.length / 3)) {
+        return `"${key}" (did you mean "${closest}"?)`;
+      }
+      return `"${key}"`;
+    });
+  if (unknown.length > 0) {
+    const prefix = unknown.length === 1 ? "Unknown field " : "Unknown fields ";
+    throw new Error(prefix + unknown.join(", "));
+  }
+}
+
+/**
+ * Copies only the fields of a struct from a map, used by structs which ignore
+ * unknown fields
+ *
+ * @param fields The fields of the map
+ * @param names The names of all the fields of the struct
+ * @returns The fields of the map which are part of the struct
+ */
+export function pickFields(
+  fields: Record<string, unknown>,
+  names: string[],
+): Record<string, unknown> {
+  const result: Record<string, unknown> = {};
+  for (const name of names) {
+    if (hasField(fields, name)) {
+      result[name] = fields[name];
+    }
+  }
+  return result;
+}
+
+/**
+ * Counts the number of characters which must be inserted, removed or replaced
+ * to turn one string into another, swapping two neighbouring characters counts
+ * as a single edit
+ *
+ * @param from The string to start from
+ * @param to The string to end at
+ * @returns The number of edits
+ */
+function editDistance(from: string, to: string): number {
+  let before: number[] = [];
+  let previous = Array.from({ length: to.length + 1 }, (_, j) => j);
+  for (let i = 1; i <= from.length; i++) {
+    const current = [i];
+    for (let j = 1; j <= to.length; j++) {
+      const replace = previous[j - 1] + (from[i - 1] === to[j - 1] ? 0 : 1);
+      current[j] = Math.min(previous[j] + 1, current[j - 1] + 1, replace);
+      if (i > 1 && j > 1 && from[i - 1] === to[j - 2] && from[i - 2] === to[j - 1]) {
+        current[j] = Math.min(current[j], before[j - 2] + 1);
+      }
+    }
+    before = previous;
+    previous = current;
+  }
+  return previous[to.length];
+}
+
+/**
+ * Parses the first of the types which succeeds
+ *
+ * @param value The value to parse
+ * @param parsers The name and parser of all the types in order
+ * @returns The value parsed by the first successful parser
+ */
+export function parseVariant(
+  value: unknown,
+  parsers: [string, (value: unknown) => unknown][],
+): unknown {
+  const errors: string[] = [];
+  for (const [name, parser] of parsers) {
+    try {
+      return parser(value);
+    } catch (error) {
+      if (!(error instanceof Error)) {
+        throw error;
+      }
+      errors.push(`${name} { ${error.message} }`);
+    }
+  }
+  throw new Error(`Unable to parse any variant: [ ${errors.join(", ")} ]`);
+}
+
+/**
+ * Parses an enum where empty types are given as strings and other types as a
+ * map with a single key being the name of the type
+ *
+ * @param value The value to parse
+ * @param emptyTypes The names of the enum types without a value
+ * @param types The parsers of the enum types with a value
+ * @returns The name of the empty type or a map from the name of the type to
+ * its parsed value
+ */
+export function parseEnum(
+  value: unknown,
+  emptyTypes: string[],
+  types: Record<string, (value: unknown) => unknown>,
+): unknown {
+  if (Array.isArray(value)) {
+    throw new Error("Expected a value or a map but received list");
+  }
+  if (typeof value === "object" && value !== null) {
+    const entries = Object.entries(value);
+    if (entries.length !== 1) {
+      throw new Error(
+        `There must be exactly one enum type specified but received ${entries.length}`,
+      );
+    }
+    const [name, data] = entries[0];
+    if (emptyTypes.includes(name)) {
+      throw new Error(`Enum type ${name} must not include values`);
+    }
+    if (!hasField(types, name)) {
+      throw new Error(`Unknown enum type "${name}"`);
+    }
+    try {
+      return { [name]: types[name](data) };
+    } catch (error) {
+      throw relocate(error, (error) => error.addField(name));
+    }
+  }
+
+  const name = toText(value);
+  if (hasField(types, name)) {
+    throw new Error(`Enum type ${name} must contain a value`);
+  }
+  if (!emptyTypes.includes(name)) {
+    throw new Error(`Unknown enum type "${name}"`);
+  }
+  return name;
+}
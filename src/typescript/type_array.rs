@@ -0,0 +1,78 @@
+use super::*;
+use indoc::formatdoc;
+
+/// The type specific information for an array
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Array {
+    /// The data type for all elements of the array
+    pub(super) data_type: String,
+}
+
+impl Array {
+    /// Constructs a new TypeScript array from a generic array
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic array to convert
+    pub(super) fn new(data: crate::Array) -> Result<Self, Error> {
+        return Ok(Self {
+            data_type: data.data_type,
+        });
+    }
+
+    /// Converts the array to a string for use in the source file
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the array
+    ///
+    /// description: The description of the array
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_source(&self, name: &str, description: &Option<String>, indent: usize) -> String {
+        return formatdoc!(
+            "
+            {description}export type {name} = {typename}[];
+
+            export const {name} = {{
+            {0:indent$}/**
+            {0:indent$} * Parses {name} from a plain value
+            {0:indent$} */
+            {0:indent$}parse(value: unknown): {name} {{
+            {0:indent$}{0:indent$}return termite.parseList(value, {parser});
+            {0:indent$}}},
+            }};",
+            "",
+            description = get_doc_comment(description, 0),
+            typename = get_typename(&self.data_type),
+            parser = get_parser(&self.data_type),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::*;
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn basic() {
+        // Check TypeScript code
+        run_typescript_test("type_array/basic/basic");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/typescript/type_array/basic/basic.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(2).unwrap();
+        let expected_source = include_str!("../../tests/typescript/type_array/basic/basic.ts");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
@@ -0,0 +1,231 @@
+use super::*;
+use crate::constraint::{self, parenthesize, Expression, Kind, Operator};
+use indoc::formatdoc;
+
+/// The type specific information for a constrained type
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct ConstrainedType {
+    /// The type that is constrained
+    pub(super) data_type: String,
+    /// All extra constraints for the type, the constraint as it was written in
+    /// the data model and the TypeScript expression which must be true
+    pub(super) constraints: Vec<(String, String)>,
+}
+
+impl ConstrainedType {
+    /// Constructs a new TypeScript constrained type from a generic
+    /// constrained type
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic constrained type to convert
+    ///
+    /// data_types: All the generic data types of the data model, used to
+    /// find the types of the values in the constraints
+    pub(super) fn new(
+        data: crate::ConstrainedType,
+        data_types: &[crate::DataType],
+    ) -> Result<Self, Error> {
+        let context = constraint::Context::new(&data.data_type, data_types);
+        let constraints = data
+            .constraints
+            .iter()
+            .map(|constraint| {
+                (
+                    constraint.to_string(),
+                    get_expression(constraint.expression(), &context),
+                )
+            })
+            .collect();
+
+        return Ok(Self {
+            data_type: data.data_type,
+            constraints,
+        });
+    }
+
+    /// Converts the constrained type to a string for use in the source file,
+    /// the type is branded such that only values which have been checked can
+    /// be used as the constrained type
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the constrained type
+    ///
+    /// description: The description of the constrained type
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_source(&self, name: &str, description: &Option<String>, indent: usize) -> String {
+        let typename = get_typename(&self.data_type);
+
+        // Document the constraints on the type
+        let constraints = self
+            .constraints
+            .iter()
+            .map(|(source, _)| format!("\n- {source}"))
+            .collect::<String>();
+        let description = match (description, constraints.is_empty()) {
+            (description, true) => description.clone(),
+            (Some(description), false) => Some(format!(
+                "{description}\n\nThe value must fulfill the constraints:{constraints}"
+            )),
+            (None, false) => Some(format!(
+                "The value must fulfill the constraints:{constraints}"
+            )),
+        };
+
+        // Check all the constraints in order
+        let checks = self
+            .constraints
+            .iter()
+            .map(|(source, code)| {
+                formatdoc!(
+                    "
+                    {0:indent$}{0:indent$}if (!({code})) {{
+                    {0:indent$}{0:indent$}{0:indent$}return {literal};
+                    {0:indent$}{0:indent$}}}
+                    ",
+                    "",
+                    literal = get_string_literal(source),
+                )
+            })
+            .collect::<String>();
+        let argument = if checks.is_empty() { "_x" } else { "x" };
+
+        return formatdoc!(
+            "
+            {description}export type {name} = termite.Brand<{typename}, \"{name}\">;
+
+            export const {name} = {{
+            {0:indent$}/**
+            {0:indent$} * Gets the first constraint of {name} the value does not fulfill,
+            {0:indent$} * undefined if it fulfills all of them
+            {0:indent$} */
+            {0:indent$}check({argument}: {typename}): string | undefined {{
+            {checks}{0:indent$}{0:indent$}return undefined;
+            {0:indent$}}},
+
+            {0:indent$}/**
+            {0:indent$} * Checks if the value fulfills all the constraints of {name}
+            {0:indent$} */
+            {0:indent$}is(x: {typename}): x is {name} {{
+            {0:indent$}{0:indent$}return {name}.check(x) === undefined;
+            {0:indent$}}},
+
+            {0:indent$}/**
+            {0:indent$} * Constructs {name} if the value fulfills all the constraints
+            {0:indent$} */
+            {0:indent$}from(x: {typename}): {name} {{
+            {0:indent$}{0:indent$}const constraint = {name}.check(x);
+            {0:indent$}{0:indent$}if (constraint !== undefined) {{
+            {0:indent$}{0:indent$}{0:indent$}throw new termite.Error(`Did not pass constraint: ${{constraint}}`);
+            {0:indent$}{0:indent$}}}
+            {0:indent$}{0:indent$}return x as {name};
+            {0:indent$}}},
+
+            {0:indent$}/**
+            {0:indent$} * Parses {name} from a plain value
+            {0:indent$} */
+            {0:indent$}parse(value: unknown): {name} {{
+            {0:indent$}{0:indent$}return {name}.from({parser}(value));
+            {0:indent$}}},
+            }};",
+            "",
+            description = get_doc_comment(&description, 0),
+            parser = get_parser(&self.data_type),
+        );
+    }
+}
+
+/// Converts an expression of the constraint language into TypeScript, the
+/// value is denoted x and constrained types are branded primitives so they
+/// can be used directly
+///
+/// # Parameters
+///
+/// expression: The expression to convert
+///
+/// context: The types of the values in the expression
+fn get_expression(expression: &Expression, context: &constraint::Context) -> String {
+    // Converts an operand and puts it in parentheses if needed
+    let operand = |operand: &Expression, precedence: u8, strict: bool| -> String {
+        return parenthesize(
+            get_expression(operand, context),
+            operand.precedence(),
+            precedence,
+            strict,
+        );
+    };
+
+    return match expression {
+        Expression::Value => "x".to_string(),
+        Expression::Field(value, name) => {
+            format!("{}{}", operand(value, 7, false), get_property_access(name))
+        }
+        Expression::Number(value) => value.clone(),
+        Expression::String(value) => get_string_literal(value),
+        Expression::Boolean(value) => value.to_string(),
+        Expression::Length(value) => match context.get_kind(value) {
+            // Count code points and not UTF-16 code units
+            Kind::String => format!("[...{}].length", operand(value, 7, false)),
+            Kind::Map => format!("Object.keys({}).length", get_expression(value, context)),
+            _ => format!("{}.length", operand(value, 7, false)),
+        },
+        Expression::Matches(value, pattern) => format!(
+            "new RegExp({}).test({})",
+            get_string_literal(pattern),
+            get_expression(value, context)
+        ),
+        Expression::In(value, set) => format!(
+            "[{}].includes({})",
+            set.iter()
+                .map(|element| get_expression(element, context))
+                .collect::<Vec<String>>()
+                .join(", "),
+            get_expression(value, context)
+        ),
+        Expression::Not(value) => format!("!{}", operand(value, 6, false)),
+        Expression::Negate(value) => format!("-{}", operand(value, 6, false)),
+        Expression::Binary(lhs, operator, rhs) => {
+            let symbol = match operator {
+                Operator::Equal => "===",
+                Operator::NotEqual => "!==",
+                _ => operator.symbol(),
+            };
+            let precedence = operator.precedence();
+            format!(
+                "{} {symbol} {}",
+                operand(lhs, precedence, operator.is_comparison()),
+                operand(rhs, precedence, true)
+            )
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::*;
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn basic() {
+        // Check TypeScript code
+        run_typescript_test("type_constrained/basic/basic");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/typescript/type_constrained/basic/basic.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(2).unwrap();
+        let expected_source =
+            include_str!("../../tests/typescript/type_constrained/basic/basic.ts");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
@@ -0,0 +1,166 @@
+use super::*;
+use indoc::formatdoc;
+
+/// The type specific information for an enum
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Enum {
+    /// A list of all the enum types
+    pub(super) types: Vec<EnumType>,
+}
+
+impl Enum {
+    /// Constructs a new TypeScript enum from a generic enum
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic enum to convert
+    pub(super) fn new(data: crate::Enum) -> Result<Self, Error> {
+        let types = data
+            .types
+            .into_iter()
+            .map(|enum_type| EnumType::new(enum_type))
+            .collect::<Result<Vec<EnumType>, Error>>()?;
+
+        return Ok(Self { types });
+    }
+
+    /// Converts the enum to a string for use in the source file, the enum is
+    /// a union of the names of the empty types and objects with a single key
+    /// being the name of the type for all other types
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the enum
+    ///
+    /// description: The description of the enum
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_source(&self, name: &str, description: &Option<String>, indent: usize) -> String {
+        let types = self
+            .types
+            .iter()
+            .map(|enum_type| enum_type.get_definition(indent))
+            .collect::<String>();
+
+        // Get the names of the empty types and the parsers of all other types
+        let empty_types = self
+            .types
+            .iter()
+            .filter(|enum_type| enum_type.data_type.is_none())
+            .map(|enum_type| get_string_literal(&enum_type.name))
+            .collect::<Vec<String>>()
+            .join(", ");
+        let parsers = self
+            .types
+            .iter()
+            .filter_map(|enum_type| {
+                return enum_type.data_type.as_ref().map(|data_type| {
+                    format!(
+                        "{0:indent$}{0:indent$}{0:indent$}{name}: {parser},\n",
+                        "",
+                        name = get_property(&enum_type.name),
+                        parser = get_parser(data_type),
+                    )
+                });
+            })
+            .collect::<String>();
+        let parsers = if parsers.is_empty() {
+            "{}".to_string()
+        } else {
+            format!("{{\n{parsers}{0:indent$}{0:indent$}}}", "")
+        };
+
+        return formatdoc!(
+            "
+            {description}export type {name} =
+            {types};
+
+            export const {name} = {{
+            {0:indent$}/**
+            {0:indent$} * Parses {name} from a plain value
+            {0:indent$} */
+            {0:indent$}parse(value: unknown): {name} {{
+            {0:indent$}{0:indent$}return termite.parseEnum(value, [{empty_types}], {parsers}) as {name};
+            {0:indent$}}},
+            }};",
+            "",
+            description = get_doc_comment(description, 0),
+            types = types.trim_end(),
+        );
+    }
+}
+
+/// The type specific information for an enum type
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct EnumType {
+    /// The name of this enum type
+    pub(super) name: String,
+    /// The description describing this enum type
+    pub(super) description: Option<String>,
+    /// The type this enum type is wrapping, may be omitted for an empty type
+    pub(super) data_type: Option<String>,
+}
+
+impl EnumType {
+    /// Constructs a new TypeScript enum type from a generic enum type
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic enum type to convert
+    fn new(data: crate::EnumType) -> Result<Self, Error> {
+        return Ok(Self {
+            name: data.name,
+            description: data.description,
+            data_type: data.data_type,
+        });
+    }
+
+    /// Converts the enum type to a member of the union type of the enum
+    ///
+    /// # Parameters
+    ///
+    /// indent: The number of spaces to use for indentation
+    fn get_definition(&self, indent: usize) -> String {
+        let value = match &self.data_type {
+            Some(data_type) => format!(
+                "{{ {name}: {typename} }}",
+                name = get_property(&self.name),
+                typename = get_typename(data_type),
+            ),
+            None => get_string_literal(&self.name),
+        };
+
+        return format!(
+            "{description}{0:indent$}| {value}\n",
+            "",
+            description = get_doc_comment(&self.description, indent),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::*;
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn basic() {
+        // Check TypeScript code
+        run_typescript_test("type_enum/basic/basic");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/typescript/type_enum/basic/basic.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(2).unwrap();
+        let expected_source = include_str!("../../tests/typescript/type_enum/basic/basic.ts");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
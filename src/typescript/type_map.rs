@@ -0,0 +1,115 @@
+use super::*;
+use indoc::formatdoc;
+
+/// The type specific information for a map
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Map {
+    /// The data type for all values of the map
+    pub(super) data_type: String,
+    /// The type all keys must be valid as, if not given any key is allowed
+    pub(super) key_type: Option<String>,
+}
+
+impl Map {
+    /// Constructs a new TypeScript map from a generic map
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic map to convert
+    pub(super) fn new(data: crate::Map) -> Result<Self, Error> {
+        // A string key is the same as not having a key type
+        let key_type = match data.key_type {
+            Some(key_type) if key_type != "string" => Some(key_type),
+            _ => None,
+        };
+
+        return Ok(Self {
+            data_type: data.data_type,
+            key_type,
+        });
+    }
+
+    /// Converts the map to a string for use in the source file, the keys are
+    /// stored as strings and are only checked against the key type when
+    /// parsing
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the map
+    ///
+    /// description: The description of the map
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_source(&self, name: &str, description: &Option<String>, indent: usize) -> String {
+        let key_parser = match &self.key_type {
+            Some(key_type) => format!(", {parser}", parser = get_parser(key_type)),
+            None => "".to_string(),
+        };
+
+        return formatdoc!(
+            "
+            {description}export type {name} = {{ [key: string]: {typename} }};
+
+            export const {name} = {{
+            {0:indent$}/**
+            {0:indent$} * Parses {name} from a plain value
+            {0:indent$} */
+            {0:indent$}parse(value: unknown): {name} {{
+            {0:indent$}{0:indent$}return termite.parseValues(value, {parser}{key_parser});
+            {0:indent$}}},
+            }};",
+            "",
+            description = get_doc_comment(description, 0),
+            typename = get_typename(&self.data_type),
+            parser = get_parser(&self.data_type),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::*;
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn basic() {
+        // Check TypeScript code
+        run_typescript_test("type_map/basic/basic");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/typescript/type_map/basic/basic.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(2).unwrap();
+        let expected_source = include_str!("../../tests/typescript/type_map/basic/basic.ts");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn key_type() {
+        // Check TypeScript code
+        run_typescript_test("type_map/key_type/key_type");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/typescript/type_map/key_type/key_type.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(2).unwrap();
+        let expected_source =
+            include_str!("../../tests/typescript/type_map/key_type/key_type.ts");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
@@ -0,0 +1,428 @@
+use super::*;
+use indoc::formatdoc;
+
+/// The type specific information for a struct
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Struct {
+    /// A list of all the fields of the struct
+    pub(super) fields: Vec<StructField>,
+    /// The name of the struct this struct extends
+    pub(super) inherit: Option<String>,
+    /// The names of all the fields of the inherited structs
+    pub(super) inherited_fields: Vec<String>,
+    /// What to do with unknown fields when parsing the inherited struct
+    pub(super) base_unknown_fields: crate::UnknownFields,
+    /// What to do with unknown fields when parsing
+    pub(super) unknown_fields: crate::UnknownFields,
+}
+
+impl Struct {
+    /// Constructs a new TypeScript struct from a generic struct
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic struct to convert
+    ///
+    /// data_types: All the generic data types of the data model, used to
+    /// find the fields of inherited structs
+    pub(super) fn new(data: crate::Struct, data_types: &[crate::DataType]) -> Result<Self, Error> {
+        // Get all the inherited structs, the data model is validated so the
+        // inheritance chain is known to be correct
+        let mut bases = vec![];
+        let mut inherit = &data.inherit;
+        while let Some(name) = inherit {
+            match data_types.iter().find(|data_type| &data_type.name == name) {
+                Some(crate::DataType {
+                    data: crate::DataTypeData::Struct(base),
+                    ..
+                }) => {
+                    bases.push(base);
+                    inherit = &base.inherit;
+                }
+                _ => break,
+            }
+        }
+        let inherited_fields = bases
+            .iter()
+            .rev()
+            .flat_map(|base| base.fields.iter())
+            .map(|field| field.name.clone())
+            .collect();
+        let base_unknown_fields = match bases.first() {
+            Some(base) => base.unknown_fields.unwrap_or_default(),
+            None => crate::UnknownFields::Collect,
+        };
+
+        let fields = data
+            .fields
+            .into_iter()
+            .map(|field| StructField::new(field))
+            .collect::<Result<Vec<StructField>, Error>>()?;
+
+        return Ok(Self {
+            fields,
+            inherit: data.inherit,
+            inherited_fields,
+            base_unknown_fields,
+            unknown_fields: data.unknown_fields.unwrap_or_default(),
+        });
+    }
+
+    /// Converts the struct to a string for use in the source file, the parse
+    /// function keeps, rejects or drops all fields which are not part of the
+    /// struct and fills in the default values of missing fields
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the struct
+    ///
+    /// description: The description of the struct
+    ///
+    /// macros: A map of all macros to expand default values
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_source(
+        &self,
+        name: &str,
+        description: &Option<String>,
+        macros: &IndexMap<String, data_model::SerializationModel>,
+        indent: usize,
+    ) -> Result<String, Error> {
+        // Get the fields
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| field.get_definition(indent))
+            .collect::<String>();
+        let fields = if fields.is_empty() {
+            "{}".to_string()
+        } else {
+            format!("{{\n{fields}}}")
+        };
+
+        // Get the parsers for all fields which are always set and the
+        // optional fields
+        let parsers = self
+            .fields
+            .iter()
+            .filter_map(|field| field.get_parser(name, indent))
+            .collect::<String>();
+        let optional_parsers = self
+            .fields
+            .iter()
+            .filter_map(|field| field.get_optional_parser(indent))
+            .collect::<String>();
+
+        // Get the functions for the default values
+        let default_functions = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| match field.get_default_function(macros, indent) {
+                Ok(result) => Ok(result),
+                Err(error) => Err(error.add_field("default").add_element(i).add_field("fields")),
+            })
+            .collect::<Result<Vec<Option<String>>, Error>>()?
+            .into_iter()
+            .flatten()
+            .map(|function| format!("\n\n{function}"))
+            .collect::<String>();
+
+        // Inherited structs parse their own fields, a base which does not
+        // keep unknown fields only gets its own fields
+        let (extends, base) = match &self.inherit {
+            Some(inherit) if self.base_unknown_fields == crate::UnknownFields::Collect => (
+                format!(" extends {inherit}"),
+                format!("{inherit}.parse(fields)"),
+            ),
+            Some(inherit) => (
+                format!(" extends {inherit}"),
+                format!(
+                    "fields,\n{0:indent$}{0:indent$}{0:indent$}...{inherit}.parse(termite.pickFields(fields, {names}))",
+                    "",
+                    names = get_names(&self.inherited_fields),
+                ),
+            ),
+            None => ("".to_string(), "fields".to_string()),
+        };
+
+        // Unknown fields are kept by spreading the fields into the result
+        let names = get_names(
+            &self
+                .inherited_fields
+                .iter()
+                .cloned()
+                .chain(self.fields.iter().map(|field| field.name.clone()))
+                .collect::<Vec<String>>(),
+        );
+        let (unknown, fields_parser) = match self.unknown_fields {
+            crate::UnknownFields::Collect => {
+                ("kept", "const fields = termite.parseMap(value);".to_string())
+            }
+            crate::UnknownFields::Reject => (
+                "rejected",
+                format!(
+                    "const fields = termite.parseMap(value);\n{0:indent$}{0:indent$}termite.rejectFields(fields, {names});",
+                    ""
+                ),
+            ),
+            crate::UnknownFields::Ignore => (
+                "ignored",
+                format!("const fields = termite.pickFields(termite.parseMap(value), {names});"),
+            ),
+        };
+
+        return Ok(formatdoc!(
+            "
+            {description}export interface {name}{extends} {fields}
+
+            export const {name} = {{
+            {0:indent$}/**
+            {0:indent$} * Parses {name} from a plain value, unknown fields are {unknown}
+            {0:indent$} */
+            {0:indent$}parse(value: unknown): {name} {{
+            {0:indent$}{0:indent$}{fields_parser}
+            {0:indent$}{0:indent$}const result: {name} = {{
+            {0:indent$}{0:indent$}{0:indent$}...{base},
+            {parsers}{0:indent$}{0:indent$}}};
+            {optional_parsers}{0:indent$}{0:indent$}return result;
+            {0:indent$}}},{default_functions}
+            }};",
+            "",
+            description = get_doc_comment(description, 0),
+        ));
+    }
+}
+
+/// Converts a list of field names into a TypeScript array literal
+///
+/// # Parameters
+///
+/// names: The names of the fields
+fn get_names(names: &[String]) -> String {
+    let names = names
+        .iter()
+        .map(|name| get_string_literal(name))
+        .collect::<Vec<String>>()
+        .join(", ");
+    return format!("[{names}]");
+}
+
+/// The data for a single field in a struct
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct StructField {
+    /// The name of the field
+    pub(super) name: String,
+    /// The description of the field
+    pub(super) description: Option<String>,
+    /// What type the field is
+    pub(super) data_type: String,
+    /// A default value if it it not required
+    pub(super) default: crate::DefaultType,
+}
+
+impl StructField {
+    /// Constructs a new TypeScript struct field from a generic struct field
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic struct field to convert
+    fn new(data: crate::StructField) -> Result<Self, Error> {
+        return Ok(Self {
+            name: data.name,
+            description: data.description,
+            data_type: data.data_type,
+            default: data.default,
+        });
+    }
+
+    /// Gets the name of the function returning the default value
+    fn get_default_name(&self) -> String {
+        return format!("default_{name}", name = self.name);
+    }
+
+    /// Converts the field to a string for use in the interface definition,
+    /// only optional fields may be missing after parsing
+    ///
+    /// # Parameters
+    ///
+    /// indent: The number of spaces to use for indentation
+    fn get_definition(&self, indent: usize) -> String {
+        let optional = match self.default {
+            crate::DefaultType::Optional => "?",
+            _ => "",
+        };
+
+        return format!(
+            "{description}{0:indent$}{name}{optional}: {typename};\n",
+            "",
+            description = get_doc_comment(&self.description, indent),
+            name = get_property(&self.name),
+            typename = get_typename(&self.data_type),
+        );
+    }
+
+    /// Gets the code parsing the field from the map of fields
+    fn get_parse_call(&self) -> String {
+        return format!(
+            "termite.parseField(fields, {name}, {parser})",
+            name = get_string_literal(&self.name),
+            parser = get_parser(&self.data_type),
+        );
+    }
+
+    /// Gets the entry parsing the field when creating the result, optional
+    /// fields are not set when creating the result
+    ///
+    /// # Parameters
+    ///
+    /// struct_name: The name of the struct the field is part of
+    ///
+    /// indent: The number of spaces to use for indentation
+    fn get_parser(&self, struct_name: &str, indent: usize) -> Option<String> {
+        let parser = match self.default {
+            crate::DefaultType::Required => self.get_parse_call(),
+            crate::DefaultType::Optional => return None,
+            crate::DefaultType::Default(_) => format!(
+                "termite.hasField(fields, {name})\n{0:indent$}{0:indent$}{0:indent$}{0:indent$}? {parser}\n{0:indent$}{0:indent$}{0:indent$}{0:indent$}: {struct_name}.{default_name}()",
+                "",
+                name = get_string_literal(&self.name),
+                parser = self.get_parse_call(),
+                default_name = self.get_default_name(),
+            ),
+        };
+
+        return Some(format!(
+            "{0:indent$}{0:indent$}{0:indent$}{name}: {parser},\n",
+            "",
+            name = get_property(&self.name),
+        ));
+    }
+
+    /// Gets the code parsing the field if it is optional
+    ///
+    /// # Parameters
+    ///
+    /// indent: The number of spaces to use for indentation
+    fn get_optional_parser(&self, indent: usize) -> Option<String> {
+        return match self.default {
+            crate::DefaultType::Optional => Some(formatdoc!(
+                "
+                {0:indent$}{0:indent$}if (termite.hasField(fields, {name})) {{
+                {0:indent$}{0:indent$}{0:indent$}result{property} = {parser};
+                {0:indent$}{0:indent$}}}
+                ",
+                "",
+                name = get_string_literal(&self.name),
+                property = get_property_access(&self.name),
+                parser = self.get_parse_call(),
+            )),
+            _ => None,
+        };
+    }
+
+    /// Gets the function returning the default value of the field if it has
+    /// one, the default value is parsed from its plain value
+    ///
+    /// # Parameters
+    ///
+    /// macros: A map of all macros to expand default values
+    ///
+    /// indent: The number of spaces to use for indentation
+    fn get_default_function(
+        &self,
+        macros: &IndexMap<String, data_model::SerializationModel>,
+        indent: usize,
+    ) -> Result<Option<String>, Error> {
+        return match &self.default {
+            crate::DefaultType::Default(value) => {
+                let value = data_model::expand_macros(value, macros, &mut HashSet::new())?;
+                Ok(Some(formatdoc!(
+                    "
+                    {0:indent$}/**
+                    {0:indent$} * The default value of the {name} field
+                    {0:indent$} */
+                    {0:indent$}{default_name}(): {typename} {{
+                    {0:indent$}{0:indent$}return {parser}({value});
+                    {0:indent$}}},",
+                    "",
+                    name = self.name,
+                    default_name = self.get_default_name(),
+                    typename = get_typename(&self.data_type),
+                    parser = get_parser(&self.data_type),
+                    value = get_literal(&value),
+                )))
+            }
+            _ => Ok(None),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::*;
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn basic() {
+        // Check TypeScript code
+        run_typescript_test("type_struct/basic/basic");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/typescript/type_struct/basic/basic.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(2).unwrap();
+        let expected_source = include_str!("../../tests/typescript/type_struct/basic/basic.ts");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn inheritance() {
+        // Check TypeScript code
+        run_typescript_test("type_struct/inheritance/inheritance");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/typescript/type_struct/inheritance/inheritance.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(2).unwrap();
+        let expected_source =
+            include_str!("../../tests/typescript/type_struct/inheritance/inheritance.ts");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+
+    #[test]
+    fn unknown_fields() {
+        // Check TypeScript code
+        run_typescript_test("type_struct/unknown_fields/unknown_fields");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/typescript/type_struct/unknown_fields/unknown_fields.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(2).unwrap();
+        let expected_source =
+            include_str!("../../tests/typescript/type_struct/unknown_fields/unknown_fields.ts");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
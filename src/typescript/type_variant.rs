@@ -0,0 +1,101 @@
+use super::*;
+use indoc::formatdoc;
+
+/// The type specific information for a variant
+#[derive(Clone, Debug, PartialEq)]
+pub(super) struct Variant {
+    /// The list of data types the variant can be
+    pub(super) data_types: Vec<String>,
+}
+
+impl Variant {
+    /// Constructs a new TypeScript variant from a generic variant
+    ///
+    /// # Parameters
+    ///
+    /// data: The generic variant to convert
+    pub(super) fn new(data: crate::Variant) -> Result<Self, Error> {
+        return Ok(Self {
+            data_types: data.data_types,
+        });
+    }
+
+    /// Converts the variant to a string for use in the source file, when
+    /// parsing the first type which succeeds is used
+    ///
+    /// # Parameters
+    ///
+    /// name: The name of the variant
+    ///
+    /// description: The description of the variant
+    ///
+    /// indent: The number of spaces to use for indentation
+    pub(super) fn get_source(&self, name: &str, description: &Option<String>, indent: usize) -> String {
+        // Numbers and integers are the same type in TypeScript
+        let mut typenames = vec![];
+        for data_type in self.data_types.iter() {
+            let typename = get_typename(data_type);
+            if !typenames.contains(&typename) {
+                typenames.push(typename);
+            }
+        }
+
+        let parsers = self
+            .data_types
+            .iter()
+            .map(|data_type| {
+                format!(
+                    "{0:indent$}{0:indent$}{0:indent$}[{name}, {parser}],\n",
+                    "",
+                    name = get_string_literal(data_type),
+                    parser = get_parser(data_type),
+                )
+            })
+            .collect::<String>();
+
+        return formatdoc!(
+            "
+            {description}export type {name} = {typename};
+
+            export const {name} = {{
+            {0:indent$}/**
+            {0:indent$} * Parses {name} from a plain value
+            {0:indent$} */
+            {0:indent$}parse(value: unknown): {name} {{
+            {0:indent$}{0:indent$}return termite.parseVariant(value, [
+            {parsers}{0:indent$}{0:indent$}]) as {name};
+            {0:indent$}}},
+            }};",
+            "",
+            description = get_doc_comment(description, 0),
+            typename = typenames.join(" | "),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::test_utils::*;
+    use super::*;
+    use crate::cpp::test_utils::str_diff;
+
+    #[test]
+    fn basic() {
+        // Check TypeScript code
+        run_typescript_test("type_variant/basic/basic");
+
+        // Make sure it generates the correct code
+        let model = crate::DataModel::import_yaml(include_str!(
+            "../../tests/typescript/type_variant/basic/basic.yaml"
+        ))
+        .unwrap();
+        let data_model = DataModel::new(model).unwrap();
+
+        // Create the source file
+        let source_file = data_model.get_source(2).unwrap();
+        let expected_source = include_str!("../../tests/typescript/type_variant/basic/basic.ts");
+
+        // Check that they are the same
+        assert_eq!(str_diff(&source_file, &expected_source), None);
+    }
+}
@@ -0,0 +1,192 @@
+//! Tests for the termite command line generator
+
+use std::{fs, path::PathBuf, process::Command};
+
+/// Creates an empty directory for the output of a test
+///
+/// # Parameters
+///
+/// name: The name of the test
+fn output_dir(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("termite_cli_{name}"));
+    let _ = fs::remove_dir_all(&path);
+    fs::create_dir_all(&path).unwrap();
+    return path;
+}
+
+/// Runs the termite binary and returns the exit code and stderr
+///
+/// # Parameters
+///
+/// args: The arguments to pass to the binary
+fn run(args: &[&str]) -> (Option<i32>, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_termite"))
+        .args(args)
+        .output()
+        .expect("failed to run termite");
+
+    return (
+        output.status.code(),
+        String::from_utf8_lossy(&output.stderr).to_string(),
+    );
+}
+
+#[test]
+fn generate_cpp() {
+    let output = output_dir("generate_cpp");
+    let (code, stderr) = run(&[
+        "generate",
+        "cpp",
+        "tests/cpp/full_example/full_example_datamodel.yaml",
+        "--output",
+        output.to_str().unwrap(),
+        "--name",
+        "full_example",
+    ]);
+    assert_eq!(code, Some(0), "{stderr}");
+
+    assert_eq!(
+        fs::read_to_string(output.join("full_example.cpp")).unwrap(),
+        include_str!("cpp/full_example/full_example.cpp")
+    );
+    for file_name in [
+        "full_example.h",
+        "termite.hpp",
+        "termite-yaml.h",
+        "termite-yaml.cpp",
+        "termite-json.h",
+        "termite-json.cpp",
+    ] {
+        assert!(output.join(file_name).exists(), "{file_name} is missing");
+    }
+}
+
+#[test]
+fn generate_schema() {
+    let output = output_dir("generate_schema");
+    let (code, stderr) = run(&[
+        "generate",
+        "schema",
+        "tests/rust/type_constrained/basic/basic.yaml",
+        "--output",
+        output.to_str().unwrap(),
+        "--type",
+        "Name",
+    ]);
+    assert_eq!(code, Some(0), "{stderr}");
+
+    let schema = fs::read_to_string(output.join("basic.schema.json")).unwrap();
+    assert!(schema.contains("\"minLength\": 1"), "{schema}");
+}
+
+#[test]
+fn generate_schema_unknown_fields() {
+    let output = output_dir("generate_schema_unknown_fields");
+    let (code, stderr) = run(&[
+        "generate",
+        "schema",
+        "tests/cpp/type_struct/unknown_fields/unknown_fields.yaml",
+        "--output",
+        output.to_str().unwrap(),
+        "--type",
+        "Ignored",
+    ]);
+    assert_eq!(code, Some(0), "{stderr}");
+
+    // Only the rejecting Size disallows unknown fields
+    let schema = fs::read_to_string(output.join("unknown_fields.schema.json")).unwrap();
+    assert_eq!(
+        schema.matches("\"unevaluatedProperties\": false").count(),
+        1,
+        "{schema}"
+    );
+}
+
+#[test]
+fn validate() {
+    let model = "tests/cpp/full_example/full_example_datamodel.yaml";
+
+    // The c++ generator allows types from the headers
+    assert_eq!(run(&["validate", "--language", "cpp", model]).0, Some(0));
+
+    let (code, stderr) = run(&["validate", model]);
+    assert_eq!(code, Some(1));
+    assert!(stderr.starts_with(&format!(
+        "error: {model}:11:5: .data_types[0].data.data_type: The type \"String\""
+    )));
+    assert!(stderr.contains("11 |     data_type: String\n   |     ^\nerror: "));
+}
+
+#[test]
+fn invalid_constraint() {
+    let output = output_dir("invalid_constraint");
+    let model = output.join("model.yaml");
+    fs::write(
+        &model,
+        indoc::indoc! {"
+            data_types:
+            - name: Positive
+              data: !ConstrainedType
+                data_type: integer
+                constraints:
+                - x >
+        "},
+    )
+    .unwrap();
+
+    let (code, stderr) = run(&["generate", "rust", model.to_str().unwrap()]);
+    assert_eq!(code, Some(1));
+    assert!(stderr.contains("data_types[0].data.ConstrainedType.constraints[0]"));
+    assert!(!output.join("model.rs").exists());
+}
+
+#[test]
+fn fmt() {
+    let output = output_dir("fmt");
+    let model = output.join("model.json");
+    fs::write(
+        &model,
+        "{\"macros\": {\"b\": \"1\", \"a\": \"2\"}, \"data_types\": []}",
+    )
+    .unwrap();
+    let model = model.to_str().unwrap();
+
+    assert_eq!(run(&["fmt", "--check", model]).0, Some(1));
+    assert_eq!(run(&["fmt", model]).0, Some(0));
+    assert_eq!(run(&["fmt", "--check", model]).0, Some(0));
+}
+
+#[test]
+fn fmt_unchanged() {
+    let model = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/yaml_import.yaml");
+
+    assert_eq!(run(&["fmt", "--check", model]), (Some(0), String::new()));
+}
+
+#[test]
+fn fmt_string_default() {
+    let output = output_dir("fmt_string_default");
+    let model = output.join("model.yaml");
+    let text = indoc::indoc! {"
+        data_types:
+        - name: Version
+          data: !Struct
+            fields:
+            - name: major
+              data_type: string
+              default: !Default '1'
+            - name: minor
+              data_type: integer
+              default: !Default 1
+    "};
+    fs::write(&model, text).unwrap();
+
+    assert_eq!(run(&["fmt", model.to_str().unwrap()]), (Some(0), String::new()));
+    assert_eq!(fs::read_to_string(&model).unwrap(), text);
+
+    // Empty sections are left out like unset fields
+    let empty = "headers: {}\nfooters: {}\nnamespace: []\nmacros: {}\n";
+    fs::write(&model, format!("{text}{empty}")).unwrap();
+    assert_eq!(run(&["fmt", model.to_str().unwrap()]), (Some(0), String::new()));
+    assert_eq!(fs::read_to_string(&model).unwrap(), text);
+}
@@ -0,0 +1,204 @@
+// Generated with the Termite Data Model Generator
+#include "dependency_order.h"
+
+
+
+namespace test {
+
+namespace {
+
+// Code to make printing easier
+template <typename T, typename = void>
+struct has_insertion_operator : std::false_type {};
+template <typename T>
+struct has_insertion_operator<T, std::void_t<decltype(std::declval<std::ostream &>() << std::declval<T>())>> : std::true_type {};
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::optional<T> &value) {
+  if (value) {
+    return os << *value;
+  } else {
+    return os << "nullopt";
+  }
+}
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::vector<T> &value) {
+  os << "[ ";
+  for (auto value_it = value.cbegin(); value_it != value.cend(); ++value_it) {
+    if (value_it != value.cbegin()) {
+      os << ", ";
+    }
+    os << *value_it;
+  }
+  return os << " ]";
+}
+
+} // namespace
+
+[[nodiscard]] bool DataType3::operator==(const DataType3 &x) const {
+  return this->field1 == x.field1 && extra_fields == x.extra_fields;
+}
+
+std::ostream &operator<<(std::ostream &os, const DataType3 &x) {
+  return os << "{ " << "field1: " << x.field1 << ", " << "extra_fields: " << x.extra_fields << " }";
+}
+
+bool DataType2::operator==(const DataType2 &x) const {
+  if (values.size() != x.values.size()) {
+    return false;
+  }
+
+  for (auto lhs = values.cbegin(), rhs = x.values.cbegin(); lhs < values.cend(); ++lhs, ++rhs) {
+    if (*lhs != *rhs) {
+      return false;
+    }
+  }
+
+  return true;
+}
+
+std::ostream &operator<<(std::ostream &os, const DataType2 &x) {
+  os << "{ values: [ ";
+  for (auto value = x.values.cbegin(); value < x.values.cend(); ++value) {
+    if (value != x.values.cbegin()) {
+      os << ", ";
+    }
+    os << *value;
+  }
+  return os << " ] }";
+}
+
+[[nodiscard]] bool DataType1::operator==(const DataType1 &x) const {
+  return this->field1 == x.field1 && this->field2 == x.field2 && extra_fields == x.extra_fields;
+}
+
+std::ostream &operator<<(std::ostream &os, const DataType1 &x) {
+  return os << "{ " << "field1: " << x.field1 << ", " << "field2: " << x.field2 << ", " << "extra_fields: " << x.extra_fields << " }";
+}
+
+} // namespace test
+
+namespace termite {
+
+template<>
+[[nodiscard]] Result<test::DataType3, Errors> Node::Map::to_value_all<test::DataType3>() const {
+  std::map<std::string, Node> map = map_;
+  Errors errors;
+
+  auto location_field1 = map.find("field1");
+  std::optional<integer> value_field1;
+  if (location_field1 == map.end()) {
+    errors.add(Error("Missing field1"));
+  } else {
+    Result<integer, Errors> raw_value_field1 = location_field1->second.to_value_all<integer>();
+    if (raw_value_field1.is_ok()) {
+      value_field1 = raw_value_field1.get_ok();
+    } else {
+      errors.append(raw_value_field1.get_err().add_field("field1"));
+    }
+    map.erase(location_field1);
+  }
+
+  if (!errors.empty()) {
+    return Result<test::DataType3, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::DataType3, Errors>::ok(test::DataType3(std::move(*value_field1), Map(std::move(map))));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DataType3>(const test::DataType3 &value) {
+  std::map<std::string, Node> map = value.extra_fields.get();
+
+  map.insert({"field1", Node::from_value(value.field1)});
+
+  return Node(Node::Map(std::move(map)));
+}
+
+template<>
+[[nodiscard]] Result<test::DataType2, Errors> Node::List::to_value_all<test::DataType2>() const {
+  std::vector<test::DataType3> values;
+  values.reserve(list_.size());
+  Errors errors;
+  for (auto node = list_.cbegin(); node < list_.cend(); ++node) {
+    Result<test::DataType3, Errors> value = node->to_value_all<test::DataType3>();
+    if (value.is_ok()) {
+      values.push_back(std::move(value.get_ok()));
+    } else {
+      errors.append(value.get_err().add_list(node - list_.cbegin()));
+    }
+  }
+  if (!errors.empty()) {
+    return Result<test::DataType2, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::DataType2, Errors>::ok(test::DataType2(std::move(values)));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DataType2>(const test::DataType2 &value) {
+  std::vector<Node> list;
+  list.reserve(value.values.size());
+  std::transform(value.values.cbegin(), value.values.cend(), std::back_inserter(list), [](const test::DataType3 &value) {
+    return Node::from_value(value);
+  });
+  return Node(Node::List(std::move(list)));
+}
+
+template<>
+[[nodiscard]] Result<test::DataType1, Errors> Node::Map::to_value_all<test::DataType1>() const {
+  std::map<std::string, Node> map = map_;
+  Errors errors;
+
+  auto location_field1 = map.find("field1");
+  std::optional<test::DataType3> value_field1;
+  if (location_field1 == map.end()) {
+    errors.add(Error("Missing field1"));
+  } else {
+    Result<test::DataType3, Errors> raw_value_field1 = location_field1->second.to_value_all<test::DataType3>();
+    if (raw_value_field1.is_ok()) {
+      value_field1 = raw_value_field1.get_ok();
+    } else {
+      errors.append(raw_value_field1.get_err().add_field("field1"));
+    }
+    map.erase(location_field1);
+  }
+
+  auto location_field2 = map.find("field2");
+  std::optional<test::DataType2> value_field2;
+  if (location_field2 == map.end()) {
+    errors.add(Error("Missing field2"));
+  } else {
+    Result<test::DataType2, Errors> raw_value_field2 = location_field2->second.to_value_all<test::DataType2>();
+    if (raw_value_field2.is_ok()) {
+      value_field2 = raw_value_field2.get_ok();
+    } else {
+      errors.append(raw_value_field2.get_err().add_field("field2"));
+    }
+    map.erase(location_field2);
+  }
+
+  if (!errors.empty()) {
+    return Result<test::DataType1, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::DataType1, Errors>::ok(test::DataType1(std::move(*value_field1), std::move(*value_field2), Map(std::move(map))));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DataType1>(const test::DataType1 &value) {
+  std::map<std::string, Node> map = value.extra_fields.get();
+
+  map.insert({"field1", Node::from_value(value.field1)});
+
+  map.insert({"field2", Node::from_value(value.field2)});
+
+  return Node(Node::Map(std::move(map)));
+}
+
+} // namespace termite
+
+
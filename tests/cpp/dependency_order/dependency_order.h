@@ -0,0 +1,206 @@
+// Generated with the Termite Data Model Generator
+#ifndef HEADER_TERMITE_H_INCLUDED
+#define HEADER_TERMITE_H_INCLUDED
+
+#include <iostream>
+#include <sstream>
+#include <optional>
+#include <variant>
+#include <algorithm>
+#include <termite.hpp>
+
+
+
+namespace test {
+
+/**
+ * @brief
+ *
+ */
+struct DataType3 {
+public:
+  /**
+   * @brief Constructs a new DataType3 object
+   *
+   * @param field1
+   * @param extra_fields Any extra fields to attach to this struct
+   */
+  explicit DataType3(termite::integer field1, ::termite::Node::Map extra_fields = ::termite::Node::Map()) : field1(std::move(field1)), extra_fields(std::move(extra_fields)) {}
+
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   *
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const DataType3 &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   *
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const DataType3 &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   *
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const DataType3 &x);
+
+  /**
+   * @brief
+   *
+   */
+  termite::integer field1;
+  /**
+   * @brief All extra fields from when reading which could not be captured
+   *
+   */
+  ::termite::Node::Map extra_fields;
+};
+
+/**
+ * @brief
+ *
+ */
+struct DataType2 {
+public:
+  /**
+   * @brief Constructs a new DataType2 object
+   *
+   * @param values The values of the array
+   */
+  explicit DataType2(std::vector<DataType3> values) : values(std::move(values)) {}
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   *
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const DataType2 &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   *
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const DataType2 &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   *
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const DataType2 &x);
+
+  /**
+   * @brief The values of the array
+   *
+   */
+  std::vector<DataType3> values;
+};
+
+/**
+ * @brief
+ *
+ */
+struct DataType1 {
+public:
+  /**
+   * @brief Constructs a new DataType1 object
+   *
+   * @param field1
+   * @param field2
+   * @param extra_fields Any extra fields to attach to this struct
+   */
+  explicit DataType1(DataType3 field1, DataType2 field2, ::termite::Node::Map extra_fields = ::termite::Node::Map()) : field1(std::move(field1)), field2(std::move(field2)), extra_fields(std::move(extra_fields)) {}
+
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   *
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const DataType1 &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   *
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const DataType1 &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   *
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const DataType1 &x);
+
+  /**
+   * @brief
+   *
+   */
+  DataType3 field1;
+  /**
+   * @brief
+   *
+   */
+  DataType2 field2;
+  /**
+   * @brief All extra fields from when reading which could not be captured
+   *
+   */
+  ::termite::Node::Map extra_fields;
+};
+
+} // namespace test
+
+namespace termite {
+
+template<>
+struct has_to_value_all<test::DataType3> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::DataType3, Errors> Node::Map::to_value_all<test::DataType3>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DataType3>(const test::DataType3 &value);
+
+template<>
+struct has_to_value_all<test::DataType2> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::DataType2, Errors> Node::List::to_value_all<test::DataType2>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DataType2>(const test::DataType2 &value);
+
+template<>
+struct has_to_value_all<test::DataType1> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::DataType1, Errors> Node::Map::to_value_all<test::DataType1>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DataType1>(const test::DataType1 &value);
+
+} // namespace termite
+
+
+
+#endif
@@ -0,0 +1,42 @@
+#include <iostream>
+#include "dependency_order.h"
+
+int main() {
+  auto value = test::DataType1(test::DataType3(1), test::DataType2({test::DataType3(2), test::DataType3(3)}));
+
+  std::map<std::string, termite::Node> map_inner1;
+  map_inner1.insert({"field1", termite::Node(termite::Node::Value("1"))});
+  std::map<std::string, termite::Node> map_inner2;
+  map_inner2.insert({"field1", termite::Node(termite::Node::Value("2"))});
+  std::map<std::string, termite::Node> map_inner3;
+  map_inner3.insert({"field1", termite::Node(termite::Node::Value("3"))});
+  std::vector<termite::Node> list;
+  list.push_back(termite::Node(termite::Node::Map(std::move(map_inner2))));
+  list.push_back(termite::Node(termite::Node::Map(std::move(map_inner3))));
+  std::map<std::string, termite::Node> map;
+  map.insert({"field1", termite::Node(termite::Node::Map(std::move(map_inner1)))});
+  map.insert({"field2", termite::Node(termite::Node::List(std::move(list)))});
+  termite::Node node(termite::Node::Map(std::move(map)));
+
+  auto value_read = node.to_value<test::DataType1>();
+  if (!value_read.is_ok()) {
+    return 1;
+  }
+  if (value_read.get_ok() != value) {
+    return 2;
+  }
+
+  auto value_read_again = termite::Node::from_value(value).to_value<test::DataType1>();
+  if (!value_read_again.is_ok()) {
+    return 3;
+  }
+  if (value_read_again.get_ok() != value) {
+    return 4;
+  }
+
+  std::cout << value << std::endl;
+
+  std::cout << "Done" << std::endl;
+
+  return 0;
+}
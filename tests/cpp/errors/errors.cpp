@@ -0,0 +1,426 @@
+// Generated with the Termite Data Model Generator
+#include "errors.h"
+
+
+
+namespace test {
+
+namespace {
+
+// Code to make printing easier
+template <typename T, typename = void>
+struct has_insertion_operator : std::false_type {};
+template <typename T>
+struct has_insertion_operator<T, std::void_t<decltype(std::declval<std::ostream &>() << std::declval<T>())>> : std::true_type {};
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::optional<T> &value) {
+  if (value) {
+    return os << *value;
+  } else {
+    return os << "nullopt";
+  }
+}
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::vector<T> &value) {
+  os << "[ ";
+  for (auto value_it = value.cbegin(); value_it != value.cend(); ++value_it) {
+    if (value_it != value.cbegin()) {
+      os << ", ";
+    }
+    os << *value_it;
+  }
+  return os << " ]";
+}
+
+} // namespace
+
+bool Points::operator==(const Points &x) const {
+  if (values.size() != x.values.size()) {
+    return false;
+  }
+
+  for (auto lhs = values.cbegin(), rhs = x.values.cbegin(); lhs < values.cend(); ++lhs, ++rhs) {
+    if (*lhs != *rhs) {
+      return false;
+    }
+  }
+
+  return true;
+}
+
+std::ostream &operator<<(std::ostream &os, const Points &x) {
+  os << "{ values: [ ";
+  for (auto value = x.values.cbegin(); value < x.values.cend(); ++value) {
+    if (value != x.values.cbegin()) {
+      os << ", ";
+    }
+    os << *value;
+  }
+  return os << " ] }";
+}
+
+bool Limits::operator==(const Limits &x) const {
+  return values == x.values;
+}
+
+std::ostream &operator<<(std::ostream &os, const Limits &x) {
+  os << "{ values: { ";
+  for (auto value = x.values.cbegin(); value != x.values.cend(); ++value) {
+    if (value != x.values.cbegin()) {
+      os << ", ";
+    }
+    os << "\"" << value->first << "\": " << value->second;
+  }
+  return os << " } }";
+}
+
+[[nodiscard]] bool Point::operator==(const Point &x) const {
+  return this->x == x.x && this->y == x.y && extra_fields == x.extra_fields;
+}
+
+std::ostream &operator<<(std::ostream &os, const Point &x) {
+  return os << "{ " << "x: " << x.x << ", " << "y: " << x.y << ", " << "extra_fields: " << x.extra_fields << " }";
+}
+
+[[nodiscard]] bool Shape::TypeCircle::operator==(const TypeCircle &x) const {
+  return value == x.value;
+}
+
+std::ostream &operator<<(std::ostream &os, const Shape::TypeCircle &x) {
+  return os << "{ value: " << x.value << " }";
+}
+
+[[nodiscard]] bool Shape::TypeEmpty::operator==(const TypeEmpty &) const {
+  return true;
+}
+
+std::ostream &operator<<(std::ostream &os, const Shape::TypeEmpty &) {
+  return os << "{  }";
+}
+
+[[nodiscard]] bool Shape::operator==(const Shape &x) const {
+  return value == x.value;
+}
+
+std::ostream &operator<<(std::ostream &os, const Shape &x) {
+  os << "{ value: ";
+  switch (static_cast<Shape::Enum>(x.value.index())) {
+  case Shape::Enum::kCircle:
+    os << "Circle(" << std::get<Shape::TypeCircle>(x.value).value << ")";
+    break;
+  case Shape::Enum::kEmpty:
+    os << "Empty";
+    break;
+  default:
+    os << "Unknown (" << x.value.index() << ")";
+    break;
+  }
+  return os << " }";
+}
+
+[[nodiscard]] bool Config::operator==(const Config &x) const {
+  return this->name == x.name && this->count == x.count && this->scale == x.scale && this->points == x.points && this->limits == x.limits && this->shape == x.shape;
+}
+
+[[nodiscard]] termite::number Config::default_scale() {
+  auto node = termite::Node(termite::Node::Value("1.0"));
+
+  return node.to_value<termite::number>().get_ok();
+}
+
+std::ostream &operator<<(std::ostream &os, const Config &x) {
+  return os << "{ " << "name: " << x.name << ", " << "count: " << x.count << ", " << "scale: " << x.scale << ", " << "points: " << x.points << ", " << "limits: " << x.limits << ", " << "shape: " << x.shape << " }";
+}
+
+} // namespace test
+
+namespace termite {
+
+template<>
+[[nodiscard]] Result<test::Points, Errors> Node::List::to_value_all<test::Points>() const {
+  std::vector<test::Point> values;
+  values.reserve(list_.size());
+  Errors errors;
+  for (auto node = list_.cbegin(); node < list_.cend(); ++node) {
+    Result<test::Point, Errors> value = node->to_value_all<test::Point>();
+    if (value.is_ok()) {
+      values.push_back(std::move(value.get_ok()));
+    } else {
+      errors.append(value.get_err().add_list(node - list_.cbegin()));
+    }
+  }
+  if (!errors.empty()) {
+    return Result<test::Points, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::Points, Errors>::ok(test::Points(std::move(values)));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Points>(const test::Points &value) {
+  std::vector<Node> list;
+  list.reserve(value.values.size());
+  std::transform(value.values.cbegin(), value.values.cend(), std::back_inserter(list), [](const test::Point &value) {
+    return Node::from_value(value);
+  });
+  return Node(Node::List(std::move(list)));
+}
+
+template<>
+[[nodiscard]] Result<test::Limits, Errors> Node::Map::to_value_all<test::Limits>() const {
+  std::map<std::string, integer> values;
+  Errors errors;
+  for (auto node = map_.cbegin(); node != map_.cend(); ++node) {
+    Result<integer, Errors> value = node->second.to_value_all<integer>();
+    if (value.is_ok()) {
+      values.insert({node->first, std::move(value.get_ok())});
+    } else {
+      errors.append(value.get_err().add_field(node->first));
+    }
+  }
+  if (!errors.empty()) {
+    return Result<test::Limits, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::Limits, Errors>::ok(test::Limits(std::move(values)));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Limits>(const test::Limits &value) {
+  std::map<std::string, Node> map;
+  for (auto node = value.values.cbegin(); node != value.values.cend(); ++node) {
+    map.insert({node->first, Node::from_value(node->second)});
+  }
+  return Node(Node::Map(std::move(map)));
+}
+
+template<>
+[[nodiscard]] Result<test::Point, Errors> Node::Map::to_value_all<test::Point>() const {
+  std::map<std::string, Node> map = map_;
+  Errors errors;
+
+  auto location_x = map.find("x");
+  std::optional<number> value_x;
+  if (location_x == map.end()) {
+    errors.add(Error("Missing x"));
+  } else {
+    Result<number, Errors> raw_value_x = location_x->second.to_value_all<number>();
+    if (raw_value_x.is_ok()) {
+      value_x = raw_value_x.get_ok();
+    } else {
+      errors.append(raw_value_x.get_err().add_field("x"));
+    }
+    map.erase(location_x);
+  }
+
+  auto location_y = map.find("y");
+  std::optional<number> value_y;
+  if (location_y == map.end()) {
+    errors.add(Error("Missing y"));
+  } else {
+    Result<number, Errors> raw_value_y = location_y->second.to_value_all<number>();
+    if (raw_value_y.is_ok()) {
+      value_y = raw_value_y.get_ok();
+    } else {
+      errors.append(raw_value_y.get_err().add_field("y"));
+    }
+    map.erase(location_y);
+  }
+
+  if (!errors.empty()) {
+    return Result<test::Point, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::Point, Errors>::ok(test::Point(std::move(*value_x), std::move(*value_y), Map(std::move(map))));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Point>(const test::Point &value) {
+  std::map<std::string, Node> map = value.extra_fields.get();
+
+  map.insert({"x", Node::from_value(value.x)});
+
+  map.insert({"y", Node::from_value(value.y)});
+
+  return Node(Node::Map(std::move(map)));
+}
+
+template<>
+[[nodiscard]] Result<test::Shape> Node::Value::to_value<test::Shape>() const {
+  if (value_ == "Circle") {
+    return Result<test::Shape>::err(Error("Enum type Circle must contain a value"));
+  }
+  if (value_ == "Empty") {
+    return Result<test::Shape>::ok(test::Shape(test::Shape::TypeEmpty{}));
+  }
+
+  std::stringstream ss;
+  ss << "Unknown enum type \"" << value_ << "\"";
+  return Result<test::Shape>::err(Error(ss.str()));
+}
+
+template<>
+[[nodiscard]] Result<test::Shape, Errors> Node::Map::to_value_all<test::Shape>() const {
+  if (map_.size() != 1) {
+    std::stringstream ss;
+    ss << "There must be exactly one enum type specified but received " << map_.size();
+    return Result<test::Shape, Errors>::err(Errors(Error(ss.str())));
+  }
+
+  if (map_.cbegin()->first == "Circle") {
+    Result<test::Point, Errors> value = map_.cbegin()->second.to_value_all<test::Point>();
+    if (value.is_ok()) {
+      return Result<test::Shape, Errors>::ok(test::Shape(test::Shape::TypeCircle{value.get_ok()}));
+    }
+    return Result<test::Shape, Errors>::err(value.get_err().add_field("Circle"));
+  }
+  if (map_.cbegin()->first == "Empty") {
+    return Result<test::Shape, Errors>::err(Errors(Error("Enum type Empty must not include values")));
+  }
+
+  std::stringstream ss;
+  ss << "Unknown enum type \"" << map_.cbegin()->first << "\"";
+  return Result<test::Shape, Errors>::err(Errors(Error(ss.str())));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Shape>(const test::Shape &value) {
+  std::map<std::string, Node> map;
+  switch (value.enum_type()) {
+  case test::Shape::Enum::kCircle:
+    map.insert({
+      "Circle",
+      Node::from_value(std::get<test::Shape::TypeCircle>(value.value).value)
+    });
+    return Node(Node::Map(std::move(map)));
+  case test::Shape::Enum::kEmpty:
+    return Node(Node::Value("Empty"));
+  default:
+    return Node(Node::Value(""));
+  }
+}
+
+template<>
+[[nodiscard]] Result<test::Config, Errors> Node::Map::to_value_all<test::Config>() const {
+  std::map<std::string, Node> map = map_;
+  Errors errors;
+
+  auto location_name = map.find("name");
+  std::optional<string> value_name;
+  if (location_name == map.end()) {
+    errors.add(Error("Missing name"));
+  } else {
+    Result<string, Errors> raw_value_name = location_name->second.to_value_all<string>();
+    if (raw_value_name.is_ok()) {
+      value_name = raw_value_name.get_ok();
+    } else {
+      errors.append(raw_value_name.get_err().add_field("name"));
+    }
+    map.erase(location_name);
+  }
+
+  auto location_count = map.find("count");
+  std::optional<integer> value_count;
+  if (location_count == map.end()) {
+    errors.add(Error("Missing count"));
+  } else {
+    Result<integer, Errors> raw_value_count = location_count->second.to_value_all<integer>();
+    if (raw_value_count.is_ok()) {
+      value_count = raw_value_count.get_ok();
+    } else {
+      errors.append(raw_value_count.get_err().add_field("count"));
+    }
+    map.erase(location_count);
+  }
+
+  auto location_scale = map.find("scale");
+  number value_scale = test::Config::default_scale();
+  if (location_scale != map.end()) {
+    Result<number, Errors> raw_value_scale = location_scale->second.to_value_all<number>();
+    if (raw_value_scale.is_ok()) {
+      value_scale = raw_value_scale.get_ok();
+    } else {
+      errors.append(raw_value_scale.get_err().add_field("scale"));
+    }
+    map.erase(location_scale);
+  }
+
+  auto location_points = map.find("points");
+  std::optional<test::Points> value_points;
+  if (location_points == map.end()) {
+    errors.add(Error("Missing points"));
+  } else {
+    Result<test::Points, Errors> raw_value_points = location_points->second.to_value_all<test::Points>();
+    if (raw_value_points.is_ok()) {
+      value_points = raw_value_points.get_ok();
+    } else {
+      errors.append(raw_value_points.get_err().add_field("points"));
+    }
+    map.erase(location_points);
+  }
+
+  auto location_limits = map.find("limits");
+  std::optional<test::Limits> value_limits;
+  if (location_limits == map.end()) {
+    errors.add(Error("Missing limits"));
+  } else {
+    Result<test::Limits, Errors> raw_value_limits = location_limits->second.to_value_all<test::Limits>();
+    if (raw_value_limits.is_ok()) {
+      value_limits = raw_value_limits.get_ok();
+    } else {
+      errors.append(raw_value_limits.get_err().add_field("limits"));
+    }
+    map.erase(location_limits);
+  }
+
+  auto location_shape = map.find("shape");
+  std::optional<test::Shape> value_shape;
+  if (location_shape == map.end()) {
+    errors.add(Error("Missing shape"));
+  } else {
+    Result<test::Shape, Errors> raw_value_shape = location_shape->second.to_value_all<test::Shape>();
+    if (raw_value_shape.is_ok()) {
+      value_shape = raw_value_shape.get_ok();
+    } else {
+      errors.append(raw_value_shape.get_err().add_field("shape"));
+    }
+    map.erase(location_shape);
+  }
+
+  if (!map.empty()) {
+    errors.add(Map::unknown_fields_error(map, {"name", "count", "scale", "points", "limits", "shape"}));
+  }
+
+  if (!errors.empty()) {
+    return Result<test::Config, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::Config, Errors>::ok(test::Config(std::move(*value_name), std::move(*value_count), std::move(value_scale), std::move(*value_points), std::move(*value_limits), std::move(*value_shape)));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Config>(const test::Config &value) {
+  std::map<std::string, Node> map;
+
+  map.insert({"name", Node::from_value(value.name)});
+
+  map.insert({"count", Node::from_value(value.count)});
+
+  map.insert({"scale", Node::from_value(value.scale)});
+
+  map.insert({"points", Node::from_value(value.points)});
+
+  map.insert({"limits", Node::from_value(value.limits)});
+
+  map.insert({"shape", Node::from_value(value.shape)});
+
+  return Node(Node::Map(std::move(map)));
+}
+
+} // namespace termite
+
+
@@ -0,0 +1,449 @@
+// Generated with the Termite Data Model Generator
+#ifndef HEADER_TERMITE_H_INCLUDED
+#define HEADER_TERMITE_H_INCLUDED
+
+#include <iostream>
+#include <sstream>
+#include <optional>
+#include <variant>
+#include <algorithm>
+#include <termite.hpp>
+
+
+
+namespace test {
+
+struct Point;
+
+/**
+ * @brief 
+ * 
+ */
+struct Points {
+public:
+  /**
+   * @brief Constructs a new Points object
+   * 
+   * @param values The values of the array
+   */
+  explicit Points(std::vector<Point> values) : values(std::move(values)) {}
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Points &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Points &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Points &x);
+
+  /**
+   * @brief The values of the array
+   * 
+   */
+  std::vector<Point> values;
+};
+
+/**
+ * @brief 
+ * 
+ */
+struct Limits {
+public:
+  /**
+   * @brief Constructs a new Limits object
+   * 
+   * @param values The values of the map
+   */
+  explicit Limits(std::map<std::string, termite::integer> values) : values(std::move(values)) {}
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Limits &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Limits &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Limits &x);
+
+  /**
+   * @brief The values of the map
+   * 
+   */
+  std::map<std::string, termite::integer> values;
+};
+
+/**
+ * @brief 
+ * 
+ */
+struct Point {
+public:
+  /**
+   * @brief Constructs a new Point object
+   * 
+   * @param x 
+   * @param y 
+   * @param extra_fields Any extra fields to attach to this struct
+   */
+  explicit Point(termite::number x, termite::number y, ::termite::Node::Map extra_fields = ::termite::Node::Map()) : x(std::move(x)), y(std::move(y)), extra_fields(std::move(extra_fields)) {}
+
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Point &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Point &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Point &x);
+
+  /**
+   * @brief 
+   * 
+   */
+  termite::number x;
+  /**
+   * @brief 
+   * 
+   */
+  termite::number y;
+  /**
+   * @brief All extra fields from when reading which could not be captured
+   * 
+   */
+  ::termite::Node::Map extra_fields;
+};
+
+/**
+ * @brief 
+ * 
+ */
+struct Shape {
+  /**
+   * @brief The values of this enum
+   * 
+   */
+  enum class Enum {
+    /**
+     * @brief 
+     * 
+     */
+    kCircle,
+    /**
+     * @brief 
+     * 
+     */
+    kEmpty,
+  };
+
+  /**
+   * @brief The data for when the enum is a Circle
+   * 
+   */
+  struct TypeCircle {
+    /**
+     * @brief The value
+     * 
+     */
+    Point value;
+
+    /**
+     * @brief Constructs a new Circle object
+     * 
+     * @param value The value of the enum
+     */
+    explicit TypeCircle(Point value) : value(std::move(value)) {}
+
+    /**
+     * @brief Checks if this object and the other object are identical
+     * 
+     * @param x The other object to compare with
+     * @return true if they are identical, false if not
+     */
+    [[nodiscard]] bool operator==(const TypeCircle &x) const;
+    /**
+     * @brief Checks if this object and the other object are different
+     * 
+     * @param x The other object to compare with
+     * @return true if they are different, false if not
+     */
+    [[nodiscard]] bool operator!=(const TypeCircle &x) const {
+      return !(*this == x);
+    }
+    /**
+     * @brief Prints the object onto the output stream
+     * 
+     * @param os The output stream to print to
+     * @param x The object to print
+     * @return The output stream
+     */
+    friend std::ostream &operator<<(std::ostream &os, const TypeCircle &x);
+  };
+
+  /**
+   * @brief The data for when the enum is a Empty
+   * 
+   */
+  struct TypeEmpty {
+    /**
+     * @brief Checks if this object and the other object are identical
+     * 
+     * @param x The other object to compare with
+     * @return true if they are identical, false if not
+     */
+    [[nodiscard]] bool operator==(const TypeEmpty &x) const;
+    /**
+     * @brief Checks if this object and the other object are different
+     * 
+     * @param x The other object to compare with
+     * @return true if they are different, false if not
+     */
+    [[nodiscard]] bool operator!=(const TypeEmpty &x) const {
+      return !(*this == x);
+    }
+    /**
+     * @brief Prints the object onto the output stream
+     * 
+     * @param os The output stream to print to
+     * @param x The object to print
+     * @return The output stream
+     */
+    friend std::ostream &operator<<(std::ostream &os, const TypeEmpty &x);
+  };
+
+  /**
+   * @brief Constructs a new Shape object
+   * 
+   * @param value The value of the enum
+   */
+  explicit Shape(std::variant<TypeCircle, TypeEmpty> value) : value(std::move(value)) {}
+
+  /**
+   * @brief Returns the enum type that is stored
+   * 
+   * @return The enum type
+   */
+  [[nodiscard]] Enum enum_type() const {
+    return static_cast<Enum>(value.index());
+  }
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Shape &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Shape &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Shape &x);
+
+  /**
+   * @brief The value of the enum
+   * 
+   */
+  std::variant<TypeCircle, TypeEmpty> value;
+};
+
+/**
+ * @brief 
+ * 
+ */
+struct Config {
+public:
+  /**
+   * @brief Constructs a new Config object
+   * 
+   * @param name 
+   * @param count 
+   * @param scale 
+   * @param points 
+   * @param limits 
+   * @param shape 
+   */
+  explicit Config(termite::string name, termite::integer count, termite::number scale, Points points, Limits limits, Shape shape) : name(std::move(name)), count(std::move(count)), scale(std::move(scale)), points(std::move(points)), limits(std::move(limits)), shape(std::move(shape)) {}
+
+  /**
+   * @brief Gets the default value for scale
+   * 
+   * @return The default value for scale
+   */
+  [[nodiscard]] static termite::number default_scale();
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Config &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Config &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Config &x);
+
+  /**
+   * @brief 
+   * 
+   */
+  termite::string name;
+  /**
+   * @brief 
+   * 
+   */
+  termite::integer count;
+  /**
+   * @brief 
+   * 
+   */
+  termite::number scale;
+  /**
+   * @brief 
+   * 
+   */
+  Points points;
+  /**
+   * @brief 
+   * 
+   */
+  Limits limits;
+  /**
+   * @brief 
+   * 
+   */
+  Shape shape;
+};
+
+} // namespace test
+
+namespace termite {
+
+template<>
+struct has_to_value_all<test::Points> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::Points, Errors> Node::List::to_value_all<test::Points>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Points>(const test::Points &value);
+
+template<>
+struct has_to_value_all<test::Limits> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::Limits, Errors> Node::Map::to_value_all<test::Limits>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Limits>(const test::Limits &value);
+
+template<>
+struct has_to_value_all<test::Point> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::Point, Errors> Node::Map::to_value_all<test::Point>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Point>(const test::Point &value);
+
+template<>
+struct has_to_value_all<test::Shape> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::Shape> Node::Value::to_value<test::Shape>() const;
+
+template<>
+[[nodiscard]] Result<test::Shape, Errors> Node::Map::to_value_all<test::Shape>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Shape>(const test::Shape &value);
+
+template<>
+struct has_to_value_all<test::Config> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::Config, Errors> Node::Map::to_value_all<test::Config>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Config>(const test::Config &value);
+
+} // namespace termite
+
+
+
+#endif
@@ -0,0 +1,90 @@
+#include <iostream>
+#include "errors.h"
+
+termite::Node value(const std::string &value) {
+  return termite::Node(termite::Node::Value(value));
+}
+
+termite::Node map(std::map<std::string, termite::Node> map) {
+  return termite::Node(termite::Node::Map(std::move(map)));
+}
+
+termite::Node list(std::vector<termite::Node> list) {
+  return termite::Node(termite::Node::List(std::move(list)));
+}
+
+int main() {
+  termite::Node node_correct = map({
+      {"name", value("config")},
+      {"count", value("2")},
+      {"points", list({map({{"x", value("1")}, {"y", value("2")}})})},
+      {"limits", map({{"a", value("1")}})},
+      {"shape", value("Empty")},
+  });
+  auto value_correct = node_correct.to_value_all<test::Config>();
+  if (!value_correct.is_ok()) {
+    std::cout << value_correct.get_err() << std::endl;
+    return 1;
+  }
+  if (value_correct.get_ok() != node_correct.to_value<test::Config>().get_ok()) {
+    return 2;
+  }
+
+  termite::Node node_wrong = map({
+      {"count", value("many")},
+      {"scale", value("big")},
+      {"points", list({
+          map({{"x", value("1")}, {"y", value("2")}}),
+          map({{"x", value("a")}}),
+      })},
+      {"limits", map({{"a", value("1")}, {"b", value("z")}})},
+      {"shape", map({{"Circle", map({{"x", value("1")}})}})},
+      {"colour", value("red")},
+  });
+
+  // All errors are found with their location
+  auto value_all = node_wrong.to_value_all<test::Config>();
+  if (value_all.is_ok()) {
+    return 3;
+  }
+  std::vector<termite::Error> expected = {
+      termite::Error("Missing name"),
+      termite::Error("Unable to parse", "count"),
+      termite::Error("Unable to parse", "scale"),
+      termite::Error("Unable to parse", "points[1].x"),
+      termite::Error("Missing y", "points[1]"),
+      termite::Error("Unable to parse", "limits.b"),
+      termite::Error("Missing y", "shape.Circle"),
+      termite::Error("Unknown field \"colour\""),
+  };
+  termite::Errors errors = value_all.get_err();
+  if (errors.get() != expected) {
+    std::cout << errors << std::endl;
+    return 4;
+  }
+
+  // The single error interface stops at the first error
+  auto value_first = node_wrong.to_value<test::Config>();
+  if (value_first.is_ok()) {
+    return 5;
+  }
+  if (value_first.get_err() != termite::Error("Missing name")) {
+    return 6;
+  }
+
+  // Errors of lists are found for every element
+  auto value_points = list({map({}), value("1")}).to_value_all<test::Points>();
+  if (value_points.is_ok()) {
+    return 7;
+  }
+  std::stringstream ss;
+  ss << value_points.get_err();
+  if (ss.str() != "[0]: Missing x\n[0]: Missing y\n[1]: Parsing not implemented for given type") {
+    std::cout << ss.str() << std::endl;
+    return 8;
+  }
+
+  std::cout << "Done" << std::endl;
+
+  return 0;
+}
@@ -1,5 +1,6 @@
 // Generated with the Termite Data Model Generator
 #include "full_example.h"
+#include <regex>
 
 // This is my .cpp file
 
@@ -66,8 +67,10 @@ std::ostream &operator<<(std::ostream &os, const VersionString &x) {
 }
 
 [[nodiscard]] termite::Result<termite::Empty> VersionString::validate(const String &x) {
-  if (!(custom::is_valid_version_string(x))) {
-    return termite::Result<termite::Empty>::err(termite::Error("Did not pass constraint: custom::is_valid_version_string(x)"));
+  static const std::regex pattern_0("^[0-9]+(\\.[0-9]+){0,2}$");
+
+  if (!(std::regex_search(x, pattern_0))) {
+    return termite::Result<termite::Empty>::err(termite::Error("Did not pass constraint: matches(x, \"^[0-9]+(\\\\.[0-9]+){0,2}$\")"));
   }
 
   return termite::Result<termite::Empty>::ok(termite::Empty());
@@ -268,13 +271,17 @@ std::ostream &operator<<(std::ostream &os, const DataModel &x) {
 namespace termite {
 
 template<>
-[[nodiscard]] Result<test::name::space::VersionString> Node::to_value<test::name::space::VersionString>() const {
-  Result<String> value = to_value<String>();
+[[nodiscard]] Result<test::name::space::VersionString, Errors> Node::to_value_all<test::name::space::VersionString>() const {
+  Result<String, Errors> value = to_value_all<String>();
   if (!value.is_ok()) {
-    return Result<test::name::space::VersionString>::err(Error(value.get_err()));
+    return Result<test::name::space::VersionString, Errors>::err(value.get_err());
   }
 
-  return test::name::space::VersionString::from_value(value.get_ok());
+  Result<test::name::space::VersionString> result = test::name::space::VersionString::from_value(value.get_ok());
+  if (!result.is_ok()) {
+    return Result<test::name::space::VersionString, Errors>::err(Errors(result.get_err().set_mark(mark_)));
+  }
+  return Result<test::name::space::VersionString, Errors>::ok(result.get_ok());
 }
 
 template<>
@@ -283,13 +290,17 @@ template<>
 }
 
 template<>
-[[nodiscard]] Result<test::name::space::SizeValue> Node::to_value<test::name::space::SizeValue>() const {
-  Result<int> value = to_value<int>();
+[[nodiscard]] Result<test::name::space::SizeValue, Errors> Node::to_value_all<test::name::space::SizeValue>() const {
+  Result<int, Errors> value = to_value_all<int>();
   if (!value.is_ok()) {
-    return Result<test::name::space::SizeValue>::err(Error(value.get_err()));
+    return Result<test::name::space::SizeValue, Errors>::err(value.get_err());
   }
 
-  return test::name::space::SizeValue::from_value(value.get_ok());
+  Result<test::name::space::SizeValue> result = test::name::space::SizeValue::from_value(value.get_ok());
+  if (!result.is_ok()) {
+    return Result<test::name::space::SizeValue, Errors>::err(Errors(result.get_err().set_mark(mark_)));
+  }
+  return Result<test::name::space::SizeValue, Errors>::ok(result.get_ok());
 }
 
 template<>
@@ -298,36 +309,43 @@ template<>
 }
 
 template<>
-[[nodiscard]] Result<test::name::space::Size> Node::Map::to_value<test::name::space::Size>() const {
+[[nodiscard]] Result<test::name::space::Size, Errors> Node::Map::to_value_all<test::name::space::Size>() const {
   std::map<std::string, Node> map = map_;
+  Errors errors;
 
   auto location_w = map.find("w");
+  std::optional<test::name::space::SizeValue> value_w;
   if (location_w == map.end()) {
-    return Result<test::name::space::Size>::err(Error("Missing w"));
-  }
-  Result<test::name::space::SizeValue> raw_value_w = location_w->second.to_value<test::name::space::SizeValue>();
-  if (!raw_value_w.is_ok()) {
-    Error error = raw_value_w.get_err();
-    error.add_field("w");
-    return Result<test::name::space::Size>::err(std::move(error));
+    errors.add(Error("Missing w"));
+  } else {
+    Result<test::name::space::SizeValue, Errors> raw_value_w = location_w->second.to_value_all<test::name::space::SizeValue>();
+    if (raw_value_w.is_ok()) {
+      value_w = raw_value_w.get_ok();
+    } else {
+      errors.append(raw_value_w.get_err().add_field("w"));
+    }
+    map.erase(location_w);
   }
-  test::name::space::SizeValue value_w = raw_value_w.get_ok();
-  map.erase(location_w);
 
   auto location_h = map.find("h");
+  std::optional<test::name::space::SizeValue> value_h;
   if (location_h == map.end()) {
-    return Result<test::name::space::Size>::err(Error("Missing h"));
+    errors.add(Error("Missing h"));
+  } else {
+    Result<test::name::space::SizeValue, Errors> raw_value_h = location_h->second.to_value_all<test::name::space::SizeValue>();
+    if (raw_value_h.is_ok()) {
+      value_h = raw_value_h.get_ok();
+    } else {
+      errors.append(raw_value_h.get_err().add_field("h"));
+    }
+    map.erase(location_h);
   }
-  Result<test::name::space::SizeValue> raw_value_h = location_h->second.to_value<test::name::space::SizeValue>();
-  if (!raw_value_h.is_ok()) {
-    Error error = raw_value_h.get_err();
-    error.add_field("h");
-    return Result<test::name::space::Size>::err(std::move(error));
+
+  if (!errors.empty()) {
+    return Result<test::name::space::Size, Errors>::err(std::move(errors));
   }
-  test::name::space::SizeValue value_h = raw_value_h.get_ok();
-  map.erase(location_h);
 
-  return Result<test::name::space::Size>::ok(test::name::space::Size(std::move(value_w), std::move(value_h), Map(std::move(map))));
+  return Result<test::name::space::Size, Errors>::ok(test::name::space::Size(std::move(*value_w), std::move(*value_h), Map(std::move(map))));
 }
 
 template<>
@@ -342,36 +360,43 @@ template<>
 }
 
 template<>
-[[nodiscard]] Result<test::name::space::Point> Node::Map::to_value<test::name::space::Point>() const {
+[[nodiscard]] Result<test::name::space::Point, Errors> Node::Map::to_value_all<test::name::space::Point>() const {
   std::map<std::string, Node> map = map_;
+  Errors errors;
 
   auto location_x = map.find("x");
+  std::optional<int> value_x;
   if (location_x == map.end()) {
-    return Result<test::name::space::Point>::err(Error("Missing x"));
-  }
-  Result<int> raw_value_x = location_x->second.to_value<int>();
-  if (!raw_value_x.is_ok()) {
-    Error error = raw_value_x.get_err();
-    error.add_field("x");
-    return Result<test::name::space::Point>::err(std::move(error));
+    errors.add(Error("Missing x"));
+  } else {
+    Result<int, Errors> raw_value_x = location_x->second.to_value_all<int>();
+    if (raw_value_x.is_ok()) {
+      value_x = raw_value_x.get_ok();
+    } else {
+      errors.append(raw_value_x.get_err().add_field("x"));
+    }
+    map.erase(location_x);
   }
-  int value_x = raw_value_x.get_ok();
-  map.erase(location_x);
 
   auto location_y = map.find("y");
+  std::optional<int> value_y;
   if (location_y == map.end()) {
-    return Result<test::name::space::Point>::err(Error("Missing y"));
+    errors.add(Error("Missing y"));
+  } else {
+    Result<int, Errors> raw_value_y = location_y->second.to_value_all<int>();
+    if (raw_value_y.is_ok()) {
+      value_y = raw_value_y.get_ok();
+    } else {
+      errors.append(raw_value_y.get_err().add_field("y"));
+    }
+    map.erase(location_y);
   }
-  Result<int> raw_value_y = location_y->second.to_value<int>();
-  if (!raw_value_y.is_ok()) {
-    Error error = raw_value_y.get_err();
-    error.add_field("y");
-    return Result<test::name::space::Point>::err(std::move(error));
+
+  if (!errors.empty()) {
+    return Result<test::name::space::Point, Errors>::err(std::move(errors));
   }
-  int value_y = raw_value_y.get_ok();
-  map.erase(location_y);
 
-  return Result<test::name::space::Point>::ok(test::name::space::Point(std::move(value_x), std::move(value_y), Map(std::move(map))));
+  return Result<test::name::space::Point, Errors>::ok(test::name::space::Point(std::move(*value_x), std::move(*value_y), Map(std::move(map))));
 }
 
 template<>
@@ -400,27 +425,27 @@ template<>
 }
 
 template<>
-[[nodiscard]] Result<test::name::space::State> Node::Map::to_value<test::name::space::State>() const {
+[[nodiscard]] Result<test::name::space::State, Errors> Node::Map::to_value_all<test::name::space::State>() const {
   if (map_.size() != 1) {
     std::stringstream ss;
     ss << "There must be exactly one enum type specified but received " << map_.size();
-    return Result<test::name::space::State>::err(Error(ss.str()));
+    return Result<test::name::space::State, Errors>::err(Errors(Error(ss.str())));
   }
 
   if (map_.cbegin()->first == "Filled") {
-    return Result<test::name::space::State>::err(Error("Enum type Filled must not include values"));
+    return Result<test::name::space::State, Errors>::err(Errors(Error("Enum type Filled must not include values")));
   }
   if (map_.cbegin()->first == "Edge") {
-    Result<test::name::space::SizeValue> value = map_.cbegin()->second.to_value<test::name::space::SizeValue>();
+    Result<test::name::space::SizeValue, Errors> value = map_.cbegin()->second.to_value_all<test::name::space::SizeValue>();
     if (value.is_ok()) {
-      return Result<test::name::space::State>::ok(test::name::space::State(test::name::space::State::TypeEdge{value.get_ok()}));
+      return Result<test::name::space::State, Errors>::ok(test::name::space::State(test::name::space::State::TypeEdge{value.get_ok()}));
     }
-    return Result<test::name::space::State>::err(value.get_err().add_field("Edge"));
+    return Result<test::name::space::State, Errors>::err(value.get_err().add_field("Edge"));
   }
 
   std::stringstream ss;
   ss << "Unknown enum type \"" << map_.cbegin()->first << "\"";
-  return Result<test::name::space::State>::err(Error(ss.str()));
+  return Result<test::name::space::State, Errors>::err(Errors(Error(ss.str())));
 }
 
 template<>
@@ -441,36 +466,41 @@ template<>
 }
 
 template<>
-[[nodiscard]] Result<test::name::space::DefaultValues> Node::Map::to_value<test::name::space::DefaultValues>() const {
+[[nodiscard]] Result<test::name::space::DefaultValues, Errors> Node::Map::to_value_all<test::name::space::DefaultValues>() const {
   std::map<std::string, Node> map = map_;
+  Errors errors;
 
   auto location_state = map.find("state");
   test::name::space::State value_state = test::name::space::DefaultValues::default_state();
   if (location_state != map.end()) {
-    Result<test::name::space::State> raw_value_state = location_state->second.to_value<test::name::space::State>();
-    if (!raw_value_state.is_ok()) {
-      Error error = raw_value_state.get_err();
-      error.add_field("state");
-      return Result<test::name::space::DefaultValues>::err(std::move(error));
+    Result<test::name::space::State, Errors> raw_value_state = location_state->second.to_value_all<test::name::space::State>();
+    if (raw_value_state.is_ok()) {
+      value_state = raw_value_state.get_ok();
+    } else {
+      errors.append(raw_value_state.get_err().add_field("state"));
     }
-    value_state = raw_value_state.get_ok();
     map.erase(location_state);
   }
 
   auto location_size = map.find("size");
+  std::optional<test::name::space::Size> value_size;
   if (location_size == map.end()) {
-    return Result<test::name::space::DefaultValues>::err(Error("Missing size"));
+    errors.add(Error("Missing size"));
+  } else {
+    Result<test::name::space::Size, Errors> raw_value_size = location_size->second.to_value_all<test::name::space::Size>();
+    if (raw_value_size.is_ok()) {
+      value_size = raw_value_size.get_ok();
+    } else {
+      errors.append(raw_value_size.get_err().add_field("size"));
+    }
+    map.erase(location_size);
   }
-  Result<test::name::space::Size> raw_value_size = location_size->second.to_value<test::name::space::Size>();
-  if (!raw_value_size.is_ok()) {
-    Error error = raw_value_size.get_err();
-    error.add_field("size");
-    return Result<test::name::space::DefaultValues>::err(std::move(error));
+
+  if (!errors.empty()) {
+    return Result<test::name::space::DefaultValues, Errors>::err(std::move(errors));
   }
-  test::name::space::Size value_size = raw_value_size.get_ok();
-  map.erase(location_size);
 
-  return Result<test::name::space::DefaultValues>::ok(test::name::space::DefaultValues(std::move(value_state), std::move(value_size), Map(std::move(map))));
+  return Result<test::name::space::DefaultValues, Errors>::ok(test::name::space::DefaultValues(std::move(value_state), std::move(*value_size), Map(std::move(map))));
 }
 
 template<>
@@ -485,49 +515,53 @@ template<>
 }
 
 template<>
-[[nodiscard]] Result<test::name::space::Rectangle> Node::Map::to_value<test::name::space::Rectangle>() const {
+[[nodiscard]] Result<test::name::space::Rectangle, Errors> Node::Map::to_value_all<test::name::space::Rectangle>() const {
   std::map<std::string, Node> map = map_;
+  Errors errors;
 
   auto location_center = map.find("center");
+  std::optional<test::name::space::Point> value_center;
   if (location_center == map.end()) {
-    return Result<test::name::space::Rectangle>::err(Error("Missing center"));
-  }
-  Result<test::name::space::Point> raw_value_center = location_center->second.to_value<test::name::space::Point>();
-  if (!raw_value_center.is_ok()) {
-    Error error = raw_value_center.get_err();
-    error.add_field("center");
-    return Result<test::name::space::Rectangle>::err(std::move(error));
+    errors.add(Error("Missing center"));
+  } else {
+    Result<test::name::space::Point, Errors> raw_value_center = location_center->second.to_value_all<test::name::space::Point>();
+    if (raw_value_center.is_ok()) {
+      value_center = raw_value_center.get_ok();
+    } else {
+      errors.append(raw_value_center.get_err().add_field("center"));
+    }
+    map.erase(location_center);
   }
-  test::name::space::Point value_center = raw_value_center.get_ok();
-  map.erase(location_center);
 
   auto location_size = map.find("size");
   std::optional<test::name::space::Size> value_size = test::name::space::Rectangle::default_size();
   if (location_size != map.end()) {
-    Result<test::name::space::Size> raw_value_size = location_size->second.to_value<test::name::space::Size>();
-    if (!raw_value_size.is_ok()) {
-      Error error = raw_value_size.get_err();
-      error.add_field("size");
-      return Result<test::name::space::Rectangle>::err(std::move(error));
+    Result<test::name::space::Size, Errors> raw_value_size = location_size->second.to_value_all<test::name::space::Size>();
+    if (raw_value_size.is_ok()) {
+      value_size = raw_value_size.get_ok();
+    } else {
+      errors.append(raw_value_size.get_err().add_field("size"));
     }
-    value_size = raw_value_size.get_ok();
     map.erase(location_size);
   }
 
   auto location_state = map.find("state");
   std::optional<test::name::space::State> value_state = test::name::space::Rectangle::default_state();
   if (location_state != map.end()) {
-    Result<test::name::space::State> raw_value_state = location_state->second.to_value<test::name::space::State>();
-    if (!raw_value_state.is_ok()) {
-      Error error = raw_value_state.get_err();
-      error.add_field("state");
-      return Result<test::name::space::Rectangle>::err(std::move(error));
+    Result<test::name::space::State, Errors> raw_value_state = location_state->second.to_value_all<test::name::space::State>();
+    if (raw_value_state.is_ok()) {
+      value_state = raw_value_state.get_ok();
+    } else {
+      errors.append(raw_value_state.get_err().add_field("state"));
     }
-    value_state = raw_value_state.get_ok();
     map.erase(location_state);
   }
 
-  return Result<test::name::space::Rectangle>::ok(test::name::space::Rectangle(std::move(value_center), std::move(value_size), std::move(value_state), Map(std::move(map))));
+  if (!errors.empty()) {
+    return Result<test::name::space::Rectangle, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::name::space::Rectangle, Errors>::ok(test::name::space::Rectangle(std::move(*value_center), std::move(value_size), std::move(value_state), Map(std::move(map))));
 }
 
 template<>
@@ -548,49 +582,55 @@ template<>
 }
 
 template<>
-[[nodiscard]] Result<test::name::space::Circle> Node::Map::to_value<test::name::space::Circle>() const {
+[[nodiscard]] Result<test::name::space::Circle, Errors> Node::Map::to_value_all<test::name::space::Circle>() const {
   std::map<std::string, Node> map = map_;
+  Errors errors;
 
   auto location_center = map.find("center");
+  std::optional<test::name::space::Point> value_center;
   if (location_center == map.end()) {
-    return Result<test::name::space::Circle>::err(Error("Missing center"));
-  }
-  Result<test::name::space::Point> raw_value_center = location_center->second.to_value<test::name::space::Point>();
-  if (!raw_value_center.is_ok()) {
-    Error error = raw_value_center.get_err();
-    error.add_field("center");
-    return Result<test::name::space::Circle>::err(std::move(error));
+    errors.add(Error("Missing center"));
+  } else {
+    Result<test::name::space::Point, Errors> raw_value_center = location_center->second.to_value_all<test::name::space::Point>();
+    if (raw_value_center.is_ok()) {
+      value_center = raw_value_center.get_ok();
+    } else {
+      errors.append(raw_value_center.get_err().add_field("center"));
+    }
+    map.erase(location_center);
   }
-  test::name::space::Point value_center = raw_value_center.get_ok();
-  map.erase(location_center);
 
   auto location_radius = map.find("radius");
+  std::optional<test::name::space::SizeValue> value_radius;
   if (location_radius == map.end()) {
-    return Result<test::name::space::Circle>::err(Error("Missing radius"));
-  }
-  Result<test::name::space::SizeValue> raw_value_radius = location_radius->second.to_value<test::name::space::SizeValue>();
-  if (!raw_value_radius.is_ok()) {
-    Error error = raw_value_radius.get_err();
-    error.add_field("radius");
-    return Result<test::name::space::Circle>::err(std::move(error));
+    errors.add(Error("Missing radius"));
+  } else {
+    Result<test::name::space::SizeValue, Errors> raw_value_radius = location_radius->second.to_value_all<test::name::space::SizeValue>();
+    if (raw_value_radius.is_ok()) {
+      value_radius = raw_value_radius.get_ok();
+    } else {
+      errors.append(raw_value_radius.get_err().add_field("radius"));
+    }
+    map.erase(location_radius);
   }
-  test::name::space::SizeValue value_radius = raw_value_radius.get_ok();
-  map.erase(location_radius);
 
   auto location_state = map.find("state");
   std::optional<test::name::space::State> value_state = test::name::space::Circle::default_state();
   if (location_state != map.end()) {
-    Result<test::name::space::State> raw_value_state = location_state->second.to_value<test::name::space::State>();
-    if (!raw_value_state.is_ok()) {
-      Error error = raw_value_state.get_err();
-      error.add_field("state");
-      return Result<test::name::space::Circle>::err(std::move(error));
+    Result<test::name::space::State, Errors> raw_value_state = location_state->second.to_value_all<test::name::space::State>();
+    if (raw_value_state.is_ok()) {
+      value_state = raw_value_state.get_ok();
+    } else {
+      errors.append(raw_value_state.get_err().add_field("state"));
     }
-    value_state = raw_value_state.get_ok();
     map.erase(location_state);
   }
 
-  return Result<test::name::space::Circle>::ok(test::name::space::Circle(std::move(value_center), std::move(value_radius), std::move(value_state), Map(std::move(map))));
+  if (!errors.empty()) {
+    return Result<test::name::space::Circle, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::name::space::Circle, Errors>::ok(test::name::space::Circle(std::move(*value_center), std::move(*value_radius), std::move(value_state), Map(std::move(map))));
 }
 
 template<>
@@ -609,26 +649,26 @@ template<>
 }
 
 template<>
-[[nodiscard]] Result<test::name::space::Geometry> Node::to_value<test::name::space::Geometry>() const {
+[[nodiscard]] Result<test::name::space::Geometry, Errors> Node::to_value_all<test::name::space::Geometry>() const {
   std::stringstream error;
   error << "Unable to parse any variant: [ ";
 
   Result<test::name::space::Circle> result_circle = to_value<test::name::space::Circle>();
   if (result_circle.is_ok()) {
-    return Result<test::name::space::Geometry>::ok(test::name::space::Geometry(result_circle.get_ok()));
+    return Result<test::name::space::Geometry, Errors>::ok(test::name::space::Geometry(result_circle.get_ok()));
   }
   error << "test::name::space::Circle { " << result_circle.get_err() << " }";
   error << ", ";
 
   Result<test::name::space::Rectangle> result_rectangle = to_value<test::name::space::Rectangle>();
   if (result_rectangle.is_ok()) {
-    return Result<test::name::space::Geometry>::ok(test::name::space::Geometry(result_rectangle.get_ok()));
+    return Result<test::name::space::Geometry, Errors>::ok(test::name::space::Geometry(result_rectangle.get_ok()));
   }
   error << "test::name::space::Rectangle { " << result_rectangle.get_err() << " }";
 
   error << " ]";
 
-  return Result<test::name::space::Geometry>::err(Error(error.str()));
+  return Result<test::name::space::Geometry, Errors>::err(Errors(Error(error.str()).set_mark(mark_)));
 }
 
 template<>
@@ -639,20 +679,23 @@ template<>
 }
 
 template<>
-[[nodiscard]] Result<test::name::space::GeometryList> Node::List::to_value<test::name::space::GeometryList>() const {
+[[nodiscard]] Result<test::name::space::GeometryList, Errors> Node::List::to_value_all<test::name::space::GeometryList>() const {
   std::vector<test::name::space::Geometry> values;
   values.reserve(list_.size());
+  Errors errors;
   for (auto node = list_.cbegin(); node < list_.cend(); ++node) {
-    Result<test::name::space::Geometry> value = node->to_value<test::name::space::Geometry>();
-    if (!value.is_ok()) {
-      Error error = value.get_err();
-      error.add_list(node - list_.cbegin());
-      return Result<test::name::space::GeometryList>::err(std::move(error));
+    Result<test::name::space::Geometry, Errors> value = node->to_value_all<test::name::space::Geometry>();
+    if (value.is_ok()) {
+      values.push_back(std::move(value.get_ok()));
+    } else {
+      errors.append(value.get_err().add_list(node - list_.cbegin()));
     }
-    values.push_back(std::move(value.get_ok()));
+  }
+  if (!errors.empty()) {
+    return Result<test::name::space::GeometryList, Errors>::err(std::move(errors));
   }
 
-  return Result<test::name::space::GeometryList>::ok(test::name::space::GeometryList(std::move(values)));
+  return Result<test::name::space::GeometryList, Errors>::ok(test::name::space::GeometryList(std::move(values)));
 }
 
 template<>
@@ -666,49 +709,55 @@ template<>
 }
 
 template<>
-[[nodiscard]] Result<test::name::space::DataModel> Node::Map::to_value<test::name::space::DataModel>() const {
+[[nodiscard]] Result<test::name::space::DataModel, Errors> Node::Map::to_value_all<test::name::space::DataModel>() const {
   std::map<std::string, Node> map = map_;
+  Errors errors;
 
   auto location_version = map.find("version");
   test::name::space::VersionString value_version = test::name::space::DataModel::default_version();
   if (location_version != map.end()) {
-    Result<test::name::space::VersionString> raw_value_version = location_version->second.to_value<test::name::space::VersionString>();
-    if (!raw_value_version.is_ok()) {
-      Error error = raw_value_version.get_err();
-      error.add_field("version");
-      return Result<test::name::space::DataModel>::err(std::move(error));
+    Result<test::name::space::VersionString, Errors> raw_value_version = location_version->second.to_value_all<test::name::space::VersionString>();
+    if (raw_value_version.is_ok()) {
+      value_version = raw_value_version.get_ok();
+    } else {
+      errors.append(raw_value_version.get_err().add_field("version"));
     }
-    value_version = raw_value_version.get_ok();
     map.erase(location_version);
   }
 
   auto location_defaults = map.find("defaults");
+  std::optional<test::name::space::DefaultValues> value_defaults;
   if (location_defaults == map.end()) {
-    return Result<test::name::space::DataModel>::err(Error("Missing defaults"));
-  }
-  Result<test::name::space::DefaultValues> raw_value_defaults = location_defaults->second.to_value<test::name::space::DefaultValues>();
-  if (!raw_value_defaults.is_ok()) {
-    Error error = raw_value_defaults.get_err();
-    error.add_field("defaults");
-    return Result<test::name::space::DataModel>::err(std::move(error));
+    errors.add(Error("Missing defaults"));
+  } else {
+    Result<test::name::space::DefaultValues, Errors> raw_value_defaults = location_defaults->second.to_value_all<test::name::space::DefaultValues>();
+    if (raw_value_defaults.is_ok()) {
+      value_defaults = raw_value_defaults.get_ok();
+    } else {
+      errors.append(raw_value_defaults.get_err().add_field("defaults"));
+    }
+    map.erase(location_defaults);
   }
-  test::name::space::DefaultValues value_defaults = raw_value_defaults.get_ok();
-  map.erase(location_defaults);
 
   auto location_geometries = map.find("geometries");
+  std::optional<test::name::space::GeometryList> value_geometries;
   if (location_geometries == map.end()) {
-    return Result<test::name::space::DataModel>::err(Error("Missing geometries"));
+    errors.add(Error("Missing geometries"));
+  } else {
+    Result<test::name::space::GeometryList, Errors> raw_value_geometries = location_geometries->second.to_value_all<test::name::space::GeometryList>();
+    if (raw_value_geometries.is_ok()) {
+      value_geometries = raw_value_geometries.get_ok();
+    } else {
+      errors.append(raw_value_geometries.get_err().add_field("geometries"));
+    }
+    map.erase(location_geometries);
   }
-  Result<test::name::space::GeometryList> raw_value_geometries = location_geometries->second.to_value<test::name::space::GeometryList>();
-  if (!raw_value_geometries.is_ok()) {
-    Error error = raw_value_geometries.get_err();
-    error.add_field("geometries");
-    return Result<test::name::space::DataModel>::err(std::move(error));
+
+  if (!errors.empty()) {
+    return Result<test::name::space::DataModel, Errors>::err(std::move(errors));
   }
-  test::name::space::GeometryList value_geometries = raw_value_geometries.get_ok();
-  map.erase(location_geometries);
 
-  return Result<test::name::space::DataModel>::ok(test::name::space::DataModel(std::move(value_version), std::move(value_defaults), std::move(value_geometries), Map(std::move(map))));
+  return Result<test::name::space::DataModel, Errors>::ok(test::name::space::DataModel(std::move(value_version), std::move(*value_defaults), std::move(*value_geometries), Map(std::move(map))));
 }
 
 template<>
@@ -10,7 +10,6 @@
 #include <termite.hpp>
 
 // This is my .h file
-#include "custom.h"
 #include <string>
 
 using String = std::string;
@@ -40,7 +39,7 @@ public:
 
   /**
    * @brief Sets the value if it fulfills the constraints:
-   * - custom::is_valid_version_string(x)
+   * - matches(x, "^[0-9]+(\\.[0-9]+){0,2}$")
    * 
    * @param value The value to set
    * @return An error if one of the constraints were not fulfilled
@@ -92,7 +91,7 @@ private:
 
   /**
    * @brief Validates if value is correct using the following constraints:
-   * - custom::is_valid_version_string(x)
+   * - matches(x, "^[0-9]+(\\.[0-9]+){0,2}$")
    * 
    * @param x The value of the parameter to validate
    */
@@ -827,70 +826,103 @@ public:
 namespace termite {
 
 template<>
-[[nodiscard]] Result<test::name::space::VersionString> Node::to_value<test::name::space::VersionString>() const;
+struct has_to_value_all<test::name::space::VersionString> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::name::space::VersionString, Errors> Node::to_value_all<test::name::space::VersionString>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::name::space::VersionString>(const test::name::space::VersionString &value);
 
 template<>
-[[nodiscard]] Result<test::name::space::SizeValue> Node::to_value<test::name::space::SizeValue>() const;
+struct has_to_value_all<test::name::space::SizeValue> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::name::space::SizeValue, Errors> Node::to_value_all<test::name::space::SizeValue>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::name::space::SizeValue>(const test::name::space::SizeValue &value);
 
 template<>
-[[nodiscard]] Result<test::name::space::Size> Node::Map::to_value<test::name::space::Size>() const;
+struct has_to_value_all<test::name::space::Size> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::name::space::Size, Errors> Node::Map::to_value_all<test::name::space::Size>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::name::space::Size>(const test::name::space::Size &value);
 
 template<>
-[[nodiscard]] Result<test::name::space::Point> Node::Map::to_value<test::name::space::Point>() const;
+struct has_to_value_all<test::name::space::Point> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::name::space::Point, Errors> Node::Map::to_value_all<test::name::space::Point>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::name::space::Point>(const test::name::space::Point &value);
 
+template<>
+struct has_to_value_all<test::name::space::State> : std::true_type {};
+
 template<>
 [[nodiscard]] Result<test::name::space::State> Node::Value::to_value<test::name::space::State>() const;
 
 template<>
-[[nodiscard]] Result<test::name::space::State> Node::Map::to_value<test::name::space::State>() const;
+[[nodiscard]] Result<test::name::space::State, Errors> Node::Map::to_value_all<test::name::space::State>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::name::space::State>(const test::name::space::State &value);
 
 template<>
-[[nodiscard]] Result<test::name::space::DefaultValues> Node::Map::to_value<test::name::space::DefaultValues>() const;
+struct has_to_value_all<test::name::space::DefaultValues> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::name::space::DefaultValues, Errors> Node::Map::to_value_all<test::name::space::DefaultValues>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::name::space::DefaultValues>(const test::name::space::DefaultValues &value);
 
 template<>
-[[nodiscard]] Result<test::name::space::Rectangle> Node::Map::to_value<test::name::space::Rectangle>() const;
+struct has_to_value_all<test::name::space::Rectangle> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::name::space::Rectangle, Errors> Node::Map::to_value_all<test::name::space::Rectangle>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::name::space::Rectangle>(const test::name::space::Rectangle &value);
 
 template<>
-[[nodiscard]] Result<test::name::space::Circle> Node::Map::to_value<test::name::space::Circle>() const;
+struct has_to_value_all<test::name::space::Circle> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::name::space::Circle, Errors> Node::Map::to_value_all<test::name::space::Circle>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::name::space::Circle>(const test::name::space::Circle &value);
 
 template<>
-[[nodiscard]] Result<test::name::space::Geometry> Node::to_value<test::name::space::Geometry>() const;
+struct has_to_value_all<test::name::space::Geometry> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::name::space::Geometry, Errors> Node::to_value_all<test::name::space::Geometry>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::name::space::Geometry>(const test::name::space::Geometry &value);
 
 template<>
-[[nodiscard]] Result<test::name::space::GeometryList> Node::List::to_value<test::name::space::GeometryList>() const;
+struct has_to_value_all<test::name::space::GeometryList> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::name::space::GeometryList, Errors> Node::List::to_value_all<test::name::space::GeometryList>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::name::space::GeometryList>(const test::name::space::GeometryList &value);
 
 template<>
-[[nodiscard]] Result<test::name::space::DataModel> Node::Map::to_value<test::name::space::DataModel>() const;
+struct has_to_value_all<test::name::space::DataModel> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::name::space::DataModel, Errors> Node::Map::to_value_all<test::name::space::DataModel>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::name::space::DataModel>(const test::name::space::DataModel &value);
@@ -0,0 +1,359 @@
+// Generated with the Termite Data Model Generator
+#include "imports.h"
+
+
+
+namespace geometry {
+
+namespace {
+
+// Code to make printing easier
+template <typename T, typename = void>
+struct has_insertion_operator : std::false_type {};
+template <typename T>
+struct has_insertion_operator<T, std::void_t<decltype(std::declval<std::ostream &>() << std::declval<T>())>> : std::true_type {};
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::optional<T> &value) {
+  if (value) {
+    return os << *value;
+  } else {
+    return os << "nullopt";
+  }
+}
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::vector<T> &value) {
+  os << "[ ";
+  for (auto value_it = value.cbegin(); value_it != value.cend(); ++value_it) {
+    if (value_it != value.cbegin()) {
+      os << ", ";
+    }
+    os << *value_it;
+  }
+  return os << " ]";
+}
+
+} // namespace
+
+[[nodiscard]] termite::Result<Radius> Radius::from_value(::shared::PositiveDouble value) {
+  termite::Result<termite::Empty> validate_result = validate(value);
+  if (!validate_result.is_ok()) {
+    termite::Error error = validate_result.get_err();
+    return termite::Result<Radius>::err(std::move(error));
+  }
+
+  return termite::Result<Radius>::ok(Radius(std::move(value), nullptr));
+}
+
+[[nodiscard]] termite::Result<termite::Empty> Radius::set(::shared::PositiveDouble value) {
+  termite::Result<termite::Empty> validate_result = validate(value);
+  if (!validate_result.is_ok()) {
+    return validate_result;
+  }
+
+  value_ = std::move(value);
+  return termite::Result<termite::Empty>::ok(termite::Empty());
+}
+
+[[nodiscard]] bool Radius::operator==(const Radius &x) const {
+  return value_ == x.value_;
+}
+std::ostream &operator<<(std::ostream &os, const Radius &x) {
+  return os << x.value_;
+}
+
+[[nodiscard]] termite::Result<termite::Empty> Radius::validate(const ::shared::PositiveDouble &x) {
+  if (!(x.get() < 100.0)) {
+    return termite::Result<termite::Empty>::err(termite::Error("Did not pass constraint: x < 100.0"));
+  }
+
+  return termite::Result<termite::Empty>::ok(termite::Empty());
+}
+
+[[nodiscard]] bool Circle::operator==(const Circle &x) const {
+  return this->x == x.x && this->y == x.y && this->radius == x.radius && extra_fields == x.extra_fields;
+}
+
+[[nodiscard]] termite::number Circle::default_x() {
+  auto node = termite::Node(termite::Node::Value("0.0"));
+
+  return node.to_value<termite::number>().get_ok();
+}
+
+[[nodiscard]] termite::number Circle::default_y() {
+  auto node = termite::Node(termite::Node::Value("0.0"));
+
+  return node.to_value<termite::number>().get_ok();
+}
+
+std::ostream &operator<<(std::ostream &os, const Circle &x) {
+  return os << "{ " << "x: " << x.x << ", " << "y: " << x.y << ", " << "radius: " << x.radius << ", " << "extra_fields: " << x.extra_fields << " }";
+}
+
+bool Polygon::operator==(const Polygon &x) const {
+  if (values.size() != x.values.size()) {
+    return false;
+  }
+
+  for (auto lhs = values.cbegin(), rhs = x.values.cbegin(); lhs < values.cend(); ++lhs, ++rhs) {
+    if (*lhs != *rhs) {
+      return false;
+    }
+  }
+
+  return true;
+}
+
+std::ostream &operator<<(std::ostream &os, const Polygon &x) {
+  os << "{ values: [ ";
+  for (auto value = x.values.cbegin(); value < x.values.cend(); ++value) {
+    if (value != x.values.cbegin()) {
+      os << ", ";
+    }
+    os << *value;
+  }
+  return os << " ] }";
+}
+
+[[nodiscard]] bool Offset::operator==(const Offset &x) const {
+  return this->point == x.point && this->angle == x.angle && extra_fields == x.extra_fields;
+}
+
+[[nodiscard]] ::shared::Point Offset::default_point() {
+  auto node = termite::Node(termite::Node::Map({
+    {"y", termite::Node(termite::Node::Value("0.0"))},
+    {"x", termite::Node(termite::Node::Value("1.0"))},
+  }));
+
+  return node.to_value<::shared::Point>().get_ok();
+}
+
+[[nodiscard]] termite::number Offset::default_angle() {
+  auto node = termite::Node(termite::Node::Value("0.0"));
+
+  return node.to_value<termite::number>().get_ok();
+}
+
+std::ostream &operator<<(std::ostream &os, const Offset &x) {
+  return os << "{ " << "point: " << x.point << ", " << "angle: " << x.angle << ", " << "extra_fields: " << x.extra_fields << " }";
+}
+
+[[nodiscard]] bool Location::operator==(const Location &x) const {
+  return value == x.value;
+}
+
+std::ostream &operator<<(std::ostream &os, const Location &x) {
+  os << "{ value: ";
+  switch (x.value.index()) {
+  case 0:
+    os << "::shared::Point " << std::get<::shared::Point>(x.value);
+    break;
+  case 1:
+    os << "termite::string " << std::get<termite::string>(x.value);
+    break;
+  default:
+    os << "Unknown(" << x.value.index() << ")";
+    break;
+  }
+  return os << " }";
+}
+
+} // namespace geometry
+
+namespace termite {
+
+template<>
+[[nodiscard]] Result<geometry::Radius, Errors> Node::to_value_all<geometry::Radius>() const {
+  Result<::shared::PositiveDouble, Errors> value = to_value_all<::shared::PositiveDouble>();
+  if (!value.is_ok()) {
+    return Result<geometry::Radius, Errors>::err(value.get_err());
+  }
+
+  Result<geometry::Radius> result = geometry::Radius::from_value(value.get_ok());
+  if (!result.is_ok()) {
+    return Result<geometry::Radius, Errors>::err(Errors(result.get_err().set_mark(mark_)));
+  }
+  return Result<geometry::Radius, Errors>::ok(result.get_ok());
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<geometry::Radius>(const geometry::Radius &value) {
+  return Node::from_value(value.get());
+}
+
+template<>
+[[nodiscard]] Result<geometry::Circle, Errors> Node::Map::to_value_all<geometry::Circle>() const {
+  std::map<std::string, Node> map = map_;
+  Errors errors;
+
+  auto location_x = map.find("x");
+  number value_x = geometry::Circle::default_x();
+  if (location_x != map.end()) {
+    Result<number, Errors> raw_value_x = location_x->second.to_value_all<number>();
+    if (raw_value_x.is_ok()) {
+      value_x = raw_value_x.get_ok();
+    } else {
+      errors.append(raw_value_x.get_err().add_field("x"));
+    }
+    map.erase(location_x);
+  }
+
+  auto location_y = map.find("y");
+  number value_y = geometry::Circle::default_y();
+  if (location_y != map.end()) {
+    Result<number, Errors> raw_value_y = location_y->second.to_value_all<number>();
+    if (raw_value_y.is_ok()) {
+      value_y = raw_value_y.get_ok();
+    } else {
+      errors.append(raw_value_y.get_err().add_field("y"));
+    }
+    map.erase(location_y);
+  }
+
+  auto location_radius = map.find("radius");
+  std::optional<geometry::Radius> value_radius;
+  if (location_radius == map.end()) {
+    errors.add(Error("Missing radius"));
+  } else {
+    Result<geometry::Radius, Errors> raw_value_radius = location_radius->second.to_value_all<geometry::Radius>();
+    if (raw_value_radius.is_ok()) {
+      value_radius = raw_value_radius.get_ok();
+    } else {
+      errors.append(raw_value_radius.get_err().add_field("radius"));
+    }
+    map.erase(location_radius);
+  }
+
+  if (!errors.empty()) {
+    return Result<geometry::Circle, Errors>::err(std::move(errors));
+  }
+
+  return Result<geometry::Circle, Errors>::ok(geometry::Circle(std::move(value_x), std::move(value_y), std::move(*value_radius), Map(std::move(map))));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<geometry::Circle>(const geometry::Circle &value) {
+  std::map<std::string, Node> map = value.extra_fields.get();
+
+  map.insert({"x", Node::from_value(value.x)});
+
+  map.insert({"y", Node::from_value(value.y)});
+
+  map.insert({"radius", Node::from_value(value.radius)});
+
+  return Node(Node::Map(std::move(map)));
+}
+
+template<>
+[[nodiscard]] Result<geometry::Polygon, Errors> Node::List::to_value_all<geometry::Polygon>() const {
+  std::vector<::shared::Point> values;
+  values.reserve(list_.size());
+  Errors errors;
+  for (auto node = list_.cbegin(); node < list_.cend(); ++node) {
+    Result<::shared::Point, Errors> value = node->to_value_all<::shared::Point>();
+    if (value.is_ok()) {
+      values.push_back(std::move(value.get_ok()));
+    } else {
+      errors.append(value.get_err().add_list(node - list_.cbegin()));
+    }
+  }
+  if (!errors.empty()) {
+    return Result<geometry::Polygon, Errors>::err(std::move(errors));
+  }
+
+  return Result<geometry::Polygon, Errors>::ok(geometry::Polygon(std::move(values)));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<geometry::Polygon>(const geometry::Polygon &value) {
+  std::vector<Node> list;
+  list.reserve(value.values.size());
+  std::transform(value.values.cbegin(), value.values.cend(), std::back_inserter(list), [](const ::shared::Point &value) {
+    return Node::from_value(value);
+  });
+  return Node(Node::List(std::move(list)));
+}
+
+template<>
+[[nodiscard]] Result<geometry::Offset, Errors> Node::Map::to_value_all<geometry::Offset>() const {
+  std::map<std::string, Node> map = map_;
+  Errors errors;
+
+  auto location_point = map.find("point");
+  ::shared::Point value_point = geometry::Offset::default_point();
+  if (location_point != map.end()) {
+    Result<::shared::Point, Errors> raw_value_point = location_point->second.to_value_all<::shared::Point>();
+    if (raw_value_point.is_ok()) {
+      value_point = raw_value_point.get_ok();
+    } else {
+      errors.append(raw_value_point.get_err().add_field("point"));
+    }
+    map.erase(location_point);
+  }
+
+  auto location_angle = map.find("angle");
+  number value_angle = geometry::Offset::default_angle();
+  if (location_angle != map.end()) {
+    Result<number, Errors> raw_value_angle = location_angle->second.to_value_all<number>();
+    if (raw_value_angle.is_ok()) {
+      value_angle = raw_value_angle.get_ok();
+    } else {
+      errors.append(raw_value_angle.get_err().add_field("angle"));
+    }
+    map.erase(location_angle);
+  }
+
+  if (!errors.empty()) {
+    return Result<geometry::Offset, Errors>::err(std::move(errors));
+  }
+
+  return Result<geometry::Offset, Errors>::ok(geometry::Offset(std::move(value_point), std::move(value_angle), Map(std::move(map))));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<geometry::Offset>(const geometry::Offset &value) {
+  std::map<std::string, Node> map = value.extra_fields.get();
+
+  map.insert({"point", Node::from_value(value.point)});
+
+  map.insert({"angle", Node::from_value(value.angle)});
+
+  return Node(Node::Map(std::move(map)));
+}
+
+template<>
+[[nodiscard]] Result<geometry::Location, Errors> Node::to_value_all<geometry::Location>() const {
+  std::stringstream error;
+  error << "Unable to parse any variant: [ ";
+
+  Result<::shared::Point> result_point = to_value<::shared::Point>();
+  if (result_point.is_ok()) {
+    return Result<geometry::Location, Errors>::ok(geometry::Location(result_point.get_ok()));
+  }
+  error << "::shared::Point { " << result_point.get_err() << " }";
+  error << ", ";
+
+  Result<string> result_string = to_value<string>();
+  if (result_string.is_ok()) {
+    return Result<geometry::Location, Errors>::ok(geometry::Location(result_string.get_ok()));
+  }
+  error << "string { " << result_string.get_err() << " }";
+
+  error << " ]";
+
+  return Result<geometry::Location, Errors>::err(Errors(Error(error.str()).set_mark(mark_)));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<geometry::Location>(const geometry::Location &value) {
+  return std::visit([](const auto &x) {
+    return Node::from_value(x);
+  }, value.value);
+}
+
+} // namespace termite
+
+
@@ -0,0 +1,392 @@
+// Generated with the Termite Data Model Generator
+#ifndef IMPORTS_TERMITE_H_INCLUDED
+#define IMPORTS_TERMITE_H_INCLUDED
+
+#include <iostream>
+#include <sstream>
+#include <optional>
+#include <variant>
+#include <algorithm>
+#include <termite.hpp>
+#include "shared.h"
+
+
+
+namespace geometry {
+
+/**
+ * @brief The radius of a circle
+ * 
+ */
+class Radius {
+public:
+  /**
+   * @brief Constructs a new Radius object, it must be valid or an exception will be thrown
+   * 
+   * @param value The value to store 
+   */
+  explicit Radius(::shared::PositiveDouble value) : Radius(from_value(std::move(value)).get_ok()) {}
+  /**
+   * @brief Constructs a new Radius object
+   * 
+   * @param value The value to store 
+   * @return The new constrained type or an error if some constraints were not upheld
+   */
+  [[nodiscard]] static termite::Result<Radius> from_value(::shared::PositiveDouble value);
+
+  /**
+   * @brief Sets the value if it fulfills the constraints:
+   * - x < 100.0
+   * 
+   * @param value The value to set
+   * @return An error if one of the constraints were not fulfilled
+   */
+  [[nodiscard]] termite::Result<termite::Empty> set(::shared::PositiveDouble value);
+
+  /**
+   * @brief Retrieves a reference to the value
+   * 
+   * @return The reference
+   */
+  [[nodiscard]] const ::shared::PositiveDouble &get() const {
+    return value_;
+  }
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Radius &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Radius &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Radius &x);
+
+private:
+  /**
+   * @brief Constructs a new Radius object
+   * 
+   * @param value The value to store
+   * @param _ A nullptr
+   */
+  explicit Radius(::shared::PositiveDouble value, void *) : value_(std::move(value)) {}
+
+  /**
+   * @brief Validates if value is correct using the following constraints:
+   * - x < 100.0
+   * 
+   * @param x The value of the parameter to validate
+   */
+  [[nodiscard]] static termite::Result<termite::Empty> validate(const ::shared::PositiveDouble &x);
+
+  /**
+   * @brief The validated value
+   * 
+   */
+  ::shared::PositiveDouble value_;
+};
+
+/**
+ * @brief A circle centered at a point
+ * 
+ */
+struct Circle {
+public:
+  /**
+   * @brief Constructs a new Circle object
+   * 
+   * @param x The x coordinate of the point
+   * @param y The y coordinate of the point
+   * @param radius The radius of the circle
+   * @param extra_fields Any extra fields to attach to this struct
+   */
+  explicit Circle(termite::number x, termite::number y, Radius radius, ::termite::Node::Map extra_fields = ::termite::Node::Map()) : x(std::move(x)), y(std::move(y)), radius(std::move(radius)), extra_fields(std::move(extra_fields)) {}
+
+  /**
+   * @brief Gets the default value for x
+   * 
+   * @return The default value for x
+   */
+  [[nodiscard]] static termite::number default_x();
+  /**
+   * @brief Gets the default value for y
+   * 
+   * @return The default value for y
+   */
+  [[nodiscard]] static termite::number default_y();
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Circle &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Circle &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Circle &x);
+
+  /**
+   * @brief The x coordinate of the point
+   * 
+   */
+  termite::number x;
+  /**
+   * @brief The y coordinate of the point
+   * 
+   */
+  termite::number y;
+  /**
+   * @brief The radius of the circle
+   * 
+   */
+  Radius radius;
+  /**
+   * @brief All extra fields from when reading which could not be captured
+   * 
+   */
+  ::termite::Node::Map extra_fields;
+};
+
+/**
+ * @brief A list of corners
+ * 
+ */
+struct Polygon {
+public:
+  /**
+   * @brief Constructs a new Polygon object
+   * 
+   * @param values The values of the array
+   */
+  explicit Polygon(std::vector<::shared::Point> values) : values(std::move(values)) {}
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Polygon &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Polygon &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Polygon &x);
+
+  /**
+   * @brief The values of the array
+   * 
+   */
+  std::vector<::shared::Point> values;
+};
+
+/**
+ * @brief An offset from the origin
+ * 
+ */
+struct Offset {
+public:
+  /**
+   * @brief Constructs a new Offset object
+   * 
+   * @param point The offset
+   * @param angle The rotation around the offset
+   * @param extra_fields Any extra fields to attach to this struct
+   */
+  explicit Offset(::shared::Point point, termite::number angle, ::termite::Node::Map extra_fields = ::termite::Node::Map()) : point(std::move(point)), angle(std::move(angle)), extra_fields(std::move(extra_fields)) {}
+
+  /**
+   * @brief Gets the default value for point
+   * 
+   * @return The default value for point
+   */
+  [[nodiscard]] static ::shared::Point default_point();
+  /**
+   * @brief Gets the default value for angle
+   * 
+   * @return The default value for angle
+   */
+  [[nodiscard]] static termite::number default_angle();
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Offset &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Offset &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Offset &x);
+
+  /**
+   * @brief The offset
+   * 
+   */
+  ::shared::Point point;
+  /**
+   * @brief The rotation around the offset
+   * 
+   */
+  termite::number angle;
+  /**
+   * @brief All extra fields from when reading which could not be captured
+   * 
+   */
+  ::termite::Node::Map extra_fields;
+};
+
+/**
+ * @brief A named location or a point
+ * 
+ */
+struct Location {
+  /**
+   * @brief Constructs a new Location object
+   * 
+   * @param value The value of the variant
+   */
+  explicit Location(std::variant<::shared::Point, termite::string> value) : value(std::move(value)) {}
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Location &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Location &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Location &x);
+
+  /**
+   * @brief The value of the variant
+   * 
+   */
+  std::variant<::shared::Point, termite::string> value;
+};
+
+} // namespace geometry
+
+namespace termite {
+
+template<>
+struct has_to_value_all<geometry::Radius> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<geometry::Radius, Errors> Node::to_value_all<geometry::Radius>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<geometry::Radius>(const geometry::Radius &value);
+
+template<>
+struct has_to_value_all<geometry::Circle> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<geometry::Circle, Errors> Node::Map::to_value_all<geometry::Circle>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<geometry::Circle>(const geometry::Circle &value);
+
+template<>
+struct has_to_value_all<geometry::Polygon> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<geometry::Polygon, Errors> Node::List::to_value_all<geometry::Polygon>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<geometry::Polygon>(const geometry::Polygon &value);
+
+template<>
+struct has_to_value_all<geometry::Offset> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<geometry::Offset, Errors> Node::Map::to_value_all<geometry::Offset>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<geometry::Offset>(const geometry::Offset &value);
+
+template<>
+struct has_to_value_all<geometry::Location> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<geometry::Location, Errors> Node::to_value_all<geometry::Location>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<geometry::Location>(const geometry::Location &value);
+
+} // namespace termite
+
+
+
+#endif
@@ -0,0 +1,70 @@
+#include <iostream>
+#include "imports.h"
+
+int main() {
+  // Constrained types of imported types
+  auto radius = geometry::Radius::from_value(shared::PositiveDouble::from_value(2.0).get_ok());
+  if (!radius.is_ok()) {
+    return 1;
+  }
+  if (geometry::Radius::from_value(shared::PositiveDouble::from_value(200.0).get_ok()).is_ok()) {
+    return 2;
+  }
+
+  // Inheriting from an imported struct
+  auto circle = geometry::Circle(1.0, 2.0, radius.get_ok());
+  if (geometry::Circle::default_x() != 0.0) {
+    return 3;
+  }
+  auto circle_read = termite::Node::from_value(circle).to_value<geometry::Circle>();
+  if (!circle_read.is_ok()) {
+    return 4;
+  }
+  if (circle_read.get_ok() != circle) {
+    return 5;
+  }
+
+  // Arrays of imported types
+  auto polygon = geometry::Polygon({shared::Point(0.0, 0.0), shared::Point(1.0, 0.0)});
+  auto polygon_read = termite::Node::from_value(polygon).to_value<geometry::Polygon>();
+  if (!polygon_read.is_ok()) {
+    return 6;
+  }
+  if (polygon_read.get_ok() != polygon) {
+    return 7;
+  }
+
+  // Default values of imported types using imported macros
+  if (geometry::Offset::default_point() != shared::Point(1.0, 0.0)) {
+    return 8;
+  }
+  if (geometry::Offset::default_angle() != 0.0) {
+    return 9;
+  }
+  auto offset_read = termite::Node(termite::Node::Map()).to_value<geometry::Offset>();
+  if (!offset_read.is_ok()) {
+    return 10;
+  }
+  if (offset_read.get_ok().point != shared::Point(1.0, 0.0)) {
+    return 11;
+  }
+
+  // Variants of imported types
+  auto location = geometry::Location(shared::Point(1.0, 2.0));
+  auto location_read = termite::Node::from_value(location).to_value<geometry::Location>();
+  if (!location_read.is_ok()) {
+    return 12;
+  }
+  if (location_read.get_ok() != location) {
+    return 13;
+  }
+  auto name_read = termite::Node(termite::Node::Value("home")).to_value<geometry::Location>();
+  if (!name_read.is_ok()) {
+    return 14;
+  }
+  if (name_read.get_ok() != geometry::Location(std::string("home"))) {
+    return 15;
+  }
+
+  return 0;
+}
@@ -0,0 +1,168 @@
+// Generated with the Termite Data Model Generator
+#include "shared.h"
+
+
+
+namespace shared {
+
+namespace {
+
+// Code to make printing easier
+template <typename T, typename = void>
+struct has_insertion_operator : std::false_type {};
+template <typename T>
+struct has_insertion_operator<T, std::void_t<decltype(std::declval<std::ostream &>() << std::declval<T>())>> : std::true_type {};
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::optional<T> &value) {
+  if (value) {
+    return os << *value;
+  } else {
+    return os << "nullopt";
+  }
+}
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::vector<T> &value) {
+  os << "[ ";
+  for (auto value_it = value.cbegin(); value_it != value.cend(); ++value_it) {
+    if (value_it != value.cbegin()) {
+      os << ", ";
+    }
+    os << *value_it;
+  }
+  return os << " ]";
+}
+
+} // namespace
+
+[[nodiscard]] termite::Result<PositiveDouble> PositiveDouble::from_value(termite::number value) {
+  termite::Result<termite::Empty> validate_result = validate(value);
+  if (!validate_result.is_ok()) {
+    termite::Error error = validate_result.get_err();
+    return termite::Result<PositiveDouble>::err(std::move(error));
+  }
+
+  return termite::Result<PositiveDouble>::ok(PositiveDouble(std::move(value), nullptr));
+}
+
+[[nodiscard]] termite::Result<termite::Empty> PositiveDouble::set(termite::number value) {
+  termite::Result<termite::Empty> validate_result = validate(value);
+  if (!validate_result.is_ok()) {
+    return validate_result;
+  }
+
+  value_ = std::move(value);
+  return termite::Result<termite::Empty>::ok(termite::Empty());
+}
+
+[[nodiscard]] bool PositiveDouble::operator==(const PositiveDouble &x) const {
+  return value_ == x.value_;
+}
+std::ostream &operator<<(std::ostream &os, const PositiveDouble &x) {
+  return os << x.value_;
+}
+
+[[nodiscard]] termite::Result<termite::Empty> PositiveDouble::validate(const termite::number &x) {
+  if (!(x > 0.0)) {
+    return termite::Result<termite::Empty>::err(termite::Error("Did not pass constraint: x > 0.0"));
+  }
+
+  return termite::Result<termite::Empty>::ok(termite::Empty());
+}
+
+[[nodiscard]] bool Point::operator==(const Point &x) const {
+  return this->x == x.x && this->y == x.y && extra_fields == x.extra_fields;
+}
+
+[[nodiscard]] termite::number Point::default_x() {
+  auto node = termite::Node(termite::Node::Value("0.0"));
+
+  return node.to_value<termite::number>().get_ok();
+}
+
+[[nodiscard]] termite::number Point::default_y() {
+  auto node = termite::Node(termite::Node::Value("0.0"));
+
+  return node.to_value<termite::number>().get_ok();
+}
+
+std::ostream &operator<<(std::ostream &os, const Point &x) {
+  return os << "{ " << "x: " << x.x << ", " << "y: " << x.y << ", " << "extra_fields: " << x.extra_fields << " }";
+}
+
+} // namespace shared
+
+namespace termite {
+
+template<>
+[[nodiscard]] Result<shared::PositiveDouble, Errors> Node::to_value_all<shared::PositiveDouble>() const {
+  Result<number, Errors> value = to_value_all<number>();
+  if (!value.is_ok()) {
+    return Result<shared::PositiveDouble, Errors>::err(value.get_err());
+  }
+
+  Result<shared::PositiveDouble> result = shared::PositiveDouble::from_value(value.get_ok());
+  if (!result.is_ok()) {
+    return Result<shared::PositiveDouble, Errors>::err(Errors(result.get_err().set_mark(mark_)));
+  }
+  return Result<shared::PositiveDouble, Errors>::ok(result.get_ok());
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<shared::PositiveDouble>(const shared::PositiveDouble &value) {
+  return Node::from_value(value.get());
+}
+
+template<>
+[[nodiscard]] Result<shared::Point, Errors> Node::Map::to_value_all<shared::Point>() const {
+  std::map<std::string, Node> map = map_;
+  Errors errors;
+
+  auto location_x = map.find("x");
+  number value_x = shared::Point::default_x();
+  if (location_x != map.end()) {
+    Result<number, Errors> raw_value_x = location_x->second.to_value_all<number>();
+    if (raw_value_x.is_ok()) {
+      value_x = raw_value_x.get_ok();
+    } else {
+      errors.append(raw_value_x.get_err().add_field("x"));
+    }
+    map.erase(location_x);
+  }
+
+  auto location_y = map.find("y");
+  number value_y = shared::Point::default_y();
+  if (location_y != map.end()) {
+    Result<number, Errors> raw_value_y = location_y->second.to_value_all<number>();
+    if (raw_value_y.is_ok()) {
+      value_y = raw_value_y.get_ok();
+    } else {
+      errors.append(raw_value_y.get_err().add_field("y"));
+    }
+    map.erase(location_y);
+  }
+
+  if (!errors.empty()) {
+    return Result<shared::Point, Errors>::err(std::move(errors));
+  }
+
+  return Result<shared::Point, Errors>::ok(shared::Point(std::move(value_x), std::move(value_y), Map(std::move(map))));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<shared::Point>(const shared::Point &value) {
+  std::map<std::string, Node> map = value.extra_fields.get();
+
+  map.insert({"x", Node::from_value(value.x)});
+
+  map.insert({"y", Node::from_value(value.y)});
+
+  return Node(Node::Map(std::move(map)));
+}
+
+} // namespace termite
+
+
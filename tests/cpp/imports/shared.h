@@ -0,0 +1,199 @@
+// Generated with the Termite Data Model Generator
+#ifndef SHARED_TERMITE_H_INCLUDED
+#define SHARED_TERMITE_H_INCLUDED
+
+#include <iostream>
+#include <sstream>
+#include <optional>
+#include <variant>
+#include <algorithm>
+#include <termite.hpp>
+
+
+
+namespace shared {
+
+/**
+ * @brief A number larger than zero
+ * 
+ */
+class PositiveDouble {
+public:
+  /**
+   * @brief Constructs a new PositiveDouble object, it must be valid or an exception will be thrown
+   * 
+   * @param value The value to store 
+   */
+  explicit PositiveDouble(termite::number value) : PositiveDouble(from_value(std::move(value)).get_ok()) {}
+  /**
+   * @brief Constructs a new PositiveDouble object
+   * 
+   * @param value The value to store 
+   * @return The new constrained type or an error if some constraints were not upheld
+   */
+  [[nodiscard]] static termite::Result<PositiveDouble> from_value(termite::number value);
+
+  /**
+   * @brief Sets the value if it fulfills the constraints:
+   * - x > 0.0
+   * 
+   * @param value The value to set
+   * @return An error if one of the constraints were not fulfilled
+   */
+  [[nodiscard]] termite::Result<termite::Empty> set(termite::number value);
+
+  /**
+   * @brief Retrieves a reference to the value
+   * 
+   * @return The reference
+   */
+  [[nodiscard]] const termite::number &get() const {
+    return value_;
+  }
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const PositiveDouble &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const PositiveDouble &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const PositiveDouble &x);
+
+private:
+  /**
+   * @brief Constructs a new PositiveDouble object
+   * 
+   * @param value The value to store
+   * @param _ A nullptr
+   */
+  explicit PositiveDouble(termite::number value, void *) : value_(std::move(value)) {}
+
+  /**
+   * @brief Validates if value is correct using the following constraints:
+   * - x > 0.0
+   * 
+   * @param x The value of the parameter to validate
+   */
+  [[nodiscard]] static termite::Result<termite::Empty> validate(const termite::number &x);
+
+  /**
+   * @brief The validated value
+   * 
+   */
+  termite::number value_;
+};
+
+/**
+ * @brief A point in 2D space
+ * 
+ */
+struct Point {
+public:
+  /**
+   * @brief Constructs a new Point object
+   * 
+   * @param x The x coordinate of the point
+   * @param y The y coordinate of the point
+   * @param extra_fields Any extra fields to attach to this struct
+   */
+  explicit Point(termite::number x, termite::number y, ::termite::Node::Map extra_fields = ::termite::Node::Map()) : x(std::move(x)), y(std::move(y)), extra_fields(std::move(extra_fields)) {}
+
+  /**
+   * @brief Gets the default value for x
+   * 
+   * @return The default value for x
+   */
+  [[nodiscard]] static termite::number default_x();
+  /**
+   * @brief Gets the default value for y
+   * 
+   * @return The default value for y
+   */
+  [[nodiscard]] static termite::number default_y();
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Point &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Point &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Point &x);
+
+  /**
+   * @brief The x coordinate of the point
+   * 
+   */
+  termite::number x;
+  /**
+   * @brief The y coordinate of the point
+   * 
+   */
+  termite::number y;
+  /**
+   * @brief All extra fields from when reading which could not be captured
+   * 
+   */
+  ::termite::Node::Map extra_fields;
+};
+
+} // namespace shared
+
+namespace termite {
+
+template<>
+struct has_to_value_all<shared::PositiveDouble> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<shared::PositiveDouble, Errors> Node::to_value_all<shared::PositiveDouble>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<shared::PositiveDouble>(const shared::PositiveDouble &value);
+
+template<>
+struct has_to_value_all<shared::Point> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<shared::Point, Errors> Node::Map::to_value_all<shared::Point>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<shared::Point>(const shared::Point &value);
+
+} // namespace termite
+
+
+
+#endif
@@ -59,10 +59,10 @@ std::ostream &operator<<(std::ostream &os, const DataType2 &x) {
 namespace termite {
 
 template<>
-[[nodiscard]] Result<test::DataType1> Node::Map::to_value<test::DataType1>() const {
+[[nodiscard]] Result<test::DataType1, Errors> Node::Map::to_value_all<test::DataType1>() const {
   std::map<std::string, Node> map = map_;
 
-  return Result<test::DataType1>::ok(test::DataType1(Map(std::move(map))));
+  return Result<test::DataType1, Errors>::ok(test::DataType1(Map(std::move(map))));
 }
 
 template<>
@@ -73,10 +73,10 @@ template<>
 }
 
 template<>
-[[nodiscard]] Result<test::DataType2> Node::Map::to_value<test::DataType2>() const {
+[[nodiscard]] Result<test::DataType2, Errors> Node::Map::to_value_all<test::DataType2>() const {
   std::map<std::string, Node> map = map_;
 
-  return Result<test::DataType2>::ok(test::DataType2(Map(std::move(map))));
+  return Result<test::DataType2, Errors>::ok(test::DataType2(Map(std::move(map))));
 }
 
 template<>
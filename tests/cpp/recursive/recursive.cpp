@@ -0,0 +1,568 @@
+// Generated with the Termite Data Model Generator
+#include "recursive.h"
+
+
+
+namespace test {
+
+namespace {
+
+// Code to make printing easier
+template <typename T, typename = void>
+struct has_insertion_operator : std::false_type {};
+template <typename T>
+struct has_insertion_operator<T, std::void_t<decltype(std::declval<std::ostream &>() << std::declval<T>())>> : std::true_type {};
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::optional<T> &value) {
+  if (value) {
+    return os << *value;
+  } else {
+    return os << "nullopt";
+  }
+}
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::vector<T> &value) {
+  os << "[ ";
+  for (auto value_it = value.cbegin(); value_it != value.cend(); ++value_it) {
+    if (value_it != value.cbegin()) {
+      os << ", ";
+    }
+    os << *value_it;
+  }
+  return os << " ]";
+}
+
+} // namespace
+
+bool TreeArray::operator==(const TreeArray &x) const {
+  if (values.size() != x.values.size()) {
+    return false;
+  }
+
+  for (auto lhs = values.cbegin(), rhs = x.values.cbegin(); lhs < values.cend(); ++lhs, ++rhs) {
+    if (*lhs != *rhs) {
+      return false;
+    }
+  }
+
+  return true;
+}
+
+std::ostream &operator<<(std::ostream &os, const TreeArray &x) {
+  os << "{ values: [ ";
+  for (auto value = x.values.cbegin(); value < x.values.cend(); ++value) {
+    if (value != x.values.cbegin()) {
+      os << ", ";
+    }
+    os << *value;
+  }
+  return os << " ] }";
+}
+
+[[nodiscard]] bool Tree::operator==(const Tree &x) const {
+  return this->value == x.value && this->children == x.children && extra_fields == x.extra_fields;
+}
+
+std::ostream &operator<<(std::ostream &os, const Tree &x) {
+  return os << "{ " << "value: " << x.value << ", " << "children: " << x.children << ", " << "extra_fields: " << x.extra_fields << " }";
+}
+
+[[nodiscard]] bool Expr::TypeZero::operator==(const TypeZero &) const {
+  return true;
+}
+
+std::ostream &operator<<(std::ostream &os, const Expr::TypeZero &) {
+  return os << "{  }";
+}
+
+[[nodiscard]] bool Expr::TypeNumber::operator==(const TypeNumber &x) const {
+  return value == x.value;
+}
+
+std::ostream &operator<<(std::ostream &os, const Expr::TypeNumber &x) {
+  return os << "{ value: " << x.value << " }";
+}
+
+[[nodiscard]] bool Expr::TypeNegate::operator==(const TypeNegate &x) const {
+  return value == x.value;
+}
+
+std::ostream &operator<<(std::ostream &os, const Expr::TypeNegate &x) {
+  return os << "{ value: " << x.value << " }";
+}
+
+[[nodiscard]] bool Expr::TypeAdd::operator==(const TypeAdd &x) const {
+  return value == x.value;
+}
+
+std::ostream &operator<<(std::ostream &os, const Expr::TypeAdd &x) {
+  return os << "{ value: " << x.value << " }";
+}
+
+[[nodiscard]] bool Expr::operator==(const Expr &x) const {
+  return value == x.value;
+}
+
+std::ostream &operator<<(std::ostream &os, const Expr &x) {
+  os << "{ value: ";
+  switch (static_cast<Expr::Enum>(x.value.index())) {
+  case Expr::Enum::kZero:
+    os << "Empty";
+    break;
+  case Expr::Enum::kNumber:
+    os << "Number(" << std::get<Expr::TypeNumber>(x.value).value << ")";
+    break;
+  case Expr::Enum::kNegate:
+    os << "Negate(" << std::get<Expr::TypeNegate>(x.value).value << ")";
+    break;
+  case Expr::Enum::kAdd:
+    os << "Add(" << std::get<Expr::TypeAdd>(x.value).value << ")";
+    break;
+  default:
+    os << "Unknown (" << x.value.index() << ")";
+    break;
+  }
+  return os << " }";
+}
+
+[[nodiscard]] bool ExprPair::operator==(const ExprPair &x) const {
+  return this->lhs == x.lhs && this->rhs == x.rhs && extra_fields == x.extra_fields;
+}
+
+[[nodiscard]] std::optional<termite::Box<Expr>> ExprPair::default_rhs() {
+  return std::nullopt;
+}
+
+std::ostream &operator<<(std::ostream &os, const ExprPair &x) {
+  return os << "{ " << "lhs: " << x.lhs << ", " << "rhs: " << x.rhs << ", " << "extra_fields: " << x.extra_fields << " }";
+}
+
+[[nodiscard]] bool Item::operator==(const Item &x) const {
+  return value == x.value;
+}
+
+std::ostream &operator<<(std::ostream &os, const Item &x) {
+  os << "{ value: ";
+  switch (x.value.index()) {
+  case 0:
+    os << "termite::integer " << std::get<termite::integer>(x.value);
+    break;
+  case 1:
+    os << "termite::Box<ItemPair> " << std::get<termite::Box<ItemPair>>(x.value);
+    break;
+  default:
+    os << "Unknown(" << x.value.index() << ")";
+    break;
+  }
+  return os << " }";
+}
+
+[[nodiscard]] bool ItemPair::operator==(const ItemPair &x) const {
+  return this->first == x.first && this->second == x.second && extra_fields == x.extra_fields;
+}
+
+[[nodiscard]] termite::Box<Item> ItemPair::default_second() {
+  auto node = termite::Node(termite::Node::Value("0"));
+
+  return node.to_value<termite::Box<Item>>().get_ok();
+}
+
+std::ostream &operator<<(std::ostream &os, const ItemPair &x) {
+  return os << "{ " << "first: " << x.first << ", " << "second: " << x.second << ", " << "extra_fields: " << x.extra_fields << " }";
+}
+
+[[nodiscard]] bool Directory::operator==(const Directory &x) const {
+  return this->children == x.children && extra_fields == x.extra_fields;
+}
+
+std::ostream &operator<<(std::ostream &os, const Directory &x) {
+  return os << "{ " << "children: " << x.children << ", " << "extra_fields: " << x.extra_fields << " }";
+}
+
+bool DirectoryMap::operator==(const DirectoryMap &x) const {
+  return values == x.values;
+}
+
+std::ostream &operator<<(std::ostream &os, const DirectoryMap &x) {
+  os << "{ values: { ";
+  for (auto value = x.values.cbegin(); value != x.values.cend(); ++value) {
+    if (value != x.values.cbegin()) {
+      os << ", ";
+    }
+    os << "\"" << value->first << "\": " << value->second;
+  }
+  return os << " } }";
+}
+
+} // namespace test
+
+namespace termite {
+
+template<>
+[[nodiscard]] Result<test::TreeArray, Errors> Node::List::to_value_all<test::TreeArray>() const {
+  std::vector<test::Tree> values;
+  values.reserve(list_.size());
+  Errors errors;
+  for (auto node = list_.cbegin(); node < list_.cend(); ++node) {
+    Result<test::Tree, Errors> value = node->to_value_all<test::Tree>();
+    if (value.is_ok()) {
+      values.push_back(std::move(value.get_ok()));
+    } else {
+      errors.append(value.get_err().add_list(node - list_.cbegin()));
+    }
+  }
+  if (!errors.empty()) {
+    return Result<test::TreeArray, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::TreeArray, Errors>::ok(test::TreeArray(std::move(values)));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::TreeArray>(const test::TreeArray &value) {
+  std::vector<Node> list;
+  list.reserve(value.values.size());
+  std::transform(value.values.cbegin(), value.values.cend(), std::back_inserter(list), [](const test::Tree &value) {
+    return Node::from_value(value);
+  });
+  return Node(Node::List(std::move(list)));
+}
+
+template<>
+[[nodiscard]] Result<test::Tree, Errors> Node::Map::to_value_all<test::Tree>() const {
+  std::map<std::string, Node> map = map_;
+  Errors errors;
+
+  auto location_value = map.find("value");
+  std::optional<integer> value_value;
+  if (location_value == map.end()) {
+    errors.add(Error("Missing value"));
+  } else {
+    Result<integer, Errors> raw_value_value = location_value->second.to_value_all<integer>();
+    if (raw_value_value.is_ok()) {
+      value_value = raw_value_value.get_ok();
+    } else {
+      errors.append(raw_value_value.get_err().add_field("value"));
+    }
+    map.erase(location_value);
+  }
+
+  auto location_children = map.find("children");
+  std::optional<test::TreeArray> value_children;
+  if (location_children == map.end()) {
+    errors.add(Error("Missing children"));
+  } else {
+    Result<test::TreeArray, Errors> raw_value_children = location_children->second.to_value_all<test::TreeArray>();
+    if (raw_value_children.is_ok()) {
+      value_children = raw_value_children.get_ok();
+    } else {
+      errors.append(raw_value_children.get_err().add_field("children"));
+    }
+    map.erase(location_children);
+  }
+
+  if (!errors.empty()) {
+    return Result<test::Tree, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::Tree, Errors>::ok(test::Tree(std::move(*value_value), std::move(*value_children), Map(std::move(map))));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Tree>(const test::Tree &value) {
+  std::map<std::string, Node> map = value.extra_fields.get();
+
+  map.insert({"value", Node::from_value(value.value)});
+
+  map.insert({"children", Node::from_value(value.children)});
+
+  return Node(Node::Map(std::move(map)));
+}
+
+template<>
+[[nodiscard]] Result<test::Expr> Node::Value::to_value<test::Expr>() const {
+  if (value_ == "Zero") {
+    return Result<test::Expr>::ok(test::Expr(test::Expr::TypeZero{}));
+  }
+  if (value_ == "Number") {
+    return Result<test::Expr>::err(Error("Enum type Number must contain a value"));
+  }
+  if (value_ == "Negate") {
+    return Result<test::Expr>::err(Error("Enum type Negate must contain a value"));
+  }
+  if (value_ == "Add") {
+    return Result<test::Expr>::err(Error("Enum type Add must contain a value"));
+  }
+
+  std::stringstream ss;
+  ss << "Unknown enum type \"" << value_ << "\"";
+  return Result<test::Expr>::err(Error(ss.str()));
+}
+
+template<>
+[[nodiscard]] Result<test::Expr, Errors> Node::Map::to_value_all<test::Expr>() const {
+  if (map_.size() != 1) {
+    std::stringstream ss;
+    ss << "There must be exactly one enum type specified but received " << map_.size();
+    return Result<test::Expr, Errors>::err(Errors(Error(ss.str())));
+  }
+
+  if (map_.cbegin()->first == "Zero") {
+    return Result<test::Expr, Errors>::err(Errors(Error("Enum type Zero must not include values")));
+  }
+  if (map_.cbegin()->first == "Number") {
+    Result<number, Errors> value = map_.cbegin()->second.to_value_all<number>();
+    if (value.is_ok()) {
+      return Result<test::Expr, Errors>::ok(test::Expr(test::Expr::TypeNumber{value.get_ok()}));
+    }
+    return Result<test::Expr, Errors>::err(value.get_err().add_field("Number"));
+  }
+  if (map_.cbegin()->first == "Negate") {
+    Result<Box<test::Expr>, Errors> value = map_.cbegin()->second.to_value_all<Box<test::Expr>>();
+    if (value.is_ok()) {
+      return Result<test::Expr, Errors>::ok(test::Expr(test::Expr::TypeNegate{value.get_ok()}));
+    }
+    return Result<test::Expr, Errors>::err(value.get_err().add_field("Negate"));
+  }
+  if (map_.cbegin()->first == "Add") {
+    Result<Box<test::ExprPair>, Errors> value = map_.cbegin()->second.to_value_all<Box<test::ExprPair>>();
+    if (value.is_ok()) {
+      return Result<test::Expr, Errors>::ok(test::Expr(test::Expr::TypeAdd{value.get_ok()}));
+    }
+    return Result<test::Expr, Errors>::err(value.get_err().add_field("Add"));
+  }
+
+  std::stringstream ss;
+  ss << "Unknown enum type \"" << map_.cbegin()->first << "\"";
+  return Result<test::Expr, Errors>::err(Errors(Error(ss.str())));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Expr>(const test::Expr &value) {
+  std::map<std::string, Node> map;
+  switch (value.enum_type()) {
+  case test::Expr::Enum::kZero:
+    return Node(Node::Value("Zero"));
+  case test::Expr::Enum::kNumber:
+    map.insert({
+      "Number",
+      Node::from_value(std::get<test::Expr::TypeNumber>(value.value).value)
+    });
+    return Node(Node::Map(std::move(map)));
+  case test::Expr::Enum::kNegate:
+    map.insert({
+      "Negate",
+      Node::from_value(std::get<test::Expr::TypeNegate>(value.value).value)
+    });
+    return Node(Node::Map(std::move(map)));
+  case test::Expr::Enum::kAdd:
+    map.insert({
+      "Add",
+      Node::from_value(std::get<test::Expr::TypeAdd>(value.value).value)
+    });
+    return Node(Node::Map(std::move(map)));
+  default:
+    return Node(Node::Value(""));
+  }
+}
+
+template<>
+[[nodiscard]] Result<test::ExprPair, Errors> Node::Map::to_value_all<test::ExprPair>() const {
+  std::map<std::string, Node> map = map_;
+  Errors errors;
+
+  auto location_lhs = map.find("lhs");
+  std::optional<Box<test::Expr>> value_lhs;
+  if (location_lhs == map.end()) {
+    errors.add(Error("Missing lhs"));
+  } else {
+    Result<Box<test::Expr>, Errors> raw_value_lhs = location_lhs->second.to_value_all<Box<test::Expr>>();
+    if (raw_value_lhs.is_ok()) {
+      value_lhs = raw_value_lhs.get_ok();
+    } else {
+      errors.append(raw_value_lhs.get_err().add_field("lhs"));
+    }
+    map.erase(location_lhs);
+  }
+
+  auto location_rhs = map.find("rhs");
+  std::optional<Box<test::Expr>> value_rhs = test::ExprPair::default_rhs();
+  if (location_rhs != map.end()) {
+    Result<Box<test::Expr>, Errors> raw_value_rhs = location_rhs->second.to_value_all<Box<test::Expr>>();
+    if (raw_value_rhs.is_ok()) {
+      value_rhs = raw_value_rhs.get_ok();
+    } else {
+      errors.append(raw_value_rhs.get_err().add_field("rhs"));
+    }
+    map.erase(location_rhs);
+  }
+
+  if (!errors.empty()) {
+    return Result<test::ExprPair, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::ExprPair, Errors>::ok(test::ExprPair(std::move(*value_lhs), std::move(value_rhs), Map(std::move(map))));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::ExprPair>(const test::ExprPair &value) {
+  std::map<std::string, Node> map = value.extra_fields.get();
+
+  map.insert({"lhs", Node::from_value(value.lhs)});
+
+  if (value.rhs) {
+    map.insert({"rhs", Node::from_value(*value.rhs)});
+  }
+
+  return Node(Node::Map(std::move(map)));
+}
+
+template<>
+[[nodiscard]] Result<test::Item, Errors> Node::to_value_all<test::Item>() const {
+  std::stringstream error;
+  error << "Unable to parse any variant: [ ";
+
+  Result<integer> result_integer = to_value<integer>();
+  if (result_integer.is_ok()) {
+    return Result<test::Item, Errors>::ok(test::Item(result_integer.get_ok()));
+  }
+  error << "integer { " << result_integer.get_err() << " }";
+  error << ", ";
+
+  Result<Box<test::ItemPair>> result_item_pair = to_value<Box<test::ItemPair>>();
+  if (result_item_pair.is_ok()) {
+    return Result<test::Item, Errors>::ok(test::Item(result_item_pair.get_ok()));
+  }
+  error << "Box<test::ItemPair> { " << result_item_pair.get_err() << " }";
+
+  error << " ]";
+
+  return Result<test::Item, Errors>::err(Errors(Error(error.str()).set_mark(mark_)));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Item>(const test::Item &value) {
+  return std::visit([](const auto &x) {
+    return Node::from_value(x);
+  }, value.value);
+}
+
+template<>
+[[nodiscard]] Result<test::ItemPair, Errors> Node::Map::to_value_all<test::ItemPair>() const {
+  std::map<std::string, Node> map = map_;
+  Errors errors;
+
+  auto location_first = map.find("first");
+  std::optional<Box<test::Item>> value_first;
+  if (location_first == map.end()) {
+    errors.add(Error("Missing first"));
+  } else {
+    Result<Box<test::Item>, Errors> raw_value_first = location_first->second.to_value_all<Box<test::Item>>();
+    if (raw_value_first.is_ok()) {
+      value_first = raw_value_first.get_ok();
+    } else {
+      errors.append(raw_value_first.get_err().add_field("first"));
+    }
+    map.erase(location_first);
+  }
+
+  auto location_second = map.find("second");
+  Box<test::Item> value_second = test::ItemPair::default_second();
+  if (location_second != map.end()) {
+    Result<Box<test::Item>, Errors> raw_value_second = location_second->second.to_value_all<Box<test::Item>>();
+    if (raw_value_second.is_ok()) {
+      value_second = raw_value_second.get_ok();
+    } else {
+      errors.append(raw_value_second.get_err().add_field("second"));
+    }
+    map.erase(location_second);
+  }
+
+  if (!errors.empty()) {
+    return Result<test::ItemPair, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::ItemPair, Errors>::ok(test::ItemPair(std::move(*value_first), std::move(value_second), Map(std::move(map))));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::ItemPair>(const test::ItemPair &value) {
+  std::map<std::string, Node> map = value.extra_fields.get();
+
+  map.insert({"first", Node::from_value(value.first)});
+
+  map.insert({"second", Node::from_value(value.second)});
+
+  return Node(Node::Map(std::move(map)));
+}
+
+template<>
+[[nodiscard]] Result<test::Directory, Errors> Node::Map::to_value_all<test::Directory>() const {
+  std::map<std::string, Node> map = map_;
+  Errors errors;
+
+  auto location_children = map.find("children");
+  std::optional<Box<test::DirectoryMap>> value_children;
+  if (location_children == map.end()) {
+    errors.add(Error("Missing children"));
+  } else {
+    Result<Box<test::DirectoryMap>, Errors> raw_value_children = location_children->second.to_value_all<Box<test::DirectoryMap>>();
+    if (raw_value_children.is_ok()) {
+      value_children = raw_value_children.get_ok();
+    } else {
+      errors.append(raw_value_children.get_err().add_field("children"));
+    }
+    map.erase(location_children);
+  }
+
+  if (!errors.empty()) {
+    return Result<test::Directory, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::Directory, Errors>::ok(test::Directory(std::move(*value_children), Map(std::move(map))));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Directory>(const test::Directory &value) {
+  std::map<std::string, Node> map = value.extra_fields.get();
+
+  map.insert({"children", Node::from_value(value.children)});
+
+  return Node(Node::Map(std::move(map)));
+}
+
+template<>
+[[nodiscard]] Result<test::DirectoryMap, Errors> Node::Map::to_value_all<test::DirectoryMap>() const {
+  std::map<std::string, Box<test::Directory>> values;
+  Errors errors;
+  for (auto node = map_.cbegin(); node != map_.cend(); ++node) {
+    Result<Box<test::Directory>, Errors> value = node->second.to_value_all<Box<test::Directory>>();
+    if (value.is_ok()) {
+      values.insert({node->first, std::move(value.get_ok())});
+    } else {
+      errors.append(value.get_err().add_field(node->first));
+    }
+  }
+  if (!errors.empty()) {
+    return Result<test::DirectoryMap, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::DirectoryMap, Errors>::ok(test::DirectoryMap(std::move(values)));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DirectoryMap>(const test::DirectoryMap &value) {
+  std::map<std::string, Node> map;
+  for (auto node = value.values.cbegin(); node != value.values.cend(); ++node) {
+    map.insert({node->first, Node::from_value(node->second)});
+  }
+  return Node(Node::Map(std::move(map)));
+}
+
+} // namespace termite
+
+
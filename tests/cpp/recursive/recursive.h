@@ -0,0 +1,719 @@
+// Generated with the Termite Data Model Generator
+#ifndef HEADER_TERMITE_H_INCLUDED
+#define HEADER_TERMITE_H_INCLUDED
+
+#include <iostream>
+#include <sstream>
+#include <optional>
+#include <variant>
+#include <algorithm>
+#include <termite.hpp>
+
+
+
+namespace test {
+
+struct Tree;
+struct ExprPair;
+struct ItemPair;
+struct DirectoryMap;
+
+/**
+ * @brief
+ *
+ */
+struct TreeArray {
+public:
+  /**
+   * @brief Constructs a new TreeArray object
+   *
+   * @param values The values of the array
+   */
+  explicit TreeArray(std::vector<Tree> values) : values(std::move(values)) {}
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   *
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const TreeArray &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   *
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const TreeArray &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   *
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const TreeArray &x);
+
+  /**
+   * @brief The values of the array
+   *
+   */
+  std::vector<Tree> values;
+};
+
+/**
+ * @brief
+ *
+ */
+struct Tree {
+public:
+  /**
+   * @brief Constructs a new Tree object
+   *
+   * @param value
+   * @param children
+   * @param extra_fields Any extra fields to attach to this struct
+   */
+  explicit Tree(termite::integer value, TreeArray children, ::termite::Node::Map extra_fields = ::termite::Node::Map()) : value(std::move(value)), children(std::move(children)), extra_fields(std::move(extra_fields)) {}
+
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   *
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Tree &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   *
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Tree &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   *
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Tree &x);
+
+  /**
+   * @brief
+   *
+   */
+  termite::integer value;
+  /**
+   * @brief
+   *
+   */
+  TreeArray children;
+  /**
+   * @brief All extra fields from when reading which could not be captured
+   *
+   */
+  ::termite::Node::Map extra_fields;
+};
+
+/**
+ * @brief
+ *
+ */
+struct Expr {
+  /**
+   * @brief The values of this enum
+   *
+   */
+  enum class Enum {
+    /**
+     * @brief
+     *
+     */
+    kZero,
+    /**
+     * @brief
+     *
+     */
+    kNumber,
+    /**
+     * @brief
+     *
+     */
+    kNegate,
+    /**
+     * @brief
+     *
+     */
+    kAdd,
+  };
+
+  /**
+   * @brief The data for when the enum is a Zero
+   *
+   */
+  struct TypeZero {
+    /**
+     * @brief Checks if this object and the other object are identical
+     *
+     * @param x The other object to compare with
+     * @return true if they are identical, false if not
+     */
+    [[nodiscard]] bool operator==(const TypeZero &x) const;
+    /**
+     * @brief Checks if this object and the other object are different
+     *
+     * @param x The other object to compare with
+     * @return true if they are different, false if not
+     */
+    [[nodiscard]] bool operator!=(const TypeZero &x) const {
+      return !(*this == x);
+    }
+    /**
+     * @brief Prints the object onto the output stream
+     *
+     * @param os The output stream to print to
+     * @param x The object to print
+     * @return The output stream
+     */
+    friend std::ostream &operator<<(std::ostream &os, const TypeZero &x);
+  };
+
+  /**
+   * @brief The data for when the enum is a Number
+   *
+   */
+  struct TypeNumber {
+    /**
+     * @brief The value
+     *
+     */
+    termite::number value;
+
+    /**
+     * @brief Constructs a new Number object
+     *
+     * @param value The value of the enum
+     */
+    explicit TypeNumber(termite::number value) : value(std::move(value)) {}
+
+    /**
+     * @brief Checks if this object and the other object are identical
+     *
+     * @param x The other object to compare with
+     * @return true if they are identical, false if not
+     */
+    [[nodiscard]] bool operator==(const TypeNumber &x) const;
+    /**
+     * @brief Checks if this object and the other object are different
+     *
+     * @param x The other object to compare with
+     * @return true if they are different, false if not
+     */
+    [[nodiscard]] bool operator!=(const TypeNumber &x) const {
+      return !(*this == x);
+    }
+    /**
+     * @brief Prints the object onto the output stream
+     *
+     * @param os The output stream to print to
+     * @param x The object to print
+     * @return The output stream
+     */
+    friend std::ostream &operator<<(std::ostream &os, const TypeNumber &x);
+  };
+
+  /**
+   * @brief The data for when the enum is a Negate
+   *
+   */
+  struct TypeNegate {
+    /**
+     * @brief The value
+     *
+     */
+    termite::Box<Expr> value;
+
+    /**
+     * @brief Constructs a new Negate object
+     *
+     * @param value The value of the enum
+     */
+    explicit TypeNegate(termite::Box<Expr> value) : value(std::move(value)) {}
+
+    /**
+     * @brief Checks if this object and the other object are identical
+     *
+     * @param x The other object to compare with
+     * @return true if they are identical, false if not
+     */
+    [[nodiscard]] bool operator==(const TypeNegate &x) const;
+    /**
+     * @brief Checks if this object and the other object are different
+     *
+     * @param x The other object to compare with
+     * @return true if they are different, false if not
+     */
+    [[nodiscard]] bool operator!=(const TypeNegate &x) const {
+      return !(*this == x);
+    }
+    /**
+     * @brief Prints the object onto the output stream
+     *
+     * @param os The output stream to print to
+     * @param x The object to print
+     * @return The output stream
+     */
+    friend std::ostream &operator<<(std::ostream &os, const TypeNegate &x);
+  };
+
+  /**
+   * @brief The data for when the enum is a Add
+   *
+   */
+  struct TypeAdd {
+    /**
+     * @brief The value
+     *
+     */
+    termite::Box<ExprPair> value;
+
+    /**
+     * @brief Constructs a new Add object
+     *
+     * @param value The value of the enum
+     */
+    explicit TypeAdd(termite::Box<ExprPair> value) : value(std::move(value)) {}
+
+    /**
+     * @brief Checks if this object and the other object are identical
+     *
+     * @param x The other object to compare with
+     * @return true if they are identical, false if not
+     */
+    [[nodiscard]] bool operator==(const TypeAdd &x) const;
+    /**
+     * @brief Checks if this object and the other object are different
+     *
+     * @param x The other object to compare with
+     * @return true if they are different, false if not
+     */
+    [[nodiscard]] bool operator!=(const TypeAdd &x) const {
+      return !(*this == x);
+    }
+    /**
+     * @brief Prints the object onto the output stream
+     *
+     * @param os The output stream to print to
+     * @param x The object to print
+     * @return The output stream
+     */
+    friend std::ostream &operator<<(std::ostream &os, const TypeAdd &x);
+  };
+
+  /**
+   * @brief Constructs a new Expr object
+   *
+   * @param value The value of the enum
+   */
+  explicit Expr(std::variant<TypeZero, TypeNumber, TypeNegate, TypeAdd> value) : value(std::move(value)) {}
+
+  /**
+   * @brief Returns the enum type that is stored
+   *
+   * @return The enum type
+   */
+  [[nodiscard]] Enum enum_type() const {
+    return static_cast<Enum>(value.index());
+  }
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   *
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Expr &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   *
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Expr &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   *
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Expr &x);
+
+  /**
+   * @brief The value of the enum
+   *
+   */
+  std::variant<TypeZero, TypeNumber, TypeNegate, TypeAdd> value;
+};
+
+/**
+ * @brief
+ *
+ */
+struct ExprPair {
+public:
+  /**
+   * @brief Constructs a new ExprPair object
+   *
+   * @param lhs
+   * @param rhs
+   * @param extra_fields Any extra fields to attach to this struct
+   */
+  explicit ExprPair(termite::Box<Expr> lhs, std::optional<termite::Box<Expr>> rhs, ::termite::Node::Map extra_fields = ::termite::Node::Map()) : lhs(std::move(lhs)), rhs(std::move(rhs)), extra_fields(std::move(extra_fields)) {}
+
+  /**
+   * @brief Gets the default value for rhs
+   *
+   * @return The default value for rhs
+   */
+  [[nodiscard]] static std::optional<termite::Box<Expr>> default_rhs();
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   *
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const ExprPair &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   *
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const ExprPair &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   *
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const ExprPair &x);
+
+  /**
+   * @brief
+   *
+   */
+  termite::Box<Expr> lhs;
+  /**
+   * @brief
+   *
+   */
+  std::optional<termite::Box<Expr>> rhs;
+  /**
+   * @brief All extra fields from when reading which could not be captured
+   *
+   */
+  ::termite::Node::Map extra_fields;
+};
+
+/**
+ * @brief
+ *
+ */
+struct Item {
+  /**
+   * @brief Constructs a new Item object
+   *
+   * @param value The value of the variant
+   */
+  explicit Item(std::variant<termite::integer, termite::Box<ItemPair>> value) : value(std::move(value)) {}
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   *
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Item &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   *
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Item &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   *
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Item &x);
+
+  /**
+   * @brief The value of the variant
+   *
+   */
+  std::variant<termite::integer, termite::Box<ItemPair>> value;
+};
+
+/**
+ * @brief
+ *
+ */
+struct ItemPair {
+public:
+  /**
+   * @brief Constructs a new ItemPair object
+   *
+   * @param first
+   * @param second
+   * @param extra_fields Any extra fields to attach to this struct
+   */
+  explicit ItemPair(termite::Box<Item> first, termite::Box<Item> second, ::termite::Node::Map extra_fields = ::termite::Node::Map()) : first(std::move(first)), second(std::move(second)), extra_fields(std::move(extra_fields)) {}
+
+  /**
+   * @brief Gets the default value for second
+   *
+   * @return The default value for second
+   */
+  [[nodiscard]] static termite::Box<Item> default_second();
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   *
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const ItemPair &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   *
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const ItemPair &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   *
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const ItemPair &x);
+
+  /**
+   * @brief
+   *
+   */
+  termite::Box<Item> first;
+  /**
+   * @brief
+   *
+   */
+  termite::Box<Item> second;
+  /**
+   * @brief All extra fields from when reading which could not be captured
+   *
+   */
+  ::termite::Node::Map extra_fields;
+};
+
+/**
+ * @brief
+ *
+ */
+struct Directory {
+public:
+  /**
+   * @brief Constructs a new Directory object
+   *
+   * @param children
+   * @param extra_fields Any extra fields to attach to this struct
+   */
+  explicit Directory(termite::Box<DirectoryMap> children, ::termite::Node::Map extra_fields = ::termite::Node::Map()) : children(std::move(children)), extra_fields(std::move(extra_fields)) {}
+
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   *
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Directory &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   *
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Directory &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   *
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Directory &x);
+
+  /**
+   * @brief
+   *
+   */
+  termite::Box<DirectoryMap> children;
+  /**
+   * @brief All extra fields from when reading which could not be captured
+   *
+   */
+  ::termite::Node::Map extra_fields;
+};
+
+/**
+ * @brief
+ *
+ */
+struct DirectoryMap {
+public:
+  /**
+   * @brief Constructs a new DirectoryMap object
+   *
+   * @param values The values of the map
+   */
+  explicit DirectoryMap(std::map<std::string, termite::Box<Directory>> values) : values(std::move(values)) {}
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   *
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const DirectoryMap &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   *
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const DirectoryMap &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   *
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const DirectoryMap &x);
+
+  /**
+   * @brief The values of the map
+   *
+   */
+  std::map<std::string, termite::Box<Directory>> values;
+};
+
+} // namespace test
+
+namespace termite {
+
+template<>
+struct has_to_value_all<test::TreeArray> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::TreeArray, Errors> Node::List::to_value_all<test::TreeArray>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::TreeArray>(const test::TreeArray &value);
+
+template<>
+struct has_to_value_all<test::Tree> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::Tree, Errors> Node::Map::to_value_all<test::Tree>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Tree>(const test::Tree &value);
+
+template<>
+struct has_to_value_all<test::Expr> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::Expr> Node::Value::to_value<test::Expr>() const;
+
+template<>
+[[nodiscard]] Result<test::Expr, Errors> Node::Map::to_value_all<test::Expr>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Expr>(const test::Expr &value);
+
+template<>
+struct has_to_value_all<test::ExprPair> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::ExprPair, Errors> Node::Map::to_value_all<test::ExprPair>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::ExprPair>(const test::ExprPair &value);
+
+template<>
+struct has_to_value_all<test::Item> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::Item, Errors> Node::to_value_all<test::Item>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Item>(const test::Item &value);
+
+template<>
+struct has_to_value_all<test::ItemPair> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::ItemPair, Errors> Node::Map::to_value_all<test::ItemPair>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::ItemPair>(const test::ItemPair &value);
+
+template<>
+struct has_to_value_all<test::Directory> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::Directory, Errors> Node::Map::to_value_all<test::Directory>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Directory>(const test::Directory &value);
+
+template<>
+struct has_to_value_all<test::DirectoryMap> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::DirectoryMap, Errors> Node::Map::to_value_all<test::DirectoryMap>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DirectoryMap>(const test::DirectoryMap &value);
+
+} // namespace termite
+
+
+
+#endif
@@ -0,0 +1,141 @@
+#include <iostream>
+#include "recursive.h"
+
+int main() {
+  // Trees
+  auto tree = test::Tree(1, test::TreeArray({test::Tree(2, test::TreeArray({})), test::Tree(3, test::TreeArray({test::Tree(4, test::TreeArray({}))}))}));
+
+  std::map<std::string, termite::Node> map_leaf;
+  map_leaf.insert({"value", termite::Node(termite::Node::Value("4"))});
+  map_leaf.insert({"children", termite::Node(termite::Node::List())});
+  std::vector<termite::Node> list_branch;
+  list_branch.push_back(termite::Node(termite::Node::Map(std::move(map_leaf))));
+  std::map<std::string, termite::Node> map_branch;
+  map_branch.insert({"value", termite::Node(termite::Node::Value("3"))});
+  map_branch.insert({"children", termite::Node(termite::Node::List(std::move(list_branch)))});
+  std::map<std::string, termite::Node> map_single;
+  map_single.insert({"value", termite::Node(termite::Node::Value("2"))});
+  map_single.insert({"children", termite::Node(termite::Node::List())});
+  std::vector<termite::Node> list_tree;
+  list_tree.push_back(termite::Node(termite::Node::Map(std::move(map_single))));
+  list_tree.push_back(termite::Node(termite::Node::Map(std::move(map_branch))));
+  std::map<std::string, termite::Node> map_tree;
+  map_tree.insert({"value", termite::Node(termite::Node::Value("1"))});
+  map_tree.insert({"children", termite::Node(termite::Node::List(std::move(list_tree)))});
+  termite::Node node_tree(termite::Node::Map(std::move(map_tree)));
+
+  auto tree_read = node_tree.to_value<test::Tree>();
+  if (!tree_read.is_ok()) {
+    return 1;
+  }
+  if (tree_read.get_ok() != tree) {
+    return 2;
+  }
+  auto tree_read_again = termite::Node::from_value(tree).to_value<test::Tree>();
+  if (!tree_read_again.is_ok()) {
+    return 3;
+  }
+  if (tree_read_again.get_ok() != tree) {
+    return 4;
+  }
+
+  // Expressions
+  auto expr = test::Expr(test::Expr::TypeAdd(test::ExprPair(test::Expr(test::Expr::TypeNegate(test::Expr(test::Expr::TypeNumber(1.5)))), test::Expr(test::Expr::TypeZero{}))));
+
+  std::map<std::string, termite::Node> map_number;
+  map_number.insert({"Number", termite::Node(termite::Node::Value("1.5"))});
+  std::map<std::string, termite::Node> map_negate;
+  map_negate.insert({"Negate", termite::Node(termite::Node::Map(std::move(map_number)))});
+  std::map<std::string, termite::Node> map_pair;
+  map_pair.insert({"lhs", termite::Node(termite::Node::Map(std::move(map_negate)))});
+  map_pair.insert({"rhs", termite::Node(termite::Node::Value("Zero"))});
+  std::map<std::string, termite::Node> map_add;
+  map_add.insert({"Add", termite::Node(termite::Node::Map(std::move(map_pair)))});
+  termite::Node node_expr(termite::Node::Map(std::move(map_add)));
+
+  auto expr_read = node_expr.to_value<test::Expr>();
+  if (!expr_read.is_ok()) {
+    return 5;
+  }
+  if (expr_read.get_ok() != expr) {
+    return 6;
+  }
+  auto expr_read_again = termite::Node::from_value(expr).to_value<test::Expr>();
+  if (!expr_read_again.is_ok()) {
+    return 7;
+  }
+  if (expr_read_again.get_ok() != expr) {
+    return 8;
+  }
+
+  // Make sure the values are copied and not shared
+  auto expr_pair = test::ExprPair(test::Expr(test::Expr::TypeZero{}), std::nullopt);
+  auto expr_pair_copy = expr_pair;
+  *expr_pair_copy.lhs = test::Expr(test::Expr::TypeNumber(2.0));
+  if (expr_pair == expr_pair_copy) {
+    return 9;
+  }
+  if (expr_pair.lhs->enum_type() != test::Expr::Enum::kZero) {
+    return 10;
+  }
+
+  // Items
+  auto item = test::Item(test::ItemPair(test::Item(1), test::Item(test::ItemPair(test::Item(2), test::Item(0)))));
+
+  std::map<std::string, termite::Node> map_inner;
+  map_inner.insert({"first", termite::Node(termite::Node::Value("2"))});
+  std::map<std::string, termite::Node> map_item;
+  map_item.insert({"first", termite::Node(termite::Node::Value("1"))});
+  map_item.insert({"second", termite::Node(termite::Node::Map(std::move(map_inner)))});
+  termite::Node node_item(termite::Node::Map(std::move(map_item)));
+
+  auto item_read = node_item.to_value<test::Item>();
+  if (!item_read.is_ok()) {
+    return 11;
+  }
+  if (item_read.get_ok() != item) {
+    return 12;
+  }
+  auto item_read_again = termite::Node::from_value(item).to_value<test::Item>();
+  if (!item_read_again.is_ok()) {
+    return 13;
+  }
+  if (item_read_again.get_ok() != item) {
+    return 14;
+  }
+
+  // Directories
+  auto directory = test::Directory(test::DirectoryMap({{"a", test::Directory(test::DirectoryMap({}))}}));
+
+  std::map<std::string, termite::Node> map_leaf_directory;
+  map_leaf_directory.insert({"children", termite::Node(termite::Node::Map())});
+  std::map<std::string, termite::Node> map_children;
+  map_children.insert({"a", termite::Node(termite::Node::Map(std::move(map_leaf_directory)))});
+  std::map<std::string, termite::Node> map_directory;
+  map_directory.insert({"children", termite::Node(termite::Node::Map(std::move(map_children)))});
+  termite::Node node_directory(termite::Node::Map(std::move(map_directory)));
+
+  auto directory_read = node_directory.to_value<test::Directory>();
+  if (!directory_read.is_ok()) {
+    return 15;
+  }
+  if (directory_read.get_ok() != directory) {
+    return 16;
+  }
+  auto directory_read_again = termite::Node::from_value(directory).to_value<test::Directory>();
+  if (!directory_read_again.is_ok()) {
+    return 17;
+  }
+  if (directory_read_again.get_ok() != directory) {
+    return 18;
+  }
+
+  std::cout << tree << std::endl;
+  std::cout << expr << std::endl;
+  std::cout << item << std::endl;
+  std::cout << directory << std::endl;
+
+  std::cout << "Done" << std::endl;
+
+  return 0;
+}
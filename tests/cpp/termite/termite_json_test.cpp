@@ -361,6 +361,67 @@ std::optional<std::string> test_to_map_empty() {
   return std::nullopt;
 }
 
+/**
+ * @brief Test if the positions of a JSON string are kept
+ *
+ * @return An error string on error
+ */
+std::optional<std::string> test_json_string_marks() {
+  auto result = termite::from_JSON_string(
+      "{\n  \"field1\": \"Test1\",\n  \"field2\": [\n    \"Test2\",\n    \"Test3\"\n  ]\n}");
+
+  if (!result.is_ok()) {
+    std::stringstream ss;
+    ss << result.get_err();
+    return ss.str();
+  }
+  auto result_node = result.get_ok();
+  const auto &map = std::get<termite::Node::Map>(result_node.get()).get();
+  const auto &list = std::get<termite::Node::List>(map.at("field2").get()).get();
+  if (map.at("field1").get_mark() != termite::Mark(2, 13) ||
+      list[1].get_mark() != termite::Mark(5, 5)) {
+    return "Wrong marks";
+  }
+
+  auto value = map.at("field1").to_value<int>();
+  if (value.is_ok()) {
+    return "Should not be able to parse";
+  }
+  std::stringstream ss;
+  ss << value.get_err();
+  if (ss.str() != "2:13: Unable to parse") {
+    return ss.str();
+  }
+
+  return std::nullopt;
+}
+
+/**
+ * @brief Test if the file of a JSON file is kept in the positions
+ *
+ * @return An error string on error
+ */
+std::optional<std::string> test_json_file_marks() {
+  auto result = termite::from_JSON_file("../files/json_test.json");
+
+  if (!result.is_ok()) {
+    std::stringstream ss;
+    ss << result.get_err();
+    return ss.str();
+  }
+  auto result_node = result.get_ok();
+  if (result_node.get_mark() != termite::Mark(1, 1, "../files/json_test.json")) {
+    return "Wrong mark";
+  }
+  std::stringstream ss;
+  ss << *result_node.get_mark();
+  if (ss.str() != "../files/json_test.json:1:1") {
+    return ss.str();
+  }
+
+  return std::nullopt;
+}
+
 int main() {
   auto names = {
       "test_scalar",    "test_list",    "test_map",
@@ -368,6 +429,7 @@ int main() {
       "test_to_list_empty", "test_to_map_empty",
       "test_json_string", "test_json_file",
       "test_to_json_string", "test_to_json_file",
+      "test_json_string_marks", "test_json_file_marks",
   };
   auto functions = {
       test_scalar,    test_list,    test_map,
@@ -375,6 +437,7 @@ int main() {
       test_to_list_empty, test_to_map_empty,
       test_json_string, test_json_file,
       test_to_json_string, test_to_json_file,
+      test_json_string_marks, test_json_file_marks,
   };
 
   std::cout << "Running " << names.size() << " tests" << std::endl;
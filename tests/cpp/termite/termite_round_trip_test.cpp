@@ -0,0 +1,282 @@
+#include "termite.hpp"
+
+#include <cmath>
+#include <limits>
+#include <optional>
+#include <sstream>
+#include <string>
+#include <vector>
+
+/**
+ * @brief Tests that every value is converted to a node and back to the same
+ * value and that the node is converted to a value and back to the same node
+ *
+ * @tparam T The type of the values
+ * @param values The values to test
+ * @return An error string on error
+ */
+template <typename T>
+std::optional<std::string> round_trip(const std::vector<T> &values) {
+  for (const T &value : values) {
+    termite::Node node = termite::Node::from_value(value);
+    termite::Result<T> result = node.to_value<T>();
+    if (!result.is_ok()) {
+      std::stringstream ss;
+      ss << std::get<termite::Node::Value>(node.get()).get() << ": "
+         << result.get_err();
+      return ss.str();
+    }
+    T result_value = result.get_ok();
+    if (result_value != value) {
+      std::stringstream ss;
+      ss << std::get<termite::Node::Value>(node.get()).get()
+         << " was parsed as " << termite::Node::from_value(result_value);
+      return ss.str();
+    }
+    termite::Node result_node = termite::Node::from_value(result_value);
+    if (result_node != node) {
+      std::stringstream ss;
+      ss << node << " was written as " << result_node;
+      return ss.str();
+    }
+  }
+
+  return std::nullopt;
+}
+
+/**
+ * @brief Tests that a value is written as the expected string
+ *
+ * @tparam T The type of the value
+ * @param value The value to write
+ * @param expected The expected string
+ * @return An error string on error
+ */
+template <typename T>
+std::optional<std::string> written_as(const T &value,
+                                      const std::string &expected) {
+  termite::Node node = termite::Node::from_value(value);
+  if (node != termite::Node(termite::Node::Value(expected))) {
+    std::stringstream ss;
+    ss << node << " is not " << expected;
+    return ss.str();
+  }
+
+  return std::nullopt;
+}
+
+/**
+ * @brief Test if numbers keep all their digits
+ *
+ * @return An error string on error
+ */
+std::optional<std::string> test_number() {
+  return round_trip<termite::number>({
+      0.0,
+      -0.5,
+      0.1,
+      0.1234567,
+      1.0 / 3.0,
+      123456789.123456789,
+      1e300,
+      -2.5e-300,
+      std::numeric_limits<double>::max(),
+      std::numeric_limits<double>::lowest(),
+      std::numeric_limits<double>::min(),
+      std::numeric_limits<double>::epsilon(),
+  });
+}
+
+/**
+ * @brief Test if single precision numbers keep all their digits
+ *
+ * @return An error string on error
+ */
+std::optional<std::string> test_float() {
+  return round_trip<float>({
+      0.0f,
+      0.1f,
+      0.1234567f,
+      1.0f / 3.0f,
+      1e30f,
+      std::numeric_limits<float>::max(),
+      std::numeric_limits<float>::lowest(),
+      std::numeric_limits<float>::min(),
+  });
+}
+
+/**
+ * @brief Test if integers keep all their digits
+ *
+ * @return An error string on error
+ */
+std::optional<std::string> test_integer() {
+  if (auto error = round_trip<termite::integer>({
+          0,
+          -1,
+          1234567890123,
+          std::numeric_limits<termite::integer>::max(),
+          std::numeric_limits<termite::integer>::min(),
+      })) {
+    return error;
+  }
+  if (auto error = round_trip<uint64_t>({
+          0,
+          std::numeric_limits<uint64_t>::max(),
+      })) {
+    return error;
+  }
+  return round_trip<int32_t>({
+      std::numeric_limits<int32_t>::max(),
+      std::numeric_limits<int32_t>::min(),
+  });
+}
+
+/**
+ * @brief Test if booleans are written as true and false
+ *
+ * @return An error string on error
+ */
+std::optional<std::string> test_boolean() {
+  if (auto error = round_trip<termite::boolean>({true, false})) {
+    return error;
+  }
+  if (auto error = written_as(true, "true")) {
+    return error;
+  }
+  return written_as(false, "false");
+}
+
+/**
+ * @brief Test if strings are kept as they are, only the whitespace around
+ * the value is removed
+ *
+ * @return An error string on error
+ */
+std::optional<std::string> test_string() {
+  if (auto error = round_trip<termite::string>(
+          {"", "Test", "1.5", "true", "a b", "a \t\n b"})) {
+    return error;
+  }
+  termite::Node node(termite::Node::Value(" \ta b\n"));
+  termite::Result<termite::string> result = node.to_value<termite::string>();
+  if (!result.is_ok() || result.get_ok() != "a b") {
+    return "\" \\ta b\\n\" is not parsed as \"a b\"";
+  }
+  return std::nullopt;
+}
+
+/**
+ * @brief Test if numbers are written with the shortest representation
+ *
+ * @return An error string on error
+ */
+std::optional<std::string> test_shortest() {
+  if (auto error = written_as(0.1234567, "0.1234567")) {
+    return error;
+  }
+  if (auto error = written_as(0.1, "0.1")) {
+    return error;
+  }
+  if (auto error = written_as(0.1f, "0.1")) {
+    return error;
+  }
+  if (auto error = written_as(2.0, "2")) {
+    return error;
+  }
+  return written_as(1e300, "1e+300");
+}
+
+/**
+ * @brief Test if infinity and NaN are written like in YAML and parsed back
+ *
+ * @return An error string on error
+ */
+std::optional<std::string> test_special() {
+  if (auto error = round_trip<termite::number>({
+          std::numeric_limits<double>::infinity(),
+          -std::numeric_limits<double>::infinity(),
+      })) {
+    return error;
+  }
+  if (auto error = round_trip<float>({
+          std::numeric_limits<float>::infinity(),
+          -std::numeric_limits<float>::infinity(),
+      })) {
+    return error;
+  }
+  if (auto error =
+          written_as(std::numeric_limits<double>::infinity(), ".inf")) {
+    return error;
+  }
+  if (auto error =
+          written_as(-std::numeric_limits<double>::infinity(), "-.inf")) {
+    return error;
+  }
+
+  // NaN is never equal to itself so it is checked on its own
+  for (std::string value : {".nan", ".NaN", ".NAN"}) {
+    termite::Result<termite::number> result =
+        termite::Node(termite::Node::Value(value)).to_value<termite::number>();
+    if (!result.is_ok() || !std::isnan(result.get_ok())) {
+      return value + " is not NaN";
+    }
+  }
+  if (auto error =
+          written_as(std::numeric_limits<double>::quiet_NaN(), ".nan")) {
+    return error;
+  }
+
+  // All the YAML spellings are accepted, other words are not numbers
+  for (std::string value : {".Inf", "+.INF", "-.Inf"}) {
+    termite::Result<termite::number> result =
+        termite::Node(termite::Node::Value(value)).to_value<termite::number>();
+    if (!result.is_ok() || !std::isinf(result.get_ok()) ||
+        (result.get_ok() < 0) != (value.front() == '-')) {
+      return value + " is not infinity";
+    }
+  }
+  for (std::string value :
+       {"inf", "-inf", "nan", ".infinity", "-.nan"}) {
+    if (termite::Node(termite::Node::Value(value))
+            .to_value<termite::number>()
+            .is_ok()) {
+      return value + " was parsed as a number";
+    }
+  }
+
+  return std::nullopt;
+}
+
+int main() {
+  auto names = {
+      "test_number", "test_float",    "test_integer", "test_boolean",
+      "test_string", "test_shortest", "test_special",
+  };
+  auto functions = {
+      test_number, test_float,    test_integer, test_boolean,
+      test_string, test_shortest, test_special,
+  };
+
+  std::cout << "Running " << names.size() << " tests" << std::endl;
+
+  int progress = 1;
+  int return_value = 0;
+  auto name_it = names.begin();
+  for (auto function_it = functions.begin(); function_it < functions.end();
+       ++function_it, ++name_it, ++progress) {
+    if (auto error = (*function_it)()) {
+      std::cout << "Error occured at \"" << *name_it << "\": " << *error
+                << std::endl;
+      if (return_value == 0) {
+        return_value = progress;
+      }
+    }
+  }
+
+  if (return_value == 0) {
+    std::cout << "No errors" << std::endl;
+  }
+
+  return return_value;
+}
@@ -4,6 +4,81 @@
 #include <sstream>
 #include <string>
 
+/**
+ * @brief A type with a custom parser for maps written before to_value_all
+ *
+ */
+struct CustomMap {
+  int value;
+
+  [[nodiscard]] bool operator==(const CustomMap &other) const {
+    return value == other.value;
+  }
+  friend std::ostream &operator<<(std::ostream &os, const CustomMap &value) {
+    return os << value.value;
+  }
+};
+
+/**
+ * @brief A type with a custom parser for nodes written before to_value_all
+ *
+ */
+struct CustomNode {
+  int value;
+
+  [[nodiscard]] bool operator==(const CustomNode &other) const {
+    return value == other.value;
+  }
+  friend std::ostream &operator<<(std::ostream &os, const CustomNode &value) {
+    return os << value.value;
+  }
+};
+
+/**
+ * @brief A type with a parser which fails without giving any errors
+ *
+ */
+struct NoErrors {
+  [[nodiscard]] bool operator==(const NoErrors &other) const { return true; }
+  friend std::ostream &operator<<(std::ostream &os, const NoErrors &value) {
+    return os << "NoErrors";
+  }
+};
+
+namespace termite {
+
+template <>
+[[nodiscard]] Result<CustomMap> Node::Map::to_value<CustomMap>() const {
+  auto location = get().find("value");
+  if (location == get().end()) {
+    return Result<CustomMap>::err(Error("Missing value"));
+  }
+  Result<int> value = location->second.to_value<int>();
+  if (!value.is_ok()) {
+    return Result<CustomMap>::err(value.get_err());
+  }
+  return Result<CustomMap>::ok(CustomMap{value.get_ok()});
+}
+
+template <>
+[[nodiscard]] Result<CustomNode> Node::to_value<CustomNode>() const {
+  Result<int> value = to_value<int>();
+  if (!value.is_ok()) {
+    return Result<CustomNode>::err(value.get_err());
+  }
+  return Result<CustomNode>::ok(CustomNode{value.get_ok()});
+}
+
+template <> struct has_to_value_all<NoErrors> : std::true_type {};
+
+template <>
+[[nodiscard]] Result<NoErrors, Errors>
+Node::Map::to_value_all<NoErrors>() const {
+  return Result<NoErrors, Errors>::err(Errors());
+}
+
+} // namespace termite
+
 /**
  * @brief Test if the error message is set correctly
  *
@@ -196,6 +271,114 @@ std::optional<std::string> test_result_get() {
   return std::nullopt;
 }
 
+/**
+ * @brief Test if errors keep the location of every error
+ *
+ * @return An error string on error
+ */
+std::optional<std::string> test_errors_add_location() {
+  termite::Errors errors;
+  errors.add(termite::Error("Error1", "field"));
+  errors.append(termite::Errors(termite::Error("Error2")));
+  errors.add_list(1).add_field("base");
+
+  std::stringstream ss;
+  ss << errors;
+  if (ss.str() != "base[1].field: Error1\nbase[1]: Error2") {
+    return ss.str();
+  }
+  if (errors.get().size() != 2 || termite::Errors().get().size() != 0) {
+    return "Wrong size";
+  }
+
+  return std::nullopt;
+}
+
+/**
+ * @brief Test if parsing with all errors gives the same values as parsing with
+ * a single error
+ *
+ * @return An error string on error
+ */
+std::optional<std::string> test_node_to_value_all() {
+  termite::Node node(termite::Node::Value("123"));
+
+  auto value = node.to_value_all<int>();
+  if (!value.is_ok() || value.get_ok() != 123) {
+    return "Wrong value";
+  }
+
+  termite::Node node_list(termite::Node::List{});
+  auto value_err = node_list.to_value_all<int>();
+  if (value_err.is_ok()) {
+    return "Parsed a list as an integer";
+  }
+  if (value_err.get_err() !=
+      termite::Errors(termite::Error("Parsing not implemented for given type"))) {
+    return "Wrong error";
+  }
+
+  return std::nullopt;
+}
+
+/**
+ * @brief Test if types with a custom to_value are parsed when finding all
+ * errors
+ *
+ * @return An error string on error
+ */
+std::optional<std::string> test_node_to_value_all_custom() {
+  termite::Node node_map(termite::Node::Map(
+      {{"value", termite::Node(termite::Node::Value("5"))}}));
+  auto value_map = node_map.to_value_all<termite::Box<CustomMap>>();
+  if (!value_map.is_ok() || value_map.get_ok()->value != 5) {
+    return "Wrong map value";
+  }
+
+  termite::Node node_missing(termite::Node::Map{});
+  auto value_missing = node_missing.to_value_all<CustomMap>();
+  if (value_missing.is_ok()) {
+    return "Parsed a map without a value";
+  }
+  if (value_missing.get_err() !=
+      termite::Errors(termite::Error("Missing value"))) {
+    return "Wrong map error";
+  }
+
+  termite::Node node_value(termite::Node::Value("7"));
+  auto value_node = node_value.to_value_all<CustomNode>();
+  if (!value_node.is_ok() || value_node.get_ok().value != 7) {
+    return "Wrong node value";
+  }
+
+  auto value_list = termite::Node(termite::Node::List{}).to_value<CustomMap>();
+  if (value_list.is_ok()) {
+    return "Parsed a list as a map";
+  }
+
+  return std::nullopt;
+}
+
+/**
+ * @brief Test if a failed parse without any errors still gives an error when
+ * stopping at the first error
+ *
+ * @return An error string on error
+ */
+std::optional<std::string> test_node_to_value_no_errors() {
+  termite::Node node(termite::Node::Map{});
+
+  auto value = node.to_value<NoErrors>();
+  if (value.is_ok()) {
+    return "Should not parse";
+  }
+  if (value.get_err() != termite::Error("Parsing failed without an error")) {
+    return "Wrong error";
+  }
+
+  return std::nullopt;
+}
+
 /**
  * @brief Test if NodeValue can get read an integer
  *
@@ -319,6 +502,10 @@ int main() {
       "test_result_equality",
       "test_result_is_ok",
       "test_result_get",
+      "test_errors_add_location",
+      "test_node_to_value_all",
+      "test_node_to_value_all_custom",
+      "test_node_to_value_no_errors",
       "test_node_value_parse_simple",
       "test_node_value_parse_spaces",
       "test_node_value_parse_error_begin",
@@ -335,6 +522,10 @@ int main() {
       test_result_equality,
       test_result_is_ok,
       test_result_get,
+      test_errors_add_location,
+      test_node_to_value_all,
+      test_node_to_value_all_custom,
+      test_node_to_value_no_errors,
       test_node_value_parse_simple,
       test_node_value_parse_spaces,
       test_node_value_parse_error_begin,
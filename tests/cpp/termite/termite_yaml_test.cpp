@@ -416,6 +416,67 @@ std::optional<std::string> test_to_map_empty() {
   return std::nullopt;
 }
 
+/**
+ * @brief Test if the positions of a YAML string are kept
+ *
+ * @return An error string on error
+ */
+std::optional<std::string> test_yaml_string_marks() {
+  auto result = termite::from_YAML_string(
+      "field1: Test1\nfield2:\n- Test2\n- Test3");
+
+  if (!result.is_ok()) {
+    std::stringstream ss;
+    ss << result.get_err();
+    return ss.str();
+  }
+  auto result_node = result.get_ok();
+  const auto &map = std::get<termite::Node::Map>(result_node.get()).get();
+  const auto &list = std::get<termite::Node::List>(map.at("field2").get()).get();
+  if (map.at("field1").get_mark() != termite::Mark(1, 9) ||
+      list[1].get_mark() != termite::Mark(4, 3)) {
+    return "Wrong marks";
+  }
+
+  auto value = map.at("field1").to_value<int>();
+  if (value.is_ok()) {
+    return "Should not be able to parse";
+  }
+  std::stringstream ss;
+  ss << value.get_err();
+  if (ss.str() != "1:9: Unable to parse") {
+    return ss.str();
+  }
+
+  return std::nullopt;
+}
+
+/**
+ * @brief Test if the file of a YAML file is kept in the positions
+ *
+ * @return An error string on error
+ */
+std::optional<std::string> test_yaml_file_marks() {
+  auto result = termite::from_YAML_file("../files/yaml_test.yaml");
+
+  if (!result.is_ok()) {
+    std::stringstream ss;
+    ss << result.get_err();
+    return ss.str();
+  }
+  auto result_node = result.get_ok();
+  if (result_node.get_mark() != termite::Mark(1, 1, "../files/yaml_test.yaml")) {
+    return "Wrong mark";
+  }
+  std::stringstream ss;
+  ss << *result_node.get_mark();
+  if (ss.str() != "../files/yaml_test.yaml:1:1") {
+    return ss.str();
+  }
+
+  return std::nullopt;
+}
+
 int main() {
   auto names = {
       "test_scalar",     "test_list",       "test_map",
@@ -424,6 +485,7 @@ int main() {
       "test_to_list_empty", "test_to_map_empty",
       "test_yaml_string", "test_yaml_file",
       "test_to_yaml_string", "test_to_yaml_file",
+      "test_yaml_string_marks", "test_yaml_file_marks",
   };
   auto functions = {
       test_scalar,     test_list,       test_map,
@@ -432,6 +494,7 @@ int main() {
       test_to_list_empty, test_to_map_empty,
       test_yaml_string, test_yaml_file,
       test_to_yaml_string, test_to_yaml_file,
+      test_yaml_string_marks, test_yaml_file_marks,
   };
 
   std::cout << "Running " << names.size() << " tests" << std::endl;
@@ -93,20 +93,23 @@ std::ostream &operator<<(std::ostream &os, const DataType2 &x) {
 namespace termite {
 
 template<>
-[[nodiscard]] Result<test::DataType1> Node::List::to_value<test::DataType1>() const {
+[[nodiscard]] Result<test::DataType1, Errors> Node::List::to_value_all<test::DataType1>() const {
   std::vector<int> values;
   values.reserve(list_.size());
+  Errors errors;
   for (auto node = list_.cbegin(); node < list_.cend(); ++node) {
-    Result<int> value = node->to_value<int>();
-    if (!value.is_ok()) {
-      Error error = value.get_err();
-      error.add_list(node - list_.cbegin());
-      return Result<test::DataType1>::err(std::move(error));
+    Result<int, Errors> value = node->to_value_all<int>();
+    if (value.is_ok()) {
+      values.push_back(std::move(value.get_ok()));
+    } else {
+      errors.append(value.get_err().add_list(node - list_.cbegin()));
     }
-    values.push_back(std::move(value.get_ok()));
+  }
+  if (!errors.empty()) {
+    return Result<test::DataType1, Errors>::err(std::move(errors));
   }
 
-  return Result<test::DataType1>::ok(test::DataType1(std::move(values)));
+  return Result<test::DataType1, Errors>::ok(test::DataType1(std::move(values)));
 }
 
 template<>
@@ -120,20 +123,23 @@ template<>
 }
 
 template<>
-[[nodiscard]] Result<test::DataType2> Node::List::to_value<test::DataType2>() const {
+[[nodiscard]] Result<test::DataType2, Errors> Node::List::to_value_all<test::DataType2>() const {
   std::vector<float> values;
   values.reserve(list_.size());
+  Errors errors;
   for (auto node = list_.cbegin(); node < list_.cend(); ++node) {
-    Result<float> value = node->to_value<float>();
-    if (!value.is_ok()) {
-      Error error = value.get_err();
-      error.add_list(node - list_.cbegin());
-      return Result<test::DataType2>::err(std::move(error));
+    Result<float, Errors> value = node->to_value_all<float>();
+    if (value.is_ok()) {
+      values.push_back(std::move(value.get_ok()));
+    } else {
+      errors.append(value.get_err().add_list(node - list_.cbegin()));
     }
-    values.push_back(std::move(value.get_ok()));
+  }
+  if (!errors.empty()) {
+    return Result<test::DataType2, Errors>::err(std::move(errors));
   }
 
-  return Result<test::DataType2>::ok(test::DataType2(std::move(values)));
+  return Result<test::DataType2, Errors>::ok(test::DataType2(std::move(values)));
 }
 
 template<>
@@ -108,13 +108,19 @@ public:
 namespace termite {
 
 template<>
-[[nodiscard]] Result<test::DataType1> Node::List::to_value<test::DataType1>() const;
+struct has_to_value_all<test::DataType1> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::DataType1, Errors> Node::List::to_value_all<test::DataType1>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::DataType1>(const test::DataType1 &value);
 
 template<>
-[[nodiscard]] Result<test::DataType2> Node::List::to_value<test::DataType2>() const;
+struct has_to_value_all<test::DataType2> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::DataType2, Errors> Node::List::to_value_all<test::DataType2>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::DataType2>(const test::DataType2 &value);
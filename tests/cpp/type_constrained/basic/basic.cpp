@@ -105,13 +105,17 @@ std::ostream &operator<<(std::ostream &os, const DataType2 &x) {
 namespace termite {
 
 template<>
-[[nodiscard]] Result<test::DataType1> Node::to_value<test::DataType1>() const {
-  Result<int> value = to_value<int>();
+[[nodiscard]] Result<test::DataType1, Errors> Node::to_value_all<test::DataType1>() const {
+  Result<int, Errors> value = to_value_all<int>();
   if (!value.is_ok()) {
-    return Result<test::DataType1>::err(Error(value.get_err()));
+    return Result<test::DataType1, Errors>::err(value.get_err());
   }
 
-  return test::DataType1::from_value(value.get_ok());
+  Result<test::DataType1> result = test::DataType1::from_value(value.get_ok());
+  if (!result.is_ok()) {
+    return Result<test::DataType1, Errors>::err(Errors(result.get_err().set_mark(mark_)));
+  }
+  return Result<test::DataType1, Errors>::ok(result.get_ok());
 }
 
 template<>
@@ -120,13 +124,17 @@ template<>
 }
 
 template<>
-[[nodiscard]] Result<test::DataType2> Node::to_value<test::DataType2>() const {
-  Result<float> value = to_value<float>();
+[[nodiscard]] Result<test::DataType2, Errors> Node::to_value_all<test::DataType2>() const {
+  Result<float, Errors> value = to_value_all<float>();
   if (!value.is_ok()) {
-    return Result<test::DataType2>::err(Error(value.get_err()));
+    return Result<test::DataType2, Errors>::err(value.get_err());
   }
 
-  return test::DataType2::from_value(value.get_ok());
+  Result<test::DataType2> result = test::DataType2::from_value(value.get_ok());
+  if (!result.is_ok()) {
+    return Result<test::DataType2, Errors>::err(Errors(result.get_err().set_mark(mark_)));
+  }
+  return Result<test::DataType2, Errors>::ok(result.get_ok());
 }
 
 template<>
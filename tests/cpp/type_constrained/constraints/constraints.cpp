@@ -105,8 +105,8 @@ std::ostream &operator<<(std::ostream &os, const DataType2 &x) {
 }
 
 [[nodiscard]] termite::Result<termite::Empty> DataType2::validate(const float &x) {
-  if (!(std::abs(x) < 1e-9)) {
-    return termite::Result<termite::Empty>::err(termite::Error("Did not pass constraint: std::abs(x) < 1e-9"));
+  if (!(x < 1e-9 && x > -1e-9)) {
+    return termite::Result<termite::Empty>::err(termite::Error("Did not pass constraint: x < 1e-9 && x > -1e-9"));
   }
 
   return termite::Result<termite::Empty>::ok(termite::Empty());
@@ -117,13 +117,17 @@ std::ostream &operator<<(std::ostream &os, const DataType2 &x) {
 namespace termite {
 
 template<>
-[[nodiscard]] Result<test::DataType1> Node::to_value<test::DataType1>() const {
-  Result<int> value = to_value<int>();
+[[nodiscard]] Result<test::DataType1, Errors> Node::to_value_all<test::DataType1>() const {
+  Result<int, Errors> value = to_value_all<int>();
   if (!value.is_ok()) {
-    return Result<test::DataType1>::err(Error(value.get_err()));
+    return Result<test::DataType1, Errors>::err(value.get_err());
   }
 
-  return test::DataType1::from_value(value.get_ok());
+  Result<test::DataType1> result = test::DataType1::from_value(value.get_ok());
+  if (!result.is_ok()) {
+    return Result<test::DataType1, Errors>::err(Errors(result.get_err().set_mark(mark_)));
+  }
+  return Result<test::DataType1, Errors>::ok(result.get_ok());
 }
 
 template<>
@@ -132,13 +136,17 @@ template<>
 }
 
 template<>
-[[nodiscard]] Result<test::DataType2> Node::to_value<test::DataType2>() const {
-  Result<float> value = to_value<float>();
+[[nodiscard]] Result<test::DataType2, Errors> Node::to_value_all<test::DataType2>() const {
+  Result<float, Errors> value = to_value_all<float>();
   if (!value.is_ok()) {
-    return Result<test::DataType2>::err(Error(value.get_err()));
+    return Result<test::DataType2, Errors>::err(value.get_err());
   }
 
-  return test::DataType2::from_value(value.get_ok());
+  Result<test::DataType2> result = test::DataType2::from_value(value.get_ok());
+  if (!result.is_ok()) {
+    return Result<test::DataType2, Errors>::err(Errors(result.get_err().set_mark(mark_)));
+  }
+  return Result<test::DataType2, Errors>::ok(result.get_ok());
 }
 
 template<>
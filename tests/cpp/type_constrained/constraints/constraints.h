@@ -124,7 +124,7 @@ public:
 
   /**
    * @brief Sets the value if it fulfills the constraints:
-   * - std::abs(x) < 1e-9
+   * - x < 1e-9 && x > -1e-9
    *
    * @param value The value to set
    * @return An error if one of the constraints were not fulfilled
@@ -176,7 +176,7 @@ private:
 
   /**
    * @brief Validates if value is correct using the following constraints:
-   * - std::abs(x) < 1e-9
+   * - x < 1e-9 && x > -1e-9
    *
    * @param x The value of the parameter to validate
    */
@@ -194,13 +194,19 @@ private:
 namespace termite {
 
 template<>
-[[nodiscard]] Result<test::DataType1> Node::to_value<test::DataType1>() const;
+struct has_to_value_all<test::DataType1> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::DataType1, Errors> Node::to_value_all<test::DataType1>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::DataType1>(const test::DataType1 &value);
 
 template<>
-[[nodiscard]] Result<test::DataType2> Node::to_value<test::DataType2>() const;
+struct has_to_value_all<test::DataType2> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::DataType2, Errors> Node::to_value_all<test::DataType2>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::DataType2>(const test::DataType2 &value);
@@ -0,0 +1,114 @@
+// Generated with the Termite Data Model Generator
+#include "matches.h"
+#include <regex>
+
+
+
+namespace test {
+
+namespace {
+
+// Code to make printing easier
+template <typename T, typename = void>
+struct has_insertion_operator : std::false_type {};
+template <typename T>
+struct has_insertion_operator<T, std::void_t<decltype(std::declval<std::ostream &>() << std::declval<T>())>> : std::true_type {};
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::optional<T> &value) {
+  if (value) {
+    return os << *value;
+  } else {
+    return os << "nullopt";
+  }
+}
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::vector<T> &value) {
+  os << "[ ";
+  for (auto value_it = value.cbegin(); value_it != value.cend(); ++value_it) {
+    if (value_it != value.cbegin()) {
+      os << ", ";
+    }
+    os << *value_it;
+  }
+  return os << " ]";
+}
+
+} // namespace
+
+[[nodiscard]] termite::Result<Version> Version::from_value(termite::string value) {
+  termite::Result<termite::Empty> validate_result = validate(value);
+  if (!validate_result.is_ok()) {
+    termite::Error error = validate_result.get_err();
+    return termite::Result<Version>::err(std::move(error));
+  }
+
+  return termite::Result<Version>::ok(Version(std::move(value), nullptr));
+}
+
+[[nodiscard]] termite::Result<termite::Empty> Version::set(termite::string value) {
+  termite::Result<termite::Empty> validate_result = validate(value);
+  if (!validate_result.is_ok()) {
+    return validate_result;
+  }
+
+  value_ = std::move(value);
+  return termite::Result<termite::Empty>::ok(termite::Empty());
+}
+
+[[nodiscard]] bool Version::operator==(const Version &x) const {
+  return value_ == x.value_;
+}
+std::ostream &operator<<(std::ostream &os, const Version &x) {
+  return os << x.value_;
+}
+
+[[nodiscard]] termite::Result<termite::Empty> Version::validate(const termite::string &x) {
+  static const std::regex pattern_0("^[0-9]+(\\.[0-9]+)*$");
+  static const std::regex pattern_1("^1\\.");
+  static const std::regex pattern_2("^2\\.");
+
+  if (!(std::regex_search(x, pattern_0))) {
+    return termite::Result<termite::Empty>::err(termite::Error("Did not pass constraint: matches(x, \"^[0-9]+(\\\\.[0-9]+)*$\")"));
+  }
+
+  if (!(termite::utf8_length(x) <= 8)) {
+    return termite::Result<termite::Empty>::err(termite::Error("Did not pass constraint: len(x) <= 8"));
+  }
+
+  if (!(std::regex_search(x, pattern_1) || std::regex_search(x, pattern_2))) {
+    return termite::Result<termite::Empty>::err(termite::Error("Did not pass constraint: matches(x, \"^1\\\\.\") || matches(x, \"^2\\\\.\")"));
+  }
+
+  return termite::Result<termite::Empty>::ok(termite::Empty());
+}
+
+} // namespace test
+
+namespace termite {
+
+template<>
+[[nodiscard]] Result<test::Version, Errors> Node::to_value_all<test::Version>() const {
+  Result<string, Errors> value = to_value_all<string>();
+  if (!value.is_ok()) {
+    return Result<test::Version, Errors>::err(value.get_err());
+  }
+
+  Result<test::Version> result = test::Version::from_value(value.get_ok());
+  if (!result.is_ok()) {
+    return Result<test::Version, Errors>::err(Errors(result.get_err().set_mark(mark_)));
+  }
+  return Result<test::Version, Errors>::ok(result.get_ok());
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Version>(const test::Version &value) {
+  return Node::from_value(value.get());
+}
+
+} // namespace termite
+
+
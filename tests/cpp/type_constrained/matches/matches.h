@@ -0,0 +1,124 @@
+// Generated with the Termite Data Model Generator
+#ifndef HEADER_TERMITE_H_INCLUDED
+#define HEADER_TERMITE_H_INCLUDED
+
+#include <iostream>
+#include <sstream>
+#include <optional>
+#include <variant>
+#include <algorithm>
+#include <termite.hpp>
+
+
+
+namespace test {
+
+/**
+ * @brief 
+ * 
+ */
+class Version {
+public:
+  /**
+   * @brief Constructs a new Version object, it must be valid or an exception will be thrown
+   * 
+   * @param value The value to store 
+   */
+  explicit Version(termite::string value) : Version(from_value(std::move(value)).get_ok()) {}
+  /**
+   * @brief Constructs a new Version object
+   * 
+   * @param value The value to store 
+   * @return The new constrained type or an error if some constraints were not upheld
+   */
+  [[nodiscard]] static termite::Result<Version> from_value(termite::string value);
+
+  /**
+   * @brief Sets the value if it fulfills the constraints:
+   * - matches(x, "^[0-9]+(\\.[0-9]+)*$")
+   * - len(x) <= 8
+   * - matches(x, "^1\\.") || matches(x, "^2\\.")
+   * 
+   * @param value The value to set
+   * @return An error if one of the constraints were not fulfilled
+   */
+  [[nodiscard]] termite::Result<termite::Empty> set(termite::string value);
+
+  /**
+   * @brief Retrieves a reference to the value
+   * 
+   * @return The reference
+   */
+  [[nodiscard]] const termite::string &get() const {
+    return value_;
+  }
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Version &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Version &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Version &x);
+
+private:
+  /**
+   * @brief Constructs a new Version object
+   * 
+   * @param value The value to store
+   * @param _ A nullptr
+   */
+  explicit Version(termite::string value, void *) : value_(std::move(value)) {}
+
+  /**
+   * @brief Validates if value is correct using the following constraints:
+   * - matches(x, "^[0-9]+(\\.[0-9]+)*$")
+   * - len(x) <= 8
+   * - matches(x, "^1\\.") || matches(x, "^2\\.")
+   * 
+   * @param x The value of the parameter to validate
+   */
+  [[nodiscard]] static termite::Result<termite::Empty> validate(const termite::string &x);
+
+  /**
+   * @brief The validated value
+   * 
+   */
+  termite::string value_;
+};
+
+} // namespace test
+
+namespace termite {
+
+template<>
+struct has_to_value_all<test::Version> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::Version, Errors> Node::to_value_all<test::Version>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Version>(const test::Version &value);
+
+} // namespace termite
+
+
+
+#endif
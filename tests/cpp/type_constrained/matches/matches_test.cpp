@@ -0,0 +1,24 @@
+#include <iostream>
+#include "matches.h"
+
+int main() {
+  if (!test::Version::from_value("1.2.3").is_ok()) {
+    return 1;
+  }
+  if (!test::Version::from_value("2.0").is_ok()) {
+    return 2;
+  }
+
+  // The patterns are checked again for every value
+  if (test::Version::from_value("1.a").is_ok()) {
+    return 3;
+  }
+  if (test::Version::from_value("3.0").is_ok()) {
+    return 4;
+  }
+  if (test::Version::from_value("1.2.3.4.5").is_ok()) {
+    return 5;
+  }
+
+  return 0;
+}
@@ -121,41 +121,41 @@ template<>
 }
 
 template<>
-[[nodiscard]] Result<test::DataType> Node::Map::to_value<test::DataType>() const {
+[[nodiscard]] Result<test::DataType, Errors> Node::Map::to_value_all<test::DataType>() const {
   if (map_.size() != 1) {
     std::stringstream ss;
     ss << "There must be exactly one enum type specified but received " << map_.size();
-    return Result<test::DataType>::err(Error(ss.str()));
+    return Result<test::DataType, Errors>::err(Errors(Error(ss.str())));
   }
 
   if (map_.cbegin()->first == "Int1") {
-    Result<int> value = map_.cbegin()->second.to_value<int>();
+    Result<int, Errors> value = map_.cbegin()->second.to_value_all<int>();
     if (value.is_ok()) {
-      return Result<test::DataType>::ok(test::DataType(test::DataType::TypeInt1{value.get_ok()}));
+      return Result<test::DataType, Errors>::ok(test::DataType(test::DataType::TypeInt1{value.get_ok()}));
     }
-    return Result<test::DataType>::err(value.get_err().add_field("Int1"));
+    return Result<test::DataType, Errors>::err(value.get_err().add_field("Int1"));
   }
   if (map_.cbegin()->first == "Int2") {
-    Result<int> value = map_.cbegin()->second.to_value<int>();
+    Result<int, Errors> value = map_.cbegin()->second.to_value_all<int>();
     if (value.is_ok()) {
-      return Result<test::DataType>::ok(test::DataType(test::DataType::TypeInt2{value.get_ok()}));
+      return Result<test::DataType, Errors>::ok(test::DataType(test::DataType::TypeInt2{value.get_ok()}));
     }
-    return Result<test::DataType>::err(value.get_err().add_field("Int2"));
+    return Result<test::DataType, Errors>::err(value.get_err().add_field("Int2"));
   }
   if (map_.cbegin()->first == "Float") {
-    Result<float> value = map_.cbegin()->second.to_value<float>();
+    Result<float, Errors> value = map_.cbegin()->second.to_value_all<float>();
     if (value.is_ok()) {
-      return Result<test::DataType>::ok(test::DataType(test::DataType::TypeFloat{value.get_ok()}));
+      return Result<test::DataType, Errors>::ok(test::DataType(test::DataType::TypeFloat{value.get_ok()}));
     }
-    return Result<test::DataType>::err(value.get_err().add_field("Float"));
+    return Result<test::DataType, Errors>::err(value.get_err().add_field("Float"));
   }
   if (map_.cbegin()->first == "Empty") {
-    return Result<test::DataType>::err(Error("Enum type Empty must not include values"));
+    return Result<test::DataType, Errors>::err(Errors(Error("Enum type Empty must not include values")));
   }
 
   std::stringstream ss;
   ss << "Unknown enum type \"" << map_.cbegin()->first << "\"";
-  return Result<test::DataType>::err(Error(ss.str()));
+  return Result<test::DataType, Errors>::err(Errors(Error(ss.str())));
 }
 
 template<>
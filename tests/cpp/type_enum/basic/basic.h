@@ -260,11 +260,14 @@ struct DataType {
 
 namespace termite {
 
+template<>
+struct has_to_value_all<test::DataType> : std::true_type {};
+
 template<>
 [[nodiscard]] Result<test::DataType> Node::Value::to_value<test::DataType>() const;
 
 template<>
-[[nodiscard]] Result<test::DataType> Node::Map::to_value<test::DataType>() const;
+[[nodiscard]] Result<test::DataType, Errors> Node::Map::to_value_all<test::DataType>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::DataType>(const test::DataType &value);
@@ -0,0 +1,133 @@
+// Generated with the Termite Data Model Generator
+#include "basic.h"
+
+
+
+namespace test {
+
+namespace {
+
+// Code to make printing easier
+template <typename T, typename = void>
+struct has_insertion_operator : std::false_type {};
+template <typename T>
+struct has_insertion_operator<T, std::void_t<decltype(std::declval<std::ostream &>() << std::declval<T>())>> : std::true_type {};
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::optional<T> &value) {
+  if (value) {
+    return os << *value;
+  } else {
+    return os << "nullopt";
+  }
+}
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::vector<T> &value) {
+  os << "[ ";
+  for (auto value_it = value.cbegin(); value_it != value.cend(); ++value_it) {
+    if (value_it != value.cbegin()) {
+      os << ", ";
+    }
+    os << *value_it;
+  }
+  return os << " ]";
+}
+
+} // namespace
+
+bool DataType1::operator==(const DataType1 &x) const {
+  return values == x.values;
+}
+
+std::ostream &operator<<(std::ostream &os, const DataType1 &x) {
+  os << "{ values: { ";
+  for (auto value = x.values.cbegin(); value != x.values.cend(); ++value) {
+    if (value != x.values.cbegin()) {
+      os << ", ";
+    }
+    os << "\"" << value->first << "\": " << value->second;
+  }
+  return os << " } }";
+}
+
+bool DataType2::operator==(const DataType2 &x) const {
+  return values == x.values;
+}
+
+std::ostream &operator<<(std::ostream &os, const DataType2 &x) {
+  os << "{ values: { ";
+  for (auto value = x.values.cbegin(); value != x.values.cend(); ++value) {
+    if (value != x.values.cbegin()) {
+      os << ", ";
+    }
+    os << "\"" << value->first << "\": " << value->second;
+  }
+  return os << " } }";
+}
+
+} // namespace test
+
+namespace termite {
+
+template<>
+[[nodiscard]] Result<test::DataType1, Errors> Node::Map::to_value_all<test::DataType1>() const {
+  std::map<std::string, int> values;
+  Errors errors;
+  for (auto node = map_.cbegin(); node != map_.cend(); ++node) {
+    Result<int, Errors> value = node->second.to_value_all<int>();
+    if (value.is_ok()) {
+      values.insert({node->first, std::move(value.get_ok())});
+    } else {
+      errors.append(value.get_err().add_field(node->first));
+    }
+  }
+  if (!errors.empty()) {
+    return Result<test::DataType1, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::DataType1, Errors>::ok(test::DataType1(std::move(values)));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DataType1>(const test::DataType1 &value) {
+  std::map<std::string, Node> map;
+  for (auto node = value.values.cbegin(); node != value.values.cend(); ++node) {
+    map.insert({node->first, Node::from_value(node->second)});
+  }
+  return Node(Node::Map(std::move(map)));
+}
+
+template<>
+[[nodiscard]] Result<test::DataType2, Errors> Node::Map::to_value_all<test::DataType2>() const {
+  std::map<std::string, float> values;
+  Errors errors;
+  for (auto node = map_.cbegin(); node != map_.cend(); ++node) {
+    Result<float, Errors> value = node->second.to_value_all<float>();
+    if (value.is_ok()) {
+      values.insert({node->first, std::move(value.get_ok())});
+    } else {
+      errors.append(value.get_err().add_field(node->first));
+    }
+  }
+  if (!errors.empty()) {
+    return Result<test::DataType2, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::DataType2, Errors>::ok(test::DataType2(std::move(values)));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DataType2>(const test::DataType2 &value) {
+  std::map<std::string, Node> map;
+  for (auto node = value.values.cbegin(); node != value.values.cend(); ++node) {
+    map.insert({node->first, Node::from_value(node->second)});
+  }
+  return Node(Node::Map(std::move(map)));
+}
+
+} // namespace termite
+
+
@@ -0,0 +1,95 @@
+#include <iostream>
+#include "basic.h"
+
+int main() {
+  auto value1 = test::DataType1({{"a", 1}, {"b", 2}});
+  auto value2 = test::DataType2({{"a", 1.5}, {"c", -3.5}});
+  if (value1 != value1) {
+    return 1;
+  }
+  if (value1 == test::DataType1({{"a", 1}, {"b", 2}, {"c", 3}})) {
+    return 3;
+  }
+  if (value1 == test::DataType1({{"a", 1}, {"b", 3}})) {
+    return 4;
+  }
+  if (value2 == test::DataType2({{"a", 1.5}})) {
+    return 5;
+  }
+  if (value2 == test::DataType2({{"a", 1.5}, {"b", -3.5}})) {
+    return 6;
+  }
+
+  std::map<std::string, termite::Node> map_correct1;
+  map_correct1.insert({"a", termite::Node(termite::Node::Value("1"))});
+  map_correct1.insert({"b", termite::Node(termite::Node::Value("2"))});
+  termite::Node node_correct1(termite::Node::Map(std::move(map_correct1)));
+  auto value_read_correct1 = node_correct1.to_value<test::DataType1>();
+  if (!value_read_correct1.is_ok()) {
+    return 11;
+  }
+  if (value_read_correct1.get_ok() != value1) {
+    return 12;
+  }
+
+  std::map<std::string, termite::Node> map_correct2;
+  map_correct2.insert({"a", termite::Node(termite::Node::Value("1.5"))});
+  map_correct2.insert({"c", termite::Node(termite::Node::Value("-3.5"))});
+  termite::Node node_correct2(termite::Node::Map(std::move(map_correct2)));
+  auto value_read_correct2 = node_correct2.to_value<test::DataType2>();
+  if (!value_read_correct2.is_ok()) {
+    return 13;
+  }
+  if (value_read_correct2.get_ok() != value2) {
+    return 14;
+  }
+
+  std::map<std::string, termite::Node> map_empty;
+  termite::Node node_empty(termite::Node::Map(std::move(map_empty)));
+  auto value_read_empty = node_empty.to_value<test::DataType1>();
+  if (!value_read_empty.is_ok()) {
+    return 15;
+  }
+  if (value_read_empty.get_ok() != test::DataType1({})) {
+    return 16;
+  }
+
+  std::map<std::string, termite::Node> map_type1;
+  map_type1.insert({"a", termite::Node(termite::Node::Value("1"))});
+  map_type1.insert({"b", termite::Node(termite::Node::Value("2.5"))});
+  termite::Node node_type1(termite::Node::Map(std::move(map_type1)));
+  auto value_read_type1 = node_type1.to_value<test::DataType1>();
+  if (value_read_type1.is_ok()) {
+    return 17;
+  }
+
+  termite::Node node_wrong(termite::Node::Value("1.0"));
+  auto value_wrong_wrong = node_wrong.to_value<test::DataType1>();
+  if (value_wrong_wrong.is_ok()) {
+    return 18;
+  }
+
+  std::vector<termite::Node> vector_wrong;
+  vector_wrong.emplace_back(termite::Node::Value("1"));
+  termite::Node node_list(termite::Node::List(std::move(vector_wrong)));
+  auto value_list_wrong = node_list.to_value<test::DataType1>();
+  if (value_list_wrong.is_ok()) {
+    return 19;
+  }
+
+  termite::Node converted_node = termite::Node::from_value(value1);
+  auto converted_value = converted_node.to_value<test::DataType1>();
+  if (!converted_value.is_ok()) {
+    return 20;
+  }
+  if (converted_value.get_ok() != value1) {
+    return 21;
+  }
+
+  std::cout << value1 << std::endl;
+  std::cout << value2 << std::endl;
+
+  std::cout << "Done" << std::endl;
+
+  return 0;
+}
@@ -0,0 +1,148 @@
+// Generated with the Termite Data Model Generator
+#include "key_type.h"
+
+
+
+namespace test {
+
+namespace {
+
+// Code to make printing easier
+template <typename T, typename = void>
+struct has_insertion_operator : std::false_type {};
+template <typename T>
+struct has_insertion_operator<T, std::void_t<decltype(std::declval<std::ostream &>() << std::declval<T>())>> : std::true_type {};
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::optional<T> &value) {
+  if (value) {
+    return os << *value;
+  } else {
+    return os << "nullopt";
+  }
+}
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::vector<T> &value) {
+  os << "[ ";
+  for (auto value_it = value.cbegin(); value_it != value.cend(); ++value_it) {
+    if (value_it != value.cbegin()) {
+      os << ", ";
+    }
+    os << *value_it;
+  }
+  return os << " ]";
+}
+
+} // namespace
+
+[[nodiscard]] termite::Result<Key> Key::from_value(termite::string value) {
+  termite::Result<termite::Empty> validate_result = validate(value);
+  if (!validate_result.is_ok()) {
+    termite::Error error = validate_result.get_err();
+    return termite::Result<Key>::err(std::move(error));
+  }
+
+  return termite::Result<Key>::ok(Key(std::move(value), nullptr));
+}
+
+[[nodiscard]] termite::Result<termite::Empty> Key::set(termite::string value) {
+  termite::Result<termite::Empty> validate_result = validate(value);
+  if (!validate_result.is_ok()) {
+    return validate_result;
+  }
+
+  value_ = std::move(value);
+  return termite::Result<termite::Empty>::ok(termite::Empty());
+}
+
+[[nodiscard]] bool Key::operator==(const Key &x) const {
+  return value_ == x.value_;
+}
+std::ostream &operator<<(std::ostream &os, const Key &x) {
+  return os << x.value_;
+}
+
+[[nodiscard]] termite::Result<termite::Empty> Key::validate(const termite::string &x) {
+  if (!(termite::utf8_length(x) == 2)) {
+    return termite::Result<termite::Empty>::err(termite::Error("Did not pass constraint: len(x) == 2"));
+  }
+
+  return termite::Result<termite::Empty>::ok(termite::Empty());
+}
+
+bool DataType::operator==(const DataType &x) const {
+  return values == x.values;
+}
+
+std::ostream &operator<<(std::ostream &os, const DataType &x) {
+  os << "{ values: { ";
+  for (auto value = x.values.cbegin(); value != x.values.cend(); ++value) {
+    if (value != x.values.cbegin()) {
+      os << ", ";
+    }
+    os << "\"" << value->first << "\": " << value->second;
+  }
+  return os << " } }";
+}
+
+} // namespace test
+
+namespace termite {
+
+template<>
+[[nodiscard]] Result<test::Key, Errors> Node::to_value_all<test::Key>() const {
+  Result<string, Errors> value = to_value_all<string>();
+  if (!value.is_ok()) {
+    return Result<test::Key, Errors>::err(value.get_err());
+  }
+
+  Result<test::Key> result = test::Key::from_value(value.get_ok());
+  if (!result.is_ok()) {
+    return Result<test::Key, Errors>::err(Errors(result.get_err().set_mark(mark_)));
+  }
+  return Result<test::Key, Errors>::ok(result.get_ok());
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Key>(const test::Key &value) {
+  return Node::from_value(value.get());
+}
+
+template<>
+[[nodiscard]] Result<test::DataType, Errors> Node::Map::to_value_all<test::DataType>() const {
+  std::map<std::string, integer> values;
+  Errors errors;
+  for (auto node = map_.cbegin(); node != map_.cend(); ++node) {
+    Result<test::Key, Errors> key = Node(Node::Value(node->first)).to_value_all<test::Key>();
+    if (!key.is_ok()) {
+      errors.append(key.get_err().add_field(node->first));
+    }
+    Result<integer, Errors> value = node->second.to_value_all<integer>();
+    if (value.is_ok()) {
+      values.insert({node->first, std::move(value.get_ok())});
+    } else {
+      errors.append(value.get_err().add_field(node->first));
+    }
+  }
+  if (!errors.empty()) {
+    return Result<test::DataType, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::DataType, Errors>::ok(test::DataType(std::move(values)));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DataType>(const test::DataType &value) {
+  std::map<std::string, Node> map;
+  for (auto node = value.values.cbegin(); node != value.values.cend(); ++node) {
+    map.insert({node->first, Node::from_value(node->second)});
+  }
+  return Node(Node::Map(std::move(map)));
+}
+
+} // namespace termite
+
+
@@ -0,0 +1,174 @@
+// Generated with the Termite Data Model Generator
+#ifndef HEADER_TERMITE_H_INCLUDED
+#define HEADER_TERMITE_H_INCLUDED
+
+#include <iostream>
+#include <sstream>
+#include <optional>
+#include <variant>
+#include <algorithm>
+#include <termite.hpp>
+
+
+
+namespace test {
+
+/**
+ * @brief
+ *
+ */
+class Key {
+public:
+  /**
+   * @brief Constructs a new Key object, it must be valid or an exception will be thrown
+   *
+   * @param value The value to store
+   */
+  explicit Key(termite::string value) : Key(from_value(std::move(value)).get_ok()) {}
+  /**
+   * @brief Constructs a new Key object
+   *
+   * @param value The value to store
+   * @return The new constrained type or an error if some constraints were not upheld
+   */
+  [[nodiscard]] static termite::Result<Key> from_value(termite::string value);
+
+  /**
+   * @brief Sets the value if it fulfills the constraints:
+   * - len(x) == 2
+   *
+   * @param value The value to set
+   * @return An error if one of the constraints were not fulfilled
+   */
+  [[nodiscard]] termite::Result<termite::Empty> set(termite::string value);
+
+  /**
+   * @brief Retrieves a reference to the value
+   *
+   * @return The reference
+   */
+  [[nodiscard]] const termite::string &get() const {
+    return value_;
+  }
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   *
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Key &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   *
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Key &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   *
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Key &x);
+
+private:
+  /**
+   * @brief Constructs a new Key object
+   *
+   * @param value The value to store
+   * @param _ A nullptr
+   */
+  explicit Key(termite::string value, void *) : value_(std::move(value)) {}
+
+  /**
+   * @brief Validates if value is correct using the following constraints:
+   * - len(x) == 2
+   *
+   * @param x The value of the parameter to validate
+   */
+  [[nodiscard]] static termite::Result<termite::Empty> validate(const termite::string &x);
+
+  /**
+   * @brief The validated value
+   *
+   */
+  termite::string value_;
+};
+
+/**
+ * @brief
+ *
+ */
+struct DataType {
+public:
+  /**
+   * @brief Constructs a new DataType object
+   *
+   * @param values The values of the map
+   */
+  explicit DataType(std::map<std::string, termite::integer> values) : values(std::move(values)) {}
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   *
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const DataType &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   *
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const DataType &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   *
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const DataType &x);
+
+  /**
+   * @brief The values of the map
+   *
+   */
+  std::map<std::string, termite::integer> values;
+};
+
+} // namespace test
+
+namespace termite {
+
+template<>
+struct has_to_value_all<test::Key> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::Key, Errors> Node::to_value_all<test::Key>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Key>(const test::Key &value);
+
+template<>
+struct has_to_value_all<test::DataType> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::DataType, Errors> Node::Map::to_value_all<test::DataType>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DataType>(const test::DataType &value);
+
+} // namespace termite
+
+
+
+#endif
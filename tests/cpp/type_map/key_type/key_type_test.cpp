@@ -0,0 +1,59 @@
+#include <iostream>
+#include "key_type.h"
+
+int main() {
+  auto value = test::DataType({{"ab", 1}, {"cd", 2}});
+
+  std::map<std::string, termite::Node> map_correct;
+  map_correct.insert({"ab", termite::Node(termite::Node::Value("1"))});
+  map_correct.insert({"cd", termite::Node(termite::Node::Value("2"))});
+  termite::Node node_correct(termite::Node::Map(std::move(map_correct)));
+  auto value_read_correct = node_correct.to_value<test::DataType>();
+  if (!value_read_correct.is_ok()) {
+    return 1;
+  }
+  if (value_read_correct.get_ok() != value) {
+    return 2;
+  }
+
+  std::map<std::string, termite::Node> map_key;
+  map_key.insert({"ab", termite::Node(termite::Node::Value("1"))});
+  map_key.insert({"cde", termite::Node(termite::Node::Value("2"))});
+  termite::Node node_key(termite::Node::Map(std::move(map_key)));
+  auto value_read_key = node_key.to_value<test::DataType>();
+  if (value_read_key.is_ok()) {
+    return 3;
+  }
+
+  std::map<std::string, termite::Node> map_type;
+  map_type.insert({"ab", termite::Node(termite::Node::Value("1.5"))});
+  termite::Node node_type(termite::Node::Map(std::move(map_type)));
+  auto value_read_type = node_type.to_value<test::DataType>();
+  if (value_read_type.is_ok()) {
+    return 4;
+  }
+
+  std::map<std::string, termite::Node> map_utf8;
+  map_utf8.insert({"\xC3\xA9\xC3\xA9", termite::Node(termite::Node::Value("1"))});
+  termite::Node node_utf8(termite::Node::Map(std::move(map_utf8)));
+  auto value_read_utf8 = node_utf8.to_value<test::DataType>();
+  if (!value_read_utf8.is_ok()) {
+    return 7;
+  }
+
+  termite::Node converted_node = termite::Node::from_value(value);
+  auto converted_value = converted_node.to_value<test::DataType>();
+  if (!converted_value.is_ok()) {
+    return 5;
+  }
+  if (converted_value.get_ok() != value) {
+    return 6;
+  }
+
+  std::cout << value << std::endl;
+  std::cout << value_read_key.get_err() << std::endl;
+
+  std::cout << "Done" << std::endl;
+
+  return 0;
+}
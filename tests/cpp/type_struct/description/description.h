@@ -110,13 +110,19 @@ public:
 namespace termite {
 
 template<>
-[[nodiscard]] Result<test::DataType1> Node::Map::to_value<test::DataType1>() const;
+struct has_to_value_all<test::DataType1> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::DataType1, Errors> Node::Map::to_value_all<test::DataType1>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::DataType1>(const test::DataType1 &value);
 
 template<>
-[[nodiscard]] Result<test::DataType2> Node::Map::to_value<test::DataType2>() const;
+struct has_to_value_all<test::DataType2> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::DataType2, Errors> Node::Map::to_value_all<test::DataType2>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::DataType2>(const test::DataType2 &value);
@@ -0,0 +1,212 @@
+// Generated with the Termite Data Model Generator
+#include "empty_default.h"
+
+
+
+namespace test {
+
+namespace {
+
+// Code to make printing easier
+template <typename T, typename = void>
+struct has_insertion_operator : std::false_type {};
+template <typename T>
+struct has_insertion_operator<T, std::void_t<decltype(std::declval<std::ostream &>() << std::declval<T>())>> : std::true_type {};
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::optional<T> &value) {
+  if (value) {
+    return os << *value;
+  } else {
+    return os << "nullopt";
+  }
+}
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::vector<T> &value) {
+  os << "[ ";
+  for (auto value_it = value.cbegin(); value_it != value.cend(); ++value_it) {
+    if (value_it != value.cbegin()) {
+      os << ", ";
+    }
+    os << *value_it;
+  }
+  return os << " ]";
+}
+
+} // namespace
+
+bool Values::operator==(const Values &x) const {
+  return values == x.values;
+}
+
+std::ostream &operator<<(std::ostream &os, const Values &x) {
+  os << "{ values: { ";
+  for (auto value = x.values.cbegin(); value != x.values.cend(); ++value) {
+    if (value != x.values.cbegin()) {
+      os << ", ";
+    }
+    os << "\"" << value->first << "\": " << value->second;
+  }
+  return os << " } }";
+}
+
+bool List::operator==(const List &x) const {
+  if (values.size() != x.values.size()) {
+    return false;
+  }
+
+  for (auto lhs = values.cbegin(), rhs = x.values.cbegin(); lhs < values.cend(); ++lhs, ++rhs) {
+    if (*lhs != *rhs) {
+      return false;
+    }
+  }
+
+  return true;
+}
+
+std::ostream &operator<<(std::ostream &os, const List &x) {
+  os << "{ values: [ ";
+  for (auto value = x.values.cbegin(); value < x.values.cend(); ++value) {
+    if (value != x.values.cbegin()) {
+      os << ", ";
+    }
+    os << *value;
+  }
+  return os << " ] }";
+}
+
+[[nodiscard]] bool DataType::operator==(const DataType &x) const {
+  return this->values == x.values && this->list == x.list && extra_fields == x.extra_fields;
+}
+
+[[nodiscard]] Values DataType::default_values() {
+  auto node = termite::Node(termite::Node::Map(std::map<std::string, termite::Node>{}));
+
+  return node.to_value<Values>().get_ok();
+}
+
+[[nodiscard]] List DataType::default_list() {
+  auto node = termite::Node(termite::Node::List(std::vector<termite::Node>{}));
+
+  return node.to_value<List>().get_ok();
+}
+
+std::ostream &operator<<(std::ostream &os, const DataType &x) {
+  return os << "{ " << "values: " << x.values << ", " << "list: " << x.list << ", " << "extra_fields: " << x.extra_fields << " }";
+}
+
+} // namespace test
+
+namespace termite {
+
+template<>
+[[nodiscard]] Result<test::Values, Errors> Node::Map::to_value_all<test::Values>() const {
+  std::map<std::string, integer> values;
+  Errors errors;
+  for (auto node = map_.cbegin(); node != map_.cend(); ++node) {
+    Result<integer, Errors> value = node->second.to_value_all<integer>();
+    if (value.is_ok()) {
+      values.insert({node->first, std::move(value.get_ok())});
+    } else {
+      errors.append(value.get_err().add_field(node->first));
+    }
+  }
+  if (!errors.empty()) {
+    return Result<test::Values, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::Values, Errors>::ok(test::Values(std::move(values)));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Values>(const test::Values &value) {
+  std::map<std::string, Node> map;
+  for (auto node = value.values.cbegin(); node != value.values.cend(); ++node) {
+    map.insert({node->first, Node::from_value(node->second)});
+  }
+  return Node(Node::Map(std::move(map)));
+}
+
+template<>
+[[nodiscard]] Result<test::List, Errors> Node::List::to_value_all<test::List>() const {
+  std::vector<integer> values;
+  values.reserve(list_.size());
+  Errors errors;
+  for (auto node = list_.cbegin(); node < list_.cend(); ++node) {
+    Result<integer, Errors> value = node->to_value_all<integer>();
+    if (value.is_ok()) {
+      values.push_back(std::move(value.get_ok()));
+    } else {
+      errors.append(value.get_err().add_list(node - list_.cbegin()));
+    }
+  }
+  if (!errors.empty()) {
+    return Result<test::List, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::List, Errors>::ok(test::List(std::move(values)));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::List>(const test::List &value) {
+  std::vector<Node> list;
+  list.reserve(value.values.size());
+  std::transform(value.values.cbegin(), value.values.cend(), std::back_inserter(list), [](const integer &value) {
+    return Node::from_value(value);
+  });
+  return Node(Node::List(std::move(list)));
+}
+
+template<>
+[[nodiscard]] Result<test::DataType, Errors> Node::Map::to_value_all<test::DataType>() const {
+  std::map<std::string, Node> map = map_;
+  Errors errors;
+
+  auto location_values = map.find("values");
+  test::Values value_values = test::DataType::default_values();
+  if (location_values != map.end()) {
+    Result<test::Values, Errors> raw_value_values = location_values->second.to_value_all<test::Values>();
+    if (raw_value_values.is_ok()) {
+      value_values = raw_value_values.get_ok();
+    } else {
+      errors.append(raw_value_values.get_err().add_field("values"));
+    }
+    map.erase(location_values);
+  }
+
+  auto location_list = map.find("list");
+  test::List value_list = test::DataType::default_list();
+  if (location_list != map.end()) {
+    Result<test::List, Errors> raw_value_list = location_list->second.to_value_all<test::List>();
+    if (raw_value_list.is_ok()) {
+      value_list = raw_value_list.get_ok();
+    } else {
+      errors.append(raw_value_list.get_err().add_field("list"));
+    }
+    map.erase(location_list);
+  }
+
+  if (!errors.empty()) {
+    return Result<test::DataType, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::DataType, Errors>::ok(test::DataType(std::move(value_values), std::move(value_list), Map(std::move(map))));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DataType>(const test::DataType &value) {
+  std::map<std::string, Node> map = value.extra_fields.get();
+
+  map.insert({"values", Node::from_value(value.values)});
+
+  map.insert({"list", Node::from_value(value.list)});
+
+  return Node(Node::Map(std::move(map)));
+}
+
+} // namespace termite
+
+
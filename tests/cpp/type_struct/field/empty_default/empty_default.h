@@ -0,0 +1,211 @@
+// Generated with the Termite Data Model Generator
+#ifndef HEADER_TERMITE_H_INCLUDED
+#define HEADER_TERMITE_H_INCLUDED
+
+#include <iostream>
+#include <sstream>
+#include <optional>
+#include <variant>
+#include <algorithm>
+#include <termite.hpp>
+
+
+
+namespace test {
+
+/**
+ * @brief 
+ * 
+ */
+struct Values {
+public:
+  /**
+   * @brief Constructs a new Values object
+   * 
+   * @param values The values of the map
+   */
+  explicit Values(std::map<std::string, termite::integer> values) : values(std::move(values)) {}
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Values &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Values &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Values &x);
+
+  /**
+   * @brief The values of the map
+   * 
+   */
+  std::map<std::string, termite::integer> values;
+};
+
+/**
+ * @brief 
+ * 
+ */
+struct List {
+public:
+  /**
+   * @brief Constructs a new List object
+   * 
+   * @param values The values of the array
+   */
+  explicit List(std::vector<termite::integer> values) : values(std::move(values)) {}
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const List &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const List &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const List &x);
+
+  /**
+   * @brief The values of the array
+   * 
+   */
+  std::vector<termite::integer> values;
+};
+
+/**
+ * @brief 
+ * 
+ */
+struct DataType {
+public:
+  /**
+   * @brief Constructs a new DataType object
+   * 
+   * @param values 
+   * @param list 
+   * @param extra_fields Any extra fields to attach to this struct
+   */
+  explicit DataType(Values values, List list, ::termite::Node::Map extra_fields = ::termite::Node::Map()) : values(std::move(values)), list(std::move(list)), extra_fields(std::move(extra_fields)) {}
+
+  /**
+   * @brief Gets the default value for values
+   * 
+   * @return The default value for values
+   */
+  [[nodiscard]] static Values default_values();
+  /**
+   * @brief Gets the default value for list
+   * 
+   * @return The default value for list
+   */
+  [[nodiscard]] static List default_list();
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const DataType &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const DataType &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const DataType &x);
+
+  /**
+   * @brief 
+   * 
+   */
+  Values values;
+  /**
+   * @brief 
+   * 
+   */
+  List list;
+  /**
+   * @brief All extra fields from when reading which could not be captured
+   * 
+   */
+  ::termite::Node::Map extra_fields;
+};
+
+} // namespace test
+
+namespace termite {
+
+template<>
+struct has_to_value_all<test::Values> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::Values, Errors> Node::Map::to_value_all<test::Values>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Values>(const test::Values &value);
+
+template<>
+struct has_to_value_all<test::List> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::List, Errors> Node::List::to_value_all<test::List>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::List>(const test::List &value);
+
+template<>
+struct has_to_value_all<test::DataType> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::DataType, Errors> Node::Map::to_value_all<test::DataType>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DataType>(const test::DataType &value);
+
+} // namespace termite
+
+
+
+#endif
@@ -0,0 +1,23 @@
+#include <iostream>
+#include "empty_default.h"
+
+int main() {
+  if (!test::DataType::default_values().values.empty()) {
+    return 1;
+  }
+  if (!test::DataType::default_list().values.empty()) {
+    return 2;
+  }
+
+  // Missing fields use the empty defaults
+  auto value = termite::Node(termite::Node::Map()).to_value<test::DataType>();
+  if (!value.is_ok()) {
+    std::cout << value.get_err() << std::endl;
+    return 3;
+  }
+  if (value.get_ok() != test::DataType(test::Values({}), test::List({}))) {
+    return 4;
+  }
+
+  return 0;
+}
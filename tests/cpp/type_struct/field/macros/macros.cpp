@@ -61,36 +61,39 @@ std::ostream &operator<<(std::ostream &os, const DataType &x) {
 namespace termite {
 
 template<>
-[[nodiscard]] Result<test::DataType> Node::Map::to_value<test::DataType>() const {
+[[nodiscard]] Result<test::DataType, Errors> Node::Map::to_value_all<test::DataType>() const {
   std::map<std::string, Node> map = map_;
+  Errors errors;
 
   auto location_field1 = map.find("field1");
   int value_field1 = test::DataType::default_field1();
   if (location_field1 != map.end()) {
-    Result<int> raw_value_field1 = location_field1->second.to_value<int>();
-    if (!raw_value_field1.is_ok()) {
-      Error error = raw_value_field1.get_err();
-      error.add_field("field1");
-      return Result<test::DataType>::err(std::move(error));
+    Result<int, Errors> raw_value_field1 = location_field1->second.to_value_all<int>();
+    if (raw_value_field1.is_ok()) {
+      value_field1 = raw_value_field1.get_ok();
+    } else {
+      errors.append(raw_value_field1.get_err().add_field("field1"));
     }
-    value_field1 = raw_value_field1.get_ok();
     map.erase(location_field1);
   }
 
   auto location_field2 = map.find("field2");
   std::optional<float> value_field2 = test::DataType::default_field2();
   if (location_field2 != map.end()) {
-    Result<float> raw_value_field2 = location_field2->second.to_value<float>();
-    if (!raw_value_field2.is_ok()) {
-      Error error = raw_value_field2.get_err();
-      error.add_field("field2");
-      return Result<test::DataType>::err(std::move(error));
+    Result<float, Errors> raw_value_field2 = location_field2->second.to_value_all<float>();
+    if (raw_value_field2.is_ok()) {
+      value_field2 = raw_value_field2.get_ok();
+    } else {
+      errors.append(raw_value_field2.get_err().add_field("field2"));
     }
-    value_field2 = raw_value_field2.get_ok();
     map.erase(location_field2);
   }
 
-  return Result<test::DataType>::ok(test::DataType(std::move(value_field1), std::move(value_field2), Map(std::move(map))));
+  if (!errors.empty()) {
+    return Result<test::DataType, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::DataType, Errors>::ok(test::DataType(std::move(value_field1), std::move(value_field2), Map(std::move(map))));
 }
 
 template<>
@@ -0,0 +1,236 @@
+// Generated with the Termite Data Model Generator
+#include "inherit.h"
+
+
+
+namespace test {
+
+namespace {
+
+// Code to make printing easier
+template <typename T, typename = void>
+struct has_insertion_operator : std::false_type {};
+template <typename T>
+struct has_insertion_operator<T, std::void_t<decltype(std::declval<std::ostream &>() << std::declval<T>())>> : std::true_type {};
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::optional<T> &value) {
+  if (value) {
+    return os << *value;
+  } else {
+    return os << "nullopt";
+  }
+}
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::vector<T> &value) {
+  os << "[ ";
+  for (auto value_it = value.cbegin(); value_it != value.cend(); ++value_it) {
+    if (value_it != value.cbegin()) {
+      os << ", ";
+    }
+    os << *value_it;
+  }
+  return os << " ]";
+}
+
+} // namespace
+
+[[nodiscard]] bool DataType3::operator==(const DataType3 &x) const {
+  return this->field1 == x.field1 && this->field2 == x.field2 && this->field3 == x.field3 && extra_fields == x.extra_fields;
+}
+
+[[nodiscard]] std::optional<float> DataType3::default_field2() {
+  return std::nullopt;
+}
+
+[[nodiscard]] int DataType3::default_field3() {
+  auto node = termite::Node(termite::Node::Value("3"));
+
+  return node.to_value<int>().get_ok();
+}
+
+std::ostream &operator<<(std::ostream &os, const DataType3 &x) {
+  return os << "{ " << "field1: " << x.field1 << ", " << "field2: " << x.field2 << ", " << "field3: " << x.field3 << ", " << "extra_fields: " << x.extra_fields << " }";
+}
+
+[[nodiscard]] bool DataType1::operator==(const DataType1 &x) const {
+  return this->field1 == x.field1 && extra_fields == x.extra_fields;
+}
+
+std::ostream &operator<<(std::ostream &os, const DataType1 &x) {
+  return os << "{ " << "field1: " << x.field1 << ", " << "extra_fields: " << x.extra_fields << " }";
+}
+
+[[nodiscard]] bool DataType2::operator==(const DataType2 &x) const {
+  return this->field1 == x.field1 && this->field2 == x.field2 && extra_fields == x.extra_fields;
+}
+
+[[nodiscard]] std::optional<float> DataType2::default_field2() {
+  return std::nullopt;
+}
+
+std::ostream &operator<<(std::ostream &os, const DataType2 &x) {
+  return os << "{ " << "field1: " << x.field1 << ", " << "field2: " << x.field2 << ", " << "extra_fields: " << x.extra_fields << " }";
+}
+
+} // namespace test
+
+namespace termite {
+
+template<>
+[[nodiscard]] Result<test::DataType3, Errors> Node::Map::to_value_all<test::DataType3>() const {
+  std::map<std::string, Node> map = map_;
+  Errors errors;
+
+  auto location_field1 = map.find("field1");
+  std::optional<int> value_field1;
+  if (location_field1 == map.end()) {
+    errors.add(Error("Missing field1"));
+  } else {
+    Result<int, Errors> raw_value_field1 = location_field1->second.to_value_all<int>();
+    if (raw_value_field1.is_ok()) {
+      value_field1 = raw_value_field1.get_ok();
+    } else {
+      errors.append(raw_value_field1.get_err().add_field("field1"));
+    }
+    map.erase(location_field1);
+  }
+
+  auto location_field2 = map.find("field2");
+  std::optional<float> value_field2 = test::DataType3::default_field2();
+  if (location_field2 != map.end()) {
+    Result<float, Errors> raw_value_field2 = location_field2->second.to_value_all<float>();
+    if (raw_value_field2.is_ok()) {
+      value_field2 = raw_value_field2.get_ok();
+    } else {
+      errors.append(raw_value_field2.get_err().add_field("field2"));
+    }
+    map.erase(location_field2);
+  }
+
+  auto location_field3 = map.find("field3");
+  int value_field3 = test::DataType3::default_field3();
+  if (location_field3 != map.end()) {
+    Result<int, Errors> raw_value_field3 = location_field3->second.to_value_all<int>();
+    if (raw_value_field3.is_ok()) {
+      value_field3 = raw_value_field3.get_ok();
+    } else {
+      errors.append(raw_value_field3.get_err().add_field("field3"));
+    }
+    map.erase(location_field3);
+  }
+
+  if (!errors.empty()) {
+    return Result<test::DataType3, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::DataType3, Errors>::ok(test::DataType3(std::move(*value_field1), std::move(value_field2), std::move(value_field3), Map(std::move(map))));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DataType3>(const test::DataType3 &value) {
+  std::map<std::string, Node> map = value.extra_fields.get();
+
+  map.insert({"field1", Node::from_value(value.field1)});
+
+  if (value.field2) {
+    map.insert({"field2", Node::from_value(*value.field2)});
+  }
+
+  map.insert({"field3", Node::from_value(value.field3)});
+
+  return Node(Node::Map(std::move(map)));
+}
+
+template<>
+[[nodiscard]] Result<test::DataType1, Errors> Node::Map::to_value_all<test::DataType1>() const {
+  std::map<std::string, Node> map = map_;
+  Errors errors;
+
+  auto location_field1 = map.find("field1");
+  std::optional<int> value_field1;
+  if (location_field1 == map.end()) {
+    errors.add(Error("Missing field1"));
+  } else {
+    Result<int, Errors> raw_value_field1 = location_field1->second.to_value_all<int>();
+    if (raw_value_field1.is_ok()) {
+      value_field1 = raw_value_field1.get_ok();
+    } else {
+      errors.append(raw_value_field1.get_err().add_field("field1"));
+    }
+    map.erase(location_field1);
+  }
+
+  if (!errors.empty()) {
+    return Result<test::DataType1, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::DataType1, Errors>::ok(test::DataType1(std::move(*value_field1), Map(std::move(map))));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DataType1>(const test::DataType1 &value) {
+  std::map<std::string, Node> map = value.extra_fields.get();
+
+  map.insert({"field1", Node::from_value(value.field1)});
+
+  return Node(Node::Map(std::move(map)));
+}
+
+template<>
+[[nodiscard]] Result<test::DataType2, Errors> Node::Map::to_value_all<test::DataType2>() const {
+  std::map<std::string, Node> map = map_;
+  Errors errors;
+
+  auto location_field1 = map.find("field1");
+  std::optional<int> value_field1;
+  if (location_field1 == map.end()) {
+    errors.add(Error("Missing field1"));
+  } else {
+    Result<int, Errors> raw_value_field1 = location_field1->second.to_value_all<int>();
+    if (raw_value_field1.is_ok()) {
+      value_field1 = raw_value_field1.get_ok();
+    } else {
+      errors.append(raw_value_field1.get_err().add_field("field1"));
+    }
+    map.erase(location_field1);
+  }
+
+  auto location_field2 = map.find("field2");
+  std::optional<float> value_field2 = test::DataType2::default_field2();
+  if (location_field2 != map.end()) {
+    Result<float, Errors> raw_value_field2 = location_field2->second.to_value_all<float>();
+    if (raw_value_field2.is_ok()) {
+      value_field2 = raw_value_field2.get_ok();
+    } else {
+      errors.append(raw_value_field2.get_err().add_field("field2"));
+    }
+    map.erase(location_field2);
+  }
+
+  if (!errors.empty()) {
+    return Result<test::DataType2, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::DataType2, Errors>::ok(test::DataType2(std::move(*value_field1), std::move(value_field2), Map(std::move(map))));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DataType2>(const test::DataType2 &value) {
+  std::map<std::string, Node> map = value.extra_fields.get();
+
+  map.insert({"field1", Node::from_value(value.field1)});
+
+  if (value.field2) {
+    map.insert({"field2", Node::from_value(*value.field2)});
+  }
+
+  return Node(Node::Map(std::move(map)));
+}
+
+} // namespace termite
+
+
@@ -0,0 +1,243 @@
+// Generated with the Termite Data Model Generator
+#ifndef HEADER_TERMITE_H_INCLUDED
+#define HEADER_TERMITE_H_INCLUDED
+
+#include <iostream>
+#include <sstream>
+#include <optional>
+#include <variant>
+#include <algorithm>
+#include <termite.hpp>
+
+
+
+namespace test {
+
+/**
+ * @brief
+ *
+ */
+struct DataType3 {
+public:
+  /**
+   * @brief Constructs a new DataType3 object
+   *
+   * @param field1
+   * @param field2
+   * @param field3
+   * @param extra_fields Any extra fields to attach to this struct
+   */
+  explicit DataType3(int field1, std::optional<float> field2, int field3, ::termite::Node::Map extra_fields = ::termite::Node::Map()) : field1(std::move(field1)), field2(std::move(field2)), field3(std::move(field3)), extra_fields(std::move(extra_fields)) {}
+
+  /**
+   * @brief Gets the default value for field2
+   *
+   * @return The default value for field2
+   */
+  [[nodiscard]] static std::optional<float> default_field2();
+  /**
+   * @brief Gets the default value for field3
+   *
+   * @return The default value for field3
+   */
+  [[nodiscard]] static int default_field3();
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   *
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const DataType3 &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   *
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const DataType3 &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   *
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const DataType3 &x);
+
+  /**
+   * @brief
+   *
+   */
+  int field1;
+  /**
+   * @brief
+   *
+   */
+  std::optional<float> field2;
+  /**
+   * @brief
+   *
+   */
+  int field3;
+  /**
+   * @brief All extra fields from when reading which could not be captured
+   *
+   */
+  ::termite::Node::Map extra_fields;
+};
+
+/**
+ * @brief
+ *
+ */
+struct DataType1 {
+public:
+  /**
+   * @brief Constructs a new DataType1 object
+   *
+   * @param field1
+   * @param extra_fields Any extra fields to attach to this struct
+   */
+  explicit DataType1(int field1, ::termite::Node::Map extra_fields = ::termite::Node::Map()) : field1(std::move(field1)), extra_fields(std::move(extra_fields)) {}
+
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   *
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const DataType1 &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   *
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const DataType1 &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   *
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const DataType1 &x);
+
+  /**
+   * @brief
+   *
+   */
+  int field1;
+  /**
+   * @brief All extra fields from when reading which could not be captured
+   *
+   */
+  ::termite::Node::Map extra_fields;
+};
+
+/**
+ * @brief
+ *
+ */
+struct DataType2 {
+public:
+  /**
+   * @brief Constructs a new DataType2 object
+   *
+   * @param field1
+   * @param field2
+   * @param extra_fields Any extra fields to attach to this struct
+   */
+  explicit DataType2(int field1, std::optional<float> field2, ::termite::Node::Map extra_fields = ::termite::Node::Map()) : field1(std::move(field1)), field2(std::move(field2)), extra_fields(std::move(extra_fields)) {}
+
+  /**
+   * @brief Gets the default value for field2
+   *
+   * @return The default value for field2
+   */
+  [[nodiscard]] static std::optional<float> default_field2();
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   *
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const DataType2 &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   *
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const DataType2 &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   *
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const DataType2 &x);
+
+  /**
+   * @brief
+   *
+   */
+  int field1;
+  /**
+   * @brief
+   *
+   */
+  std::optional<float> field2;
+  /**
+   * @brief All extra fields from when reading which could not be captured
+   *
+   */
+  ::termite::Node::Map extra_fields;
+};
+
+} // namespace test
+
+namespace termite {
+
+template<>
+struct has_to_value_all<test::DataType3> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::DataType3, Errors> Node::Map::to_value_all<test::DataType3>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DataType3>(const test::DataType3 &value);
+
+template<>
+struct has_to_value_all<test::DataType1> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::DataType1, Errors> Node::Map::to_value_all<test::DataType1>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DataType1>(const test::DataType1 &value);
+
+template<>
+struct has_to_value_all<test::DataType2> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::DataType2, Errors> Node::Map::to_value_all<test::DataType2>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::DataType2>(const test::DataType2 &value);
+
+} // namespace termite
+
+
+
+#endif
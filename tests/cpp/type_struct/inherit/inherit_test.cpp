@@ -0,0 +1,87 @@
+#include <iostream>
+#include "inherit.h"
+
+int main() {
+  auto value1 = test::DataType3(1, test::DataType3::default_field2(), test::DataType3::default_field3());
+  auto value2 = test::DataType3(-2, 3.5, 4);
+  if (value1 != value1) {
+    return 1;
+  }
+  if (value2 != value2) {
+    return 2;
+  }
+  if (value1 == value2) {
+    return 3;
+  }
+  if (value1.field3 != 3) {
+    return 4;
+  }
+
+  std::map<std::string, termite::Node> map_correct1;
+  map_correct1.insert({"field1", termite::Node(termite::Node::Value("1"))});
+  termite::Node node_correct1(termite::Node::Map(std::move(map_correct1)));
+  auto value1_read_correct = node_correct1.to_value<test::DataType3>();
+  if (!value1_read_correct.is_ok()) {
+    return 5;
+  }
+  if (value1_read_correct.get_ok() != value1) {
+    return 6;
+  }
+
+  std::map<std::string, termite::Node> map_correct2;
+  map_correct2.insert({"field1", termite::Node(termite::Node::Value("-2"))});
+  map_correct2.insert({"field2", termite::Node(termite::Node::Value("3.5"))});
+  map_correct2.insert({"field3", termite::Node(termite::Node::Value("4"))});
+  termite::Node node_correct2(termite::Node::Map(std::move(map_correct2)));
+  auto value2_read_correct = node_correct2.to_value<test::DataType3>();
+  if (!value2_read_correct.is_ok()) {
+    return 7;
+  }
+  if (value2_read_correct.get_ok() != value2) {
+    return 8;
+  }
+
+  std::map<std::string, termite::Node> map_missing;
+  map_missing.insert({"field3", termite::Node(termite::Node::Value("4"))});
+  termite::Node node_missing(termite::Node::Map(std::move(map_missing)));
+  auto value_missing = node_missing.to_value<test::DataType3>();
+  if (value_missing.is_ok()) {
+    return 9;
+  }
+
+  auto base = test::DataType2(-2, 3.5);
+  termite::Node base_node = termite::Node::from_value(value2);
+  auto base_value = base_node.to_value<test::DataType2>();
+  if (!base_value.is_ok()) {
+    return 10;
+  }
+  if (base_value.get_ok() == base) {
+    return 11;
+  }
+
+  termite::Node converted_node1 = termite::Node::from_value(value1);
+  auto converted_value1 = converted_node1.to_value<test::DataType3>();
+  if (!converted_value1.is_ok()) {
+    return 12;
+  }
+  if (converted_value1.get_ok() != value1) {
+    return 13;
+  }
+
+  termite::Node converted_node2 = termite::Node::from_value(value2);
+  auto converted_value2 = converted_node2.to_value<test::DataType3>();
+  if (!converted_value2.is_ok()) {
+    return 14;
+  }
+  if (converted_value2.get_ok() != value2) {
+    return 15;
+  }
+
+  std::stringstream printed;
+  printed << value2;
+  if (printed.str() != "{ field1: -2, field2: 3.5, field3: 4, extra_fields: { map: {  } } }") {
+    return 16;
+  }
+
+  return 0;
+}
@@ -0,0 +1,210 @@
+// Generated with the Termite Data Model Generator
+#include "unknown_fields.h"
+
+
+
+namespace test {
+
+namespace {
+
+// Code to make printing easier
+template <typename T, typename = void>
+struct has_insertion_operator : std::false_type {};
+template <typename T>
+struct has_insertion_operator<T, std::void_t<decltype(std::declval<std::ostream &>() << std::declval<T>())>> : std::true_type {};
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::optional<T> &value) {
+  if (value) {
+    return os << *value;
+  } else {
+    return os << "nullopt";
+  }
+}
+
+template <typename T>
+typename std::enable_if<has_insertion_operator<T>::value, std::ostream &>::type
+operator<<(std::ostream &os, const std::vector<T> &value) {
+  os << "[ ";
+  for (auto value_it = value.cbegin(); value_it != value.cend(); ++value_it) {
+    if (value_it != value.cbegin()) {
+      os << ", ";
+    }
+    os << *value_it;
+  }
+  return os << " ]";
+}
+
+} // namespace
+
+[[nodiscard]] bool Size::operator==(const Size &x) const {
+  return this->width == x.width && this->height == x.height;
+}
+
+[[nodiscard]] termite::integer Size::default_height() {
+  auto node = termite::Node(termite::Node::Value("1"));
+
+  return node.to_value<termite::integer>().get_ok();
+}
+
+std::ostream &operator<<(std::ostream &os, const Size &x) {
+  return os << "{ " << "width: " << x.width << ", " << "height: " << x.height << " }";
+}
+
+[[nodiscard]] bool Ignored::operator==(const Ignored &x) const {
+  return this->size == x.size;
+}
+
+std::ostream &operator<<(std::ostream &os, const Ignored &x) {
+  return os << "{ " << "size: " << x.size << " }";
+}
+
+[[nodiscard]] bool Collected::operator==(const Collected &x) const {
+  return extra_fields == x.extra_fields;
+}
+
+std::ostream &operator<<(std::ostream &os, const Collected &x) {
+  return os << "{ " << "extra_fields: " << x.extra_fields << " }";
+}
+
+[[nodiscard]] bool Empty::operator==(const Empty &x) const {
+  return true;
+}
+
+std::ostream &operator<<(std::ostream &os, const Empty &x) {
+  return os << "{ " << " }";
+}
+
+} // namespace test
+
+namespace termite {
+
+template<>
+[[nodiscard]] Result<test::Size, Errors> Node::Map::to_value_all<test::Size>() const {
+  std::map<std::string, Node> map = map_;
+  Errors errors;
+
+  auto location_width = map.find("width");
+  std::optional<integer> value_width;
+  if (location_width == map.end()) {
+    errors.add(Error("Missing width"));
+  } else {
+    Result<integer, Errors> raw_value_width = location_width->second.to_value_all<integer>();
+    if (raw_value_width.is_ok()) {
+      value_width = raw_value_width.get_ok();
+    } else {
+      errors.append(raw_value_width.get_err().add_field("width"));
+    }
+    map.erase(location_width);
+  }
+
+  auto location_height = map.find("height");
+  integer value_height = test::Size::default_height();
+  if (location_height != map.end()) {
+    Result<integer, Errors> raw_value_height = location_height->second.to_value_all<integer>();
+    if (raw_value_height.is_ok()) {
+      value_height = raw_value_height.get_ok();
+    } else {
+      errors.append(raw_value_height.get_err().add_field("height"));
+    }
+    map.erase(location_height);
+  }
+
+  if (!map.empty()) {
+    errors.add(Map::unknown_fields_error(map, {"width", "height"}));
+  }
+
+  if (!errors.empty()) {
+    return Result<test::Size, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::Size, Errors>::ok(test::Size(std::move(*value_width), std::move(value_height)));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Size>(const test::Size &value) {
+  std::map<std::string, Node> map;
+
+  map.insert({"width", Node::from_value(value.width)});
+
+  map.insert({"height", Node::from_value(value.height)});
+
+  return Node(Node::Map(std::move(map)));
+}
+
+template<>
+[[nodiscard]] Result<test::Ignored, Errors> Node::Map::to_value_all<test::Ignored>() const {
+  std::map<std::string, Node> map = map_;
+  Errors errors;
+
+  auto location_size = map.find("size");
+  std::optional<test::Size> value_size;
+  if (location_size == map.end()) {
+    errors.add(Error("Missing size"));
+  } else {
+    Result<test::Size, Errors> raw_value_size = location_size->second.to_value_all<test::Size>();
+    if (raw_value_size.is_ok()) {
+      value_size = raw_value_size.get_ok();
+    } else {
+      errors.append(raw_value_size.get_err().add_field("size"));
+    }
+    map.erase(location_size);
+  }
+
+  if (!errors.empty()) {
+    return Result<test::Ignored, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::Ignored, Errors>::ok(test::Ignored(std::move(*value_size)));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Ignored>(const test::Ignored &value) {
+  std::map<std::string, Node> map;
+
+  map.insert({"size", Node::from_value(value.size)});
+
+  return Node(Node::Map(std::move(map)));
+}
+
+template<>
+[[nodiscard]] Result<test::Collected, Errors> Node::Map::to_value_all<test::Collected>() const {
+  std::map<std::string, Node> map = map_;
+
+  return Result<test::Collected, Errors>::ok(test::Collected(Map(std::move(map))));
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Collected>(const test::Collected &value) {
+  std::map<std::string, Node> map = value.extra_fields.get();
+
+  return Node(Node::Map(std::move(map)));
+}
+
+template<>
+[[nodiscard]] Result<test::Empty, Errors> Node::Map::to_value_all<test::Empty>() const {
+  std::map<std::string, Node> map = map_;
+  Errors errors;
+
+  if (!map.empty()) {
+    errors.add(Map::unknown_fields_error(map, {}));
+  }
+
+  if (!errors.empty()) {
+    return Result<test::Empty, Errors>::err(std::move(errors));
+  }
+
+  return Result<test::Empty, Errors>::ok(test::Empty());
+}
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Empty>(const test::Empty &value) {
+  std::map<std::string, Node> map;
+
+  return Node(Node::Map(std::move(map)));
+}
+
+} // namespace termite
+
+
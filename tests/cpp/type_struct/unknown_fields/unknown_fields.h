@@ -0,0 +1,250 @@
+// Generated with the Termite Data Model Generator
+#ifndef HEADER_TERMITE_H_INCLUDED
+#define HEADER_TERMITE_H_INCLUDED
+
+#include <iostream>
+#include <sstream>
+#include <optional>
+#include <variant>
+#include <algorithm>
+#include <termite.hpp>
+
+
+
+namespace test {
+
+/**
+ * @brief Rejects unknown fields through the data model setting
+ * 
+ */
+struct Size {
+public:
+  /**
+   * @brief Constructs a new Size object
+   * 
+   * @param width 
+   * @param height 
+   */
+  explicit Size(termite::integer width, termite::integer height) : width(std::move(width)), height(std::move(height)) {}
+
+  /**
+   * @brief Gets the default value for height
+   * 
+   * @return The default value for height
+   */
+  [[nodiscard]] static termite::integer default_height();
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Size &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Size &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Size &x);
+
+  /**
+   * @brief 
+   * 
+   */
+  termite::integer width;
+  /**
+   * @brief 
+   * 
+   */
+  termite::integer height;
+};
+
+/**
+ * @brief 
+ * 
+ */
+struct Ignored {
+public:
+  /**
+   * @brief Constructs a new Ignored object
+   * 
+   * @param size 
+   */
+  explicit Ignored(Size size) : size(std::move(size)) {}
+
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Ignored &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Ignored &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Ignored &x);
+
+  /**
+   * @brief 
+   * 
+   */
+  Size size;
+};
+
+/**
+ * @brief 
+ * 
+ */
+struct Collected {
+public:
+  /**
+   * @brief Constructs a new Collected object
+   * 
+   * @param extra_fields Any extra fields to attach to this struct
+   */
+  explicit Collected(::termite::Node::Map extra_fields = ::termite::Node::Map()) : extra_fields(std::move(extra_fields)) {}
+
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Collected &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Collected &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Collected &x);
+
+  /**
+   * @brief All extra fields from when reading which could not be captured
+   * 
+   */
+  ::termite::Node::Map extra_fields;
+};
+
+/**
+ * @brief 
+ * 
+ */
+struct Empty {
+public:
+  /**
+   * @brief Constructs a new Empty object
+   * 
+   */
+  explicit Empty() {}
+
+
+  /**
+   * @brief Checks if this object and the other object are identical
+   * 
+   * @param x The other object to compare with
+   * @return true if they are identical, false if not
+   */
+  [[nodiscard]] bool operator==(const Empty &x) const;
+  /**
+   * @brief Checks if this object and the other object are different
+   * 
+   * @param x The other object to compare with
+   * @return true if they are different, false if not
+   */
+  [[nodiscard]] bool operator!=(const Empty &x) const {
+    return !(*this == x);
+  }
+  /**
+   * @brief Prints the object onto the output stream
+   * 
+   * @param os The output stream to print to
+   * @param x The object to print
+   * @return The output stream
+   */
+  friend std::ostream &operator<<(std::ostream &os, const Empty &x);
+
+};
+
+} // namespace test
+
+namespace termite {
+
+template<>
+struct has_to_value_all<test::Size> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::Size, Errors> Node::Map::to_value_all<test::Size>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Size>(const test::Size &value);
+
+template<>
+struct has_to_value_all<test::Ignored> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::Ignored, Errors> Node::Map::to_value_all<test::Ignored>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Ignored>(const test::Ignored &value);
+
+template<>
+struct has_to_value_all<test::Collected> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::Collected, Errors> Node::Map::to_value_all<test::Collected>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Collected>(const test::Collected &value);
+
+template<>
+struct has_to_value_all<test::Empty> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::Empty, Errors> Node::Map::to_value_all<test::Empty>() const;
+
+template<>
+[[nodiscard]] Node Node::from_value<test::Empty>(const test::Empty &value);
+
+} // namespace termite
+
+
+
+#endif
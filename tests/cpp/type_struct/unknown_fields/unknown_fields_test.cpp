@@ -0,0 +1,115 @@
+#include <iostream>
+#include "unknown_fields.h"
+
+int main() {
+  std::map<std::string, termite::Node> map_correct;
+  map_correct.insert({"width", termite::Node(termite::Node::Value("2"))});
+  termite::Node node_correct(termite::Node::Map(std::move(map_correct)));
+  auto value_correct = node_correct.to_value<test::Size>();
+  if (!value_correct.is_ok()) {
+    return 1;
+  }
+  if (value_correct.get_ok() != test::Size(2, 1)) {
+    return 2;
+  }
+
+  // A typo is rejected with a suggestion
+  std::map<std::string, termite::Node> map_typo;
+  map_typo.insert({"widht", termite::Node(termite::Node::Value("2"))});
+  map_typo.insert({"width", termite::Node(termite::Node::Value("2"))});
+  map_typo.insert({"zzzzzzzzzz", termite::Node(termite::Node::Value("2"))});
+  termite::Node node_typo(termite::Node::Map(std::move(map_typo)));
+  auto value_typo = node_typo.to_value<test::Size>();
+  if (value_typo.is_ok()) {
+    return 3;
+  }
+  termite::Error error_typo = value_typo.get_err();
+  if (error_typo.get_message() !=
+      "Unknown fields \"widht\" (did you mean \"width\"?), \"zzzzzzzzzz\"") {
+    std::cout << error_typo << std::endl;
+    return 4;
+  }
+
+  // Short field names are only suggested for close typos
+  std::map<std::string, termite::Node> map_short;
+  map_short.insert({"q", termite::Node(termite::Node::Value("2"))});
+  map_short.insert({"xx", termite::Node(termite::Node::Value("2"))});
+  termite::Error error_short =
+      termite::Node::Map::unknown_fields_error(map_short, {"x", "yy"});
+  if (error_short.get_message() != "Unknown fields \"q\", \"xx\"") {
+    std::cout << error_short << std::endl;
+    return 13;
+  }
+  std::map<std::string, termite::Node> map_long;
+  map_long.insert({"hieght", termite::Node(termite::Node::Value("2"))});
+  termite::Error error_long =
+      termite::Node::Map::unknown_fields_error(map_long, {"height"});
+  if (error_long.get_message() !=
+      "Unknown field \"hieght\" (did you mean \"height\"?)") {
+    std::cout << error_long << std::endl;
+    return 14;
+  }
+
+  // The error of a nested struct gets its location
+  std::map<std::string, termite::Node> map_nested_size;
+  map_nested_size.insert({"width", termite::Node(termite::Node::Value("2"))});
+  map_nested_size.insert({"heigth", termite::Node(termite::Node::Value("2"))});
+  std::map<std::string, termite::Node> map_nested;
+  map_nested.insert({"size", termite::Node(termite::Node::Map(std::move(map_nested_size)))});
+  termite::Node node_nested(termite::Node::Map(std::move(map_nested)));
+  auto value_nested = node_nested.to_value<test::Ignored>();
+  if (value_nested.is_ok()) {
+    return 5;
+  }
+  termite::Error error_nested = value_nested.get_err();
+  if (error_nested.get_location() != "size" ||
+      error_nested.get_message() !=
+          "Unknown field \"heigth\" (did you mean \"height\"?)") {
+    std::cout << error_nested << std::endl;
+    return 6;
+  }
+
+  // Ignored fields are dropped
+  std::map<std::string, termite::Node> map_ignored_size;
+  map_ignored_size.insert({"width", termite::Node(termite::Node::Value("2"))});
+  std::map<std::string, termite::Node> map_ignored;
+  map_ignored.insert({"size", termite::Node(termite::Node::Map(std::move(map_ignored_size)))});
+  map_ignored.insert({"extra", termite::Node(termite::Node::Value("2"))});
+  termite::Node node_ignored(termite::Node::Map(std::move(map_ignored)));
+  auto value_ignored = node_ignored.to_value<test::Ignored>();
+  if (!value_ignored.is_ok()) {
+    return 7;
+  }
+  termite::Node converted_ignored = termite::Node::from_value(value_ignored.get_ok());
+  if (converted_ignored.to_value<test::Ignored>().get_ok() != value_ignored.get_ok() ||
+      std::get<termite::Node::Map>(converted_ignored.get()).get().size() != 1) {
+    return 8;
+  }
+
+  // Collected fields are kept
+  std::map<std::string, termite::Node> map_collected;
+  map_collected.insert({"extra", termite::Node(termite::Node::Value("2"))});
+  termite::Node node_collected(termite::Node::Map(std::move(map_collected)));
+  auto value_collected = node_collected.to_value<test::Collected>();
+  if (!value_collected.is_ok()) {
+    return 9;
+  }
+  if (value_collected.get_ok().extra_fields.get().size() != 1) {
+    return 10;
+  }
+
+  // Empty structs reject everything
+  std::map<std::string, termite::Node> map_empty;
+  map_empty.insert({"extra", termite::Node(termite::Node::Value("2"))});
+  termite::Node node_empty(termite::Node::Map(std::move(map_empty)));
+  if (node_empty.to_value<test::Empty>().is_ok()) {
+    return 11;
+  }
+  if (test::Empty() != test::Empty()) {
+    return 12;
+  }
+
+  std::cout << "Done" << std::endl;
+
+  return 0;
+}
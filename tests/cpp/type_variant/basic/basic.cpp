@@ -63,26 +63,26 @@ std::ostream &operator<<(std::ostream &os, const DataType &x) {
 namespace termite {
 
 template<>
-[[nodiscard]] Result<test::DataType> Node::to_value<test::DataType>() const {
+[[nodiscard]] Result<test::DataType, Errors> Node::to_value_all<test::DataType>() const {
   std::stringstream error;
   error << "Unable to parse any variant: [ ";
 
   Result<int> result_int = to_value<int>();
   if (result_int.is_ok()) {
-    return Result<test::DataType>::ok(test::DataType(result_int.get_ok()));
+    return Result<test::DataType, Errors>::ok(test::DataType(result_int.get_ok()));
   }
   error << "int { " << result_int.get_err() << " }";
   error << ", ";
 
   Result<float> result_float = to_value<float>();
   if (result_float.is_ok()) {
-    return Result<test::DataType>::ok(test::DataType(result_float.get_ok()));
+    return Result<test::DataType, Errors>::ok(test::DataType(result_float.get_ok()));
   }
   error << "float { " << result_float.get_err() << " }";
 
   error << " ]";
 
-  return Result<test::DataType>::err(Error(error.str()));
+  return Result<test::DataType, Errors>::err(Errors(Error(error.str()).set_mark(mark_)));
 }
 
 template<>
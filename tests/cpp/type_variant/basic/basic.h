@@ -62,7 +62,10 @@ struct DataType {
 namespace termite {
 
 template<>
-[[nodiscard]] Result<test::DataType> Node::to_value<test::DataType>() const;
+struct has_to_value_all<test::DataType> : std::true_type {};
+
+template<>
+[[nodiscard]] Result<test::DataType, Errors> Node::to_value_all<test::DataType>() const;
 
 template<>
 [[nodiscard]] Node Node::from_value<test::DataType>(const test::DataType &value);
@@ -0,0 +1,43 @@
+# Generated with the Termite Data Model Generator
+
+from __future__ import annotations
+
+import dataclasses
+import typing
+
+import termite
+
+
+# Header with message: This is a macro message
+
+
+@dataclasses.dataclass(kw_only=True)
+class DataType:
+    """
+    A data type with a long description
+
+    which spans multiple lines
+
+    Attributes:
+        extra_fields: All fields which were not captured when parsing
+    """
+
+    field: int
+    extra_fields: dict[str, typing.Any] = dataclasses.field(default_factory=dict)
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> DataType:
+        """Parses DataType from a plain value, unknown fields are kept in extra_fields"""
+        fields = termite.parse_map(value)
+        kwargs = {}
+        kwargs["field"] = termite.parse_field(fields, "field", termite.parse_integer)
+        return cls(**kwargs, extra_fields=fields)
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the DataType to a plain value"""
+        value = termite.to_value(self.extra_fields)
+        value["field"] = termite.to_value(self.field)
+        return value
+
+
+# Footer
@@ -0,0 +1,15 @@
+import unittest
+
+from outline import DataType
+
+
+class TestOutline(unittest.TestCase):
+    def test_round_trip(self):
+        value = DataType.from_value({"field": "1"})
+        self.assertEqual(value, DataType(field=1))
+        self.assertEqual(value.to_value(), {"field": 1})
+        self.assertIn("which spans multiple lines", DataType.__doc__)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,233 @@
+# Generated with the Termite Data Model Generator
+
+from __future__ import annotations
+
+import dataclasses
+import typing
+
+import termite
+
+
+@dataclasses.dataclass(kw_only=True)
+class Tree:
+    """
+    Attributes:
+        extra_fields: All fields which were not captured when parsing
+    """
+
+    value: int
+    children: TreeArray
+    extra_fields: dict[str, typing.Any] = dataclasses.field(default_factory=dict)
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Tree:
+        """Parses Tree from a plain value, unknown fields are kept in extra_fields"""
+        fields = termite.parse_map(value)
+        kwargs = {}
+        kwargs["value"] = termite.parse_field(fields, "value", termite.parse_integer)
+        kwargs["children"] = termite.parse_field(fields, "children", TreeArray.from_value)
+        return cls(**kwargs, extra_fields=fields)
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the Tree to a plain value"""
+        value = termite.to_value(self.extra_fields)
+        value["value"] = termite.to_value(self.value)
+        value["children"] = termite.to_value(self.children)
+        return value
+
+
+@dataclasses.dataclass
+class TreeArray:
+    """
+    Attributes:
+        values: The values of the array
+    """
+
+    values: list[Tree]
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> TreeArray:
+        """Parses TreeArray from a plain value"""
+        return cls(termite.parse_list(value, Tree.from_value))
+
+    def to_value(self) -> list[typing.Any]:
+        """Converts the TreeArray to a plain value"""
+        return termite.to_value(self.values)
+
+
+@dataclasses.dataclass
+class Expr:
+    """
+    Attributes:
+        value: The enum type which is set
+    """
+
+    @dataclasses.dataclass
+    class Zero:
+        pass
+
+    @dataclasses.dataclass
+    class Number:
+        value: float
+
+    @dataclasses.dataclass
+    class Negate:
+        value: Expr
+
+    @dataclasses.dataclass
+    class Add:
+        value: ExprPair
+
+    value: Expr.Zero | Expr.Number | Expr.Negate | Expr.Add
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Expr:
+        """Parses Expr from a plain value"""
+        return cls(termite.parse_enum(
+            value,
+            {
+                "Zero": cls.Zero,
+            },
+            {
+                "Number": lambda data: cls.Number(termite.parse_number(data)),
+                "Negate": lambda data: cls.Negate(Expr.from_value(data)),
+                "Add": lambda data: cls.Add(ExprPair.from_value(data)),
+            },
+        ))
+
+    def to_value(self) -> typing.Any:
+        """Converts the Expr to a plain value"""
+        if isinstance(self.value, Expr.Zero):
+            return "Zero"
+        if isinstance(self.value, Expr.Number):
+            return {"Number": termite.to_value(self.value.value)}
+        if isinstance(self.value, Expr.Negate):
+            return {"Negate": termite.to_value(self.value.value)}
+        if isinstance(self.value, Expr.Add):
+            return {"Add": termite.to_value(self.value.value)}
+        raise termite.Error(f"Unknown enum type {type(self.value).__name__}")
+
+
+@dataclasses.dataclass(kw_only=True)
+class ExprPair:
+    """
+    Attributes:
+        extra_fields: All fields which were not captured when parsing
+    """
+
+    lhs: Expr
+    rhs: Expr | None = None
+    extra_fields: dict[str, typing.Any] = dataclasses.field(default_factory=dict)
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> ExprPair:
+        """Parses ExprPair from a plain value, unknown fields are kept in extra_fields"""
+        fields = termite.parse_map(value)
+        kwargs = {}
+        kwargs["lhs"] = termite.parse_field(fields, "lhs", Expr.from_value)
+        if "rhs" in fields:
+            kwargs["rhs"] = termite.parse_field(fields, "rhs", Expr.from_value)
+        return cls(**kwargs, extra_fields=fields)
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the ExprPair to a plain value"""
+        value = termite.to_value(self.extra_fields)
+        value["lhs"] = termite.to_value(self.lhs)
+        if self.rhs is not None:
+            value["rhs"] = termite.to_value(self.rhs)
+        return value
+
+
+@dataclasses.dataclass
+class Item:
+    """
+    Attributes:
+        value: The value of the first type which could be parsed
+    """
+
+    value: int | ItemPair
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Item:
+        """Parses Item from a plain value"""
+        return cls(termite.parse_variant(value, [
+            ("integer", termite.parse_integer),
+            ("ItemPair", ItemPair.from_value),
+        ]))
+
+    def to_value(self) -> typing.Any:
+        """Converts the Item to a plain value"""
+        return termite.to_value(self.value)
+
+
+@dataclasses.dataclass(kw_only=True)
+class ItemPair:
+    """
+    Attributes:
+        extra_fields: All fields which were not captured when parsing
+    """
+
+    first: Item
+    second: Item = dataclasses.field(default_factory=lambda: Item.from_value("0"))
+    extra_fields: dict[str, typing.Any] = dataclasses.field(default_factory=dict)
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> ItemPair:
+        """Parses ItemPair from a plain value, unknown fields are kept in extra_fields"""
+        fields = termite.parse_map(value)
+        kwargs = {}
+        kwargs["first"] = termite.parse_field(fields, "first", Item.from_value)
+        if "second" in fields:
+            kwargs["second"] = termite.parse_field(fields, "second", Item.from_value)
+        return cls(**kwargs, extra_fields=fields)
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the ItemPair to a plain value"""
+        value = termite.to_value(self.extra_fields)
+        value["first"] = termite.to_value(self.first)
+        value["second"] = termite.to_value(self.second)
+        return value
+
+
+@dataclasses.dataclass(kw_only=True)
+class Directory:
+    """
+    Attributes:
+        extra_fields: All fields which were not captured when parsing
+    """
+
+    children: DirectoryMap
+    extra_fields: dict[str, typing.Any] = dataclasses.field(default_factory=dict)
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Directory:
+        """Parses Directory from a plain value, unknown fields are kept in extra_fields"""
+        fields = termite.parse_map(value)
+        kwargs = {}
+        kwargs["children"] = termite.parse_field(fields, "children", DirectoryMap.from_value)
+        return cls(**kwargs, extra_fields=fields)
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the Directory to a plain value"""
+        value = termite.to_value(self.extra_fields)
+        value["children"] = termite.to_value(self.children)
+        return value
+
+
+@dataclasses.dataclass
+class DirectoryMap:
+    """
+    Attributes:
+        values: The values of the map
+    """
+
+    values: dict[str, Directory]
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> DirectoryMap:
+        """Parses DirectoryMap from a plain value"""
+        return cls(termite.parse_values(value, Directory.from_value))
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the DirectoryMap to a plain value"""
+        return termite.to_value(self.values)
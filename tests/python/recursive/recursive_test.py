@@ -0,0 +1,34 @@
+import unittest
+
+import termite
+from recursive import Directory, Expr, Item, Tree
+
+
+class TestRecursive(unittest.TestCase):
+    def test_tree(self):
+        data = {"value": 1, "children": [{"value": 2, "children": []}]}
+        value = Tree.from_value(data)
+        self.assertEqual(value.children.values[0].value, 2)
+        self.assertEqual(value.to_value(), data)
+
+    def test_expr(self):
+        data = {"Add": {"lhs": {"Negate": {"Number": 1.5}}, "rhs": "Zero"}}
+        value = Expr.from_value(data)
+        self.assertEqual(value.to_value(), data)
+        self.assertEqual(Expr.from_value({"Add": {"lhs": "Zero"}}).to_value(), {"Add": {"lhs": "Zero"}})
+
+    def test_item(self):
+        data = {"first": {"first": 1, "second": 2}, "second": 3}
+        self.assertEqual(Item.from_value(data).to_value(), data)
+        self.assertEqual(Item.from_value({"first": 1}).to_value(), {"first": 1, "second": 0})
+
+    def test_directory(self):
+        data = {"children": {"a": {"children": {}}, "b": {"children": {"c": {"children": {}}}}}}
+        self.assertEqual(Directory.from_value(data).to_value(), data)
+        with self.assertRaises(termite.Error) as context:
+            Directory.from_value({"children": {"a": {}}})
+        self.assertEqual(context.exception, termite.Error("Missing children", "children.a"))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,108 @@
+import unittest
+
+import termite
+
+
+class TestScalars(unittest.TestCase):
+    def test_string(self):
+        self.assertEqual(termite.parse_string(" text "), "text")
+        self.assertEqual(termite.parse_string(5), "5")
+        self.assertEqual(termite.parse_string(True), "true")
+        with self.assertRaises(termite.Error):
+            termite.parse_string([])
+
+    def test_number(self):
+        self.assertEqual(termite.parse_number("1.5"), 1.5)
+        self.assertEqual(termite.parse_number(" -2e3 "), -2000.0)
+        self.assertEqual(termite.parse_number(3), 3.0)
+        for value in ["", "one", "1.0.0", True, {}]:
+            with self.assertRaises(termite.Error):
+                termite.parse_number(value)
+
+    def test_integer(self):
+        self.assertEqual(termite.parse_integer("-5"), -5)
+        self.assertEqual(termite.parse_integer(7), 7)
+        self.assertEqual(termite.parse_integer(str(2**63 - 1)), 2**63 - 1)
+        for value in ["1.5", 1.0, False, str(2**63), "a"]:
+            with self.assertRaises(termite.Error):
+                termite.parse_integer(value)
+
+    def test_boolean(self):
+        for value in [True, "true", "True", 1]:
+            self.assertTrue(termite.parse_boolean(value))
+        for value in [False, "false", "False", 0]:
+            self.assertFalse(termite.parse_boolean(value))
+        with self.assertRaises(termite.Error):
+            termite.parse_boolean("yes")
+
+
+class TestContainers(unittest.TestCase):
+    def test_list(self):
+        self.assertEqual(termite.parse_list(["1", 2], termite.parse_integer), [1, 2])
+        with self.assertRaises(termite.Error) as context:
+            termite.parse_list([1, "a"], termite.parse_integer)
+        self.assertEqual(context.exception.location, "[1]")
+        with self.assertRaises(termite.Error):
+            termite.parse_list({}, termite.parse_integer)
+
+    def test_values(self):
+        self.assertEqual(termite.parse_values({"a": "1"}, termite.parse_integer), {"a": 1})
+        with self.assertRaises(termite.Error) as context:
+            termite.parse_values({"a": {"b": [1, "c"]}}, lambda value: termite.parse_values(value, lambda value: termite.parse_list(value, termite.parse_integer)))
+        self.assertEqual(context.exception.location, "a.b[1]")
+
+    def test_field(self):
+        fields = {"a": "1", "b": 2}
+        self.assertEqual(termite.parse_field(fields, "a", termite.parse_integer), 1)
+        self.assertEqual(fields, {"b": 2})
+        with self.assertRaises(termite.Error) as context:
+            termite.parse_field(fields, "a", termite.parse_integer)
+        self.assertEqual(context.exception, termite.Error("Missing a"))
+
+    def test_reject_fields(self):
+        termite.reject_fields({}, ["width"])
+        with self.assertRaises(termite.Error) as context:
+            termite.reject_fields({"widht": 1, "zzzzzzzzzz": 2}, ["width", "height"])
+        self.assertEqual(
+            context.exception.message,
+            'Unknown fields "widht" (did you mean "width"?), "zzzzzzzzzz"',
+        )
+
+        # Short field names are only suggested for close typos
+        with self.assertRaises(termite.Error) as context:
+            termite.reject_fields({"q": 1}, ["x"])
+        self.assertEqual(context.exception.message, 'Unknown field "q"')
+
+    def test_variant(self):
+        parsers = [("integer", termite.parse_integer), ("boolean", termite.parse_boolean)]
+        self.assertEqual(termite.parse_variant("5", parsers), 5)
+        self.assertEqual(termite.parse_variant("true", parsers), True)
+        with self.assertRaises(termite.Error) as context:
+            termite.parse_variant("text", parsers)
+        self.assertEqual(
+            context.exception.message,
+            "Unable to parse any variant: [ integer { Unable to parse }, boolean { Unable to parse } ]",
+        )
+
+    def test_enum(self):
+        empty_types = {"Empty": lambda: "empty"}
+        types = {"Int": termite.parse_integer}
+        self.assertEqual(termite.parse_enum("Empty", empty_types, types), "empty")
+        self.assertEqual(termite.parse_enum({"Int": "5"}, empty_types, types), 5)
+        for value in ["Int", {"Empty": 1}, {"Int": 1, "Other": 2}, {}, "Other", []]:
+            with self.assertRaises(termite.Error):
+                termite.parse_enum(value, empty_types, types)
+        with self.assertRaises(termite.Error) as context:
+            termite.parse_enum({"Int": "a"}, empty_types, types)
+        self.assertEqual(context.exception.location, "Int")
+
+    def test_to_value(self):
+        class Value:
+            def to_value(self):
+                return "value"
+
+        self.assertEqual(termite.to_value({"a": [Value(), 1]}), {"a": ["value", 1]})
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,73 @@
+# Generated with the Termite Data Model Generator
+
+from __future__ import annotations
+
+import dataclasses
+import typing
+
+import termite
+
+
+@dataclasses.dataclass
+class Numbers:
+    """
+    Attributes:
+        values: The values of the array
+    """
+
+    values: list[int]
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Numbers:
+        """Parses Numbers from a plain value"""
+        return cls(termite.parse_list(value, termite.parse_integer))
+
+    def to_value(self) -> list[typing.Any]:
+        """Converts the Numbers to a plain value"""
+        return termite.to_value(self.values)
+
+
+@dataclasses.dataclass(kw_only=True)
+class Element:
+    """
+    Attributes:
+        extra_fields: All fields which were not captured when parsing
+    """
+
+    name: str
+    extra_fields: dict[str, typing.Any] = dataclasses.field(default_factory=dict)
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Element:
+        """Parses Element from a plain value, unknown fields are kept in extra_fields"""
+        fields = termite.parse_map(value)
+        kwargs = {}
+        kwargs["name"] = termite.parse_field(fields, "name", termite.parse_string)
+        return cls(**kwargs, extra_fields=fields)
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the Element to a plain value"""
+        value = termite.to_value(self.extra_fields)
+        value["name"] = termite.to_value(self.name)
+        return value
+
+
+@dataclasses.dataclass
+class Elements:
+    """
+    A list of elements
+
+    Attributes:
+        values: The values of the array
+    """
+
+    values: list[Element]
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Elements:
+        """Parses Elements from a plain value"""
+        return cls(termite.parse_list(value, Element.from_value))
+
+    def to_value(self) -> list[typing.Any]:
+        """Converts the Elements to a plain value"""
+        return termite.to_value(self.values)
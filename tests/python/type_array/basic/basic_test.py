@@ -0,0 +1,29 @@
+import unittest
+
+import termite
+from basic import Element, Elements, Numbers
+
+
+class TestBasic(unittest.TestCase):
+    def test_builtin(self):
+        value = Numbers.from_value(["1", 2])
+        self.assertEqual(value, Numbers([1, 2]))
+        self.assertEqual(value.to_value(), [1, 2])
+        self.assertEqual(Numbers.from_value([]).to_value(), [])
+
+    def test_custom(self):
+        data = [{"name": "a"}, {"name": "b", "extra": 1}]
+        value = Elements.from_value(data)
+        self.assertEqual(value.values[0], Element(name="a"))
+        self.assertEqual(value.to_value(), data)
+
+    def test_error(self):
+        with self.assertRaises(termite.Error) as context:
+            Elements.from_value([{"name": "a"}, {}])
+        self.assertEqual(context.exception, termite.Error("Missing name", "[1]"))
+        with self.assertRaises(termite.Error):
+            Numbers.from_value({"a": 1})
+
+
+if __name__ == "__main__":
+    unittest.main()
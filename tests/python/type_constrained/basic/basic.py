@@ -0,0 +1,165 @@
+# Generated with the Termite Data Model Generator
+
+from __future__ import annotations
+
+import dataclasses
+import typing
+
+import termite
+
+
+@dataclasses.dataclass(frozen=True)
+class PositiveDouble:
+    """
+    The value must fulfill the constraints:
+    - x > 0.0
+
+    Attributes:
+        value: The constrained value
+    """
+
+    value: float
+
+    def __post_init__(self):
+        x = self.value
+        if not (x > 0.0):
+            raise termite.Error("Did not pass constraint: x > 0.0")
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> PositiveDouble:
+        """Parses PositiveDouble from a plain value"""
+        return cls(termite.parse_number(value))
+
+    def to_value(self) -> typing.Any:
+        """Converts the PositiveDouble to a plain value"""
+        return termite.to_value(self.value)
+
+
+@dataclasses.dataclass(frozen=True)
+class SmallPositiveDouble:
+    """
+    The value must fulfill the constraints:
+    - x < 10.0
+
+    Attributes:
+        value: The constrained value
+    """
+
+    value: PositiveDouble
+
+    def __post_init__(self):
+        x = self.value
+        if not (x.value < 10.0):
+            raise termite.Error("Did not pass constraint: x < 10.0")
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> SmallPositiveDouble:
+        """Parses SmallPositiveDouble from a plain value"""
+        return cls(PositiveDouble.from_value(value))
+
+    def to_value(self) -> typing.Any:
+        """Converts the SmallPositiveDouble to a plain value"""
+        return termite.to_value(self.value)
+
+
+@dataclasses.dataclass(frozen=True)
+class Name:
+    """
+    The value must fulfill the constraints:
+    - len(x) > 0
+    - len(x) < 10
+
+    Attributes:
+        value: The constrained value
+    """
+
+    value: str
+
+    def __post_init__(self):
+        x = self.value
+        if not (len(x) > 0):
+            raise termite.Error("Did not pass constraint: len(x) > 0")
+        if not (len(x) < 10):
+            raise termite.Error("Did not pass constraint: len(x) < 10")
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Name:
+        """Parses Name from a plain value"""
+        return cls(termite.parse_string(value))
+
+    def to_value(self) -> typing.Any:
+        """Converts the Name to a plain value"""
+        return termite.to_value(self.value)
+
+
+@dataclasses.dataclass(frozen=True)
+class Anything:
+    """
+    Attributes:
+        value: The constrained value
+    """
+
+    value: int
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Anything:
+        """Parses Anything from a plain value"""
+        return cls(termite.parse_integer(value))
+
+    def to_value(self) -> typing.Any:
+        """Converts the Anything to a plain value"""
+        return termite.to_value(self.value)
+
+
+@dataclasses.dataclass(frozen=True)
+class Country:
+    """
+    The value must fulfill the constraints:
+    - matches(x, "^[A-Z]{2}$") && x in ["SE", "NO"]
+
+    Attributes:
+        value: The constrained value
+    """
+
+    value: str
+
+    def __post_init__(self):
+        x = self.value
+        if not (termite.matches(x, "^[A-Z]{2}$") and x in ["SE", "NO"]):
+            raise termite.Error("Did not pass constraint: matches(x, \"^[A-Z]{2}$\") && x in [\"SE\", \"NO\"]")
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Country:
+        """Parses Country from a plain value"""
+        return cls(termite.parse_string(value))
+
+    def to_value(self) -> typing.Any:
+        """Converts the Country to a plain value"""
+        return termite.to_value(self.value)
+
+
+@dataclasses.dataclass(frozen=True)
+class Odd:
+    """
+    The value must fulfill the constraints:
+    - x % 2 == 1 || x % 2 == -1
+
+    Attributes:
+        value: The constrained value
+    """
+
+    value: int
+
+    def __post_init__(self):
+        x = self.value
+        if not (termite.remainder(x, 2) == 1 or termite.remainder(x, 2) == -1):
+            raise termite.Error("Did not pass constraint: x % 2 == 1 || x % 2 == -1")
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Odd:
+        """Parses Odd from a plain value"""
+        return cls(termite.parse_integer(value))
+
+    def to_value(self) -> typing.Any:
+        """Converts the Odd to a plain value"""
+        return termite.to_value(self.value)
@@ -0,0 +1,40 @@
+import dataclasses
+import unittest
+
+import termite
+from basic import Anything, Country, Name, Odd, PositiveDouble, SmallPositiveDouble
+
+
+class TestBasic(unittest.TestCase):
+    def test_valid(self):
+        self.assertEqual(PositiveDouble.from_value("1.5"), PositiveDouble(1.5))
+        self.assertEqual(SmallPositiveDouble.from_value(5).to_value(), 5.0)
+        self.assertEqual(Name.from_value("name").to_value(), "name")
+        self.assertEqual(Anything.from_value(-3).to_value(), -3)
+        self.assertEqual(Country.from_value("SE").to_value(), "SE")
+        self.assertEqual(Odd.from_value(-3).to_value(), -3)
+
+    def test_invalid(self):
+        for parser, data, message in [
+            (PositiveDouble.from_value, 0, "Did not pass constraint: x > 0.0"),
+            (SmallPositiveDouble.from_value, -1, "Did not pass constraint: x > 0.0"),
+            (SmallPositiveDouble.from_value, 10, "Did not pass constraint: x < 10.0"),
+            (Name.from_value, "", "Did not pass constraint: len(x) > 0"),
+            (Name.from_value, "a long name", "Did not pass constraint: len(x) < 10"),
+            (Country.from_value, "DK", "Did not pass constraint: matches(x, \"^[A-Z]{2}$\") && x in [\"SE\", \"NO\"]"),
+            (Country.from_value, "no", "Did not pass constraint: matches(x, \"^[A-Z]{2}$\") && x in [\"SE\", \"NO\"]"),
+            (Odd.from_value, -4, "Did not pass constraint: x % 2 == 1 || x % 2 == -1"),
+        ]:
+            with self.assertRaises(termite.Error) as context:
+                parser(data)
+            self.assertEqual(context.exception.message, message)
+
+    def test_constructor(self):
+        with self.assertRaises(termite.Error):
+            PositiveDouble(-1.0)
+        with self.assertRaises(dataclasses.FrozenInstanceError):
+            PositiveDouble(1.0).value = -1.0
+
+
+if __name__ == "__main__":
+    unittest.main()
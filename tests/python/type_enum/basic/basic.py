@@ -0,0 +1,92 @@
+# Generated with the Termite Data Model Generator
+
+from __future__ import annotations
+
+import dataclasses
+import typing
+
+import termite
+
+
+@dataclasses.dataclass(kw_only=True)
+class Point:
+    """
+    Attributes:
+        extra_fields: All fields which were not captured when parsing
+    """
+
+    x: float
+    extra_fields: dict[str, typing.Any] = dataclasses.field(default_factory=dict)
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Point:
+        """Parses Point from a plain value, unknown fields are kept in extra_fields"""
+        fields = termite.parse_map(value)
+        kwargs = {}
+        kwargs["x"] = termite.parse_field(fields, "x", termite.parse_number)
+        return cls(**kwargs, extra_fields=fields)
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the Point to a plain value"""
+        value = termite.to_value(self.extra_fields)
+        value["x"] = termite.to_value(self.x)
+        return value
+
+
+@dataclasses.dataclass
+class DataType:
+    """
+    Attributes:
+        value: The enum type which is set
+    """
+
+    @dataclasses.dataclass
+    class Empty:
+        """
+        A type without data
+        """
+
+    @dataclasses.dataclass
+    class Int:
+        value: int
+
+    @dataclasses.dataclass
+    class Text:
+        value: str
+
+    @dataclasses.dataclass
+    class Position:
+        """
+        A custom type
+        """
+
+        value: Point
+
+    value: DataType.Empty | DataType.Int | DataType.Text | DataType.Position
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> DataType:
+        """Parses DataType from a plain value"""
+        return cls(termite.parse_enum(
+            value,
+            {
+                "Empty": cls.Empty,
+            },
+            {
+                "Int": lambda data: cls.Int(termite.parse_integer(data)),
+                "Text": lambda data: cls.Text(termite.parse_string(data)),
+                "Position": lambda data: cls.Position(Point.from_value(data)),
+            },
+        ))
+
+    def to_value(self) -> typing.Any:
+        """Converts the DataType to a plain value"""
+        if isinstance(self.value, DataType.Empty):
+            return "Empty"
+        if isinstance(self.value, DataType.Int):
+            return {"Int": termite.to_value(self.value.value)}
+        if isinstance(self.value, DataType.Text):
+            return {"Text": termite.to_value(self.value.value)}
+        if isinstance(self.value, DataType.Position):
+            return {"Position": termite.to_value(self.value.value)}
+        raise termite.Error(f"Unknown enum type {type(self.value).__name__}")
@@ -0,0 +1,38 @@
+import unittest
+
+import termite
+from basic import DataType, Point
+
+
+class TestBasic(unittest.TestCase):
+    def test_empty(self):
+        value = DataType.from_value("Empty")
+        self.assertEqual(value, DataType(DataType.Empty()))
+        self.assertEqual(value.to_value(), "Empty")
+
+    def test_values(self):
+        self.assertEqual(DataType.from_value({"Int": "5"}), DataType(DataType.Int(5)))
+        self.assertEqual(DataType.from_value({"Text": "a"}), DataType(DataType.Text("a")))
+        self.assertEqual(DataType.from_value({"Position": {"x": 1}}), DataType(DataType.Position(Point(x=1.0))))
+
+    def test_round_trip(self):
+        for data in ["Empty", {"Int": 5}, {"Text": "a"}, {"Position": {"x": 1.0}}]:
+            self.assertEqual(DataType.from_value(data).to_value(), data)
+
+    def test_errors(self):
+        for data, message in [
+            ("Int", "Enum type Int must contain a value"),
+            ({"Empty": 1}, "Enum type Empty must not include values"),
+            ("Other", 'Unknown enum type "Other"'),
+            ({"Int": 1, "Text": "a"}, "There must be exactly one enum type specified but received 2"),
+        ]:
+            with self.assertRaises(termite.Error) as context:
+                DataType.from_value(data)
+            self.assertEqual(context.exception.message, message)
+        with self.assertRaises(termite.Error) as context:
+            DataType.from_value({"Position": {}})
+        self.assertEqual(context.exception, termite.Error("Missing x", "Position"))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,46 @@
+# Generated with the Termite Data Model Generator
+
+from __future__ import annotations
+
+import dataclasses
+import typing
+
+import termite
+
+
+@dataclasses.dataclass
+class DataType1:
+    """
+    Attributes:
+        values: The values of the map
+    """
+
+    values: dict[str, int]
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> DataType1:
+        """Parses DataType1 from a plain value"""
+        return cls(termite.parse_values(value, termite.parse_integer))
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the DataType1 to a plain value"""
+        return termite.to_value(self.values)
+
+
+@dataclasses.dataclass
+class DataType2:
+    """
+    Attributes:
+        values: The values of the map
+    """
+
+    values: dict[str, float]
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> DataType2:
+        """Parses DataType2 from a plain value"""
+        return cls(termite.parse_values(value, termite.parse_number))
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the DataType2 to a plain value"""
+        return termite.to_value(self.values)
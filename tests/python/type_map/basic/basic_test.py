@@ -0,0 +1,23 @@
+import unittest
+
+import termite
+from basic import DataType1, DataType2
+
+
+class TestBasic(unittest.TestCase):
+    def test_map(self):
+        value = DataType1.from_value({"a": "1", "b": 2})
+        self.assertEqual(value, DataType1({"a": 1, "b": 2}))
+        self.assertEqual(value.to_value(), {"a": 1, "b": 2})
+        self.assertEqual(DataType2.from_value({"c": 1.5}).to_value(), {"c": 1.5})
+
+    def test_error(self):
+        with self.assertRaises(termite.Error) as context:
+            DataType1.from_value({"a": 1, "b": "c"})
+        self.assertEqual(context.exception, termite.Error("Unable to parse", "b"))
+        with self.assertRaises(termite.Error):
+            DataType1.from_value([1])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,54 @@
+# Generated with the Termite Data Model Generator
+
+from __future__ import annotations
+
+import dataclasses
+import typing
+
+import termite
+
+
+@dataclasses.dataclass(frozen=True)
+class Key:
+    """
+    The value must fulfill the constraints:
+    - len(x) == 2
+
+    Attributes:
+        value: The constrained value
+    """
+
+    value: str
+
+    def __post_init__(self):
+        x = self.value
+        if not (len(x) == 2):
+            raise termite.Error("Did not pass constraint: len(x) == 2")
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Key:
+        """Parses Key from a plain value"""
+        return cls(termite.parse_string(value))
+
+    def to_value(self) -> typing.Any:
+        """Converts the Key to a plain value"""
+        return termite.to_value(self.value)
+
+
+@dataclasses.dataclass
+class DataType:
+    """
+    Attributes:
+        values: The values of the map
+    """
+
+    values: dict[str, int]
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> DataType:
+        """Parses DataType from a plain value"""
+        return cls(termite.parse_values(value, termite.parse_integer, Key.from_value))
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the DataType to a plain value"""
+        return termite.to_value(self.values)
@@ -0,0 +1,20 @@
+import unittest
+
+import termite
+from key_type import DataType
+
+
+class TestKeyType(unittest.TestCase):
+    def test_valid_keys(self):
+        value = DataType.from_value({"ab": 1, "cd": "2"})
+        self.assertEqual(value, DataType({"ab": 1, "cd": 2}))
+        self.assertEqual(value.to_value(), {"ab": 1, "cd": 2})
+
+    def test_invalid_key(self):
+        with self.assertRaises(termite.Error) as context:
+            DataType.from_value({"ab": 1, "abc": 2})
+        self.assertEqual(context.exception, termite.Error("Did not pass constraint: len(x) == 2", "abc"))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,101 @@
+# Generated with the Termite Data Model Generator
+
+from __future__ import annotations
+
+import dataclasses
+import typing
+
+import termite
+
+
+@dataclasses.dataclass(kw_only=True)
+class Point:
+    """
+    A point in 2D space
+
+    Attributes:
+        x: The x-coordinate
+        y: The y-coordinate
+        extra_fields: All fields which were not captured when parsing
+    """
+
+    x: float
+    y: float = dataclasses.field(default_factory=lambda: termite.parse_number("0.0"))
+    extra_fields: dict[str, typing.Any] = dataclasses.field(default_factory=dict)
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Point:
+        """Parses Point from a plain value, unknown fields are kept in extra_fields"""
+        fields = termite.parse_map(value)
+        kwargs = {}
+        kwargs["x"] = termite.parse_field(fields, "x", termite.parse_number)
+        if "y" in fields:
+            kwargs["y"] = termite.parse_field(fields, "y", termite.parse_number)
+        return cls(**kwargs, extra_fields=fields)
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the Point to a plain value"""
+        value = termite.to_value(self.extra_fields)
+        value["x"] = termite.to_value(self.x)
+        value["y"] = termite.to_value(self.y)
+        return value
+
+
+@dataclasses.dataclass(kw_only=True)
+class DataType:
+    """
+    Attributes:
+        extra_fields: All fields which were not captured when parsing
+    """
+
+    id: int
+    type: str = dataclasses.field(default_factory=lambda: termite.parse_string("default"))
+    active: bool | None = None
+    point: Point = dataclasses.field(default_factory=lambda: Point.from_value({"x": "1.5"}))
+    extra_fields: dict[str, typing.Any] = dataclasses.field(default_factory=dict)
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> DataType:
+        """Parses DataType from a plain value, unknown fields are kept in extra_fields"""
+        fields = termite.parse_map(value)
+        kwargs = {}
+        kwargs["id"] = termite.parse_field(fields, "id", termite.parse_integer)
+        if "type" in fields:
+            kwargs["type"] = termite.parse_field(fields, "type", termite.parse_string)
+        if "active" in fields:
+            kwargs["active"] = termite.parse_field(fields, "active", termite.parse_boolean)
+        if "point" in fields:
+            kwargs["point"] = termite.parse_field(fields, "point", Point.from_value)
+        return cls(**kwargs, extra_fields=fields)
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the DataType to a plain value"""
+        value = termite.to_value(self.extra_fields)
+        value["id"] = termite.to_value(self.id)
+        value["type"] = termite.to_value(self.type)
+        if self.active is not None:
+            value["active"] = termite.to_value(self.active)
+        value["point"] = termite.to_value(self.point)
+        return value
+
+
+@dataclasses.dataclass(kw_only=True)
+class Empty:
+    """
+    Attributes:
+        extra_fields: All fields which were not captured when parsing
+    """
+
+    extra_fields: dict[str, typing.Any] = dataclasses.field(default_factory=dict)
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Empty:
+        """Parses Empty from a plain value, unknown fields are kept in extra_fields"""
+        fields = termite.parse_map(value)
+        kwargs = {}
+        return cls(**kwargs, extra_fields=fields)
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the Empty to a plain value"""
+        value = termite.to_value(self.extra_fields)
+        return value
@@ -0,0 +1,40 @@
+import json
+import unittest
+
+import termite
+from basic import DataType, Empty, Point
+
+
+class TestBasic(unittest.TestCase):
+    def test_required(self):
+        point = Point.from_value({"x": "1.5"})
+        self.assertEqual(point, Point(x=1.5, y=0.0))
+        with self.assertRaises(termite.Error) as context:
+            Point.from_value({"y": 1})
+        self.assertEqual(context.exception, termite.Error("Missing x"))
+
+    def test_defaults(self):
+        value = DataType.from_value({"id": 5})
+        self.assertEqual(value, DataType(id=5, type="default", active=None, point=Point(x=1.5)))
+        self.assertEqual(value.to_value(), {"id": 5, "type": "default", "point": {"x": 1.5, "y": 0.0}})
+
+    def test_round_trip(self):
+        data = {"id": 1, "type": "text", "active": True, "point": {"x": 2.0, "y": 3.0, "z": 4}, "other": [1]}
+        value = DataType.from_value(json.loads(json.dumps(data)))
+        self.assertEqual(value.point.extra_fields, {"z": 4})
+        self.assertEqual(value.extra_fields, {"other": [1]})
+        self.assertEqual(value.to_value(), data)
+
+    def test_error_location(self):
+        with self.assertRaises(termite.Error) as context:
+            DataType.from_value({"id": 1, "point": {"x": "a"}})
+        self.assertEqual(context.exception, termite.Error("Unable to parse", "point.x"))
+
+    def test_empty(self):
+        self.assertEqual(Empty.from_value({}).to_value(), {})
+        with self.assertRaises(termite.Error):
+            Empty.from_value([])
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,96 @@
+# Generated with the Termite Data Model Generator
+
+from __future__ import annotations
+
+import dataclasses
+import typing
+
+import termite
+
+
+@dataclasses.dataclass(kw_only=True)
+class Base:
+    """
+    Attributes:
+        extra_fields: All fields which were not captured when parsing
+    """
+
+    id: int
+    extra_fields: dict[str, typing.Any] = dataclasses.field(default_factory=dict)
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Base:
+        """Parses Base from a plain value, unknown fields are kept in extra_fields"""
+        fields = termite.parse_map(value)
+        kwargs = {}
+        kwargs["id"] = termite.parse_field(fields, "id", termite.parse_integer)
+        return cls(**kwargs, extra_fields=fields)
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the Base to a plain value"""
+        value = termite.to_value(self.extra_fields)
+        value["id"] = termite.to_value(self.id)
+        return value
+
+
+@dataclasses.dataclass(kw_only=True)
+class Middle:
+    """
+    Attributes:
+        extra_fields: All fields which were not captured when parsing
+    """
+
+    id: int
+    name: str = dataclasses.field(default_factory=lambda: termite.parse_string("middle"))
+    extra_fields: dict[str, typing.Any] = dataclasses.field(default_factory=dict)
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Middle:
+        """Parses Middle from a plain value, unknown fields are kept in extra_fields"""
+        fields = termite.parse_map(value)
+        kwargs = {}
+        kwargs["id"] = termite.parse_field(fields, "id", termite.parse_integer)
+        if "name" in fields:
+            kwargs["name"] = termite.parse_field(fields, "name", termite.parse_string)
+        return cls(**kwargs, extra_fields=fields)
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the Middle to a plain value"""
+        value = termite.to_value(self.extra_fields)
+        value["id"] = termite.to_value(self.id)
+        value["name"] = termite.to_value(self.name)
+        return value
+
+
+@dataclasses.dataclass(kw_only=True)
+class Derived:
+    """
+    Attributes:
+        extra_fields: All fields which were not captured when parsing
+    """
+
+    id: int
+    name: str = dataclasses.field(default_factory=lambda: termite.parse_string("middle"))
+    value: float | None = None
+    extra_fields: dict[str, typing.Any] = dataclasses.field(default_factory=dict)
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Derived:
+        """Parses Derived from a plain value, unknown fields are kept in extra_fields"""
+        fields = termite.parse_map(value)
+        kwargs = {}
+        kwargs["id"] = termite.parse_field(fields, "id", termite.parse_integer)
+        if "name" in fields:
+            kwargs["name"] = termite.parse_field(fields, "name", termite.parse_string)
+        if "value" in fields:
+            kwargs["value"] = termite.parse_field(fields, "value", termite.parse_number)
+        return cls(**kwargs, extra_fields=fields)
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the Derived to a plain value"""
+        value = termite.to_value(self.extra_fields)
+        value["id"] = termite.to_value(self.id)
+        value["name"] = termite.to_value(self.name)
+        if self.value is not None:
+            value["value"] = termite.to_value(self.value)
+        return value
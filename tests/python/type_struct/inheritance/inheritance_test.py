@@ -0,0 +1,27 @@
+import unittest
+
+import termite
+from inheritance import Base, Derived, Middle
+
+
+class TestInheritance(unittest.TestCase):
+    def test_base(self):
+        self.assertEqual(Base.from_value({"id": 1}), Base(id=1))
+
+    def test_middle(self):
+        value = Middle.from_value({"id": 1})
+        self.assertEqual(value, Middle(id=1, name="middle"))
+        self.assertEqual(value.to_value(), {"id": 1, "name": "middle"})
+
+    def test_derived(self):
+        data = {"id": 2, "name": "derived", "value": 1.5}
+        value = Derived.from_value(data)
+        self.assertEqual(value, Derived(id=2, name="derived", value=1.5))
+        self.assertEqual(value.to_value(), data)
+        with self.assertRaises(termite.Error) as context:
+            Derived.from_value({"name": "derived"})
+        self.assertEqual(context.exception, termite.Error("Missing id"))
+
+
+if __name__ == "__main__":
+    unittest.main()
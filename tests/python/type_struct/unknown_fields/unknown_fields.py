@@ -0,0 +1,93 @@
+# Generated with the Termite Data Model Generator
+
+from __future__ import annotations
+
+import dataclasses
+import typing
+
+import termite
+
+
+@dataclasses.dataclass(kw_only=True)
+class Size:
+    """
+    Rejects unknown fields through the data model setting
+    """
+
+    width: int
+    height: int = dataclasses.field(default_factory=lambda: termite.parse_integer("1"))
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Size:
+        """Parses Size from a plain value, unknown fields are rejected"""
+        fields = termite.parse_map(value)
+        kwargs = {}
+        kwargs["width"] = termite.parse_field(fields, "width", termite.parse_integer)
+        if "height" in fields:
+            kwargs["height"] = termite.parse_field(fields, "height", termite.parse_integer)
+        termite.reject_fields(fields, ["width", "height"])
+        return cls(**kwargs)
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the Size to a plain value"""
+        value = {}
+        value["width"] = termite.to_value(self.width)
+        value["height"] = termite.to_value(self.height)
+        return value
+
+
+@dataclasses.dataclass(kw_only=True)
+class Ignored:
+    size: Size
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Ignored:
+        """Parses Ignored from a plain value, unknown fields are ignored"""
+        fields = termite.parse_map(value)
+        kwargs = {}
+        kwargs["size"] = termite.parse_field(fields, "size", Size.from_value)
+        return cls(**kwargs)
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the Ignored to a plain value"""
+        value = {}
+        value["size"] = termite.to_value(self.size)
+        return value
+
+
+@dataclasses.dataclass(kw_only=True)
+class Collected:
+    """
+    Attributes:
+        extra_fields: All fields which were not captured when parsing
+    """
+
+    extra_fields: dict[str, typing.Any] = dataclasses.field(default_factory=dict)
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Collected:
+        """Parses Collected from a plain value, unknown fields are kept in extra_fields"""
+        fields = termite.parse_map(value)
+        kwargs = {}
+        return cls(**kwargs, extra_fields=fields)
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the Collected to a plain value"""
+        value = termite.to_value(self.extra_fields)
+        return value
+
+
+@dataclasses.dataclass(kw_only=True)
+class Empty:
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Empty:
+        """Parses Empty from a plain value, unknown fields are rejected"""
+        fields = termite.parse_map(value)
+        kwargs = {}
+        termite.reject_fields(fields, [])
+        return cls(**kwargs)
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the Empty to a plain value"""
+        value = {}
+        return value
@@ -0,0 +1,40 @@
+import unittest
+
+import termite
+from unknown_fields import Collected, Empty, Ignored, Size
+
+
+class TestUnknownFields(unittest.TestCase):
+    def test_reject(self):
+        self.assertEqual(Size.from_value({"width": 2}), Size(width=2, height=1))
+        with self.assertRaises(termite.Error) as context:
+            Size.from_value({"width": 2, "widht": 2, "zzzzzzzzzz": 2})
+        self.assertEqual(
+            context.exception,
+            termite.Error('Unknown fields "widht" (did you mean "width"?), "zzzzzzzzzz"'),
+        )
+        self.assertEqual(Empty.from_value({}).to_value(), {})
+        with self.assertRaises(termite.Error):
+            Empty.from_value({"extra": 2})
+
+    def test_nested_location(self):
+        with self.assertRaises(termite.Error) as context:
+            Ignored.from_value({"size": {"width": 2, "heigth": 2}})
+        self.assertEqual(
+            context.exception,
+            termite.Error('Unknown field "heigth" (did you mean "height"?)', "size"),
+        )
+
+    def test_ignore(self):
+        value = Ignored.from_value({"size": {"width": 2}, "extra": 2})
+        self.assertEqual(value, Ignored(size=Size(width=2)))
+        self.assertEqual(value.to_value(), {"size": {"width": 2, "height": 1}})
+
+    def test_collect(self):
+        value = Collected.from_value({"extra": 2})
+        self.assertEqual(value.extra_fields, {"extra": 2})
+        self.assertEqual(value.to_value(), {"extra": 2})
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,59 @@
+# Generated with the Termite Data Model Generator
+
+from __future__ import annotations
+
+import dataclasses
+import typing
+
+import termite
+
+
+@dataclasses.dataclass(kw_only=True)
+class Point:
+    """
+    Attributes:
+        extra_fields: All fields which were not captured when parsing
+    """
+
+    x: float
+    extra_fields: dict[str, typing.Any] = dataclasses.field(default_factory=dict)
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> Point:
+        """Parses Point from a plain value, unknown fields are kept in extra_fields"""
+        fields = termite.parse_map(value)
+        kwargs = {}
+        kwargs["x"] = termite.parse_field(fields, "x", termite.parse_number)
+        return cls(**kwargs, extra_fields=fields)
+
+    def to_value(self) -> dict[str, typing.Any]:
+        """Converts the Point to a plain value"""
+        value = termite.to_value(self.extra_fields)
+        value["x"] = termite.to_value(self.x)
+        return value
+
+
+@dataclasses.dataclass
+class DataType:
+    """
+    Any of the types
+
+    Attributes:
+        value: The value of the first type which could be parsed
+    """
+
+    value: int | float | Point | str
+
+    @classmethod
+    def from_value(cls, value: typing.Any) -> DataType:
+        """Parses DataType from a plain value"""
+        return cls(termite.parse_variant(value, [
+            ("integer", termite.parse_integer),
+            ("number", termite.parse_number),
+            ("Point", Point.from_value),
+            ("string", termite.parse_string),
+        ]))
+
+    def to_value(self) -> typing.Any:
+        """Converts the DataType to a plain value"""
+        return termite.to_value(self.value)
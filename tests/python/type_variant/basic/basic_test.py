@@ -0,0 +1,24 @@
+import unittest
+
+import termite
+from basic import DataType, Point
+
+
+class TestBasic(unittest.TestCase):
+    def test_first_match(self):
+        self.assertEqual(DataType.from_value("5"), DataType(5))
+        self.assertEqual(DataType.from_value(1.5), DataType(1.5))
+        self.assertEqual(DataType.from_value({"x": 1}), DataType(Point(x=1.0)))
+        self.assertEqual(DataType.from_value("text"), DataType("text"))
+
+    def test_round_trip(self):
+        for data in [5, 1.5, {"x": 1.0}, "text"]:
+            self.assertEqual(DataType.from_value(data).to_value(), data)
+
+    def test_error(self):
+        with self.assertRaises(termite.Error):
+            DataType.from_value([])
+
+
+if __name__ == "__main__":
+    unittest.main()
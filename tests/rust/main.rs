@@ -0,0 +1,46 @@
+//! Round trip tests for the generated Rust code, every test directory holds the
+//! data model, the generated code and the tests using the generated code
+
+// Not all generated code is used by the tests
+#![allow(dead_code)]
+
+#[path = "outline/outline_test.rs"]
+mod outline;
+#[path = "recursive/recursive_test.rs"]
+mod recursive;
+
+mod type_array {
+    #[path = "basic/basic_test.rs"]
+    mod basic;
+}
+
+mod type_constrained {
+    #[path = "basic/basic_test.rs"]
+    mod basic;
+}
+
+mod type_enum {
+    #[path = "basic/basic_test.rs"]
+    mod basic;
+}
+
+mod type_map {
+    #[path = "basic/basic_test.rs"]
+    mod basic;
+    #[path = "key_type/key_type_test.rs"]
+    mod key_type;
+}
+
+mod type_struct {
+    #[path = "basic/basic_test.rs"]
+    mod basic;
+    #[path = "inheritance/inheritance_test.rs"]
+    mod inheritance;
+    #[path = "unknown_fields/unknown_fields_test.rs"]
+    mod unknown_fields;
+}
+
+mod type_variant {
+    #[path = "basic/basic_test.rs"]
+    mod basic;
+}
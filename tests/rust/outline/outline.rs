@@ -0,0 +1,16 @@
+// Generated with the Termite Data Model Generator
+
+// Header with message: This is a macro message
+
+/// A data type with a long description
+///
+/// which spans multiple lines
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DataType {
+    pub field: i64,
+    /// All fields which were not captured when parsing
+    #[serde(flatten)]
+    pub extra_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+// Footer
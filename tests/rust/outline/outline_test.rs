@@ -0,0 +1,18 @@
+#[path = "outline.rs"]
+mod generated;
+use generated::*;
+
+#[test]
+fn round_trip() {
+    let value: DataType = serde_yaml::from_str("field: 1").unwrap();
+    assert_eq!(value.field, 1);
+    assert!(value.extra_fields.is_empty());
+
+    let yaml = serde_yaml::to_string(&value).unwrap();
+    assert_eq!(serde_yaml::from_str::<DataType>(&yaml).unwrap(), value);
+}
+
+#[test]
+fn missing_field() {
+    assert!(serde_yaml::from_str::<DataType>("other: 1").is_err());
+}
@@ -0,0 +1,126 @@
+// Generated with the Termite Data Model Generator
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Tree {
+    pub value: i64,
+    pub children: TreeArray,
+    /// All fields which were not captured when parsing
+    #[serde(flatten)]
+    pub extra_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct TreeArray {
+    /// The values of the array
+    pub values: Vec<Tree>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    Zero,
+    Number(f64),
+    Negate(Box<Expr>),
+    Add(Box<ExprPair>),
+}
+
+impl serde::Serialize for Expr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Zero => serializer.serialize_str("Zero"),
+            Self::Number(value) => serde::Serialize::serialize(&std::collections::BTreeMap::from([("Number", value)]), serializer),
+            Self::Negate(value) => serde::Serialize::serialize(&std::collections::BTreeMap::from([("Negate", value)]), serializer),
+            Self::Add(value) => serde::Serialize::serialize(&std::collections::BTreeMap::from([("Add", value)]), serializer),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Expr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const NAMES: &[&str] = &["Zero", "Number", "Negate", "Add"];
+
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = Expr;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("one of the types of Expr")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                match value {
+                    "Zero" => Ok(Expr::Zero),
+                    _ => Err(E::unknown_variant(value, NAMES)),
+                }
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let Some(type_name) = map.next_key::<String>()? else {
+                    return Err(serde::de::Error::invalid_length(0, &self));
+                };
+                let value = match type_name.as_str() {
+                    "Number" => Expr::Number(map.next_value()?),
+                    "Negate" => Expr::Negate(map.next_value()?),
+                    "Add" => Expr::Add(map.next_value()?),
+                    _ => return Err(serde::de::Error::unknown_variant(&type_name, NAMES)),
+                };
+                if map.next_key::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(2, &self));
+                }
+                Ok(value)
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ExprPair {
+    pub lhs: Box<Expr>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rhs: Option<Box<Expr>>,
+    /// All fields which were not captured when parsing
+    #[serde(flatten)]
+    pub extra_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum Item {
+    Integer(i64),
+    ItemPair(Box<ItemPair>),
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ItemPair {
+    pub first: Box<Item>,
+    #[serde(default = "ItemPair::default_second")]
+    pub second: Box<Item>,
+    /// All fields which were not captured when parsing
+    #[serde(flatten)]
+    pub extra_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ItemPair {
+    /// The default value of the second field
+    pub fn default_second() -> Box<Item> {
+        serde_json::from_value(serde_json::json!(0)).unwrap()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Directory {
+    pub children: DirectoryMap,
+    /// All fields which were not captured when parsing
+    #[serde(flatten)]
+    pub extra_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct DirectoryMap {
+    /// The values of the map
+    pub values: std::collections::BTreeMap<String, Directory>,
+}
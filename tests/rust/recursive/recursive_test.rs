@@ -0,0 +1,66 @@
+#[path = "recursive.rs"]
+mod generated;
+use generated::*;
+
+#[test]
+fn tree() {
+    let value: Tree =
+        serde_yaml::from_str("value: 1\nchildren:\n- value: 2\n  children: []\n- value: 3\n  children:\n  - value: 4\n    children: []")
+            .unwrap();
+    assert_eq!(value.value, 1);
+    assert_eq!(value.children.values.len(), 2);
+    assert_eq!(value.children.values[1].children.values[0].value, 4);
+
+    let yaml = serde_yaml::to_string(&value).unwrap();
+    assert_eq!(serde_yaml::from_str::<Tree>(&yaml).unwrap(), value);
+}
+
+#[test]
+fn expr() {
+    let value: Expr =
+        serde_yaml::from_str("Add:\n  lhs:\n    Negate:\n      Number: 1.5\n  rhs: Zero").unwrap();
+    assert_eq!(
+        value,
+        Expr::Add(Box::new(ExprPair {
+            lhs: Box::new(Expr::Negate(Box::new(Expr::Number(1.5)))),
+            rhs: Some(Box::new(Expr::Zero)),
+            extra_fields: serde_json::Map::new(),
+        }))
+    );
+
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(serde_json::from_str::<Expr>(&json).unwrap(), value);
+}
+
+#[test]
+fn item() {
+    let value: Item = serde_json::from_str(r#"{"first": {"first": 1, "second": 2}}"#).unwrap();
+    assert_eq!(
+        value,
+        Item::ItemPair(Box::new(ItemPair {
+            first: Box::new(Item::ItemPair(Box::new(ItemPair {
+                first: Box::new(Item::Integer(1)),
+                second: Box::new(Item::Integer(2)),
+                extra_fields: serde_json::Map::new(),
+            }))),
+            second: Box::new(Item::Integer(0)),
+            extra_fields: serde_json::Map::new(),
+        }))
+    );
+
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(serde_json::from_str::<Item>(&json).unwrap(), value);
+}
+
+#[test]
+fn directory() {
+    let value: Directory = serde_yaml::from_str(
+        "children:\n  a:\n    children: {}\n  b:\n    children:\n      c:\n        children: {}",
+    )
+    .unwrap();
+    assert_eq!(value.children.values.len(), 2);
+    assert!(value.children.values["b"].children.values.contains_key("c"));
+
+    let yaml = serde_yaml::to_string(&value).unwrap();
+    assert_eq!(serde_yaml::from_str::<Directory>(&yaml).unwrap(), value);
+}
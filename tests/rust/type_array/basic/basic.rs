@@ -0,0 +1,24 @@
+// Generated with the Termite Data Model Generator
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct Numbers {
+    /// The values of the array
+    pub values: Vec<i64>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Element {
+    pub name: String,
+    /// All fields which were not captured when parsing
+    #[serde(flatten)]
+    pub extra_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A list of elements
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct Elements {
+    /// The values of the array
+    pub values: Vec<Element>,
+}
@@ -0,0 +1,28 @@
+#[path = "basic.rs"]
+mod generated;
+use generated::*;
+
+#[test]
+fn builtin() {
+    let value: Numbers = serde_yaml::from_str("[1, 2, 3]").unwrap();
+    assert_eq!(value.values, vec![1, 2, 3]);
+
+    let yaml = serde_yaml::to_string(&value).unwrap();
+    assert_eq!(serde_yaml::from_str::<Numbers>(&yaml).unwrap(), value);
+}
+
+#[test]
+fn custom() {
+    let value: Elements = serde_json::from_str(r#"[{"name": "a"}, {"name": "b"}]"#).unwrap();
+    assert_eq!(value.values.len(), 2);
+    assert_eq!(value.values[0].name, "a");
+    assert_eq!(value.values[1].name, "b");
+
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(serde_json::from_str::<Elements>(&json).unwrap(), value);
+}
+
+#[test]
+fn invalid_element() {
+    assert!(serde_json::from_str::<Elements>(r#"[{"name": "a"}, {}]"#).is_err());
+}
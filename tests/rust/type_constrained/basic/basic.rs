@@ -0,0 +1,219 @@
+// Generated with the Termite Data Model Generator
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "f64", into = "f64")]
+pub struct PositiveDouble(f64);
+
+impl PositiveDouble {
+    /// Constructs a new PositiveDouble if the value fulfills the constraints:
+    /// - x > 0.0
+    pub fn new(value: f64) -> Result<Self, String> {
+        let x = value;
+        let valid = x > 0.0;
+        if !valid {
+            return Err("Did not pass constraint: x > 0.0".to_string());
+        }
+        Ok(Self(value))
+    }
+
+    /// Gets a reference to the constrained value
+    pub fn get(&self) -> &f64 {
+        &self.0
+    }
+
+    /// Takes out the constrained value
+    pub fn into_inner(self) -> f64 {
+        self.0
+    }
+}
+
+impl TryFrom<f64> for PositiveDouble {
+    type Error = String;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<PositiveDouble> for f64 {
+    fn from(value: PositiveDouble) -> Self {
+        value.0
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "PositiveDouble", into = "PositiveDouble")]
+pub struct SmallPositiveDouble(PositiveDouble);
+
+impl SmallPositiveDouble {
+    /// Constructs a new SmallPositiveDouble if the value fulfills the constraints:
+    /// - x < 10.0
+    pub fn new(value: PositiveDouble) -> Result<Self, String> {
+        let x = &value;
+        let valid = *x.get() < 10.0;
+        if !valid {
+            return Err("Did not pass constraint: x < 10.0".to_string());
+        }
+        Ok(Self(value))
+    }
+
+    /// Gets a reference to the constrained value
+    pub fn get(&self) -> &PositiveDouble {
+        &self.0
+    }
+
+    /// Takes out the constrained value
+    pub fn into_inner(self) -> PositiveDouble {
+        self.0
+    }
+}
+
+impl TryFrom<PositiveDouble> for SmallPositiveDouble {
+    type Error = String;
+
+    fn try_from(value: PositiveDouble) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<SmallPositiveDouble> for PositiveDouble {
+    fn from(value: SmallPositiveDouble) -> Self {
+        value.0
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Name(String);
+
+impl Name {
+    /// Constructs a new Name if the value fulfills the constraints:
+    /// - len(x) > 0
+    /// - len(x) < 10
+    pub fn new(value: String) -> Result<Self, String> {
+        let x = &value;
+        let valid = (x.chars().count() as i64) > 0;
+        if !valid {
+            return Err("Did not pass constraint: len(x) > 0".to_string());
+        }
+        let valid = (x.chars().count() as i64) < 10;
+        if !valid {
+            return Err("Did not pass constraint: len(x) < 10".to_string());
+        }
+        Ok(Self(value))
+    }
+
+    /// Gets a reference to the constrained value
+    pub fn get(&self) -> &String {
+        &self.0
+    }
+
+    /// Takes out the constrained value
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl TryFrom<String> for Name {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<Name> for String {
+    fn from(value: Name) -> Self {
+        value.0
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "i64", into = "i64")]
+pub struct Anything(i64);
+
+impl Anything {
+    /// Constructs a new Anything
+    pub fn new(value: i64) -> Result<Self, String> {
+        Ok(Self(value))
+    }
+
+    /// Gets a reference to the constrained value
+    pub fn get(&self) -> &i64 {
+        &self.0
+    }
+
+    /// Takes out the constrained value
+    pub fn into_inner(self) -> i64 {
+        self.0
+    }
+}
+
+impl TryFrom<i64> for Anything {
+    type Error = String;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<Anything> for i64 {
+    fn from(value: Anything) -> Self {
+        value.0
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Code(String);
+
+impl Code {
+    /// Constructs a new Code if the value fulfills the constraints:
+    /// - matches(x, "^[A-Z]{2}$") || matches(x, "^[0-9]+$")
+    /// - !matches(x, "^00")
+    pub fn new(value: String) -> Result<Self, String> {
+        static PATTERN_0: std::sync::LazyLock<regex::Regex> = std::sync::LazyLock::new(|| {
+            regex::Regex::new("^[A-Z]{2}$").expect("The pattern is checked by termite")
+        });
+        static PATTERN_1: std::sync::LazyLock<regex::Regex> = std::sync::LazyLock::new(|| {
+            regex::Regex::new("^[0-9]+$").expect("The pattern is checked by termite")
+        });
+        static PATTERN_2: std::sync::LazyLock<regex::Regex> = std::sync::LazyLock::new(|| {
+            regex::Regex::new("^00").expect("The pattern is checked by termite")
+        });
+        let x = &value;
+        let valid = PATTERN_0.is_match(&x) || PATTERN_1.is_match(&x);
+        if !valid {
+            return Err("Did not pass constraint: matches(x, \"^[A-Z]{2}$\") || matches(x, \"^[0-9]+$\")".to_string());
+        }
+        let valid = !PATTERN_2.is_match(&x);
+        if !valid {
+            return Err("Did not pass constraint: !matches(x, \"^00\")".to_string());
+        }
+        Ok(Self(value))
+    }
+
+    /// Gets a reference to the constrained value
+    pub fn get(&self) -> &String {
+        &self.0
+    }
+
+    /// Takes out the constrained value
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl TryFrom<String> for Code {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<Code> for String {
+    fn from(value: Code) -> Self {
+        value.0
+    }
+}
@@ -0,0 +1,60 @@
+#[path = "basic.rs"]
+mod generated;
+use generated::*;
+
+#[test]
+fn new() {
+    assert_eq!(*PositiveDouble::new(1.0).unwrap().get(), 1.0);
+    assert_eq!(
+        PositiveDouble::new(-1.0),
+        Err("Did not pass constraint: x > 0.0".to_string())
+    );
+    assert!(SmallPositiveDouble::new(PositiveDouble::new(5.0).unwrap()).is_ok());
+    assert!(SmallPositiveDouble::new(PositiveDouble::new(50.0).unwrap()).is_err());
+    assert_eq!(Name::new("name".to_string()).unwrap().into_inner(), "name");
+    assert_eq!(
+        Name::new("".to_string()),
+        Err("Did not pass constraint: len(x) > 0".to_string())
+    );
+    assert!(Name::new("a very long name".to_string()).is_err());
+    assert_eq!(*Anything::new(-5).unwrap().get(), -5);
+    assert!(Code::new("AB".to_string()).is_ok());
+    assert!(Code::new("123".to_string()).is_ok());
+    assert_eq!(
+        Code::new("ABC".to_string()),
+        Err(
+            "Did not pass constraint: matches(x, \"^[A-Z]{2}$\") || matches(x, \"^[0-9]+$\")"
+                .to_string()
+        )
+    );
+    assert_eq!(
+        Code::new("0012".to_string()),
+        Err("Did not pass constraint: !matches(x, \"^00\")".to_string())
+    );
+}
+
+#[test]
+fn parse() {
+    assert_eq!(
+        serde_yaml::from_str::<SmallPositiveDouble>("2.5").unwrap(),
+        SmallPositiveDouble::new(PositiveDouble::new(2.5).unwrap()).unwrap()
+    );
+    assert!(serde_yaml::from_str::<SmallPositiveDouble>("-2.5").is_err());
+    assert!(serde_yaml::from_str::<SmallPositiveDouble>("20.0").is_err());
+    assert!(serde_json::from_str::<Name>(r#""""#).is_err());
+}
+
+#[test]
+fn round_trip() {
+    let value = SmallPositiveDouble::new(PositiveDouble::new(2.5).unwrap()).unwrap();
+    let yaml = serde_yaml::to_string(&value).unwrap();
+    assert_eq!(
+        serde_yaml::from_str::<SmallPositiveDouble>(&yaml).unwrap(),
+        value
+    );
+
+    let value = Name::new("name".to_string()).unwrap();
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#""name""#);
+    assert_eq!(serde_json::from_str::<Name>(&json).unwrap(), value);
+}
@@ -0,0 +1,71 @@
+// Generated with the Termite Data Model Generator
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Point {
+    pub x: f64,
+    /// All fields which were not captured when parsing
+    #[serde(flatten)]
+    pub extra_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum DataType {
+    /// A type without data
+    Empty,
+    Int(i64),
+    Text(String),
+    /// A custom type
+    Position(Point),
+}
+
+impl serde::Serialize for DataType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Empty => serializer.serialize_str("Empty"),
+            Self::Int(value) => serde::Serialize::serialize(&std::collections::BTreeMap::from([("Int", value)]), serializer),
+            Self::Text(value) => serde::Serialize::serialize(&std::collections::BTreeMap::from([("Text", value)]), serializer),
+            Self::Position(value) => serde::Serialize::serialize(&std::collections::BTreeMap::from([("Position", value)]), serializer),
+        }
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for DataType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const NAMES: &[&str] = &["Empty", "Int", "Text", "Position"];
+
+        struct Visitor;
+
+        impl<'de> serde::de::Visitor<'de> for Visitor {
+            type Value = DataType;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("one of the types of DataType")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                match value {
+                    "Empty" => Ok(DataType::Empty),
+                    _ => Err(E::unknown_variant(value, NAMES)),
+                }
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let Some(type_name) = map.next_key::<String>()? else {
+                    return Err(serde::de::Error::invalid_length(0, &self));
+                };
+                let value = match type_name.as_str() {
+                    "Int" => DataType::Int(map.next_value()?),
+                    "Text" => DataType::Text(map.next_value()?),
+                    "Position" => DataType::Position(map.next_value()?),
+                    _ => return Err(serde::de::Error::unknown_variant(&type_name, NAMES)),
+                };
+                if map.next_key::<serde::de::IgnoredAny>()?.is_some() {
+                    return Err(serde::de::Error::invalid_length(2, &self));
+                }
+                Ok(value)
+            }
+        }
+
+        deserializer.deserialize_any(Visitor)
+    }
+}
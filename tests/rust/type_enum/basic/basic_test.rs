@@ -0,0 +1,60 @@
+#[path = "basic.rs"]
+mod generated;
+use generated::*;
+
+#[test]
+fn layout() {
+    assert_eq!(
+        serde_yaml::from_str::<DataType>("Empty").unwrap(),
+        DataType::Empty
+    );
+    assert_eq!(
+        serde_yaml::from_str::<DataType>("Int: 5").unwrap(),
+        DataType::Int(5)
+    );
+    assert_eq!(
+        serde_yaml::from_str::<DataType>("Text: text").unwrap(),
+        DataType::Text("text".to_string())
+    );
+    assert_eq!(
+        serde_yaml::from_str::<DataType>("Position:\n  x: 1.0").unwrap(),
+        DataType::Position(Point {
+            x: 1.0,
+            extra_fields: serde_json::Map::new(),
+        })
+    );
+
+    // Empty types are written as strings and others as single field maps
+    assert_eq!(
+        serde_json::to_value(DataType::Empty).unwrap(),
+        serde_json::json!("Empty")
+    );
+    assert_eq!(
+        serde_json::to_value(DataType::Int(5)).unwrap(),
+        serde_json::json!({"Int": 5})
+    );
+}
+
+#[test]
+fn round_trip() {
+    for value in [
+        DataType::Empty,
+        DataType::Int(1),
+        DataType::Text("text".to_string()),
+        DataType::Position(Point {
+            x: 1.0,
+            extra_fields: serde_json::Map::new(),
+        }),
+    ] {
+        let yaml = serde_yaml::to_string(&value).unwrap();
+        assert_eq!(serde_yaml::from_str::<DataType>(&yaml).unwrap(), value);
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<DataType>(&json).unwrap(), value);
+    }
+}
+
+#[test]
+fn invalid() {
+    assert!(serde_yaml::from_str::<DataType>("Other").is_err());
+    assert!(serde_yaml::from_str::<DataType>("Int: text").is_err());
+}
@@ -0,0 +1,15 @@
+// Generated with the Termite Data Model Generator
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct DataType1 {
+    /// The values of the map
+    pub values: std::collections::BTreeMap<String, i64>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct DataType2 {
+    /// The values of the map
+    pub values: std::collections::BTreeMap<String, f64>,
+}
@@ -0,0 +1,24 @@
+#[path = "basic.rs"]
+mod generated;
+use generated::*;
+
+#[test]
+fn round_trip() {
+    let value: DataType1 = serde_yaml::from_str("a: 1\nb: 2").unwrap();
+    assert_eq!(value.values.get("a"), Some(&1));
+    assert_eq!(value.values.get("b"), Some(&2));
+
+    let yaml = serde_yaml::to_string(&value).unwrap();
+    assert_eq!(serde_yaml::from_str::<DataType1>(&yaml).unwrap(), value);
+
+    let value: DataType2 = serde_json::from_str(r#"{"x": 1.5}"#).unwrap();
+    assert_eq!(value.values.get("x"), Some(&1.5));
+
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(serde_json::from_str::<DataType2>(&json).unwrap(), value);
+}
+
+#[test]
+fn invalid_value() {
+    assert!(serde_yaml::from_str::<DataType1>("a: text").is_err());
+}
@@ -0,0 +1,70 @@
+// Generated with the Termite Data Model Generator
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Key(String);
+
+impl Key {
+    /// Constructs a new Key if the value fulfills the constraints:
+    /// - len(x) == 2
+    pub fn new(value: String) -> Result<Self, String> {
+        let x = &value;
+        let valid = (x.chars().count() as i64) == 2;
+        if !valid {
+            return Err("Did not pass constraint: len(x) == 2".to_string());
+        }
+        Ok(Self(value))
+    }
+
+    /// Gets a reference to the constrained value
+    pub fn get(&self) -> &String {
+        &self.0
+    }
+
+    /// Takes out the constrained value
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl TryFrom<String> for Key {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<Key> for String {
+    fn from(value: Key) -> Self {
+        value.0
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "std::collections::BTreeMap<String, i64>", into = "std::collections::BTreeMap<String, i64>")]
+pub struct DataType {
+    /// The values of the map, all keys must be valid as Key
+    pub values: std::collections::BTreeMap<String, i64>,
+}
+
+impl TryFrom<std::collections::BTreeMap<String, i64>> for DataType {
+    type Error = String;
+
+    fn try_from(values: std::collections::BTreeMap<String, i64>) -> Result<Self, Self::Error> {
+        for key in values.keys() {
+            let deserializer: serde::de::value::StrDeserializer<serde::de::value::Error> =
+                serde::de::IntoDeserializer::into_deserializer(key.as_str());
+            if let Err(error) = <Key as serde::Deserialize>::deserialize(deserializer) {
+                return Err(format!("Invalid key {key:?}: {error}"));
+            }
+        }
+        Ok(Self { values })
+    }
+}
+
+impl From<DataType> for std::collections::BTreeMap<String, i64> {
+    fn from(value: DataType) -> Self {
+        value.values
+    }
+}
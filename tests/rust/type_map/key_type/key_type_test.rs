@@ -0,0 +1,18 @@
+#[path = "key_type.rs"]
+mod generated;
+use generated::*;
+
+#[test]
+fn valid_keys() {
+    let value: DataType = serde_yaml::from_str("ab: 1\ncd: 2").unwrap();
+    assert_eq!(value.values.get("ab"), Some(&1));
+    assert_eq!(value.values.get("cd"), Some(&2));
+
+    let yaml = serde_yaml::to_string(&value).unwrap();
+    assert_eq!(serde_yaml::from_str::<DataType>(&yaml).unwrap(), value);
+}
+
+#[test]
+fn invalid_key() {
+    assert!(serde_yaml::from_str::<DataType>("ab: 1\nabc: 2").is_err());
+}
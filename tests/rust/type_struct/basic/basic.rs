@@ -0,0 +1,54 @@
+// Generated with the Termite Data Model Generator
+
+/// A point in 2D space
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Point {
+    /// The x-coordinate
+    pub x: f64,
+    /// The y-coordinate
+    #[serde(default = "Point::default_y")]
+    pub y: f64,
+    /// All fields which were not captured when parsing
+    #[serde(flatten)]
+    pub extra_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Point {
+    /// The default value of the y field
+    pub fn default_y() -> f64 {
+        serde_json::from_value(serde_json::json!(0)).unwrap()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DataType {
+    pub id: i64,
+    #[serde(default = "DataType::default_type")]
+    pub r#type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(default = "DataType::default_point")]
+    pub point: Point,
+    /// All fields which were not captured when parsing
+    #[serde(flatten)]
+    pub extra_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+impl DataType {
+    /// The default value of the type field
+    pub fn default_type() -> String {
+        serde_json::from_value(serde_json::json!("default")).unwrap()
+    }
+
+    /// The default value of the point field
+    pub fn default_point() -> Point {
+        serde_json::from_value(serde_json::json!({"x":1.5})).unwrap()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Empty {
+    /// All fields which were not captured when parsing
+    #[serde(flatten)]
+    pub extra_fields: serde_json::Map<String, serde_json::Value>,
+}
@@ -0,0 +1,88 @@
+#[path = "basic.rs"]
+mod generated;
+use generated::*;
+
+#[test]
+fn defaults() {
+    let value: DataType = serde_yaml::from_str("id: 1").unwrap();
+    assert_eq!(value.id, 1);
+    assert_eq!(value.r#type, "default");
+    assert_eq!(value.active, None);
+    assert_eq!(value.point.x, 1.5);
+    assert_eq!(value.point.y, 0.0);
+    assert!(value.extra_fields.is_empty());
+}
+
+#[test]
+fn all_fields() {
+    let value: DataType =
+        serde_yaml::from_str("id: 2\ntype: other\nactive: true\npoint:\n  x: -1.0\n  y: 2.5")
+            .unwrap();
+    assert_eq!(value.id, 2);
+    assert_eq!(value.r#type, "other");
+    assert_eq!(value.active, Some(true));
+    assert_eq!(value.point.x, -1.0);
+    assert_eq!(value.point.y, 2.5);
+}
+
+#[test]
+fn extra_fields() {
+    let value: DataType = serde_json::from_str(
+        r#"{"id": 3, "unknown": {"nested": [1, 2]}, "point": {"x": 1.0, "z": "z"}}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        value.extra_fields.get("unknown"),
+        Some(&serde_json::json!({"nested": [1, 2]}))
+    );
+    assert_eq!(
+        value.point.extra_fields.get("z"),
+        Some(&serde_json::json!("z"))
+    );
+
+    // The extra fields are written back
+    let json = serde_json::to_value(&value).unwrap();
+    assert_eq!(json["unknown"], serde_json::json!({"nested": [1, 2]}));
+    assert_eq!(json["point"]["z"], serde_json::json!("z"));
+    assert_eq!(serde_json::from_value::<DataType>(json).unwrap(), value);
+}
+
+#[test]
+fn round_trip() {
+    let value = DataType {
+        id: 4,
+        r#type: "value".to_string(),
+        active: Some(false),
+        point: Point {
+            x: 1.0,
+            y: 2.0,
+            extra_fields: serde_json::Map::new(),
+        },
+        extra_fields: serde_json::Map::new(),
+    };
+
+    let yaml = serde_yaml::to_string(&value).unwrap();
+    assert_eq!(serde_yaml::from_str::<DataType>(&yaml).unwrap(), value);
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(serde_json::from_str::<DataType>(&json).unwrap(), value);
+
+    // Optional fields which are not set are left out
+    let value = DataType {
+        active: None,
+        ..value
+    };
+    let json = serde_json::to_value(&value).unwrap();
+    assert!(json.get("active").is_none());
+    assert_eq!(serde_json::from_value::<DataType>(json).unwrap(), value);
+}
+
+#[test]
+fn empty() {
+    let value: Empty = serde_yaml::from_str("{}").unwrap();
+    assert!(value.extra_fields.is_empty());
+}
+
+#[test]
+fn missing_field() {
+    assert!(serde_yaml::from_str::<DataType>("type: value").is_err());
+}
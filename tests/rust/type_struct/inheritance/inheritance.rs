@@ -0,0 +1,45 @@
+// Generated with the Termite Data Model Generator
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Base {
+    pub id: i64,
+    /// All fields which were not captured when parsing
+    #[serde(flatten)]
+    pub extra_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Middle {
+    pub id: i64,
+    #[serde(default = "Middle::default_name")]
+    pub name: String,
+    /// All fields which were not captured when parsing
+    #[serde(flatten)]
+    pub extra_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Middle {
+    /// The default value of the name field
+    pub fn default_name() -> String {
+        serde_json::from_value(serde_json::json!("middle")).unwrap()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Derived {
+    pub id: i64,
+    #[serde(default = "Derived::default_name")]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
+    /// All fields which were not captured when parsing
+    #[serde(flatten)]
+    pub extra_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Derived {
+    /// The default value of the name field
+    pub fn default_name() -> String {
+        serde_json::from_value(serde_json::json!("middle")).unwrap()
+    }
+}
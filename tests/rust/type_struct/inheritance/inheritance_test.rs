@@ -0,0 +1,20 @@
+#[path = "inheritance.rs"]
+mod generated;
+use generated::*;
+
+#[test]
+fn inherited_fields() {
+    let value: Derived = serde_yaml::from_str("id: 1\nvalue: 2.5").unwrap();
+    assert_eq!(value.id, 1);
+    assert_eq!(value.name, "middle");
+    assert_eq!(value.value, Some(2.5));
+    assert!(value.extra_fields.is_empty());
+
+    let yaml = serde_yaml::to_string(&value).unwrap();
+    assert_eq!(serde_yaml::from_str::<Derived>(&yaml).unwrap(), value);
+}
+
+#[test]
+fn missing_inherited_field() {
+    assert!(serde_yaml::from_str::<Derived>("name: name").is_err());
+}
@@ -0,0 +1,34 @@
+// Generated with the Termite Data Model Generator
+
+/// Rejects unknown fields through the data model setting
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Size {
+    pub width: i64,
+    #[serde(default = "Size::default_height")]
+    pub height: i64,
+}
+
+impl Size {
+    /// The default value of the height field
+    pub fn default_height() -> i64 {
+        serde_json::from_value(serde_json::json!(1)).unwrap()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Ignored {
+    pub size: Size,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Collected {
+    /// All fields which were not captured when parsing
+    #[serde(flatten)]
+    pub extra_fields: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Empty {
+}
@@ -0,0 +1,34 @@
+#[path = "unknown_fields.rs"]
+mod generated;
+use generated::*;
+
+#[test]
+fn reject() {
+    let value: Size = serde_yaml::from_str("width: 2").unwrap();
+    assert_eq!(value, Size { width: 2, height: 1 });
+
+    let error = serde_yaml::from_str::<Size>("width: 2\nwidht: 3").unwrap_err();
+    assert!(error.to_string().contains("unknown field `widht`"));
+    assert!(serde_yaml::from_str::<Empty>("{}").is_ok());
+    assert!(serde_yaml::from_str::<Empty>("x: 1").is_err());
+}
+
+#[test]
+fn ignore() {
+    let value: Ignored = serde_yaml::from_str("size:\n  width: 2\nunknown: 1").unwrap();
+    assert_eq!(value.size.width, 2);
+
+    // The unknown fields are not written back
+    let json = serde_json::to_value(&value).unwrap();
+    assert_eq!(json, serde_json::json!({"size": {"width": 2, "height": 1}}));
+
+    // Ignoring only applies to the struct itself
+    assert!(serde_yaml::from_str::<Ignored>("size:\n  width: 2\n  depth: 1").is_err());
+}
+
+#[test]
+fn collect() {
+    let value: Collected = serde_yaml::from_str("unknown: 1").unwrap();
+    assert_eq!(value.extra_fields.get("unknown"), Some(&serde_json::json!(1)));
+    assert_eq!(serde_json::to_value(&value).unwrap(), serde_json::json!({"unknown": 1}));
+}
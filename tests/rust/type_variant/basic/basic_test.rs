@@ -0,0 +1,47 @@
+#[path = "basic.rs"]
+mod generated;
+use generated::*;
+
+#[test]
+fn order() {
+    assert_eq!(
+        serde_json::from_str::<DataType>("1").unwrap(),
+        DataType::Integer(1)
+    );
+    assert_eq!(
+        serde_json::from_str::<DataType>("1.5").unwrap(),
+        DataType::Number(1.5)
+    );
+    assert_eq!(
+        serde_json::from_str::<DataType>(r#""text""#).unwrap(),
+        DataType::String("text".to_string())
+    );
+    assert_eq!(
+        serde_json::from_str::<DataType>(r#"{"x": 2.0}"#).unwrap(),
+        DataType::Point(Point {
+            x: 2.0,
+            extra_fields: serde_json::Map::new(),
+        })
+    );
+}
+
+#[test]
+fn round_trip() {
+    for value in [
+        DataType::Integer(2),
+        DataType::Number(2.5),
+        DataType::String("text".to_string()),
+        DataType::Point(Point {
+            x: 1.0,
+            extra_fields: serde_json::Map::new(),
+        }),
+    ] {
+        let yaml = serde_yaml::to_string(&value).unwrap();
+        assert_eq!(serde_yaml::from_str::<DataType>(&yaml).unwrap(), value);
+    }
+}
+
+#[test]
+fn invalid() {
+    assert!(serde_json::from_str::<DataType>("[1]").is_err());
+}
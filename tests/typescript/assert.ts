@@ -0,0 +1,99 @@
+/**
+ * Assertions for the TypeScript tests, they do not rely on any node type
+ * declarations such that the tests only need tsc and node to run
+ */
+
+import * as termite from "./termite";
+
+/**
+ * Checks if two plain values are equal, maps are equal if they have the same
+ * fields regardless of the order
+ *
+ * @param lhs The first value
+ * @param rhs The second value
+ * @returns True if the values are equal
+ */
+function isEqual(lhs: unknown, rhs: unknown): boolean {
+  if (Array.isArray(lhs) || Array.isArray(rhs)) {
+    return (
+      Array.isArray(lhs) &&
+      Array.isArray(rhs) &&
+      lhs.length === rhs.length &&
+      lhs.every((value, index) => isEqual(value, rhs[index]))
+    );
+  }
+  if (typeof lhs === "object" && lhs !== null && typeof rhs === "object" && rhs !== null) {
+    const lhsFields = lhs as Record<string, unknown>;
+    const rhsFields = rhs as Record<string, unknown>;
+    const keys = Object.keys(lhsFields).sort();
+    return (
+      isEqual(keys, Object.keys(rhsFields).sort()) &&
+      keys.every((key) => isEqual(lhsFields[key], rhsFields[key]))
+    );
+  }
+  return lhs === rhs;
+}
+
+/**
+ * Makes sure a value equals the expected value
+ *
+ * @param actual The value to check
+ * @param expected The expected value
+ */
+export function assertEqual(actual: unknown, expected: unknown): void {
+  if (!isEqual(actual, expected)) {
+    throw new Error(
+      `Expected ${JSON.stringify(expected)} but received ${JSON.stringify(actual)}`,
+    );
+  }
+}
+
+/**
+ * Makes sure a callback throws a termite error
+ *
+ * @param callback The callback to run
+ * @returns The thrown error
+ */
+export function assertThrows(callback: () => unknown): termite.Error {
+  try {
+    callback();
+  } catch (error) {
+    if (error instanceof termite.Error) {
+      return error;
+    }
+    throw error;
+  }
+  throw new Error("Expected a termite error to be thrown");
+}
+
+/**
+ * Makes sure a callback throws a termite error with the given message and
+ * location
+ *
+ * @param callback The callback to run
+ * @param reason The expected message without the location
+ * @param location The expected location
+ */
+export function assertError(
+  callback: () => unknown,
+  reason: string,
+  location: string = "",
+): void {
+  const error = assertThrows(callback);
+  assertEqual({ reason: error.reason, location: error.location }, { reason, location });
+}
+
+/**
+ * Runs a single test and adds its name to the error if it fails
+ *
+ * @param name The name of the test
+ * @param callback The test to run
+ */
+export function test(name: string, callback: () => void): void {
+  try {
+    callback();
+  } catch (error) {
+    const message = error instanceof Error ? error.message : String(error);
+    throw new Error(`${name}: ${message}`);
+  }
+}
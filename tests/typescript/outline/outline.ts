@@ -0,0 +1,30 @@
+// Generated with the Termite Data Model Generator
+
+import * as termite from "./termite";
+
+// Header with message: This is a macro message
+
+/**
+ * A data type with a long description
+ *
+ * which spans multiple lines
+ */
+export interface DataType {
+  field: number;
+}
+
+export const DataType = {
+  /**
+   * Parses DataType from a plain value, unknown fields are kept
+   */
+  parse(value: unknown): DataType {
+    const fields = termite.parseMap(value);
+    const result: DataType = {
+      ...fields,
+      field: termite.parseField(fields, "field", termite.parseInteger),
+    };
+    return result;
+  },
+};
+
+// Footer
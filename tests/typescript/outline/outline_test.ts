@@ -0,0 +1,8 @@
+import { assertEqual, assertError, test } from "./assert";
+import { DataType } from "./outline";
+
+test("parse", () => {
+  assertEqual(DataType.parse({ field: "1" }), { field: 1 });
+  assertEqual(DataType.parse({ field: 1, extra: [2] }), { field: 1, extra: [2] });
+  assertError(() => DataType.parse({}), "Missing field");
+});
@@ -0,0 +1,147 @@
+// Generated with the Termite Data Model Generator
+
+import * as termite from "./termite";
+
+export interface Tree {
+  value: number;
+  children: TreeArray;
+}
+
+export const Tree = {
+  /**
+   * Parses Tree from a plain value, unknown fields are kept
+   */
+  parse(value: unknown): Tree {
+    const fields = termite.parseMap(value);
+    const result: Tree = {
+      ...fields,
+      value: termite.parseField(fields, "value", termite.parseInteger),
+      children: termite.parseField(fields, "children", TreeArray.parse),
+    };
+    return result;
+  },
+};
+
+export type TreeArray = Tree[];
+
+export const TreeArray = {
+  /**
+   * Parses TreeArray from a plain value
+   */
+  parse(value: unknown): TreeArray {
+    return termite.parseList(value, Tree.parse);
+  },
+};
+
+export type Expr =
+  | "Zero"
+  | { Number: number }
+  | { Negate: Expr }
+  | { Add: ExprPair };
+
+export const Expr = {
+  /**
+   * Parses Expr from a plain value
+   */
+  parse(value: unknown): Expr {
+    return termite.parseEnum(value, ["Zero"], {
+      Number: termite.parseNumber,
+      Negate: Expr.parse,
+      Add: ExprPair.parse,
+    }) as Expr;
+  },
+};
+
+export interface ExprPair {
+  lhs: Expr;
+  rhs?: Expr;
+}
+
+export const ExprPair = {
+  /**
+   * Parses ExprPair from a plain value, unknown fields are kept
+   */
+  parse(value: unknown): ExprPair {
+    const fields = termite.parseMap(value);
+    const result: ExprPair = {
+      ...fields,
+      lhs: termite.parseField(fields, "lhs", Expr.parse),
+    };
+    if (termite.hasField(fields, "rhs")) {
+      result.rhs = termite.parseField(fields, "rhs", Expr.parse);
+    }
+    return result;
+  },
+};
+
+export type Item = number | ItemPair;
+
+export const Item = {
+  /**
+   * Parses Item from a plain value
+   */
+  parse(value: unknown): Item {
+    return termite.parseVariant(value, [
+      ["integer", termite.parseInteger],
+      ["ItemPair", ItemPair.parse],
+    ]) as Item;
+  },
+};
+
+export interface ItemPair {
+  first: Item;
+  second: Item;
+}
+
+export const ItemPair = {
+  /**
+   * Parses ItemPair from a plain value, unknown fields are kept
+   */
+  parse(value: unknown): ItemPair {
+    const fields = termite.parseMap(value);
+    const result: ItemPair = {
+      ...fields,
+      first: termite.parseField(fields, "first", Item.parse),
+      second: termite.hasField(fields, "second")
+        ? termite.parseField(fields, "second", Item.parse)
+        : ItemPair.default_second(),
+    };
+    return result;
+  },
+
+  /**
+   * The default value of the second field
+   */
+  default_second(): Item {
+    return Item.parse("0");
+  },
+};
+
+export interface Directory {
+  children: DirectoryMap;
+}
+
+export const Directory = {
+  /**
+   * Parses Directory from a plain value, unknown fields are kept
+   */
+  parse(value: unknown): Directory {
+    const fields = termite.parseMap(value);
+    const result: Directory = {
+      ...fields,
+      children: termite.parseField(fields, "children", DirectoryMap.parse),
+    };
+    return result;
+  },
+};
+
+export type DirectoryMap = { [key: string]: Directory };
+
+export const DirectoryMap = {
+  /**
+   * Parses DirectoryMap from a plain value
+   */
+  parse(value: unknown): DirectoryMap {
+    return termite.parseValues(value, Directory.parse);
+  },
+};
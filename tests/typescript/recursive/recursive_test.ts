@@ -0,0 +1,33 @@
+import { assertEqual, assertError, test } from "./assert";
+import { Directory, Expr, Item, Tree } from "./recursive";
+
+test("tree", () => {
+  const data = { value: 1, children: [{ value: 2, children: [] }] };
+  const value = Tree.parse(data);
+  assertEqual(value.children[0].value, 2);
+  assertEqual(value, data);
+  assertError(
+    () => Tree.parse({ value: 1, children: [{ value: "a", children: [] }] }),
+    "Unable to parse",
+    "children[0].value",
+  );
+});
+
+test("expr", () => {
+  const data = { Add: { lhs: { Negate: { Number: 1.5 } }, rhs: "Zero" } };
+  assertEqual(Expr.parse(data), data);
+  assertEqual(Expr.parse({ Add: { lhs: "Zero" } }), { Add: { lhs: "Zero" } });
+  assertError(() => Expr.parse({ Negate: "One" }), 'Unknown enum type "One"', "Negate");
+});
+
+test("item", () => {
+  const data = { first: { first: 1, second: 2 }, second: 3 };
+  assertEqual(Item.parse(data), data);
+  assertEqual(Item.parse({ first: 1 }), { first: 1, second: 0 });
+});
+
+test("directory", () => {
+  const data = { children: { a: { children: {} }, b: { children: { c: { children: {} } } } } };
+  assertEqual(Directory.parse(data), data);
+  assertError(() => Directory.parse({ children: { a: {} } }), "Missing children", "children.a");
+});
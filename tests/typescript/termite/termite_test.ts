@@ -0,0 +1,125 @@
+import { assertEqual, assertError, assertThrows, test } from "./assert";
+import * as termite from "./termite";
+
+test("error", () => {
+  const error = new termite.Error("Unable to parse").addList(1).addField("b").addField("a");
+  assertEqual(error.location, "a.b[1]");
+  assertEqual(error.message, "a.b[1]: Unable to parse");
+  assertEqual(new termite.Error("Unable to parse").message, "Unable to parse");
+});
+
+test("string", () => {
+  assertEqual(termite.parseString(" text "), "text");
+  assertEqual(termite.parseString(5), "5");
+  assertEqual(termite.parseString(true), "true");
+  assertThrows(() => termite.parseString([]));
+});
+
+test("number", () => {
+  assertEqual(termite.parseNumber("1.5"), 1.5);
+  assertEqual(termite.parseNumber(" -2e3 "), -2000.0);
+  assertEqual(termite.parseNumber(3), 3.0);
+  for (const value of ["", "one", "1.0.0", true, {}, null]) {
+    assertThrows(() => termite.parseNumber(value));
+  }
+});
+
+test("integer", () => {
+  assertEqual(termite.parseInteger("-5"), -5);
+  assertEqual(termite.parseInteger(7), 7);
+  assertEqual(
+    termite.parseInteger(String(Number.MAX_SAFE_INTEGER)),
+    Number.MAX_SAFE_INTEGER,
+  );
+  for (const value of ["1.5", 1.5, false, String(Number.MAX_SAFE_INTEGER + 1), "a"]) {
+    assertThrows(() => termite.parseInteger(value));
+  }
+});
+
+test("boolean", () => {
+  for (const value of [true, "true", "True", 1]) {
+    assertEqual(termite.parseBoolean(value), true);
+  }
+  for (const value of [false, "false", "False", 0]) {
+    assertEqual(termite.parseBoolean(value), false);
+  }
+  assertThrows(() => termite.parseBoolean("yes"));
+});
+
+test("list", () => {
+  assertEqual(termite.parseList(["1", 2], termite.parseInteger), [1, 2]);
+  assertError(() => termite.parseList([1, "a"], termite.parseInteger), "Unable to parse", "[1]");
+  assertThrows(() => termite.parseList({}, termite.parseInteger));
+});
+
+test("values", () => {
+  assertEqual(termite.parseValues({ a: "1" }, termite.parseInteger), { a: 1 });
+  assertError(
+    () =>
+      termite.parseValues({ a: { b: [1, "c"] } }, (value) =>
+        termite.parseValues(value, (value) => termite.parseList(value, termite.parseInteger)),
+      ),
+    "Unable to parse",
+    "a.b[1]",
+  );
+  assertError(
+    () => termite.parseValues({ ab: 1, abc: 2 }, termite.parseInteger, termite.parseBoolean),
+    "Unable to parse",
+    "ab",
+  );
+  assertThrows(() => termite.parseValues([1], termite.parseInteger));
+});
+
+test("field", () => {
+  const fields = termite.parseMap({ a: "1", b: 2 });
+  assertEqual(termite.hasField(fields, "a"), true);
+  assertEqual(termite.hasField(fields, "c"), false);
+  assertEqual(termite.parseField(fields, "a", termite.parseInteger), 1);
+  assertError(() => termite.parseField(fields, "c", termite.parseInteger), "Missing c");
+  assertError(
+    () => termite.parseField({ a: "x" }, "a", termite.parseInteger),
+    "Unable to parse",
+    "a",
+  );
+  assertThrows(() => termite.parseMap([]));
+  assertThrows(() => termite.parseMap(null));
+});
+
+test("reject fields", () => {
+  termite.rejectFields({}, ["width"]);
+  termite.rejectFields({ width: 1 }, ["width"]);
+  assertError(
+    () => termite.rejectFields({ widht: 1, zzzzzzzzzz: 2 }, ["width", "height"]),
+    'Unknown fields "widht" (did you mean "width"?), "zzzzzzzzzz"',
+  );
+
+  // Short field names are only suggested for close typos
+  assertError(() => termite.rejectFields({ q: 1 }, ["x"]), 'Unknown field "q"');
+});
+
+test("pick fields", () => {
+  assertEqual(termite.pickFields({ width: 1, extra: 2 }, ["width", "height"]), { width: 1 });
+});
+
+test("variant", () => {
+  const parsers: [string, (value: unknown) => unknown][] = [
+    ["integer", termite.parseInteger],
+    ["boolean", termite.parseBoolean],
+  ];
+  assertEqual(termite.parseVariant("5", parsers), 5);
+  assertEqual(termite.parseVariant("true", parsers), true);
+  assertError(
+    () => termite.parseVariant("text", parsers),
+    "Unable to parse any variant: [ integer { Unable to parse }, boolean { Unable to parse } ]",
+  );
+});
+
+test("enum", () => {
+  const types = { Int: termite.parseInteger };
+  assertEqual(termite.parseEnum("Empty", ["Empty"], types), "Empty");
+  assertEqual(termite.parseEnum({ Int: "5" }, ["Empty"], types), { Int: 5 });
+  for (const value of ["Int", { Empty: 1 }, { Int: 1, Other: 2 }, {}, "Other", []]) {
+    assertThrows(() => termite.parseEnum(value, ["Empty"], types));
+  }
+  assertError(() => termite.parseEnum({ Int: "a" }, ["Empty"], types), "Unable to parse", "Int");
+});
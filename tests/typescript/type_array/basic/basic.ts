@@ -0,0 +1,46 @@
+// Generated with the Termite Data Model Generator
+
+import * as termite from "./termite";
+
+export type Numbers = number[];
+
+export const Numbers = {
+  /**
+   * Parses Numbers from a plain value
+   */
+  parse(value: unknown): Numbers {
+    return termite.parseList(value, termite.parseInteger);
+  },
+};
+
+export interface Element {
+  name: string;
+}
+
+export const Element = {
+  /**
+   * Parses Element from a plain value, unknown fields are kept
+   */
+  parse(value: unknown): Element {
+    const fields = termite.parseMap(value);
+    const result: Element = {
+      ...fields,
+      name: termite.parseField(fields, "name", termite.parseString),
+    };
+    return result;
+  },
+};
+
+/**
+ * A list of elements
+ */
+export type Elements = Element[];
+
+export const Elements = {
+  /**
+   * Parses Elements from a plain value
+   */
+  parse(value: unknown): Elements {
+    return termite.parseList(value, Element.parse);
+  },
+};
@@ -0,0 +1,19 @@
+import { assertEqual, assertError, assertThrows, test } from "./assert";
+import { Elements, Numbers } from "./basic";
+
+test("builtin", () => {
+  assertEqual(Numbers.parse(["1", 2]), [1, 2]);
+  assertEqual(Numbers.parse([]), []);
+});
+
+test("custom", () => {
+  const data = [{ name: "a" }, { name: "b", extra: 1 }];
+  const value = Elements.parse(data);
+  assertEqual(value[0].name, "a");
+  assertEqual(value, data);
+});
+
+test("error", () => {
+  assertError(() => Elements.parse([{ name: "a" }, {}]), "Missing name", "[1]");
+  assertThrows(() => Numbers.parse({ a: 1 }));
+});
@@ -0,0 +1,176 @@
+// Generated with the Termite Data Model Generator
+
+import * as termite from "./termite";
+
+/**
+ * The value must fulfill the constraints:
+ * - x > 0.0
+ */
+export type PositiveDouble = termite.Brand<number, "PositiveDouble">;
+
+export const PositiveDouble = {
+  /**
+   * Gets the first constraint of PositiveDouble the value does not fulfill,
+   * undefined if it fulfills all of them
+   */
+  check(x: number): string | undefined {
+    if (!(x > 0.0)) {
+      return "x > 0.0";
+    }
+    return undefined;
+  },
+
+  /**
+   * Checks if the value fulfills all the constraints of PositiveDouble
+   */
+  is(x: number): x is PositiveDouble {
+    return PositiveDouble.check(x) === undefined;
+  },
+
+  /**
+   * Constructs PositiveDouble if the value fulfills all the constraints
+   */
+  from(x: number): PositiveDouble {
+    const constraint = PositiveDouble.check(x);
+    if (constraint !== undefined) {
+      throw new termite.Error(`Did not pass constraint: ${constraint}`);
+    }
+    return x as PositiveDouble;
+  },
+
+  /**
+   * Parses PositiveDouble from a plain value
+   */
+  parse(value: unknown): PositiveDouble {
+    return PositiveDouble.from(termite.parseNumber(value));
+  },
+};
+
+/**
+ * The value must fulfill the constraints:
+ * - x < 10.0
+ */
+export type SmallPositiveDouble = termite.Brand<PositiveDouble, "SmallPositiveDouble">;
+
+export const SmallPositiveDouble = {
+  /**
+   * Gets the first constraint of SmallPositiveDouble the value does not fulfill,
+   * undefined if it fulfills all of them
+   */
+  check(x: PositiveDouble): string | undefined {
+    if (!(x < 10.0)) {
+      return "x < 10.0";
+    }
+    return undefined;
+  },
+
+  /**
+   * Checks if the value fulfills all the constraints of SmallPositiveDouble
+   */
+  is(x: PositiveDouble): x is SmallPositiveDouble {
+    return SmallPositiveDouble.check(x) === undefined;
+  },
+
+  /**
+   * Constructs SmallPositiveDouble if the value fulfills all the constraints
+   */
+  from(x: PositiveDouble): SmallPositiveDouble {
+    const constraint = SmallPositiveDouble.check(x);
+    if (constraint !== undefined) {
+      throw new termite.Error(`Did not pass constraint: ${constraint}`);
+    }
+    return x as SmallPositiveDouble;
+  },
+
+  /**
+   * Parses SmallPositiveDouble from a plain value
+   */
+  parse(value: unknown): SmallPositiveDouble {
+    return SmallPositiveDouble.from(PositiveDouble.parse(value));
+  },
+};
+
+/**
+ * The value must fulfill the constraints:
+ * - len(x) > 0
+ * - len(x) < 10
+ */
+export type Name = termite.Brand<string, "Name">;
+
+export const Name = {
+  /**
+   * Gets the first constraint of Name the value does not fulfill,
+   * undefined if it fulfills all of them
+   */
+  check(x: string): string | undefined {
+    if (!([...x].length > 0)) {
+      return "len(x) > 0";
+    }
+    if (!([...x].length < 10)) {
+      return "len(x) < 10";
+    }
+    return undefined;
+  },
+
+  /**
+   * Checks if the value fulfills all the constraints of Name
+   */
+  is(x: string): x is Name {
+    return Name.check(x) === undefined;
+  },
+
+  /**
+   * Constructs Name if the value fulfills all the constraints
+   */
+  from(x: string): Name {
+    const constraint = Name.check(x);
+    if (constraint !== undefined) {
+      throw new termite.Error(`Did not pass constraint: ${constraint}`);
+    }
+    return x as Name;
+  },
+
+  /**
+   * Parses Name from a plain value
+   */
+  parse(value: unknown): Name {
+    return Name.from(termite.parseString(value));
+  },
+};
+
+export type Anything = termite.Brand<number, "Anything">;
+
+export const Anything = {
+  /**
+   * Gets the first constraint of Anything the value does not fulfill,
+   * undefined if it fulfills all of them
+   */
+  check(_x: number): string | undefined {
+    return undefined;
+  },
+
+  /**
+   * Checks if the value fulfills all the constraints of Anything
+   */
+  is(x: number): x is Anything {
+    return Anything.check(x) === undefined;
+  },
+
+  /**
+   * Constructs Anything if the value fulfills all the constraints
+   */
+  from(x: number): Anything {
+    const constraint = Anything.check(x);
+    if (constraint !== undefined) {
+      throw new termite.Error(`Did not pass constraint: ${constraint}`);
+    }
+    return x as Anything;
+  },
+
+  /**
+   * Parses Anything from a plain value
+   */
+  parse(value: unknown): Anything {
+    return Anything.from(termite.parseInteger(value));
+  },
+};
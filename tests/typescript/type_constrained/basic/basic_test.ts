@@ -0,0 +1,31 @@
+import { assertEqual, assertError, test } from "./assert";
+import { Anything, Name, PositiveDouble, SmallPositiveDouble } from "./basic";
+
+test("valid", () => {
+  assertEqual(PositiveDouble.parse("1.5"), 1.5);
+  assertEqual(SmallPositiveDouble.parse(5), 5.0);
+  assertEqual(Name.parse("name"), "name");
+  assertEqual(Anything.parse(-3), -3);
+});
+
+test("invalid", () => {
+  const cases: [(value: unknown) => unknown, unknown, string][] = [
+    [PositiveDouble.parse, 0, "Did not pass constraint: x > 0.0"],
+    [SmallPositiveDouble.parse, -1, "Did not pass constraint: x > 0.0"],
+    [SmallPositiveDouble.parse, 10, "Did not pass constraint: x < 10.0"],
+    [Name.parse, "", "Did not pass constraint: len(x) > 0"],
+    [Name.parse, "a long name", "Did not pass constraint: len(x) < 10"],
+  ];
+  for (const [parser, data, message] of cases) {
+    assertError(() => parser(data), message);
+  }
+});
+
+test("construct", () => {
+  const value = PositiveDouble.from(1.5);
+  assertEqual(PositiveDouble.is(value), true);
+  assertEqual(PositiveDouble.check(-1.0), "x > 0.0");
+  assertEqual(SmallPositiveDouble.is(PositiveDouble.from(20.0)), false);
+  assertEqual(SmallPositiveDouble.from(value), 1.5);
+  assertError(() => PositiveDouble.from(-1.0), "Did not pass constraint: x > 0.0");
+});
@@ -0,0 +1,46 @@
+// Generated with the Termite Data Model Generator
+
+import * as termite from "./termite";
+
+export interface Point {
+  x: number;
+}
+
+export const Point = {
+  /**
+   * Parses Point from a plain value, unknown fields are kept
+   */
+  parse(value: unknown): Point {
+    const fields = termite.parseMap(value);
+    const result: Point = {
+      ...fields,
+      x: termite.parseField(fields, "x", termite.parseNumber),
+    };
+    return result;
+  },
+};
+
+export type DataType =
+  /**
+   * A type without data
+   */
+  | "Empty"
+  | { Int: number }
+  | { Text: string }
+  /**
+   * A custom type
+   */
+  | { Position: Point };
+
+export const DataType = {
+  /**
+   * Parses DataType from a plain value
+   */
+  parse(value: unknown): DataType {
+    return termite.parseEnum(value, ["Empty"], {
+      Int: termite.parseInteger,
+      Text: termite.parseString,
+      Position: Point.parse,
+    }) as DataType;
+  },
+};
@@ -0,0 +1,25 @@
+import { assertEqual, assertError, test } from "./assert";
+import { DataType } from "./basic";
+
+test("empty", () => {
+  assertEqual(DataType.parse("Empty"), "Empty");
+});
+
+test("values", () => {
+  assertEqual(DataType.parse({ Int: "5" }), { Int: 5 });
+  assertEqual(DataType.parse({ Text: "a" }), { Text: "a" });
+  assertEqual(DataType.parse({ Position: { x: 1 } }), { Position: { x: 1.0 } });
+});
+
+test("errors", () => {
+  const cases: [unknown, string][] = [
+    ["Int", "Enum type Int must contain a value"],
+    [{ Empty: 1 }, "Enum type Empty must not include values"],
+    ["Other", 'Unknown enum type "Other"'],
+    [{ Int: 1, Text: "a" }, "There must be exactly one enum type specified but received 2"],
+  ];
+  for (const [data, message] of cases) {
+    assertError(() => DataType.parse(data), message);
+  }
+  assertError(() => DataType.parse({ Position: {} }), "Missing x", "Position");
+});
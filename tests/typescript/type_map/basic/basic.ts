@@ -0,0 +1,25 @@
+// Generated with the Termite Data Model Generator
+
+import * as termite from "./termite";
+
+export type DataType1 = { [key: string]: number };
+
+export const DataType1 = {
+  /**
+   * Parses DataType1 from a plain value
+   */
+  parse(value: unknown): DataType1 {
+    return termite.parseValues(value, termite.parseInteger);
+  },
+};
+
+export type DataType2 = { [key: string]: number };
+
+export const DataType2 = {
+  /**
+   * Parses DataType2 from a plain value
+   */
+  parse(value: unknown): DataType2 {
+    return termite.parseValues(value, termite.parseNumber);
+  },
+};
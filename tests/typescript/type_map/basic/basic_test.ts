@@ -0,0 +1,12 @@
+import { assertEqual, assertError, assertThrows, test } from "./assert";
+import { DataType1, DataType2 } from "./basic";
+
+test("map", () => {
+  assertEqual(DataType1.parse({ a: "1", b: 2 }), { a: 1, b: 2 });
+  assertEqual(DataType2.parse({ c: 1.5 }), { c: 1.5 });
+});
+
+test("error", () => {
+  assertError(() => DataType1.parse({ a: 1, b: "c" }), "Unable to parse", "b");
+  assertThrows(() => DataType1.parse([1]));
+});
@@ -0,0 +1,58 @@
+// Generated with the Termite Data Model Generator
+
+import * as termite from "./termite";
+
+/**
+ * The value must fulfill the constraints:
+ * - len(x) == 2
+ */
+export type Key = termite.Brand<string, "Key">;
+
+export const Key = {
+  /**
+   * Gets the first constraint of Key the value does not fulfill,
+   * undefined if it fulfills all of them
+   */
+  check(x: string): string | undefined {
+    if (!([...x].length === 2)) {
+      return "len(x) == 2";
+    }
+    return undefined;
+  },
+
+  /**
+   * Checks if the value fulfills all the constraints of Key
+   */
+  is(x: string): x is Key {
+    return Key.check(x) === undefined;
+  },
+
+  /**
+   * Constructs Key if the value fulfills all the constraints
+   */
+  from(x: string): Key {
+    const constraint = Key.check(x);
+    if (constraint !== undefined) {
+      throw new termite.Error(`Did not pass constraint: ${constraint}`);
+    }
+    return x as Key;
+  },
+
+  /**
+   * Parses Key from a plain value
+   */
+  parse(value: unknown): Key {
+    return Key.from(termite.parseString(value));
+  },
+};
+
+export type DataType = { [key: string]: number };
+
+export const DataType = {
+  /**
+   * Parses DataType from a plain value
+   */
+  parse(value: unknown): DataType {
+    return termite.parseValues(value, termite.parseInteger, Key.parse);
+  },
+};
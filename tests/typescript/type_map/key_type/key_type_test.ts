@@ -0,0 +1,14 @@
+import { assertEqual, assertError, test } from "./assert";
+import { DataType } from "./key_type";
+
+test("valid keys", () => {
+  assertEqual(DataType.parse({ ab: 1, cd: "2" }), { ab: 1, cd: 2 });
+});
+
+test("invalid key", () => {
+  assertError(
+    () => DataType.parse({ ab: 1, abc: 2 }),
+    "Did not pass constraint: len(x) == 2",
+    "abc",
+  );
+});
@@ -0,0 +1,100 @@
+// Generated with the Termite Data Model Generator
+
+import * as termite from "./termite";
+
+/**
+ * A point in 2D space
+ */
+export interface Point {
+  /**
+   * The x-coordinate
+   */
+  x: number;
+  /**
+   * The y-coordinate
+   */
+  y: number;
+}
+
+export const Point = {
+  /**
+   * Parses Point from a plain value, unknown fields are kept
+   */
+  parse(value: unknown): Point {
+    const fields = termite.parseMap(value);
+    const result: Point = {
+      ...fields,
+      x: termite.parseField(fields, "x", termite.parseNumber),
+      y: termite.hasField(fields, "y")
+        ? termite.parseField(fields, "y", termite.parseNumber)
+        : Point.default_y(),
+    };
+    return result;
+  },
+
+  /**
+   * The default value of the y field
+   */
+  default_y(): number {
+    return termite.parseNumber("0.0");
+  },
+};
+
+export interface DataType {
+  id: number;
+  type: string;
+  active?: boolean;
+  point: Point;
+}
+
+export const DataType = {
+  /**
+   * Parses DataType from a plain value, unknown fields are kept
+   */
+  parse(value: unknown): DataType {
+    const fields = termite.parseMap(value);
+    const result: DataType = {
+      ...fields,
+      id: termite.parseField(fields, "id", termite.parseInteger),
+      type: termite.hasField(fields, "type")
+        ? termite.parseField(fields, "type", termite.parseString)
+        : DataType.default_type(),
+      point: termite.hasField(fields, "point")
+        ? termite.parseField(fields, "point", Point.parse)
+        : DataType.default_point(),
+    };
+    if (termite.hasField(fields, "active")) {
+      result.active = termite.parseField(fields, "active", termite.parseBoolean);
+    }
+    return result;
+  },
+
+  /**
+   * The default value of the type field
+   */
+  default_type(): string {
+    return termite.parseString("default");
+  },
+
+  /**
+   * The default value of the point field
+   */
+  default_point(): Point {
+    return Point.parse({ x: "1.5" });
+  },
+};
+
+export interface Empty {}
+
+export const Empty = {
+  /**
+   * Parses Empty from a plain value, unknown fields are kept
+   */
+  parse(value: unknown): Empty {
+    const fields = termite.parseMap(value);
+    const result: Empty = {
+      ...fields,
+    };
+    return result;
+  },
+};
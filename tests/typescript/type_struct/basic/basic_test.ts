@@ -0,0 +1,37 @@
+import { assertEqual, assertError, assertThrows, test } from "./assert";
+import { DataType, Empty, Point } from "./basic";
+
+test("required", () => {
+  assertEqual(Point.parse({ x: "1.5" }), { x: 1.5, y: 0.0 });
+  assertError(() => Point.parse({ y: 1 }), "Missing x");
+});
+
+test("defaults", () => {
+  const value = DataType.parse({ id: 5 });
+  assertEqual(value, { id: 5, type: "default", point: { x: 1.5, y: 0.0 } });
+  assertEqual("active" in value, false);
+});
+
+test("round trip", () => {
+  const data = {
+    id: 1,
+    type: "text",
+    active: true,
+    point: { x: 2.0, y: 3.0, z: 4 },
+    other: [1],
+  };
+  const value = DataType.parse(JSON.parse(JSON.stringify(data)));
+  assertEqual(value.active, true);
+  assertEqual(value.point.x, 2.0);
+  assertEqual(value, data);
+  assertEqual(DataType.parse({ id: "1", active: "false" }).active, false);
+});
+
+test("error location", () => {
+  assertError(() => DataType.parse({ id: 1, point: { x: "a" } }), "Unable to parse", "point.x");
+});
+
+test("empty", () => {
+  assertEqual(Empty.parse({}), {});
+  assertThrows(() => Empty.parse([]));
+});
@@ -0,0 +1,68 @@
+// Generated with the Termite Data Model Generator
+
+import * as termite from "./termite";
+
+export interface Base {
+  id: number;
+}
+
+export const Base = {
+  /**
+   * Parses Base from a plain value, unknown fields are kept
+   */
+  parse(value: unknown): Base {
+    const fields = termite.parseMap(value);
+    const result: Base = {
+      ...fields,
+      id: termite.parseField(fields, "id", termite.parseInteger),
+    };
+    return result;
+  },
+};
+
+export interface Middle extends Base {
+  name: string;
+}
+
+export const Middle = {
+  /**
+   * Parses Middle from a plain value, unknown fields are kept
+   */
+  parse(value: unknown): Middle {
+    const fields = termite.parseMap(value);
+    const result: Middle = {
+      ...Base.parse(fields),
+      name: termite.hasField(fields, "name")
+        ? termite.parseField(fields, "name", termite.parseString)
+        : Middle.default_name(),
+    };
+    return result;
+  },
+
+  /**
+   * The default value of the name field
+   */
+  default_name(): string {
+    return termite.parseString("middle");
+  },
+};
+
+export interface Derived extends Middle {
+  value?: number;
+}
+
+export const Derived = {
+  /**
+   * Parses Derived from a plain value, unknown fields are kept
+   */
+  parse(value: unknown): Derived {
+    const fields = termite.parseMap(value);
+    const result: Derived = {
+      ...Middle.parse(fields),
+    };
+    if (termite.hasField(fields, "value")) {
+      result.value = termite.parseField(fields, "value", termite.parseNumber);
+    }
+    return result;
+  },
+};
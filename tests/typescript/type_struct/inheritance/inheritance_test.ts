@@ -0,0 +1,17 @@
+import { assertEqual, assertError, test } from "./assert";
+import { Base, Derived, Middle } from "./inheritance";
+
+test("base", () => {
+  assertEqual(Base.parse({ id: "1" }), { id: 1 });
+});
+
+test("middle", () => {
+  assertEqual(Middle.parse({ id: 1 }), { id: 1, name: "middle" });
+});
+
+test("derived", () => {
+  const data = { id: 2, name: "derived", value: 1.5 };
+  assertEqual(Derived.parse(data), data);
+  assertEqual(Derived.parse({ id: 2, value: "1.5" }), { id: 2, name: "middle", value: 1.5 });
+  assertError(() => Derived.parse({ name: "derived" }), "Missing id");
+});
@@ -0,0 +1,107 @@
+// Generated with the Termite Data Model Generator
+
+import * as termite from "./termite";
+
+/**
+ * Rejects unknown fields through the data model setting
+ */
+export interface Size {
+  width: number;
+  height: number;
+}
+
+export const Size = {
+  /**
+   * Parses Size from a plain value, unknown fields are rejected
+   */
+  parse(value: unknown): Size {
+    const fields = termite.parseMap(value);
+    termite.rejectFields(fields, ["width", "height"]);
+    const result: Size = {
+      ...fields,
+      width: termite.parseField(fields, "width", termite.parseInteger),
+      height: termite.hasField(fields, "height")
+        ? termite.parseField(fields, "height", termite.parseInteger)
+        : Size.default_height(),
+    };
+    return result;
+  },
+
+  /**
+   * The default value of the height field
+   */
+  default_height(): number {
+    return termite.parseInteger("1");
+  },
+};
+
+export interface Ignored {
+  size: Size;
+}
+
+export const Ignored = {
+  /**
+   * Parses Ignored from a plain value, unknown fields are ignored
+   */
+  parse(value: unknown): Ignored {
+    const fields = termite.pickFields(termite.parseMap(value), ["size"]);
+    const result: Ignored = {
+      ...fields,
+      size: termite.parseField(fields, "size", Size.parse),
+    };
+    return result;
+  },
+};
+
+export interface Collected {}
+
+export const Collected = {
+  /**
+   * Parses Collected from a plain value, unknown fields are kept
+   */
+  parse(value: unknown): Collected {
+    const fields = termite.parseMap(value);
+    const result: Collected = {
+      ...fields,
+    };
+    return result;
+  },
+};
+
+export interface Empty {}
+
+export const Empty = {
+  /**
+   * Parses Empty from a plain value, unknown fields are rejected
+   */
+  parse(value: unknown): Empty {
+    const fields = termite.parseMap(value);
+    termite.rejectFields(fields, []);
+    const result: Empty = {
+      ...fields,
+    };
+    return result;
+  },
+};
+
+/**
+ * Keeps unknown fields while the inherited struct rejects them
+ */
+export interface Box extends Size {
+  depth: number;
+}
+
+export const Box = {
+  /**
+   * Parses Box from a plain value, unknown fields are kept
+   */
+  parse(value: unknown): Box {
+    const fields = termite.parseMap(value);
+    const result: Box = {
+      ...fields,
+      ...Size.parse(termite.pickFields(fields, ["width", "height"])),
+      depth: termite.parseField(fields, "depth", termite.parseInteger),
+    };
+    return result;
+  },
+};
@@ -0,0 +1,38 @@
+import { assertEqual, assertError, assertThrows, test } from "./assert";
+import { Box, Collected, Empty, Ignored, Size } from "./unknown_fields";
+
+test("reject", () => {
+  assertEqual(Size.parse({ width: 2 }), { width: 2, height: 1 });
+  assertError(
+    () => Size.parse({ width: 2, widht: 2, zzzzzzzzzz: 2 }),
+    'Unknown fields "widht" (did you mean "width"?), "zzzzzzzzzz"',
+  );
+  assertEqual(Empty.parse({}), {});
+  assertThrows(() => Empty.parse({ extra: 2 }));
+});
+
+test("nested location", () => {
+  assertError(
+    () => Ignored.parse({ size: { width: 2, heigth: 2 } }),
+    'Unknown field "heigth" (did you mean "height"?)',
+    "size",
+  );
+});
+
+test("ignore", () => {
+  assertEqual(Ignored.parse({ size: { width: 2 }, extra: 2 }), { size: { width: 2, height: 1 } });
+});
+
+test("collect", () => {
+  assertEqual(Collected.parse({ extra: 2 }), { extra: 2 });
+});
+
+test("inherited", () => {
+  assertEqual(Box.parse({ width: 2, depth: "3", extra: 4 }), {
+    width: 2,
+    height: 1,
+    depth: 3,
+    extra: 4,
+  });
+  assertError(() => Box.parse({ width: 2 }), "Missing depth");
+});
@@ -0,0 +1,40 @@
+// Generated with the Termite Data Model Generator
+
+import * as termite from "./termite";
+
+export interface Point {
+  x: number;
+}
+
+export const Point = {
+  /**
+   * Parses Point from a plain value, unknown fields are kept
+   */
+  parse(value: unknown): Point {
+    const fields = termite.parseMap(value);
+    const result: Point = {
+      ...fields,
+      x: termite.parseField(fields, "x", termite.parseNumber),
+    };
+    return result;
+  },
+};
+
+/**
+ * Any of the types
+ */
+export type DataType = number | Point | string;
+
+export const DataType = {
+  /**
+   * Parses DataType from a plain value
+   */
+  parse(value: unknown): DataType {
+    return termite.parseVariant(value, [
+      ["integer", termite.parseInteger],
+      ["number", termite.parseNumber],
+      ["Point", Point.parse],
+      ["string", termite.parseString],
+    ]) as DataType;
+  },
+};
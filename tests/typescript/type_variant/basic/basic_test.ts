@@ -0,0 +1,13 @@
+import { assertEqual, assertThrows, test } from "./assert";
+import { DataType } from "./basic";
+
+test("first match", () => {
+  assertEqual(DataType.parse("5"), 5);
+  assertEqual(DataType.parse(1.5), 1.5);
+  assertEqual(DataType.parse({ x: 1 }), { x: 1.0 });
+  assertEqual(DataType.parse("text"), "text");
+});
+
+test("error", () => {
+  assertThrows(() => DataType.parse([]));
+});